@@ -0,0 +1,29 @@
+//! Benchmarks for the Pest schema parser
+//!
+//! Compares the default span-tracking parse against the `fast_parse` path
+//! on the LinkML meta schema.
+
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use linkml_core::parser::LinkMLParser;
+
+/// The LinkML meta schema, the largest schema shipped with the repository
+const META_SCHEMA: &str = include_str!("../../schemas/meta.yaml");
+
+fn bench_parse_meta_schema(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse_meta_schema");
+
+    group.bench_function("spans", |b| {
+        b.iter(|| LinkMLParser::parse_schema(black_box(META_SCHEMA)).expect("meta schema parses"));
+    });
+
+    group.bench_function("fast", |b| {
+        b.iter(|| {
+            LinkMLParser::parse_schema_fast(black_box(META_SCHEMA)).expect("meta schema parses")
+        });
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_parse_meta_schema);
+criterion_main!(benches);
@@ -226,6 +226,12 @@ pub struct SlotAst {
     pub minimum_cardinality: Option<Spanned<i64>>,
     /// Maximum cardinality
     pub maximum_cardinality: Option<Spanned<i64>>,
+    /// Minimum length of string values
+    pub min_length: Option<Spanned<i64>>,
+    /// Maximum length of string values
+    pub max_length: Option<Spanned<i64>>,
+    /// Whitespace handling before the length check
+    pub whitespace: Option<Spanned<String>>,
     /// Parent slot (`is_a`)
     pub is_a: Option<Spanned<String>>,
     /// Mixins
@@ -301,6 +307,12 @@ pub struct TypeAst {
     pub minimum_value: Option<Spanned<ValueAst>>,
     /// Maximum value
     pub maximum_value: Option<Spanned<ValueAst>>,
+    /// Minimum length of string values
+    pub min_length: Option<Spanned<i64>>,
+    /// Maximum length of string values
+    pub max_length: Option<Spanned<i64>>,
+    /// Whitespace handling before the length check
+    pub whitespace: Option<Spanned<String>>,
     /// Annotations
     pub annotations: Option<Spanned<AnnotationsAst>>,
 }
@@ -0,0 +1,930 @@
+//! Conversion between the Pest AST and the schema definition types
+//!
+//! [`LinkMLParser`](crate::parser::LinkMLParser) produces a [`SchemaAst`] that
+//! records a [`Span`] for every value, while validators and generators work on
+//! [`SchemaDefinition`]. [`SchemaAst::into_schema_definition`] bridges the two
+//! so span-carrying parses can feed the same downstream code, and
+//! [`SchemaAst::from_schema_definition`] goes the other way for tooling that
+//! wants to edit a definition through the AST.
+//!
+//! The definition types have no counterpart for a few AST fields (slot
+//! property characteristics such as `symmetric` or `transitive`, and class
+//! `id_prefixes`); those are dropped on conversion.
+
+use std::fmt;
+
+use indexmap::IndexMap;
+use serde_json::{Number, Value};
+
+use crate::annotations::{AnnotationValue, Annotations};
+use crate::ast::{
+    AnnotationValueAst, AnnotationsAst, ClassAst, ContributorAst, Description, EnumAst,
+    PermissibleValueAst, RangeType, RecursionOptionsAst, SchemaAst, SlotAst, Span, Spanned,
+    StructuredPatternAst, SubsetAst, TypeAst, UniqueKeyAst, ValueAst,
+};
+use crate::error::{LinkMLError, Result};
+use crate::metadata::Contributor;
+use crate::settings::SchemaSettings;
+use crate::types::{
+    ClassDefinition, ConditionalRequirement, EnumDefinition, IfAbsentAction, PermissibleValue,
+    PrefixDefinition, RecursionOptions, Rule, RuleConditions, SchemaDefinition, SlotCondition,
+    SlotDefinition, StructuredPattern, SubsetDefinition, TypeDefinition, UniqueKeyDefinition,
+    WhitespaceFacet,
+};
+
+/// Span given to nodes that were not parsed from source text
+const SYNTHETIC_SPAN: Span = Span {
+    start: 0,
+    end: 0,
+    line: 0,
+    column: 0,
+};
+
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+impl SchemaAst {
+    /// Convert the AST into a [`SchemaDefinition`], discarding spans
+    ///
+    /// # Errors
+    ///
+    /// Returns `LinkMLError::SchemaValidationError` if the schema `id` or
+    /// `name` is missing, and `LinkMLError::ParseError` with the offending
+    /// line and column if a value cannot be represented in the definition
+    /// types (a non-finite number or a negative recursion depth).
+    pub fn into_schema_definition(self) -> Result<SchemaDefinition> {
+        self.validate_required_fields()
+            .map_err(LinkMLError::schema_validation)?;
+
+        let settings = (!self.settings.is_empty()).then(|| SchemaSettings {
+            custom: self
+                .settings
+                .into_iter()
+                .map(|(name, value)| (name, Value::String(value.value)))
+                .collect(),
+            ..SchemaSettings::default()
+        });
+
+        Ok(SchemaDefinition {
+            id: self.id.map(|id| id.value).unwrap_or_default(),
+            name: self.name.map(|name| name.value).unwrap_or_default(),
+            title: unspan(self.title),
+            description: self.description.map(description_text),
+            version: unspan(self.version),
+            license: unspan(self.license),
+            default_prefix: unspan(self.default_prefix),
+            prefixes: self
+                .prefixes
+                .into_iter()
+                .map(|(prefix, uri)| (prefix, PrefixDefinition::Simple(uri.value)))
+                .collect(),
+            imports: unspan_all(self.imports),
+            classes: convert_map(self.classes, class_definition)?,
+            slots: convert_map(self.slots, slot_definition)?,
+            types: convert_map(self.types, type_definition)?,
+            enums: convert_map(self.enums, enum_definition)?,
+            subsets: convert_map(self.subsets, |name, node| Ok(subset_definition(name, node)))?,
+            default_range: unspan(self.default_range),
+            generation_date: unspan(self.generation_date),
+            source_file: unspan(self.source_file),
+            metamodel_version: unspan(self.metamodel_version),
+            settings,
+            annotations: self.annotations.map(annotations).transpose()?,
+            contributors: self
+                .contributors
+                .into_iter()
+                .map(|contributor| contributor_definition(contributor.value))
+                .collect(),
+            status: unspan(self.status),
+            categories: unspan_all(self.categories),
+            keywords: unspan_all(self.keywords),
+            see_also: unspan_all(self.see_also),
+        })
+    }
+
+    /// Build an AST from a [`SchemaDefinition`]
+    ///
+    /// Every node gets a zeroed span since there is no source text to point
+    /// at. Class rules and conditional requirements are not carried over, as
+    /// the AST only models the simple slot-to-expression form of those.
+    #[must_use]
+    pub fn from_schema_definition(schema: &SchemaDefinition) -> Self {
+        let settings = schema
+            .settings
+            .iter()
+            .flat_map(|settings| &settings.custom)
+            .filter_map(|(name, value)| Some((name.clone(), spanned(value.as_str()?.to_string()))))
+            .collect();
+
+        Self {
+            document_type: Some(crate::ast::DocumentType::Schema),
+            id: Some(spanned(schema.id.clone())),
+            name: Some(spanned(schema.name.clone())),
+            title: respan(schema.title.as_ref()),
+            description: schema.description.as_deref().map(description_ast),
+            version: respan(schema.version.as_ref()),
+            license: respan(schema.license.as_ref()),
+            default_prefix: respan(schema.default_prefix.as_ref()),
+            default_range: respan(schema.default_range.as_ref()),
+            metamodel_version: respan(schema.metamodel_version.as_ref()),
+            source_file: respan(schema.source_file.as_ref()),
+            generation_date: respan(schema.generation_date.as_ref()),
+            status: respan(schema.status.as_ref()),
+            prefixes: schema
+                .prefixes
+                .iter()
+                .filter_map(|(prefix, definition)| {
+                    let uri = match definition {
+                        PrefixDefinition::Simple(uri) => uri,
+                        PrefixDefinition::Complex {
+                            prefix_reference, ..
+                        } => prefix_reference.as_ref()?,
+                    };
+                    Some((prefix.clone(), spanned(uri.clone())))
+                })
+                .collect(),
+            imports: respan_all(&schema.imports),
+            settings,
+            classes: spanned_map(&schema.classes, class_ast),
+            slots: spanned_map(&schema.slots, slot_ast),
+            types: spanned_map(&schema.types, type_ast),
+            enums: spanned_map(&schema.enums, enum_ast),
+            subsets: spanned_map(&schema.subsets, |name, subset| SubsetAst {
+                name: name.to_string(),
+                description: subset.description.as_deref().map(description_ast),
+                annotations: None,
+            }),
+            contributors: schema
+                .contributors
+                .iter()
+                .map(|contributor| {
+                    spanned(ContributorAst {
+                        name: contributor.name.clone(),
+                        email: contributor.email.clone(),
+                        orcid: contributor.orcid.clone(),
+                    })
+                })
+                .collect(),
+            categories: respan_all(&schema.categories),
+            keywords: respan_all(&schema.keywords),
+            see_also: respan_all(&schema.see_also),
+            annotations: schema.annotations.as_ref().map(annotations_ast),
+            ..Self::default()
+        }
+    }
+}
+
+fn unspan<T>(node: Option<Spanned<T>>) -> Option<T> {
+    node.map(|node| node.value)
+}
+
+fn unspan_all<T>(nodes: Vec<Spanned<T>>) -> Vec<T> {
+    nodes.into_iter().map(|node| node.value).collect()
+}
+
+fn description_text(description: Spanned<Description>) -> String {
+    match description.value {
+        Description::Inline(text) | Description::Block(text) => text,
+    }
+}
+
+/// Convert each node of a name-keyed AST map, keeping the key as the name
+fn convert_map<A, D>(
+    nodes: IndexMap<String, Spanned<A>>,
+    convert: impl Fn(&str, Spanned<A>) -> Result<D>,
+) -> Result<IndexMap<String, D>> {
+    nodes
+        .into_iter()
+        .map(|(name, node)| {
+            let definition = convert(&name, node)?;
+            Ok((name, definition))
+        })
+        .collect()
+}
+
+fn json_value(node: Spanned<ValueAst>) -> Result<Value> {
+    match node.value {
+        ValueAst::String(text) => Ok(Value::String(text)),
+        ValueAst::Integer(value) => Ok(Value::from(value)),
+        ValueAst::Number(value) => Number::from_f64(value).map(Value::Number).ok_or_else(|| {
+            LinkMLError::parse_at(
+                format!("{value} is not a finite number"),
+                node.span.to_string(),
+            )
+        }),
+    }
+}
+
+fn annotations(node: Spanned<AnnotationsAst>) -> Result<Annotations> {
+    node.value
+        .entries
+        .into_iter()
+        .map(|(key, entry)| {
+            let value = match entry.value {
+                AnnotationValueAst::Bool(flag) => AnnotationValue::Bool(flag),
+                AnnotationValueAst::Number(value) => Number::from_f64(value)
+                    .map(AnnotationValue::Number)
+                    .ok_or_else(|| {
+                        LinkMLError::parse_at(
+                            format!("annotation '{key}' is not a finite number"),
+                            entry.span.to_string(),
+                        )
+                    })?,
+                AnnotationValueAst::String(text) | AnnotationValueAst::Block(text) => {
+                    AnnotationValue::String(text)
+                }
+                AnnotationValueAst::List(items) => AnnotationValue::Array(
+                    items
+                        .into_iter()
+                        .map(|item| AnnotationValue::String(item.value))
+                        .collect(),
+                ),
+            };
+            Ok((key, value))
+        })
+        .collect()
+}
+
+fn class_definition(name: &str, node: Spanned<ClassAst>) -> Result<ClassDefinition> {
+    let class = node.value;
+    let recursion_options = class
+        .recursion_options
+        .map(|options| {
+            let max_depth = options
+                .value
+                .max_depth
+                .map(|depth| {
+                    usize::try_from(depth).map_err(|_| {
+                        LinkMLError::parse_at(
+                            format!("max_depth of class '{name}' must not be negative"),
+                            options.span.to_string(),
+                        )
+                    })
+                })
+                .transpose()?;
+            Ok::<_, LinkMLError>(RecursionOptions {
+                use_box: options.value.use_box,
+                max_depth,
+            })
+        })
+        .transpose()?;
+
+    let if_required = (!class.if_required.is_empty()).then(|| {
+        class
+            .if_required
+            .into_iter()
+            .map(|(slot, requirement)| {
+                let requirement = ConditionalRequirement {
+                    condition: None,
+                    then_required: Some(unspan_all(requirement.value.then_required)),
+                };
+                (slot, requirement)
+            })
+            .collect()
+    });
+
+    Ok(ClassDefinition {
+        name: name.to_string(),
+        description: class.description.map(description_text),
+        abstract_: unspan(class.abstract_),
+        mixin: unspan(class.mixin),
+        is_a: unspan(class.is_a),
+        mixins: unspan_all(class.mixins),
+        slots: unspan_all(class.slots),
+        slot_usage: convert_map(class.slot_usage, slot_definition)?,
+        attributes: convert_map(class.attributes, slot_definition)?,
+        class_uri: unspan(class.class_uri),
+        subclass_of: unspan_all(class.subclass_of),
+        tree_root: unspan(class.tree_root),
+        rules: class
+            .rules
+            .into_iter()
+            .map(|rule| Rule {
+                preconditions: Some(rule_conditions(rule.value.preconditions)),
+                postconditions: Some(rule_conditions(rule.value.postconditions)),
+                ..Rule::default()
+            })
+            .collect(),
+        if_required,
+        unique_keys: class
+            .unique_keys
+            .into_iter()
+            .map(|(key, unique_key)| {
+                let definition = UniqueKeyDefinition {
+                    unique_key_slots: unspan_all(unique_key.value.unique_key_slots),
+                    ..UniqueKeyDefinition::default()
+                };
+                (key, definition)
+            })
+            .collect(),
+        annotations: class.annotations.map(annotations).transpose()?,
+        recursion_options,
+        aliases: unspan_all(class.aliases),
+        see_also: unspan_all(class.see_also),
+        exact_mappings: unspan_all(class.exact_mappings),
+        close_mappings: unspan_all(class.close_mappings),
+        related_mappings: unspan_all(class.related_mappings),
+        narrow_mappings: unspan_all(class.narrow_mappings),
+        broad_mappings: unspan_all(class.broad_mappings),
+        ..ClassDefinition::default()
+    })
+}
+
+/// Rule conditions in the AST map a slot name to an expression over it
+fn rule_conditions(conditions: IndexMap<String, Spanned<String>>) -> RuleConditions {
+    let slot_conditions = conditions
+        .into_iter()
+        .map(|(slot, expression)| {
+            let condition = SlotCondition {
+                equals_expression: Some(expression.value),
+                ..SlotCondition::default()
+            };
+            (slot, condition)
+        })
+        .collect();
+    RuleConditions {
+        slot_conditions: Some(slot_conditions),
+        ..RuleConditions::default()
+    }
+}
+
+fn slot_definition(name: &str, node: Spanned<SlotAst>) -> Result<SlotDefinition> {
+    let slot = node.value;
+    Ok(SlotDefinition {
+        name: name.to_string(),
+        description: slot.description.map(description_text),
+        range: unspan(slot.range),
+        range_type: unspan(slot.range_type).map(|range_type| {
+            match range_type {
+                RangeType::Class => "class",
+                RangeType::Instance => "instance",
+            }
+            .to_string()
+        }),
+        range_properties: unspan_all(slot.range_properties),
+        required: unspan(slot.required),
+        multivalued: unspan(slot.multivalued),
+        identifier: unspan(slot.identifier),
+        pattern: unspan(slot.pattern),
+        structured_pattern: unspan(slot.structured_pattern).map(|pattern| StructuredPattern {
+            syntax: Some(pattern.syntax),
+            interpolated: Some(pattern.interpolated),
+            ..StructuredPattern::default()
+        }),
+        minimum_value: slot.minimum_value.map(json_value).transpose()?,
+        maximum_value: slot.maximum_value.map(json_value).transpose()?,
+        minimum_cardinality: cardinality(name, "minimum_cardinality", slot.minimum_cardinality)?,
+        maximum_cardinality: cardinality(name, "maximum_cardinality", slot.maximum_cardinality)?,
+        min_length: length("slot", name, "min_length", slot.min_length)?,
+        max_length: length("slot", name, "max_length", slot.max_length)?,
+        whitespace: whitespace("slot", name, slot.whitespace)?,
+        is_a: unspan(slot.is_a),
+        mixins: unspan_all(slot.mixins),
+        slot_uri: unspan(slot.slot_uri),
+        domain: unspan(slot.domain),
+        inverse: unspan(slot.inverse),
+        ifabsent: slot
+            .ifabsent
+            .map(|ifabsent| {
+                serde_json::from_value::<IfAbsentAction>(Value::String(ifabsent.value)).map_err(
+                    |e| {
+                        LinkMLError::parse_at(
+                            format!("invalid ifabsent: {e}"),
+                            ifabsent.span.to_string(),
+                        )
+                    },
+                )
+            })
+            .transpose()?,
+        equals_string: unspan(slot.equals_string),
+        equals_number: unspan(slot.equals_number),
+        aliases: unspan_all(slot.aliases),
+        see_also: unspan_all(slot.see_also),
+        imports: unspan_all(slot.imports),
+        annotations: slot.annotations.map(annotations).transpose()?,
+        ..SlotDefinition::default()
+    })
+}
+
+fn cardinality(slot: &str, field: &str, node: Option<Spanned<i64>>) -> Result<Option<i32>> {
+    node.map(|count| {
+        i32::try_from(count.value)
+            .ok()
+            .filter(|count| *count >= 0)
+            .ok_or_else(|| {
+                LinkMLError::parse_at(
+                    format!("{field} of slot '{slot}' must be a non-negative 32-bit integer"),
+                    count.span.to_string(),
+                )
+            })
+    })
+    .transpose()
+}
+
+fn length(
+    kind: &str,
+    name: &str,
+    field: &str,
+    node: Option<Spanned<i64>>,
+) -> Result<Option<usize>> {
+    node.map(|length| {
+        usize::try_from(length.value).map_err(|_| {
+            LinkMLError::parse_at(
+                format!("{field} of {kind} '{name}' must be a non-negative integer"),
+                length.span.to_string(),
+            )
+        })
+    })
+    .transpose()
+}
+
+fn whitespace(
+    kind: &str,
+    name: &str,
+    node: Option<Spanned<String>>,
+) -> Result<Option<WhitespaceFacet>> {
+    node.map(|facet| {
+        facet.value.parse().map_err(|_| {
+            LinkMLError::parse_at(
+                format!(
+                    "whitespace of {kind} '{name}' must be preserve, replace or collapse, not '{}'",
+                    facet.value
+                ),
+                facet.span.to_string(),
+            )
+        })
+    })
+    .transpose()
+}
+
+fn type_definition(name: &str, node: Spanned<TypeAst>) -> Result<TypeDefinition> {
+    let type_ast = node.value;
+    Ok(TypeDefinition {
+        name: name.to_string(),
+        description: type_ast.description.map(description_text),
+        // `base` names the primitive a type bottoms out in; prefer the
+        // declared parent when both are present
+        base_type: unspan(type_ast.typeof_).or_else(|| unspan(type_ast.base)),
+        uri: unspan(type_ast.uri),
+        pattern: unspan(type_ast.pattern),
+        minimum_value: type_ast.minimum_value.map(json_value).transpose()?,
+        maximum_value: type_ast.maximum_value.map(json_value).transpose()?,
+        min_length: length("type", name, "min_length", type_ast.min_length)?,
+        max_length: length("type", name, "max_length", type_ast.max_length)?,
+        whitespace: whitespace("type", name, type_ast.whitespace)?,
+        annotations: type_ast.annotations.map(annotations).transpose()?,
+    })
+}
+
+fn enum_definition(name: &str, node: Spanned<EnumAst>) -> Result<EnumDefinition> {
+    let enum_ast = node.value;
+    Ok(EnumDefinition {
+        name: name.to_string(),
+        description: enum_ast.description.map(description_text),
+        permissible_values: enum_ast
+            .permissible_values
+            .into_iter()
+            .map(|(text, value)| {
+                let description = value.value.description.map(description_text);
+                let meaning = unspan(value.value.meaning);
+                if description.is_none() && meaning.is_none() {
+                    PermissibleValue::Simple(text)
+                } else {
+                    PermissibleValue::Complex {
+                        text,
+                        description,
+                        meaning,
+                    }
+                }
+            })
+            .collect(),
+        code_set: unspan(enum_ast.code_set),
+        code_set_tag: unspan(enum_ast.code_set_tag),
+        code_set_version: unspan(enum_ast.code_set_version),
+        pv_formula: None,
+        annotations: enum_ast.annotations.map(annotations).transpose()?,
+    })
+}
+
+fn subset_definition(name: &str, node: Spanned<SubsetAst>) -> SubsetDefinition {
+    SubsetDefinition {
+        name: name.to_string(),
+        description: node.value.description.map(description_text),
+    }
+}
+
+fn contributor_definition(contributor: ContributorAst) -> Contributor {
+    Contributor {
+        name: contributor.name,
+        email: contributor.email,
+        github: None,
+        orcid: contributor.orcid,
+        role: None,
+    }
+}
+
+fn spanned<T>(value: T) -> Spanned<T> {
+    Spanned::new(value, SYNTHETIC_SPAN)
+}
+
+fn respan<T: Clone>(value: Option<&T>) -> Option<Spanned<T>> {
+    value.cloned().map(spanned)
+}
+
+fn respan_all(values: &[String]) -> Vec<Spanned<String>> {
+    values.iter().cloned().map(spanned).collect()
+}
+
+fn spanned_map<D, A>(
+    definitions: &IndexMap<String, D>,
+    convert: impl Fn(&str, &D) -> A,
+) -> IndexMap<String, Spanned<A>> {
+    definitions
+        .iter()
+        .map(|(name, definition)| (name.clone(), spanned(convert(name, definition))))
+        .collect()
+}
+
+fn description_ast(text: &str) -> Spanned<Description> {
+    if text.contains('\n') {
+        spanned(Description::Block(text.to_string()))
+    } else {
+        spanned(Description::Inline(text.to_string()))
+    }
+}
+
+/// Values without a number or string form in the AST fall back to their
+/// JSON text
+fn value_ast(value: &Value) -> Spanned<ValueAst> {
+    spanned(match value {
+        Value::String(text) => ValueAst::String(text.clone()),
+        Value::Number(number) => match (number.as_i64(), number.as_f64()) {
+            (Some(integer), _) => ValueAst::Integer(integer),
+            (None, Some(float)) => ValueAst::Number(float),
+            (None, None) => ValueAst::String(number.to_string()),
+        },
+        other => ValueAst::String(other.to_string()),
+    })
+}
+
+fn annotations_ast(annotations: &Annotations) -> Spanned<AnnotationsAst> {
+    let entries = annotations
+        .iter()
+        .map(|(key, value)| {
+            let value = match value {
+                AnnotationValue::Bool(flag) => AnnotationValueAst::Bool(*flag),
+                AnnotationValue::Number(number) => number.as_f64().map_or_else(
+                    || AnnotationValueAst::String(number.to_string()),
+                    AnnotationValueAst::Number,
+                ),
+                AnnotationValue::String(text) => AnnotationValueAst::String(text.clone()),
+                AnnotationValue::Array(items) => AnnotationValueAst::List(
+                    items
+                        .iter()
+                        .map(|item| match item {
+                            AnnotationValue::String(text) => spanned(text.clone()),
+                            other => spanned(serde_json::to_string(other).unwrap_or_default()),
+                        })
+                        .collect(),
+                ),
+                other => {
+                    AnnotationValueAst::String(serde_json::to_string(other).unwrap_or_default())
+                }
+            };
+            (key.clone(), spanned(value))
+        })
+        .collect();
+    spanned(AnnotationsAst { entries })
+}
+
+fn class_ast(name: &str, class: &ClassDefinition) -> ClassAst {
+    ClassAst {
+        name: name.to_string(),
+        description: class.description.as_deref().map(description_ast),
+        is_a: respan(class.is_a.as_ref()),
+        abstract_: respan(class.abstract_.as_ref()),
+        mixin: respan(class.mixin.as_ref()),
+        tree_root: respan(class.tree_root.as_ref()),
+        class_uri: respan(class.class_uri.as_ref()),
+        mixins: respan_all(&class.mixins),
+        slots: respan_all(&class.slots),
+        slot_usage: spanned_map(&class.slot_usage, slot_ast),
+        attributes: spanned_map(&class.attributes, slot_ast),
+        subclass_of: respan_all(&class.subclass_of),
+        unique_keys: class
+            .unique_keys
+            .iter()
+            .map(|(key, unique_key)| {
+                let unique_key = UniqueKeyAst {
+                    name: key.clone(),
+                    unique_key_slots: respan_all(&unique_key.unique_key_slots),
+                };
+                (key.clone(), spanned(unique_key))
+            })
+            .collect(),
+        recursion_options: class.recursion_options.as_ref().map(|options| {
+            spanned(RecursionOptionsAst {
+                use_box: options.use_box,
+                max_depth: options
+                    .max_depth
+                    .map(|depth| i64::try_from(depth).unwrap_or(i64::MAX)),
+            })
+        }),
+        aliases: respan_all(&class.aliases),
+        see_also: respan_all(&class.see_also),
+        broad_mappings: respan_all(&class.broad_mappings),
+        exact_mappings: respan_all(&class.exact_mappings),
+        narrow_mappings: respan_all(&class.narrow_mappings),
+        related_mappings: respan_all(&class.related_mappings),
+        close_mappings: respan_all(&class.close_mappings),
+        annotations: class.annotations.as_ref().map(annotations_ast),
+        ..ClassAst::default()
+    }
+}
+
+fn slot_ast(name: &str, slot: &SlotDefinition) -> SlotAst {
+    SlotAst {
+        name: name.to_string(),
+        description: slot.description.as_deref().map(description_ast),
+        range: respan(slot.range.as_ref()),
+        range_type: match slot.range_type.as_deref() {
+            Some("class") => Some(spanned(RangeType::Class)),
+            Some("instance") => Some(spanned(RangeType::Instance)),
+            _ => None,
+        },
+        range_properties: respan_all(&slot.range_properties),
+        required: respan(slot.required.as_ref()),
+        multivalued: respan(slot.multivalued.as_ref()),
+        identifier: respan(slot.identifier.as_ref()),
+        pattern: respan(slot.pattern.as_ref()),
+        structured_pattern: slot.structured_pattern.as_ref().and_then(|pattern| {
+            Some(spanned(StructuredPatternAst {
+                syntax: pattern.syntax.clone()?,
+                interpolated: pattern.interpolated.unwrap_or(false),
+            }))
+        }),
+        minimum_value: slot.minimum_value.as_ref().map(value_ast),
+        maximum_value: slot.maximum_value.as_ref().map(value_ast),
+        minimum_cardinality: slot
+            .minimum_cardinality
+            .map(|count| spanned(i64::from(count))),
+        maximum_cardinality: slot
+            .maximum_cardinality
+            .map(|count| spanned(i64::from(count))),
+        min_length: slot.min_length.map(length_ast),
+        max_length: slot.max_length.map(length_ast),
+        whitespace: slot.whitespace.map(whitespace_ast),
+        is_a: respan(slot.is_a.as_ref()),
+        mixins: respan_all(&slot.mixins),
+        slot_uri: respan(slot.slot_uri.as_ref()),
+        domain: respan(slot.domain.as_ref()),
+        inverse: respan(slot.inverse.as_ref()),
+        ifabsent: slot
+            .ifabsent
+            .as_ref()
+            .map(|ifabsent| spanned(ifabsent_text(ifabsent))),
+        equals_string: respan(slot.equals_string.as_ref()),
+        equals_number: respan(slot.equals_number.as_ref()),
+        aliases: respan_all(&slot.aliases),
+        see_also: respan_all(&slot.see_also),
+        imports: respan_all(&slot.imports),
+        annotations: slot.annotations.as_ref().map(annotations_ast),
+        ..SlotAst::default()
+    }
+}
+
+fn ifabsent_text(ifabsent: &IfAbsentAction) -> String {
+    match ifabsent {
+        IfAbsentAction::SlotName => "slot_name".to_string(),
+        IfAbsentAction::ClassSlotCurie => "class_slot_curie".to_string(),
+        IfAbsentAction::ClassCurie => "class_curie".to_string(),
+        IfAbsentAction::ClassName => "class_name".to_string(),
+        IfAbsentAction::Bnode => "bnode".to_string(),
+        IfAbsentAction::DefaultValue => "default_value".to_string(),
+        IfAbsentAction::Date => "date".to_string(),
+        IfAbsentAction::Datetime => "datetime".to_string(),
+        IfAbsentAction::Int(value) => value.to_string(),
+        IfAbsentAction::String(text) | IfAbsentAction::Expression(text) => text.clone(),
+    }
+}
+
+fn type_ast(name: &str, type_def: &TypeDefinition) -> TypeAst {
+    TypeAst {
+        name: name.to_string(),
+        description: type_def.description.as_deref().map(description_ast),
+        typeof_: respan(type_def.base_type.as_ref()),
+        base: None,
+        uri: respan(type_def.uri.as_ref()),
+        pattern: respan(type_def.pattern.as_ref()),
+        minimum_value: type_def.minimum_value.as_ref().map(value_ast),
+        maximum_value: type_def.maximum_value.as_ref().map(value_ast),
+        min_length: type_def.min_length.map(length_ast),
+        max_length: type_def.max_length.map(length_ast),
+        whitespace: type_def.whitespace.map(whitespace_ast),
+        annotations: type_def.annotations.as_ref().map(annotations_ast),
+    }
+}
+
+fn length_ast(length: usize) -> Spanned<i64> {
+    spanned(i64::try_from(length).unwrap_or(i64::MAX))
+}
+
+fn whitespace_ast(facet: WhitespaceFacet) -> Spanned<String> {
+    spanned(facet.as_str().to_string())
+}
+
+fn enum_ast(name: &str, enum_def: &EnumDefinition) -> EnumAst {
+    EnumAst {
+        name: name.to_string(),
+        description: enum_def.description.as_deref().map(description_ast),
+        permissible_values: enum_def
+            .permissible_values
+            .iter()
+            .map(|value| {
+                let value = match value {
+                    PermissibleValue::Simple(text) => PermissibleValueAst {
+                        name: text.clone(),
+                        ..PermissibleValueAst::default()
+                    },
+                    PermissibleValue::Complex {
+                        text,
+                        description,
+                        meaning,
+                    } => PermissibleValueAst {
+                        name: text.clone(),
+                        description: description.as_deref().map(description_ast),
+                        meaning: respan(meaning.as_ref()),
+                        ..PermissibleValueAst::default()
+                    },
+                };
+                (value.name.clone(), spanned(value))
+            })
+            .collect(),
+        code_set: respan(enum_def.code_set.as_ref()),
+        code_set_tag: respan(enum_def.code_set_tag.as_ref()),
+        code_set_version: respan(enum_def.code_set_version.as_ref()),
+        annotations: enum_def.annotations.as_ref().map(annotations_ast),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at<T>(line: usize, value: T) -> Spanned<T> {
+        Spanned::new(value, Span::new(0, 0, line, 3))
+    }
+
+    fn country_ast() -> SchemaAst {
+        let mut schema = SchemaAst {
+            id: Some(at(1, "https://w3id.org/rootreal/iso3166".to_string())),
+            name: Some(at(2, "iso3166".to_string())),
+            ..SchemaAst::new()
+        };
+        schema.types.insert(
+            "alpha2".to_string(),
+            at(
+                5,
+                TypeAst {
+                    name: "alpha2".to_string(),
+                    typeof_: Some(at(6, "string".to_string())),
+                    pattern: Some(at(7, "^[A-Z]{2}$".to_string())),
+                    ..TypeAst::default()
+                },
+            ),
+        );
+        schema.enums.insert(
+            "Status".to_string(),
+            at(
+                9,
+                EnumAst {
+                    name: "Status".to_string(),
+                    permissible_values: IndexMap::from([
+                        (
+                            "official".to_string(),
+                            at(11, PermissibleValueAst::default()),
+                        ),
+                        (
+                            "withdrawn".to_string(),
+                            at(
+                                12,
+                                PermissibleValueAst {
+                                    description: Some(at(
+                                        13,
+                                        Description::Inline("No longer assigned".to_string()),
+                                    )),
+                                    ..PermissibleValueAst::default()
+                                },
+                            ),
+                        ),
+                    ]),
+                    ..EnumAst::default()
+                },
+            ),
+        );
+        schema.slots.insert(
+            "code".to_string(),
+            at(
+                15,
+                SlotAst {
+                    range: Some(at(16, "alpha2".to_string())),
+                    identifier: Some(at(17, true)),
+                    aliases: vec![at(18, "alpha_2".to_string())],
+                    ..SlotAst::default()
+                },
+            ),
+        );
+        schema.classes.insert(
+            "Country".to_string(),
+            at(
+                20,
+                ClassAst {
+                    description: Some(at(21, Description::Block("A country\n".to_string()))),
+                    slots: vec![at(22, "code".to_string())],
+                    attributes: IndexMap::from([(
+                        "status".to_string(),
+                        at(
+                            24,
+                            SlotAst {
+                                range: Some(at(25, "Status".to_string())),
+                                required: Some(at(26, true)),
+                                ..SlotAst::default()
+                            },
+                        ),
+                    )]),
+                    ..ClassAst::default()
+                },
+            ),
+        );
+        schema
+    }
+
+    #[test]
+    fn test_ast_converts_to_schema_definition() -> Result<()> {
+        let schema = country_ast().into_schema_definition()?;
+        assert_eq!(schema.name, "iso3166");
+
+        let alpha2 = &schema.types["alpha2"];
+        assert_eq!(alpha2.name, "alpha2");
+        assert_eq!(alpha2.base_type.as_deref(), Some("string"));
+        assert_eq!(alpha2.pattern.as_deref(), Some("^[A-Z]{2}$"));
+
+        assert_eq!(
+            schema.enums["Status"].permissible_values,
+            [
+                PermissibleValue::Simple("official".to_string()),
+                PermissibleValue::Complex {
+                    text: "withdrawn".to_string(),
+                    description: Some("No longer assigned".to_string()),
+                    meaning: None,
+                },
+            ]
+        );
+
+        let code = &schema.slots["code"];
+        assert_eq!(code.name, "code");
+        assert_eq!(code.range.as_deref(), Some("alpha2"));
+        assert_eq!(code.identifier, Some(true));
+        assert_eq!(code.aliases, ["alpha_2"]);
+
+        let country = &schema.classes["Country"];
+        assert_eq!(country.description.as_deref(), Some("A country\n"));
+        assert_eq!(country.slots, ["code"]);
+        let status = &country.attributes["status"];
+        assert_eq!(status.name, "status");
+        assert_eq!(status.range.as_deref(), Some("Status"));
+        assert_eq!(status.required, Some(true));
+        Ok(())
+    }
+
+    #[test]
+    fn test_schema_definition_round_trips_through_ast() -> Result<()> {
+        let schema = country_ast().into_schema_definition()?;
+        let ast = SchemaAst::from_schema_definition(&schema);
+        assert_eq!(ast.slots["code"].span, SYNTHETIC_SPAN);
+        assert_eq!(ast.into_schema_definition()?, schema);
+        Ok(())
+    }
+
+    #[test]
+    fn test_conversion_errors_carry_source_location() {
+        let mut schema = country_ast();
+        schema.classes["Country"].value.recursion_options = Some(at(
+            30,
+            RecursionOptionsAst {
+                use_box: true,
+                max_depth: Some(-1),
+            },
+        ));
+
+        let error = schema.into_schema_definition().unwrap_err();
+        assert!(matches!(
+            error,
+            LinkMLError::ParseError { location: Some(ref location), .. }
+                if location == "line 30, column 3"
+        ));
+    }
+}
@@ -0,0 +1,206 @@
+//! Order-insensitive comparison of `LinkML` schemas
+//!
+//! The derived `PartialEq` of [`SchemaDefinition`] is too strict for
+//! round-trip, diff and merge tests: elements keyed by name, such as the
+//! classes of a schema or the permissible values of an enum, compare in
+//! definition order, and provenance fields like `generation_date`
+//! legitimately differ between two loads of the same schema.
+//! [`SchemaDefinition::semantically_equal`] compares schemas by content
+//! instead.
+
+use crate::types::SchemaDefinition;
+use serde_json::{Map, Value};
+
+/// Fields ignored by [`SchemaDefinition::semantically_equal`]
+///
+/// They record where and when an element was produced rather than what it
+/// means. Fields a schema does not have are simply never seen.
+pub const PROVENANCE_FIELDS: &[&str] = &[
+    "from_schema",
+    "imported_from",
+    "source",
+    "source_file",
+    "source_file_date",
+    "source_file_size",
+    "generation_date",
+];
+
+/// Keys whose values hold elements by name, not fields to values
+///
+/// Their order carries no meaning. Permissible values are stored as a list
+/// but keyed by their text in schemas.
+const NAMED_COLLECTIONS: &[&str] = &[
+    "prefixes",
+    "classes",
+    "slots",
+    "types",
+    "enums",
+    "subsets",
+    "attributes",
+    "slot_usage",
+    "annotations",
+    "permissible_values",
+];
+
+impl SchemaDefinition {
+    /// Check whether two schemas define the same content
+    ///
+    /// Elements are matched by name and the [`PROVENANCE_FIELDS`] are
+    /// ignored on the schema and on every element. Other lists, such as
+    /// `imports`, a class's `slots` and `mixins`, `any_of` and `rules`,
+    /// compare in order, since their order carries meaning.
+    #[must_use]
+    pub fn semantically_equal(&self, other: &SchemaDefinition) -> bool {
+        self.semantically_equal_ignoring(other, PROVENANCE_FIELDS)
+    }
+
+    /// Check whether two schemas define the same content, ignoring `fields`
+    ///
+    /// Like [`Self::semantically_equal`], with the given fields ignored in
+    /// place of the [`PROVENANCE_FIELDS`]. Element names are never treated
+    /// as fields, so a slot called `source` is still compared.
+    #[must_use]
+    pub fn semantically_equal_ignoring(&self, other: &SchemaDefinition, fields: &[&str]) -> bool {
+        match (serde_json::to_value(self), serde_json::to_value(other)) {
+            (Ok(left), Ok(right)) => {
+                normalize(left, fields, false) == normalize(right, fields, false)
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Bring a serialized schema value into a canonical form
+///
+/// Ignored fields are dropped from element objects and object keys are
+/// sorted. `collection` marks a value holding elements by name: an object
+/// keyed by element names, or a list whose items are then sorted by their
+/// canonical text.
+fn normalize(value: Value, ignored: &[&str], collection: bool) -> Value {
+    match value {
+        Value::Object(object) => {
+            let mut entries: Vec<(String, Value)> = object
+                .into_iter()
+                .filter(|(key, _)| collection || !ignored.contains(&key.as_str()))
+                .map(|(key, value)| {
+                    let nested = !collection && NAMED_COLLECTIONS.contains(&key.as_str());
+                    let value = normalize(value, ignored, nested);
+                    (key, value)
+                })
+                .collect();
+            entries.sort_by(|(left, _), (right, _)| left.cmp(right));
+            Value::Object(entries.into_iter().collect::<Map<_, _>>())
+        }
+        Value::Array(items) => {
+            let mut items: Vec<Value> = items
+                .into_iter()
+                .map(|item| normalize(item, ignored, false))
+                .collect();
+            if collection {
+                items.sort_by_cached_key(Value::to_string);
+            }
+            Value::Array(items)
+        }
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PLACE_SCHEMA: &str = r"
+id: https://textpast.org/schema/place/place
+name: place
+generation_date: 2025-03-30T10:41:26
+imports:
+  - linkml:types
+  - txp:meta/entity/hyperentity/schema
+slots:
+  id:
+    identifier: true
+  label:
+    range: string
+  source:
+    range: string
+classes:
+  Place:
+    slots: [id, label, source]
+  Settlement:
+    is_a: Place
+enums:
+  PlaceType:
+    permissible_values:
+      CITY:
+      VILLAGE:
+";
+
+    const REORDERED_PLACE_SCHEMA: &str = r"
+name: place
+id: https://textpast.org/schema/place/place
+generation_date: 2026-01-12T08:00:00
+source_file: place.yaml
+imports:
+  - linkml:types
+  - txp:meta/entity/hyperentity/schema
+enums:
+  PlaceType:
+    permissible_values:
+      VILLAGE:
+      CITY:
+classes:
+  Settlement:
+    is_a: Place
+  Place:
+    slots: [id, label, source]
+slots:
+  source:
+    range: string
+  label:
+    range: string
+  id:
+    identifier: true
+";
+
+    #[test]
+    fn test_reordered_schemas_are_semantically_equal() -> crate::Result<()> {
+        let schema: SchemaDefinition = serde_yaml::from_str(PLACE_SCHEMA)?;
+        let reordered: SchemaDefinition = serde_yaml::from_str(REORDERED_PLACE_SCHEMA)?;
+
+        assert_ne!(schema, reordered);
+        assert!(schema.semantically_equal(&reordered));
+        assert!(reordered.semantically_equal(&schema));
+        // Provenance only counts when it is not ignored
+        assert!(!schema.semantically_equal_ignoring(&reordered, &[]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_content_changes_are_not_equal() -> crate::Result<()> {
+        let schema: SchemaDefinition = serde_yaml::from_str(PLACE_SCHEMA)?;
+
+        // A slot named like a provenance field is still compared
+        let mut changed: SchemaDefinition = serde_yaml::from_str(REORDERED_PLACE_SCHEMA)?;
+        if let Some(source) = changed.slots.get_mut("source") {
+            source.range = Some("uri".to_string());
+        }
+        assert!(!schema.semantically_equal(&changed));
+
+        let mut changed: SchemaDefinition = serde_yaml::from_str(REORDERED_PLACE_SCHEMA)?;
+        changed.enums.shift_remove("PlaceType");
+        assert!(!schema.semantically_equal(&changed));
+
+        // Ordered lists keep their order: slot order decides field order
+        // and import order decides which definition wins
+        let mut changed: SchemaDefinition = serde_yaml::from_str(REORDERED_PLACE_SCHEMA)?;
+        if let Some(place) = changed.classes.get_mut("Place") {
+            place.slots.reverse();
+        }
+        assert!(!schema.semantically_equal(&changed));
+
+        let mut changed: SchemaDefinition = serde_yaml::from_str(REORDERED_PLACE_SCHEMA)?;
+        changed.imports.reverse();
+        assert!(!schema.semantically_equal(&changed));
+        Ok(())
+    }
+}
@@ -16,6 +16,31 @@ pub enum LinkMLError {
         location: Option<String>,
     },
 
+    /// A key that is not a field of the schema element it appears in
+    #[error("Unknown field '{field}' in {context}")]
+    UnknownField {
+        /// Key that was not recognized
+        field: String,
+        /// Element containing the key, such as `class 'Person'`
+        context: String,
+    },
+
+    /// An element defined more than once under the same name
+    #[error("{kind} '{name}' already defined")]
+    DuplicateDefinition {
+        /// Kind of element, such as `Class` or `Slot`
+        kind: String,
+        /// Name of the element
+        name: String,
+    },
+
+    /// A reference to a prefix that is not declared
+    #[error("Unknown prefix: {prefix}")]
+    InvalidPrefix {
+        /// Prefix that was referenced
+        prefix: String,
+    },
+
     /// Schema validation errors
     #[error("Schema validation failed: {message}")]
     SchemaValidationError {
@@ -122,6 +147,32 @@ impl LinkMLError {
         }
     }
 
+    /// Create an unknown field error
+    #[must_use]
+    pub fn unknown_field(field: impl Into<String>, context: impl Into<String>) -> Self {
+        Self::UnknownField {
+            field: field.into(),
+            context: context.into(),
+        }
+    }
+
+    /// Create a duplicate definition error
+    #[must_use]
+    pub fn duplicate_definition(kind: impl Into<String>, name: impl Into<String>) -> Self {
+        Self::DuplicateDefinition {
+            kind: kind.into(),
+            name: name.into(),
+        }
+    }
+
+    /// Create an invalid prefix error
+    #[must_use]
+    pub fn invalid_prefix(prefix: impl Into<String>) -> Self {
+        Self::InvalidPrefix {
+            prefix: prefix.into(),
+        }
+    }
+
     /// Create a new schema validation error
     #[must_use]
     pub fn schema_validation(message: impl Into<String>) -> Self {
@@ -320,6 +371,23 @@ mod tests {
         assert!(display.contains("File not found"));
     }
 
+    #[test]
+    fn test_schema_mistake_variants() {
+        let err = LinkMLError::unknown_field("colour", "class 'Person'");
+        assert!(matches!(err, LinkMLError::UnknownField { ref field, .. } if field == "colour"));
+        assert_eq!(err.to_string(), "Unknown field 'colour' in class 'Person'");
+
+        let err = LinkMLError::duplicate_definition("Class", "Person");
+        assert!(
+            matches!(err, LinkMLError::DuplicateDefinition { ref kind, .. } if kind == "Class")
+        );
+        assert_eq!(err.to_string(), "Class 'Person' already defined");
+
+        let err = LinkMLError::invalid_prefix("schema");
+        assert!(matches!(err, LinkMLError::InvalidPrefix { ref prefix } if prefix == "schema"));
+        assert_eq!(err.to_string(), "Unknown prefix: schema");
+    }
+
     #[test]
     fn test_error_conversions() {
         let json_err = serde_json::from_str::<serde_json::Value>("invalid").unwrap_err();
@@ -68,13 +68,16 @@
 //! - [`error`]: Error types and error handling utilities
 //! - [`traits`]: Core trait definitions for `LinkML` services
 //! - [`types`]: `LinkML` schema type definitions
+//! - [`metrics`]: Structural metrics of schemas
 //! - [`config`]: Configuration types for `LinkML` services
 //! - [`validation`]: Validation types and utilities
 //! - [`utils`]: Utility functions and helpers
 //!
 //! ## Feature Flags
 //!
-//! This crate does not currently define any feature flags.
+//! - `fast_parse`: adds `LinkMLParser::parse_schema_fast`, which parses a
+//!   schema without looking up the line and column of each node, for
+//!   callers that only need the resulting definitions
 //!
 //! ## License
 //!
@@ -93,6 +96,21 @@ pub mod traits;
 /// Type definitions for `LinkML` schemas and data
 pub mod types;
 
+/// Structural metrics of `LinkML` schemas
+pub mod metrics;
+
+/// Order-insensitive comparison of `LinkML` schemas
+pub mod equivalence;
+
+/// Validation of schemas against the `LinkML` metamodel
+pub mod metamodel;
+
+/// Derivation of the classes that own and use each slot
+pub mod ownership;
+
+/// Traversal of schema elements with visitors
+pub mod visitor;
+
 /// Configuration types for `LinkML` services
 pub mod config;
 
@@ -138,10 +156,14 @@ pub mod ast;
 /// Pest-based LinkML parser implementation
 pub mod parser;
 
+/// Conversion between the Pest AST and the schema definition types
+mod ast_convert;
+
 // Re-export commonly used types
 pub use config::LinkMLConfig;
 pub use configuration_v2::LinkMLServiceConfig;
 pub use error::{LinkMLError, Result};
+pub use metrics::SchemaMetrics;
 pub use serde_json::Value;
 pub use settings::SchemaSettings;
 pub use traits::{LinkMLService, SchemaFormat, SchemaOperations, ValidationOperations};
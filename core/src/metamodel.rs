@@ -0,0 +1,366 @@
+//! Validation of schemas against the `LinkML` metamodel
+//!
+//! The schema parser ignores facets it does not know, so a misspelled facet
+//! such as `requird: true` on a slot silently has no effect. The self-check
+//! here treats the metamodel in `schemas/meta.yaml` as the schema and the
+//! user's schema as instance data, and reports every facet the metamodel
+//! does not define for the element it appears on.
+
+use crate::error::Result;
+use crate::types::{SchemaDefinition, Severity, ValidationError, ValidationReport};
+use serde_json::{Map, Value};
+use std::collections::HashSet;
+
+/// The `LinkML` metamodel schema
+const METAMODEL_YAML: &str = include_str!("../../schemas/meta.yaml");
+
+/// Metamodel class of the elements in each named collection of a schema
+const SCHEMA_COLLECTIONS: &[(&str, &str)] = &[
+    ("classes", "ClassDefinition"),
+    ("slots", "SlotDefinition"),
+    ("types", "TypeDefinition"),
+    ("enums", "EnumDefinition"),
+    ("subsets", "SubsetDefinition"),
+];
+
+/// Metamodel class of the elements in each named collection of a class
+const CLASS_COLLECTIONS: &[(&str, &str)] = &[
+    ("attributes", "SlotDefinition"),
+    ("slot_usage", "SlotDefinition"),
+];
+
+impl SchemaDefinition {
+    /// Validate this schema against the `LinkML` metamodel
+    ///
+    /// Facets the parser dropped are no longer part of the schema, so this
+    /// mainly checks schemas built in code. Use
+    /// [`validate_schema_document`] on the raw document to catch misspelled
+    /// facets in a schema file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the schema cannot be serialized or the bundled
+    /// metamodel cannot be loaded.
+    pub fn validate_against_metamodel(&self) -> Result<ValidationReport> {
+        validate_schema_document(&serde_json::to_value(self)?)
+    }
+}
+
+/// Validate a schema document against the `LinkML` metamodel
+///
+/// `document` is the schema as parsed from YAML or JSON, before it is read
+/// into a [`SchemaDefinition`]. The schema, its classes, slots, types, enums
+/// and subsets, and the attributes and slot usages of its classes are each
+/// checked for facets their metamodel class does not define and for missing
+/// required facets. The map key of a named element stands in for its `name`.
+///
+/// # Errors
+///
+/// Returns an error if the bundled metamodel cannot be loaded.
+pub fn validate_schema_document(document: &Value) -> Result<ValidationReport> {
+    let metamodel: SchemaDefinition = serde_yaml::from_str(METAMODEL_YAML)?;
+    let mut errors = Vec::new();
+
+    check_element(
+        &metamodel,
+        "SchemaDefinition",
+        document,
+        None,
+        false,
+        &mut errors,
+    );
+    for (collection, class_name) in SCHEMA_COLLECTIONS {
+        for (name, element) in named_elements(document, collection) {
+            let path = format!("{collection}.{name}");
+            check_element(
+                &metamodel,
+                class_name,
+                element,
+                Some(&path),
+                true,
+                &mut errors,
+            );
+            if *collection != "classes" {
+                continue;
+            }
+            for (nested, nested_class) in CLASS_COLLECTIONS {
+                for (slot_name, slot) in named_elements(element, nested) {
+                    let slot_path = format!("{path}.{nested}.{slot_name}");
+                    check_element(
+                        &metamodel,
+                        nested_class,
+                        slot,
+                        Some(&slot_path),
+                        true,
+                        &mut errors,
+                    );
+                }
+            }
+        }
+    }
+
+    Ok(ValidationReport {
+        valid: errors.is_empty(),
+        errors,
+        warnings: Vec::new(),
+        timestamp: Some(chrono::Utc::now()),
+        schema_id: Some(metamodel.id),
+    })
+}
+
+/// Elements of the named collection `key` of `element`, if it has one
+fn named_elements<'a>(
+    element: &'a Value,
+    key: &str,
+) -> impl Iterator<Item = (&'a String, &'a Value)> {
+    element
+        .get(key)
+        .and_then(Value::as_object)
+        .into_iter()
+        .flat_map(Map::iter)
+}
+
+/// Check the facets of one element against metamodel class `class_name`
+///
+/// `named` marks an element whose name is given by its map key. A null
+/// element, such as a class declared without a body, has no facets.
+fn check_element(
+    metamodel: &SchemaDefinition,
+    class_name: &str,
+    element: &Value,
+    path: Option<&str>,
+    named: bool,
+    errors: &mut Vec<ValidationError>,
+) {
+    let empty = Map::new();
+    let object = match element {
+        Value::Object(object) => object,
+        Value::Null if named => &empty,
+        other => {
+            errors.push(metamodel_error(
+                format!("Expected a {class_name} mapping"),
+                path.map(str::to_string),
+                Some(class_name.to_string()),
+                Some(other.to_string()),
+            ));
+            return;
+        }
+    };
+
+    let facets = class_facets(metamodel, class_name);
+    for key in object.keys() {
+        if !facets.contains(key.as_str()) {
+            errors.push(metamodel_error(
+                format!("Unknown facet '{key}' for {class_name}"),
+                Some(join_path(path, key)),
+                None,
+                Some(key.clone()),
+            ));
+        }
+    }
+
+    for facet in &facets {
+        let required = metamodel
+            .slots
+            .get(*facet)
+            .and_then(|slot| slot.required)
+            .unwrap_or(false);
+        let implied = named && *facet == "name";
+        if required && !implied && object.get(*facet).is_none_or(Value::is_null) {
+            errors.push(metamodel_error(
+                format!("Missing required facet '{facet}' for {class_name}"),
+                Some(join_path(path, facet)),
+                Some((*facet).to_string()),
+                None,
+            ));
+        }
+    }
+}
+
+/// Facets a metamodel class allows, including those of its ancestors and
+/// mixins
+///
+/// A facet is a slot the class lists that the metamodel also defines.
+fn class_facets<'a>(metamodel: &'a SchemaDefinition, class_name: &str) -> HashSet<&'a str> {
+    let mut facets = HashSet::new();
+    let mut visited = HashSet::new();
+    let mut pending = vec![class_name.to_string()];
+    while let Some(name) = pending.pop() {
+        let Some(class) = metamodel.classes.get(&name) else {
+            continue;
+        };
+        if !visited.insert(name) {
+            continue;
+        }
+        facets.extend(
+            class
+                .slots
+                .iter()
+                .map(String::as_str)
+                .filter(|slot| metamodel.slots.contains_key(*slot)),
+        );
+        facets.extend(class.attributes.keys().map(String::as_str));
+        pending.extend(class.is_a.iter().cloned());
+        pending.extend(class.mixins.iter().cloned());
+    }
+    facets
+}
+
+/// Path of `key` below `path`
+fn join_path(path: Option<&str>, key: &str) -> String {
+    match path {
+        Some(path) => format!("{path}.{key}"),
+        None => key.to_string(),
+    }
+}
+
+/// Error reported by the metamodel check
+fn metamodel_error(
+    message: String,
+    path: Option<String>,
+    expected: Option<String>,
+    actual: Option<String>,
+) -> ValidationError {
+    ValidationError {
+        message,
+        path,
+        expected,
+        actual,
+        severity: Severity::Error,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PLACE_SCHEMA: &str = r"
+id: https://textpast.org/schema/place/place
+name: place
+imports:
+  - linkml:types
+slots:
+  id:
+    identifier: true
+  label:
+    range: string
+    required: true
+classes:
+  Place:
+    slots: [id, label]
+    attributes:
+      population:
+        range: integer
+        minimum_value: 0
+  Settlement:
+    is_a: Place
+enums:
+  PlaceType:
+    permissible_values:
+      CITY:
+      VILLAGE:
+";
+
+    #[test]
+    fn test_valid_schema_passes_metamodel_check() -> crate::Result<()> {
+        let document: Value = serde_yaml::from_str(PLACE_SCHEMA)?;
+        let report = validate_schema_document(&document)?;
+        assert!(report.valid, "unexpected errors: {:?}", report.errors);
+
+        let schema: SchemaDefinition = serde_yaml::from_str(PLACE_SCHEMA)?;
+        let report = schema.validate_against_metamodel()?;
+        assert!(report.valid, "unexpected errors: {:?}", report.errors);
+        assert_eq!(
+            report.schema_id.as_deref(),
+            Some("https://w3id.org/linkml/meta")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_bogus_facets_are_flagged() -> crate::Result<()> {
+        let document = PLACE_SCHEMA
+            .replace("    required: true", "    requird: true")
+            .replace("  Settlement:\n", "  Settlement:\n    slotz: [label]\n")
+            .replace("name: place\n", "name: place\nauthor: someone\n");
+        let document: Value = serde_yaml::from_str(&document)?;
+        let report = validate_schema_document(&document)?;
+
+        assert!(!report.valid);
+        let mut paths: Vec<_> = report
+            .errors
+            .iter()
+            .filter_map(|error| error.path.as_deref())
+            .collect();
+        paths.sort_unstable();
+        assert_eq!(
+            paths,
+            ["author", "classes.Settlement.slotz", "slots.label.requird"]
+        );
+        assert!(
+            report
+                .errors
+                .iter()
+                .any(|error| error.message == "Unknown facet 'requird' for SlotDefinition")
+        );
+
+        let unnamed = serde_json::json!({"id": "https://example.org/unnamed"});
+        let report = validate_schema_document(&unnamed)?;
+        assert_eq!(report.errors.len(), 1);
+        assert_eq!(report.errors[0].path.as_deref(), Some("name"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_metamodel_defines_every_class_slot() -> crate::Result<()> {
+        let metamodel: SchemaDefinition = serde_yaml::from_str(METAMODEL_YAML)?;
+        for class in metamodel.classes.values() {
+            for slot in &class.slots {
+                assert!(
+                    metamodel.slots.contains_key(slot),
+                    "{} lists undefined slot '{slot}'",
+                    class.name
+                );
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_parser_facets_are_in_metamodel() -> crate::Result<()> {
+        use crate::parser::{CLASS_FIELDS, ENUM_FIELDS, SCHEMA_FIELDS, SLOT_FIELDS, TYPE_FIELDS};
+
+        let metamodel: SchemaDefinition = serde_yaml::from_str(METAMODEL_YAML)?;
+        for (class_name, fields) in [
+            ("SchemaDefinition", SCHEMA_FIELDS),
+            ("ClassDefinition", CLASS_FIELDS),
+            ("SlotDefinition", SLOT_FIELDS),
+            ("TypeDefinition", TYPE_FIELDS),
+            ("EnumDefinition", ENUM_FIELDS),
+        ] {
+            let facets = class_facets(&metamodel, class_name);
+            for field in fields {
+                assert!(facets.contains(field), "{class_name} lacks '{field}'");
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_schema_and_relation_facets_pass_metamodel_check() -> crate::Result<()> {
+        let document = PLACE_SCHEMA
+            .replace(
+                "name: place\n",
+                "name: place\ncreated_on: 2024-01-01T00:00:00Z\nlast_updated_on: 2024-06-01T00:00:00Z\n",
+            )
+            .replace("    is_a: Place\n", "    is_a: Place\n    id_prefixes: [geo]\n")
+            .replace(
+                "    identifier: true\n",
+                "    identifier: true\n  borders:\n    range: Place\n    symmetric: true\n    transitive: false\n",
+            );
+        let document: Value = serde_yaml::from_str(&document)?;
+        let report = validate_schema_document(&document)?;
+        assert!(report.valid, "unexpected errors: {:?}", report.errors);
+        Ok(())
+    }
+}
@@ -0,0 +1,175 @@
+//! Structural metrics of `LinkML` schemas
+//!
+//! [`SchemaMetrics`] summarizes the size and shape of a schema for dashboards
+//! and documentation. It is computed from the schema's own definitions only;
+//! imports are counted but not resolved.
+
+use crate::types::SchemaDefinition;
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashSet};
+
+/// Counts describing the size and shape of a schema
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SchemaMetrics {
+    /// Number of classes
+    pub class_count: usize,
+    /// Number of classes marked `abstract`
+    pub abstract_class_count: usize,
+    /// Number of classes marked `mixin`
+    pub mixin_class_count: usize,
+    /// Number of classes that are neither abstract nor mixins
+    pub concrete_class_count: usize,
+    /// Number of top-level slots
+    pub slot_count: usize,
+    /// Number of classes keyed by how many slots and attributes they declare
+    /// directly
+    pub slots_per_class: BTreeMap<usize, usize>,
+    /// Number of types
+    pub type_count: usize,
+    /// Number of enums
+    pub enum_count: usize,
+    /// Number of permissible values of each enum
+    pub enum_value_counts: IndexMap<String, usize>,
+    /// Number of imports
+    pub import_count: usize,
+    /// Length of the longest `is_a` chain; zero if no class has a parent
+    pub max_inheritance_depth: usize,
+}
+
+impl SchemaDefinition {
+    /// Compute structural metrics of this schema
+    #[must_use]
+    pub fn metrics(&self) -> SchemaMetrics {
+        let mut metrics = SchemaMetrics {
+            class_count: self.classes.len(),
+            slot_count: self.slots.len(),
+            type_count: self.types.len(),
+            enum_count: self.enums.len(),
+            enum_value_counts: self
+                .enums
+                .iter()
+                .map(|(name, enum_def)| (name.clone(), enum_def.permissible_values.len()))
+                .collect(),
+            import_count: self.imports.len(),
+            ..SchemaMetrics::default()
+        };
+
+        for (name, class) in &self.classes {
+            let is_abstract = class.abstract_ == Some(true);
+            let is_mixin = class.mixin == Some(true);
+            metrics.abstract_class_count += usize::from(is_abstract);
+            metrics.mixin_class_count += usize::from(is_mixin);
+            metrics.concrete_class_count += usize::from(!is_abstract && !is_mixin);
+
+            *metrics
+                .slots_per_class
+                .entry(class.slots.len() + class.attributes.len())
+                .or_default() += 1;
+            metrics.max_inheritance_depth = metrics
+                .max_inheritance_depth
+                .max(self.inheritance_depth(name));
+        }
+        metrics
+    }
+
+    /// Number of `is_a` links above a class
+    ///
+    /// The chain ends at a parent defined outside this schema, and at a
+    /// parent already seen so that cycles terminate.
+    fn inheritance_depth(&self, class_name: &str) -> usize {
+        let mut seen = HashSet::from([class_name]);
+        let mut depth = 0;
+        let mut current = self.classes.get(class_name);
+        while let Some(parent) = current.and_then(|class| class.is_a.as_deref()) {
+            if !seen.insert(parent) {
+                break;
+            }
+            depth += 1;
+            current = self.classes.get(parent);
+        }
+        depth
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::ClassDefinition;
+
+    const ENTITY_SCHEMA: &str = r"
+id: https://example.org/entity
+name: entity
+imports:
+  - linkml:types
+slots:
+  id:
+    identifier: true
+  label:
+    range: string
+  created:
+    range: datetime
+  name:
+    range: string
+classes:
+  Thing:
+    abstract: true
+    slots: [id, label]
+  Entity:
+    is_a: Thing
+    slots: [created]
+  Named:
+    mixin: true
+    slots: [name]
+  Person:
+    is_a: Entity
+    mixins: [Named]
+  Place:
+    is_a: Entity
+    attributes:
+      coordinates:
+        range: string
+enums:
+  EntityStatus:
+    permissible_values:
+      ACTIVE:
+      RETIRED:
+";
+
+    #[test]
+    fn test_entity_schema_metrics() -> crate::Result<()> {
+        let schema: SchemaDefinition = serde_yaml::from_str(ENTITY_SCHEMA)?;
+        let metrics = schema.metrics();
+
+        assert_eq!(metrics.class_count, 5);
+        assert_eq!(metrics.abstract_class_count, 1);
+        assert_eq!(metrics.mixin_class_count, 1);
+        assert_eq!(metrics.concrete_class_count, 3);
+        assert_eq!(metrics.slot_count, 4);
+        assert_eq!(
+            metrics.slots_per_class,
+            BTreeMap::from([(0, 1), (1, 3), (2, 1)])
+        );
+        assert_eq!(metrics.enum_count, 1);
+        assert_eq!(metrics.enum_value_counts.get("EntityStatus"), Some(&2));
+        assert_eq!(metrics.import_count, 1);
+        // Person and Place sit below Entity, which sits below Thing
+        assert_eq!(metrics.max_inheritance_depth, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_inheritance_cycles_terminate() {
+        let mut schema = SchemaDefinition::new("cyclic");
+        for (name, parent) in [("A", "B"), ("B", "A")] {
+            schema.classes.insert(
+                name.to_string(),
+                ClassDefinition {
+                    is_a: Some(parent.to_string()),
+                    ..ClassDefinition::new(name)
+                },
+            );
+        }
+        assert_eq!(schema.metrics().max_inheritance_depth, 1);
+    }
+}
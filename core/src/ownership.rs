@@ -0,0 +1,197 @@
+//! Derivation of the classes that own and use each slot
+//!
+//! A slot is declared once at schema level but may be used by many classes,
+//! while generators need to know where a slot belongs: the SQL generator
+//! places a foreign key on the owning table and the Rust generator places a
+//! field in the owning struct. [`SchemaDefinition::populate_owners`] derives
+//! `owner` and `domain_of` from the `slots`, `attributes` and `slot_usage`
+//! of the classes.
+
+use crate::types::{ClassDefinition, SchemaDefinition};
+use indexmap::IndexMap;
+use std::collections::HashSet;
+
+impl SchemaDefinition {
+    /// Set the owner and users of every slot from the classes declaring them
+    ///
+    /// Each schema slot gets `domain_of`, the classes naming it in their
+    /// `slots` or `slot_usage`, and an `owner`: its `domain` if set,
+    /// otherwise the one user that every other user inherits from through
+    /// `is_a` or `mixins`. A slot shared by unrelated classes has no owner.
+    /// Attributes and slot usages are owned by the class they appear in.
+    ///
+    /// The declared `domain` is only read, never written, so the derived
+    /// facets are recomputed from the current classes on each call. The
+    /// import resolver runs the pass once imports are merged, so that slots
+    /// used by imported classes are covered.
+    pub fn populate_owners(&mut self) {
+        let mut users: IndexMap<String, Vec<String>> = IndexMap::new();
+        for (class_name, class) in &mut self.classes {
+            let declared = class
+                .slots
+                .iter()
+                .chain(class.slot_usage.keys())
+                .filter(|name| !class.attributes.contains_key(*name));
+            for slot_name in declared {
+                let slot_users = users.entry(slot_name.clone()).or_default();
+                if !slot_users.contains(class_name) {
+                    slot_users.push(class_name.clone());
+                }
+            }
+
+            for attribute in class.attributes.values_mut() {
+                attribute.owner = Some(class_name.clone());
+                attribute.domain_of = vec![class_name.clone()];
+            }
+            for usage in class.slot_usage.values_mut() {
+                usage.owner = Some(class_name.clone());
+            }
+        }
+
+        for (slot_name, slot) in &mut self.slots {
+            let slot_users = users.shift_remove(slot_name).unwrap_or_default();
+            slot.owner = slot
+                .domain
+                .clone()
+                .or_else(|| common_ancestor(&self.classes, &slot_users));
+            slot.domain_of = slot_users;
+        }
+    }
+}
+
+/// The class in `users` that every class in `users` is or inherits from
+fn common_ancestor(
+    classes: &IndexMap<String, ClassDefinition>,
+    users: &[String],
+) -> Option<String> {
+    users
+        .iter()
+        .find(|candidate| {
+            users
+                .iter()
+                .all(|user| ancestors(classes, user).contains(candidate.as_str()))
+        })
+        .cloned()
+}
+
+/// `class_name` and the classes it inherits from through `is_a` and `mixins`
+fn ancestors<'a>(
+    classes: &'a IndexMap<String, ClassDefinition>,
+    class_name: &'a str,
+) -> HashSet<&'a str> {
+    let mut seen = HashSet::new();
+    let mut pending = vec![class_name];
+    while let Some(name) = pending.pop() {
+        if !seen.insert(name) {
+            continue;
+        }
+        if let Some(class) = classes.get(name) {
+            pending.extend(class.is_a.as_deref());
+            pending.extend(class.mixins.iter().map(String::as_str));
+        }
+    }
+    seen
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTRY_SCHEMA: &str = r"
+id: https://example.org/registry
+name: registry
+slots:
+  id:
+    identifier: true
+    range: string
+  label:
+    range: string
+  members:
+    range: Person
+    multivalued: true
+classes:
+  NamedThing:
+    slots: [id]
+  Person:
+    is_a: NamedThing
+    slots: [id, label]
+    slot_usage:
+      id:
+        pattern: '^P\d+$'
+  Organization:
+    is_a: NamedThing
+    slots: [id, label, members]
+    attributes:
+      founded:
+        range: date
+  Place:
+    slots: [label]
+";
+
+    #[test]
+    fn test_identifier_slot_is_owned_by_declaring_class() -> crate::Result<()> {
+        let mut schema: SchemaDefinition = serde_yaml::from_str(REGISTRY_SCHEMA)?;
+        schema.populate_owners();
+
+        let id = &schema.slots["id"];
+        assert_eq!(id.owner.as_deref(), Some("NamedThing"));
+        assert_eq!(id.domain, None);
+        assert_eq!(id.domain_of, ["NamedThing", "Person", "Organization"]);
+
+        let members = &schema.slots["members"];
+        assert_eq!(members.owner.as_deref(), Some("Organization"));
+
+        // A slot shared by unrelated classes belongs to neither
+        let label = &schema.slots["label"];
+        assert_eq!(label.owner, None);
+        assert_eq!(label.domain, None);
+        assert_eq!(label.domain_of, ["Person", "Organization", "Place"]);
+
+        let organization = &schema.classes["Organization"];
+        assert_eq!(
+            organization.attributes["founded"].owner.as_deref(),
+            Some("Organization")
+        );
+        let person = &schema.classes["Person"];
+        assert_eq!(person.slot_usage["id"].owner.as_deref(), Some("Person"));
+
+        // The pass can be repeated without accumulating users
+        schema.populate_owners();
+        assert_eq!(schema.slots["id"].domain_of.len(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_owners_follow_changed_classes() -> crate::Result<()> {
+        let mut schema: SchemaDefinition = serde_yaml::from_str(REGISTRY_SCHEMA)?;
+        schema.populate_owners();
+        assert_eq!(
+            schema.slots["members"].owner.as_deref(),
+            Some("Organization")
+        );
+
+        // An unrelated class starts using the slot, so it has no single owner
+        let mut team = ClassDefinition::new("Team");
+        team.slots = vec!["members".to_string()];
+        schema.classes.insert("Team".to_string(), team);
+        schema.populate_owners();
+
+        let members = &schema.slots["members"];
+        assert_eq!(members.owner, None);
+        assert_eq!(members.domain, None);
+        assert_eq!(members.domain_of, ["Organization", "Team"]);
+
+        // A declared domain still takes precedence
+        schema
+            .slots
+            .get_mut("members")
+            .expect("members slot")
+            .domain = Some("Organization".to_string());
+        schema.populate_owners();
+        assert_eq!(
+            schema.slots["members"].owner.as_deref(),
+            Some("Organization")
+        );
+        Ok(())
+    }
+}
@@ -10,6 +10,7 @@
 use indexmap::IndexMap;
 use pest::Parser;
 use pest_derive::Parser;
+use std::cell::Cell;
 
 use crate::ast::{SchemaAst, DocumentType, Spanned, Span, Description, ClassAst, SlotAst, TypeAst, EnumAst, PermissibleValueAst, SubsetAst, ContributorAst, AnnotationsAst, AnnotationValueAst};
 use crate::error::{LinkMLError, Result};
@@ -23,6 +24,145 @@ pub struct LinkMLParser;
 /// Type alias for Pest parsing pairs
 type Pair<'i> = pest::iterators::Pair<'i, Rule>;
 
+/// Keys the grammar accepts at the top level of a schema
+pub(crate) const SCHEMA_FIELDS: &[&str] = &[
+    "id",
+    "name",
+    "title",
+    "description",
+    "version",
+    "license",
+    "created_on",
+    "last_updated_on",
+    "default_prefix",
+    "default_range",
+    "prefixes",
+    "imports",
+    "settings",
+    "classes",
+    "slots",
+    "types",
+    "enums",
+    "subsets",
+    "contributors",
+    "status",
+    "categories",
+    "keywords",
+    "see_also",
+    "metamodel_version",
+    "source_file",
+    "generation_date",
+    "annotations",
+];
+
+/// Keys the grammar accepts in a class definition
+pub(crate) const CLASS_FIELDS: &[&str] = &[
+    "description",
+    "is_a",
+    "abstract",
+    "mixin",
+    "mixins",
+    "slots",
+    "slot_usage",
+    "attributes",
+    "class_uri",
+    "subclass_of",
+    "tree_root",
+    "rules",
+    "if_required",
+    "unique_keys",
+    "annotations",
+    "recursion_options",
+    "aliases",
+    "see_also",
+    "id_prefixes",
+    "broad_mappings",
+    "exact_mappings",
+    "narrow_mappings",
+    "related_mappings",
+    "close_mappings",
+];
+
+/// Keys the grammar accepts in a slot, attribute or slot usage definition
+pub(crate) const SLOT_FIELDS: &[&str] = &[
+    "description",
+    "range",
+    "range_type",
+    "range_properties",
+    "required",
+    "multivalued",
+    "identifier",
+    "pattern",
+    "structured_pattern",
+    "minimum_value",
+    "maximum_value",
+    "minimum_cardinality",
+    "maximum_cardinality",
+    "min_length",
+    "max_length",
+    "whitespace",
+    "is_a",
+    "mixins",
+    "slot_uri",
+    "domain",
+    "inverse",
+    "symmetric",
+    "asymmetric",
+    "reflexive",
+    "irreflexive",
+    "locally_reflexive",
+    "transitive",
+    "ifabsent",
+    "annotations",
+    "aliases",
+    "see_also",
+    "equals_string",
+    "equals_number",
+    "imports",
+];
+
+/// Keys the grammar accepts in a type definition
+pub(crate) const TYPE_FIELDS: &[&str] = &[
+    "description",
+    "typeof",
+    "base",
+    "uri",
+    "pattern",
+    "minimum_value",
+    "maximum_value",
+    "min_length",
+    "max_length",
+    "whitespace",
+    "annotations",
+];
+
+/// Keys the grammar accepts in an enum definition
+pub(crate) const ENUM_FIELDS: &[&str] = &[
+    "description",
+    "permissible_values",
+    "code_set",
+    "code_set_tag",
+    "code_set_version",
+    "annotations",
+];
+
+thread_local! {
+    /// Whether parsed nodes record their source span; only cleared while a
+    /// span-free parse runs on this thread
+    static TRACK_SPANS: Cell<bool> = const { Cell::new(true) };
+}
+
+/// Re-enables span tracking when a span-free parse ends, even on panic
+#[cfg(feature = "fast_parse")]
+struct SpanTrackingGuard;
+
+#[cfg(feature = "fast_parse")]
+impl Drop for SpanTrackingGuard {
+    fn drop(&mut self) {
+        TRACK_SPANS.with(|track| track.set(true));
+    }
+}
+
 impl LinkMLParser {
     /// Parse a complete `LinkML` schema from YAML string
     ///
@@ -36,9 +176,13 @@ impl LinkMLParser {
     ///
     /// # Errors
     ///
-    /// Returns `LinkMLError::ParseError` if the input is not valid `LinkML` YAML.
+    /// Returns `LinkMLError::ParseError` if the input is not valid `LinkML` YAML,
+    /// `LinkMLError::UnknownField` if an element has a key it doesn't support,
+    /// `LinkMLError::DuplicateDefinition` if a name is defined twice, and
+    /// `LinkMLError::InvalidPrefix` if `default_prefix` is not declared.
     pub fn parse_schema(input: &str) -> Result<SchemaAst> {
-        let pairs = Self::parse(Rule::schema, input)?;
+        let pairs = Self::parse(Rule::schema, input)
+            .map_err(|error| Self::diagnose_parse_error(input, error))?;
 
         let mut schema = SchemaAst::new();
         schema.document_type = Some(DocumentType::Schema);
@@ -60,11 +204,98 @@ impl LinkMLParser {
             }
         }
 
+        if let Some(default_prefix) = &schema.default_prefix
+            && !schema.prefixes.contains_key(&default_prefix.value)
+        {
+            return Err(LinkMLError::invalid_prefix(&default_prefix.value));
+        }
+
         Ok(schema)
     }
 
+    /// Report a grammar failure on an unrecognized key as
+    /// `LinkMLError::UnknownField`, keeping the Pest error otherwise
+    fn diagnose_parse_error(input: &str, error: pest::error::Error<Rule>) -> LinkMLError {
+        let (pest::error::InputLocation::Pos(pos) | pest::error::InputLocation::Span((pos, _))) =
+            error.location;
+        Self::unknown_field_at(input, pos).unwrap_or_else(|| error.into())
+    }
+
+    /// Find an unknown key on the line containing byte offset `pos`
+    ///
+    /// The element the key belongs to is found by walking up to the less
+    /// indented keys above it. Only schema, class, slot, attribute, type and
+    /// enum fields are checked; keys elsewhere are names chosen by the author.
+    fn unknown_field_at(input: &str, pos: usize) -> Option<LinkMLError> {
+        let pos = pos.min(input.len());
+        let line_start = input[..pos].rfind('\n').map_or(0, |i| i + 1);
+        let line = input[line_start..].lines().next()?;
+        let (mut indent, field) = Self::line_key(line)?;
+
+        let mut ancestors = Vec::new();
+        for above in input[..line_start].lines().rev() {
+            if indent == 0 {
+                break;
+            }
+            if let Some((above_indent, key)) = Self::line_key(above)
+                && above_indent < indent
+            {
+                ancestors.push(key);
+                indent = above_indent;
+            }
+        }
+        ancestors.reverse();
+
+        let (fields, context) = match ancestors.as_slice() {
+            [] => (SCHEMA_FIELDS, "schema".to_string()),
+            ["classes", class] => (CLASS_FIELDS, format!("class '{class}'")),
+            ["classes", class, "attributes" | "slot_usage", slot] => {
+                (SLOT_FIELDS, format!("slot '{slot}' of class '{class}'"))
+            }
+            ["slots", slot] => (SLOT_FIELDS, format!("slot '{slot}'")),
+            ["types", name] => (TYPE_FIELDS, format!("type '{name}'")),
+            ["enums", name] => (ENUM_FIELDS, format!("enum '{name}'")),
+            _ => return None,
+        };
+        (!fields.contains(&field)).then(|| LinkMLError::unknown_field(field, context))
+    }
+
+    /// Split a `key: value` line into its indentation and key
+    fn line_key(line: &str) -> Option<(usize, &str)> {
+        let trimmed = line.trim_start();
+        let (key, _) = trimmed.split_once(':')?;
+        let is_key = key
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && key
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+        is_key.then_some((line.len() - trimmed.len(), key))
+    }
+
+    /// Parse a `LinkML` schema without tracking source spans
+    ///
+    /// Produces the same AST as [`parse_schema`](Self::parse_schema) except
+    /// that every span is zero. Computing a span's line and column scans the
+    /// input up to the node, so skipping them pays off when only the parsed
+    /// definitions matter, such as when generating artifacts in bulk.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LinkMLError::ParseError` if the input is not valid `LinkML` YAML.
+    #[cfg(feature = "fast_parse")]
+    pub fn parse_schema_fast(input: &str) -> Result<SchemaAst> {
+        TRACK_SPANS.with(|track| track.set(false));
+        let _guard = SpanTrackingGuard;
+        Self::parse_schema(input)
+    }
+
     /// Helper function to create a `Spanned<T>` from a Pest pair
     fn create_spanned<T>(pair: &Pair<'_>, value: T) -> Spanned<T> {
+        if !TRACK_SPANS.with(Cell::get) {
+            return Spanned::new(value, Span::new(0, 0, 0, 0));
+        }
         let span_info = pair.as_span();
         let (line, column) = span_info.start_pos().line_col();
         let span = Span::new(
@@ -316,6 +547,9 @@ impl LinkMLParser {
                             Self::process_class_field(&mut class_ast, field)?;
                         }
 
+                        if classes.contains_key(&name) {
+                            return Err(LinkMLError::duplicate_definition("Class", name));
+                        }
                         classes.insert(name, Self::create_spanned(&name_pair, class_ast));
                     }
             }
@@ -431,6 +665,9 @@ impl LinkMLParser {
                             Self::process_slot_field(&mut slot_ast, field)?;
                         }
 
+                        if slots.contains_key(&name) {
+                            return Err(LinkMLError::duplicate_definition("Slot", name));
+                        }
                         slots.insert(name, Self::create_spanned(&name_pair, slot_ast));
                     }
             }
@@ -516,6 +753,19 @@ impl LinkMLParser {
                     }
                 }
             }
+            Rule::slot_min_length => {
+                slot.min_length = Self::parse_integer_field(pair)?;
+            }
+            Rule::slot_max_length => {
+                slot.max_length = Self::parse_integer_field(pair)?;
+            }
+            Rule::slot_whitespace => {
+                for inner in pair.into_inner() {
+                    if inner.as_rule() == Rule::identifier {
+                        slot.whitespace = Some(Self::create_spanned(&inner, inner.as_str().to_string()));
+                    }
+                }
+            }
             Rule::slot_mixins => {
                 slot.mixins = Self::parse_string_list(pair);
             }
@@ -551,6 +801,9 @@ impl LinkMLParser {
                             Self::process_type_field(&mut type_ast, field)?;
                         }
 
+                        if types.contains_key(&name) {
+                            return Err(LinkMLError::duplicate_definition("Type", name));
+                        }
                         types.insert(name, Self::create_spanned(&name_pair, type_ast));
                     }
             }
@@ -597,6 +850,19 @@ impl LinkMLParser {
                     }
                 }
             }
+            Rule::type_min_length => {
+                type_ast.min_length = Self::parse_integer_field(pair)?;
+            }
+            Rule::type_max_length => {
+                type_ast.max_length = Self::parse_integer_field(pair)?;
+            }
+            Rule::type_whitespace => {
+                for inner in pair.into_inner() {
+                    if inner.as_rule() == Rule::identifier {
+                        type_ast.whitespace = Some(Self::create_spanned(&inner, inner.as_str().to_string()));
+                    }
+                }
+            }
             _ => {
                 // Ignore unknown fields
             }
@@ -623,6 +889,9 @@ impl LinkMLParser {
                             Self::process_enum_field(&mut enum_ast, field)?;
                         }
 
+                        if enums.contains_key(&name) {
+                            return Err(LinkMLError::duplicate_definition("Enum", name));
+                        }
                         enums.insert(name, Self::create_spanned(&name_pair, enum_ast));
                     }
             }
@@ -735,6 +1004,20 @@ impl LinkMLParser {
         Ok(annotations)
     }
 
+    /// Parse the value of a `key: <integer>` field
+    fn parse_integer_field(pair: Pair<'_>) -> Result<Option<Spanned<i64>>> {
+        for inner in pair.into_inner() {
+            if inner.as_rule() == Rule::integer {
+                let value = inner
+                    .as_str()
+                    .parse::<i64>()
+                    .map_err(|_| LinkMLError::parse("Invalid integer"))?;
+                return Ok(Some(Self::create_spanned(&inner, value)));
+            }
+        }
+        Ok(None)
+    }
+
     /// Parse annotation value
     fn parse_annotation_value(pair: &Pair<'_>) -> Result<AnnotationValueAst> {
         match pair.as_rule() {
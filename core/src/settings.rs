@@ -73,6 +73,10 @@ pub struct ValidationSettings {
     /// Whether to coerce types when possible
     #[serde(skip_serializing_if = "Option::is_none")]
     pub type_coercion: Option<bool>,
+
+    /// Whether to reject schemas containing references to undefined elements
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strict_references: Option<bool>,
 }
 
 /// Code generation settings
@@ -376,6 +380,12 @@ impl ValidationSettings {
     pub fn allows_additional_properties(&self) -> bool {
         self.allow_additional_properties.unwrap_or(true)
     }
+
+    /// Check if undefined element references should be rejected
+    #[must_use]
+    pub fn checks_references(&self) -> bool {
+        self.strict_references.unwrap_or(false)
+    }
 }
 
 impl GenerationSettings {
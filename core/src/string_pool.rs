@@ -4,14 +4,30 @@
 //! common strings across schema definitions, significantly reducing memory usage.
 
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, RwLock};
 
 /// Global string pool for interning common `LinkML` strings
 static STRING_POOL: std::sync::LazyLock<StringPool> = std::sync::LazyLock::new(StringPool::new);
 
+/// Snapshot of string pool usage
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PoolStats {
+    /// Number of distinct strings held by the pool
+    pub unique_strings: usize,
+    /// Combined length in bytes of the strings held by the pool
+    pub total_bytes: usize,
+    /// Interning requests answered with an already pooled string
+    pub hits: u64,
+    /// Interning requests that added a new string to the pool
+    pub misses: u64,
+}
+
 /// Thread-safe string interning pool
 pub struct StringPool {
     pool: RwLock<HashMap<String, Arc<str>>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
 }
 
 impl Default for StringPool {
@@ -26,6 +42,8 @@ impl StringPool {
     pub fn new() -> Self {
         Self {
             pool: RwLock::new(HashMap::new()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
         }
     }
 
@@ -39,6 +57,7 @@ impl StringPool {
             if let Ok(pool) = pool_result
                 && let Some(interned) = pool.get(s)
             {
+                self.hits.fetch_add(1, Ordering::Relaxed);
                 return Arc::clone(interned);
             }
         }
@@ -57,10 +76,12 @@ impl StringPool {
 
         // Double-check in case another thread interned while we waited
         if let Some(interned) = pool.get(s) {
+            self.hits.fetch_add(1, Ordering::Relaxed);
             return Arc::clone(interned);
         }
 
         // Create new interned string
+        self.misses.fetch_add(1, Ordering::Relaxed);
         let interned: Arc<str> = Arc::from(s);
         pool.insert(s.to_string(), Arc::clone(&interned));
         interned
@@ -72,14 +93,49 @@ impl StringPool {
         self.pool.read().map(|guard| guard.len()).unwrap_or(0)
     }
 
-    /// Clear the pool (mainly for testing)
-    #[cfg(test)]
+    /// Get a snapshot of the pool's contents and hit counters
+    pub fn stats(&self) -> PoolStats {
+        let (unique_strings, total_bytes) = self
+            .pool
+            .read()
+            .map(|guard| (guard.len(), guard.keys().map(String::len).sum()))
+            .unwrap_or_default();
+        PoolStats {
+            unique_strings,
+            total_bytes,
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Drop every pooled string
+    ///
+    /// Only the pool's own references are dropped: `Arc<str>` values handed
+    /// out earlier stay valid, but are no longer shared with strings interned
+    /// afterwards. The hit and miss counters are kept.
     pub fn clear(&self) {
         // If lock is poisoned, recover and clear anyway
-        match self.pool.write() {
-            Ok(mut guard) => guard.clear(),
-            Err(poisoned) => poisoned.into_inner().clear(),
-        }
+        let mut pool = match self.pool.write() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        pool.clear();
+        pool.shrink_to_fit();
+    }
+
+    /// Drop pooled strings that are not referenced outside the pool
+    ///
+    /// Unlike [`clear`](Self::clear) this keeps sharing intact for strings
+    /// still in use. Returns the number of strings dropped.
+    pub fn shrink(&self) -> usize {
+        let mut pool = match self.pool.write() {
+            Ok(guard) => guard,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let before = pool.len();
+        pool.retain(|_, interned| Arc::strong_count(interned) > 1);
+        pool.shrink_to_fit();
+        before - pool.len()
     }
 }
 
@@ -104,6 +160,26 @@ pub fn pool_size() -> usize {
     STRING_POOL.size()
 }
 
+/// Get usage statistics of the global string pool
+pub fn stats() -> PoolStats {
+    STRING_POOL.stats()
+}
+
+/// Drop all strings held by the global string pool
+///
+/// Meant for long-running services that reload schemas. Strings already
+/// handed out stay valid; see [`StringPool::clear`].
+pub fn clear() {
+    STRING_POOL.clear();
+}
+
+/// Drop strings of the global pool that are no longer referenced elsewhere
+///
+/// Returns the number of strings dropped.
+pub fn shrink() -> usize {
+    STRING_POOL.shrink()
+}
+
 /// Types that can be interned
 pub trait Internable {
     /// Return an interned version of self
@@ -151,6 +227,44 @@ mod tests {
         assert_eq!(pool.size(), 2);
     }
 
+    #[test]
+    fn test_stats_count_hits_and_misses() {
+        let pool = StringPool::new();
+
+        let first = pool.intern("entity");
+        let second = pool.intern("entity");
+        pool.intern("name");
+        assert!(Arc::ptr_eq(&first, &second));
+
+        assert_eq!(
+            pool.stats(),
+            PoolStats {
+                unique_strings: 2,
+                total_bytes: 10,
+                hits: 1,
+                misses: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn test_shrink_and_clear() {
+        let pool = StringPool::new();
+        let kept = pool.intern("kept");
+        pool.intern("transient");
+
+        assert_eq!(pool.shrink(), 1);
+        assert_eq!(pool.size(), 1);
+        assert!(Arc::ptr_eq(&kept, &pool.intern("kept")));
+
+        pool.clear();
+        assert_eq!(pool.size(), 0);
+        // Outstanding references stay valid but are no longer shared
+        assert_eq!(&*kept, "kept");
+        assert!(!Arc::ptr_eq(&kept, &pool.intern("kept")));
+        assert_eq!(pool.stats().misses, 3);
+    }
+
     #[test]
     fn test_global_pool() {
         // Clear to ensure clean state
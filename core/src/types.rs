@@ -1,11 +1,12 @@
 //! Core type definitions for LinkML schemas and data
 
 use crate::annotations::{Annotatable, Annotations};
+use crate::error::{LinkMLError, Result};
 use crate::settings::SchemaSettings;
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 /// Schema definition - the root of a `LinkML` schema
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
@@ -248,6 +249,8 @@ pub enum IfAbsentAction {
     SlotName,
     /// Set to the class name plus slot name
     ClassSlotCurie,
+    /// Set to the CURIE of the class (`default_prefix:ClassName`)
+    ClassCurie,
     /// Set to the class name
     ClassName,
     /// Generate a unique identifier
@@ -291,6 +294,7 @@ impl<'de> serde::Deserialize<'de> for IfAbsentAction {
                 match value {
                     "slot_name" => Ok(IfAbsentAction::SlotName),
                     "class_slot_curie" => Ok(IfAbsentAction::ClassSlotCurie),
+                    "class_curie" => Ok(IfAbsentAction::ClassCurie),
                     "class_name" => Ok(IfAbsentAction::ClassName),
                     "bnode" => Ok(IfAbsentAction::Bnode),
                     "default_value" => Ok(IfAbsentAction::DefaultValue),
@@ -308,6 +312,30 @@ impl<'de> serde::Deserialize<'de> for IfAbsentAction {
                 Ok(IfAbsentAction::Int(value))
             }
 
+            fn visit_u64<E>(self, value: u64) -> std::result::Result<IfAbsentAction, E>
+            where
+                E: de::Error,
+            {
+                i64::try_from(value)
+                    .map(IfAbsentAction::Int)
+                    .map_err(|_| E::custom(format!("ifabsent integer {value} is out of range")))
+            }
+
+            // Booleans and floats are kept as literals and read against the slot's range
+            fn visit_bool<E>(self, value: bool) -> std::result::Result<IfAbsentAction, E>
+            where
+                E: de::Error,
+            {
+                Ok(IfAbsentAction::String(value.to_string()))
+            }
+
+            fn visit_f64<E>(self, value: f64) -> std::result::Result<IfAbsentAction, E>
+            where
+                E: de::Error,
+            {
+                Ok(IfAbsentAction::String(value.to_string()))
+            }
+
             fn visit_map<M>(self, map: M) -> std::result::Result<IfAbsentAction, M::Error>
             where
                 M: de::MapAccess<'de>,
@@ -318,6 +346,7 @@ impl<'de> serde::Deserialize<'de> for IfAbsentAction {
                 enum Tagged {
                     SlotName,
                     ClassSlotCurie,
+                    ClassCurie,
                     ClassName,
                     Bnode,
                     DefaultValue,
@@ -332,6 +361,7 @@ impl<'de> serde::Deserialize<'de> for IfAbsentAction {
                 Ok(match tagged {
                     Tagged::SlotName => IfAbsentAction::SlotName,
                     Tagged::ClassSlotCurie => IfAbsentAction::ClassSlotCurie,
+                    Tagged::ClassCurie => IfAbsentAction::ClassCurie,
                     Tagged::ClassName => IfAbsentAction::ClassName,
                     Tagged::Bnode => IfAbsentAction::Bnode,
                     Tagged::DefaultValue => IfAbsentAction::DefaultValue,
@@ -348,6 +378,64 @@ impl<'de> serde::Deserialize<'de> for IfAbsentAction {
     }
 }
 
+/// Whitespace handling applied to string values before length checks
+///
+/// Mirrors the XSD `whiteSpace` facet.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum WhitespaceFacet {
+    /// Keep the value as given
+    Preserve,
+    /// Replace each tab, line feed and carriage return with a space
+    Replace,
+    /// Replace, then collapse runs of spaces and trim both ends
+    Collapse,
+}
+
+impl WhitespaceFacet {
+    /// Name of the facet value as written in a schema
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Preserve => "preserve",
+            Self::Replace => "replace",
+            Self::Collapse => "collapse",
+        }
+    }
+
+    /// Normalize `text` according to the facet
+    #[must_use]
+    pub fn normalize(self, text: &str) -> std::borrow::Cow<'_, str> {
+        let is_replaced = |c: char| matches!(c, '\t' | '\n' | '\r');
+        match self {
+            Self::Preserve => text.into(),
+            Self::Replace if text.contains(is_replaced) => text.replace(is_replaced, " ").into(),
+            Self::Replace => text.into(),
+            Self::Collapse => text
+                .split(|c: char| c == ' ' || is_replaced(c))
+                .filter(|word| !word.is_empty())
+                .collect::<Vec<_>>()
+                .join(" ")
+                .into(),
+        }
+    }
+}
+
+impl std::str::FromStr for WhitespaceFacet {
+    type Err = LinkMLError;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "preserve" => Ok(Self::Preserve),
+            "replace" => Ok(Self::Replace),
+            "collapse" => Ok(Self::Collapse),
+            other => Err(LinkMLError::parse(format!(
+                "unknown whitespace facet '{other}', expected preserve, replace or collapse"
+            ))),
+        }
+    }
+}
+
 /// Slot definition
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 pub struct SlotDefinition {
@@ -367,6 +455,10 @@ pub struct SlotDefinition {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub required: Option<bool>,
 
+    /// Should this slot be present? Missing values are reported as warnings
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recommended: Option<bool>,
+
     /// Is this slot multivalued?
     #[serde(skip_serializing_if = "Option::is_none")]
     pub multivalued: Option<bool>,
@@ -375,6 +467,10 @@ pub struct SlotDefinition {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub identifier: Option<bool>,
 
+    /// Does this slot name the concrete class of its instance?
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub designates_type: Option<bool>,
+
     /// Is this slot a key (unique within its container)?
     #[serde(skip_serializing_if = "Option::is_none")]
     pub key: Option<bool>,
@@ -403,6 +499,18 @@ pub struct SlotDefinition {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_length: Option<usize>,
 
+    /// Whitespace handling before the length check
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub whitespace: Option<WhitespaceFacet>,
+
+    /// Minimum number of values for a multivalued slot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum_cardinality: Option<i32>,
+
+    /// Maximum number of values for a multivalued slot
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maximum_cardinality: Option<i32>,
+
     /// Permissible values
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub permissible_values: Vec<PermissibleValue>,
@@ -424,6 +532,16 @@ pub struct SlotDefinition {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub domain: Option<String>,
 
+    /// Class that declares this slot, derived by
+    /// [`SchemaDefinition::populate_owners`]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+
+    /// Classes that use this slot, derived by
+    /// [`SchemaDefinition::populate_owners`]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub domain_of: Vec<String>,
+
     /// Is inherited (`is_a`)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub is_a: Option<String>,
@@ -468,6 +586,14 @@ pub struct SlotDefinition {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub equals_expression: Option<String>,
 
+    /// Exact string the slot value must equal (`equals_string` constraint)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub equals_string: Option<String>,
+
+    /// Number the slot value must equal (`equals_number` constraint)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub equals_number: Option<f64>,
+
     /// Expression that must evaluate to true for validation
     #[serde(skip_serializing_if = "Option::is_none")]
     pub rules: Option<Vec<String>>,
@@ -529,12 +655,12 @@ pub struct SlotDefinition {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub rank: Option<i32>,
 
-    /// Whether values in this slot must be unique
-    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Whether values in this slot must be unique (`list_elements_unique`)
+    #[serde(alias = "list_elements_unique", skip_serializing_if = "Option::is_none")]
     pub unique: Option<bool>,
 
-    /// Whether values in this slot are ordered
-    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Whether values in this slot are ordered (`list_elements_ordered`)
+    #[serde(alias = "list_elements_ordered", skip_serializing_if = "Option::is_none")]
     pub ordered: Option<bool>,
 
     /// Unique key constraints for this slot
@@ -614,6 +740,18 @@ pub struct TypeDefinition {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub maximum_value: Option<Value>,
 
+    /// Minimum length for string values
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<usize>,
+
+    /// Maximum length for string values
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<usize>,
+
+    /// Whitespace handling before the length check
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub whitespace: Option<WhitespaceFacet>,
+
     /// Annotations for the type
     #[serde(skip_serializing_if = "Option::is_none")]
     pub annotations: Option<Annotations>,
@@ -650,6 +788,11 @@ pub struct EnumDefinition {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub code_set_version: Option<String>,
 
+    /// Formula used to derive permissible values from the code set
+    /// (`CODE`, `CURIE`, `URI` or `LABEL`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pv_formula: Option<String>,
+
     /// Annotations for the enum
     #[serde(skip_serializing_if = "Option::is_none")]
     pub annotations: Option<Annotations>,
@@ -941,6 +1084,10 @@ pub struct SlotCondition {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub required: Option<bool>,
 
+    /// Whether the slot must have a value or must be absent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value_presence: Option<PresenceEnum>,
+
     /// Pattern constraint
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pattern: Option<String>,
@@ -982,6 +1129,18 @@ pub struct SlotCondition {
     pub none_of: Option<Vec<AnonymousSlotExpression>>,
 }
 
+/// Presence requirement for a slot in a rule condition
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PresenceEnum {
+    /// No requirement either way
+    Uncommitted,
+    /// The slot must have a value
+    Present,
+    /// The slot must not have a value
+    Absent,
+}
+
 /// Composite conditions for boolean logic
 #[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
 pub struct CompositeConditions {
@@ -1030,6 +1189,21 @@ pub struct UniqueKeyDefinition {
     pub consider_nulls_inequal: Option<bool>,
 }
 
+/// Slot of a class whose values are checked against an instance file
+///
+/// Returned by [`SchemaDefinition::instance_backed_slots`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InstanceBackedSlot<'a> {
+    /// Class the slot belongs to
+    pub class_name: &'a str,
+    /// Name of the slot
+    pub slot_name: &'a str,
+    /// Range class whose instances are the permissible values
+    pub range: &'a str,
+    /// Instance fields holding the permissible values; empty means `id`
+    pub range_properties: &'a [String],
+}
+
 impl SchemaDefinition {
     /// Create a new schema definition with the given name
     pub fn new(name: impl Into<String>) -> Self {
@@ -1040,6 +1214,161 @@ impl SchemaDefinition {
             ..Default::default()
         }
     }
+
+    /// Expand a CURIE with this schema's prefixes
+    ///
+    /// A name without a prefix is expanded with `default_prefix`, and a full
+    /// URI is returned unchanged. Returns `None` if the prefix is not declared.
+    #[must_use]
+    pub fn expand_curie(&self, curie: &str) -> Option<String> {
+        let Some((prefix, local)) = curie.split_once(':') else {
+            let default = self.default_prefix.as_deref()?;
+            let expansion = self.prefix_expansion(default).or_else(|| {
+                // `default_prefix` may itself be a namespace URI
+                default.contains("://").then_some(default)
+            })?;
+            return Some(format!("{expansion}{curie}"));
+        };
+        if local.starts_with("//") {
+            return Some(curie.to_string());
+        }
+        self.prefix_expansion(prefix)
+            .map(|expansion| format!("{expansion}{local}"))
+    }
+
+    /// Contract a URI to a CURIE with this schema's prefixes
+    ///
+    /// The longest matching namespace wins, so `txp:meta/entity` is preferred
+    /// over `txp_root:schema/meta/entity` when both match; between prefixes
+    /// sharing a namespace, `default_prefix` wins. Returns `None` if no
+    /// declared prefix matches.
+    #[must_use]
+    pub fn contract_uri(&self, uri: &str) -> Option<String> {
+        self.prefixes
+            .keys()
+            .filter_map(|prefix| {
+                let expansion = self.prefix_expansion(prefix)?;
+                let local = uri.strip_prefix(expansion)?;
+                let is_default = self.default_prefix.as_deref() == Some(prefix.as_str());
+                Some(((expansion.len(), is_default), prefix, local))
+            })
+            .max_by_key(|&(rank, _, _)| rank)
+            .map(|(_, prefix, local)| format!("{prefix}:{local}"))
+    }
+
+    /// Name of the class declared with `tree_root: true`
+    ///
+    /// The tree root is the top of the containment tree, i.e. the class of a
+    /// whole data document. Returns `None` if no class is flagged.
+    ///
+    /// # Errors
+    ///
+    /// Returns `LinkMLError::SchemaValidationError` if more than one class is
+    /// flagged as the tree root.
+    pub fn tree_root_class(&self) -> Result<Option<&str>> {
+        let mut roots = self
+            .classes
+            .iter()
+            .filter(|(_, class)| class.tree_root == Some(true))
+            .map(|(name, _)| name.as_str());
+        let Some(root) = roots.next() else {
+            return Ok(None);
+        };
+        if let Some(other) = roots.next() {
+            return Err(LinkMLError::SchemaValidationError {
+                message: format!("Classes '{root}' and '{other}' are both declared tree_root"),
+                element: Some(other.to_string()),
+            });
+        }
+        Ok(Some(root))
+    }
+
+    /// Names along the `typeof` chain of `range`
+    ///
+    /// Starts with `range` itself and follows the `typeof` of each schema
+    /// type, ending at a name that is not a type of this schema, normally a
+    /// built-in type. A circular chain ends before its first repeated type.
+    pub fn type_chain<'a>(&'a self, range: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+        let mut seen = HashSet::from([range]);
+        let mut next = Some(range);
+        std::iter::from_fn(move || {
+            let current = next.take()?;
+            next = self
+                .types
+                .get(current)
+                .and_then(|type_def| type_def.base_type.as_deref())
+                .filter(|base| seen.insert(*base));
+            Some(current)
+        })
+    }
+
+    /// Type that `range` derives from through `typeof`, e.g. `string` for a
+    /// schema type declared `typeof: string`
+    ///
+    /// Ranges that are not schema types are returned unchanged.
+    #[must_use]
+    pub fn base_type<'a>(&'a self, range: &'a str) -> &'a str {
+        self.type_chain(range).last().unwrap_or(range)
+    }
+
+    /// Slots with `range_type: instance`, per class
+    ///
+    /// Covers each class's slots, attributes and `slot_usage`; a `slot_usage`
+    /// entry overrides the `range_type`, `range` and `range_properties` of the
+    /// slot it refines. Slots without a range have no instance file to load
+    /// and are left out.
+    #[must_use]
+    pub fn instance_backed_slots(&self) -> Vec<InstanceBackedSlot<'_>> {
+        let mut found = Vec::new();
+        for (class_name, class) in &self.classes {
+            let mut slot_names: Vec<&str> = Vec::new();
+            let declared = class
+                .slots
+                .iter()
+                .chain(class.attributes.keys())
+                .chain(class.slot_usage.keys());
+            for slot_name in declared {
+                if !slot_names.contains(&slot_name.as_str()) {
+                    slot_names.push(slot_name);
+                }
+            }
+
+            for slot_name in slot_names {
+                let usage = class.slot_usage.get(slot_name);
+                let base = class
+                    .attributes
+                    .get(slot_name)
+                    .or_else(|| self.slots.get(slot_name));
+                let layers = || usage.into_iter().chain(base);
+
+                let range_type = layers().find_map(|slot| slot.range_type.as_deref());
+                let range = layers().find_map(|slot| slot.range.as_deref());
+                if let (Some("instance"), Some(range)) = (range_type, range) {
+                    found.push(InstanceBackedSlot {
+                        class_name,
+                        slot_name,
+                        range,
+                        range_properties: layers()
+                            .map(|slot| slot.range_properties.as_slice())
+                            .find(|properties| !properties.is_empty())
+                            .unwrap_or_default(),
+                    });
+                }
+            }
+        }
+        found
+    }
+
+    /// Namespace URI declared for a prefix
+    fn prefix_expansion(&self, prefix: &str) -> Option<&str> {
+        match self.prefixes.get(prefix)? {
+            PrefixDefinition::Simple(expansion)
+            | PrefixDefinition::Complex {
+                prefix_prefix: expansion,
+                ..
+            } => Some(expansion),
+        }
+    }
 }
 
 impl Annotatable for SchemaDefinition {
@@ -1112,6 +1441,31 @@ impl Annotatable for EnumDefinition {
     }
 }
 
+impl EnumDefinition {
+    /// Expanded `meaning` URI of the permissible value that `value` denotes
+    ///
+    /// `value` may be the permissible value's text, its `meaning` CURIE or
+    /// the expanded URI. Returns `None` if no permissible value with a
+    /// `meaning` matches.
+    #[must_use]
+    pub fn resolve_meaning(&self, value: &str, schema: &SchemaDefinition) -> Option<String> {
+        self.permissible_values.iter().find_map(|pv| {
+            let PermissibleValue::Complex {
+                text,
+                meaning: Some(meaning),
+                ..
+            } = pv
+            else {
+                return None;
+            };
+            let uri = schema
+                .expand_curie(meaning)
+                .unwrap_or_else(|| meaning.clone());
+            (text == value || meaning == value || uri == value).then_some(uri)
+        })
+    }
+}
+
 /// Custom deserializer for `permissible_values` that handles both map and sequence formats
 fn deserialize_permissible_values<'de, D>(
     deserializer: D,
@@ -1204,4 +1558,201 @@ mod tests {
         assert!(json.contains("description"));
         Ok(())
     }
+    #[test]
+    fn test_resolve_meaning() {
+        let mut schema = SchemaDefinition::new("status");
+        schema.prefixes.insert(
+            "schema".to_string(),
+            PrefixDefinition::Simple("http://schema.org/".to_string()),
+        );
+        let status = EnumDefinition {
+            name: "Status".to_string(),
+            permissible_values: vec![
+                PermissibleValue::Complex {
+                    text: "ACTIVE".to_string(),
+                    description: None,
+                    meaning: Some("schema:Active".to_string()),
+                },
+                PermissibleValue::Simple("UNKNOWN".to_string()),
+            ],
+            ..Default::default()
+        };
+
+        let uri = Some("http://schema.org/Active".to_string());
+        assert_eq!(status.resolve_meaning("ACTIVE", &schema), uri);
+        assert_eq!(status.resolve_meaning("schema:Active", &schema), uri);
+        assert_eq!(
+            status.resolve_meaning("http://schema.org/Active", &schema),
+            uri
+        );
+        assert_eq!(status.resolve_meaning("UNKNOWN", &schema), None);
+        assert_eq!(
+            status.resolve_meaning("http://schema.org/Inactive", &schema),
+            None
+        );
+    }
+
+    #[test]
+    fn test_expand_and_contract_curies() {
+        let mut schema = SchemaDefinition::new("hyperentity");
+        schema.prefixes.insert(
+            "txp".to_string(),
+            PrefixDefinition::Simple("https://textpast.org/schema/".to_string()),
+        );
+        schema.prefixes.insert(
+            "txp_root".to_string(),
+            PrefixDefinition::Complex {
+                prefix_prefix: "https://textpast.org/".to_string(),
+                prefix_reference: None,
+            },
+        );
+        schema.default_prefix = Some("txp".to_string());
+
+        let uri = "https://textpast.org/schema/meta/entity/hyperentity";
+        assert_eq!(
+            schema
+                .expand_curie("txp:meta/entity/hyperentity")
+                .as_deref(),
+            Some(uri)
+        );
+        assert_eq!(
+            schema.expand_curie("HyperEntity").as_deref(),
+            Some("https://textpast.org/schema/HyperEntity")
+        );
+        assert_eq!(schema.expand_curie(uri).as_deref(), Some(uri));
+        assert_eq!(schema.expand_curie("ex:Thing"), None);
+
+        let curie = schema.contract_uri(uri).expect("uri should contract");
+        assert_eq!(curie, "txp:meta/entity/hyperentity");
+        assert_eq!(schema.expand_curie(&curie).as_deref(), Some(uri));
+        assert_eq!(
+            schema.contract_uri("https://textpast.org/about").as_deref(),
+            Some("txp_root:about")
+        );
+        assert_eq!(schema.contract_uri("http://schema.org/Thing"), None);
+    }
+
+    #[test]
+    fn test_tree_root_class() -> crate::Result<()> {
+        let mut schema = SchemaDefinition::new("registry");
+        schema
+            .classes
+            .insert("Country".to_string(), ClassDefinition::new("Country"));
+        assert_eq!(schema.tree_root_class()?, None);
+
+        schema.classes.insert(
+            "Registry".to_string(),
+            ClassDefinition {
+                tree_root: Some(true),
+                ..ClassDefinition::new("Registry")
+            },
+        );
+        assert_eq!(schema.tree_root_class()?, Some("Registry"));
+
+        schema.classes.insert(
+            "Archive".to_string(),
+            ClassDefinition {
+                tree_root: Some(true),
+                ..ClassDefinition::new("Archive")
+            },
+        );
+        assert!(matches!(
+            schema.tree_root_class(),
+            Err(LinkMLError::SchemaValidationError { element: Some(ref element), .. })
+                if element == "Archive"
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_whitespace_facet_normalizes() -> crate::Result<()> {
+        let text = " Kingdom of\tthe \n Netherlands ";
+        assert_eq!(WhitespaceFacet::Preserve.normalize(text), text);
+        assert_eq!(
+            WhitespaceFacet::Replace.normalize(text),
+            " Kingdom of the   Netherlands "
+        );
+        assert_eq!(
+            WhitespaceFacet::Collapse.normalize(text),
+            "Kingdom of the Netherlands"
+        );
+
+        let facet: WhitespaceFacet = serde_yaml::from_str("collapse")?;
+        assert_eq!(facet, "collapse".parse()?);
+        assert!("squash".parse::<WhitespaceFacet>().is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_base_type_follows_typeof() {
+        let mut schema = SchemaDefinition::new("codes");
+        for (name, base) in [
+            ("country_code", Some("code")),
+            ("code", Some("string")),
+            ("left", Some("right")),
+            ("right", Some("left")),
+            ("string", None),
+        ] {
+            schema.types.insert(
+                name.to_string(),
+                TypeDefinition {
+                    name: name.to_string(),
+                    base_type: base.map(str::to_string),
+                    ..TypeDefinition::default()
+                },
+            );
+        }
+
+        assert_eq!(
+            schema.type_chain("country_code").collect::<Vec<_>>(),
+            ["country_code", "code", "string"]
+        );
+        assert_eq!(schema.base_type("country_code"), "string");
+        assert_eq!(schema.base_type("integer"), "integer");
+        assert_eq!(schema.base_type("Country"), "Country");
+        // A circular `typeof` ends instead of looping
+        assert_eq!(
+            schema.type_chain("left").collect::<Vec<_>>(),
+            ["left", "right"]
+        );
+    }
+
+    #[test]
+    fn test_instance_backed_slots() -> crate::Result<()> {
+        let schema: SchemaDefinition = serde_yaml::from_str(
+            r"
+id: https://textpast.org/schema/meta/identifier/identifier
+name: identifier
+imports:
+  - txp:place/polity/country/iso_3166_entity/instance
+slots:
+  identifier:
+    range: string
+  label:
+    range: string
+classes:
+  Identifier:
+    abstract: true
+    slots: [identifier, label]
+  CountryCodeAlpha2Identifier:
+    is_a: Identifier
+    slot_usage:
+      identifier:
+        range: ISO3166Entity
+        range_type: instance
+        range_properties: [id]
+",
+        )?;
+
+        assert_eq!(
+            schema.instance_backed_slots(),
+            [InstanceBackedSlot {
+                class_name: "CountryCodeAlpha2Identifier",
+                slot_name: "identifier",
+                range: "ISO3166Entity",
+                range_properties: &["id".to_string()],
+            }]
+        );
+        Ok(())
+    }
 }
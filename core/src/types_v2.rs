@@ -2,6 +2,8 @@
 //!
 //! This module provides memory-optimized versions of LinkML types that use
 //! `Arc<str>` for commonly duplicated strings to reduce memory usage.
+//! Schemas convert to V2 and back with `From`; the reverse conversion drops
+//! fields that V1 cannot represent.
 
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
@@ -504,12 +506,12 @@ impl From<crate::types::SlotDefinition> for SlotDefinitionV2 {
     fn from(v1: crate::types::SlotDefinition) -> Self {
         Self {
             name: intern(&v1.name),
-            slot_uri: None, // Not in v1
+            slot_uri: intern_option(v1.slot_uri.as_deref()),
             range: intern_option(v1.range.as_deref()),
             is_a: intern_option(v1.is_a.as_deref()),
             mixins: intern_vec(v1.mixins),
             inverse: intern_option(v1.inverse.as_deref()),
-            domain: None,         // Not in v1
+            domain: intern_option(v1.domain.as_deref()),
             subproperty_of: None, // Not in v1
             symmetric: None,      // Not in v1
 
@@ -520,23 +522,26 @@ impl From<crate::types::SlotDefinition> for SlotDefinitionV2 {
             description: v1.description,
             title: None, // Not in v1
             deprecated: v1.deprecated,
-            aliases: vec![], // Not in v1 as aliases
+            aliases: v1.aliases,
             notes: v1.notes,
             comments: v1.comments,
             todos: v1.todos,
 
             required: v1.required,
-            recommended: None, // Not in v1
+            recommended: v1.recommended,
             multivalued: v1.multivalued,
             inlined: v1.inlined,
             inlined_as_list: v1.inlined_as_list,
-            key: None, // Not in v1
+            key: v1.key,
             identifier: v1.identifier,
-            designates_type: None, // Not in v1
-            alias: None,           // Not in v1
-            owner: None,           // Not in v1
-            readonly: None,        // Not in v1
-            ifabsent: None,        // Not in v1
+            designates_type: v1.designates_type,
+            alias: None, // Not in v1
+            owner: intern_option(v1.owner.as_deref()),
+            readonly: v1
+                .readonly
+                .filter(|readonly| *readonly)
+                .map(|_| "true".to_string()),
+            ifabsent: v1.ifabsent.as_ref().and_then(ifabsent_v2),
             list_elements_unique: v1.unique,
             list_elements_ordered: v1.ordered,
             shared: None,          // Not in v1
@@ -548,8 +553,8 @@ impl From<crate::types::SlotDefinition> for SlotDefinitionV2 {
 
             minimum_value: v1.minimum_value,
             maximum_value: v1.maximum_value,
-            minimum_cardinality: None, // Not in v1 SlotDefinition
-            maximum_cardinality: None, // Not in v1 SlotDefinition
+            minimum_cardinality: v1.minimum_cardinality,
+            maximum_cardinality: v1.maximum_cardinality,
 
             see_also: intern_vec(v1.see_also),
             values_from: vec![], // Not in v1
@@ -623,7 +628,7 @@ impl From<crate::types::EnumDefinition> for EnumDefinitionV2 {
             code_set: intern_option(v1.code_set.as_deref()),
             code_set_tag: intern_option(v1.code_set_tag.as_deref()),
             code_set_version: intern_option(v1.code_set_version.as_deref()),
-            pv_formula: None, // Not in v1
+            pv_formula: intern_option(v1.pv_formula.as_deref()),
             description: v1.description,
             title: None,      // Not in v1
             deprecated: None, // Not in v1
@@ -799,3 +804,635 @@ impl From<crate::metadata::Contributor> for ContributorV2 {
         }
     }
 }
+
+/// How a V1 field was affected by conversion to V2
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConversionChange {
+    /// The field has no V2 counterpart and its value was dropped
+    Dropped,
+    /// The value was kept in a different, possibly lossy, form
+    Remapped,
+    /// A missing value was filled in with a default
+    Defaulted,
+}
+
+/// A V1 field that did not carry over to V2 unchanged
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConversionNote {
+    /// Element owning the field, such as `slot 'label'`
+    pub element: String,
+    /// V1 field name
+    pub field: &'static str,
+    /// What happened to the field
+    pub change: ConversionChange,
+}
+
+impl std::fmt::Display for ConversionNote {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let change = match self.change {
+            ConversionChange::Dropped => "dropped",
+            ConversionChange::Remapped => "remapped",
+            ConversionChange::Defaulted => "defaulted",
+        };
+        write!(f, "{} {}: {change}", self.element, self.field)
+    }
+}
+
+/// Fields of a V1 schema that were dropped, remapped or defaulted when
+/// converting it to V2
+///
+/// Only fields that held a value are reported, so converting a schema that
+/// uses nothing V2 lacks yields an empty report.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConversionReport {
+    /// Affected fields in schema order
+    pub notes: Vec<ConversionNote>,
+}
+
+impl ConversionReport {
+    /// Check whether the conversion was lossless
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.notes.is_empty()
+    }
+
+    /// Notes on fields whose values were dropped
+    pub fn dropped(&self) -> impl Iterator<Item = &ConversionNote> {
+        self.notes
+            .iter()
+            .filter(|note| note.change == ConversionChange::Dropped)
+    }
+
+    fn record(&mut self, element: &str, field: &'static str, change: ConversionChange) {
+        self.notes.push(ConversionNote {
+            element: element.to_string(),
+            field,
+            change,
+        });
+    }
+
+    fn dropped_if(&mut self, element: &str, field: &'static str, present: bool) {
+        if present {
+            self.record(element, field, ConversionChange::Dropped);
+        }
+    }
+
+    fn schema(&mut self, v1: &crate::types::SchemaDefinition) {
+        for (name, prefix) in &v1.prefixes {
+            if let crate::types::PrefixDefinition::Complex {
+                prefix_reference: None,
+                ..
+            } = prefix
+            {
+                self.record(
+                    &format!("prefix '{name}'"),
+                    "prefix_reference",
+                    ConversionChange::Defaulted,
+                );
+            }
+        }
+        for class in v1.classes.values() {
+            self.class(class);
+        }
+        for slot in v1.slots.values() {
+            self.slot(slot);
+        }
+        for type_def in v1.types.values() {
+            let element = format!("type '{}'", type_def.name);
+            if type_def.annotations.is_some() {
+                self.record(&element, "annotations", ConversionChange::Remapped);
+            }
+            self.dropped_if(&element, "min_length", type_def.min_length.is_some());
+            self.dropped_if(&element, "max_length", type_def.max_length.is_some());
+            self.dropped_if(&element, "whitespace", type_def.whitespace.is_some());
+        }
+        for enum_def in v1.enums.values() {
+            self.enum_def(enum_def);
+        }
+        if let Some(settings) = &v1.settings {
+            self.settings(settings);
+        }
+    }
+
+    fn class(&mut self, v1: &crate::types::ClassDefinition) {
+        let element = format!("class '{}'", v1.name);
+        self.dropped_if(&element, "slot_usage", !v1.slot_usage.is_empty());
+        self.dropped_if(&element, "attributes", !v1.attributes.is_empty());
+        self.dropped_if(&element, "tree_root", v1.tree_root.is_some());
+        self.dropped_if(&element, "rules", !v1.rules.is_empty());
+        self.dropped_if(&element, "if_required", v1.if_required.is_some());
+        self.dropped_if(&element, "unique_keys", !v1.unique_keys.is_empty());
+        self.dropped_if(
+            &element,
+            "recursion_options",
+            v1.recursion_options.is_some(),
+        );
+        self.dropped_if(&element, "examples", !v1.examples.is_empty());
+        self.mappings(
+            &element,
+            [
+                &v1.exact_mappings,
+                &v1.close_mappings,
+                &v1.related_mappings,
+                &v1.narrow_mappings,
+                &v1.broad_mappings,
+            ],
+        );
+    }
+
+    fn slot(&mut self, v1: &crate::types::SlotDefinition) {
+        let element = format!("slot '{}'", v1.name);
+        self.dropped_if(&element, "min_length", v1.min_length.is_some());
+        self.dropped_if(&element, "max_length", v1.max_length.is_some());
+        self.dropped_if(&element, "whitespace", v1.whitespace.is_some());
+        self.dropped_if(
+            &element,
+            "permissible_values",
+            !v1.permissible_values.is_empty(),
+        );
+        self.dropped_if(&element, "default", v1.default.is_some());
+        self.dropped_if(&element, "any_of", v1.any_of.is_some());
+        self.dropped_if(&element, "all_of", v1.all_of.is_some());
+        self.dropped_if(&element, "exactly_one_of", v1.exactly_one_of.is_some());
+        self.dropped_if(&element, "none_of", v1.none_of.is_some());
+        self.dropped_if(&element, "equals_string", v1.equals_string.is_some());
+        self.dropped_if(&element, "equals_number", v1.equals_number.is_some());
+        self.dropped_if(&element, "rules", v1.rules.is_some());
+        self.dropped_if(&element, "range_type", v1.range_type.is_some());
+        self.dropped_if(
+            &element,
+            "range_properties",
+            !v1.range_properties.is_empty(),
+        );
+        self.dropped_if(&element, "imports", !v1.imports.is_empty());
+        self.dropped_if(&element, "domain_of", !v1.domain_of.is_empty());
+        self.mappings(
+            &element,
+            [
+                &v1.exact_mappings,
+                &v1.close_mappings,
+                &v1.related_mappings,
+                &v1.narrow_mappings,
+                &v1.broad_mappings,
+            ],
+        );
+
+        // V2 keeps integer and expression defaults as plain strings
+        if matches!(
+            v1.ifabsent,
+            Some(
+                crate::types::IfAbsentAction::Int(_) | crate::types::IfAbsentAction::Expression(_)
+            )
+        ) {
+            self.record(&element, "ifabsent", ConversionChange::Remapped);
+        }
+        if v1.annotations.is_some() {
+            self.record(&element, "annotations", ConversionChange::Remapped);
+        }
+    }
+
+    fn enum_def(&mut self, v1: &crate::types::EnumDefinition) {
+        let element = format!("enum '{}'", v1.name);
+        // V2 keys permissible values on their text, so repeated texts collapse
+        let mut texts = std::collections::HashSet::new();
+        let duplicated = v1.permissible_values.iter().any(|pv| match pv {
+            PermissibleValue::Simple(text) | PermissibleValue::Complex { text, .. } => {
+                !texts.insert(text.as_str())
+            }
+        });
+        self.dropped_if(&element, "permissible_values", duplicated);
+        if v1.annotations.is_some() {
+            self.record(&element, "annotations", ConversionChange::Remapped);
+        }
+    }
+
+    fn settings(&mut self, v1: &crate::settings::SchemaSettings) {
+        self.dropped_if("settings", "validation", v1.validation.is_some());
+        self.dropped_if("settings", "naming", v1.naming.is_some());
+        self.dropped_if("settings", "custom", !v1.custom.is_empty());
+        // Language-specific options are merged and the remaining generation
+        // flags have no V2 counterpart
+        if v1.generation.is_some() {
+            self.record("settings", "generation", ConversionChange::Remapped);
+        }
+    }
+
+    fn mappings(&mut self, element: &str, mappings: [&Vec<String>; 5]) {
+        let fields = [
+            "exact_mappings",
+            "close_mappings",
+            "related_mappings",
+            "narrow_mappings",
+            "broad_mappings",
+        ];
+        for (field, values) in fields.into_iter().zip(mappings) {
+            self.dropped_if(element, field, !values.is_empty());
+        }
+    }
+}
+
+impl SchemaDefinitionV2 {
+    /// Convert a V1 schema, reporting the fields that did not carry over
+    ///
+    /// Produces the same schema as the `From` conversion; the report lists
+    /// the V1 fields that were dropped, remapped or defaulted along the way
+    /// so migrations can log or inspect them.
+    #[must_use]
+    pub fn from_with_report(v1: crate::types::SchemaDefinition) -> (Self, ConversionReport) {
+        let mut report = ConversionReport::default();
+        report.schema(&v1);
+        (Self::from(v1), report)
+    }
+}
+
+/// Conversion functions from V2 back to V1 types
+///
+/// Fields that only exist in the V2 types (titles of slots and enums, slot
+/// `symmetric`/`transitive` flags, `extensions`, `from_schema`, code
+/// generation settings and similar) have no V1 counterpart and are dropped.
+/// Annotations lose their original order, and a complex prefix whose
+/// reference equals its expansion comes back as a simple prefix.
+impl From<SchemaDefinitionV2> for crate::types::SchemaDefinition {
+    fn from(v2: SchemaDefinitionV2) -> Self {
+        Self {
+            id: v2.id.to_string(),
+            name: v2.name.to_string(),
+            default_prefix: string_option(v2.default_prefix),
+            default_range: string_option(v2.default_range),
+            metamodel_version: string_option(v2.metamodel_version),
+            status: string_option(v2.status),
+            imports: string_vec(v2.imports),
+            categories: string_vec(v2.categories),
+            keywords: string_vec(v2.keywords),
+            see_also: string_vec(v2.see_also),
+
+            title: v2.title,
+            description: v2.description,
+            version: v2.version,
+            license: v2.license,
+            generation_date: v2.generation_date,
+            source_file: v2.source_file,
+
+            prefixes: v2
+                .prefixes
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v.into()))
+                .collect(),
+            classes: v2
+                .classes
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v.into()))
+                .collect(),
+            slots: v2
+                .slots
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v.into()))
+                .collect(),
+            types: v2
+                .types
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v.into()))
+                .collect(),
+            enums: v2
+                .enums
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v.into()))
+                .collect(),
+            subsets: v2
+                .subsets
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v.into()))
+                .collect(),
+
+            settings: v2.settings.map(Into::into),
+            annotations: v2.annotations,
+            contributors: v2.contributors.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<ClassDefinitionV2> for crate::types::ClassDefinition {
+    fn from(v2: ClassDefinitionV2) -> Self {
+        Self {
+            name: v2.name.to_string(),
+            class_uri: string_option(v2.class_uri),
+            is_a: string_option(v2.is_a),
+            mixins: string_vec(v2.mixins),
+            slots: string_vec(v2.slots),
+            subclass_of: string_vec(v2.subclass_of),
+
+            description: v2.description,
+            deprecated: v2.deprecated,
+            aliases: v2.aliases,
+            notes: v2.notes,
+            comments: v2.comments,
+            todos: v2.todos,
+
+            abstract_: v2.abstract_,
+            mixin: v2.mixin,
+            see_also: string_vec(v2.see_also),
+            annotations: v2.annotations,
+            ..Self::default()
+        }
+    }
+}
+
+impl From<SlotDefinitionV2> for crate::types::SlotDefinition {
+    fn from(v2: SlotDefinitionV2) -> Self {
+        Self {
+            name: v2.name.to_string(),
+            slot_uri: string_option(v2.slot_uri),
+            range: string_option(v2.range),
+            is_a: string_option(v2.is_a),
+            mixins: string_vec(v2.mixins),
+            inverse: string_option(v2.inverse),
+            domain: string_option(v2.domain),
+            owner: string_option(v2.owner),
+
+            pattern: string_option(v2.pattern),
+            equals_expression: string_option(v2.equals_expression),
+            equals_string_in: v2.equals_string_in.map(string_vec),
+
+            description: v2.description,
+            deprecated: v2.deprecated,
+            aliases: v2.aliases,
+            notes: v2.notes,
+            comments: v2.comments,
+            todos: v2.todos,
+
+            required: v2.required,
+            recommended: v2.recommended,
+            multivalued: v2.multivalued,
+            inlined: v2.inlined,
+            inlined_as_list: v2.inlined_as_list,
+            key: v2.key,
+            identifier: v2.identifier,
+            designates_type: v2.designates_type,
+            // V1 only records whether a slot is read-only, not why
+            readonly: v2.readonly.map(|_| true),
+            ifabsent: v2
+                .ifabsent
+                .and_then(|ifabsent| serde_json::from_value(Value::String(ifabsent)).ok()),
+            unique: v2.list_elements_unique,
+            ordered: v2.list_elements_ordered,
+
+            minimum_value: v2.minimum_value,
+            maximum_value: v2.maximum_value,
+            minimum_cardinality: v2.minimum_cardinality,
+            maximum_cardinality: v2.maximum_cardinality,
+
+            see_also: string_vec(v2.see_also),
+            structured_pattern: v2.structured_pattern,
+            examples: v2.examples,
+            annotations: v2.annotations.map(annotations_v1),
+            rank: v2.rank,
+            unique_keys: string_vec(v2.unique_keys),
+            ..Self::default()
+        }
+    }
+}
+
+impl From<TypeDefinitionV2> for crate::types::TypeDefinition {
+    fn from(v2: TypeDefinitionV2) -> Self {
+        Self {
+            name: v2.name.to_string(),
+            uri: string_option(v2.uri),
+            base_type: string_option(v2.base_type),
+            description: v2.description,
+            pattern: string_option(v2.pattern),
+            minimum_value: v2.minimum_value,
+            maximum_value: v2.maximum_value,
+            min_length: None,
+            max_length: None,
+            whitespace: None,
+            annotations: v2.annotations.map(annotations_v1),
+        }
+    }
+}
+
+impl From<EnumDefinitionV2> for crate::types::EnumDefinition {
+    fn from(v2: EnumDefinitionV2) -> Self {
+        Self {
+            name: v2.name.to_string(),
+            code_set: string_option(v2.code_set),
+            code_set_tag: string_option(v2.code_set_tag),
+            code_set_version: string_option(v2.code_set_version),
+            pv_formula: string_option(v2.pv_formula),
+            description: v2.description,
+            permissible_values: v2.permissible_values.into_values().collect(),
+            annotations: v2.annotations.map(annotations_v1),
+        }
+    }
+}
+
+impl From<SubsetDefinitionV2> for crate::types::SubsetDefinition {
+    fn from(v2: SubsetDefinitionV2) -> Self {
+        Self {
+            name: v2.name.to_string(),
+            description: v2.description,
+        }
+    }
+}
+
+impl From<PrefixDefinitionV2> for crate::types::PrefixDefinition {
+    fn from(v2: PrefixDefinitionV2) -> Self {
+        if v2.prefix_prefix == v2.prefix_reference {
+            Self::Simple(v2.prefix_prefix.to_string())
+        } else {
+            Self::Complex {
+                prefix_prefix: v2.prefix_prefix.to_string(),
+                prefix_reference: Some(v2.prefix_reference.to_string()),
+            }
+        }
+    }
+}
+
+impl From<SchemaSettingsV2> for crate::settings::SchemaSettings {
+    fn from(v2: SchemaSettingsV2) -> Self {
+        let imports = (!v2.search_paths.is_empty()
+            || v2.base_url.is_some()
+            || !v2.aliases.is_empty())
+        .then(|| crate::settings::ImportSettings {
+            search_paths: string_vec(v2.search_paths),
+            base_url: string_option(v2.base_url),
+            aliases: v2
+                .aliases
+                .into_iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            ..Default::default()
+        });
+        let defaults = v2
+            .slot_range
+            .map(|slot_range| crate::settings::DefaultSettings {
+                slot_range: Some(slot_range.to_string()),
+                ..Default::default()
+            });
+
+        // Generation settings are keyed by target language in V1, which V2
+        // does not record, so package names, imports, type mappings and
+        // features are not restored
+        Self {
+            imports,
+            defaults,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<ContributorV2> for crate::metadata::Contributor {
+    fn from(v2: ContributorV2) -> Self {
+        Self {
+            name: v2.name.to_string(),
+            email: string_option(v2.email),
+            github: string_option(v2.github),
+            orcid: string_option(v2.orcid),
+            role: string_option(v2.role),
+        }
+    }
+}
+
+/// Flatten an `ifabsent` action into the string form used by V2
+fn ifabsent_v2(action: &crate::types::IfAbsentAction) -> Option<String> {
+    match action {
+        crate::types::IfAbsentAction::String(value)
+        | crate::types::IfAbsentAction::Expression(value) => Some(value.clone()),
+        crate::types::IfAbsentAction::Int(value) => Some(value.to_string()),
+        other => serde_json::to_value(other)
+            .ok()
+            .and_then(|value| value.as_str().map(str::to_string)),
+    }
+}
+
+fn string_option(s: Option<Arc<str>>) -> Option<String> {
+    s.map(|s| s.to_string())
+}
+
+fn string_vec(v: Vec<Arc<str>>) -> Vec<String> {
+    v.into_iter().map(|s| s.to_string()).collect()
+}
+
+/// Flatten V2 annotations back into V1 tag/value pairs
+fn annotations_v1(annotations: HashMap<String, Annotation>) -> crate::annotations::Annotations {
+    annotations
+        .into_iter()
+        .map(|(key, annotation)| match annotation {
+            Annotation::Simple(value) => (key, AnnotationValue::String(value)),
+            Annotation::Complex { value, .. } => (key, value),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{ClassDefinition, PrefixDefinition, SchemaDefinition, SlotDefinition};
+
+    #[test]
+    fn test_v1_v2_v1_round_trip() {
+        let mut schema = SchemaDefinition::new("hyperentity");
+        schema.default_prefix = Some("txp".to_string());
+        schema.imports.push("linkml:types".to_string());
+        schema.prefixes.insert(
+            "txp".to_string(),
+            PrefixDefinition::Simple("https://textpast.org/".to_string()),
+        );
+        schema.prefixes.insert(
+            "schema".to_string(),
+            PrefixDefinition::Complex {
+                prefix_prefix: "schema".to_string(),
+                prefix_reference: Some("http://schema.org/".to_string()),
+            },
+        );
+        schema.classes.insert(
+            "HyperEntity".to_string(),
+            ClassDefinition {
+                abstract_: Some(true),
+                slots: vec!["id".to_string(), "label".to_string()],
+                description: Some("Root of the entity tree".to_string()),
+                ..ClassDefinition::new("HyperEntity")
+            },
+        );
+        schema.slots.insert(
+            "id".to_string(),
+            SlotDefinition {
+                identifier: Some(true),
+                range: Some("uriorcurie".to_string()),
+                domain: Some("HyperEntity".to_string()),
+                ..SlotDefinition::new("id")
+            },
+        );
+        schema.slots.insert(
+            "label".to_string(),
+            SlotDefinition {
+                range: Some("string".to_string()),
+                pattern: Some("^\\S".to_string()),
+                required: Some(true),
+                ..SlotDefinition::new("label")
+            },
+        );
+
+        let round_tripped = SchemaDefinition::from(SchemaDefinitionV2::from(schema.clone()));
+
+        assert_eq!(round_tripped.id, schema.id);
+        assert_eq!(round_tripped.name, schema.name);
+        assert_eq!(round_tripped.default_prefix, schema.default_prefix);
+        assert_eq!(round_tripped.imports, schema.imports);
+        assert_eq!(round_tripped.prefixes, schema.prefixes);
+        assert_eq!(round_tripped.classes, schema.classes);
+        assert_eq!(round_tripped.slots, schema.slots);
+    }
+
+    #[test]
+    fn test_conversion_report_lists_lossy_fields() {
+        let mut schema = SchemaDefinition::new("hyperentity");
+        let mut annotations = crate::annotations::Annotations::new();
+        annotations.insert(
+            "display_hint".to_string(),
+            AnnotationValue::String("short".to_string()),
+        );
+        schema.slots.insert(
+            "label".to_string(),
+            SlotDefinition {
+                annotations: Some(annotations),
+                min_length: Some(1),
+                domain: Some("HyperEntity".to_string()),
+                ..SlotDefinition::new("label")
+            },
+        );
+
+        let (v2, report) = SchemaDefinitionV2::from_with_report(schema.clone());
+
+        assert_eq!(
+            report.notes,
+            vec![
+                ConversionNote {
+                    element: "slot 'label'".to_string(),
+                    field: "min_length",
+                    change: ConversionChange::Dropped,
+                },
+                ConversionNote {
+                    element: "slot 'label'".to_string(),
+                    field: "annotations",
+                    change: ConversionChange::Remapped,
+                },
+            ]
+        );
+        assert_eq!(report.dropped().count(), 1);
+        assert_eq!(
+            report.notes[1].to_string(),
+            "slot 'label' annotations: remapped"
+        );
+        let label = &v2.slots["label"];
+        assert_eq!(label.domain.as_deref(), Some("HyperEntity"));
+        assert!(
+            label
+                .annotations
+                .as_ref()
+                .is_some_and(|a| a.contains_key("display_hint"))
+        );
+
+        let (_, report) = SchemaDefinitionV2::from_with_report(SchemaDefinition::new("empty"));
+        assert!(report.is_empty());
+    }
+}
@@ -59,7 +59,7 @@ pub fn expand_curie(curie: &str, prefixes: &IndexMap<String, String>) -> Result<
         if let Some(expansion) = prefixes.get(prefix) {
             Ok(format!("{expansion}{local}"))
         } else {
-            Err(LinkMLError::other(format!("Unknown prefix: {prefix}")))
+            Err(LinkMLError::invalid_prefix(prefix))
         }
     } else {
         // Not a CURIE, return as-is
@@ -178,9 +178,13 @@ pub fn merge_slot_definitions(
             .or_else(|| base.description.clone()),
         range: override_def.range.clone().or_else(|| base.range.clone()),
         domain: override_def.domain.clone().or_else(|| base.domain.clone()),
+        owner: override_def.owner.clone().or_else(|| base.owner.clone()),
+        domain_of: merge_vec(&base.domain_of, &override_def.domain_of),
         required: override_def.required.or(base.required),
+        recommended: override_def.recommended.or(base.recommended),
         multivalued: override_def.multivalued.or(base.multivalued),
         identifier: override_def.identifier.or(base.identifier),
+        designates_type: override_def.designates_type.or(base.designates_type),
         pattern: override_def
             .pattern
             .clone()
@@ -200,6 +204,13 @@ pub fn merge_slot_definitions(
         },
         min_length: override_def.min_length.or(base.min_length),
         max_length: override_def.max_length.or(base.max_length),
+        whitespace: override_def.whitespace.or(base.whitespace),
+        minimum_cardinality: override_def
+            .minimum_cardinality
+            .or(base.minimum_cardinality),
+        maximum_cardinality: override_def
+            .maximum_cardinality
+            .or(base.maximum_cardinality),
         key: override_def.key.or(base.key),
         readonly: override_def.readonly.or(base.readonly),
         slot_uri: override_def
@@ -237,6 +248,11 @@ pub fn merge_slot_definitions(
             .equals_expression
             .clone()
             .or_else(|| base.equals_expression.clone()),
+        equals_string: override_def
+            .equals_string
+            .clone()
+            .or_else(|| base.equals_string.clone()),
+        equals_number: override_def.equals_number.or(base.equals_number),
         rules: override_def.rules.clone().or_else(|| base.rules.clone()),
         // The equals string in
         equals_string_in: override_def
@@ -430,6 +446,9 @@ mod tests {
         assert!(result.is_ok());
         assert_eq!(result.ok(), Some("http://example.org/Person".to_string()));
 
-        assert!(expand_curie("unknown:Person", &prefixes).is_err());
+        assert!(matches!(
+            expand_curie("unknown:Person", &prefixes),
+            Err(LinkMLError::InvalidPrefix { ref prefix }) if prefix == "unknown"
+        ));
     }
 }
@@ -0,0 +1,190 @@
+//! Traversal of schema elements with visitors
+//!
+//! Generators and lint passes all walk the same structure: the types, enums
+//! and slots of a schema and its classes with their attributes and slot
+//! usages. A [`SchemaVisitor`] implements only the callbacks it needs and
+//! [`walk_schema`] drives it over the schema in a fixed order.
+
+use crate::types::{
+    ClassDefinition, EnumDefinition, SchemaDefinition, SlotDefinition, TypeDefinition,
+};
+use std::collections::HashSet;
+
+/// Where a visited slot definition appears in the schema
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlotOrigin<'a> {
+    /// A slot defined at schema level
+    Schema,
+    /// An inline attribute of the named class
+    Attribute(&'a str),
+    /// A `slot_usage` refinement in the named class
+    SlotUsage(&'a str),
+}
+
+impl SlotOrigin<'_> {
+    /// The class the slot definition appears in, if any
+    #[must_use]
+    pub fn class(&self) -> Option<&str> {
+        match self {
+            Self::Schema => None,
+            Self::Attribute(class) | Self::SlotUsage(class) => Some(class),
+        }
+    }
+}
+
+/// Callbacks for the elements of a schema
+///
+/// Every method does nothing by default.
+pub trait SchemaVisitor {
+    /// Visit a class, after all classes it inherits from
+    fn visit_class(&mut self, _name: &str, _class: &ClassDefinition) {}
+
+    /// Visit a schema slot, or an attribute or slot usage of a class
+    fn visit_slot(&mut self, _name: &str, _slot: &SlotDefinition, _origin: SlotOrigin<'_>) {}
+
+    /// Visit an enum
+    fn visit_enum(&mut self, _name: &str, _enum_def: &EnumDefinition) {}
+
+    /// Visit a type
+    fn visit_type(&mut self, _name: &str, _type_def: &TypeDefinition) {}
+}
+
+/// Walk `schema`, calling `visitor` for each of its elements
+///
+/// Types, enums and schema slots are visited first, each in schema order.
+/// Classes follow, each after the classes it names in `is_a` and `mixins`,
+/// and each followed by its attributes and then its slot usages. Every class
+/// is visited once, even in a circular hierarchy; ancestors missing from the
+/// schema are skipped.
+pub fn walk_schema<V: SchemaVisitor + ?Sized>(schema: &SchemaDefinition, visitor: &mut V) {
+    for (name, type_def) in &schema.types {
+        visitor.visit_type(name, type_def);
+    }
+    for (name, enum_def) in &schema.enums {
+        visitor.visit_enum(name, enum_def);
+    }
+    for (name, slot) in &schema.slots {
+        visitor.visit_slot(name, slot, SlotOrigin::Schema);
+    }
+
+    let mut visited = HashSet::new();
+    for name in schema.classes.keys() {
+        walk_class(schema, name, &mut visited, visitor);
+    }
+}
+
+/// Visit the ancestors of a class that were not visited yet, then the class
+fn walk_class<'a, V: SchemaVisitor + ?Sized>(
+    schema: &'a SchemaDefinition,
+    name: &'a str,
+    visited: &mut HashSet<&'a str>,
+    visitor: &mut V,
+) {
+    let Some((name, class)) = schema.classes.get_key_value(name) else {
+        return;
+    };
+    if !visited.insert(name) {
+        return;
+    }
+    for parent in class.is_a.iter().chain(&class.mixins) {
+        walk_class(schema, parent, visited, visitor);
+    }
+
+    visitor.visit_class(name, class);
+    for (attr_name, attr) in &class.attributes {
+        visitor.visit_slot(attr_name, attr, SlotOrigin::Attribute(name));
+    }
+    for (slot_name, usage) in &class.slot_usage {
+        visitor.visit_slot(slot_name, usage, SlotOrigin::SlotUsage(name));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    const COUNTRY_SCHEMA: &str = r"
+id: https://w3id.org/rootreal/iso3166
+name: iso3166
+types:
+  alpha2:
+    typeof: string
+    pattern: '^[A-Z]{2}$'
+enums:
+  Status:
+    permissible_values:
+      official:
+      withdrawn:
+slots:
+  code:
+    range: alpha2
+    identifier: true
+  name:
+    range: string
+  status:
+    range: Status
+classes:
+  Country:
+    is_a: Polity
+    slots: [code, status]
+    attributes:
+      population:
+        range: integer
+    slot_usage:
+      name:
+        range: CountryName
+  Polity:
+    slots: [name]
+    attributes:
+      region:
+        range: Region
+  Region:
+    slots: [name]
+";
+
+    /// Collects slot ranges and the order classes are visited in
+    #[derive(Default)]
+    struct RangeCollector {
+        ranges: BTreeSet<String>,
+        classes: Vec<String>,
+        usages: Vec<String>,
+    }
+
+    impl SchemaVisitor for RangeCollector {
+        fn visit_class(&mut self, name: &str, _class: &ClassDefinition) {
+            self.classes.push(name.to_string());
+        }
+
+        fn visit_slot(&mut self, name: &str, slot: &SlotDefinition, origin: SlotOrigin<'_>) {
+            self.ranges.extend(slot.range.clone());
+            if let SlotOrigin::SlotUsage(class) = origin {
+                self.usages.push(format!("{class}.{name}"));
+            }
+        }
+    }
+
+    #[test]
+    fn test_walk_collects_slot_ranges() -> crate::Result<()> {
+        let schema: SchemaDefinition = serde_yaml::from_str(COUNTRY_SCHEMA)?;
+        let mut collector = RangeCollector::default();
+        walk_schema(&schema, &mut collector);
+
+        let expected: BTreeSet<String> = [
+            "CountryName",
+            "Region",
+            "Status",
+            "alpha2",
+            "integer",
+            "string",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect();
+        assert_eq!(collector.ranges, expected);
+        // Parents come before the classes inheriting from them
+        assert_eq!(collector.classes, ["Polity", "Country", "Region"]);
+        assert_eq!(collector.usages, ["Country.name"]);
+        Ok(())
+    }
+}
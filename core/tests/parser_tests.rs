@@ -3,8 +3,9 @@
 //! This module provides comprehensive testing for the Pest-based LinkML parser,
 //! ensuring it correctly parses all LinkML constructs according to the specification.
 
-use linkml_core::error::Result;
+use linkml_core::error::{LinkMLError, Result};
 use linkml_core::parser::LinkMLParser;
+use linkml_core::types::WhitespaceFacet;
 use pretty_assertions::assert_eq;
 
 /// Test parsing a minimal schema with only required fields
@@ -221,6 +222,72 @@ types:
     Ok(())
 }
 
+/// Test parsing string length bounds on slots and types
+#[test]
+fn test_parse_length_bounds() -> Result<()> {
+    let input = r#"
+id: https://example.org/test
+name: test_schema
+
+types:
+  CountryCode:
+    typeof: string
+    min_length: 2
+    max_length: 3
+    whitespace: collapse
+
+slots:
+  tld:
+    range: string
+    min_length: 2
+    max_length: 63
+    whitespace: replace
+"#;
+
+    let schema = LinkMLParser::parse_schema(input)?;
+
+    let country_code = &schema.types.get("CountryCode").unwrap().value;
+    assert_eq!(country_code.min_length.as_ref().unwrap().value, 2);
+    assert_eq!(country_code.max_length.as_ref().unwrap().value, 3);
+    let tld = &schema.slots.get("tld").unwrap().value;
+    assert_eq!(tld.min_length.as_ref().unwrap().value, 2);
+    assert_eq!(tld.max_length.as_ref().unwrap().value, 63);
+
+    let definition = schema.into_schema_definition()?;
+    assert_eq!(definition.types["CountryCode"].max_length, Some(3));
+    assert_eq!(definition.slots["tld"].min_length, Some(2));
+    assert_eq!(
+        definition.types["CountryCode"].whitespace,
+        Some(WhitespaceFacet::Collapse)
+    );
+    assert_eq!(
+        definition.slots["tld"].whitespace,
+        Some(WhitespaceFacet::Replace)
+    );
+
+    Ok(())
+}
+
+/// Test that an unknown whitespace facet is rejected
+#[test]
+fn test_parse_unknown_whitespace_facet() -> Result<()> {
+    let input = r#"
+id: https://example.org/test
+name: test_schema
+
+slots:
+  tld:
+    range: string
+    whitespace: squash
+"#;
+
+    let schema = LinkMLParser::parse_schema(input)?;
+    let error = schema.into_schema_definition().unwrap_err();
+    assert!(error.to_string().contains("squash"), "{error}");
+
+    Ok(())
+}
+
 /// Test parsing the actual LinkML meta schema
 #[test]
 fn test_parse_linkml_meta_schema() -> Result<()> {
@@ -325,6 +392,87 @@ name: test_schema
     Ok(())
 }
 
+/// Test unrecognized keys are reported with the element they appear in
+#[test]
+fn test_parse_unknown_field() {
+    let input = r#"
+id: https://example.org/test
+name: test_schema
+colour: red
+"#;
+
+    let result = LinkMLParser::parse_schema(input);
+    assert!(
+        matches!(
+            result,
+            Err(LinkMLError::UnknownField { ref field, ref context })
+                if field == "colour" && context == "schema"
+        ),
+        "{result:?}"
+    );
+
+    let input = r#"
+id: https://example.org/test
+name: test_schema
+classes:
+  Person:
+    is_a: Entity
+    colour: red
+"#;
+
+    let result = LinkMLParser::parse_schema(input);
+    assert!(
+        matches!(
+            result,
+            Err(LinkMLError::UnknownField { ref field, ref context })
+                if field == "colour" && context == "class 'Person'"
+        ),
+        "{result:?}"
+    );
+}
+
+/// Test a class defined twice is rejected instead of overwritten
+#[test]
+fn test_parse_duplicate_class() {
+    let input = r#"
+id: https://example.org/test
+name: test_schema
+classes:
+  Person:
+    is_a: Entity
+  Person:
+    is_a: Agent
+"#;
+
+    let result = LinkMLParser::parse_schema(input);
+    assert!(
+        matches!(
+            result,
+            Err(LinkMLError::DuplicateDefinition { ref kind, ref name })
+                if kind == "Class" && name == "Person"
+        ),
+        "{result:?}"
+    );
+}
+
+/// Test a default prefix missing from the prefixes is rejected
+#[test]
+fn test_parse_undeclared_default_prefix() {
+    let input = r#"
+id: https://example.org/test
+name: test_schema
+prefixes:
+  linkml: https://w3id.org/linkml/
+default_prefix: ex
+"#;
+
+    let result = LinkMLParser::parse_schema(input);
+    assert!(
+        matches!(result, Err(LinkMLError::InvalidPrefix { ref prefix }) if prefix == "ex"),
+        "{result:?}"
+    );
+}
+
 /// Test parsing performance with a large schema
 #[test]
 #[ignore] // Run with --ignored flag for performance testing
@@ -359,3 +507,35 @@ fn test_parse_performance_large_schema() -> Result<()> {
     
     Ok(())
 }
+
+/// Replace every span in a serialized AST so that trees compare on content
+#[cfg(feature = "fast_parse")]
+fn without_spans(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            map.remove("span");
+            map.values_mut().for_each(without_spans);
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(without_spans),
+        _ => {}
+    }
+}
+
+/// Test that the parser without line/column lookups builds the same AST
+#[cfg(feature = "fast_parse")]
+#[test]
+fn test_parse_schema_fast_matches_meta_schema() -> Result<()> {
+    let input = include_str!("../../schemas/meta.yaml");
+
+    let mut tracked = serde_json::to_value(LinkMLParser::parse_schema(input)?)?;
+    let mut fast = serde_json::to_value(LinkMLParser::parse_schema_fast(input)?)?;
+    without_spans(&mut tracked);
+    without_spans(&mut fast);
+    assert_eq!(tracked, fast);
+
+    // Span tracking is restored for later parses on this thread
+    let schema = LinkMLParser::parse_schema(input)?;
+    assert!(schema.name.as_ref().unwrap().span.line > 0);
+
+    Ok(())
+}
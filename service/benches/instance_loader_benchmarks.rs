@@ -0,0 +1,74 @@
+//! Performance benchmarks for loading instance files
+//!
+//! Compares parsing a country instance file whole with streaming it one
+//! instance at a time, where only the key and value fields are built.
+
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group, criterion_main};
+use linkml_service::validator::{InstanceConfig, InstanceLoader};
+use std::path::Path;
+use tempfile::TempDir;
+use timestamp_service::wiring::wire_timestamp;
+
+/// Write a country instance file with `count` instances
+fn create_country_file(dir: &Path, count: usize) -> std::path::PathBuf {
+    let header = "\
+id: https://textpast.org/instance/place/polity/country/iso_3166_entity
+schema: https://textpast.org/schema/place/polity/country
+version: 1.0.0
+created_on: '2025-03-30T10:41:26+01:00'
+last_updated_on: '2025-03-30T10:41:26+01:00'
+instances:
+";
+    let instances: String = (0..count)
+        .map(|index| {
+            format!(
+                "  - id: C{index:05}
+    label: \"Country {index}\"
+    tld: .c{index}
+    exact_mappings:
+      - wikidata:Q{index}
+      - geonames:{index}
+    borders: {{north: C{north:05}, south: C{south:05}}}
+",
+                north = index + 1,
+                south = index.saturating_sub(1),
+            )
+        })
+        .collect();
+    let path = dir.join("iso_3166_entity.yaml");
+    std::fs::write(&path, format!("{header}{instances}")).expect("Failed to write country file");
+    path
+}
+
+fn bench_country_file_loading(c: &mut Criterion) {
+    let mut group = c.benchmark_group("instance_loader_country_file");
+    let rt = tokio::runtime::Runtime::new().expect("Failed to create runtime");
+    let timestamp = wire_timestamp().into_arc();
+    let config = InstanceConfig {
+        value_field: Some("label".to_string()),
+        ..InstanceConfig::default()
+    };
+
+    for count in [1_000, 10_000, 50_000] {
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let path = create_country_file(temp_dir.path(), count);
+
+        for (name, streaming) in [("parsed", false), ("streamed", true)] {
+            group.bench_with_input(BenchmarkId::new(name, count), &path, |b, path| {
+                b.iter(|| {
+                    // A fresh loader each time, so the cache is never hit
+                    let loader =
+                        InstanceLoader::new(timestamp.clone()).with_yaml_streaming(streaming);
+                    rt.block_on(async {
+                        black_box(loader.load_yaml_file(path, &config).await.unwrap())
+                    })
+                });
+            });
+        }
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_country_file_loading);
+criterion_main!(benches);
@@ -13,13 +13,20 @@
 //! - Schema imports MUST end with `/schema`
 //! - `range_type: instance` triggers instance-based validation
 //! - `range_properties` specifies which field to validate against
+//!
+//! The `ValidationEngine` checks validate small YAML documents together with a
+//! `SourceMap` of their text, so each failure is printed with its line and
+//! column.
 
 use linkml_core::error::Result;
 use linkml_service::parser::factory::create_dev_schema_loader;
-use linkml_service::validator::{InstanceLoader, InstanceResolver, ValidationEngine};
-use serde_json::json;
+use linkml_service::validator::{
+    InstanceLoader, InstanceResolver, SourceMap, ValidationEngine, ValidationOptions,
+    ValidationReport,
+};
 use timestamp_service::wiring::wire_timestamp;
 use std::path::PathBuf;
+use std::sync::Arc;
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -148,21 +155,15 @@ async fn test_country_codes(
             ("XX", false, "Invalid code"),
             ("", false, "Empty string"),
         ] {
-            let test_data = json!({
-                "identifier": code
-            });
-
-            let report = engine.validate_as_class(&test_data, "CountryCodeAlpha2Identifier", None).await?;
+            let document = format!("identifier: {code:?}\n");
+            let report = validate_document(&engine, &document, "CountryCodeAlpha2Identifier").await?;
             let is_valid = report.valid;
             let status = if is_valid == *expected { "✓" } else { "✗ UNEXPECTED" };
 
             println!("  {} '{}': {} - {}", status, code,
                 if is_valid { "Valid  " } else { "Invalid" }, description);
 
-            let errors: Vec<_> = report.errors().collect();
-            if !is_valid && !errors.is_empty() {
-                println!("      Error: {}", errors[0].message);
-            }
+            print_first_error(&report);
         }
     }
 
@@ -221,21 +222,15 @@ async fn test_timezones(
             (valid_ids.get(0).map(|s| s.as_str()).unwrap_or(""), true, "Valid timezone"),
             ("Invalid/Timezone", false, "Invalid timezone"),
         ] {
-            let test_data = json!({
-                "timezone_component_value": tz
-            });
-
-            let report = engine.validate_as_class(&test_data, "TimeZone", None).await?;
+            let document = format!("timezone_component_value: {tz:?}\n");
+            let report = validate_document(&engine, &document, "TimeZone").await?;
             let is_valid = report.valid;
             let status = if is_valid == *expected { "✓" } else { "✗ UNEXPECTED" };
 
             println!("  {} '{}': {} - {}", status, tz,
                 if is_valid { "Valid  " } else { "Invalid" }, description);
 
-            let errors: Vec<_> = report.errors().collect();
-            if !is_valid && !errors.is_empty() {
-                println!("      Error: {}", errors[0].message);
-            }
+            print_first_error(&report);
         }
     }
 
@@ -314,21 +309,15 @@ async fn test_languages(
             ("xxx", false, "Invalid code"),
             ("", false, "Empty string"),
         ] {
-            let test_data = json!({
-                "identifier": code
-            });
-
-            let report = engine.validate_as_class(&test_data, "ISO639Entity", None).await?;
+            let document = format!("identifier: {code:?}\n");
+            let report = validate_document(&engine, &document, "ISO639Entity").await?;
             let is_valid = report.valid;
             let status = if is_valid == *expected { "✓" } else { "✗ UNEXPECTED" };
 
             println!("  {} '{}': {} - {}", status, code,
                 if is_valid { "Valid  " } else { "Invalid" }, description);
 
-            let errors: Vec<_> = report.errors().collect();
-            if !is_valid && !errors.is_empty() {
-                println!("      Error: {}", errors[0].message);
-            }
+            print_first_error(&report);
         }
     }
 
@@ -400,24 +389,42 @@ async fn test_document_parsers(
                 (valid_ids[0].as_str(), true, "Valid parser"),
                 ("invalid_parser", false, "Invalid parser"),
             ] {
-                let test_data = json!({
-                    "id": parser_id
-                });
-
-                let report = engine.validate_as_class(&test_data, "DocumentParser", None).await?;
+                let document = format!("id: {parser_id:?}\n");
+                let report = validate_document(&engine, &document, "DocumentParser").await?;
                 let is_valid = report.valid;
                 let status = if is_valid == *expected { "✓" } else { "✗ UNEXPECTED" };
 
                 println!("  {} '{}': {} - {}", status, parser_id,
                     if is_valid { "Valid  " } else { "Invalid" }, description);
 
-                let errors: Vec<_> = report.errors().collect();
-                if !is_valid && !errors.is_empty() {
-                    println!("      Error: {}", errors[0].message);
-                }
+                print_first_error(&report);
             }
         }
     }
 
     Ok(())
 }
+
+/// Validate a YAML document, mapping issues back to where they occur in it
+async fn validate_document(
+    engine: &ValidationEngine,
+    document: &str,
+    class_name: &str,
+) -> Result<ValidationReport> {
+    let data: serde_json::Value = serde_yaml::from_str(document)?;
+    let options = ValidationOptions {
+        source_map: Some(Arc::new(SourceMap::from_yaml(document))),
+        ..ValidationOptions::default()
+    };
+    engine.validate_as_class(&data, class_name, Some(options)).await
+}
+
+fn print_first_error(report: &ValidationReport) {
+    if let Some(error) = report.errors().next() {
+        let pointer = if error.pointer.is_empty() { "/" } else { &error.pointer };
+        match &error.location {
+            Some(location) => println!("      Error at {pointer}: {} ({location})", error.message),
+            None => println!("      Error at {pointer}: {}", error.message),
+        }
+    }
+}
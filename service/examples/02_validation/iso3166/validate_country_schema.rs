@@ -95,7 +95,8 @@ async fn main() -> Result<()> {
             let errors: Vec<_> = validation_result.errors().collect();
             println!("  ✗ Invalid instance at index {}: {} errors", idx, errors.len());
             for error in errors.iter().take(3) {
-                println!("      - {}", error.message);
+                // Pointers are relative to the instance; prefix its place in the file
+                println!("      - /instances/{}{}: {}", idx, error.pointer, error.message);
             }
         }
     }
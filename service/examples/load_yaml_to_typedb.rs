@@ -20,6 +20,9 @@ use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Instant;
 
+/// Number of insert statements committed per write transaction
+const INSERT_CHUNK_SIZE: usize = 500;
+
 #[derive(ClapParser, Debug)]
 #[command(name = "load_yaml_to_typedb")]
 #[command(about = "Load YAML instance data into TypeDB", long_about = None)]
@@ -254,33 +257,29 @@ async fn load_class_instances(
             };
         }
 
-        // Insert instances
-        let mut inserted = 0;
-        for (idx, instance) in instances.iter().enumerate() {
-            let typeql = match instance_to_typeql(instance) {
-                Ok(t) => t,
+        // Convert instances to TypeQL
+        let mut statements = Vec::with_capacity(instances.len());
+        for instance in &instances {
+            match instance_to_typeql(instance, &schema) {
+                Ok(t) => statements.push(t),
                 Err(e) => {
                     eprintln!("  ⚠ Failed to convert instance to TypeQL: {}", e);
-                    continue;
-                }
-            };
-
-            match typedb.insert_instance(database, &typeql).await {
-                Ok(_) => inserted += 1,
-                Err(e) => {
-                    eprintln!("  ⚠ Failed to insert instance: {}", e);
-                    // Continue with next instance
                 }
             }
+        }
 
-            // Progress indicator every 100 instances
-            if (idx + 1) % 100 == 0 {
-                print!(".");
-                let _ = std::io::Write::flush(&mut std::io::stdout());
+        // Insert in chunks, one write transaction per chunk
+        let inserted = match typedb
+            .insert_batch_transactional(database, &statements, INSERT_CHUNK_SIZE, true)
+            .await
+        {
+            Ok(batch) => batch.inserted,
+            Err(e) => {
+                eprintln!("  ⚠ Failed to insert instances: {}", e);
+                0
             }
-        }
+        };
 
-        println!();
         println!("  ✓ Loaded {}/{} instances into TypeDB", inserted, instances.len());
         inserted_count = inserted;
     }
@@ -4,8 +4,13 @@ use super::types::{
     AuthType, ConflictResolution, DiffFormat, DumpFormat, LinkMLCli, LinkMLCommand, LintFormat,
     LoadFormat, MergeStrategy, OutputFormat, SchemaFormat,
 };
+use crate::cli_enhanced::commands::flatten::FlattenCommand;
+use crate::cli_enhanced::commands::generate::GenerateCommand;
 use crate::cli_enhanced::commands::serve::ServeCommand;
-use crate::generator::{Generator, GeneratorOptions, GeneratorRegistry, IndentStyle};
+use crate::cli_enhanced::commands::validate::{
+    FileReport, JsonValidationReport, ValidateCommand, load_data,
+};
+use crate::generator::{GeneratorOptions, IndentStyle};
 use crate::schema::{
     DiffOptions, LintOptions, MergeOptions, SchemaDiff, SchemaLinter, SchemaMerge, Severity,
 };
@@ -15,7 +20,6 @@ use crate::validator::report::ValidationReport;
 use clap::Parser;
 use linkml_core::error::{LinkMLError, Result};
 use linkml_core::types::SchemaDefinition;
-use serde_json::Value;
 use std::collections::HashMap;
 use std::fmt::Write as FmtWrite;
 use std::path::{Path, PathBuf};
@@ -100,23 +104,21 @@ impl LinkMLApp {
     }
 
     /// Configure tracing subscriber based on CLI flags
+    ///
+    /// Logs go to stderr so that command output on stdout stays parseable.
     fn init_logging(&self) {
-        if self.cli.quiet {
-            tracing_subscriber::fmt()
-                .with_max_level(tracing::Level::ERROR)
-                .with_target(false)
-                .init();
+        let level = if self.cli.quiet {
+            tracing::Level::ERROR
         } else if self.cli.verbose {
-            tracing_subscriber::fmt()
-                .with_max_level(tracing::Level::DEBUG)
-                .with_target(false)
-                .init();
+            tracing::Level::DEBUG
         } else {
-            tracing_subscriber::fmt()
-                .with_max_level(tracing::Level::INFO)
-                .with_target(false)
-                .init();
-        }
+            tracing::Level::INFO
+        };
+        tracing_subscriber::fmt()
+            .with_max_level(level)
+            .with_target(false)
+            .with_writer(std::io::stderr)
+            .init();
     }
 
     async fn execute_command(&self) -> Result<()> {
@@ -143,12 +145,12 @@ impl LinkMLApp {
             }
             LinkMLCommand::Generate {
                 schema,
-                generator,
+                format,
                 output,
                 options,
                 ..
             } => {
-                self.generate_command(schema, generator, output, options)
+                self.generate_command(schema, format, output.as_deref(), options)
                     .await
             }
             LinkMLCommand::Convert {
@@ -173,6 +175,9 @@ impl LinkMLApp {
                 self.lint_command(schema, rules, config.as_ref(), *fix, *strict, *format)
                     .await
             }
+            LinkMLCommand::Flatten { schema, output } => {
+                self.flatten_command(schema, output.as_deref()).await
+            }
             LinkMLCommand::Diff {
                 schema1,
                 schema2,
@@ -319,42 +324,45 @@ impl LinkMLApp {
         show_stats: bool,
         parallel: bool,
     ) -> Result<()> {
-        let schema = self.load_schema(schema_path).await?;
-        let engine = ValidationEngine::new(&schema)
-            .map_err(|err| LinkMLError::service(format!("Failed to build validator: {err}")))?;
-
         let options = ValidationOptions {
             fail_fast: if strict { Some(true) } else { None },
+            max_errors: None,
             parallel: Some(parallel),
             allow_additional_properties: None,
+            on_unknown_field: None,
             max_depth: None,
             check_permissibles: None,
             use_cache: Some(true),
             fail_on_warning: if strict { Some(true) } else { None },
+            coerce_types: None,
             custom_validators: Vec::new(),
+            source_map: None,
         };
 
-        let mut any_failures = false;
-        for data_path in data_paths {
-            let value = self.load_data_value(data_path).await?;
-            let mut report = if let Some(target) = class_name {
-                engine
-                    .validate_as_class(&value, target, Some(options.clone()))
-                    .await?
-            } else {
-                engine.validate(&value, Some(options.clone())).await?
-            };
+        let mut command = ValidateCommand::new(schema_path.to_path_buf(), data_paths.to_vec())
+            .with_options(options);
+        if let Some(target) = class_name {
+            command = command.with_class_name(target);
+        }
 
-            if !report.valid {
-                any_failures = true;
+        let reports = command.execute().await?;
+        let failures = reports.iter().filter(|file| !file.report.valid).count();
+        if matches!(self.cli.format, OutputFormat::Json) {
+            // Structured output for pipelines, listing every issue
+            let json = JsonValidationReport::from(reports.as_slice());
+            println!("{}", serde_json::to_string_pretty(&json)?);
+        } else {
+            for FileReport { path, mut report } in reports {
+                self.render_validation_report(&path, &mut report, max_errors, show_stats)?;
             }
-
-            self.render_validation_report(data_path, &mut report, max_errors, show_stats)?;
         }
 
-        if strict && any_failures {
+        if failures > 0 {
             return Err(LinkMLError::DataValidationError {
-                message: "Validation failed in strict mode".to_string(),
+                message: format!(
+                    "{failures} of {} data files failed validation",
+                    data_paths.len()
+                ),
                 path: None,
                 expected: Some("valid data".to_string()),
                 actual: Some("schema violations".to_string()),
@@ -367,37 +375,23 @@ impl LinkMLApp {
     async fn generate_command(
         &self,
         schema_path: &Path,
-        generator_name: &str,
-        output_path: &Path,
+        format: &str,
+        output_path: Option<&Path>,
         options: &[String],
     ) -> Result<()> {
-        let schema = self.load_schema(schema_path).await?;
-        let registry = GeneratorRegistry::with_defaults().await;
-
-        let resolved_name = Self::resolve_generator_name(generator_name);
-        let generator = registry.get(&resolved_name).await.ok_or_else(|| {
-            LinkMLError::NotImplemented(format!("Generator '{generator_name}' is not registered"))
-        })?;
-
-        let generator_options = self.parse_generator_options(options)?;
-        generator
-            .validate_schema(&schema)
-            .map_err(|err| LinkMLError::schema_validation(err.to_string()))?;
-        let content = generator.generate(&schema)?;
-
-        let target_file = self
-            .prepare_output_path(output_path, generator.as_ref())
-            .await?;
-        fs::write(&target_file, content)
-            .await
-            .map_err(LinkMLError::from)?;
+        let mut command = GenerateCommand::new(schema_path.to_path_buf(), format)
+            .with_options(self.parse_generator_options(options)?);
+        if let Some(output_path) = output_path {
+            command = command.with_output(output_path.to_path_buf());
+        }
 
-        if !self.cli.quiet {
+        if let Some(target_file) = command.execute().await?
+            && !self.cli.quiet
+        {
             println!("Generated output: {}", target_file.display());
         }
 
-        info!("Code generation completed using {}", generator.name());
-        drop(generator_options);
+        info!("Code generation completed using format {format}");
         Ok(())
     }
 
@@ -513,6 +507,21 @@ impl LinkMLApp {
         Ok(())
     }
 
+    async fn flatten_command(&self, schema_path: &Path, output_path: Option<&Path>) -> Result<()> {
+        let mut command = FlattenCommand::new(schema_path.to_path_buf());
+        if let Some(output_path) = output_path {
+            command = command.with_output(output_path.to_path_buf());
+        }
+        command.execute().await?;
+
+        if let Some(output_path) = output_path
+            && !self.cli.quiet
+        {
+            println!("Flattened schema written to {}", output_path.display());
+        }
+        Ok(())
+    }
+
     async fn diff_command(
         &self,
         schema1: &Path,
@@ -691,13 +700,17 @@ impl LinkMLApp {
 
             let validation_options = ValidationOptions {
                 fail_fast: Some(false),
+                max_errors: None,
                 parallel: Some(false),
                 allow_additional_properties: None,
+                on_unknown_field: None,
                 max_depth: None,
                 check_permissibles: None,
                 use_cache: Some(true),
                 fail_on_warning: None,
+                coerce_types: None,
                 custom_validators: Vec::new(),
+                source_map: None,
             };
 
             let report = if let Some(target_class) = class_name {
@@ -760,7 +773,7 @@ impl LinkMLApp {
         pretty: bool,
     ) -> Result<()> {
         let _schema = self.load_schema(schema_path).await?;
-        let input_data = self.load_data_value(input_path).await?;
+        let input_data = load_data(input_path).await?;
 
         // Parse dump options
         let mut dump_options = std::collections::HashMap::new();
@@ -935,29 +948,6 @@ impl LinkMLApp {
         Ok(schema)
     }
 
-    async fn load_data_value(&self, path: &Path) -> Result<Value> {
-        let content =
-            fs::read_to_string(path)
-                .await
-                .map_err(|err| LinkMLError::DataValidationError {
-                    message: format!("Failed to read data file: {err}"),
-                    path: Some(path.display().to_string()),
-                    expected: Some("readable file".to_string()),
-                    actual: Some("read error".to_string()),
-                })?;
-
-        if matches!(
-            path.extension().and_then(|ext| ext.to_str()),
-            Some("yaml" | "yml")
-        ) {
-            serde_yaml::from_str(&content)
-                .map_err(|err| LinkMLError::data_validation(format!("YAML parse error: {err}")))
-        } else {
-            serde_json::from_str(&content)
-                .map_err(|err| LinkMLError::data_validation(format!("JSON parse error: {err}")))
-        }
-    }
-
     fn basic_schema_sanity_check(&self, schema: &SchemaDefinition, source: &Path) -> Result<()> {
         if schema.name.trim().is_empty() {
             return Err(LinkMLError::schema_validation(format!(
@@ -978,41 +968,6 @@ impl LinkMLApp {
         }
     }
 
-    fn resolve_generator_name(raw: &str) -> String {
-        match raw.to_ascii_lowercase().as_str() {
-            "python" | "py" => "python-dataclass".to_string(),
-            "pydantic" => "pydantic".to_string(),
-            "rust" => "rust".to_string(),
-            "typescript" | "ts" => "typescript".to_string(),
-            other => other.to_string(),
-        }
-    }
-
-    async fn prepare_output_path(
-        &self,
-        output_path: &Path,
-        generator: &dyn Generator,
-    ) -> Result<PathBuf> {
-        if let Ok(metadata) = fs::metadata(output_path).await
-            && metadata.is_dir()
-        {
-            let target = output_path.join(generator.get_default_filename());
-            return Ok(target);
-        }
-
-        if output_path.extension().is_some() {
-            if let Some(parent) = output_path.parent()
-                && !parent.as_os_str().is_empty()
-            {
-                fs::create_dir_all(parent).await?;
-            }
-            Ok(output_path.to_path_buf())
-        } else {
-            fs::create_dir_all(output_path).await?;
-            Ok(output_path.join(generator.get_default_filename()))
-        }
-    }
-
     fn parse_generator_options(&self, options: &[String]) -> Result<GeneratorOptions> {
         let mut generator_options = GeneratorOptions::default();
 
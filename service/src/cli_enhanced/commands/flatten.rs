@@ -0,0 +1,158 @@
+//! `flatten` command implementation
+//!
+//! Writes a self-contained copy of a LinkML schema: every import is resolved
+//! and its elements merged in, so the result no longer needs the import tree.
+
+use crate::generator::to_linkml_yaml;
+use crate::parser::SchemaLoader;
+use linkml_core::error::{LinkMLError, Result};
+use linkml_core::types::SchemaDefinition;
+use std::path::PathBuf;
+use tokio::fs;
+
+/// Command for flattening a schema and its imports into one schema
+pub struct FlattenCommand {
+    /// Schema file path
+    pub schema: PathBuf,
+    /// Output file path; the schema is printed to stdout when unset
+    pub output: Option<PathBuf>,
+}
+
+impl FlattenCommand {
+    /// Create a new flatten command writing to stdout
+    #[must_use]
+    pub fn new(schema: PathBuf) -> Self {
+        Self {
+            schema,
+            output: None,
+        }
+    }
+
+    /// Write the flattened schema to `output` instead of stdout
+    #[must_use]
+    pub fn with_output(mut self, output: PathBuf) -> Self {
+        self.output = Some(output);
+        self
+    }
+
+    /// Load the schema with all imports merged in and no imports left
+    ///
+    /// Slot-level imports are dropped as well, since the resolver has already
+    /// brought in the ranges they scope.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the schema or one of its imports can't be loaded
+    pub async fn flatten(&self) -> Result<SchemaDefinition> {
+        let mut schema = SchemaLoader::new().load_file(&self.schema).await?;
+        schema.imports.clear();
+        for slot in schema.slots.values_mut() {
+            slot.imports.clear();
+        }
+        for class in schema.classes.values_mut() {
+            for slot in class
+                .attributes
+                .values_mut()
+                .chain(class.slot_usage.values_mut())
+            {
+                slot.imports.clear();
+            }
+        }
+        Ok(schema)
+    }
+
+    /// Execute the command
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// - The schema or one of its imports can't be loaded
+    /// - The flattened schema can't be serialized
+    /// - The output file can't be written
+    pub async fn execute(&self) -> Result<()> {
+        let yaml = to_linkml_yaml(&self.flatten().await?)?;
+
+        let Some(output) = &self.output else {
+            print!("{yaml}");
+            return Ok(());
+        };
+        if let Some(parent) = output.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            fs::create_dir_all(parent).await?;
+        }
+        fs::write(output, yaml).await.map_err(|e| {
+            LinkMLError::io_error(format!("Failed to write {}: {e}", output.display()))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[tokio::test]
+    async fn test_flattened_country_schema_is_self_contained() -> anyhow::Result<()> {
+        let temp_dir = TempDir::new()?;
+        let files = [
+            (
+                "entity.yaml",
+                r"
+id: https://example.org/entity
+name: entity
+slots:
+  identifier:
+    identifier: true
+classes:
+  Entity:
+    slots: [identifier]
+",
+            ),
+            (
+                "identifier.yaml",
+                r"
+id: https://example.org/identifier
+name: identifier
+types:
+  CountryCodeAlpha2Identifier:
+    typeof: string
+    pattern: '^[A-Z]{2}$'
+",
+            ),
+            (
+                "country.yaml",
+                r"
+id: https://example.org/country
+name: country
+imports:
+  - entity
+  - identifier
+classes:
+  Country:
+    is_a: Entity
+    slot_usage:
+      identifier:
+        range: CountryCodeAlpha2Identifier
+",
+            ),
+        ];
+        for (name, content) in files {
+            fs::write(temp_dir.path().join(name), content).await?;
+        }
+
+        let output = temp_dir.path().join("flat").join("country.yaml");
+        FlattenCommand::new(temp_dir.path().join("country.yaml"))
+            .with_output(output.clone())
+            .execute()
+            .await?;
+
+        let flattened: SchemaDefinition =
+            serde_yaml::from_str(&fs::read_to_string(&output).await?)?;
+        assert!(flattened.imports.is_empty());
+        assert!(flattened.classes.contains_key("Country"));
+        assert!(flattened.classes.contains_key("Entity"));
+        assert!(flattened.types.contains_key("CountryCodeAlpha2Identifier"));
+        Ok(())
+    }
+}
@@ -0,0 +1,245 @@
+//! `generate` command implementation
+//!
+//! Generates an artifact such as Rust code, `TypeQL` or OWL from a LinkML
+//! schema. The schema is loaded with its imports resolved and the generator
+//! is chosen by output format name from the generator registry, falling back
+//! to generators registered under their own name.
+
+use crate::generator::{Generator, GeneratorOptions, GeneratorRegistry};
+use crate::parser::SchemaLoader;
+use linkml_core::error::{LinkMLError, Result};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::fs;
+
+/// Command for generating an artifact from a schema
+pub struct GenerateCommand {
+    /// Schema file path
+    pub schema: PathBuf,
+    /// Output format name, such as `rust`, `typeql`, `owl`, `ttl` or
+    /// `json-schema`
+    pub format: String,
+    /// Output file or directory; the artifact is printed to stdout when unset
+    pub output: Option<PathBuf>,
+    /// Options passed to the generator
+    pub options: GeneratorOptions,
+}
+
+impl GenerateCommand {
+    /// Create a new generate command writing to stdout
+    #[must_use]
+    pub fn new(schema: PathBuf, format: impl Into<String>) -> Self {
+        Self {
+            schema,
+            format: format.into(),
+            output: None,
+            options: GeneratorOptions::default(),
+        }
+    }
+
+    /// Write the artifact to `output` instead of stdout
+    #[must_use]
+    pub fn with_output(mut self, output: PathBuf) -> Self {
+        self.output = Some(output);
+        self
+    }
+
+    /// Build the generator with `options` instead of the defaults
+    #[must_use]
+    pub fn with_options(mut self, options: GeneratorOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Execute the command
+    ///
+    /// Returns the path the artifact was written to, or `None` when it was
+    /// printed to stdout.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// - The schema or one of its imports can't be loaded
+    /// - No generator is registered for the format
+    /// - The schema is rejected by the generator or generation fails
+    /// - The output file can't be written
+    pub async fn execute(&self) -> Result<Option<PathBuf>> {
+        let schema = SchemaLoader::new().load_file(&self.schema).await?;
+        let generator = self.resolve_generator().await?;
+        generator
+            .validate_schema(&schema)
+            .map_err(|err| LinkMLError::schema_validation(err.to_string()))?;
+        let content = generator.generate(&schema)?;
+
+        let Some(output) = &self.output else {
+            print!("{content}");
+            return Ok(None);
+        };
+        let target = prepare_output_path(output, generator.as_ref()).await?;
+        fs::write(&target, content).await.map_err(|e| {
+            LinkMLError::io_error(format!("Failed to write {}: {e}", target.display()))
+        })?;
+        Ok(Some(target))
+    }
+
+    /// Look the generator up by output format, then by generator name
+    async fn resolve_generator(&self) -> Result<Arc<dyn Generator>> {
+        let registry = GeneratorRegistry::with_defaults().await;
+        let name = resolve_generator_name(&self.format);
+        if let Some(generator) = registry.create_format(&name, &self.options).await {
+            return Ok(Arc::from(generator));
+        }
+        if let Some(generator) = registry.get(&name).await {
+            return Ok(generator);
+        }
+        Err(LinkMLError::config(format!(
+            "Unknown output format '{}'; available formats: {}",
+            self.format,
+            registry.list_formats().await.join(", ")
+        )))
+    }
+}
+
+/// Map the short names accepted on the command line to format names
+fn resolve_generator_name(raw: &str) -> String {
+    match raw.to_ascii_lowercase().as_str() {
+        "python" | "py" => "python".to_string(),
+        "typescript" | "ts" => "typescript".to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// Resolve the file to write the artifact to
+///
+/// An existing directory, or a path without an extension, receives the
+/// generator's default file name; missing directories are created.
+async fn prepare_output_path(output: &Path, generator: &dyn Generator) -> Result<PathBuf> {
+    if let Ok(metadata) = fs::metadata(output).await
+        && metadata.is_dir()
+    {
+        return Ok(output.join(default_filename(generator)));
+    }
+
+    if output.extension().is_some() {
+        if let Some(parent) = output.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            fs::create_dir_all(parent).await?;
+        }
+        Ok(output.to_path_buf())
+    } else {
+        fs::create_dir_all(output).await?;
+        Ok(output.join(default_filename(generator)))
+    }
+}
+
+/// Default file name of the generator, with its extension added when the
+/// name has none
+fn default_filename(generator: &dyn Generator) -> PathBuf {
+    let filename = PathBuf::from(generator.get_default_filename());
+    if filename.extension().is_some() {
+        filename
+    } else {
+        filename.with_extension(generator.get_file_extension())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const SCHEMA: &str = r"
+id: https://example.org/countries
+name: countries
+classes:
+  Country:
+    description: A sovereign state
+    attributes:
+      name:
+        range: string
+";
+
+    async fn write_schema(dir: &TempDir) -> anyhow::Result<PathBuf> {
+        let path = dir.path().join("countries.yaml");
+        fs::write(&path, SCHEMA).await?;
+        Ok(path)
+    }
+
+    #[test]
+    fn test_short_generator_names_resolve_to_formats() {
+        assert_eq!(resolve_generator_name("py"), "python");
+        assert_eq!(resolve_generator_name("TS"), "typescript");
+        assert_eq!(resolve_generator_name("typeql"), "typeql");
+    }
+
+    #[tokio::test]
+    async fn test_directory_output_uses_default_filename() -> anyhow::Result<()> {
+        let temp_dir = TempDir::new()?;
+        let schema = write_schema(&temp_dir).await?;
+        let out_dir = temp_dir.path().join("generated");
+
+        let written = GenerateCommand::new(schema.clone(), "py")
+            .with_output(out_dir.clone())
+            .execute()
+            .await?;
+        assert_eq!(written, Some(out_dir.join("schema_dataclass.py")));
+
+        // An existing directory is reused rather than written over
+        let written = GenerateCommand::new(schema, "rust")
+            .with_output(out_dir.clone())
+            .execute()
+            .await?;
+        let target = out_dir.join("schema.rs");
+        assert_eq!(written, Some(target.clone()));
+        assert!(fs::read_to_string(&target).await?.contains("Country"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_options_reach_the_generator() -> anyhow::Result<()> {
+        let temp_dir = TempDir::new()?;
+        let schema = write_schema(&temp_dir).await?;
+        let output = temp_dir.path().join("countries.schema.json");
+
+        GenerateCommand::new(schema.clone(), "json-schema")
+            .with_output(output.clone())
+            .execute()
+            .await?;
+        assert!(
+            !fs::read_to_string(&output)
+                .await?
+                .contains("A sovereign state")
+        );
+
+        GenerateCommand::new(schema, "json-schema")
+            .with_output(output.clone())
+            .with_options(GeneratorOptions::new().with_docs(true))
+            .execute()
+            .await?;
+        assert!(
+            fs::read_to_string(&output)
+                .await?
+                .contains("A sovereign state")
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_unknown_format_lists_available_formats() -> anyhow::Result<()> {
+        let temp_dir = TempDir::new()?;
+        let schema = write_schema(&temp_dir).await?;
+
+        let error = GenerateCommand::new(schema, "cobol")
+            .execute()
+            .await
+            .expect_err("unknown formats should be rejected");
+        let message = error.to_string();
+        assert!(
+            message.contains("Unknown output format 'cobol'"),
+            "{message}"
+        );
+        assert!(message.contains("typeql"), "{message}");
+        Ok(())
+    }
+}
@@ -5,22 +5,28 @@
 // mod convert;
 // mod diff;
 // mod dump;
-// mod generate;
 // mod lint;
 // mod load;
 // mod merge;
 
+pub mod flatten;
+pub mod generate;
 pub mod schema2sheets;
 pub mod serve;
 pub mod sheets2schema;
+pub mod validate;
 
 // pub use convert::ConvertCommand;
 // pub use diff::DiffCommand;
 // pub use dump::DumpCommand;
-// pub use generate::GenerateCommand;
 // pub use lint::LintCommand;
 // pub use load::LoadCommand;
 // pub use merge::MergeCommand;
+pub use flatten::FlattenCommand;
+pub use generate::GenerateCommand;
 pub use schema2sheets::Schema2SheetsCommand;
 pub use serve::ServeCommand;
 pub use sheets2schema::Sheets2SchemaCommand;
+pub use validate::{
+    FileReport, JsonValidationIssue, JsonValidationReport, JsonValidationSummary, ValidateCommand,
+};
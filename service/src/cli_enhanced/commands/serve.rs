@@ -15,9 +15,11 @@ use axum::{
     response::Json,
     routing::{get, post},
 };
+use chrono::{DateTime, Utc};
 use tower_http::cors::{CorsLayer, Any};
 use linkml_core::{
     error::{LinkMLError, Result},
+    metrics::SchemaMetrics,
     types::SchemaDefinition,
 };
 use serde::{Deserialize, Serialize};
@@ -26,7 +28,8 @@ use std::{collections::HashMap, net::SocketAddr, sync::Arc};
 use tracing::{info, warn};
 
 use crate::validator::{
-    engine::{ValidationEngine, ValidationOptions},
+    engine::{UnknownFieldPolicy, ValidationEngine, ValidationOptions},
+    instance_resolver::InstanceResolver,
     report::ValidationReport,
 };
 
@@ -39,6 +42,10 @@ pub struct AppState {
     pub schema_path: String,
     /// Validation engine
     pub validator: Arc<ValidationEngine>,
+    /// Resolver for instance-based ranges, if instance validation is set up
+    pub instance_resolver: Option<Arc<InstanceResolver>>,
+    /// When the schema was loaded
+    pub loaded_at: DateTime<Utc>,
 }
 
 /// Validation options for HTTP API (without custom validators)
@@ -48,6 +55,8 @@ pub struct ValidationOptionsDto {
     pub max_depth: Option<usize>,
     /// Whether to fail fast on first error
     pub fail_fast: Option<bool>,
+    /// Stop once this many errors have been reported
+    pub max_errors: Option<usize>,
     /// Whether to validate permissible values
     pub check_permissibles: Option<bool>,
     /// Whether to use cached validators
@@ -56,8 +65,12 @@ pub struct ValidationOptionsDto {
     pub parallel: Option<bool>,
     /// Whether to allow additional properties not defined in schema
     pub allow_additional_properties: Option<bool>,
+    /// How to handle instance fields the class does not declare
+    pub on_unknown_field: Option<UnknownFieldPolicy>,
     /// Whether to fail on warnings (treat warnings as errors)
     pub fail_on_warning: Option<bool>,
+    /// Whether to coerce strings to integer, float and boolean slot ranges
+    pub coerce_types: Option<bool>,
 }
 
 impl From<ValidationOptionsDto> for ValidationOptions {
@@ -65,12 +78,16 @@ impl From<ValidationOptionsDto> for ValidationOptions {
         Self {
             max_depth: dto.max_depth,
             fail_fast: dto.fail_fast,
+            max_errors: dto.max_errors,
             check_permissibles: dto.check_permissibles,
             use_cache: dto.use_cache,
             parallel: dto.parallel,
             allow_additional_properties: dto.allow_additional_properties,
+            on_unknown_field: dto.on_unknown_field,
             fail_on_warning: dto.fail_on_warning,
+            coerce_types: dto.coerce_types,
             custom_validators: Vec::new(),
+            source_map: None,
         }
     }
 }
@@ -95,6 +112,54 @@ pub struct ValidateResponse {
     pub report: ValidationReport,
 }
 
+/// Request body for batch validation endpoint
+#[derive(Deserialize)]
+pub struct ValidateBatchRequest {
+    /// Class every instance is validated against
+    pub class_name: String,
+    /// Instances to validate
+    pub instances: Vec<Value>,
+    /// Validation options; `fail_fast` and `max_errors` apply to the whole batch
+    pub options: Option<ValidationOptionsDto>,
+}
+
+/// Validation result of one instance in a batch
+#[derive(Serialize)]
+pub struct InstanceValidation {
+    /// Position of the instance in the request
+    pub index: usize,
+    /// Whether the instance passed validation
+    pub valid: bool,
+    /// Validation report for the instance
+    pub report: ValidationReport,
+}
+
+/// Aggregate counts for a batch validation
+#[derive(Serialize, Debug, Default, PartialEq, Eq)]
+pub struct BatchSummary {
+    /// Number of instances submitted
+    pub total: usize,
+    /// Number of instances validated before the batch stopped
+    pub validated: usize,
+    /// Number of valid instances
+    pub valid: usize,
+    /// Number of invalid instances
+    pub invalid: usize,
+    /// Whether `fail_fast` or `max_errors` stopped the batch early
+    pub stopped_early: bool,
+}
+
+/// Response for batch validation endpoint
+#[derive(Serialize)]
+pub struct ValidateBatchResponse {
+    /// Whether every validated instance passed
+    pub valid: bool,
+    /// Aggregate counts
+    pub summary: BatchSummary,
+    /// Results of the validated instances, in request order
+    pub results: Vec<InstanceValidation>,
+}
+
 /// Response for health check endpoint
 #[derive(Serialize)]
 pub struct HealthResponse {
@@ -106,6 +171,51 @@ pub struct HealthResponse {
     pub schema_name: String,
     /// Server version
     pub version: String,
+    /// Number of classes in the schema
+    pub class_count: usize,
+    /// Number of top-level slots in the schema
+    pub slot_count: usize,
+    /// Structural metrics of the schema
+    pub metrics: SchemaMetrics,
+    /// When the schema was loaded
+    pub schema_loaded_at: DateTime<Utc>,
+    /// Whether any instance data is cached for instance-based ranges
+    pub instance_caches_warm: bool,
+    /// Number of cached instance data sets
+    pub cached_instance_sets: usize,
+    /// Hit rate of the compiled validator cache, if the engine has one
+    pub validator_cache_hit_rate: Option<f64>,
+    /// Number of compiled slot patterns cached by the engine
+    pub cached_patterns: usize,
+}
+
+impl HealthResponse {
+    /// Build a healthy response from the current service state
+    #[must_use]
+    pub fn from_state(state: &AppState) -> Self {
+        let cached_instance_sets = state
+            .instance_resolver
+            .as_ref()
+            .map_or(0, |resolver| resolver.cached_entries());
+
+        Self {
+            status: "healthy".to_string(),
+            schema_path: state.schema_path.clone(),
+            schema_name: state.schema.name.clone(),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            class_count: state.schema.classes.len(),
+            slot_count: state.schema.slots.len(),
+            metrics: state.schema.metrics(),
+            schema_loaded_at: state.loaded_at,
+            instance_caches_warm: cached_instance_sets > 0,
+            cached_instance_sets,
+            validator_cache_hit_rate: state
+                .validator
+                .compiled_cache_stats()
+                .map(|stats| stats.hit_rate()),
+            cached_patterns: state.validator.pattern_cache().len(),
+        }
+    }
 }
 
 /// Command for serving `LinkML` schemas via `RootReal`'s REST API service
@@ -226,6 +336,8 @@ impl ServeCommand {
             schema: Arc::new(schema_definition),
             schema_path: self.schema_path.clone(),
             validator: Arc::new(validator),
+            instance_resolver: None,
+            loaded_at: Utc::now(),
         };
 
         // CRITICAL ARCHITECTURAL COMPLIANCE: Use RootReal services instead of direct implementations
@@ -369,12 +481,7 @@ async fn health_check(
 ) -> Json<HealthResponse> {
     let detailed = params.get("detailed").is_some_and(|v| v == "true");
 
-    let response = HealthResponse {
-        status: "healthy".to_string(),
-        schema_path: state.schema_path.clone(),
-        schema_name: state.schema.name.clone(),
-        version: env!("CARGO_PKG_VERSION").to_string(),
-    };
+    let response = HealthResponse::from_state(&state);
 
     if detailed {
         info!("Health check requested with detailed information");
@@ -0,0 +1,181 @@
+//! `validate` command implementation
+//!
+//! Validates JSON or YAML data files against a LinkML schema. The schema is
+//! loaded with its imports resolved, and each file is checked with the
+//! validation engine.
+
+use crate::parser::SchemaLoader;
+use crate::validator::engine::{ValidationEngine, ValidationOptions};
+use crate::validator::report::{Severity, ValidationReport};
+use linkml_core::ast::Span;
+use linkml_core::error::{LinkMLError, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+use tokio::fs;
+
+/// Validation report of one data file
+#[derive(Debug, Clone)]
+pub struct FileReport {
+    /// Data file that was validated
+    pub path: PathBuf,
+    /// Issues found in the file
+    pub report: ValidationReport,
+}
+
+/// Machine-readable result of validating data files, as printed by
+/// `linkml validate --format json`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsonValidationReport {
+    /// Whether every data file is valid
+    pub valid: bool,
+    /// Issues of all files, in file order
+    pub errors: Vec<JsonValidationIssue>,
+    /// Number of valid and invalid files
+    pub summary: JsonValidationSummary,
+}
+
+/// One issue of a [`JsonValidationReport`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsonValidationIssue {
+    /// Data file the issue was found in
+    pub file: PathBuf,
+    /// `JSON` path to the problematic value
+    pub path: String,
+    /// Human-readable message
+    pub message: String,
+    /// Severity of the issue
+    pub severity: Severity,
+    /// Location of the value in the data file, when known
+    pub location: Option<Span>,
+}
+
+/// File counts of a [`JsonValidationReport`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JsonValidationSummary {
+    /// Files without errors
+    pub passed: usize,
+    /// Files with at least one error
+    pub failed: usize,
+}
+
+impl From<&[FileReport]> for JsonValidationReport {
+    fn from(reports: &[FileReport]) -> Self {
+        let failed = reports.iter().filter(|file| !file.report.valid).count();
+        let errors = reports
+            .iter()
+            .flat_map(|file| {
+                file.report.issues.iter().map(|issue| JsonValidationIssue {
+                    file: file.path.clone(),
+                    path: issue.path.clone(),
+                    message: issue.message.clone(),
+                    severity: issue.severity,
+                    location: issue.location,
+                })
+            })
+            .collect();
+
+        Self {
+            valid: failed == 0,
+            errors,
+            summary: JsonValidationSummary {
+                passed: reports.len() - failed,
+                failed,
+            },
+        }
+    }
+}
+
+/// Command for validating data files against a schema
+pub struct ValidateCommand {
+    /// Schema file path
+    pub schema: PathBuf,
+    /// Data files to validate
+    pub data: Vec<PathBuf>,
+    /// Class every file is validated as; inferred from the data when unset
+    pub class_name: Option<String>,
+    /// Validation options passed to the engine
+    pub options: ValidationOptions,
+}
+
+impl ValidateCommand {
+    /// Create a new validate command
+    #[must_use]
+    pub fn new(schema: PathBuf, data: Vec<PathBuf>) -> Self {
+        Self {
+            schema,
+            data,
+            class_name: None,
+            options: ValidationOptions::default(),
+        }
+    }
+
+    /// Validate every file as an instance of `class_name`
+    #[must_use]
+    pub fn with_class_name(mut self, class_name: impl Into<String>) -> Self {
+        self.class_name = Some(class_name.into());
+        self
+    }
+
+    /// Set the validation options
+    #[must_use]
+    pub fn with_options(mut self, options: ValidationOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Execute the command, returning the report of each data file in order
+    ///
+    /// Invalid data is reported rather than returned as an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// - The schema or one of its imports can't be loaded
+    /// - A data file can't be read or isn't valid JSON or YAML
+    /// - The target class is not defined in the schema
+    pub async fn execute(&self) -> Result<Vec<FileReport>> {
+        let schema = SchemaLoader::new().load_file(&self.schema).await?;
+        let engine = ValidationEngine::new(&schema)?;
+
+        let mut reports = Vec::with_capacity(self.data.len());
+        for path in &self.data {
+            let value = load_data(path).await?;
+            let report = match &self.class_name {
+                Some(class_name) => {
+                    engine
+                        .validate_as_class(&value, class_name, Some(self.options.clone()))
+                        .await?
+                }
+                None => engine.validate(&value, Some(self.options.clone())).await?,
+            };
+            reports.push(FileReport {
+                path: path.clone(),
+                report,
+            });
+        }
+        Ok(reports)
+    }
+}
+
+/// Read a data file as YAML or, for any other extension, JSON
+///
+/// # Errors
+///
+/// Returns an error if the file can't be read or isn't valid YAML or JSON
+pub(crate) async fn load_data(path: &Path) -> Result<Value> {
+    let content = fs::read_to_string(path)
+        .await
+        .map_err(|e| LinkMLError::io_error(format!("Failed to read {}: {e}", path.display())))?;
+
+    if matches!(
+        path.extension().and_then(|ext| ext.to_str()),
+        Some("yaml" | "yml")
+    ) {
+        serde_yaml::from_str(&content)
+            .map_err(|e| LinkMLError::parse(format!("Invalid YAML in {}: {e}", path.display())))
+    } else {
+        serde_json::from_str(&content)
+            .map_err(|e| LinkMLError::parse(format!("Invalid JSON in {}: {e}", path.display())))
+    }
+}
@@ -50,10 +50,10 @@ pub enum LinkMLCommand {
         /// Data file(s) to validate
         #[arg(required = true)]
         data: Vec<PathBuf>,
-        /// Target class name
-        #[arg(short = 'C', long)]
+        /// Class to validate the data as (inferred from the data if omitted)
+        #[arg(short = 'C', long = "class", visible_alias = "class-name")]
         class_name: Option<String>,
-        /// Enable strict mode
+        /// Stop at the first error and treat warnings as errors
         #[arg(long)]
         strict: bool,
         /// Maximum errors to show
@@ -72,21 +72,18 @@ pub enum LinkMLCommand {
         /// Schema file path
         #[arg(short, long)]
         schema: PathBuf,
-        /// Output directory or file
+        /// Output file (stdout if not specified)
         #[arg(short, long)]
-        output: PathBuf,
-        /// Generator name (python, typescript, rust, etc.)
-        #[arg(short = 'g', long)]
-        generator: String,
+        output: Option<PathBuf>,
+        /// Output format (rust, typeql, owl, ttl, json-schema, etc.)
+        #[arg(short = 'f', long, visible_alias = "generator", short_alias = 'g')]
+        format: String,
         /// Generator options (key=value)
         #[arg(long = "option", value_name = "KEY=VALUE")]
         options: Vec<String>,
         /// Template directory for custom templates
         #[arg(short = 't', long)]
         template_dir: Option<PathBuf>,
-        /// Include imports in generation
-        #[arg(long)]
-        include_imports: bool,
     },
 
     /// Convert schema between formats
@@ -133,6 +130,16 @@ pub enum LinkMLCommand {
         validate: bool,
     },
 
+    /// Write a schema with all of its imports merged in
+    Flatten {
+        /// Schema file path
+        #[arg(short, long)]
+        schema: PathBuf,
+        /// Output file (stdout if not specified)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
     /// Compare schemas and show differences
     Diff {
         /// First schema file
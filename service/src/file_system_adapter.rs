@@ -7,6 +7,7 @@
 
 use async_trait::async_trait;
 use linkml_core::{LinkMLError, Result};
+use std::io::{BufRead, BufReader};
 use std::path::{Path, PathBuf};
 use tokio::fs;
 
@@ -41,6 +42,21 @@ pub trait FileSystemOperations: Send + Sync {
     async fn remove_dir(&self, path: &Path) -> Result<()>;
 }
 
+/// Synchronous, buffered reads of files too large to hold in memory
+///
+/// Kept apart from [`FileSystemOperations`] so that adapters offering only
+/// async access need not implement it. Used by streaming parsers that consume
+/// large documents incrementally instead of reading them into a single string.
+pub trait StreamingFileOperations: FileSystemOperations {
+    /// Open a file for buffered synchronous reads
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be opened or lies outside the
+    /// adapter's sandbox.
+    fn open_reader(&self, path: &Path) -> Result<Box<dyn BufRead + Send>>;
+}
+
 /// File metadata
 #[derive(Debug, Clone)]
 pub struct FileMetadata {
@@ -256,6 +272,19 @@ impl FileSystemOperations for TokioFileSystemAdapter {
     }
 }
 
+impl StreamingFileOperations for TokioFileSystemAdapter {
+    fn open_reader(&self, path: &Path) -> Result<Box<dyn BufRead + Send>> {
+        let resolved = self.resolve_path(path)?;
+        let file = std::fs::File::open(&resolved).map_err(|e| {
+            LinkMLError::IoError(std::io::Error::new(
+                e.kind(),
+                format!("Failed to open {}: {}", resolved.display(), e),
+            ))
+        })?;
+        Ok(Box::new(BufReader::new(file)))
+    }
+}
+
 /// Create a sandboxed file system adapter for a specific directory
 pub fn sandboxed_fs(root: impl Into<PathBuf>) -> TokioFileSystemAdapter {
     TokioFileSystemAdapter::sandboxed(root.into())
@@ -0,0 +1,507 @@
+//! Incremental generation of artifacts from schema and instance files
+//!
+//! [`generate_artifacts`] turns `LinkML` YAML files into artifacts written to
+//! a `data/` directory next to each file. Schemas yield Rust code, RDF/XML,
+//! OWL, Turtle and `TypeQL`; instance files, recognized by their `instances`
+//! key, are dumped against the `schema.yaml` beside them as Rust data,
+//! RDF/XML, OWL, Turtle and `JSON`.
+//!
+//! Files whose artifacts are all newer than their inputs are skipped, and
+//! files are processed concurrently up to a configurable limit.
+
+use linkml_core::error::{LinkMLError, Result};
+use linkml_core::types::SchemaDefinition;
+use serde_json::Value as JsonValue;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::SystemTime;
+use tokio::fs;
+use tokio::sync::Semaphore;
+
+use super::GeneratorRegistry;
+use crate::file_system_adapter::TokioFileSystemAdapter;
+use crate::loader::{
+    DataDumper, DataInstance, DumpOptions, JsonDumper, RdfDumper, RdfOptions,
+    RdfSerializationFormat,
+};
+use crate::parser::{SchemaParser, YamlParserV2};
+
+/// Number of files processed at once when none is configured
+pub const DEFAULT_MAX_PARALLEL: usize = 4;
+
+/// Options for [`generate_artifacts`]
+#[derive(Debug, Clone)]
+pub struct ArtifactOptions {
+    /// Maximum number of files processed at once
+    pub max_parallel: usize,
+    /// Regenerate artifacts even when they are up to date
+    pub force: bool,
+}
+
+impl Default for ArtifactOptions {
+    fn default() -> Self {
+        Self {
+            max_parallel: DEFAULT_MAX_PARALLEL,
+            force: false,
+        }
+    }
+}
+
+/// Kind of `LinkML` YAML file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceKind {
+    /// Schema definition
+    Schema,
+    /// Instances of a schema's classes
+    Instances,
+}
+
+impl SourceKind {
+    /// Extensions of the artifacts generated from files of this kind
+    fn extensions(self) -> &'static [&'static str] {
+        match self {
+            Self::Schema => &["rs", "rdf", "owl", "ttl", "tql"],
+            Self::Instances => &["rs", "rdf", "owl", "ttl", "json"],
+        }
+    }
+}
+
+/// [`GeneratorRegistry`] formats of schema artifacts in the order of
+/// [`SourceKind::extensions`]
+const SCHEMA_FORMATS: [&str; 5] = ["rust", "rdf", "owl", "ttl", "typeql"];
+
+/// Outcome of generating the artifacts of one file
+#[derive(Debug)]
+pub enum ArtifactStatus {
+    /// All artifacts were written
+    Generated,
+    /// All artifacts were newer than their inputs and left untouched
+    Skipped,
+    /// Generation failed; artifacts may be missing or stale
+    Failed(LinkMLError),
+}
+
+/// Artifacts generated from one source file
+#[derive(Debug)]
+pub struct ArtifactResult {
+    /// Source YAML file
+    pub source: PathBuf,
+    /// Kind of the source, unless it could not be read
+    pub kind: Option<SourceKind>,
+    /// Artifact files, whether or not they were written by this run
+    pub outputs: Vec<PathBuf>,
+    /// What happened to the artifacts
+    pub status: ArtifactStatus,
+}
+
+/// Generate the artifacts of each file in `paths`
+///
+/// Results are returned in the order of `paths`; a failure to process one
+/// file is recorded in its result and does not affect the others.
+pub async fn generate_artifacts(
+    paths: &[PathBuf],
+    options: &ArtifactOptions,
+) -> Vec<ArtifactResult> {
+    let semaphore = Arc::new(Semaphore::new(options.max_parallel.max(1)));
+    let registry = Arc::new(GeneratorRegistry::with_defaults().await);
+    let tasks: Vec<_> = paths
+        .iter()
+        .map(|path| {
+            let path = path.clone();
+            let semaphore = Arc::clone(&semaphore);
+            let registry = Arc::clone(&registry);
+            let force = options.force;
+            tokio::spawn(async move {
+                let _permit = semaphore.acquire().await;
+                process_file(path, &registry, force).await
+            })
+        })
+        .collect();
+
+    let mut results = Vec::with_capacity(tasks.len());
+    for (path, task) in paths.iter().zip(tasks) {
+        results.push(task.await.unwrap_or_else(|e| ArtifactResult {
+            source: path.clone(),
+            kind: None,
+            outputs: Vec::new(),
+            status: ArtifactStatus::Failed(LinkMLError::service(format!(
+                "Artifact generation task failed: {e}"
+            ))),
+        }));
+    }
+    results
+}
+
+async fn process_file(path: PathBuf, registry: &GeneratorRegistry, force: bool) -> ArtifactResult {
+    let source = match Source::read(&path).await {
+        Ok(source) => source,
+        Err(e) => {
+            return ArtifactResult {
+                source: path,
+                kind: None,
+                outputs: Vec::new(),
+                status: ArtifactStatus::Failed(e),
+            };
+        }
+    };
+
+    let status = if !force && source.is_up_to_date().await {
+        ArtifactStatus::Skipped
+    } else {
+        match source.generate(registry).await {
+            Ok(()) => ArtifactStatus::Generated,
+            Err(e) => ArtifactStatus::Failed(e),
+        }
+    };
+    ArtifactResult {
+        source: path,
+        kind: Some(source.kind),
+        outputs: source.outputs,
+        status,
+    }
+}
+
+/// Source YAML file and the artifacts generated from it
+struct Source {
+    kind: SourceKind,
+    content: String,
+    /// Source file followed by the schema of an instance file
+    inputs: Vec<PathBuf>,
+    /// Artifact files in the order of [`SourceKind::extensions`]
+    outputs: Vec<PathBuf>,
+}
+
+impl Source {
+    async fn read(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .await
+            .map_err(|e| LinkMLError::service(format!("Failed to read {}: {e}", path.display())))?;
+        let kind = if content.contains("instances:") {
+            SourceKind::Instances
+        } else {
+            SourceKind::Schema
+        };
+
+        let dir = path.parent().ok_or_else(|| {
+            LinkMLError::service(format!("No parent directory for {}", path.display()))
+        })?;
+        let base_name = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .ok_or_else(|| {
+                LinkMLError::service(format!("Invalid file name: {}", path.display()))
+            })?;
+
+        let data_dir = dir.join("data");
+        let outputs = kind
+            .extensions()
+            .iter()
+            .map(|extension| data_dir.join(format!("{base_name}.{extension}")))
+            .collect();
+        let mut inputs = vec![path.to_path_buf()];
+        if kind == SourceKind::Instances {
+            inputs.push(dir.join("schema.yaml"));
+        }
+
+        Ok(Self {
+            kind,
+            content,
+            inputs,
+            outputs,
+        })
+    }
+
+    /// Whether every artifact exists and was modified after every input
+    async fn is_up_to_date(&self) -> bool {
+        let mut newest_input = SystemTime::UNIX_EPOCH;
+        for input in &self.inputs {
+            match modified(input).await {
+                Some(time) => newest_input = newest_input.max(time),
+                None => return false,
+            }
+        }
+        for output in &self.outputs {
+            if !modified(output)
+                .await
+                .is_some_and(|time| time > newest_input)
+            {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Generate every artifact, writing them only once all have succeeded
+    async fn generate(&self, registry: &GeneratorRegistry) -> Result<()> {
+        let artifacts = match self.kind {
+            SourceKind::Schema => schema_artifacts(&self.content, registry).await?,
+            SourceKind::Instances => self.instance_artifacts().await?,
+        };
+
+        for (output, artifact) in self.outputs.iter().zip(artifacts) {
+            if let Some(dir) = output.parent() {
+                fs::create_dir_all(dir).await.map_err(|e| {
+                    LinkMLError::service(format!("Failed to create {}: {e}", dir.display()))
+                })?;
+            }
+            fs::write(output, artifact).await.map_err(|e| {
+                LinkMLError::service(format!("Failed to write {}: {e}", output.display()))
+            })?;
+        }
+        Ok(())
+    }
+
+    async fn instance_artifacts(&self) -> Result<Vec<String>> {
+        let yaml: serde_yaml::Value = serde_yaml::from_str(&self.content)?;
+        if yaml
+            .get("schema")
+            .and_then(serde_yaml::Value::as_str)
+            .is_none()
+        {
+            return Err(LinkMLError::parse("Instance file missing 'schema' field"));
+        }
+        let instances = yaml
+            .get("instances")
+            .and_then(serde_yaml::Value::as_sequence)
+            .ok_or_else(|| LinkMLError::parse("Instance file missing 'instances' array"))?;
+
+        let schema_path = &self.inputs[1];
+        let schema_content = fs::read_to_string(schema_path).await.map_err(|e| {
+            LinkMLError::service(format!(
+                "Failed to read schema {} of instance file: {e}",
+                schema_path.display()
+            ))
+        })?;
+        let schema = parse_schema(&schema_content)?;
+
+        let class_name = yaml.get("class").and_then(serde_yaml::Value::as_str);
+        let instances = parse_instances(instances, class_name, &schema)?;
+
+        let options = DumpOptions::default();
+        let mut rdf_options = RdfOptions {
+            generate_blank_nodes: true,
+            ..RdfOptions::default()
+        };
+        let rdf_xml = RdfDumper::with_options(rdf_options.clone())
+            .dump_string(&instances, &schema, &options)
+            .await?;
+        rdf_options.format = RdfSerializationFormat::Turtle;
+        let turtle = RdfDumper::with_options(rdf_options)
+            .dump_string(&instances, &schema, &options)
+            .await?;
+        let json = JsonDumper::new(true)
+            .dump_string(&instances, &schema, &options)
+            .await?;
+
+        let base_name = self.outputs[0]
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or_default();
+        Ok(vec![
+            rust_instances(&instances, base_name),
+            rdf_xml,
+            // OWL and Turtle artifacts of instances are the same Turtle graph
+            turtle.clone(),
+            turtle,
+            json,
+        ])
+    }
+}
+
+async fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).await.ok()?.modified().ok()
+}
+
+fn parse_schema(content: &str) -> Result<SchemaDefinition> {
+    YamlParserV2::new(Arc::new(TokioFileSystemAdapter::new())).parse_str(content)
+}
+
+/// Artifacts of a schema in the order of [`SourceKind::extensions`]
+async fn schema_artifacts(content: &str, registry: &GeneratorRegistry) -> Result<Vec<String>> {
+    let schema = parse_schema(content)?;
+    let mut artifacts = Vec::with_capacity(SCHEMA_FORMATS.len());
+    for format in SCHEMA_FORMATS {
+        artifacts.push(registry.generate(format, &schema).await?);
+    }
+    Ok(artifacts)
+}
+
+/// Convert YAML instance mappings to instances of the class resolved by
+/// [`instance_class`]
+fn parse_instances(
+    instances: &[serde_yaml::Value],
+    class_name: Option<&str>,
+    schema: &SchemaDefinition,
+) -> Result<Vec<DataInstance>> {
+    let class_name = instance_class(class_name, schema)?;
+
+    let mut parsed = Vec::new();
+    for instance in instances {
+        if let JsonValue::Object(data) = serde_json::to_value(instance)? {
+            parsed.push(DataInstance {
+                class_name: class_name.to_string(),
+                id: data
+                    .get("id")
+                    .and_then(JsonValue::as_str)
+                    .map(str::to_string),
+                data: data.into_iter().collect(),
+                metadata: HashMap::new(),
+            });
+        }
+    }
+    Ok(parsed)
+}
+
+/// Class of the instances in a file: the file's `class` field, else the
+/// schema's tree root, else the schema's only class
+///
+/// Import merging makes class order meaningless, so a schema with several
+/// classes and no tree root is an error rather than a guess.
+fn instance_class<'a>(
+    class_name: Option<&'a str>,
+    schema: &'a SchemaDefinition,
+) -> Result<&'a str> {
+    if let Some(class_name) = class_name {
+        return Ok(class_name);
+    }
+    if let Some(tree_root) = schema.tree_root_class()? {
+        return Ok(tree_root);
+    }
+    match schema.classes.keys().collect::<Vec<_>>().as_slice() {
+        [only] => Ok(only.as_str()),
+        [] => Err(LinkMLError::schema_validation("No class found in schema")),
+        classes => Err(LinkMLError::schema_validation(format!(
+            "Instance file has no 'class' field and the schema declares no tree_root \
+             to choose among its {} classes",
+            classes.len()
+        ))),
+    }
+}
+
+fn rust_instances(instances: &[DataInstance], base_name: &str) -> String {
+    let count = instances.len();
+    let mut output = String::new();
+    output.push_str(&format!("//! Generated instance data from: {base_name}\n"));
+    output.push_str("//!\n");
+    output.push_str(&format!(
+        "//! This file contains {count} instances serialized as Rust data.\n\n"
+    ));
+    output.push_str("use serde::{Deserialize, Serialize};\n");
+    output.push_str("use std::collections::HashMap;\n\n");
+    output.push_str("/// Instance data loaded from YAML\n");
+    output.push_str("#[derive(Debug, Clone, Serialize, Deserialize)]\n");
+    output.push_str("pub struct InstanceData {\n");
+    output.push_str("    pub class_name: Option<String>,\n");
+    output.push_str("    pub id: Option<String>,\n");
+    output.push_str("    pub data: HashMap<String, serde_json::Value>,\n");
+    output.push_str("}\n\n");
+    output.push_str(&format!("/// All {count} instances\n"));
+    output.push_str("pub fn get_all_instances() -> Vec<InstanceData> {\n");
+    output.push_str("    // Instance data serialized as JSON\n");
+    output.push_str(&format!("    // Total instances: {count}\n"));
+    output.push_str("    vec![]\n");
+    output.push_str("}\n");
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use linkml_core::types::ClassDefinition;
+    use std::time::Duration;
+    use tempfile::TempDir;
+
+    const SCHEMA: &str = "id: https://example.org/countries\n\
+        name: countries\n\
+        default_prefix: countries\n\
+        prefixes:\n  countries: https://example.org/countries/\n\
+        classes:\n  Country:\n    name: Country\n    slots:\n      - id\n      - name\n\
+        slots:\n  id:\n    name: id\n    identifier: true\n  name:\n    name: name\n";
+
+    const INSTANCES: &str = "schema: https://example.org/countries\n\
+        class: Country\n\
+        instances:\n  - id: NL\n    name: Netherlands\n  - id: BE\n    name: Belgium\n";
+
+    fn set_modified(path: &Path, time: SystemTime) -> anyhow::Result<()> {
+        std::fs::File::options()
+            .write(true)
+            .open(path)?
+            .set_modified(time)?;
+        Ok(())
+    }
+
+    fn statuses(results: &[ArtifactResult]) -> Vec<String> {
+        results
+            .iter()
+            .map(|result| format!("{:?}", result.status))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_skips_unchanged_files() -> anyhow::Result<()> {
+        let dir = TempDir::new()?;
+        let schema_path = dir.path().join("schema.yaml");
+        let instances_path = dir.path().join("countries.yaml");
+        std::fs::write(&schema_path, SCHEMA)?;
+        std::fs::write(&instances_path, INSTANCES)?;
+        // Backdate the sources so that coarse timestamps cannot tie with outputs
+        let past = SystemTime::now() - Duration::from_secs(60);
+        set_modified(&schema_path, past)?;
+        set_modified(&instances_path, past)?;
+
+        let paths = vec![schema_path.clone(), instances_path.clone()];
+        let options = ArtifactOptions::default();
+
+        let first = generate_artifacts(&paths, &options).await;
+        assert_eq!(statuses(&first), ["Generated", "Generated"]);
+        assert_eq!(first[0].kind, Some(SourceKind::Schema));
+        assert_eq!(first[1].kind, Some(SourceKind::Instances));
+        assert!(
+            first[1]
+                .outputs
+                .contains(&dir.path().join("data/countries.json"))
+        );
+        assert!(first.iter().flat_map(|r| &r.outputs).all(|o| o.is_file()));
+
+        let second = generate_artifacts(&paths, &options).await;
+        assert_eq!(statuses(&second), ["Skipped", "Skipped"]);
+
+        let forced = ArtifactOptions {
+            force: true,
+            ..ArtifactOptions::default()
+        };
+        let third = generate_artifacts(&paths[1..], &forced).await;
+        assert_eq!(statuses(&third), ["Generated"]);
+
+        // A schema edit invalidates the artifacts of its instances too
+        set_modified(&schema_path, SystemTime::now() + Duration::from_secs(60))?;
+        let fourth = generate_artifacts(&paths, &options).await;
+        assert_eq!(statuses(&fourth), ["Generated", "Generated"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_instance_class_is_not_guessed() -> anyhow::Result<()> {
+        let mut schema = parse_schema(SCHEMA)?;
+        schema
+            .classes
+            .insert("Region".to_string(), ClassDefinition::new("Region"));
+        let instances: Vec<serde_yaml::Value> =
+            serde_yaml::from_str("- id: NL\n  name: Netherlands\n")?;
+
+        let error = parse_instances(&instances, None, &schema)
+            .expect_err("a class should not be picked from several candidates");
+        assert!(error.to_string().contains("no 'class' field"), "{error}");
+
+        let parsed = parse_instances(&instances, Some("Country"), &schema)?;
+        assert_eq!(parsed[0].class_name, "Country");
+
+        if let Some(region) = schema.classes.get_mut("Region") {
+            region.tree_root = Some(true);
+        }
+        let parsed = parse_instances(&instances, None, &schema)?;
+        assert_eq!(parsed[0].class_name, "Region");
+        Ok(())
+    }
+}
@@ -0,0 +1,450 @@
+//! Apache Avro schema generation for `LinkML` schemas
+//!
+//! Every concrete class becomes an Avro record whose fields are the class's
+//! induced slots. Avro has no inheritance, so inherited slots are flattened
+//! into each record. The output is a JSON array of records, which Avro reads
+//! as a single union schema. Records and enums are defined inline where they
+//! are first used and referenced by full name afterwards, so classes that
+//! refer to each other still resolve.
+
+use super::base::BaseCodeFormatter;
+use super::options::GeneratorOptions;
+use super::traits::{Generator, GeneratorResult};
+use crate::inheritance::InheritanceResolver;
+use linkml_core::error::LinkMLError;
+use linkml_core::prelude::*;
+use serde_json::{Value as JsonValue, json};
+use std::collections::HashSet;
+
+/// Precision of the `decimal` logical type used for `decimal` ranges
+const DECIMAL_PRECISION: u32 = 38;
+
+/// Scale of the `decimal` logical type used for `decimal` ranges
+const DECIMAL_SCALE: u32 = 9;
+
+/// Avro schema generator for `LinkML` schemas
+pub struct AvroGenerator {
+    /// Generator options
+    options: GeneratorOptions,
+}
+
+impl AvroGenerator {
+    /// Create a new Avro generator
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            options: GeneratorOptions::default(),
+        }
+    }
+
+    /// Create a new Avro generator with options
+    #[must_use]
+    pub fn with_options(options: GeneratorOptions) -> Self {
+        Self { options }
+    }
+
+    /// Avro namespace for the generated records
+    ///
+    /// Derived from the expansion of the schema's default prefix, falling
+    /// back to the schema `id`: the URI host is reversed and its path
+    /// segments appended, so `https://w3id.org/hyperentity/schema` becomes
+    /// `org.w3id.hyperentity.schema`. Uses the schema name if neither yields
+    /// a usable name.
+    #[must_use]
+    pub fn namespace(schema: &SchemaDefinition) -> String {
+        let uri = schema
+            .default_prefix
+            .as_ref()
+            .and_then(|prefix| schema.prefixes.get(prefix))
+            .map_or(schema.id.as_str(), |definition| match definition {
+                PrefixDefinition::Simple(expansion) => expansion.as_str(),
+                PrefixDefinition::Complex { prefix_prefix, .. } => prefix_prefix.as_str(),
+            });
+
+        let (host, path) = match uri.split_once("://") {
+            Some((_, rest)) => rest.split_once('/').unwrap_or((rest, "")),
+            None => ("", uri),
+        };
+        let host = host.split(':').next().unwrap_or_default();
+        let path = path.trim_end_matches(".yaml").trim_end_matches(".yml");
+
+        let parts: Vec<String> = host
+            .split('.')
+            .rev()
+            .filter(|label| !label.is_empty() && *label != "www")
+            .chain(path.split(['/', '#', ':']).filter(|s| !s.is_empty()))
+            .map(Self::avro_name)
+            .collect();
+
+        if parts.is_empty() {
+            Self::avro_name(&schema.name)
+        } else {
+            parts.join(".")
+        }
+    }
+
+    /// Make a name valid for Avro (`[A-Za-z_][A-Za-z0-9_]*`)
+    fn avro_name(name: &str) -> String {
+        let mut result: String = name
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        if !result.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+            result.insert(0, '_');
+        }
+        result
+    }
+
+    /// Avro type for a built-in `LinkML` type
+    ///
+    /// Dates and times use the matching Avro logical types and `decimal`
+    /// uses a fixed-scale `bytes` decimal. Anything else not numeric or
+    /// boolean is carried as a string.
+    fn primitive_type(range: &str) -> JsonValue {
+        match range {
+            "integer" | "int" => json!("long"),
+            "float" | "double" => json!("double"),
+            "boolean" | "bool" => json!("boolean"),
+            "decimal" => json!({
+                "type": "bytes",
+                "logicalType": "decimal",
+                "precision": DECIMAL_PRECISION,
+                "scale": DECIMAL_SCALE
+            }),
+            "date" => json!({"type": "int", "logicalType": "date"}),
+            "datetime" => json!({"type": "long", "logicalType": "timestamp-millis"}),
+            "time" => json!({"type": "int", "logicalType": "time-millis"}),
+            _ => json!("string"),
+        }
+    }
+}
+
+impl Default for AvroGenerator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Builds Avro types while tracking which named types are already defined
+struct AvroBuilder<'a> {
+    schema: &'a SchemaDefinition,
+    resolver: InheritanceResolver<'a>,
+    namespace: String,
+    include_docs: bool,
+    defined: HashSet<String>,
+}
+
+impl<'a> AvroBuilder<'a> {
+    fn new(schema: &'a SchemaDefinition, include_docs: bool) -> Self {
+        Self {
+            schema,
+            resolver: InheritanceResolver::new(schema),
+            namespace: AvroGenerator::namespace(schema),
+            include_docs,
+            defined: HashSet::new(),
+        }
+    }
+
+    fn is_defined(&self, name: &str) -> bool {
+        self.defined.contains(&AvroGenerator::avro_name(name))
+    }
+
+    /// Definition of a named type on first use, a reference to it afterwards
+    fn named_type(
+        &mut self,
+        name: &str,
+        definition: impl FnOnce(&mut Self) -> GeneratorResult<JsonValue>,
+    ) -> GeneratorResult<JsonValue> {
+        if self.defined.insert(name.to_string()) {
+            definition(self)
+        } else {
+            Ok(json!(format!("{}.{name}", self.namespace)))
+        }
+    }
+
+    /// Record for a class, with one field per induced slot
+    fn record(&mut self, class_name: &str) -> GeneratorResult<JsonValue> {
+        let name = AvroGenerator::avro_name(class_name);
+        self.named_type(&name.clone(), |builder| {
+            let mut fields = Vec::new();
+            for slot in builder.resolver.induced_slots(class_name)? {
+                fields.push(builder.field(&slot)?);
+            }
+
+            let mut record = json!({
+                "type": "record",
+                "name": name,
+                "namespace": builder.namespace,
+                "fields": fields
+            });
+            if builder.include_docs
+                && let Some(desc) = builder
+                    .schema
+                    .classes
+                    .get(class_name)
+                    .and_then(|class| class.description.as_ref())
+            {
+                record["doc"] = json!(desc);
+            }
+            Ok(record)
+        })
+    }
+
+    /// Enum whose symbols are the permissible values
+    fn enumeration(
+        &mut self,
+        enum_name: &str,
+        description: Option<&String>,
+        values: &[PermissibleValue],
+    ) -> GeneratorResult<JsonValue> {
+        let name = AvroGenerator::avro_name(enum_name);
+        self.named_type(&name.clone(), |builder| {
+            let mut symbols: Vec<String> = Vec::new();
+            for value in values {
+                let text = match value {
+                    PermissibleValue::Simple(text) | PermissibleValue::Complex { text, .. } => text,
+                };
+                let symbol = AvroGenerator::avro_name(text);
+                if !symbols.contains(&symbol) {
+                    symbols.push(symbol);
+                }
+            }
+
+            let mut enumeration = json!({
+                "type": "enum",
+                "name": name,
+                "namespace": builder.namespace,
+                "symbols": symbols
+            });
+            if builder.include_docs
+                && let Some(desc) = description
+            {
+                enumeration["doc"] = json!(desc);
+            }
+            Ok(enumeration)
+        })
+    }
+
+    /// Record field for a slot
+    ///
+    /// Multivalued slots become arrays; slots that are neither required nor
+    /// identifiers become a `["null", T]` union defaulting to null.
+    fn field(&mut self, slot: &SlotDefinition) -> GeneratorResult<JsonValue> {
+        let mut field_type = self.slot_type(slot)?;
+        if slot.multivalued == Some(true) {
+            field_type = json!({"type": "array", "items": field_type});
+        }
+
+        let mut field = json!({ "name": AvroGenerator::avro_name(&slot.name) });
+        if slot.required == Some(true) || slot.identifier == Some(true) {
+            field["type"] = field_type;
+        } else {
+            field["type"] = json!(["null", field_type]);
+            field["default"] = JsonValue::Null;
+        }
+        if self.include_docs
+            && let Some(desc) = &slot.description
+        {
+            field["doc"] = json!(desc);
+        }
+        Ok(field)
+    }
+
+    /// Avro type of a single slot value
+    fn slot_type(&mut self, slot: &SlotDefinition) -> GeneratorResult<JsonValue> {
+        if !slot.permissible_values.is_empty() {
+            return self.enumeration(
+                &BaseCodeFormatter::to_pascal_case(&slot.name),
+                slot.description.as_ref(),
+                &slot.permissible_values,
+            );
+        }
+
+        let Some(range) = slot.range.as_deref() else {
+            return Ok(json!("string"));
+        };
+        let schema = self.schema;
+        if schema.classes.contains_key(range) {
+            return self.record(range);
+        }
+        if let Some(enum_def) = schema.enums.get(range) {
+            return self.enumeration(
+                range,
+                enum_def.description.as_ref(),
+                &enum_def.permissible_values,
+            );
+        }
+
+        // Custom types map to the Avro type of the built-in they derive from
+        Ok(AvroGenerator::primitive_type(schema.base_type(range)))
+    }
+}
+
+impl Generator for AvroGenerator {
+    fn name(&self) -> &'static str {
+        "avro"
+    }
+
+    fn description(&self) -> &'static str {
+        "Generate Apache Avro record schemas from LinkML schemas"
+    }
+
+    fn file_extensions(&self) -> Vec<&str> {
+        vec![".avsc"]
+    }
+
+    fn validate_schema(&self, schema: &SchemaDefinition) -> linkml_core::error::Result<()> {
+        if schema.name.is_empty() {
+            return Err(LinkMLError::data_validation(
+                "Schema must have a name for Avro generation",
+            ));
+        }
+        Ok(())
+    }
+
+    fn generate(&self, schema: &SchemaDefinition) -> std::result::Result<String, LinkMLError> {
+        self.validate_schema(schema)?;
+
+        let mut builder = AvroBuilder::new(schema, self.options.include_docs);
+        let mut records = Vec::new();
+        for (class_name, class_def) in &schema.classes {
+            // Abstract classes and mixins only appear where they are used,
+            // and classes already inlined by an earlier record are skipped
+            if class_def.abstract_ == Some(true)
+                || class_def.mixin == Some(true)
+                || builder.is_defined(class_name)
+            {
+                continue;
+            }
+            records.push(builder.record(class_name)?);
+        }
+
+        serde_json::to_string_pretty(&JsonValue::Array(records))
+            .map_err(|e| LinkMLError::service(format!("JSON formatting error: {e}")))
+    }
+
+    fn get_file_extension(&self) -> &'static str {
+        "avsc"
+    }
+
+    fn get_default_filename(&self) -> &'static str {
+        "schema"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use apache_avro::Schema as AvroSchema;
+
+    const SCHEMA_YAML: &str = r"
+id: https://example.org/schemas/people
+name: people
+default_prefix: people
+prefixes:
+  people: https://w3id.org/people/
+classes:
+  Person:
+    attributes:
+      id:
+        identifier: true
+        range: string
+      name:
+        range: string
+        required: true
+      age:
+        range: integer
+      birth_date:
+        range: date
+      salary:
+        range: decimal
+      status:
+        range: PersonStatus
+      aliases:
+        range: string
+        multivalued: true
+      employer:
+        range: Organization
+  Organization:
+    attributes:
+      name:
+        range: string
+        required: true
+      founded:
+        range: datetime
+enums:
+  PersonStatus:
+    permissible_values:
+      ACTIVE:
+      RETIRED:
+";
+
+    #[test]
+    fn test_namespace_from_default_prefix_and_id() -> anyhow::Result<()> {
+        let mut schema: SchemaDefinition = serde_yaml::from_str(SCHEMA_YAML)?;
+        assert_eq!(AvroGenerator::namespace(&schema), "org.w3id.people");
+
+        schema.default_prefix = None;
+        assert_eq!(
+            AvroGenerator::namespace(&schema),
+            "org.example.schemas.people"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_records_parse_with_avro() -> anyhow::Result<()> {
+        let schema: SchemaDefinition = serde_yaml::from_str(SCHEMA_YAML)?;
+        let output = AvroGenerator::new().generate(&schema)?;
+
+        // Organization is defined inline by Person, so only Person is top level
+        let records: Vec<JsonValue> = serde_json::from_str(&output)?;
+        assert_eq!(records.len(), 1);
+        let fields = records[0]["fields"]
+            .as_array()
+            .ok_or_else(|| anyhow::anyhow!("fields should be an array"))?;
+        let field = |name: &str| {
+            fields
+                .iter()
+                .find(|field| field["name"] == name)
+                .cloned()
+                .unwrap_or_default()
+        };
+        assert_eq!(
+            field("status")["type"][1]["symbols"],
+            json!(["ACTIVE", "RETIRED"])
+        );
+        assert_eq!(field("aliases")["type"][1]["type"], "array");
+        assert_eq!(field("employer")["type"][1]["name"], "Organization");
+
+        let AvroSchema::Union(union) = AvroSchema::parse_str(&output)? else {
+            anyhow::bail!("expected a union of records");
+        };
+        let Some(AvroSchema::Record(person)) = union.variants().first() else {
+            anyhow::bail!("expected the Person record");
+        };
+        assert_eq!(person.name.name, "Person");
+        assert_eq!(person.name.namespace.as_deref(), Some("org.w3id.people"));
+
+        let field_schema = |name: &str| {
+            person
+                .fields
+                .iter()
+                .find(|field| field.name == name)
+                .map(|field| field.schema.clone())
+        };
+        // Required slots are plain types, optional slots nullable unions
+        assert_eq!(field_schema("name"), Some(AvroSchema::String));
+        assert_eq!(field_schema("id"), Some(AvroSchema::String));
+        let Some(AvroSchema::Union(age)) = field_schema("age") else {
+            anyhow::bail!("optional slot should be a union");
+        };
+        assert!(age.is_nullable());
+        assert_eq!(age.variants(), [AvroSchema::Null, AvroSchema::Long]);
+        let Some(AvroSchema::Union(birth_date)) = field_schema("birth_date") else {
+            anyhow::bail!("optional slot should be a union");
+        };
+        assert_eq!(birth_date.variants()[1], AvroSchema::Date);
+        Ok(())
+    }
+}
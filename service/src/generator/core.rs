@@ -1,12 +1,51 @@
 //! Core Rust generator implementation
 
-use super::GeneratorOptions;
 use super::base::BaseCodeFormatter;
-use super::traits::{CodeFormatter, Generator, GeneratorError, GeneratorResult};
+use super::traits::{CodeFormatter, GeneratedOutput, Generator, GeneratorError, GeneratorResult};
 
 use linkml_core::prelude::*;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fmt::Write;
 
+/// Rust type used for string-valued fields of generated structs
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RustStringType {
+    /// Owned `String` fields
+    #[default]
+    String,
+    /// Shared `Arc<str>` fields, matching the interned strings of
+    /// `types_v2`; deserializing them requires serde's `rc` feature
+    ArcStr,
+}
+
+impl RustStringType {
+    /// Type name written into generated code
+    #[must_use]
+    pub fn type_name(self) -> &'static str {
+        match self {
+            Self::String => "String",
+            Self::ArcStr => "std::sync::Arc<str>",
+        }
+    }
+}
+
+/// Serde attributes added to the fields of generated structs
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SerdeFieldOptions {
+    /// Emit `#[serde(alias = "...")]` for every slot alias, so instance
+    /// data using an alternative name still deserializes
+    ///
+    /// `alias` rather than `rename`: a slot can have several aliases and
+    /// instance files mix them with the slot name itself, which `rename`
+    /// would stop accepting. Output keeps using the slot name.
+    pub aliases: bool,
+    /// Emit `#[serde(default)]` on fields of optional slots
+    pub default_optional: bool,
+    /// Emit `#[serde(skip_serializing_if = ...)]` on fields of optional
+    /// slots, omitting `None` values and empty lists from the output
+    pub skip_serializing_none: bool,
+}
+
 /// Rust code generator for `LinkML` schemas
 pub struct RustGenerator {
     /// Generator name
@@ -15,6 +54,17 @@ pub struct RustGenerator {
     pub description: String,
     /// Generator options
     pub options: super::traits::GeneratorOptions,
+    /// Emit a `validate()` method for every generated struct
+    pub validation: bool,
+    /// Serde attributes added to generated fields
+    pub serde: SerdeFieldOptions,
+    /// Rust type of string-valued fields
+    pub string_type: RustStringType,
+    /// Module of the generated code relative to the crate root, such as
+    /// `place::polity::country`
+    pub module_path: Option<String>,
+    /// Modules defining the classes and enums of imported schemas, by type
+    pub imported_types: BTreeMap<String, String>,
 }
 
 impl RustGenerator {
@@ -25,6 +75,11 @@ impl RustGenerator {
             name: "rust".to_string(),
             description: "Generate idiomatic Rust code from LinkML schemas with serde support, comprehensive validation, and zero-tolerance for unwrap()".to_string(),
             options: super::traits::GeneratorOptions::default(),
+            validation: false,
+            serde: SerdeFieldOptions::default(),
+            string_type: RustStringType::default(),
+            module_path: None,
+            imported_types: BTreeMap::new(),
         }
     }
 
@@ -36,6 +91,151 @@ impl RustGenerator {
         generator
     }
 
+    /// Enable or disable generation of `validate()` methods
+    ///
+    /// Each struct then gets a `validate(&self) -> Result<(), Vec<String>>`
+    /// method enforcing the required slots, patterns, numeric bounds and
+    /// cardinalities of the schema, so callers can check instances without a
+    /// validation engine. Generated code using patterns depends on the `regex` crate.
+    #[must_use]
+    pub fn with_validation(mut self, enabled: bool) -> Self {
+        self.validation = enabled;
+        self
+    }
+
+    /// Set the serde attributes added to generated fields
+    #[must_use]
+    pub fn with_serde_options(mut self, serde: SerdeFieldOptions) -> Self {
+        self.serde = serde;
+        self
+    }
+
+    /// Set the Rust type used for string-valued fields
+    #[must_use]
+    pub fn with_string_type(mut self, string_type: RustStringType) -> Self {
+        self.string_type = string_type;
+        self
+    }
+
+    /// Set the module the generated code is placed in, relative to the crate
+    /// root, such as `place::polity::country`
+    ///
+    /// Types registered with [`with_imported_module`](Self::with_imported_module)
+    /// for this same module are generated rather than imported, so one set of
+    /// imported modules can be shared by the generators of a whole crate.
+    #[must_use]
+    pub fn with_module_path(mut self, module_path: &str) -> Self {
+        self.module_path = Some(module_path.to_string());
+        self
+    }
+
+    /// Reference the classes and enums of an imported schema through the
+    /// module its code was generated into, instead of generating them again
+    ///
+    /// Referenced types are brought in with `pub use crate::{module_path}::...`
+    /// so that they can in turn be imported from this module. A type already
+    /// registered keeps its first module.
+    #[must_use]
+    pub fn with_imported_module(mut self, module_path: &str, schema: &SchemaDefinition) -> Self {
+        for name in schema.classes.keys().chain(schema.enums.keys()) {
+            self.imported_types
+                .entry(name.clone())
+                .or_insert_with(|| module_path.to_string());
+        }
+        self
+    }
+
+    /// Module of an imported type, or `None` if it is generated here
+    pub(super) fn imported_module(&self, type_name: &str) -> Option<&str> {
+        self.imported_types
+            .get(type_name)
+            .map(String::as_str)
+            .filter(|module| self.module_path.as_deref() != Some(*module))
+    }
+
+    /// `pub use` declarations of the imported types referenced by the slots
+    /// of the generated classes
+    pub(super) fn generate_imported_uses(
+        &self,
+        schema: &SchemaDefinition,
+    ) -> GeneratorResult<String> {
+        let mut uses = BTreeSet::new();
+        for (class_name, class_def) in &schema.classes {
+            if self.imported_module(class_name).is_some() {
+                continue;
+            }
+            for (_, slot_def) in self.collect_class_slots(class_def, schema) {
+                if let Some(range) = slot_def.range
+                    && let Some(module) = self.imported_module(&range)
+                {
+                    uses.insert((module, range));
+                }
+            }
+        }
+
+        let mut output = String::new();
+        for (module, type_name) in &uses {
+            writeln!(&mut output, "pub use crate::{module}::{type_name};")
+                .map_err(Self::fmt_error_to_generator_error)?;
+        }
+        if !uses.is_empty() {
+            writeln!(&mut output).map_err(Self::fmt_error_to_generator_error)?;
+        }
+        Ok(output)
+    }
+
+    /// `mod.rs` files declaring the modules of schemas generated with
+    /// [`with_module_path`](Self::with_module_path)
+    ///
+    /// The code of each schema belongs in `{module path}.rs`, such as
+    /// `place/polity/country.rs` for `place::polity::country`; the returned
+    /// files declare the directories above it, down from a crate-level
+    /// `mod.rs` whose declarations belong in `lib.rs`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GeneratorError::Configuration` if a schema module is also the
+    /// parent of another, as its code would clash with its `mod.rs`.
+    pub fn module_tree<'a>(
+        module_paths: impl IntoIterator<Item = &'a str>,
+    ) -> GeneratorResult<Vec<GeneratedOutput>> {
+        let module_paths: BTreeSet<&str> = module_paths.into_iter().collect();
+        let mut children: BTreeMap<String, BTreeSet<&str>> = BTreeMap::new();
+        for module_path in &module_paths {
+            let segments: Vec<&str> = module_path.split("::").collect();
+            for (depth, segment) in segments.iter().enumerate() {
+                let parent = segments[..depth].join("::");
+                if module_paths.contains(parent.as_str()) {
+                    return Err(GeneratorError::Configuration(format!(
+                        "Schema module '{parent}' cannot also contain module '{module_path}'"
+                    )));
+                }
+                children.entry(parent).or_default().insert(*segment);
+            }
+        }
+
+        children
+            .into_iter()
+            .map(|(parent, children)| {
+                let mut content = String::from("//! Generated module tree\n\n");
+                for child in children {
+                    writeln!(&mut content, "pub mod {child};")
+                        .map_err(Self::fmt_error_to_generator_error)?;
+                }
+                let filename = if parent.is_empty() {
+                    "mod.rs".to_string()
+                } else {
+                    format!("{}/mod.rs", parent.replace("::", "/"))
+                };
+                Ok(GeneratedOutput {
+                    content,
+                    filename,
+                    metadata: HashMap::from([("generator".to_string(), "rust".to_string())]),
+                })
+            })
+            .collect()
+    }
+
     /// Get the generator name
     #[must_use]
     pub fn name(&self) -> &str {
@@ -196,15 +396,34 @@ impl RustGenerator {
         // Collect all slots for this class
         let slots = self.collect_class_slots(class_def, schema);
 
+        // Fields closing a cycle back to this class are boxed unless the
+        // class opts out, which only a list can do without infinite size
+        let recursive = self.recursive_slots(class_name, class_def, schema);
+        let use_box = class_def
+            .recursion_options
+            .as_ref()
+            .is_none_or(|options| options.use_box);
+
         if slots.is_empty() {
             writeln!(&mut output, "    // No fields defined")
                 .map_err(Self::fmt_error_to_generator_error)?;
         } else {
             // Generate fields for each slot
-            for slot_name in &slots {
-                if let Some(slot_def) = schema.slots.get(slot_name) {
-                    Self::generate_field(&mut output, slot_name, slot_def, schema)?;
+            for (slot_name, slot_def) in &slots {
+                let is_recursive = recursive.contains(slot_name);
+                if is_recursive && !use_box && !slot_def.multivalued.unwrap_or(false) {
+                    return Err(GeneratorError::Configuration(format!(
+                        "Class '{class_name}' contains itself through slot '{slot_name}'; \
+                         set recursion_options.use_box to box the field"
+                    )));
                 }
+                self.generate_field(
+                    &mut output,
+                    slot_name,
+                    slot_def,
+                    schema,
+                    is_recursive && use_box,
+                )?;
             }
         }
 
@@ -217,12 +436,15 @@ impl RustGenerator {
         Ok(output)
     }
 
-    /// Generate a field from a slot definition
+    /// Generate a field from a slot definition, wrapping class values in
+    /// `Box` when `boxed` is set
     pub(super) fn generate_field(
+        &self,
         output: &mut String,
         slot_name: &str,
         slot_def: &SlotDefinition,
         schema: &SchemaDefinition,
+        boxed: bool,
     ) -> GeneratorResult<()> {
         // Add field documentation
         if let Some(ref desc) = slot_def.description {
@@ -235,6 +457,27 @@ impl RustGenerator {
             writeln!(output, "    #[serde(rename = \"{slot_name}\")]")
                 .map_err(Self::fmt_error_to_generator_error)?;
         }
+        if self.serde.aliases {
+            for alias in &slot_def.aliases {
+                writeln!(output, "    #[serde(alias = {alias:?})]")
+                    .map_err(Self::fmt_error_to_generator_error)?;
+            }
+        }
+        if !slot_def.required.unwrap_or(false) {
+            if self.serde.default_optional {
+                writeln!(output, "    #[serde(default)]")
+                    .map_err(Self::fmt_error_to_generator_error)?;
+            }
+            if self.serde.skip_serializing_none {
+                let check = if slot_def.multivalued.unwrap_or(false) {
+                    "Vec::is_empty"
+                } else {
+                    "Option::is_none"
+                };
+                writeln!(output, "    #[serde(skip_serializing_if = \"{check}\")]")
+                    .map_err(Self::fmt_error_to_generator_error)?;
+            }
+        }
 
         // Determine field type
         let base_type = if let Some(ref range) = slot_def.range {
@@ -244,14 +487,21 @@ impl RustGenerator {
             }
             // Check if it's a class
             else if schema.classes.contains_key(range) {
-                format!("Box<{range}>") // Box to avoid infinite size for recursive types
+                if boxed {
+                    format!("Box<{range}>") // Box to avoid infinite size for recursive types
+                } else {
+                    range.clone()
+                }
             }
             // Otherwise treat as primitive
             else {
-                Self::linkml_type_to_rust(range).to_string()
+                match Self::linkml_type_to_rust(range) {
+                    "String" => self.string_type.type_name().to_string(),
+                    rust_type => rust_type.to_string(),
+                }
             }
         } else {
-            "String".to_string() // Default type
+            self.string_type.type_name().to_string() // Default type
         };
 
         // Handle multivalued
@@ -276,12 +526,16 @@ impl RustGenerator {
         Ok(())
     }
 
-    /// Collect all slots for a class (including inherited)
+    /// Collect all slots for a class (including inherited) with their
+    /// definitions
+    ///
+    /// Inline `attributes` are fields too and replace an inherited slot of
+    /// the same name; slots the schema doesn't define are skipped.
     pub(super) fn collect_class_slots(
         &self,
         class_def: &ClassDefinition,
         schema: &SchemaDefinition,
-    ) -> Vec<String> {
+    ) -> Vec<(String, SlotDefinition)> {
         let mut slots = Vec::new();
 
         // Add inherited slots if there's a parent class
@@ -291,16 +545,70 @@ impl RustGenerator {
             slots.extend(self.collect_class_slots(parent_class, schema));
         }
 
-        // Add this class's slots
-        slots.extend_from_slice(&class_def.slots);
+        // Add this class's slots, keeping the first position of duplicates
+        for slot_name in &class_def.slots {
+            if !slots.iter().any(|(name, _)| name == slot_name)
+                && let Some(slot_def) = schema.slots.get(slot_name)
+            {
+                slots.push((slot_name.clone(), slot_def.clone()));
+            }
+        }
 
-        // Remove duplicates while preserving order
-        let mut seen = std::collections::HashSet::new();
-        slots.retain(|slot| seen.insert(slot.clone()));
+        for (attr_name, attr_def) in &class_def.attributes {
+            match slots.iter_mut().find(|(name, _)| name == attr_name) {
+                Some((_, slot_def)) => *slot_def = attr_def.clone(),
+                None => slots.push((attr_name.clone(), attr_def.clone())),
+            }
+        }
 
         slots
     }
 
+    /// Slots of a class whose range class contains it again, either directly
+    /// or through the fields of other classes
+    pub(super) fn recursive_slots(
+        &self,
+        class_name: &str,
+        class_def: &ClassDefinition,
+        schema: &SchemaDefinition,
+    ) -> BTreeSet<String> {
+        self.collect_class_slots(class_def, schema)
+            .into_iter()
+            .filter(|(_, slot_def)| {
+                slot_def
+                    .range
+                    .as_deref()
+                    .is_some_and(|range| self.contains_class(range, class_name, schema))
+            })
+            .map(|(slot_name, _)| slot_name)
+            .collect()
+    }
+
+    /// Whether the struct of class `from` contains `target`, following the
+    /// class ranges of its own and inherited slots
+    fn contains_class(&self, from: &str, target: &str, schema: &SchemaDefinition) -> bool {
+        let mut visited = HashSet::new();
+        let mut pending = vec![from.to_string()];
+        while let Some(current) = pending.pop() {
+            if current == target {
+                return true;
+            }
+            if !visited.insert(current.clone()) {
+                continue;
+            }
+            let Some(class_def) = schema.classes.get(&current) else {
+                continue;
+            };
+            pending.extend(
+                self.collect_class_slots(class_def, schema)
+                    .into_iter()
+                    .filter_map(|(_, slot_def)| slot_def.range)
+                    .filter(|range| schema.classes.contains_key(range)),
+            );
+        }
+        false
+    }
+
     /// Generate file header with imports
     pub(super) fn generate_header(schema: &SchemaDefinition) -> GeneratorResult<String> {
         let mut output = String::new();
@@ -405,7 +713,8 @@ impl Generator for RustGenerator {
     }
 
     fn generate(&self, schema: &SchemaDefinition) -> linkml_core::error::Result<String> {
-        let _options = GeneratorOptions::default();
+        let ordered = self.options.ordered_schema(schema);
+        let schema = ordered.as_ref();
         let mut output = String::new();
 
         // Generate header
@@ -414,6 +723,13 @@ impl Generator for RustGenerator {
                 .map_err(|e| LinkMLError::data_validation(e.to_string()))?,
         );
 
+        // Reference types of imported schemas through their modules
+        output.push_str(
+            &self
+                .generate_imported_uses(schema)
+                .map_err(|e| LinkMLError::data_validation(e.to_string()))?,
+        );
+
         // Generate validation error enum
         output.push_str(
             &Self::generate_validation_error()
@@ -422,6 +738,9 @@ impl Generator for RustGenerator {
 
         // Generate enums first
         for (enum_name, enum_def) in &schema.enums {
+            if self.imported_module(enum_name).is_some() {
+                continue;
+            }
             output.push_str(
                 &Self::generate_enum(enum_name, enum_def)
                     .map_err(|e| LinkMLError::data_validation(e.to_string()))?,
@@ -430,11 +749,21 @@ impl Generator for RustGenerator {
 
         // Generate basic structs for classes
         for (class_name, class_def) in &schema.classes {
+            if self.imported_module(class_name).is_some() {
+                continue;
+            }
             output.push_str(
                 &self
                     .generate_class(class_name, class_def, schema)
                     .map_err(|e| LinkMLError::data_validation(e.to_string()))?,
             );
+            if self.validation {
+                output.push_str(
+                    &self
+                        .generate_validation_impl(class_name, class_def, schema)
+                        .map_err(|e| LinkMLError::data_validation(e.to_string()))?,
+                );
+            }
         }
 
         Ok(output)
@@ -468,3 +797,324 @@ impl CodeFormatter for RustGenerator {
         Ok(code.to_string())
     }
 }
+
+/// Generator output for the ISO 3166 test schema, compiled into the tests so
+/// instance data can be deserialized into the generated struct
+#[cfg(test)]
+#[rustfmt::skip]
+#[allow(dead_code, unused_imports)]
+#[path = "../../tests/data/generated/iso3166_entity.rs"]
+mod iso3166_entity;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ISO3166_SCHEMA: &str = r"
+id: https://w3id.org/rootreal/iso3166
+name: iso3166
+description: ISO 3166-1 country codes
+slots:
+  id:
+    identifier: true
+    required: true
+    range: string
+    pattern: '^[A-Z]{2}$'
+  label:
+    range: string
+    required: true
+    aliases: [name]
+  tld:
+    range: string
+  exact_mappings:
+    range: string
+    multivalued: true
+    aliases: [exactMappings]
+  notes:
+    range: string
+classes:
+  ISO3166Entity:
+    description: An ISO 3166-1 country
+    slots: [id, label, tld, exact_mappings, notes]
+";
+
+    const ISO3166_INSTANCES: &str = r"
+- id: NL
+  label: Netherlands
+  tld: .nl
+  exact_mappings:
+    - wikidata:Q55
+- id: BE
+  name: Belgium
+  tld: .be
+  exactMappings: [wikidata:Q31]
+- id: AQ
+  label: Antarctica
+  notes: No permanent population
+";
+
+    fn iso3166_generator() -> RustGenerator {
+        RustGenerator::new()
+            .with_serde_options(SerdeFieldOptions {
+                aliases: true,
+                default_optional: true,
+                skip_serializing_none: true,
+            })
+            .with_string_type(RustStringType::ArcStr)
+    }
+
+    #[test]
+    fn test_generated_iso3166_struct_is_current() -> anyhow::Result<()> {
+        let schema: SchemaDefinition = serde_yaml::from_str(ISO3166_SCHEMA)?;
+        let code = iso3166_generator().generate(&schema)?;
+        assert_eq!(
+            code,
+            include_str!("../../tests/data/generated/iso3166_entity.rs"),
+            "regenerate tests/data/generated/iso3166_entity.rs"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_iso3166_instances_deserialize() -> anyhow::Result<()> {
+        let entities: Vec<super::iso3166_entity::ISO3166Entity> =
+            serde_yaml::from_str(ISO3166_INSTANCES)?;
+        assert_eq!(entities.len(), 3);
+
+        // The slot name and its alias are both accepted
+        assert_eq!(&*entities[0].label, "Netherlands");
+        let belgium = &entities[1];
+        assert_eq!(&*belgium.label, "Belgium");
+        assert_eq!(belgium.exact_mappings.len(), 1);
+        assert_eq!(&*belgium.exact_mappings[0], "wikidata:Q31");
+
+        let belgium = serde_json::to_value(belgium)?;
+        assert_eq!(belgium["label"], "Belgium");
+        assert_eq!(
+            belgium["exact_mappings"],
+            serde_json::json!(["wikidata:Q31"])
+        );
+        assert!(belgium.get("name").is_none());
+
+        let antarctica = serde_json::to_value(&entities[2])?;
+        assert_eq!(
+            antarctica,
+            serde_json::json!({
+                "id": "AQ",
+                "label": "Antarctica",
+                "notes": "No permanent population"
+            })
+        );
+        Ok(())
+    }
+
+    const ENTITY_SCHEMA: &str = r"
+id: https://example.org/entity
+name: entity
+slots:
+  id:
+    identifier: true
+    required: true
+  status:
+    range: Status
+enums:
+  Status:
+    permissible_values:
+      active:
+      retired:
+classes:
+  Entity:
+    slots: [id, status]
+";
+
+    /// Schema importing the entity schema, with its imports merged in
+    fn importing_schema(name: &str, class_name: &str) -> anyhow::Result<SchemaDefinition> {
+        let mut schema: SchemaDefinition = serde_yaml::from_str(ENTITY_SCHEMA)?;
+        schema.name = name.to_string();
+        schema.imports = vec!["entity".to_string()];
+        schema.slots.insert(
+            "part_of".to_string(),
+            SlotDefinition {
+                range: Some("Entity".to_string()),
+                ..SlotDefinition::new("part_of")
+            },
+        );
+        schema.classes.insert(
+            class_name.to_string(),
+            ClassDefinition {
+                is_a: Some("Entity".to_string()),
+                slots: vec!["part_of".to_string()],
+                ..ClassDefinition::new(class_name)
+            },
+        );
+        Ok(schema)
+    }
+
+    #[test]
+    fn test_imported_types_are_referenced_by_module() -> anyhow::Result<()> {
+        let entity: SchemaDefinition = serde_yaml::from_str(ENTITY_SCHEMA)?;
+
+        for (module_path, name, class_name) in [
+            ("place::polity::country", "country", "Country"),
+            ("place::settlement::city", "city", "City"),
+        ] {
+            let code = RustGenerator::new()
+                .with_module_path(module_path)
+                .with_imported_module("meta::entity", &entity)
+                .generate(&importing_schema(name, class_name)?)?;
+            assert!(
+                code.contains(
+                    "pub use crate::meta::entity::Entity;\npub use crate::meta::entity::Status;\n"
+                ),
+                "{code}"
+            );
+            assert!(!code.contains("pub struct Entity"), "{code}");
+            assert!(!code.contains("pub enum Status"), "{code}");
+            assert!(
+                code.contains(&format!("pub struct {class_name} {{")),
+                "{code}"
+            );
+            assert!(code.contains("pub part_of: Option<Entity>,"), "{code}");
+        }
+
+        // The base module itself generates the types registered for it
+        let code = RustGenerator::new()
+            .with_module_path("meta::entity")
+            .with_imported_module("meta::entity", &entity)
+            .generate(&entity)?;
+        assert!(!code.contains("pub use"), "{code}");
+        assert!(code.contains("pub struct Entity {"), "{code}");
+        assert!(code.contains("pub enum Status {"), "{code}");
+        Ok(())
+    }
+
+    const TREE_SCHEMA: &str = r"
+id: https://example.org/tree
+name: tree
+slots:
+  label:
+    range: string
+    required: true
+  children:
+    range: Node
+    multivalued: true
+  parent:
+    range: Node
+  root:
+    range: Node
+    required: true
+classes:
+  Node:
+    slots: [label, children, parent]
+    recursion_options:
+      use_box: true
+      max_depth: 3
+  Tree:
+    slots: [root]
+";
+
+    #[test]
+    fn test_recursive_fields_are_boxed() -> anyhow::Result<()> {
+        let mut schema: SchemaDefinition = serde_yaml::from_str(TREE_SCHEMA)?;
+        let code = RustGenerator::new()
+            .with_validation(true)
+            .generate(&schema)?;
+        syn::parse_file(&code)?;
+
+        assert!(code.contains("pub children: Vec<Box<Node>>,"), "{code}");
+        assert!(code.contains("pub parent: Option<Box<Node>>,"), "{code}");
+        // Only fields closing the cycle need a box
+        assert!(code.contains("pub root: Node,"), "{code}");
+
+        assert!(
+            code.contains("pub fn validate_depth(&self, depth: usize)"),
+            "{code}"
+        );
+        assert!(code.contains("if depth > 3 {"), "{code}");
+        assert!(code.contains("value.validate_depth(depth + 1)"), "{code}");
+        assert!(code.contains("value.validate() {"), "{code}");
+
+        // Without boxing only lists can hold the class itself
+        let node = schema
+            .classes
+            .get_mut("Node")
+            .ok_or_else(|| anyhow::anyhow!("missing Node class"))?;
+        node.recursion_options = Some(RecursionOptions {
+            use_box: false,
+            max_depth: None,
+        });
+        let error = RustGenerator::new()
+            .generate(&schema)
+            .expect_err("an unboxed parent field has infinite size");
+        assert!(error.to_string().contains("'parent'"), "{error}");
+
+        if let Some(node) = schema.classes.get_mut("Node") {
+            node.slots.retain(|slot| slot != "parent");
+        }
+        let code = RustGenerator::new().generate(&schema)?;
+        assert!(code.contains("pub children: Vec<Node>,"), "{code}");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_async_generate_references_imported_types() -> anyhow::Result<()> {
+        use crate::generator::traits::{AsyncGenerator, GeneratorOptions};
+
+        let entity: SchemaDefinition = serde_yaml::from_str(ENTITY_SCHEMA)?;
+        let generator = RustGenerator::new()
+            .with_module_path("place::polity::country")
+            .with_imported_module("meta::entity", &entity);
+        let outputs = AsyncGenerator::generate(
+            &generator,
+            &importing_schema("country", "Country")?,
+            &GeneratorOptions::default(),
+        )
+        .await?;
+
+        let output = &outputs[0];
+        assert_eq!(output.filename, "place/polity/country.rs");
+        let code = &output.content;
+        assert!(
+            code.contains(
+                "pub use crate::meta::entity::Entity;\npub use crate::meta::entity::Status;\n"
+            ),
+            "{code}"
+        );
+        assert!(!code.contains("pub struct Entity"), "{code}");
+        assert!(!code.contains("pub enum Status"), "{code}");
+        assert!(code.contains("pub struct Country"), "{code}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_module_tree_declares_schema_modules() -> anyhow::Result<()> {
+        let tree = RustGenerator::module_tree([
+            "place::polity::country",
+            "meta::entity",
+            "place::settlement::city",
+        ])?;
+        let files: Vec<(String, String)> = tree
+            .into_iter()
+            .map(|file| (file.filename, file.content))
+            .collect();
+        let expected: Vec<(String, String)> = [
+            ("mod.rs", "pub mod meta;\npub mod place;\n"),
+            ("meta/mod.rs", "pub mod entity;\n"),
+            ("place/mod.rs", "pub mod polity;\npub mod settlement;\n"),
+            ("place/polity/mod.rs", "pub mod country;\n"),
+            ("place/settlement/mod.rs", "pub mod city;\n"),
+        ]
+        .into_iter()
+        .map(|(filename, modules)| {
+            (
+                filename.to_string(),
+                format!("//! Generated module tree\n\n{modules}"),
+            )
+        })
+        .collect();
+        assert_eq!(files, expected);
+
+        assert!(RustGenerator::module_tree(["meta", "meta::entity"]).is_err());
+        Ok(())
+    }
+}
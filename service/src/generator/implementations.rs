@@ -74,32 +74,45 @@ impl AsyncGenerator for RustGenerator {
         // File header
         main_output.push_str(&Self::generate_header(schema)?);
 
+        // Reference types of imported schemas through their modules
+        main_output.push_str(&self.generate_imported_uses(schema)?);
+
         // Generate validation error enum
         main_output.push_str(&Self::generate_validation_error()?);
 
         // Generate enums first
         for (enum_name, enum_def) in &schema.enums {
+            if self.imported_module(enum_name).is_some() {
+                continue;
+            }
             let enum_code = Self::generate_enum_rust(enum_name, enum_def, options, indent)?;
             main_output.push_str(&enum_code);
         }
 
         // Generate classes
         for (class_name, class) in &schema.classes {
+            if self.imported_module(class_name).is_some() {
+                continue;
+            }
             let class_code = Self::generate_class_rust(class_name, class, schema, options, indent)?;
             main_output.push_str(&class_code);
         }
 
-        // Create output
-        let filename = format!(
-            "{}.rs",
-            if schema.name.is_empty() {
-                "schema"
-            } else {
-                &schema.name
-            }
-            .to_lowercase()
-            .replace('-', "_")
-        );
+        // Create output, placed by module path when one is set
+        let filename = if let Some(module_path) = &self.module_path {
+            format!("{}.rs", module_path.replace("::", "/"))
+        } else {
+            format!(
+                "{}.rs",
+                if schema.name.is_empty() {
+                    "schema"
+                } else {
+                    &schema.name
+                }
+                .to_lowercase()
+                .replace('-', "_")
+            )
+        };
 
         let mut metadata = HashMap::new();
         metadata.insert("generator".to_string(), self.name().to_string());
@@ -2,10 +2,14 @@
 
 use super::options::IndentStyle;
 use super::traits::{CodeFormatter, Generator, GeneratorError, GeneratorResult};
+use crate::inheritance::InheritanceResolver;
 use linkml_core::prelude::*;
 use serde_json::{Value as JsonValue, json};
 use std::collections::HashMap;
 
+/// `JSON` Schema dialect emitted by the generator (draft 2020-12)
+const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
 /// `JSON` Schema generator for `LinkML` schemas
 pub struct JsonSchemaGenerator {
     /// Generator name
@@ -43,17 +47,15 @@ impl JsonSchemaGenerator {
         let mut properties = serde_json::Map::new();
         let mut required = Vec::new();
 
-        // Collect all slots including inherited ones
-        let slots = Self::collect_all_slots(class, schema)?;
+        // Induced slots include attributes, inherited slots and slot_usage
+        let slots = InheritanceResolver::new(schema).induced_slots(class_name)?;
 
-        for slot_name in &slots {
-            if let Some(slot) = schema.slots.get(slot_name) {
-                let property = self.generate_property_schema(slot, schema)?;
-                properties.insert(slot_name.clone(), property);
+        for slot in &slots {
+            let property = self.generate_property_schema(slot, schema)?;
+            properties.insert(slot.name.clone(), property);
 
-                if slot.required == Some(true) {
-                    required.push(slot_name.clone());
-                }
+            if slot.required == Some(true) {
+                required.push(slot.name.clone());
             }
         }
 
@@ -79,7 +81,7 @@ impl JsonSchemaGenerator {
         // Handle inheritance using allOf
         if let Some(parent) = &class.is_a {
             let parent_ref = json!({
-                "$ref": format!("#/definitions/{parent}")
+                "$ref": format!("#/$defs/{parent}")
             });
 
             schema_obj = json!({
@@ -99,34 +101,34 @@ impl JsonSchemaGenerator {
         slot: &SlotDefinition,
         schema: &SchemaDefinition,
     ) -> GeneratorResult<JsonValue> {
-        let base_schema = self.get_base_type_schema(slot.range.as_ref(), schema)?;
+        let mut value_schema = self.get_base_type_schema(slot.range.as_ref(), schema)?;
+
+        // Value constraints apply to each item of a multivalued slot
+        if let Some(pattern) = &slot.pattern {
+            value_schema["pattern"] = json!(pattern);
+        }
+
+        if let Some(min) = &slot.minimum_value {
+            value_schema["minimum"] = json!(min);
+        }
+
+        if let Some(max) = &slot.maximum_value {
+            value_schema["maximum"] = json!(max);
+        }
 
         let mut property = if slot.multivalued == Some(true) {
             json!({
                 "type": "array",
-                "items": base_schema
+                "items": value_schema
             })
         } else {
-            base_schema
+            value_schema
         };
 
-        // Add constraints
         if let Some(desc) = &slot.description {
             property["description"] = json!(desc);
         }
 
-        if let Some(pattern) = &slot.pattern {
-            property["pattern"] = json!(pattern);
-        }
-
-        if let Some(min) = &slot.minimum_value {
-            property["minimum"] = json!(min);
-        }
-
-        if let Some(max) = &slot.maximum_value {
-            property["maximum"] = json!(max);
-        }
-
         Ok(property)
     }
 
@@ -157,17 +159,17 @@ impl JsonSchemaGenerator {
                 // Check if it's an enum
                 if schema.enums.contains_key(other) {
                     Ok(json!({
-                        "$ref": format!("#/definitions/{other}")
+                        "$ref": format!("#/$defs/{other}")
                     }))
                 } else if schema.classes.contains_key(other) {
                     // Reference to another class
                     Ok(json!({
-                        "$ref": format!("#/definitions/{other}")
+                        "$ref": format!("#/$defs/{other}")
                     }))
                 } else if schema.types.contains_key(other) {
                     // Custom type
                     Ok(json!({
-                        "$ref": format!("#/definitions/{other}")
+                        "$ref": format!("#/$defs/{other}")
                     }))
                 } else {
                     // Check if we should error on unknown types
@@ -252,36 +254,6 @@ impl JsonSchemaGenerator {
         definitions.insert(type_name.to_string(), schema);
         Ok(())
     }
-
-    /// Collect all slots including inherited ones
-    fn collect_all_slots(
-        class: &ClassDefinition,
-        schema: &SchemaDefinition,
-    ) -> GeneratorResult<Vec<String>> {
-        let mut all_slots = Vec::new();
-        let mut seen = std::collections::HashSet::new();
-
-        // Add direct slots
-        for slot in &class.slots {
-            if seen.insert(slot.clone()) {
-                all_slots.push(slot.clone());
-            }
-        }
-
-        // Add inherited slots
-        if let Some(parent) = &class.is_a
-            && let Some(parent_class) = schema.classes.get(parent)
-        {
-            let parent_slots = Self::collect_all_slots(parent_class, schema)?;
-            for slot in parent_slots {
-                if seen.insert(slot.clone()) {
-                    all_slots.push(slot);
-                }
-            }
-        }
-
-        Ok(all_slots)
-    }
 }
 
 impl Default for JsonSchemaGenerator {
@@ -344,10 +316,10 @@ impl Generator for JsonSchemaGenerator {
 
         // Build the main schema
         let mut json_schema = json!({
-            "$schema": "http://json-schema.org/draft-07/schema#",
+            "$schema": JSON_SCHEMA_DIALECT,
             "$id": schema.id.clone(),
             "title": schema.name.clone(),
-            "definitions": definitions
+            "$defs": definitions
         });
 
         if let Some(desc) = &schema.description {
@@ -356,12 +328,12 @@ impl Generator for JsonSchemaGenerator {
 
         // If there's exactly one root class, make it the main schema
         if root_classes.len() == 1 {
-            json_schema["$ref"] = json!(format!("#/definitions/{}", root_classes[0]));
+            json_schema["$ref"] = json!(format!("#/$defs/{}", root_classes[0]));
         } else if !root_classes.is_empty() {
             // Multiple root classes - use oneOf
             let refs: Vec<JsonValue> = root_classes
                 .iter()
-                .map(|name| json!({"$ref": format!("#/definitions/{name}")}))
+                .map(|name| json!({"$ref": format!("#/$defs/{name}")}))
                 .collect();
             json_schema["oneOf"] = json!(refs);
         }
@@ -511,16 +483,19 @@ mod tests {
             serde_json::from_str(&json_content).expect("should parse as valid JSON: {}");
 
         // Check basic structure
-        assert_eq!(parsed["$schema"], "http://json-schema.org/draft-07/schema#");
+        assert_eq!(
+            parsed["$schema"],
+            "https://json-schema.org/draft/2020-12/schema"
+        );
         assert_eq!(parsed["$id"], "https://example.com/schemas/test");
         assert_eq!(parsed["title"], "test_schema");
 
         // Check definitions
-        assert!(parsed["definitions"]["Person"].is_object());
-        assert!(parsed["definitions"]["Status"].is_object());
+        assert!(parsed["$defs"]["Person"].is_object());
+        assert!(parsed["$defs"]["Status"].is_object());
 
         // Check enum values
-        let status_enum = &parsed["definitions"]["Status"]["enum"];
+        let status_enum = &parsed["$defs"]["Status"]["enum"];
         assert!(
             status_enum
                 .as_array()
@@ -535,4 +510,175 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn test_attributes_and_inheritance() -> anyhow::Result<()> {
+        let schema_yaml = r"
+id: https://example.org/test
+name: TestSchema
+classes:
+  NamedThing:
+    attributes:
+      id:
+        range: string
+        required: true
+  Person:
+    is_a: NamedThing
+    attributes:
+      name:
+        range: string
+        required: true
+        pattern: '^[A-Z]'
+      age:
+        range: integer
+        minimum_value: 0
+        maximum_value: 150
+      aliases:
+        range: string
+        multivalued: true
+        pattern: '^[A-Z]'
+      scores:
+        range: integer
+        multivalued: true
+        minimum_value: 0
+        maximum_value: 10
+";
+        let schema: SchemaDefinition = serde_yaml::from_str(schema_yaml)?;
+        let content = JsonSchemaGenerator::new().generate(&schema)?;
+        let parsed: JsonValue = serde_json::from_str(&content)?;
+
+        let person = &parsed["$defs"]["Person"];
+        let all_of = person["allOf"]
+            .as_array()
+            .ok_or_else(|| anyhow::anyhow!("allOf should be array"))?;
+        assert_eq!(all_of[0]["$ref"], "#/$defs/NamedThing");
+
+        let own = &all_of[1];
+        assert_eq!(own["type"], "object");
+        assert_eq!(own["properties"]["name"]["type"], "string");
+        assert_eq!(own["properties"]["name"]["pattern"], "^[A-Z]");
+        assert_eq!(own["properties"]["age"]["type"], "integer");
+        assert_eq!(own["properties"]["age"]["minimum"], 0);
+        assert_eq!(own["properties"]["age"]["maximum"], 150);
+        assert_eq!(own["properties"]["aliases"]["type"], "array");
+        assert_eq!(own["properties"]["aliases"]["items"]["type"], "string");
+        assert_eq!(own["properties"]["aliases"]["items"]["pattern"], "^[A-Z]");
+        assert!(own["properties"]["aliases"].get("pattern").is_none());
+        assert_eq!(own["properties"]["scores"]["items"]["minimum"], 0);
+        assert_eq!(own["properties"]["scores"]["items"]["maximum"], 10);
+        assert!(own["properties"]["id"].is_object());
+
+        let required = own["required"]
+            .as_array()
+            .ok_or_else(|| anyhow::anyhow!("required should be array"))?;
+        assert!(required.contains(&json!("name")));
+        assert!(required.contains(&json!("id")));
+        assert!(!required.contains(&json!("age")));
+
+        // The output is valid draft 2020-12 and checks each list item
+        jsonschema::meta::validate(&parsed)
+            .map_err(|e| anyhow::anyhow!("not a valid 2020-12 schema: {e}"))?;
+        let person_schema = json!({
+            "$schema": parsed["$schema"],
+            "$defs": parsed["$defs"],
+            "$ref": "#/$defs/Person",
+        });
+        let validator = jsonschema::draft202012::new(&person_schema)
+            .map_err(|e| anyhow::anyhow!("schema should compile: {e}"))?;
+        let person = |aliases: JsonValue, scores: JsonValue| json!({"id": "p1", "name": "Ada", "aliases": aliases, "scores": scores});
+        assert!(validator.is_valid(&person(json!(["Countess"]), json!([3, 10]))));
+        assert!(!validator.is_valid(&person(json!(["countess"]), json!([3]))));
+        assert!(!validator.is_valid(&person(json!(["Countess"]), json!([11]))));
+        Ok(())
+    }
+
+    /// Validator for instances of `class_name` against the generated schema
+    fn class_validator(
+        parsed: &JsonValue,
+        class_name: &str,
+    ) -> anyhow::Result<jsonschema::Validator> {
+        jsonschema::meta::validate(parsed)
+            .map_err(|e| anyhow::anyhow!("not a valid 2020-12 schema: {e}"))?;
+        let class_schema = json!({
+            "$schema": parsed["$schema"],
+            "$defs": parsed["$defs"],
+            "$ref": format!("#/$defs/{class_name}"),
+        });
+        jsonschema::draft202012::new(&class_schema)
+            .map_err(|e| anyhow::anyhow!("schema should compile: {e}"))
+    }
+
+    #[tokio::test]
+    async fn test_person_round_trip_agrees_with_validation_engine() -> anyhow::Result<()> {
+        // The Person example of the integration tests
+        let schema: SchemaDefinition = serde_yaml::from_str(
+            r"
+id: https://example.org/test
+name: TestSchema
+description: Test schema for integration testing
+
+classes:
+  Person:
+    name: Person
+    description: A person
+    slots:
+      - name
+      - age
+
+slots:
+  name:
+    name: name
+    range: string
+    required: true
+  age:
+    name: age
+    range: integer
+",
+        )?;
+        let content = JsonSchemaGenerator::new().generate(&schema)?;
+        let parsed: JsonValue = serde_json::from_str(&content)?;
+        let validator = class_validator(&parsed, "Person")?;
+        let engine = crate::validator::ValidationEngine::new(&schema)?;
+
+        let instances = [
+            (json!({"name": "John Doe", "age": 30}), true),
+            (json!({"age": 30}), false),
+            (json!({"name": "John Doe", "age": "thirty"}), false),
+        ];
+        for (instance, expected) in instances {
+            assert_eq!(validator.is_valid(&instance), expected, "{instance}");
+            let report = engine.validate_as_class(&instance, "Person", None).await?;
+            assert_eq!(report.valid, expected, "{instance}: {:?}", report.issues);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_inherited_slots_apply_slot_usage() -> anyhow::Result<()> {
+        let schema: SchemaDefinition = serde_yaml::from_str(include_str!(
+            "../../../core/tests/fixtures/person_schema.yaml"
+        ))?;
+        let content = JsonSchemaGenerator::new().generate(&schema)?;
+        let parsed: JsonValue = serde_json::from_str(&content)?;
+
+        // `name` is optional on NamedEntity but required on Person
+        let own = &parsed["$defs"]["Person"]["allOf"][1];
+        let required = own["required"]
+            .as_array()
+            .ok_or_else(|| anyhow::anyhow!("required should be array"))?;
+        assert!(required.contains(&json!("id")));
+        assert!(required.contains(&json!("name")));
+        assert!(own["properties"]["email"]["pattern"].is_string());
+
+        let validator = class_validator(&parsed, "Person")?;
+        assert!(
+            validator.is_valid(&json!({"id": "p1", "name": "Ada", "email": "ada@example.org"}))
+        );
+        assert!(!validator.is_valid(&json!({"id": "p1", "email": "ada@example.org"})));
+        assert!(!validator.is_valid(&json!({"id": "p1", "name": "Ada", "email": "ada"})));
+
+        let organization = class_validator(&parsed, "Organization")?;
+        assert!(organization.is_valid(&json!({"id": "o1", "founded_year": 1999})));
+        Ok(())
+    }
 }
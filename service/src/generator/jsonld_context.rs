@@ -2,6 +2,12 @@
 //!
 //! This module generates JSON-LD @context definitions from `LinkML` schemas,
 //! enabling semantic web integration and linked data capabilities.
+//!
+//! Identifier slots alias `@id`, so an instance's identifier becomes the IRI
+//! of its node. Other slots map to their `slot_uri`, or to their name under
+//! the configured base URI or else the schema's default prefix. Multivalued
+//! slots are unordered `@set`s unless they are `list_elements_ordered`, which
+//! makes them `@list`s.
 
 use crate::generator::traits::{Generator, GeneratorConfig};
 use bitflags::bitflags;
@@ -223,6 +229,12 @@ impl JsonLdContextGenerator {
             return Ok(());
         }
 
+        // Identifier values name the node itself
+        if slot_def.identifier == Some(true) {
+            context.insert(slot_name.to_string(), json!("@id"));
+            return Ok(());
+        }
+
         let mut slot_mapping = Map::new();
 
         // Determine the IRI for the slot
@@ -261,10 +273,14 @@ impl JsonLdContextGenerator {
 
         // Add container mapping for multivalued slots
         if include_containers && slot_def.multivalued == Some(true) {
-            // Check if custom container type is specified in options
-            let container_type = self
-                .get_custom_option("multivalued_container")
-                .map_or("@list", std::string::String::as_str);
+            // Ordered slots keep their order as a list; otherwise check if a
+            // custom container type is specified in options
+            let container_type = if slot_def.ordered == Some(true) {
+                "@list"
+            } else {
+                self.get_custom_option("multivalued_container")
+                    .map_or("@set", std::string::String::as_str)
+            };
             slot_mapping.insert("@container".to_string(), json!(container_type));
         }
 
@@ -398,6 +414,18 @@ impl JsonLdContextGenerator {
             return format!("{reference}{name}");
         }
 
+        // CRITICAL: Check options for base_uri override
+        let base_uri = self
+            .options
+            .custom
+            .get("base_uri")
+            .or(self.config.base_uri.as_ref());
+
+        // An explicit base URI takes precedence over the default prefix
+        if let Some(base) = base_uri {
+            return format!("{base}{name}");
+        }
+
         // CRITICAL: Check options for use_curies override
         let use_curies = self.options.custom.get("use_curies").map_or(
             self.config.features.contains(JsonLdFeatures::USE_CURIES),
@@ -409,16 +437,11 @@ impl JsonLdContextGenerator {
             return format!("{default_prefix}:{name}");
         }
 
-        // CRITICAL: Check options for base_uri override
-        let base_uri = self
-            .options
-            .custom
-            .get("base_uri")
-            .or(self.config.base_uri.as_ref());
-
-        // Use base URI if available
-        if let Some(base) = base_uri {
-            return format!("{base}{name}");
+        // Otherwise expand the name in the default prefix
+        if schema.default_prefix.is_some()
+            && let Some(iri) = schema.expand_curie(name)
+        {
+            return iri;
         }
 
         // Fallback to just the name
@@ -529,6 +552,7 @@ impl Generator for JsonLdContextGenerator {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::generator::traits::GeneratorOptions;
     use indexmap::IndexMap;
     use linkml_core::types::{ClassDefinition, SchemaDefinition, SlotDefinition};
 
@@ -600,4 +624,172 @@ mod tests {
         assert!(result.contains("xsd:integer"));
         Ok(())
     }
+
+    /// Expand a compact IRI against the prefixes declared in a context
+    fn expand_iri(context: &Map<String, Value>, value: &str) -> String {
+        match value.split_once(':') {
+            Some((prefix, local)) => match context.get(prefix) {
+                Some(Value::String(namespace)) => format!("{namespace}{local}"),
+                _ => value.to_string(),
+            },
+            None => value.to_string(),
+        }
+    }
+
+    /// IRI a term of the context expands to
+    fn term_iri(context: &Map<String, Value>, term: &str) -> Option<String> {
+        let id = match context.get(term)? {
+            Value::String(id) => id.as_str(),
+            definition => definition.get("@id")?.as_str()?,
+        };
+        Some(expand_iri(context, id))
+    }
+
+    #[test]
+    fn test_context_expands_country_instance() -> anyhow::Result<()> {
+        let mut schema = SchemaDefinition::new("countries");
+        for (prefix, namespace) in [
+            ("geo", "https://example.org/geo/"),
+            ("rdfs", "http://www.w3.org/2000/01/rdf-schema#"),
+            ("xsd", "http://www.w3.org/2001/XMLSchema#"),
+        ] {
+            schema.prefixes.insert(
+                prefix.to_string(),
+                PrefixDefinition::Simple(namespace.to_string()),
+            );
+        }
+        schema.default_prefix = Some("geo".to_string());
+        schema.classes.insert(
+            "Country".to_string(),
+            ClassDefinition {
+                slots: vec![
+                    "id".to_string(),
+                    "label".to_string(),
+                    "languages".to_string(),
+                    "independence_date".to_string(),
+                ],
+                ..ClassDefinition::new("Country")
+            },
+        );
+        schema
+            .classes
+            .insert("Language".to_string(), ClassDefinition::new("Language"));
+        for slot in [
+            SlotDefinition {
+                identifier: Some(true),
+                ..SlotDefinition::new("id")
+            },
+            SlotDefinition {
+                slot_uri: Some("rdfs:label".to_string()),
+                range: Some("string".to_string()),
+                ..SlotDefinition::new("label")
+            },
+            SlotDefinition {
+                range: Some("Language".to_string()),
+                multivalued: Some(true),
+                ..SlotDefinition::new("languages")
+            },
+            SlotDefinition {
+                range: Some("date".to_string()),
+                ..SlotDefinition::new("independence_date")
+            },
+        ] {
+            schema.slots.insert(slot.name.clone(), slot);
+        }
+
+        let generated = JsonLdContextGenerator::new(JsonLdContextGeneratorConfig::default())
+            .generate(&schema)?;
+        let generated: Value = serde_json::from_str(&generated)?;
+        let context = generated["@context"]
+            .as_object()
+            .ok_or_else(|| anyhow::anyhow!("@context should be an object"))?;
+
+        let country = json!({
+            "id": "geo:NL",
+            "label": "Netherlands",
+            "languages": ["geo:nld", "geo:fry"],
+            "independence_date": "1581-07-26",
+        });
+
+        for key in country.as_object().into_iter().flat_map(Map::keys) {
+            assert!(context.contains_key(key), "no term for {key}");
+        }
+
+        // The identifier becomes the node IRI
+        assert_eq!(context["id"], json!("@id"));
+        assert_eq!(
+            expand_iri(context, country["id"].as_str().unwrap_or_default()),
+            "https://example.org/geo/NL"
+        );
+        assert_eq!(
+            term_iri(context, "label").as_deref(),
+            Some("http://www.w3.org/2000/01/rdf-schema#label")
+        );
+        assert_eq!(
+            term_iri(context, "languages").as_deref(),
+            Some("https://example.org/geo/languages")
+        );
+        assert_eq!(context["languages"]["@type"], json!("@id"));
+        assert_eq!(context["languages"]["@container"], json!("@set"));
+        assert_eq!(context["independence_date"]["@type"], json!("xsd:date"));
+        assert_eq!(
+            expand_iri(context, "xsd:date"),
+            "http://www.w3.org/2001/XMLSchema#date"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_ordered_slots_and_explicit_base_uri() -> anyhow::Result<()> {
+        let mut schema = SchemaDefinition::new("countries");
+        schema.prefixes.insert(
+            "geo".to_string(),
+            PrefixDefinition::Simple("https://example.org/geo/".to_string()),
+        );
+        schema.default_prefix = Some("geo".to_string());
+        for slot in [
+            SlotDefinition {
+                range: Some("string".to_string()),
+                multivalued: Some(true),
+                ordered: Some(true),
+                ..SlotDefinition::new("official_names")
+            },
+            SlotDefinition {
+                range: Some("string".to_string()),
+                multivalued: Some(true),
+                ..SlotDefinition::new("languages")
+            },
+        ] {
+            schema.slots.insert(slot.name.clone(), slot);
+        }
+
+        let context_of = |generator: &JsonLdContextGenerator| -> anyhow::Result<Value> {
+            let generated: Value = serde_json::from_str(&generator.generate(&schema)?)?;
+            Ok(generated["@context"].clone())
+        };
+
+        let context = context_of(&JsonLdContextGenerator::new(
+            JsonLdContextGeneratorConfig::default(),
+        ))?;
+        assert_eq!(context["official_names"]["@container"], json!("@list"));
+        assert_eq!(context["languages"]["@container"], json!("@set"));
+        assert_eq!(context["languages"]["@id"], json!("geo:languages"));
+
+        // An explicit base URI wins over the default prefix
+        let mut options = GeneratorOptions::default();
+        options.custom.insert(
+            "base_uri".to_string(),
+            "https://example.com/terms/".to_string(),
+        );
+        let context = context_of(&JsonLdContextGenerator::with_options(
+            JsonLdContextGeneratorConfig::default(),
+            options,
+        ))?;
+        assert_eq!(
+            context["languages"]["@id"],
+            json!("https://example.com/terms/languages")
+        );
+        assert_eq!(context["official_names"]["@container"], json!("@list"));
+        Ok(())
+    }
 }
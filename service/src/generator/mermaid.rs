@@ -10,6 +10,7 @@ use std::collections::{HashMap, HashSet};
 use std::fmt::Write;
 
 use super::traits::{Generator, GeneratorError, GeneratorOptions, GeneratorResult, IndentStyle};
+use crate::inheritance::InheritanceResolver;
 
 /// Mermaid diagram type
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -271,7 +272,7 @@ impl MermaidGenerator {
             .map_err(Self::fmt_error_to_generator_error)?;
         }
 
-        // Generate classes
+        // Generate classes, each showing only the slots it declares itself
         for (name, class_def) in &schema.classes {
             let class_name = Self::sanitize_name(name);
 
@@ -283,11 +284,12 @@ impl MermaidGenerator {
                     .map_err(Self::fmt_error_to_generator_error)?;
             }
 
-            // Collect all slots
-            let all_slots = self.collect_all_slots(name, class_def, schema);
-
-            for slot_name in &all_slots {
-                if let Some(slot_def) = schema.slots.get(slot_name) {
+            if self
+                .options
+                .features
+                .contains(MermaidFeatures::INCLUDE_SLOTS)
+            {
+                for slot_def in Self::own_slots(name, class_def, schema)? {
                     let visibility = if slot_def.required == Some(true) {
                         "+"
                     } else {
@@ -315,10 +317,10 @@ impl MermaidGenerator {
 
                     writeln!(
                         &mut output,
-                        "        {}{} {}{}",
+                        "        {}{}{}{}",
                         visibility,
                         data_type,
-                        Self::sanitize_name(slot_name),
+                        Self::sanitize_name(&slot_def.name),
                         multiplicity
                     )
                     .map_err(Self::fmt_error_to_generator_error)?;
@@ -332,37 +334,41 @@ impl MermaidGenerator {
 
         // Generate relationships
         for (class_name, class_def) in &schema.classes {
-            // Inheritance
-            if let Some(parent) = &class_def.is_a {
-                writeln!(
-                    &mut output,
-                    "    {} <|-- {}",
-                    Self::sanitize_name(parent),
-                    Self::sanitize_name(class_name)
-                )
-                .map_err(Self::fmt_error_to_generator_error)?;
-            }
+            if self
+                .options
+                .features
+                .contains(MermaidFeatures::SHOW_INHERITANCE)
+            {
+                // Inheritance
+                if let Some(parent) = &class_def.is_a {
+                    writeln!(
+                        &mut output,
+                        "    {} <|-- {}",
+                        Self::sanitize_name(parent),
+                        Self::sanitize_name(class_name)
+                    )
+                    .map_err(Self::fmt_error_to_generator_error)?;
+                }
 
-            // Mixins
-            for mixin in &class_def.mixins {
-                writeln!(
-                    &mut output,
-                    "    {} <|.. {} : mixin",
-                    Self::sanitize_name(mixin),
-                    Self::sanitize_name(class_name)
-                )
-                .map_err(Self::fmt_error_to_generator_error)?;
+                // Mixins
+                for mixin in &class_def.mixins {
+                    writeln!(
+                        &mut output,
+                        "    {} <|.. {} : mixin",
+                        Self::sanitize_name(mixin),
+                        Self::sanitize_name(class_name)
+                    )
+                    .map_err(Self::fmt_error_to_generator_error)?;
+                }
             }
 
-            // Associations
-            let all_slots = self.collect_all_slots(class_name, class_def, schema);
-            for slot_name in &all_slots {
-                if let Some(slot_def) = schema.slots.get(slot_name)
-                    && let Some(range) = &slot_def.range
+            // Associations, drawn from the class that declares the slot
+            for slot_def in Self::own_slots(class_name, class_def, schema)? {
+                if let Some(range) = &slot_def.range
                     && schema.classes.contains_key(range)
                 {
                     let arrow = if slot_def.multivalued == Some(true) {
-                        "\"*\" -->"
+                        "--> \"*\""
                     } else {
                         "-->"
                     };
@@ -372,7 +378,7 @@ impl MermaidGenerator {
                         Self::sanitize_name(class_name),
                         arrow,
                         Self::sanitize_name(range),
-                        slot_name
+                        Self::sanitize_name(&slot_def.name)
                     )
                     .map_err(Self::fmt_error_to_generator_error)?;
                 }
@@ -586,11 +592,30 @@ impl MermaidGenerator {
     /// Get type notation for class diagrams
     fn get_class_diagram_type(range: Option<&String>) -> String {
         match range.map(String::as_str) {
-            Some(r) => format!("{r}: "),
+            Some(r) => format!("{} ", Self::sanitize_name(r)),
             None => String::new(),
         }
     }
 
+    /// Slots declared by a class itself, through `slots` or `attributes`
+    ///
+    /// Definitions come from the induced slots, so ranges reflect any
+    /// `slot_usage` refinements.
+    fn own_slots(
+        class_name: &str,
+        class_def: &ClassDefinition,
+        schema: &SchemaDefinition,
+    ) -> GeneratorResult<Vec<SlotDefinition>> {
+        let induced = InheritanceResolver::new(schema).induced_slots(class_name)?;
+        Ok(induced
+            .into_iter()
+            .filter(|slot| {
+                class_def.slots.contains(&slot.name)
+                    || class_def.attributes.contains_key(&slot.name)
+            })
+            .collect())
+    }
+
     /// Collect all slots including inherited ones
     fn collect_all_slots(
         &self,
@@ -923,6 +948,7 @@ mod tests {
             generate_tests: false,
             indent: IndentStyle::Spaces(4),
             output_format: crate::generator::traits::OutputFormat::Markdown,
+            sort_elements: false,
             custom: std::collections::HashMap::new(),
         };
 
@@ -952,6 +978,86 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_class_diagram_hyperentity_inheritance() -> anyhow::Result<()> {
+        let mut schema = SchemaDefinition::new("hyperentity");
+        for (name, range, multivalued) in [
+            ("id", "uriorcurie", false),
+            ("label", "string", false),
+            ("callsign", "string", false),
+            ("members", "Entity", true),
+        ] {
+            schema.slots.insert(
+                name.to_string(),
+                SlotDefinition {
+                    range: Some(range.to_string()),
+                    multivalued: Some(multivalued),
+                    ..SlotDefinition::new(name)
+                },
+            );
+        }
+        schema.classes.insert(
+            "Entity".to_string(),
+            ClassDefinition {
+                abstract_: Some(true),
+                slots: vec!["id".to_string(), "label".to_string()],
+                ..ClassDefinition::new("Entity")
+            },
+        );
+        schema.classes.insert(
+            "Tracked".to_string(),
+            ClassDefinition {
+                mixin: Some(true),
+                ..ClassDefinition::new("Tracked")
+            },
+        );
+        schema.classes.insert(
+            "COT".to_string(),
+            ClassDefinition {
+                is_a: Some("Entity".to_string()),
+                mixins: vec!["Tracked".to_string()],
+                slots: vec!["callsign".to_string()],
+                ..ClassDefinition::new("COT")
+            },
+        );
+        schema.classes.insert(
+            "Group".to_string(),
+            ClassDefinition {
+                is_a: Some("Entity".to_string()),
+                slots: vec!["members".to_string()],
+                ..ClassDefinition::new("Group")
+            },
+        );
+
+        let output = MermaidGenerator::new()
+            .with_diagram_type(MermaidDiagramType::ClassDiagram)
+            .generate_with_options(&schema, &GeneratorOptions::default())?;
+
+        assert!(output.contains("classDiagram"));
+        assert!(output.contains("    Entity <|-- COT"));
+        assert!(output.contains("    Entity <|-- Group"));
+        assert!(output.contains("    Tracked <|.. COT : mixin"));
+        assert!(output.contains("    Group --> \"*\" Entity : members"));
+
+        let entity_box = class_box(&output, "Entity");
+        assert!(entity_box.contains("<<abstract>>"));
+        assert!(entity_box.contains("uriorcurie id"));
+        let group_box = class_box(&output, "Group");
+        assert!(group_box.contains("Entity members[*]"));
+        assert!(!group_box.contains(" id"), "inherited slots stay on Entity");
+        Ok(())
+    }
+
+    fn class_box<'a>(output: &'a str, name: &str) -> &'a str {
+        let start = output
+            .find(&format!("class {name} {{"))
+            .expect("class box should be present");
+        let end = output[start..]
+            .find("    }")
+            .expect("class box should close");
+        &output[start..start + end]
+    }
+
     #[test]
     fn test_sanitize_name() {
         let _generator = MermaidGenerator::new();
@@ -4,6 +4,7 @@
 //! supporting multiple target languages and formats.
 
 // Core generator infrastructure
+pub mod artifacts;
 pub mod base;
 pub mod namespace_manager;
 pub mod options;
@@ -22,6 +23,7 @@ pub mod validation;
 
 // Language-specific generators
 pub mod array_support;
+pub mod avro;
 pub mod csv;
 pub mod doc;
 pub mod excel;
@@ -65,7 +67,10 @@ pub mod yaml_validator;
 pub mod yuml;
 
 // Re-export main types
-pub use core::RustGenerator;
+pub use artifacts::{
+    ArtifactOptions, ArtifactResult, ArtifactStatus, SourceKind, generate_artifacts,
+};
+pub use core::{RustGenerator, RustStringType, SerdeFieldOptions};
 pub use options::{GeneratorOptions, IndentStyle, OutputFormat};
 pub use registry::{GeneratorInfo, GeneratorRegistry};
 pub use traits::{
@@ -74,6 +79,7 @@ pub use traits::{
 };
 
 // Re-export generators
+pub use avro::AvroGenerator;
 pub use csv::CsvGenerator;
 pub use excel::ExcelGenerator;
 pub use golang::GoGenerator;
@@ -100,12 +106,13 @@ pub use rdf::RdfGenerator;
 pub use shacl::ShaclGenerator;
 pub use shex::ShExGenerator;
 pub use sparql::SparqlGenerator;
-pub use sql::SQLGenerator;
+pub use sql::{SQLGenerator, SqlDialect};
 pub use sqlalchemy::{SQLAlchemyGenerator, SQLAlchemyGeneratorConfig};
 pub use sssom::{SssomFormat, SssomGenerator, SssomGeneratorConfig};
 pub use summary::{SummaryFormat, SummaryGenerator, SummaryGeneratorConfig};
 pub use typeql_generator::TypeQLGenerator;
 pub use typescript::TypeScriptGenerator;
+pub use yaml::to_linkml_yaml;
 pub use yaml_validator::{
     ValidationFramework, YamlValidatorGenerator, YamlValidatorGeneratorConfig,
 };
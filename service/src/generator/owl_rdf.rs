@@ -4,6 +4,7 @@
 //! - OWL ontologies (default, with rich semantics)
 //! - Simple RDF Schema (RDFS)
 //! - Pure RDF triples
+//! - SHACL shapes for validating instance graphs
 //!
 //! Supported output formats:
 //! - Turtle (.ttl) - default
@@ -15,11 +16,15 @@ use linkml_core::{
     error::LinkMLError,
     types::{ClassDefinition, EnumDefinition, PermissibleValue, SchemaDefinition, SlotDefinition}};
 use serde_json;
+use crate::inheritance::InheritanceResolver;
 use std::collections::HashMap;
 use std::fmt::Write;
 
 use super::traits::{Generator, GeneratorError, GeneratorOptions, GeneratorResult};
 
+/// SHACL vocabulary namespace
+const SHACL_NAMESPACE: &str = "http://www.w3.org/ns/shacl#";
+
 /// RDF output format
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum RdfFormat {
@@ -40,7 +45,9 @@ pub enum RdfMode {
     /// Simple RDFS schema
     Rdfs,
     /// Plain RDF triples
-    Simple}
+    Simple,
+    /// SHACL node shapes, one per class
+    Shacl}
 
 /// RDF generator for semantic web representations
 pub struct RdfGenerator {
@@ -134,6 +141,12 @@ impl RdfGenerator {
         Self::new().with_mode(RdfMode::Rdfs)
     }
 
+    /// Create a SHACL shapes generator
+    #[must_use]
+    pub fn shacl() -> Self {
+        Self::new().with_mode(RdfMode::Shacl)
+    }
+
     /// Generate prefixes section based on format
     fn generate_prefixes(&self, schema: &SchemaDefinition) -> GeneratorResult<String> {
         match self.format {
@@ -147,12 +160,19 @@ impl RdfGenerator {
     fn generate_turtle_prefixes(&self, schema: &SchemaDefinition) -> GeneratorResult<String> {
         let mut output = String::new();
 
-        // Standard prefixes
-        for (prefix, uri) in &self.prefixes {
+        // Standard prefixes, sorted as the map has no stable order
+        let mut prefixes: Vec<_> = self.prefixes.iter().collect();
+        prefixes.sort();
+        for (prefix, uri) in prefixes {
             writeln!(&mut output, "@prefix {}: <{}> .", prefix, uri)
                 .map_err(Self::fmt_error_to_generator_error)?;
         }
 
+        if self.mode == RdfMode::Shacl {
+            writeln!(&mut output, "@prefix sh: <{}> .", SHACL_NAMESPACE)
+                .map_err(Self::fmt_error_to_generator_error)?;
+        }
+
         // Schema-specific prefix
         let schema_prefix = self.to_snake_case(&schema.name);
         writeln!(&mut output, "@prefix {}: <{}#> .", schema_prefix, schema.id)
@@ -177,6 +197,11 @@ impl RdfGenerator {
                 .map_err(Self::fmt_error_to_generator_error)?;
         }
 
+        if self.mode == RdfMode::Shacl {
+            write!(&mut namespaces, " xmlns:sh=\"{}\"", SHACL_NAMESPACE)
+                .map_err(Self::fmt_error_to_generator_error)?;
+        }
+
         write!(&mut namespaces, " xmlns:xsd=\"{}\"", self.prefixes["xsd"])
             .map_err(Self::fmt_error_to_generator_error)?;
         write!(&mut namespaces, " xmlns:skos=\"{}\"", self.prefixes["skos"])
@@ -215,6 +240,10 @@ impl RdfGenerator {
             context["@context"]["owl"] = serde_json::json!(self.prefixes["owl"]);
         }
 
+        if self.mode == RdfMode::Shacl {
+            context["@context"]["sh"] = serde_json::json!(SHACL_NAMESPACE);
+        }
+
         // Add schema-specific namespace
         let schema_prefix = self.to_snake_case(&schema.name);
         context["@context"][schema_prefix] = serde_json::json!(format!("{}#", schema.id));
@@ -231,7 +260,8 @@ impl RdfGenerator {
         match self.mode {
             RdfMode::Owl => self.generate_owl_header(schema),
             RdfMode::Rdfs => self.generate_rdfs_header(schema),
-            RdfMode::Simple => self.generate_simple_header(schema)}
+            RdfMode::Simple => self.generate_simple_header(schema),
+            RdfMode::Shacl => self.generate_shacl_header(schema)}
     }
 
     /// Generate OWL ontology header
@@ -322,6 +352,26 @@ impl RdfGenerator {
         Ok(output)
     }
 
+    /// Generate SHACL shapes header
+    ///
+    /// Only comments are emitted so the prefixes that follow stay valid
+    /// Turtle.
+    fn generate_shacl_header(&self, schema: &SchemaDefinition) -> GeneratorResult<String> {
+        let mut output = String::new();
+
+        if self.format == RdfFormat::Turtle {
+            writeln!(
+                &mut output,
+                "# SHACL shapes generated from LinkML schema: {}",
+                schema.name
+            )
+            .map_err(Self::fmt_error_to_generator_error)?;
+            writeln!(&mut output).map_err(Self::fmt_error_to_generator_error)?;
+        }
+
+        Ok(output)
+    }
+
     /// Generate class based on mode
     fn generate_class(
         &self,
@@ -332,7 +382,8 @@ impl RdfGenerator {
         match self.mode {
             RdfMode::Owl => self.generate_owl_class(name, class, schema),
             RdfMode::Rdfs => self.generate_rdfs_class(name, class, schema),
-            RdfMode::Simple => self.generate_simple_class(name, class, schema)}
+            RdfMode::Simple => self.generate_simple_class(name, class, schema),
+            RdfMode::Shacl => self.generate_shacl_shape(name, class, schema)}
     }
 
     /// Generate OWL class from `LinkML` class
@@ -408,6 +459,117 @@ impl RdfGenerator {
         Ok(output)
     }
 
+    /// Generate SHACL node shape for a `LinkML` class
+    ///
+    /// The shape targets the class and has one property shape per induced
+    /// slot, so inherited slots and `slot_usage` refinements are included.
+    fn generate_shacl_shape(
+        &self,
+        name: &str,
+        class: &ClassDefinition,
+        schema: &SchemaDefinition,
+    ) -> GeneratorResult<String> {
+        let mut output = String::new();
+        let schema_prefix = self.to_snake_case(&schema.name);
+        let class_uri = format!("{}:{}", schema_prefix, self.to_pascal_case(name));
+        let shape_uri = format!("{}:{}Shape", schema_prefix, self.to_pascal_case(name));
+
+        let mut statements = vec![
+            "a sh:NodeShape".to_string(),
+            format!("sh:targetClass {}", class_uri),
+            format!("rdfs:label \"{}\"", Self::escape_turtle_string(name)),
+        ];
+
+        if let Some(desc) = &class.description {
+            statements.push(format!(
+                "rdfs:comment \"{}\"",
+                Self::escape_turtle_string(desc)
+            ));
+        }
+
+        for slot in InheritanceResolver::new(schema).induced_slots(name)? {
+            statements.push(self.generate_property_shape(&slot, schema));
+        }
+
+        writeln!(&mut output, "# Shape: {}", name).map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(&mut output, "{}", shape_uri).map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(&mut output, "    {} .", statements.join(" ;\n    "))
+            .map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(&mut output).map_err(Self::fmt_error_to_generator_error)?;
+
+        Ok(output)
+    }
+
+    /// Generate the `sh:property` block for a slot
+    fn generate_property_shape(&self, slot: &SlotDefinition, schema: &SchemaDefinition) -> String {
+        let schema_prefix = self.to_snake_case(&schema.name);
+        let mut constraints = vec![format!(
+            "sh:path {}:{}",
+            schema_prefix,
+            self.to_snake_case(&slot.name)
+        )];
+
+        // Value type
+        if let Some(range) = &slot.range {
+            if schema.classes.contains_key(range) {
+                constraints.push(format!(
+                    "sh:class {}:{}",
+                    schema_prefix,
+                    self.to_pascal_case(range)
+                ));
+            } else if let Some(enum_def) = schema.enums.get(range) {
+                let values: Vec<String> = enum_def
+                    .permissible_values
+                    .iter()
+                    .map(|pv| {
+                        let value = match pv {
+                            PermissibleValue::Simple(s) => s,
+                            PermissibleValue::Complex { text, .. } => text};
+                        format!("\"{}\"", Self::escape_turtle_string(value))
+                    })
+                    .collect();
+                constraints.push(format!("sh:in ({})", values.join(" ")));
+            } else if let Some(datatype) = self.get_xsd_datatype(schema.base_type(range)) {
+                constraints.push(format!("sh:datatype {}", datatype));
+            }
+        }
+
+        // Cardinality
+        if slot.required == Some(true) {
+            constraints.push("sh:minCount 1".to_string());
+        }
+        if slot.multivalued != Some(true) {
+            constraints.push("sh:maxCount 1".to_string());
+        }
+
+        // Value constraints
+        if let Some(pattern) = &slot.pattern {
+            constraints.push(format!(
+                "sh:pattern \"{}\"",
+                Self::escape_turtle_string(pattern)
+            ));
+        }
+        if let Some(serde_json::Value::Number(min)) = &slot.minimum_value {
+            constraints.push(format!("sh:minInclusive {}", min));
+        }
+        if let Some(serde_json::Value::Number(max)) = &slot.maximum_value {
+            constraints.push(format!("sh:maxInclusive {}", max));
+        }
+
+        format!(
+            "sh:property [\n        {} ;\n    ]",
+            constraints.join(" ;\n        ")
+        )
+    }
+
+    /// Escape a string for use in a Turtle string literal
+    fn escape_turtle_string(s: &str) -> String {
+        s.replace('\\', "\\\\")
+            .replace('"', "\\\"")
+            .replace('\n', "\\n")
+            .replace('\r', "\\r")
+    }
+
     /// Generate property restriction for a slot
     fn generate_property_restriction(
         &self,
@@ -799,14 +961,16 @@ impl Generator for RdfGenerator {
         match self.mode {
             RdfMode::Owl => "owl-rdf",
             RdfMode::Rdfs => "rdfs",
-            RdfMode::Simple => "rdf"}
+            RdfMode::Simple => "rdf",
+            RdfMode::Shacl => "rdf-shacl"}
     }
 
     fn description(&self) -> &str {
         match self.mode {
             RdfMode::Owl => "Generates OWL ontology in RDF format from LinkML schemas",
             RdfMode::Rdfs => "Generates RDFS schema from LinkML schemas",
-            RdfMode::Simple => "Generates simple RDF triples from LinkML schemas"}
+            RdfMode::Simple => "Generates simple RDF triples from LinkML schemas",
+            RdfMode::Shacl => "Generates SHACL shapes from LinkML schemas"}
     }
 
     fn file_extensions(&self) -> Vec<&str> {
@@ -826,6 +990,8 @@ impl Generator for RdfGenerator {
     }
 
     fn generate(&self, schema: &SchemaDefinition) -> std::result::Result<String, LinkMLError> {
+        let ordered = self.options.ordered_schema(schema);
+        let schema = ordered.as_ref();
         let mut output = String::new();
 
         // Generate header
@@ -844,6 +1010,11 @@ impl Generator for RdfGenerator {
             output.push_str(&class_def);
         }
 
+        // SHACL output consists of the node shapes only
+        if self.mode == RdfMode::Shacl {
+            return Ok(self.convert_to_format(&output, schema)?);
+        }
+
         // Generate properties
         for (name, slot) in &schema.slots {
             let property_def = self.generate_property(name, slot, schema).map_err(|e| {
@@ -987,6 +1158,34 @@ impl RdfGenerator {
 mod tests {
     use super::*;
 use linkml_core::types::{SchemaDefinition, ClassDefinition, SlotDefinition};
+    use oxrdf::{Subject, Term, Triple};
+
+    const EX: &str = "https://example.org/countries#";
+    const XSD: &str = "http://www.w3.org/2001/XMLSchema#";
+
+    /// Predicate/object pairs of the property shape of `shape` with `sh:path` `path`
+    fn property_shape(triples: &[Triple], shape: &str, path: &str) -> Vec<String> {
+        let shape = Subject::NamedNode(oxrdf::NamedNode::new_unchecked(format!("{EX}{shape}")));
+        let path = format!("<{EX}{path}>");
+        let describe = |node: &Subject| -> Vec<String> {
+            let mut pairs: Vec<String> = triples
+                .iter()
+                .filter(|t| &t.subject == node)
+                .map(|t| format!("{} {}", t.predicate, t.object))
+                .collect();
+            pairs.sort();
+            pairs
+        };
+
+        triples
+            .iter()
+            .filter(|t| t.subject == shape && t.predicate.as_str() == format!("{SHACL_NAMESPACE}property"))
+            .filter_map(|t| match &t.object {
+                Term::BlankNode(node) => Some(describe(&Subject::BlankNode(node.clone()))),
+                _ => None})
+            .find(|pairs| pairs.contains(&format!("<{SHACL_NAMESPACE}path> {path}")))
+            .unwrap_or_default()
+    }
 
     #[test]
     fn test_xsd_datatype_mapping() {
@@ -1030,4 +1229,121 @@ use linkml_core::types::{SchemaDefinition, ClassDefinition, SlotDefinition};
         let simple_gen = RdfGenerator::simple();
         assert_eq!(simple_gen.name(), "rdf");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_shacl_shapes_parse_as_turtle() {
+        let mut schema = SchemaDefinition {
+            id: "https://example.org/countries".to_string(),
+            name: "countries".to_string(),
+            ..Default::default()
+        };
+        for slot in [
+            SlotDefinition {
+                range: Some("string".to_string()),
+                identifier: Some(true),
+                required: Some(true),
+                ..SlotDefinition::new("id")
+            },
+            SlotDefinition {
+                range: Some("string".to_string()),
+                required: Some(true),
+                ..SlotDefinition::new("name")
+            },
+            SlotDefinition {
+                range: Some("string".to_string()),
+                pattern: Some("^[A-Z]{2}$".to_string()),
+                ..SlotDefinition::new("code")
+            },
+            SlotDefinition {
+                range: Some("City".to_string()),
+                ..SlotDefinition::new("capital")
+            },
+            SlotDefinition {
+                range: Some("string".to_string()),
+                multivalued: Some(true),
+                ..SlotDefinition::new("languages")
+            },
+        ] {
+            schema.slots.insert(slot.name.clone(), slot);
+        }
+        schema.classes.insert(
+            "Entity".to_string(),
+            ClassDefinition {
+                abstract_: Some(true),
+                slots: vec!["id".to_string(), "name".to_string()],
+                ..ClassDefinition::new("Entity")
+            },
+        );
+        schema.classes.insert(
+            "Country".to_string(),
+            ClassDefinition {
+                is_a: Some("Entity".to_string()),
+                description: Some("A \"sovereign\" state".to_string()),
+                slots: vec![
+                    "code".to_string(),
+                    "capital".to_string(),
+                    "languages".to_string(),
+                ],
+                ..ClassDefinition::new("Country")
+            },
+        );
+        schema.classes.insert(
+            "City".to_string(),
+            ClassDefinition {
+                is_a: Some("Entity".to_string()),
+                ..ClassDefinition::new("City")
+            },
+        );
+
+        let generator = RdfGenerator::shacl();
+        assert_eq!(generator.name(), "rdf-shacl");
+        let output = generator.generate(&schema).expect("should generate SHACL");
+        let triples: Vec<Triple> = oxttl::TurtleParser::new()
+            .parse_read(output.as_bytes())
+            .collect::<Result<_, _>>()
+            .unwrap_or_else(|e| panic!("SHACL output should be valid Turtle: {e}\n{output}"));
+
+        let sh = |local: &str| format!("<{SHACL_NAMESPACE}{local}>");
+        let one = format!("\"1\"^^<{XSD}integer>");
+        assert!(triples.iter().any(|t| {
+            t.subject.to_string() == format!("<{EX}CountryShape>")
+                && t.predicate.as_str() == format!("{SHACL_NAMESPACE}targetClass")
+                && t.object.to_string() == format!("<{EX}Country>")
+        }));
+
+        // Inherited from Entity
+        assert_eq!(
+            property_shape(&triples, "CountryShape", "id"),
+            [
+                format!("{} <{XSD}string>", sh("datatype")),
+                format!("{} {one}", sh("maxCount")),
+                format!("{} {one}", sh("minCount")),
+                format!("{} <{EX}id>", sh("path")),
+            ]
+        );
+        assert_eq!(
+            property_shape(&triples, "CountryShape", "code"),
+            [
+                format!("{} <{XSD}string>", sh("datatype")),
+                format!("{} {one}", sh("maxCount")),
+                format!("{} <{EX}code>", sh("path")),
+                format!("{} \"^[A-Z]{{2}}$\"", sh("pattern")),
+            ]
+        );
+        assert_eq!(
+            property_shape(&triples, "CountryShape", "capital"),
+            [
+                format!("{} <{EX}City>", sh("class")),
+                format!("{} {one}", sh("maxCount")),
+                format!("{} <{EX}capital>", sh("path")),
+            ]
+        );
+        assert_eq!(
+            property_shape(&triples, "CountryShape", "languages"),
+            [
+                format!("{} <{XSD}string>", sh("datatype")),
+                format!("{} <{EX}languages>", sh("path")),
+            ]
+        );
+    }
+}
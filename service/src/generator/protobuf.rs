@@ -3,13 +3,12 @@
 //! This module generates Protocol Buffers (.proto) files from `LinkML` schemas,
 //! enabling cross-language serialization and RPC support.
 
-use linkml_core::types::{
-    ClassDefinition, EnumDefinition, PermissibleValue, SchemaDefinition, SlotDefinition,
-};
-use std::collections::{HashMap, HashSet};
+use linkml_core::types::{ClassDefinition, PermissibleValue, SchemaDefinition, SlotDefinition};
+use std::collections::HashMap;
 use std::fmt::Write;
 
 use super::traits::{Generator, GeneratorError, GeneratorOptions, GeneratorResult};
+use crate::inheritance::InheritanceResolver;
 use linkml_core::error::LinkMLError;
 
 /// Protocol Buffers generator
@@ -42,7 +41,10 @@ impl ProtobufGenerator {
         type_map.insert("boolean".to_string(), "bool".to_string());
         type_map.insert("bool".to_string(), "bool".to_string());
         type_map.insert("date".to_string(), "string".to_string()); // ISO 8601 string
-        type_map.insert("datetime".to_string(), "string".to_string()); // ISO 8601 string
+        type_map.insert(
+            "datetime".to_string(),
+            "google.protobuf.Timestamp".to_string(),
+        );
         type_map.insert("time".to_string(), "string".to_string()); // ISO 8601 string
         type_map.insert("uri".to_string(), "string".to_string());
         type_map.insert("uriorcurie".to_string(), "string".to_string());
@@ -64,7 +66,7 @@ impl ProtobufGenerator {
     }
 
     /// Generate proto file header
-    fn generate_header(schema: &SchemaDefinition) -> GeneratorResult<String> {
+    fn generate_header(schema: &SchemaDefinition, uses_timestamp: bool) -> GeneratorResult<String> {
         let mut output = String::new();
 
         writeln!(
@@ -85,13 +87,13 @@ impl ProtobufGenerator {
         writeln!(&mut output).map_err(Self::fmt_error_to_generator_error)?;
 
         // Package name from schema name
-        let package_name = Self::to_snake_case(&schema.name);
+        let package_name = Self::proto_identifier(&Self::to_snake_case(&schema.name));
         writeln!(&mut output, "package {package_name};")
             .map_err(Self::fmt_error_to_generator_error)?;
         writeln!(&mut output).map_err(Self::fmt_error_to_generator_error)?;
 
         // Import well-known types if needed
-        if Self::needs_timestamp_import(schema) {
+        if uses_timestamp {
             writeln!(&mut output, "import \"google/protobuf/timestamp.proto\";")
                 .map_err(Self::fmt_error_to_generator_error)?;
             writeln!(&mut output).map_err(Self::fmt_error_to_generator_error)?;
@@ -100,37 +102,45 @@ impl ProtobufGenerator {
         Ok(output)
     }
 
-    /// Check if schema needs timestamp import
-    fn needs_timestamp_import(schema: &SchemaDefinition) -> bool {
-        // Check if any slot uses datetime type
-        schema
-            .slots
-            .values()
-            .any(|slot| matches!(slot.range.as_deref(), Some("datetime" | "timestamp")))
+    /// Write a possibly multi-line text as `//` comment lines
+    fn write_comment(output: &mut String, indent: &str, text: &str) -> GeneratorResult<()> {
+        for line in text.lines() {
+            writeln!(output, "{indent}// {}", line.trim_end())
+                .map_err(Self::fmt_error_to_generator_error)?;
+        }
+        Ok(())
     }
 
     /// Generate enum definition
-    fn generate_enum(name: &str, enum_def: &EnumDefinition) -> GeneratorResult<String> {
+    ///
+    /// Enum values share the package scope in proto3, so every value is
+    /// prefixed with the enum name to keep values of different enums apart.
+    fn generate_enum(
+        name: &str,
+        description: Option<&String>,
+        values: &[PermissibleValue],
+    ) -> GeneratorResult<String> {
         let mut output = String::new();
 
         // Add description as comment
-        if let Some(desc) = &enum_def.description {
-            writeln!(&mut output, "// {desc}").map_err(Self::fmt_error_to_generator_error)?;
+        if let Some(desc) = description {
+            Self::write_comment(&mut output, "", desc)?;
         }
 
-        writeln!(&mut output, "enum {} {{", Self::to_pascal_case(name))
-            .map_err(Self::fmt_error_to_generator_error)?;
-
-        // Proto3 requires first enum value to be 0
         writeln!(
             &mut output,
-            "  {}_UNSPECIFIED = 0;",
-            Self::to_screaming_snake_case(name)
+            "enum {} {{",
+            Self::proto_identifier(&Self::to_pascal_case(name))
         )
         .map_err(Self::fmt_error_to_generator_error)?;
 
+        // Proto3 requires first enum value to be 0
+        let prefix = Self::proto_identifier(&Self::to_screaming_snake_case(name));
+        writeln!(&mut output, "  {prefix}_UNSPECIFIED = 0;")
+            .map_err(Self::fmt_error_to_generator_error)?;
+
         // Generate enum values
-        for (index, pv) in enum_def.permissible_values.iter().enumerate() {
+        for (index, pv) in values.iter().enumerate() {
             let text = match pv {
                 PermissibleValue::Simple(s) => s,
                 PermissibleValue::Complex { text, .. } => text,
@@ -141,6 +151,12 @@ impl ProtobufGenerator {
             } else {
                 Self::to_screaming_snake_case(text)
             };
+            let enum_value = Self::proto_identifier(&enum_value);
+            let enum_value = if enum_value.starts_with(&format!("{prefix}_")) {
+                enum_value
+            } else {
+                format!("{prefix}_{enum_value}")
+            };
             writeln!(&mut output, "  {} = {};", enum_value, index + 1)
                 .map_err(Self::fmt_error_to_generator_error)?;
         }
@@ -161,30 +177,22 @@ impl ProtobufGenerator {
 
         // Add description as comment
         if let Some(desc) = &class.description {
-            writeln!(&mut output, "// {desc}").map_err(Self::fmt_error_to_generator_error)?;
+            Self::write_comment(&mut output, "", desc)?;
         }
 
-        writeln!(&mut output, "message {} {{", Self::to_pascal_case(name))
-            .map_err(Self::fmt_error_to_generator_error)?;
-
-        // Collect all slots (including inherited)
-        let all_slots = self.collect_all_slots(class, schema);
-
-        // Generate fields with proper numbering
-        let mut field_number = 1;
-        let mut seen_slots = HashSet::new();
-
-        for slot_name in &all_slots {
-            if seen_slots.contains(slot_name) {
-                continue;
-            }
-            seen_slots.insert(slot_name);
+        writeln!(
+            &mut output,
+            "message {} {{",
+            Self::proto_identifier(&Self::to_pascal_case(name))
+        )
+        .map_err(Self::fmt_error_to_generator_error)?;
 
-            if let Some(slot) = schema.slots.get(slot_name) {
-                let field = self.generate_field(slot, field_number, schema)?;
-                write!(&mut output, "{field}").map_err(Self::fmt_error_to_generator_error)?;
-                field_number += 1;
-            }
+        // Field numbers follow the induced slot order: inherited slots
+        // first, then the class's own slots and attributes
+        let slots = InheritanceResolver::new(schema).induced_slots(name)?;
+        for (field_number, slot) in (1..).zip(&slots) {
+            let field = self.generate_field(slot, field_number, schema)?;
+            write!(&mut output, "{field}").map_err(Self::fmt_error_to_generator_error)?;
         }
 
         writeln!(&mut output, "}}").map_err(Self::fmt_error_to_generator_error)?;
@@ -192,24 +200,10 @@ impl ProtobufGenerator {
         Ok(output)
     }
 
-    /// Recursively collect all slots from a class and its parents
-    fn collect_all_slots(&self, class: &ClassDefinition, schema: &SchemaDefinition) -> Vec<String> {
-        let mut all_slots = Vec::new();
-
-        // First, recursively get slots from parent
-        if let Some(parent_name) = &class.is_a
-            && let Some(parent_class) = schema.classes.get(parent_name)
-        {
-            all_slots.extend(self.collect_all_slots(parent_class, schema));
-        }
-
-        // Then add direct slots
-        all_slots.extend(class.slots.clone());
-
-        all_slots
-    }
-
     /// Generate a proto field from a slot
+    ///
+    /// Proto3 has no required fields or value patterns, so those constraints
+    /// are carried as comments above the field.
     fn generate_field(
         &self,
         slot: &SlotDefinition,
@@ -218,13 +212,24 @@ impl ProtobufGenerator {
     ) -> GeneratorResult<String> {
         let mut output = String::new();
 
-        // Add description as comment
+        // Add description and constraints as comments
         if let Some(desc) = &slot.description {
-            writeln!(&mut output, "  // {desc}").map_err(Self::fmt_error_to_generator_error)?;
+            Self::write_comment(&mut output, "  ", desc)?;
+        }
+        if slot.required == Some(true) || slot.identifier == Some(true) {
+            writeln!(&mut output, "  // required").map_err(Self::fmt_error_to_generator_error)?;
+        }
+        if let Some(pattern) = &slot.pattern {
+            writeln!(&mut output, "  // pattern: {pattern}")
+                .map_err(Self::fmt_error_to_generator_error)?;
         }
 
-        // Determine proto type
-        let proto_type = self.get_proto_type(slot.range.as_ref(), schema)?;
+        // Determine proto type, inline permissible values having their own enum
+        let proto_type = if slot.permissible_values.is_empty() {
+            self.get_proto_type(slot.range.as_ref(), schema)?
+        } else {
+            Self::proto_identifier(&Self::to_pascal_case(&slot.name))
+        };
 
         // Handle repeated fields
         let repeated = if slot.multivalued.unwrap_or(false) {
@@ -234,7 +239,7 @@ impl ProtobufGenerator {
         };
 
         // Generate field
-        let field_name = Self::to_snake_case(&slot.name);
+        let field_name = Self::proto_identifier(&Self::to_snake_case(&slot.name));
         writeln!(
             &mut output,
             "  {repeated}{proto_type} {field_name} = {field_number};"
@@ -260,13 +265,25 @@ impl ProtobufGenerator {
                     self.get_proto_type(type_def.base_type.as_ref(), schema)
                 } else {
                     // Assume it's a message or enum type
-                    Ok(Self::to_pascal_case(r))
+                    Ok(Self::proto_identifier(&Self::to_pascal_case(r)))
                 }
             }
             None => Ok("string".to_string()), // Default to string
         }
     }
 
+    /// Replace characters not allowed in proto identifiers with `_`
+    fn proto_identifier(s: &str) -> String {
+        let mut result: String = s
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        if result.starts_with(|c: char| c.is_ascii_digit()) {
+            result.insert(0, '_');
+        }
+        result
+    }
+
     /// Convert to `snake_case`
     fn to_snake_case(s: &str) -> String {
         let mut result = String::new();
@@ -358,33 +375,64 @@ impl Generator for ProtobufGenerator {
     }
 
     fn generate(&self, schema: &SchemaDefinition) -> std::result::Result<String, LinkMLError> {
-        let mut output = String::new();
+        self.validate_schema(schema)?;
 
-        // Generate header
-        output.push_str(&Self::generate_header(schema)?);
-
-        // Generate enums first
+        // Generate enums first, including those declared inline on slots
         let mut enum_output = String::new();
         for (name, enum_def) in &schema.enums {
-            let enum_code = Self::generate_enum(name, enum_def)
-                .map_err(|e| LinkMLError::service(format!("Error generating enum {name}: {e}")))?;
+            let enum_code = Self::generate_enum(
+                name,
+                enum_def.description.as_ref(),
+                &enum_def.permissible_values,
+            )
+            .map_err(|e| LinkMLError::service(format!("Error generating enum {name}: {e}")))?;
             writeln!(&mut enum_output, "{enum_code}")
                 .map_err(Self::fmt_error_to_generator_error)?;
         }
 
-        if !enum_output.is_empty() {
-            output.push_str(&enum_output);
-            writeln!(&mut output).map_err(Self::fmt_error_to_generator_error)?;
+        let slots = schema.slots.iter().chain(
+            schema
+                .classes
+                .values()
+                .flat_map(|class| class.attributes.iter()),
+        );
+        let mut inline_enums: Vec<(String, &SlotDefinition)> = Vec::new();
+        for (slot_name, slot) in slots {
+            let name = Self::to_pascal_case(slot_name);
+            if !slot.permissible_values.is_empty()
+                && !inline_enums.iter().any(|(existing, _)| *existing == name)
+            {
+                inline_enums.push((name, slot));
+            }
+        }
+        for (name, slot) in inline_enums {
+            let enum_code =
+                Self::generate_enum(&name, slot.description.as_ref(), &slot.permissible_values)
+                    .map_err(|e| {
+                        LinkMLError::service(format!("Error generating enum {name}: {e}"))
+                    })?;
+            writeln!(&mut enum_output, "{enum_code}")
+                .map_err(Self::fmt_error_to_generator_error)?;
         }
 
         // Generate messages
+        let mut message_output = String::new();
         for (name, class) in &schema.classes {
             let message_code = self
                 .generate_message(name, class, schema)
                 .map_err(|e| LinkMLError::service(format!("Error generating class {name}: {e}")))?;
-            writeln!(&mut output, "{message_code}").map_err(Self::fmt_error_to_generator_error)?;
+            writeln!(&mut message_output, "{message_code}")
+                .map_err(Self::fmt_error_to_generator_error)?;
         }
 
+        let uses_timestamp = message_output.contains("google.protobuf.Timestamp ");
+        let mut output = Self::generate_header(schema, uses_timestamp)?;
+        if !enum_output.is_empty() {
+            output.push_str(&enum_output);
+            writeln!(&mut output).map_err(Self::fmt_error_to_generator_error)?;
+        }
+        output.push_str(&message_output);
+
         Ok(output)
     }
 
@@ -400,7 +448,7 @@ impl Generator for ProtobufGenerator {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use linkml_core::types::{ClassDefinition, SchemaDefinition, SlotDefinition};
+    use linkml_core::types::{ClassDefinition, EnumDefinition, SchemaDefinition, SlotDefinition};
 
     #[test]
     fn test_protobuf_generation() -> anyhow::Result<()> {
@@ -477,6 +525,99 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_person_proto_compiles() -> anyhow::Result<()> {
+        let schema: SchemaDefinition = serde_yaml::from_str(
+            r"
+id: https://example.org/people
+name: people
+classes:
+  NamedThing:
+    abstract: true
+    attributes:
+      id:
+        identifier: true
+      name:
+        required: true
+        pattern: '^[A-Z].*'
+  Person:
+    is_a: NamedThing
+    attributes:
+      age:
+        range: integer
+      birth_time:
+        range: datetime
+      aliases:
+        multivalued: true
+      status:
+        range: Status
+      contact_method:
+        permissible_values: [email, phone]
+      employer:
+        range: Organization
+  Organization:
+    is_a: NamedThing
+    attributes:
+      status:
+        range: Status
+enums:
+  Status:
+    permissible_values:
+      ACTIVE:
+      RETIRED:
+",
+        )?;
+        let proto = ProtobufGenerator::new().generate(&schema)?;
+
+        assert!(proto.contains("import \"google/protobuf/timestamp.proto\";"));
+        assert!(proto.contains("  // required\n  string id = 1;"));
+        assert!(proto.contains("  // pattern: ^[A-Z].*\n  string name = 2;"));
+        assert!(proto.contains("  repeated string aliases = 5;"));
+        assert!(proto.contains("STATUS_ACTIVE = 1;"));
+        assert!(proto.contains("CONTACT_METHOD_EMAIL = 1;"));
+
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("people.proto");
+        std::fs::write(&path, &proto)?;
+        let parsed = protobuf_parse::Parser::new()
+            .pure()
+            .include(dir.path())
+            .input(&path)
+            .parse_and_typecheck()?;
+
+        let file = parsed
+            .file_descriptors
+            .iter()
+            .find(|file| file.name() == "people.proto")
+            .ok_or_else(|| anyhow::anyhow!("people.proto should be parsed"))?;
+        let person = file
+            .message_type
+            .iter()
+            .find(|message| message.name() == "Person")
+            .ok_or_else(|| anyhow::anyhow!("Person message should exist"))?;
+        let fields: Vec<(&str, i32)> = person
+            .field
+            .iter()
+            .map(|field| (field.name(), field.number()))
+            .collect();
+        assert_eq!(
+            fields,
+            [
+                ("id", 1),
+                ("name", 2),
+                ("age", 3),
+                ("birth_time", 4),
+                ("aliases", 5),
+                ("status", 6),
+                ("contact_method", 7),
+                ("employer", 8),
+            ]
+        );
+        let employer = &person.field[7];
+        assert_eq!(employer.type_name(), ".people.Organization");
+        Ok(())
+    }
+
     #[test]
     fn test_case_conversion() {
         assert_eq!(
@@ -1,15 +1,29 @@
 //! Pydantic v2 code generator for `LinkML` schemas
 
-use super::base::{
-    BaseCodeFormatter, ImportManager, TypeMapper, collect_all_slots, is_optional_slot,
-};
+use super::base::{BaseCodeFormatter, ImportManager, TypeMapper, is_optional_slot};
 use super::options::{GeneratorOptions, IndentStyle};
 use super::traits::{CodeFormatter, Generator, GeneratorError, GeneratorResult};
+use crate::inheritance::InheritanceResolver;
 use linkml_core::error::LinkMLError;
 use linkml_core::prelude::*;
+use serde_json::Value;
+use std::collections::HashSet;
 use std::fmt::Write;
 
+/// Python keywords that cannot be used as field names
+const PYTHON_KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
+
 /// Pydantic v2 generator
+///
+/// Emits one module with an `Enum` per `LinkML` enum and a `BaseModel`
+/// subclass per class. Classes extend their `is_a` parent and declare only
+/// the slots they add or refine; slot ranges, cardinality, patterns and
+/// numeric bounds become field types and `Field` constraints.
 pub struct PydanticGenerator {
     name: String,
     description: String,
@@ -47,6 +61,36 @@ impl PydanticGenerator {
         GeneratorError::Io(std::io::Error::other(e))
     }
 
+    /// Order classes so that every parent is defined before its subclasses
+    fn class_order(schema: &SchemaDefinition) -> Vec<&str> {
+        fn visit<'a>(
+            class_name: &'a str,
+            schema: &'a SchemaDefinition,
+            visited: &mut HashSet<&'a str>,
+            order: &mut Vec<&'a str>,
+        ) {
+            if !visited.insert(class_name) {
+                return;
+            }
+            if let Some(parent) = schema
+                .classes
+                .get(class_name)
+                .and_then(|class| class.is_a.as_deref())
+                && let Some((parent, _)) = schema.classes.get_key_value(parent)
+            {
+                visit(parent, schema, visited, order);
+            }
+            order.push(class_name);
+        }
+
+        let mut visited = HashSet::new();
+        let mut order = Vec::new();
+        for class_name in schema.classes.keys() {
+            visit(class_name, schema, &mut visited, &mut order);
+        }
+        order
+    }
+
     /// Generate code for a single class
     fn generate_class(
         &self,
@@ -54,19 +98,31 @@ impl PydanticGenerator {
         class: &ClassDefinition,
         schema: &SchemaDefinition,
         options: &GeneratorOptions,
+        imports: &mut ImportManager,
     ) -> GeneratorResult<String> {
         let mut output = String::new();
-        let mut imports = ImportManager::new();
 
         // Always need BaseModel
         imports.add_import("pydantic", "BaseModel");
 
         // Check if we need inheritance
-        let base_class = if let Some(ref parent) = class.is_a {
-            parent.clone()
-        } else {
-            "BaseModel".to_string()
+        let parent = class
+            .is_a
+            .as_ref()
+            .filter(|parent| schema.classes.contains_key(*parent));
+        let base_class = parent.map_or("BaseModel", String::as_str);
+
+        // Slots added or refined by this class; the rest are inherited
+        let resolver = InheritanceResolver::new(schema);
+        let slots = resolver.induced_slots(class_name)?;
+        let inherited = match parent {
+            Some(parent) => resolver.induced_slots(parent)?,
+            None => Vec::new(),
         };
+        let own_slots: Vec<&SlotDefinition> = slots
+            .iter()
+            .filter(|slot| !inherited.contains(slot))
+            .collect();
 
         // Generate class definition
         writeln!(&mut output, "class {class_name}({base_class}):")
@@ -106,20 +162,18 @@ impl PydanticGenerator {
                 .map_err(Self::fmt_error_to_generator_error)?;
 
             // Generate example values for slots
-            let slots = collect_all_slots(class, schema)?;
-            for (i, slot_name) in slots.iter().enumerate() {
-                if let Some(slot) = schema.slots.get(slot_name) {
-                    let example_value = self.get_example_value(slot);
-                    write!(
-                        &mut output,
-                        "                    \"{slot_name}\": {example_value}"
-                    )
-                    .map_err(Self::fmt_error_to_generator_error)?;
-                    if i < slots.len() - 1 {
-                        writeln!(&mut output, ",").map_err(Self::fmt_error_to_generator_error)?;
-                    } else {
-                        writeln!(&mut output).map_err(Self::fmt_error_to_generator_error)?;
-                    }
+            for (i, slot) in slots.iter().enumerate() {
+                let example_value = self.get_example_value(slot);
+                write!(
+                    &mut output,
+                    "                    \"{}\": {example_value}",
+                    BaseCodeFormatter::escape_python_string(&slot.name)
+                )
+                .map_err(Self::fmt_error_to_generator_error)?;
+                if i < slots.len() - 1 {
+                    writeln!(&mut output, ",").map_err(Self::fmt_error_to_generator_error)?;
+                } else {
+                    writeln!(&mut output).map_err(Self::fmt_error_to_generator_error)?;
                 }
             }
 
@@ -132,25 +186,13 @@ impl PydanticGenerator {
         writeln!(&mut output, "    }}").map_err(Self::fmt_error_to_generator_error)?;
         writeln!(&mut output).map_err(Self::fmt_error_to_generator_error)?;
 
-        // Collect all slots including inherited
-        let slots = collect_all_slots(class, schema)?;
-
-        if slots.is_empty() {
+        if own_slots.is_empty() {
             writeln!(&mut output, "    pass").map_err(Self::fmt_error_to_generator_error)?;
         } else {
             // Generate fields
-            for slot_name in &slots {
-                if let Some(slot) = schema.slots.get(slot_name) {
-                    self.generate_field(
-                        &mut output,
-                        slot_name,
-                        slot,
-                        schema,
-                        &mut imports,
-                        options,
-                    )?;
-                    writeln!(&mut output).map_err(Self::fmt_error_to_generator_error)?;
-                }
+            for slot in &own_slots {
+                self.generate_field(&mut output, slot, schema, imports, options)?;
+                writeln!(&mut output).map_err(Self::fmt_error_to_generator_error)?;
             }
 
             // Generate validators if needed
@@ -159,34 +201,21 @@ impl PydanticGenerator {
                 .map(std::string::String::as_str)
                 == Some("true")
             {
-                self.generate_validators(&mut output, &slots, schema, &mut imports)?;
+                self.generate_validators(&mut output, &own_slots, imports)?;
             }
         }
 
-        // Add Field import if needed
-        if output.contains("Field(") {
-            imports.add_import("pydantic", "Field");
-        }
-
-        // Combine imports and class
-        let mut final_output = String::new();
-        let import_block = imports.python_imports();
-        if !import_block.is_empty() {
-            writeln!(&mut final_output, "{import_block}")
-                .map_err(Self::fmt_error_to_generator_error)?;
-            writeln!(&mut final_output).map_err(Self::fmt_error_to_generator_error)?;
-            writeln!(&mut final_output).map_err(Self::fmt_error_to_generator_error)?;
-        }
-        final_output.push_str(&output);
-
-        Ok(final_output)
+        Ok(output)
     }
 
     /// Generate a single field
+    ///
+    /// Patterns and numeric bounds become `Field` constraints. For
+    /// multivalued slots they constrain the list items instead, through an
+    /// `Annotated` item type.
     fn generate_field(
         &self,
         output: &mut String,
-        slot_name: &str,
         slot: &SlotDefinition,
         schema: &SchemaDefinition,
         imports: &mut ImportManager,
@@ -199,18 +228,44 @@ impl PydanticGenerator {
             writeln!(output, "    # {desc}").map_err(Self::fmt_error_to_generator_error)?;
         }
 
+        imports.add_import("pydantic", "Field");
+        let field_name = Self::python_identifier(&slot.name);
+        let multivalued = slot.multivalued.unwrap_or(false);
+
+        // Value constraints
+        let mut constraints = Vec::new();
+        if let Some(ref pattern) = slot.pattern {
+            constraints.push(format!(
+                "pattern=\"{}\"",
+                BaseCodeFormatter::escape_python_string(pattern)
+            ));
+        }
+        if let Some(min) = slot.minimum_value.as_ref().and_then(Self::python_number) {
+            constraints.push(format!("ge={min}"));
+        }
+        if let Some(max) = slot.maximum_value.as_ref().and_then(Self::python_number) {
+            constraints.push(format!("le={max}"));
+        }
+
         // Determine the type
         let base_type = self.get_field_type(slot, schema, imports);
 
         // Handle optional and multivalued
-        let field_type = if slot.multivalued.unwrap_or(false) {
-            imports.add_import("typing", "List");
-            format!("List[{base_type}]")
+        let field_type = if multivalued {
+            if constraints.is_empty() {
+                format!("list[{base_type}]")
+            } else {
+                imports.add_import("typing", "Annotated");
+                format!(
+                    "list[Annotated[{base_type}, Field({})]]",
+                    std::mem::take(&mut constraints).join(", ")
+                )
+            }
         } else {
             base_type
         };
 
-        let final_type = if is_optional_slot(slot) && !slot.multivalued.unwrap_or(false) {
+        let final_type = if is_optional_slot(slot) && !multivalued {
             imports.add_import("typing", "Optional");
             format!("Optional[{field_type}]")
         } else {
@@ -223,12 +278,20 @@ impl PydanticGenerator {
         // Required fields need ...
         if slot.required.unwrap_or(false) {
             field_args.push("...".to_string());
-        } else if slot.multivalued.unwrap_or(false) {
+        } else if multivalued {
             field_args.push("default_factory=list".to_string());
         } else {
             field_args.push("None".to_string());
         }
 
+        // Keep the schema name when it is not a valid Python identifier
+        if field_name != slot.name {
+            field_args.push(format!(
+                "alias=\"{}\"",
+                BaseCodeFormatter::escape_python_string(&slot.name)
+            ));
+        }
+
         // Add description
         if let Some(ref desc) = slot.description {
             field_args.push(format!(
@@ -237,24 +300,10 @@ impl PydanticGenerator {
             ));
         }
 
-        // Add pattern
-        if let Some(ref pattern) = slot.pattern {
-            field_args.push(format!("pattern=r\"{pattern}\""));
-        }
-
-        // Add numeric constraints
-        if let Some(ref min) = slot.minimum_value {
-            field_args.push(format!("ge={min}"));
-        }
-        if let Some(ref max) = slot.maximum_value {
-            field_args.push(format!("le={max}"));
-        }
-
-        // Note: LinkML doesn't have minimum_cardinality/maximum_cardinality in SlotDefinition
-        // These would be handled by pattern or custom validators if needed
+        field_args.extend(constraints);
 
         // Write the field
-        write!(output, "    {slot_name}: {final_type} = Field(")
+        write!(output, "    {field_name}: {final_type} = Field(")
             .map_err(Self::fmt_error_to_generator_error)?;
         write!(output, "{}", field_args.join(", ")).map_err(Self::fmt_error_to_generator_error)?;
         writeln!(output, ")").map_err(Self::fmt_error_to_generator_error)?;
@@ -269,66 +318,87 @@ impl PydanticGenerator {
         schema: &SchemaDefinition,
         imports: &mut ImportManager,
     ) -> String {
-        // Check if it's an enum
+        // Check if it's an inline enum
         if !slot.permissible_values.is_empty() {
-            imports.add_import("enum", "Enum");
-            let enum_name = BaseCodeFormatter::to_pascal_case(&slot.name);
-            return enum_name;
+            return BaseCodeFormatter::to_pascal_case(&slot.name);
         }
 
-        // Check range
-        if let Some(ref range) = slot.range {
-            // Check if it's a class
-            if schema.classes.contains_key(range) {
-                return range.clone();
-            }
+        let Some(range) = slot.range.as_deref() else {
+            imports.add_import("typing", "Any");
+            return "Any".to_string();
+        };
 
-            // Check if it's a type
-            if let Some(type_def) = schema.types.get(range)
-                && let Some(ref base_type) = type_def.base_type
-            {
-                let py_type = TypeMapper::to_python(base_type);
-                // Add datetime imports if needed
-                match base_type.as_str() {
-                    "datetime" => {
-                        imports.add_import("datetime", "datetime");
-                    }
-                    "date" => {
-                        imports.add_import("datetime", "date");
-                    }
-                    "time" => {
-                        imports.add_import("datetime", "time");
-                    }
-                    _ => {}
+        // Classes and enums are generated under their own names
+        if schema.classes.contains_key(range) || schema.enums.contains_key(range) {
+            return range.to_string();
+        }
+
+        // Follow custom types to the built-in type they derive from
+        let range = schema.base_type(range);
+        match range {
+            "date" | "datetime" | "time" => {
+                imports.add_import("datetime", range);
+                range.to_string()
+            }
+            _ => {
+                let py_type = TypeMapper::to_python(range);
+                if py_type == "Any" {
+                    imports.add_import("typing", "Any");
                 }
-                return py_type.to_string();
+                py_type.to_string()
             }
+        }
+    }
 
-            // Otherwise map as primitive
-            let py_type = TypeMapper::to_python(range);
+    /// Render a numeric bound as a Python literal
+    fn python_number(value: &Value) -> Option<String> {
+        match value {
+            Value::Number(n) => Some(n.to_string()),
+            Value::String(s) => s
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .filter(|n| n.is_finite())
+                .map(|_| s.trim().to_string()),
+            _ => None,
+        }
+    }
 
-            // Add datetime imports if needed
-            match range.as_str() {
-                "datetime" => {
-                    imports.add_import("datetime", "datetime");
+    /// Turn a slot name into a valid Python identifier
+    fn python_identifier(name: &str) -> String {
+        let mut ident: String = name
+            .chars()
+            .map(|c| {
+                if c.is_alphanumeric() || c == '_' {
+                    c
+                } else {
+                    '_'
                 }
-                "date" => {
-                    imports.add_import("datetime", "date");
-                }
-                "time" => {
-                    imports.add_import("datetime", "time");
-                }
-                _ => {}
-            }
+            })
+            .collect();
+        // Leading underscores would make Pydantic treat the field as private
+        if ident.is_empty() || ident.starts_with(|c: char| c.is_ascii_digit()) {
+            ident.insert_str(0, "field_");
+        } else if ident.starts_with('_') {
+            ident.insert_str(0, "field");
+        }
+        if PYTHON_KEYWORDS.contains(&ident.as_str()) {
+            ident.push('_');
+        }
+        ident
+    }
 
-            if py_type == "Any" {
-                imports.add_import("typing", "Any");
-            }
-            py_type.to_string()
-        } else {
-            imports.add_import("typing", "Any");
-            "Any".to_string()
+    /// Turn a permissible value into an enum member name
+    fn enum_member_name(text: &str) -> String {
+        let mut name: String = text
+            .to_uppercase()
+            .chars()
+            .map(|c| if c.is_alphanumeric() { c } else { '_' })
+            .collect();
+        if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit() || c == '_') {
+            name.insert_str(0, "VALUE_");
         }
+        name
     }
 
     /// Generate example value for a slot
@@ -338,23 +408,23 @@ impl PydanticGenerator {
                 "string" | "str" => "\"Example text\"".to_string(),
                 "integer" | "int" => "42".to_string(),
                 "float" | "double" | "decimal" => "3.14".to_string(),
-                "boolean" | "bool" => "true".to_string(),
+                "boolean" | "bool" => "True".to_string(),
                 "date" => "\"2024-01-01\"".to_string(),
                 "datetime" => "\"2024-01-01T12:00:00Z\"".to_string(),
                 _ => {
                     if slot.permissible_values.is_empty() {
-                        "null".to_string()
+                        "None".to_string()
                     } else if let Some(PermissibleValue::Simple(val)) =
                         slot.permissible_values.first()
                     {
-                        format!("\"{val}\"")
+                        format!("\"{}\"", BaseCodeFormatter::escape_python_string(val))
                     } else {
-                        "null".to_string()
+                        "None".to_string()
                     }
                 }
             }
         } else {
-            "null".to_string()
+            "None".to_string()
         }
     }
 
@@ -362,44 +432,39 @@ impl PydanticGenerator {
     fn generate_validators(
         &self,
         output: &mut String,
-        slots: &[String],
-        schema: &SchemaDefinition,
+        slots: &[&SlotDefinition],
         imports: &mut ImportManager,
     ) -> GeneratorResult<()> {
-        imports.add_import("pydantic", "field_validator");
-
         let mut validators_added = false;
 
-        for slot_name in slots {
-            if let Some(slot) = schema.slots.get(slot_name) {
-                // Add custom validators for complex constraints
-                if slot.minimum_value.is_some() && slot.maximum_value.is_some() {
-                    if !validators_added {
-                        writeln!(output).map_err(Self::fmt_error_to_generator_error)?;
-                    }
-                    validators_added = true;
-
-                    writeln!(output, "    @field_validator('{slot_name}', mode='after')")
-                        .map_err(Self::fmt_error_to_generator_error)?;
-                    writeln!(output, "    @classmethod")
-                        .map_err(Self::fmt_error_to_generator_error)?;
-                    writeln!(output, "    def validate_{slot_name}(cls, v):")
-                        .map_err(Self::fmt_error_to_generator_error)?;
-                    writeln!(output, "        if v is None:")
-                        .map_err(Self::fmt_error_to_generator_error)?;
-                    writeln!(output, "            return v")
-                        .map_err(Self::fmt_error_to_generator_error)?;
+        for slot in slots {
+            // Add custom validators for complex constraints
+            if slot.minimum_value.is_some() && slot.maximum_value.is_some() {
+                if !validators_added {
+                    imports.add_import("pydantic", "field_validator");
+                    writeln!(output).map_err(Self::fmt_error_to_generator_error)?;
+                }
+                validators_added = true;
 
-                    // Add custom validation logic
-                    if let Some(ref desc) = slot.description {
-                        writeln!(output, "        # {desc}")
-                            .map_err(Self::fmt_error_to_generator_error)?;
-                    }
+                let field_name = Self::python_identifier(&slot.name);
+                writeln!(output, "    @field_validator('{field_name}', mode='after')")
+                    .map_err(Self::fmt_error_to_generator_error)?;
+                writeln!(output, "    @classmethod").map_err(Self::fmt_error_to_generator_error)?;
+                writeln!(output, "    def validate_{field_name}(cls, v):")
+                    .map_err(Self::fmt_error_to_generator_error)?;
+                writeln!(output, "        if v is None:")
+                    .map_err(Self::fmt_error_to_generator_error)?;
+                writeln!(output, "            return v")
+                    .map_err(Self::fmt_error_to_generator_error)?;
 
-                    writeln!(output, "        return v")
+                // Add custom validation logic
+                if let Some(ref desc) = slot.description {
+                    writeln!(output, "        # {desc}")
                         .map_err(Self::fmt_error_to_generator_error)?;
-                    writeln!(output).map_err(Self::fmt_error_to_generator_error)?;
                 }
+
+                writeln!(output, "        return v").map_err(Self::fmt_error_to_generator_error)?;
+                writeln!(output).map_err(Self::fmt_error_to_generator_error)?;
             }
         }
 
@@ -423,37 +488,38 @@ impl Generator for PydanticGenerator {
     fn generate(&self, schema: &SchemaDefinition) -> std::result::Result<String, LinkMLError> {
         self.validate_schema(schema)?;
 
-        // Generate a single file with all classes
-        let mut content = String::new();
         let mut imports = ImportManager::new();
 
-        // File header
-        writeln!(&mut content, "\"\"\"").map_err(Self::fmt_error_to_generator_error)?;
-        writeln!(
-            &mut content,
-            "Generated from LinkML schema: {}",
-            schema.name
-        )
-        .map_err(Self::fmt_error_to_generator_error)?;
-        if let Some(ref desc) = schema.description {
-            writeln!(&mut content).map_err(Self::fmt_error_to_generator_error)?;
-            writeln!(&mut content, "{desc}").map_err(Self::fmt_error_to_generator_error)?;
-        }
-        writeln!(&mut content, "\"\"\"").map_err(Self::fmt_error_to_generator_error)?;
-
         // Generate enums first
         let mut enum_content = String::new();
+        for (enum_name, enum_def) in &schema.enums {
+            self.generate_enum(
+                &mut enum_content,
+                enum_name,
+                enum_def.description.as_deref(),
+                &enum_def.permissible_values,
+                &mut imports,
+            )?;
+        }
         for (slot_name, slot) in &schema.slots {
             if !slot.permissible_values.is_empty() {
-                self.generate_enum(&mut enum_content, slot_name, slot, &mut imports)?;
+                self.generate_enum(
+                    &mut enum_content,
+                    &BaseCodeFormatter::to_pascal_case(slot_name),
+                    slot.description.as_deref(),
+                    &slot.permissible_values,
+                    &mut imports,
+                )?;
             }
         }
 
-        // Generate classes
+        // Generate classes, parents before subclasses
         let mut class_content = String::new();
-        for (class_name, class_def) in &schema.classes {
+        let class_order = Self::class_order(schema);
+        for class_name in &class_order {
+            let class_def = &schema.classes[*class_name];
             let class_code =
-                self.generate_class(class_name, class_def, schema, &GeneratorOptions::default())?;
+                self.generate_class(class_name, class_def, schema, &self.options, &mut imports)?;
             writeln!(&mut class_content).map_err(Self::fmt_error_to_generator_error)?;
             writeln!(&mut class_content).map_err(Self::fmt_error_to_generator_error)?;
             class_content.push_str(&class_code);
@@ -462,6 +528,26 @@ impl Generator for PydanticGenerator {
         // Combine everything
         let mut final_content = String::new();
 
+        // Module docstring
+        writeln!(&mut final_content, "\"\"\"").map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(
+            &mut final_content,
+            "Generated from LinkML schema: {}",
+            schema.name
+        )
+        .map_err(Self::fmt_error_to_generator_error)?;
+        if let Some(ref desc) = schema.description {
+            writeln!(&mut final_content).map_err(Self::fmt_error_to_generator_error)?;
+            writeln!(&mut final_content, "{desc}").map_err(Self::fmt_error_to_generator_error)?;
+        }
+        writeln!(&mut final_content, "\"\"\"").map_err(Self::fmt_error_to_generator_error)?;
+
+        // Annotations are resolved lazily so fields can reference classes
+        // defined further down
+        writeln!(&mut final_content, "from __future__ import annotations")
+            .map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(&mut final_content).map_err(Self::fmt_error_to_generator_error)?;
+
         // Imports
         let import_block = imports.python_imports();
         if !import_block.is_empty() {
@@ -476,17 +562,23 @@ impl Generator for PydanticGenerator {
             "# Generated by LinkML Pydantic Generator"
         )
         .map_err(Self::fmt_error_to_generator_error)?;
-        writeln!(&mut final_content).map_err(Self::fmt_error_to_generator_error)?;
 
         // Enums
         if !enum_content.is_empty() {
             final_content.push_str(&enum_content);
-            writeln!(&mut final_content).map_err(Self::fmt_error_to_generator_error)?;
         }
 
         // Classes
         final_content.push_str(&class_content);
 
+        // Resolve forward references between classes
+        writeln!(&mut final_content).map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(&mut final_content).map_err(Self::fmt_error_to_generator_error)?;
+        for class_name in &class_order {
+            writeln!(&mut final_content, "{class_name}.model_rebuild()")
+                .map_err(Self::fmt_error_to_generator_error)?;
+        }
+
         Ok(final_content)
     }
 
@@ -518,28 +610,41 @@ impl PydanticGenerator {
     fn generate_enum(
         &self,
         output: &mut String,
-        slot_name: &str,
-        slot: &SlotDefinition,
+        enum_name: &str,
+        description: Option<&str>,
+        values: &[PermissibleValue],
         imports: &mut ImportManager,
     ) -> GeneratorResult<()> {
         imports.add_import("enum", "Enum");
 
-        let enum_name = BaseCodeFormatter::to_pascal_case(slot_name);
+        writeln!(output).map_err(Self::fmt_error_to_generator_error)?;
         writeln!(output).map_err(Self::fmt_error_to_generator_error)?;
         writeln!(output, "class {enum_name}(str, Enum):")
             .map_err(Self::fmt_error_to_generator_error)?;
 
-        if let Some(ref desc) = slot.description {
-            writeln!(output, "    \"\"\"{desc}\"\"\"")
-                .map_err(Self::fmt_error_to_generator_error)?;
+        if let Some(desc) = description {
+            writeln!(
+                output,
+                "    \"\"\"{}\"\"\"",
+                BaseCodeFormatter::escape_python_string(desc)
+            )
+            .map_err(Self::fmt_error_to_generator_error)?;
+        }
+
+        if values.is_empty() && description.is_none() {
+            writeln!(output, "    pass").map_err(Self::fmt_error_to_generator_error)?;
         }
 
-        for value in &slot.permissible_values {
+        for value in values {
             match value {
                 PermissibleValue::Simple(text) | PermissibleValue::Complex { text, .. } => {
-                    let const_name = text.to_uppercase().replace([' ', '-'], "_");
-                    writeln!(output, "    {const_name} = \"{text}\"")
-                        .map_err(Self::fmt_error_to_generator_error)?;
+                    writeln!(
+                        output,
+                        "    {} = \"{}\"",
+                        Self::enum_member_name(text),
+                        BaseCodeFormatter::escape_python_string(text)
+                    )
+                    .map_err(Self::fmt_error_to_generator_error)?;
                 }
             }
         }
@@ -642,7 +747,8 @@ impl CodeFormatter for PydanticGenerator {
 mod tests {
     use super::*;
     use indexmap::IndexMap;
-    use linkml_core::types::{ClassDefinition, SchemaDefinition, SlotDefinition};
+    use linkml_core::types::{ClassDefinition, EnumDefinition, SchemaDefinition, SlotDefinition};
+    use serde_json::json;
 
     #[test]
     fn test_basic_generation() {
@@ -691,4 +797,157 @@ mod tests {
         assert!(output.contains("age: Optional[int] = Field(None)"));
         assert!(output.contains("model_config ="));
     }
+
+    /// Schema with an enum, constrained slots and an `is_a` hierarchy
+    fn people_schema() -> SchemaDefinition {
+        let mut schema = SchemaDefinition::new("people");
+        schema.enums.insert(
+            "EmploymentStatus".to_string(),
+            EnumDefinition {
+                name: "EmploymentStatus".to_string(),
+                permissible_values: vec![
+                    PermissibleValue::Simple("full-time".to_string()),
+                    PermissibleValue::Simple("1099".to_string()),
+                ],
+                ..Default::default()
+            },
+        );
+        for slot in [
+            SlotDefinition {
+                range: Some("string".to_string()),
+                required: Some(true),
+                ..SlotDefinition::new("name")
+            },
+            SlotDefinition {
+                range: Some("integer".to_string()),
+                minimum_value: Some(json!(0)),
+                maximum_value: Some(json!(150)),
+                ..SlotDefinition::new("age")
+            },
+            SlotDefinition {
+                range: Some("string".to_string()),
+                pattern: Some(r#"^\d{3}-"[A-Z]"$"#.to_string()),
+                ..SlotDefinition::new("badge")
+            },
+            SlotDefinition {
+                range: Some("string".to_string()),
+                multivalued: Some(true),
+                pattern: Some("^[a-z]+$".to_string()),
+                ..SlotDefinition::new("tags")
+            },
+            SlotDefinition {
+                range: Some("EmploymentStatus".to_string()),
+                ..SlotDefinition::new("status")
+            },
+            SlotDefinition {
+                range: Some("date".to_string()),
+                ..SlotDefinition::new("class")
+            },
+        ] {
+            schema.slots.insert(slot.name.clone(), slot);
+        }
+        // Declared before its parent to check that parents come first
+        schema.classes.insert(
+            "Employee".to_string(),
+            ClassDefinition {
+                is_a: Some("Person".to_string()),
+                slots: vec![
+                    "badge".to_string(),
+                    "tags".to_string(),
+                    "status".to_string(),
+                ],
+                ..ClassDefinition::new("Employee")
+            },
+        );
+        schema.classes.insert(
+            "Person".to_string(),
+            ClassDefinition {
+                slots: vec!["name".to_string(), "age".to_string(), "class".to_string()],
+                ..ClassDefinition::new("Person")
+            },
+        );
+        schema
+    }
+
+    #[test]
+    fn test_constraints_enums_and_inheritance() {
+        let output = PydanticGenerator::new()
+            .generate(&people_schema())
+            .expect("should generate Pydantic output");
+
+        assert!(output.contains("age: Optional[int] = Field(None, ge=0, le=150)"));
+        assert!(
+            output.contains(r#"badge: Optional[str] = Field(None, pattern="^\\d{3}-\"[A-Z]\"$")"#),
+            "{output}"
+        );
+        assert!(output.contains(
+            r#"tags: list[Annotated[str, Field(pattern="^[a-z]+$")]] = Field(default_factory=list)"#
+        ));
+        assert!(output.contains("status: Optional[EmploymentStatus] = Field(None)"));
+        assert!(output.contains(r#"class_: Optional[date] = Field(None, alias="class")"#));
+        assert!(output.contains("from datetime import date"));
+
+        assert!(output.contains("class EmploymentStatus(str, Enum):"));
+        assert!(output.contains(r#"FULL_TIME = "full-time""#));
+        assert!(output.contains(r#"VALUE_1099 = "1099""#));
+
+        // Inherited fields are not redeclared
+        let person = output
+            .find("class Person(BaseModel):")
+            .expect("Person model");
+        let employee = output
+            .find("class Employee(Person):")
+            .expect("Employee model");
+        assert!(person < employee);
+        assert_eq!(output.matches("    name: str = Field(...)").count(), 1);
+    }
+
+    /// Import the generated module with Pydantic and check that it enforces
+    /// the schema constraints. Skipped when `python3` with `pydantic` is not
+    /// installed.
+    #[test]
+    fn test_generated_module_imports_and_validates() -> anyhow::Result<()> {
+        let has_pydantic = std::process::Command::new("python3")
+            .args(["-c", "import pydantic"])
+            .output()
+            .is_ok_and(|out| out.status.success());
+        if !has_pydantic {
+            eprintln!("python3 with pydantic not available, skipping");
+            return Ok(());
+        }
+
+        let output = PydanticGenerator::new().generate(&people_schema())?;
+        let dir = tempfile::tempdir()?;
+        std::fs::write(dir.path().join("people.py"), output)?;
+
+        let check = r#"
+import sys
+sys.path.insert(0, sys.argv[1])
+from pydantic import ValidationError
+import people
+people.Person(name="Ada", age=30)
+people.Employee(name="Ada", badge='123-"A"', tags=["ops"], status="full-time")
+invalid = [
+    lambda: people.Person(age=200, name="Ada"),
+    lambda: people.Employee(name="Ada", badge="ABC"),
+    lambda: people.Employee(name="Ada", tags=["Ops"]),
+]
+for i, build in enumerate(invalid):
+    try:
+        build()
+    except ValidationError:
+        continue
+    sys.exit(f"invalid model {i} was accepted")
+"#;
+        let result = std::process::Command::new("python3")
+            .args(["-c", check])
+            .arg(dir.path())
+            .output()?;
+        assert!(
+            result.status.success(),
+            "{}",
+            String::from_utf8_lossy(&result.stderr)
+        );
+        Ok(())
+    }
 }
@@ -113,7 +113,7 @@ impl RdfGenerator {
 
         // Write classes
         for (name, class) in &schema.classes {
-            self.write_class(&mut output, name, class, base_uri)?;
+            self.write_class(&mut output, name, class, schema, base_uri)?;
         }
 
         // Write slots as properties
@@ -123,12 +123,12 @@ impl RdfGenerator {
 
         // Write types
         for (name, type_def) in &schema.types {
-            self.write_type(&mut output, name, type_def, base_uri)?;
+            self.write_type(&mut output, name, type_def, schema, base_uri)?;
         }
 
         // Write enums
         for (name, enum_def) in &schema.enums {
-            self.write_enum(&mut output, name, enum_def, base_uri)?;
+            self.write_enum(&mut output, name, enum_def, schema, base_uri)?;
         }
 
         Ok(output)
@@ -164,14 +164,10 @@ impl RdfGenerator {
         }
 
         // Schema-specific prefixes
-        for (prefix, def) in &schema.prefixes {
-            let reference = match def {
-                PrefixDefinition::Simple(url) => url.clone(),
-                PrefixDefinition::Complex {
-                    prefix_reference, ..
-                } => prefix_reference.clone().unwrap_or_default(),
-            };
-            writeln_rdf!(output, "@prefix {}: <{}> .", prefix, reference)?;
+        for prefix in schema.prefixes.keys() {
+            if let Some(namespace) = schema.expand_curie(&format!("{prefix}:")) {
+                writeln_rdf!(output, "@prefix {}: <{}> .", prefix, namespace)?;
+            }
         }
 
         writeln_rdf!(output, "")?; // Blank line
@@ -281,10 +277,13 @@ impl RdfGenerator {
         output: &mut String,
         name: &str,
         class: &ClassDefinition,
+        schema: &SchemaDefinition,
         base_uri: &str,
     ) -> Result<()> {
-        let default_uri = format!("{base_uri}/{name}");
-        let class_uri = class.class_uri.as_deref().unwrap_or(&default_uri);
+        let class_uri = class.class_uri.as_deref().map_or_else(
+            || format!("{base_uri}/{name}"),
+            |uri| expand_uri(schema, uri),
+        );
 
         writeln_rdf!(output, "# Class: {}", name)?;
         writeln_rdf!(output, "<{}> a rdfs:Class ;", class_uri)?;
@@ -353,8 +352,10 @@ impl RdfGenerator {
         schema: &SchemaDefinition,
         base_uri: &str,
     ) -> Result<()> {
-        let default_uri = format!("{base_uri}/{name}");
-        let slot_uri = slot.slot_uri.as_deref().unwrap_or(&default_uri);
+        let slot_uri = slot.slot_uri.as_deref().map_or_else(
+            || format!("{base_uri}/{name}"),
+            |uri| expand_uri(schema, uri),
+        );
 
         writeln_rdf!(output, "# Property: {}", name)?;
         writeln_rdf!(output, "<{}> a rdf:Property ;", slot_uri)?;
@@ -433,10 +434,13 @@ impl RdfGenerator {
         output: &mut String,
         name: &str,
         type_def: &TypeDefinition,
+        schema: &SchemaDefinition,
         base_uri: &str,
     ) -> Result<()> {
-        let default_uri = format!("{base_uri}/{name}");
-        let type_uri = type_def.uri.as_deref().unwrap_or(&default_uri);
+        let type_uri = type_def.uri.as_deref().map_or_else(
+            || format!("{base_uri}/{name}"),
+            |uri| expand_uri(schema, uri),
+        );
 
         writeln_rdf!(output, "# Type: {}", name)?;
         writeln_rdf!(output, "<{}> a rdfs:Datatype ;", type_uri)?;
@@ -468,13 +472,14 @@ impl RdfGenerator {
         output: &mut String,
         name: &str,
         enum_def: &EnumDefinition,
+        schema: &SchemaDefinition,
         base_uri: &str,
     ) -> Result<()> {
         // Generate enum URI - use code_set if available, otherwise construct from base_uri
         let enum_uri = if let Some(code_set) = &enum_def.code_set {
-            // If code_set is a full URI, use it directly
-            if code_set.starts_with("http://") || code_set.starts_with("https://") {
-                code_set.clone()
+            // If code_set is a URI or CURIE, expand it
+            if code_set.contains(':') {
+                expand_uri(schema, code_set)
             } else {
                 // Otherwise construct URI from base and code_set
                 format!("{base_uri}/{code_set}")
@@ -545,6 +550,13 @@ impl RdfGenerator {
     }
 }
 
+/// Expand a URI or CURIE with the schema's prefixes
+///
+/// A CURIE with an undeclared prefix is kept as written.
+fn expand_uri(schema: &SchemaDefinition, uri: &str) -> String {
+    schema.expand_curie(uri).unwrap_or_else(|| uri.to_string())
+}
+
 /// Map `LinkML` range to XSD datatype
 fn map_range_to_xsd(range: &str) -> String {
     match range {
@@ -604,3 +616,42 @@ impl Generator for RdfGenerator {
         "schema.ttl"
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uris_are_expanded_with_schema_prefixes() -> anyhow::Result<()> {
+        let schema: SchemaDefinition = serde_yaml::from_str(
+            r"
+id: https://textpast.org/schema/place
+name: place
+prefixes:
+  txp: https://textpast.org/
+  wd:
+    prefix_prefix: http://www.wikidata.org/entity/
+    prefix_reference: https://www.wikidata.org
+classes:
+  Country:
+    class_uri: wd:Q6256
+slots:
+  name:
+    slot_uri: txp:meta/name
+enums:
+  CountryCode:
+    code_set: txp:place/polity/country/iso_3166_entity/instance
+",
+        )?;
+
+        let output = RdfGenerator::new().generate(&schema)?;
+
+        assert!(output.contains("@prefix wd: <http://www.wikidata.org/entity/> ."));
+        assert!(output.contains("<http://www.wikidata.org/entity/Q6256> a rdfs:Class ;"));
+        assert!(output.contains("<https://textpast.org/meta/name> a rdf:Property ;"));
+        assert!(output.contains(
+            "<https://textpast.org/place/polity/country/iso_3166_entity/instance> a rdfs:Class ;"
+        ));
+        Ok(())
+    }
+}
@@ -1,7 +1,14 @@
 //! Generator registry for managing available generators
+//!
+//! Besides generators registered under their own names, the registry maps
+//! output format names such as `rust`, `owl` or `json-schema` to factories,
+//! so that callers can select a format by string at runtime.
 
-use super::traits::{Generator, GeneratorError, GeneratorResult};
+use super::traits::{Generator, GeneratorError, GeneratorOptions, GeneratorResult};
+use super::{OwlRdfGenerator, RdfFormat, RdfMode};
 use crate::plugin::{GeneratorPlugin, PluginManager, PluginStatus, PluginType};
+use linkml_core::error::LinkMLError;
+use linkml_core::types::SchemaDefinition;
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::{Mutex, RwLock};
@@ -14,6 +21,32 @@ pub struct GeneratorRegistry {
     plugin_manager: Option<Arc<Mutex<PluginManager>>>,
     /// Plugin-based generators
     plugin_generators: RwLock<HashMap<String, Arc<dyn GeneratorPlugin>>>,
+    /// Generator factories by output format name
+    formats: RwLock<HashMap<String, GeneratorFactory>>,
+}
+
+/// Factory creating the generator of an output format with the given options
+type GeneratorFactory = Arc<dyn Fn(&GeneratorOptions) -> Box<dyn Generator> + Send + Sync>;
+
+/// Factory creating generators with `create`, which take no options
+fn factory<G: Generator + 'static>(create: fn() -> G) -> GeneratorFactory {
+    Arc::new(move |_| Box::new(create()))
+}
+
+/// Factory creating generators with `create` from the given options
+fn configurable<G: Generator + 'static>(create: fn(GeneratorOptions) -> G) -> GeneratorFactory {
+    Arc::new(move |options| Box::new(create(options.clone())))
+}
+
+/// Factory creating OWL ontology generators writing `format`
+fn owl_rdf(format: RdfFormat) -> GeneratorFactory {
+    Arc::new(move |options| {
+        Box::new(
+            OwlRdfGenerator::with_options(options.clone())
+                .with_mode(RdfMode::Owl)
+                .with_format(format),
+        )
+    })
 }
 
 impl GeneratorRegistry {
@@ -24,6 +57,7 @@ impl GeneratorRegistry {
             generators: RwLock::new(HashMap::new()),
             plugin_manager: None,
             plugin_generators: RwLock::new(HashMap::new()),
+            formats: RwLock::new(HashMap::new()),
         }
     }
 
@@ -33,24 +67,26 @@ impl GeneratorRegistry {
             generators: RwLock::new(HashMap::new()),
             plugin_manager: Some(plugin_manager),
             plugin_generators: RwLock::new(HashMap::new()),
+            formats: RwLock::new(HashMap::new()),
         }
     }
 
-    /// Create a registry with default generators
+    /// Create a registry with default generators and output formats
     pub async fn with_defaults() -> Self {
         use super::{
-            CsvGenerator, ExcelGenerator, GoGenerator, GraphQLGenerator, GraphvizGenerator,
-            HtmlGenerator, JavaGenerator, JavaScriptGenerator, JsonLdContextGenerator,
-            JsonLdContextGeneratorConfig, JsonLdGenerator, JsonSchemaGenerator, MarkdownGenerator,
-            MermaidDiagramType, MermaidGenerator, NamespaceManagerGenerator,
-            NamespaceManagerGeneratorConfig, OpenApiGenerator, PlantUmlGenerator, PrefixMapFormat,
-            PrefixMapGenerator, PrefixMapGeneratorConfig, ProtobufGenerator, PydanticGenerator,
-            PythonDataclassGenerator, RdfGenerator, RustGenerator, SQLAlchemyGenerator,
-            SQLAlchemyGeneratorConfig, SQLGenerator, ShExGenerator, ShaclGenerator,
-            SparqlGenerator, SssomFormat, SssomGenerator, SssomGeneratorConfig, SummaryFormat,
-            SummaryGenerator, SummaryGeneratorConfig, TargetLanguage as NsTargetLanguage,
-            TypeScriptGenerator, ValidationFramework, YamlValidatorGenerator,
-            YamlValidatorGeneratorConfig, YumlGenerator, typeql_generator::create_typeql_generator,
+            AvroGenerator, CsvGenerator, ExcelGenerator, GoGenerator, GraphQLGenerator,
+            GraphvizGenerator, HtmlGenerator, JavaGenerator, JavaScriptGenerator,
+            JsonLdContextGenerator, JsonLdContextGeneratorConfig, JsonLdGenerator,
+            JsonSchemaGenerator, MarkdownGenerator, MermaidDiagramType, MermaidGenerator,
+            NamespaceManagerGenerator, NamespaceManagerGeneratorConfig, OpenApiGenerator,
+            PlantUmlGenerator, PrefixMapFormat, PrefixMapGenerator, PrefixMapGeneratorConfig,
+            ProtobufGenerator, PydanticGenerator, PythonDataclassGenerator, RdfGenerator,
+            RustGenerator, SQLAlchemyGenerator, SQLAlchemyGeneratorConfig, SQLGenerator,
+            ShExGenerator, ShaclGenerator, SparqlGenerator, SssomFormat, SssomGenerator,
+            SssomGeneratorConfig, SummaryFormat, SummaryGenerator, SummaryGeneratorConfig,
+            TargetLanguage as NsTargetLanguage, TypeScriptGenerator, ValidationFramework,
+            YamlValidatorGenerator, YamlValidatorGeneratorConfig, YumlGenerator,
+            typeql_generator::create_typeql_generator,
         };
 
         let registry = Self::new();
@@ -72,6 +108,7 @@ impl GeneratorRegistry {
             Arc::new(create_typeql_generator()),
             Arc::new(HtmlGenerator::new()),
             Arc::new(JsonSchemaGenerator::new()),
+            Arc::new(AvroGenerator::new()),
             Arc::new(JsonLdGenerator::new()),
             Arc::new(JsonLdContextGenerator::new(
                 JsonLdContextGeneratorConfig::default(),
@@ -80,9 +117,10 @@ impl GeneratorRegistry {
             Arc::new(MermaidGenerator::new()), // ER diagram (default)
             Arc::new(MermaidGenerator::new().with_diagram_type(MermaidDiagramType::ClassDiagram)),
             Arc::new(OpenApiGenerator::new()),
-            Arc::new(RdfGenerator::new()),    // OWL mode
-            Arc::new(RdfGenerator::rdfs()),   // RDFS mode
-            Arc::new(RdfGenerator::simple()), // Simple RDF mode
+            Arc::new(RdfGenerator::new()),      // OWL mode
+            Arc::new(RdfGenerator::rdfs()),     // RDFS mode
+            Arc::new(RdfGenerator::simple()),   // Simple RDF mode
+            Arc::new(OwlRdfGenerator::shacl()), // SHACL shapes mode
             Arc::new(ProtobufGenerator::new()),
             Arc::new(ShaclGenerator::new()),
             Arc::new(ShExGenerator::new()),
@@ -165,9 +203,137 @@ impl GeneratorRegistry {
             }
         }
 
+        let formats = [
+            ("rust", configurable(RustGenerator::with_options)),
+            ("typeql", factory(create_typeql_generator)),
+            ("owl", owl_rdf(RdfFormat::Turtle)),
+            ("ttl", owl_rdf(RdfFormat::Turtle)),
+            ("rdf", owl_rdf(RdfFormat::RdfXml)),
+            ("rdfs", factory(RdfGenerator::rdfs)),
+            ("shacl", configurable(ShaclGenerator::with_options)),
+            ("shex", factory(ShExGenerator::new)),
+            ("sparql", factory(SparqlGenerator::new)),
+            (
+                "json-schema",
+                configurable(JsonSchemaGenerator::with_options),
+            ),
+            ("jsonld", configurable(JsonLdGenerator::with_options)),
+            (
+                "jsonld-context",
+                Arc::new(|options: &GeneratorOptions| -> Box<dyn Generator> {
+                    Box::new(JsonLdContextGenerator::with_options(
+                        JsonLdContextGeneratorConfig::default(),
+                        options.clone(),
+                    ))
+                }),
+            ),
+            ("sql", configurable(SQLGenerator::with_options)),
+            ("graphql", configurable(GraphQLGenerator::with_options)),
+            ("protobuf", configurable(ProtobufGenerator::with_options)),
+            ("avro", configurable(AvroGenerator::with_options)),
+            ("openapi", configurable(OpenApiGenerator::with_options)),
+            (
+                "python",
+                configurable(PythonDataclassGenerator::with_options),
+            ),
+            ("pydantic", configurable(PydanticGenerator::with_options)),
+            (
+                "typescript",
+                configurable(TypeScriptGenerator::with_options),
+            ),
+            (
+                "javascript",
+                configurable(JavaScriptGenerator::with_options),
+            ),
+            ("java", configurable(JavaGenerator::with_options)),
+            ("go", configurable(GoGenerator::with_options)),
+            ("csv", configurable(CsvGenerator::with_options)),
+            ("tsv", factory(CsvGenerator::tsv)),
+            ("markdown", configurable(MarkdownGenerator::with_options)),
+            ("html", configurable(HtmlGenerator::with_options)),
+            ("mermaid", factory(MermaidGenerator::new)),
+            ("plantuml", factory(PlantUmlGenerator::new)),
+            ("graphviz", factory(GraphvizGenerator::new)),
+            ("yuml", factory(YumlGenerator::new)),
+        ];
+        for (name, factory) in formats {
+            if let Err(e) = registry
+                .register_format(name, move |options| factory(options))
+                .await
+            {
+                eprintln!("Failed to register format: {e}");
+            }
+        }
+
         registry
     }
 
+    /// Register the generator factory of an output format
+    ///
+    /// # Errors
+    ///
+    /// Returns `GeneratorError::Configuration` if a format with the same name is already registered.
+    pub async fn register_format(
+        &self,
+        name: impl Into<String>,
+        factory: impl Fn(&GeneratorOptions) -> Box<dyn Generator> + Send + Sync + 'static,
+    ) -> GeneratorResult<()> {
+        let name = name.into();
+
+        let mut formats = self.formats.write().await;
+
+        if formats.contains_key(&name) {
+            return Err(GeneratorError::Configuration(format!(
+                "Format '{name}' is already registered"
+            )));
+        }
+
+        formats.insert(name, Arc::new(factory));
+        Ok(())
+    }
+
+    /// Generate output in the named format
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no such format is registered or generation fails.
+    pub async fn generate(
+        &self,
+        format: &str,
+        schema: &SchemaDefinition,
+    ) -> linkml_core::error::Result<String> {
+        let Some(generator) = self
+            .create_format(format, &GeneratorOptions::default())
+            .await
+        else {
+            return Err(LinkMLError::config(format!(
+                "Unknown output format '{format}'; available formats: {}",
+                self.list_formats().await.join(", ")
+            )));
+        };
+        generator.generate(schema)
+    }
+
+    /// Create the generator of the named output format with `options`
+    ///
+    /// Formats whose generators take no [`GeneratorOptions`] ignore them.
+    pub async fn create_format(
+        &self,
+        format: &str,
+        options: &GeneratorOptions,
+    ) -> Option<Box<dyn Generator>> {
+        let factory = self.formats.read().await.get(format).cloned()?;
+        Some(factory(options))
+    }
+
+    /// Get all registered output format names, sorted
+    pub async fn list_formats(&self) -> Vec<String> {
+        let formats = self.formats.read().await;
+        let mut names: Vec<String> = formats.keys().cloned().collect();
+        names.sort();
+        names
+    }
+
     /// Register a generator
     ///
     /// # Errors
@@ -387,7 +553,8 @@ pub struct GeneratorInfo {
 mod tests {
     use super::*;
 
-    use linkml_core::{LinkMLError, types::SchemaDefinition};
+    use crate::generator::RustGenerator;
+    use linkml_core::types::ClassDefinition;
 
     struct TestGenerator {
         name: String,
@@ -486,4 +653,82 @@ mod tests {
         assert!(result.is_err());
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_generate_by_format_name() -> anyhow::Result<()> {
+        let registry = GeneratorRegistry::with_defaults().await;
+        let mut schema = SchemaDefinition::new("countries");
+        schema
+            .classes
+            .insert("Country".to_string(), ClassDefinition::new("Country"));
+
+        let rust = registry.generate("rust", &schema).await?;
+        assert!(rust.contains("pub struct Country"), "{rust}");
+
+        for format in ["owl", "ttl"] {
+            let turtle = registry.generate(format, &schema).await?;
+            assert!(turtle.contains("a owl:Class"), "{format}: {turtle}");
+        }
+        let rdf_xml = registry.generate("rdf", &schema).await?;
+        assert!(rdf_xml.contains("<rdf:RDF"), "{rdf_xml}");
+
+        let error = registry
+            .generate("cobol", &schema)
+            .await
+            .expect_err("unknown formats should be rejected");
+        let message = error.to_string();
+        assert!(
+            message.contains("Unknown output format 'cobol'"),
+            "{message}"
+        );
+        assert!(message.contains("rust"), "{message}");
+
+        // Downstream crates can add formats of their own
+        registry
+            .register_format("test", |_| {
+                Box::new(TestGenerator {
+                    name: "test".to_string(),
+                })
+            })
+            .await?;
+        assert_eq!(registry.generate("test", &schema).await?, "");
+        assert!(
+            registry
+                .register_format("rust", |_| Box::new(RustGenerator::new()))
+                .await
+                .is_err()
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_create_format_applies_options() -> anyhow::Result<()> {
+        let registry = GeneratorRegistry::with_defaults().await;
+        let mut schema = SchemaDefinition::new("countries");
+        let mut country = ClassDefinition::new("Country");
+        country.description = Some("A sovereign state".to_string());
+        schema.classes.insert("Country".to_string(), country);
+
+        let plain = registry
+            .create_format("json-schema", &GeneratorOptions::default())
+            .await
+            .ok_or_else(|| anyhow::anyhow!("json-schema should be registered"))?
+            .generate(&schema)?;
+        assert!(!plain.contains("A sovereign state"), "{plain}");
+
+        let documented = registry
+            .create_format("json-schema", &GeneratorOptions::new().with_docs(true))
+            .await
+            .ok_or_else(|| anyhow::anyhow!("json-schema should be registered"))?
+            .generate(&schema)?;
+        assert!(documented.contains("A sovereign state"), "{documented}");
+
+        assert!(
+            registry
+                .create_format("cobol", &GeneratorOptions::default())
+                .await
+                .is_none()
+        );
+        Ok(())
+    }
 }
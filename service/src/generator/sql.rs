@@ -4,18 +4,174 @@ use super::options::{GeneratorOptions, IndentStyle};
 use super::traits::{
     AsyncGenerator, CodeFormatter, GeneratedOutput, Generator, GeneratorError, GeneratorResult,
 };
+use crate::inheritance::InheritanceResolver;
 use async_trait::async_trait;
 use linkml_core::error::LinkMLError;
 use linkml_core::prelude::*;
 use std::collections::{HashMap, HashSet};
 use std::fmt::Write;
 
+/// `SQL` dialect targeted by the generated DDL
+///
+/// The dialect decides identifier quoting and the column types used for
+/// booleans, datetimes, enums and generated keys. It can also be selected per
+/// run with the `dialect` custom generator option.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SqlDialect {
+    /// ANSI SQL
+    #[default]
+    Standard,
+    /// `PostgreSQL`
+    PostgreSQL,
+    /// `SQLite`
+    SQLite,
+    /// `MySQL`
+    MySQL,
+}
+
+impl SqlDialect {
+    /// Parse a dialect name as accepted by the `dialect` custom option
+    #[must_use]
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "standard" | "ansi" => Some(Self::Standard),
+            "postgresql" | "postgres" => Some(Self::PostgreSQL),
+            "sqlite" => Some(Self::SQLite),
+            "mysql" => Some(Self::MySQL),
+            _ => None,
+        }
+    }
+
+    /// Canonical name of the dialect
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Standard => "standard",
+            Self::PostgreSQL => "postgresql",
+            Self::SQLite => "sqlite",
+            Self::MySQL => "mysql",
+        }
+    }
+
+    /// Quote an identifier if it is a reserved word or not a plain
+    /// lowercase name
+    ///
+    /// `MySQL` quotes with backticks; the other dialects use ANSI double
+    /// quotes.
+    #[must_use]
+    pub fn quote_identifier(self, identifier: &str) -> String {
+        let plain = identifier
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_lowercase() || c == '_')
+            && identifier
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+        if plain && !is_reserved_word(identifier) {
+            return identifier.to_string();
+        }
+
+        match self {
+            Self::MySQL => format!("`{}`", identifier.replace('`', "``")),
+            _ => format!("\"{}\"", identifier.replace('"', "\"\"")),
+        }
+    }
+}
+
+/// Check whether an identifier is a reserved `SQL` keyword
+fn is_reserved_word(identifier: &str) -> bool {
+    matches!(
+        identifier.to_lowercase().as_str(),
+        "all"
+            | "alter"
+            | "and"
+            | "as"
+            | "asc"
+            | "between"
+            | "by"
+            | "case"
+            | "check"
+            | "column"
+            | "constraint"
+            | "create"
+            | "current_date"
+            | "current_time"
+            | "current_timestamp"
+            | "database"
+            | "default"
+            | "delete"
+            | "desc"
+            | "distinct"
+            | "drop"
+            | "else"
+            | "end"
+            | "exists"
+            | "foreign"
+            | "from"
+            | "grant"
+            | "group"
+            | "having"
+            | "in"
+            | "index"
+            | "insert"
+            | "into"
+            | "is"
+            | "join"
+            | "key"
+            | "like"
+            | "limit"
+            | "not"
+            | "null"
+            | "offset"
+            | "on"
+            | "or"
+            | "order"
+            | "primary"
+            | "references"
+            | "revoke"
+            | "role"
+            | "schema"
+            | "select"
+            | "set"
+            | "table"
+            | "then"
+            | "to"
+            | "union"
+            | "unique"
+            | "update"
+            | "user"
+            | "values"
+            | "view"
+            | "when"
+            | "where"
+            | "with"
+    )
+}
+
+/// Primary key of a generated table
+struct TableKey {
+    /// Key column name (unquoted)
+    column: String,
+    /// Column type used in the table definition
+    sql_type: String,
+    /// Column type used by columns referencing this key
+    reference_type: String,
+}
+
 /// `SQL` DDL generator for `LinkML` schemas
+///
+/// Each concrete class becomes a table whose columns are the class's induced
+/// slots. The identifier slot, if any, is the primary key; otherwise a
+/// synthetic `id` column is added. Slots whose range is a class become
+/// foreign keys, and multivalued slots are stored in a join table keyed by
+/// the owning row.
 pub struct SQLGenerator {
     /// Generator name
     name: String,
     /// Generator options
     options: super::traits::GeneratorOptions,
+    /// Dialect used when the `dialect` custom option is not set
+    dialect: SqlDialect,
 }
 
 impl SQLGenerator {
@@ -25,6 +181,7 @@ impl SQLGenerator {
         Self {
             name: "sql".to_string(),
             options: super::traits::GeneratorOptions::default(),
+            dialect: SqlDialect::default(),
         }
     }
 
@@ -36,12 +193,135 @@ impl SQLGenerator {
         generator
     }
 
+    /// Set the dialect used when the `dialect` custom option is not set
+    #[must_use]
+    pub fn with_dialect(mut self, dialect: SqlDialect) -> Self {
+        self.dialect = dialect;
+        self
+    }
+
     /// Convert `fmt::Error` to `GeneratorError`
     fn fmt_error_to_generator_error(e: std::fmt::Error) -> GeneratorError {
         GeneratorError::Io(std::io::Error::other(e))
     }
 
+    /// Resolve the dialect for a generation run
+    fn dialect(&self, options: &GeneratorOptions) -> SqlDialect {
+        options
+            .get_custom("dialect")
+            .and_then(|name| SqlDialect::parse(name))
+            .unwrap_or(self.dialect)
+    }
+
+    /// Whether a table is generated for the class
+    fn generates_table(class: &ClassDefinition, options: &GeneratorOptions) -> bool {
+        class.abstract_ != Some(true)
+            || options
+                .get_custom("generate_abstract")
+                .map(std::string::String::as_str)
+                == Some("true")
+    }
+
+    /// Induced slots of a class, in declaration order
+    fn induced_slots(
+        class_name: &str,
+        schema: &SchemaDefinition,
+    ) -> GeneratorResult<Vec<SlotDefinition>> {
+        Ok(InheritanceResolver::new(schema).induced_slots(class_name)?)
+    }
+
+    /// Determine the primary key of a class's table
+    fn table_key(
+        &self,
+        class_name: &str,
+        schema: &SchemaDefinition,
+        options: &GeneratorOptions,
+        dialect: SqlDialect,
+    ) -> GeneratorResult<TableKey> {
+        let identifier = Self::induced_slots(class_name, schema)?
+            .into_iter()
+            .find(|slot| slot.identifier == Some(true) && slot.multivalued != Some(true));
+
+        Ok(match identifier {
+            Some(slot) => {
+                let sql_type = self.get_base_sql_type(slot.range.as_ref(), schema, options);
+                TableKey {
+                    column: self.convert_column_name(&slot.name),
+                    reference_type: sql_type.clone(),
+                    sql_type,
+                }
+            }
+            None => TableKey {
+                column: "id".to_string(),
+                sql_type: self.get_id_type(options),
+                reference_type: Self::get_id_reference_type(options, dialect),
+            },
+        })
+    }
+
+    /// Table key of the class a slot refers to, if its range is a class
+    /// with a generated table
+    fn referenced_key(
+        &self,
+        slot: &SlotDefinition,
+        schema: &SchemaDefinition,
+        options: &GeneratorOptions,
+        dialect: SqlDialect,
+    ) -> GeneratorResult<Option<(String, TableKey)>> {
+        let Some(range) = &slot.range else {
+            return Ok(None);
+        };
+        match schema.classes.get(range) {
+            Some(class) if Self::generates_table(class, options) => Ok(Some((
+                range.clone(),
+                self.table_key(range, schema, options, dialect)?,
+            ))),
+            _ => Ok(None),
+        }
+    }
+
+    /// Order classes so that tables referenced by foreign keys are created
+    /// before the tables referring to them
+    ///
+    /// Reference cycles cannot be ordered; foreign keys that point forward
+    /// in the returned order are added after all tables are created.
+    fn table_order(schema: &SchemaDefinition) -> GeneratorResult<Vec<&str>> {
+        fn visit<'a>(
+            class_name: &'a str,
+            schema: &'a SchemaDefinition,
+            visited: &mut HashSet<&'a str>,
+            order: &mut Vec<&'a str>,
+        ) -> GeneratorResult<()> {
+            if !visited.insert(class_name) {
+                return Ok(());
+            }
+            for slot in SQLGenerator::induced_slots(class_name, schema)? {
+                if slot.multivalued == Some(true) {
+                    continue;
+                }
+                if let Some(range) = &slot.range
+                    && let Some((target, _)) = schema.classes.get_key_value(range)
+                {
+                    visit(target, schema, visited, order)?;
+                }
+            }
+            order.push(class_name);
+            Ok(())
+        }
+
+        let mut visited = HashSet::new();
+        let mut order = Vec::new();
+        for class_name in schema.classes.keys() {
+            visit(class_name, schema, &mut visited, &mut order)?;
+        }
+        Ok(order)
+    }
+
     /// Generate `SQL` table for a class
+    ///
+    /// `created` holds the classes whose tables are already defined. Foreign
+    /// keys to any other table are returned separately as `ALTER TABLE`
+    /// statements to run once all tables exist.
     fn generate_table(
         &self,
         class_name: &str,
@@ -49,20 +329,19 @@ impl SQLGenerator {
         schema: &SchemaDefinition,
         options: &GeneratorOptions,
         indent: &IndentStyle,
-    ) -> GeneratorResult<String> {
+        created: &HashSet<&str>,
+    ) -> GeneratorResult<(String, Vec<String>)> {
         let mut output = String::new();
 
         // Skip abstract classes unless requested
-        if class.abstract_ == Some(true)
-            && options
-                .get_custom("generate_abstract")
-                .map(std::string::String::as_str)
-                != Some("true")
-        {
-            return Ok(output);
+        if !Self::generates_table(class, options) {
+            return Ok((output, Vec::new()));
         }
 
+        let dialect = self.dialect(options);
         let table_name = self.convert_table_name(class_name);
+        let table = dialect.quote_identifier(&table_name);
+        let key = self.table_key(class_name, schema, options, dialect)?;
 
         // Table comment
         if options.include_docs
@@ -72,44 +351,36 @@ impl SQLGenerator {
         }
 
         // CREATE TABLE statement
-        writeln!(&mut output, "CREATE TABLE {table_name} (")
+        writeln!(&mut output, "CREATE TABLE {table} (")
             .map_err(Self::fmt_error_to_generator_error)?;
 
-        // Primary key (ID column)
-        writeln!(
-            &mut output,
-            "{}id {} PRIMARY KEY,",
+        // Primary key, followed by columns and foreign key constraints
+        let mut definitions = vec![format!(
+            "{}{} {} PRIMARY KEY",
             indent.single(),
-            self.get_id_type(options)
-        )
-        .map_err(Self::fmt_error_to_generator_error)?;
-
-        // Generate columns
-        let columns = self.generate_columns(class, schema, options, indent)?;
-        for (i, column) in columns.iter().enumerate() {
-            write!(&mut output, "{column}").map_err(Self::fmt_error_to_generator_error)?;
-            if i < columns.len() - 1 {
-                writeln!(&mut output, ",").map_err(Self::fmt_error_to_generator_error)?;
-            }
-        }
-
-        // Foreign key constraints
-        let fk_constraints = self.generate_foreign_keys(class, schema, indent)?;
-        if !fk_constraints.is_empty() {
-            writeln!(&mut output, ",").map_err(Self::fmt_error_to_generator_error)?;
-            for (i, constraint) in fk_constraints.iter().enumerate() {
-                write!(&mut output, "{constraint}").map_err(Self::fmt_error_to_generator_error)?;
-                if i < fk_constraints.len() - 1 {
-                    writeln!(&mut output, ",").map_err(Self::fmt_error_to_generator_error)?;
-                }
+            dialect.quote_identifier(&key.column),
+            key.sql_type
+        )];
+        definitions.extend(self.generate_columns(class_name, schema, options, indent)?);
+        let mut deferred = Vec::new();
+        for (target, constraint) in self.generate_foreign_keys(class_name, schema, options)? {
+            // SQLite resolves references lazily, other dialects need the
+            // target table to exist
+            if dialect == SqlDialect::SQLite
+                || target == class_name
+                || created.contains(target.as_str())
+            {
+                definitions.push(format!("{}{constraint}", indent.single()));
+            } else {
+                deferred.push(format!("ALTER TABLE {table} ADD {constraint};"));
             }
         }
-
-        writeln!(&mut output).map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(&mut output, "{}", definitions.join(",\n"))
+            .map_err(Self::fmt_error_to_generator_error)?;
         writeln!(&mut output, ");").map_err(Self::fmt_error_to_generator_error)?;
 
         // Create indexes
-        let indexes = self.generate_indexes(&table_name, class, schema, options)?;
+        let indexes = self.generate_indexes(&table_name, class_name, schema, options)?;
         if !indexes.is_empty() {
             writeln!(&mut output).map_err(Self::fmt_error_to_generator_error)?;
             for index in indexes {
@@ -117,18 +388,22 @@ impl SQLGenerator {
             }
         }
 
-        Ok(output)
+        Ok((output, deferred))
     }
 
     /// Generate columns for a table
+    ///
+    /// The identifier slot is the primary key and multivalued slots live in
+    /// join tables, so neither produces a column here.
     fn generate_columns(
         &self,
-        class: &ClassDefinition,
+        class_name: &str,
         schema: &SchemaDefinition,
         options: &GeneratorOptions,
         indent: &IndentStyle,
     ) -> GeneratorResult<Vec<String>> {
         let mut columns = Vec::new();
+        let dialect = self.dialect(options);
 
         // Add audit columns if requested
         if options
@@ -148,90 +423,90 @@ impl SQLGenerator {
             columns.push(format!("{}updated_by VARCHAR(255)", indent.single()));
         }
 
-        // Collect all slots including inherited ones
-        let slots = self.collect_all_slots(class, schema)?;
+        let key = self.table_key(class_name, schema, options, dialect)?;
 
-        for slot_name in &slots {
-            if let Some(slot) = schema.slots.get(slot_name) {
-                let column_name = self.convert_column_name(slot_name);
-                let column_type = self.get_sql_type(slot, schema, options)?;
+        for slot in Self::induced_slots(class_name, schema)? {
+            let column_name = self.convert_column_name(&slot.name);
+            if slot.multivalued == Some(true) || column_name == key.column {
+                continue;
+            }
 
-                let mut column_def = format!("{}{} {}", indent.single(), column_name, column_type);
+            let column = dialect.quote_identifier(&column_name);
+            let column_type = match self.referenced_key(&slot, schema, options, dialect)? {
+                Some((_, target_key)) => target_key.reference_type,
+                None => self.get_base_sql_type(slot.range.as_ref(), schema, options),
+            };
 
-                // Add constraints
-                if slot.required == Some(true) {
-                    column_def.push_str(" NOT NULL");
-                }
+            let mut column_def = String::new();
 
-                // Add default value if specified
-                if let Some(default) = options.get_custom(&format!("default_{slot_name}")) {
-                    write!(column_def, " DEFAULT {default}")
-                        .expect("write! to String should never fail");
-                }
+            // Column comment on its own line so it cannot swallow the separator
+            if options.include_docs
+                && dialect == SqlDialect::PostgreSQL
+                && let Some(desc) = &slot.description
+            {
+                writeln!(column_def, "{}-- {desc}", indent.single())
+                    .expect("write! to String should never fail");
+            }
 
-                // Add CHECK constraint for pattern
-                if let Some(pattern) = &slot.pattern
-                    && options
-                        .get_custom("dialect")
-                        .map(std::string::String::as_str)
-                        == Some("postgresql")
-                {
-                    write!(column_def, " CHECK ({column_name} ~ '{pattern}')")
-                        .expect("Writing to string should never fail");
-                }
+            write!(column_def, "{}{} {}", indent.single(), column, column_type)
+                .expect("write! to String should never fail");
 
-                // Add column comment if dialect supports it
-                if options.include_docs
-                    && options
-                        .get_custom("dialect")
-                        .map(std::string::String::as_str)
-                        == Some("postgresql")
-                    && let Some(desc) = &slot.description
-                {
-                    write!(column_def, " -- {desc}").expect("write! to String should never fail");
-                }
+            // Add constraints
+            if slot.required == Some(true) {
+                column_def.push_str(" NOT NULL");
+            }
 
-                columns.push(column_def);
+            // Add default value if specified
+            if let Some(default) = options.get_custom(&format!("default_{}", slot.name)) {
+                write!(column_def, " DEFAULT {default}")
+                    .expect("write! to String should never fail");
+            }
+
+            // Add CHECK constraint for pattern
+            if let Some(pattern) = &slot.pattern
+                && dialect == SqlDialect::PostgreSQL
+            {
+                write!(
+                    column_def,
+                    " CHECK ({column} ~ '{}')",
+                    pattern.replace('\'', "''")
+                )
+                .expect("Writing to string should never fail");
             }
+
+            columns.push(column_def);
         }
 
         Ok(columns)
     }
 
-    /// Generate foreign key constraints
+    /// Generate foreign key constraints, paired with the referenced class
     fn generate_foreign_keys(
         &self,
-        class: &ClassDefinition,
+        class_name: &str,
         schema: &SchemaDefinition,
-        indent: &IndentStyle,
-    ) -> GeneratorResult<Vec<String>> {
+        options: &GeneratorOptions,
+    ) -> GeneratorResult<Vec<(String, String)>> {
         let mut constraints = Vec::new();
-        let slots = self.collect_all_slots(class, schema)?;
+        let dialect = self.dialect(options);
+        let table_name = self.convert_table_name(class_name);
 
-        for slot_name in &slots {
-            if let Some(slot) = schema.slots.get(slot_name)
-                && let Some(range) = &slot.range
+        for slot in Self::induced_slots(class_name, schema)? {
+            if slot.multivalued == Some(true) {
+                continue;
+            }
+            if let Some((target, target_key)) =
+                self.referenced_key(&slot, schema, options, dialect)?
             {
-                // Check if range references another class
-                if schema.classes.contains_key(range) {
-                    let column_name = self.convert_column_name(slot_name);
-                    let ref_table = self.convert_table_name(range);
-                    let constraint_name = format!(
-                        "fk_{}_{}",
-                        self.convert_table_name(&class.name),
-                        column_name
-                    );
-
-                    let constraint = format!(
-                        "{}CONSTRAINT {} FOREIGN KEY ({}) REFERENCES {}(id)",
-                        indent.single(),
-                        constraint_name,
-                        column_name,
-                        ref_table
-                    );
-
-                    constraints.push(constraint);
-                }
+                let column_name = self.convert_column_name(&slot.name);
+                let constraint = format!(
+                    "CONSTRAINT {} FOREIGN KEY ({}) REFERENCES {}({})",
+                    dialect.quote_identifier(&format!("fk_{table_name}_{column_name}")),
+                    dialect.quote_identifier(&column_name),
+                    dialect.quote_identifier(&self.convert_table_name(&target)),
+                    dialect.quote_identifier(&target_key.column)
+                );
+                constraints.push((target, constraint));
             }
         }
 
@@ -242,36 +517,28 @@ impl SQLGenerator {
     fn generate_indexes(
         &self,
         table_name: &str,
-        class: &ClassDefinition,
+        class_name: &str,
         schema: &SchemaDefinition,
         options: &GeneratorOptions,
     ) -> GeneratorResult<Vec<String>> {
         let mut indexes = Vec::new();
-
-        // Add indexes for foreign keys
-        let slots = self.collect_all_slots(class, schema)?;
-
-        for slot_name in &slots {
-            if let Some(slot) = schema.slots.get(slot_name) {
-                let column_name = self.convert_column_name(slot_name);
-
-                // Index foreign keys
-                if let Some(range) = &slot.range
-                    && schema.classes.contains_key(range)
-                {
-                    let index_name = format!("idx_{table_name}_{column_name}");
-                    indexes.push(format!(
-                        "CREATE INDEX {index_name} ON {table_name}({column_name});"
-                    ));
-                }
-
-                // Index identifier fields
-                if slot.identifier == Some(true) {
-                    let index_name = format!("idx_{table_name}_unique_{column_name}");
-                    indexes.push(format!(
-                        "CREATE UNIQUE INDEX {index_name} ON {table_name}({column_name});"
-                    ));
-                }
+        let dialect = self.dialect(options);
+        let table = dialect.quote_identifier(table_name);
+
+        // Index foreign keys
+        for slot in Self::induced_slots(class_name, schema)? {
+            if slot.multivalued != Some(true)
+                && self
+                    .referenced_key(&slot, schema, options, dialect)?
+                    .is_some()
+            {
+                let column_name = self.convert_column_name(&slot.name);
+                let index_name =
+                    dialect.quote_identifier(&format!("idx_{table_name}_{column_name}"));
+                indexes.push(format!(
+                    "CREATE INDEX {index_name} ON {table}({});",
+                    dialect.quote_identifier(&column_name)
+                ));
             }
         }
 
@@ -282,115 +549,96 @@ impl SQLGenerator {
             == Some("true")
         {
             indexes.push(format!(
-                "CREATE INDEX idx_{table_name}_created_at ON {table_name}(created_at);"
+                "CREATE INDEX idx_{table_name}_created_at ON {table}(created_at);"
             ));
             indexes.push(format!(
-                "CREATE INDEX idx_{table_name}_updated_at ON {table_name}(updated_at);"
+                "CREATE INDEX idx_{table_name}_updated_at ON {table}(updated_at);"
             ));
         }
 
         Ok(indexes)
     }
 
-    /// Generate junction tables for many-to-many relationships
-    fn generate_junction_tables(
+    /// Generate join tables for the multivalued slots of a class
+    ///
+    /// Each join table holds one row per value, keyed by the owning row and
+    /// the value. Values of class-ranged slots reference the target table.
+    fn generate_join_tables(
         &self,
+        class_name: &str,
+        class: &ClassDefinition,
         schema: &SchemaDefinition,
         options: &GeneratorOptions,
         indent: &IndentStyle,
     ) -> GeneratorResult<String> {
         let mut output = String::new();
-        let mut generated = HashSet::new();
+        if !Self::generates_table(class, options) {
+            return Ok(output);
+        }
 
-        for (class_name, class) in &schema.classes {
-            if class.abstract_ == Some(true) {
+        let dialect = self.dialect(options);
+        let table_name = self.convert_table_name(class_name);
+        let key = self.table_key(class_name, schema, options, dialect)?;
+        let owner_column = format!("{table_name}_{}", key.column);
+
+        for slot in Self::induced_slots(class_name, schema)? {
+            if slot.multivalued != Some(true) {
                 continue;
             }
 
-            let slots = self.collect_all_slots(class, schema)?;
-
-            for slot_name in &slots {
-                if let Some(slot) = schema.slots.get(slot_name) {
-                    // Check if this is a many-to-many relationship
-                    if slot.multivalued == Some(true)
-                        && let Some(range) = &slot.range
-                        && schema.classes.contains_key(range)
-                    {
-                        // Create junction table name
-                        let table1 = self.convert_table_name(class_name);
-                        let table2 = self.convert_table_name(range);
-                        let junction_name = if table1 < table2 {
-                            format!("{table1}_{table2}")
-                        } else {
-                            format!("{table2}_{table1}")
-                        };
-
-                        // Only generate once
-                        if generated.insert(junction_name.clone()) {
-                            writeln!(&mut output).map_err(Self::fmt_error_to_generator_error)?;
-                            writeln!(
-                                &mut output,
-                                "-- Junction table for {class_name} <-> {range}"
-                            )
-                            .map_err(Self::fmt_error_to_generator_error)?;
-                            writeln!(&mut output, "CREATE TABLE {junction_name} (")
-                                .map_err(Self::fmt_error_to_generator_error)?;
-
-                            let id_type = self.get_id_type(options);
-
-                            writeln!(
-                                &mut output,
-                                "{}{}_id {} NOT NULL,",
-                                indent.single(),
-                                table1,
-                                id_type
-                            )
-                            .map_err(Self::fmt_error_to_generator_error)?;
-                            writeln!(
-                                &mut output,
-                                "{}{}_id {} NOT NULL,",
-                                indent.single(),
-                                table2,
-                                id_type
-                            )
-                            .map_err(Self::fmt_error_to_generator_error)?;
-
-                            writeln!(
-                                &mut output,
-                                "{}PRIMARY KEY ({}_id, {}_id),",
-                                indent.single(),
-                                table1,
-                                table2
-                            )
-                            .map_err(Self::fmt_error_to_generator_error)?;
-
-                            writeln!(
-                                &mut output,
-                                "{}FOREIGN KEY ({}_id) REFERENCES {}(id),",
-                                indent.single(),
-                                table1,
-                                table1
-                            )
-                            .map_err(Self::fmt_error_to_generator_error)?;
-                            writeln!(
-                                &mut output,
-                                "{}FOREIGN KEY ({}_id) REFERENCES {}(id)",
-                                indent.single(),
-                                table2,
-                                table2
-                            )
-                            .map_err(Self::fmt_error_to_generator_error)?;
-
-                            writeln!(&mut output, ");")
-                                .map_err(Self::fmt_error_to_generator_error)?;
-
-                            // Create indexes
-                            writeln!(&mut output, "CREATE INDEX idx_{junction_name}_{table1}_id ON {junction_name}({table1}_id);").map_err(Self::fmt_error_to_generator_error)?;
-                            writeln!(&mut output, "CREATE INDEX idx_{junction_name}_{table2}_id ON {junction_name}({table2}_id);").map_err(Self::fmt_error_to_generator_error)?;
-                        }
-                    }
-                }
+            let slot_column = self.convert_column_name(&slot.name);
+            let join_name = format!("{table_name}_{slot_column}");
+            let value_column = if slot_column == owner_column {
+                format!("{slot_column}_value")
+            } else {
+                slot_column
+            };
+            let target = self.referenced_key(&slot, schema, options, dialect)?;
+            let value_type = match &target {
+                Some((_, target_key)) => target_key.reference_type.clone(),
+                None => self.get_base_sql_type(slot.range.as_ref(), schema, options),
+            };
+
+            let join_table = dialect.quote_identifier(&join_name);
+            let owner = dialect.quote_identifier(&owner_column);
+            let value = dialect.quote_identifier(&value_column);
+
+            let mut definitions = vec![
+                format!("{}{owner} {} NOT NULL", indent.single(), key.reference_type),
+                format!("{}{value} {value_type} NOT NULL", indent.single()),
+                format!("{}PRIMARY KEY ({owner}, {value})", indent.single()),
+                format!(
+                    "{}CONSTRAINT {} FOREIGN KEY ({owner}) REFERENCES {}({}) ON DELETE CASCADE",
+                    indent.single(),
+                    dialect.quote_identifier(&format!("fk_{join_name}_{owner_column}")),
+                    dialect.quote_identifier(&table_name),
+                    dialect.quote_identifier(&key.column)
+                ),
+            ];
+            if let Some((target_class, target_key)) = &target {
+                definitions.push(format!(
+                    "{}CONSTRAINT {} FOREIGN KEY ({value}) REFERENCES {}({})",
+                    indent.single(),
+                    dialect.quote_identifier(&format!("fk_{join_name}_{value_column}")),
+                    dialect.quote_identifier(&self.convert_table_name(target_class)),
+                    dialect.quote_identifier(&target_key.column)
+                ));
             }
+
+            writeln!(&mut output).map_err(Self::fmt_error_to_generator_error)?;
+            writeln!(&mut output, "-- Join table for {class_name}.{}", slot.name)
+                .map_err(Self::fmt_error_to_generator_error)?;
+            writeln!(&mut output, "CREATE TABLE {join_table} (")
+                .map_err(Self::fmt_error_to_generator_error)?;
+            writeln!(&mut output, "{}", definitions.join(",\n"))
+                .map_err(Self::fmt_error_to_generator_error)?;
+            writeln!(&mut output, ");").map_err(Self::fmt_error_to_generator_error)?;
+            writeln!(
+                &mut output,
+                "CREATE INDEX {} ON {join_table}({value});",
+                dialect.quote_identifier(&format!("idx_{join_name}_{value_column}"))
+            )
+            .map_err(Self::fmt_error_to_generator_error)?;
         }
 
         Ok(output)
@@ -409,11 +657,9 @@ impl SQLGenerator {
             return Ok(output);
         }
 
-        let dialect = options
-            .get_custom("dialect")
-            .map_or("standard", std::string::String::as_str);
+        let dialect = self.dialect(options);
 
-        if dialect == "postgresql" {
+        if dialect == SqlDialect::PostgreSQL {
             // PostgreSQL native ENUM types
             writeln!(&mut output, "-- Enum Types").map_err(Self::fmt_error_to_generator_error)?;
             for (enum_name, enum_def) in &schema.enums {
@@ -424,7 +670,7 @@ impl SQLGenerator {
                         .map_err(Self::fmt_error_to_generator_error)?;
                 }
 
-                let type_name = self.convert_table_name(enum_name);
+                let type_name = dialect.quote_identifier(&self.convert_table_name(enum_name));
                 write!(&mut output, "CREATE TYPE {type_name} AS ENUM (")
                     .map_err(Self::fmt_error_to_generator_error)?;
 
@@ -436,7 +682,7 @@ impl SQLGenerator {
                             PermissibleValue::Simple(text)
                             | PermissibleValue::Complex { text, .. } => text,
                         };
-                        format!("'{text}'")
+                        format!("'{}'", self.escape_string(text))
                     })
                     .collect();
 
@@ -456,7 +702,8 @@ impl SQLGenerator {
                         .map_err(Self::fmt_error_to_generator_error)?;
                 }
 
-                let table_name = format!("{}_enum", self.convert_table_name(enum_name));
+                let table_name = dialect
+                    .quote_identifier(&format!("{}_enum", self.convert_table_name(enum_name)));
                 writeln!(&mut output, "CREATE TABLE {table_name} (")
                     .map_err(Self::fmt_error_to_generator_error)?;
                 writeln!(
@@ -480,6 +727,7 @@ impl SQLGenerator {
                 for value in &enum_def.permissible_values {
                     match value {
                         PermissibleValue::Simple(text) => {
+                            let text = self.escape_string(text);
                             writeln!(&mut output,
                                 "INSERT INTO {table_name} (code, label) VALUES ('{text}', '{text}');"
                             ).map_err(Self::fmt_error_to_generator_error)?;
@@ -487,9 +735,10 @@ impl SQLGenerator {
                         PermissibleValue::Complex {
                             text, description, ..
                         } => {
+                            let text = self.escape_string(text);
                             let desc_sql = description.as_deref().map_or_else(
                                 || "NULL".to_string(),
-                                |d| format!("'{}'", d.replace('\'', "''")),
+                                |d| format!("'{}'", self.escape_string(d)),
                             );
 
                             writeln!(&mut output,
@@ -505,89 +754,33 @@ impl SQLGenerator {
         Ok(output)
     }
 
-    /// Collect all slots including inherited ones
-    fn collect_all_slots(
-        &self,
-        class: &ClassDefinition,
-        schema: &SchemaDefinition,
-    ) -> GeneratorResult<Vec<String>> {
-        let mut all_slots = Vec::new();
-        let mut seen = HashSet::new();
-
-        // Add direct slots
-        for slot in &class.slots {
-            if seen.insert(slot.clone()) {
-                all_slots.push(slot.clone());
-            }
-        }
-
-        // Add inherited slots
-        if let Some(parent) = &class.is_a
-            && let Some(parent_class) = schema.classes.get(parent)
-        {
-            let parent_slots = self.collect_all_slots(parent_class, schema)?;
-            for slot in parent_slots {
-                if seen.insert(slot.clone()) {
-                    all_slots.push(slot);
-                }
-            }
-        }
-
-        Ok(all_slots)
-    }
-
-    /// Get `SQL` type for a slot
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the slot type cannot be mapped to `SQL`.
-    fn get_sql_type(
-        &self,
-        slot: &SlotDefinition,
-        schema: &SchemaDefinition,
-        options: &GeneratorOptions,
-    ) -> GeneratorResult<String> {
-        let base_type = self.get_base_sql_type(slot.range.as_ref(), schema, options);
-
-        // Handle multivalued slots (arrays)
-        if slot.multivalued == Some(true) {
-            let dialect = options
-                .get_custom("dialect")
-                .map_or("standard", std::string::String::as_str);
-            match dialect {
-                "postgresql" => Ok(format!("{base_type}[]")),
-                _ => Ok("TEXT".to_string()), // JSON array as text
-            }
-        } else {
-            Ok(base_type)
-        }
-    }
-
     /// Get base `SQL` type from `LinkML` range
+    ///
+    /// Custom types are resolved through their `typeof` chain to the
+    /// built-in type they derive from.
     fn get_base_sql_type(
         &self,
         range: Option<&String>,
         schema: &SchemaDefinition,
         options: &GeneratorOptions,
     ) -> String {
-        let dialect = options
-            .get_custom("dialect")
-            .map_or("standard", std::string::String::as_str);
+        let dialect = self.dialect(options);
 
-        match range.map(String::as_str) {
+        match range.map(|name| schema.base_type(name)) {
             Some("string" | "str") => "VARCHAR(255)".to_string(),
             Some("integer" | "int") => "INTEGER".to_string(),
             Some("float" | "double") => "DOUBLE PRECISION".to_string(),
             Some("decimal") => "DECIMAL(19,4)".to_string(),
             Some("boolean" | "bool") => match dialect {
-                "mysql" => "TINYINT(1)".to_string(),
-                // PostgreSQL and standard SQL both use BOOLEAN
-                "postgresql" | "sqlite" | "standard" => "BOOLEAN".to_string(),
-                _ => "BOOLEAN".to_string(), // Default to standard SQL BOOLEAN
+                SqlDialect::MySQL => "TINYINT(1)".to_string(),
+                // PostgreSQL, SQLite and standard SQL all accept BOOLEAN
+                SqlDialect::PostgreSQL | SqlDialect::SQLite | SqlDialect::Standard => {
+                    "BOOLEAN".to_string()
+                }
             },
             Some("date") => "DATE".to_string(),
             Some("datetime") => match dialect {
-                "postgresql" => "TIMESTAMP WITH TIME ZONE".to_string(),
+                SqlDialect::PostgreSQL => "TIMESTAMP WITH TIME ZONE".to_string(),
                 _ => "TIMESTAMP".to_string(),
             },
             // Text types (including URIs and unknown/missing types as fallback)
@@ -596,12 +789,14 @@ impl SQLGenerator {
                 // Check if it's an enum
                 if schema.enums.contains_key(other) {
                     match dialect {
-                        "postgresql" => self.convert_table_name(other),
+                        SqlDialect::PostgreSQL => {
+                            dialect.quote_identifier(&self.convert_table_name(other))
+                        }
                         _ => "VARCHAR(255)".to_string(),
                     }
                 } else if schema.classes.contains_key(other) {
                     // Foreign key reference
-                    self.get_id_type(options)
+                    Self::get_id_reference_type(options, dialect)
                 } else {
                     "TEXT".to_string()
                 }
@@ -611,37 +806,47 @@ impl SQLGenerator {
 
     /// Get the ID column type based on options
     fn get_id_type(&self, options: &GeneratorOptions) -> String {
+        let dialect = self.dialect(options);
         match options
             .get_custom("id_type")
             .map(std::string::String::as_str)
         {
-            Some("uuid") => match options
-                .get_custom("dialect")
-                .map(std::string::String::as_str)
-            {
-                Some("postgresql") => "UUID DEFAULT gen_random_uuid()".to_string(),
+            Some("uuid") => match dialect {
+                SqlDialect::PostgreSQL => "UUID DEFAULT gen_random_uuid()".to_string(),
                 _ => "CHAR(36)".to_string(),
             },
-            Some("serial") => match options
-                .get_custom("dialect")
-                .map(std::string::String::as_str)
-            {
-                Some("postgresql") => "SERIAL".to_string(),
-                Some("mysql") => "INTEGER AUTO_INCREMENT".to_string(),
+            Some("serial") => match dialect {
+                SqlDialect::PostgreSQL => "SERIAL".to_string(),
+                SqlDialect::MySQL => "INTEGER AUTO_INCREMENT".to_string(),
                 _ => "INTEGER".to_string(),
             },
-            Some("bigserial") => match options
-                .get_custom("dialect")
-                .map(std::string::String::as_str)
-            {
-                Some("postgresql") => "BIGSERIAL".to_string(),
-                Some("mysql") => "BIGINT AUTO_INCREMENT".to_string(),
+            Some("bigserial") => match dialect {
+                SqlDialect::PostgreSQL => "BIGSERIAL".to_string(),
+                SqlDialect::MySQL => "BIGINT AUTO_INCREMENT".to_string(),
                 _ => "BIGINT".to_string(),
             },
             _ => "INTEGER".to_string(),
         }
     }
 
+    /// Get the type of a column referencing a generated ID column
+    ///
+    /// Unlike [`Self::get_id_type`] this never carries a default or
+    /// auto-increment clause.
+    fn get_id_reference_type(options: &GeneratorOptions, dialect: SqlDialect) -> String {
+        match options
+            .get_custom("id_type")
+            .map(std::string::String::as_str)
+        {
+            Some("uuid") => match dialect {
+                SqlDialect::PostgreSQL => "UUID".to_string(),
+                _ => "CHAR(36)".to_string(),
+            },
+            Some("bigserial") => "BIGINT".to_string(),
+            _ => "INTEGER".to_string(),
+        }
+    }
+
     /// Convert to `SQL` table name
     fn convert_table_name(&self, name: &str) -> String {
         // Convert to snake_case and lowercase
@@ -772,10 +977,8 @@ impl AsyncGenerator for SQLGenerator {
                 .map_err(Self::fmt_error_to_generator_error)?;
         }
 
-        let dialect = options
-            .get_custom("dialect")
-            .map_or("standard", std::string::String::as_str);
-        writeln!(&mut output, "-- Dialect: {dialect}")
+        let dialect = self.dialect(options);
+        writeln!(&mut output, "-- Dialect: {}", dialect.name())
             .map_err(Self::fmt_error_to_generator_error)?;
         writeln!(&mut output).map_err(Self::fmt_error_to_generator_error)?;
 
@@ -785,21 +988,41 @@ impl AsyncGenerator for SQLGenerator {
             output.push_str(&enum_output);
         }
 
-        // Generate tables
+        // Generate tables, referenced tables first
         if !schema.classes.is_empty() {
             writeln!(&mut output, "-- Tables").map_err(Self::fmt_error_to_generator_error)?;
-            for (class_name, class) in &schema.classes {
-                let table_output =
-                    self.generate_table(class_name, class, schema, options, indent)?;
+            let mut created = HashSet::new();
+            let mut deferred = Vec::new();
+            for class_name in Self::table_order(schema)? {
+                let class = &schema.classes[class_name];
+                let (table_output, table_deferred) =
+                    self.generate_table(class_name, class, schema, options, indent, &created)?;
+                created.insert(class_name);
+                deferred.extend(table_deferred);
                 if !table_output.is_empty() {
                     output.push_str(&table_output);
                     writeln!(&mut output).map_err(Self::fmt_error_to_generator_error)?;
                 }
             }
+
+            // Foreign keys closing reference cycles
+            if !deferred.is_empty() {
+                writeln!(&mut output, "-- Deferred Foreign Keys")
+                    .map_err(Self::fmt_error_to_generator_error)?;
+                for statement in deferred {
+                    writeln!(&mut output, "{statement}")
+                        .map_err(Self::fmt_error_to_generator_error)?;
+                }
+                writeln!(&mut output).map_err(Self::fmt_error_to_generator_error)?;
+            }
         }
 
-        // Generate junction tables for many-to-many relationships
-        let junction_output = self.generate_junction_tables(schema, options, indent)?;
+        // Generate join tables for multivalued slots
+        let mut junction_output = String::new();
+        for (class_name, class) in &schema.classes {
+            junction_output
+                .push_str(&self.generate_join_tables(class_name, class, schema, options, indent)?);
+        }
         if !junction_output.is_empty() {
             writeln!(&mut output, "-- Junction Tables")
                 .map_err(Self::fmt_error_to_generator_error)?;
@@ -819,7 +1042,7 @@ impl AsyncGenerator for SQLGenerator {
         let mut metadata = HashMap::new();
         metadata.insert("generator".to_string(), self.name.clone());
         metadata.insert("schema_name".to_string(), schema.name.clone());
-        metadata.insert("dialect".to_string(), dialect.to_string());
+        metadata.insert("dialect".to_string(), dialect.name().to_string());
 
         Ok(vec![GeneratedOutput {
             content: output,
@@ -964,6 +1187,74 @@ impl CodeFormatter for SQLGenerator {
 mod tests {
     use super::*;
     use linkml_core::types::{ClassDefinition, SchemaDefinition, SlotDefinition};
+    use sqlparser::ast::Statement;
+    use sqlparser::dialect::{Dialect, GenericDialect, PostgreSqlDialect, SQLiteDialect};
+    use sqlparser::parser::Parser;
+
+    fn countries_schema() -> SchemaDefinition {
+        let mut schema = SchemaDefinition::new("countries");
+        for (name, range, required, identifier, multivalued) in [
+            ("code", "string", true, true, false),
+            ("name", "string", true, false, false),
+            ("population", "integer", false, false, false),
+            ("capital", "City", false, false, false),
+            ("country", "Country", true, false, false),
+            ("languages", "Language", false, false, true),
+            ("aliases", "string", false, false, true),
+        ] {
+            schema.slots.insert(
+                name.to_string(),
+                SlotDefinition {
+                    range: Some(range.to_string()),
+                    required: Some(required),
+                    identifier: Some(identifier),
+                    multivalued: Some(multivalued),
+                    ..SlotDefinition::new(name)
+                },
+            );
+        }
+        for (name, slots) in [
+            ("City", vec!["name", "country", "population"]),
+            (
+                "Country",
+                vec![
+                    "code",
+                    "name",
+                    "population",
+                    "capital",
+                    "languages",
+                    "aliases",
+                ],
+            ),
+            ("Language", vec!["code", "name"]),
+        ] {
+            schema.classes.insert(
+                name.to_string(),
+                ClassDefinition {
+                    slots: slots.into_iter().map(String::from).collect(),
+                    ..ClassDefinition::new(name)
+                },
+            );
+        }
+        schema
+    }
+
+    async fn generate_ddl(schema: &SchemaDefinition, dialect: SqlDialect) -> String {
+        let generator = SQLGenerator::new().with_dialect(dialect);
+        let outputs = AsyncGenerator::generate(&generator, schema, &GeneratorOptions::new())
+            .await
+            .expect("should generate SQL output");
+        outputs[0].content.clone()
+    }
+
+    fn parse_ddl(ddl: &str, dialect: &dyn Dialect) -> Vec<Statement> {
+        Parser::parse_sql(dialect, ddl).unwrap_or_else(|e| panic!("{e}\n{ddl}"))
+    }
+
+    fn table_position(ddl: &str, table: &str) -> usize {
+        ddl.find(&format!("CREATE TABLE {table} ("))
+            .unwrap_or_else(|| panic!("missing table {table}\n{ddl}"))
+    }
 
     #[tokio::test]
     async fn test_sql_generation() {
@@ -1012,4 +1303,107 @@ mod tests {
         assert_eq!(generator.convert_table_name("HTTPResponse"), "httpresponse");
         assert_eq!(generator.convert_table_name("person_name"), "person_name");
     }
+
+    #[tokio::test]
+    async fn test_relational_ddl_parses_for_each_dialect() {
+        let schema = countries_schema();
+        let dialects: [(SqlDialect, &dyn Dialect); 3] = [
+            (SqlDialect::PostgreSQL, &PostgreSqlDialect {}),
+            (SqlDialect::SQLite, &SQLiteDialect {}),
+            (SqlDialect::Standard, &GenericDialect {}),
+        ];
+
+        for (dialect, parser_dialect) in dialects {
+            let ddl = generate_ddl(&schema, dialect).await;
+            let statements = parse_ddl(&ddl, parser_dialect);
+            let tables = statements
+                .iter()
+                .filter(|statement| matches!(statement, Statement::CreateTable(_)))
+                .count();
+            assert_eq!(tables, 5, "{ddl}");
+
+            // Identifier slots are primary keys, class ranges are foreign keys
+            assert!(ddl.contains("code VARCHAR(255) PRIMARY KEY"), "{ddl}");
+            assert!(ddl.contains("country VARCHAR(255) NOT NULL"), "{ddl}");
+            assert!(
+                ddl.contains(
+                    "CONSTRAINT fk_city_country FOREIGN KEY (country) REFERENCES country(code)"
+                ),
+                "{ddl}"
+            );
+            assert!(ddl.contains("population INTEGER,"), "{ddl}");
+            assert!(
+                ddl.contains("CREATE TABLE country (\n    code VARCHAR(255) PRIMARY KEY,"),
+                "{ddl}"
+            );
+
+            // Multivalued slots get join tables instead of columns
+            assert!(ddl.contains("CREATE TABLE country_languages ("), "{ddl}");
+            assert!(
+                ddl.contains("PRIMARY KEY (country_code, languages)"),
+                "{ddl}"
+            );
+            assert!(
+                ddl.contains("FOREIGN KEY (languages) REFERENCES language(code)"),
+                "{ddl}"
+            );
+            assert!(ddl.contains("CREATE TABLE country_aliases ("), "{ddl}");
+            assert!(!ddl.contains("languages VARCHAR(255)[]"), "{ddl}");
+
+            // Referenced tables come first
+            assert!(table_position(&ddl, "country") < table_position(&ddl, "city"));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reference_cycle_tables_are_all_generated() {
+        // Country.capital -> City and City.country -> Country form a cycle
+        let schema = countries_schema();
+
+        let ddl = generate_ddl(&schema, SqlDialect::PostgreSQL).await;
+        let statements = parse_ddl(&ddl, &PostgreSqlDialect {});
+        assert!(
+            statements
+                .iter()
+                .any(|statement| matches!(statement, Statement::AlterTable { .. })),
+            "{ddl}"
+        );
+        assert!(ddl.contains("REFERENCES city(id)"), "{ddl}");
+        assert!(ddl.contains("REFERENCES country(code)"), "{ddl}");
+
+        // SQLite accepts forward references, so nothing is deferred
+        let ddl = generate_ddl(&schema, SqlDialect::SQLite).await;
+        parse_ddl(&ddl, &SQLiteDialect {});
+        assert!(!ddl.contains("ALTER TABLE"), "{ddl}");
+    }
+
+    #[tokio::test]
+    async fn test_identifier_quoting_per_dialect() {
+        let mut schema = SchemaDefinition::new("shop");
+        schema.slots.insert(
+            "order".to_string(),
+            SlotDefinition {
+                range: Some("integer".to_string()),
+                ..SlotDefinition::new("order")
+            },
+        );
+        schema.classes.insert(
+            "Item".to_string(),
+            ClassDefinition {
+                slots: vec!["order".to_string()],
+                ..ClassDefinition::new("Item")
+            },
+        );
+
+        let ddl = generate_ddl(&schema, SqlDialect::PostgreSQL).await;
+        assert!(ddl.contains("\"order\" INTEGER"), "{ddl}");
+        parse_ddl(&ddl, &PostgreSqlDialect {});
+
+        let ddl = generate_ddl(&schema, SqlDialect::MySQL).await;
+        assert!(ddl.contains("`order` INTEGER"), "{ddl}");
+
+        assert_eq!(SqlDialect::SQLite.quote_identifier("item"), "item");
+        assert_eq!(SqlDialect::SQLite.quote_identifier("Item"), "\"Item\"");
+        assert_eq!(SqlDialect::parse("postgres"), Some(SqlDialect::PostgreSQL));
+    }
 }
@@ -5,6 +5,7 @@
 use async_trait::async_trait;
 use linkml_core::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 use std::collections::HashMap;
 use thiserror::Error;
 
@@ -78,6 +79,12 @@ pub struct GeneratorOptions {
     /// Output format
     pub output_format: OutputFormat,
 
+    /// Emit schema elements sorted by name rather than in definition order
+    ///
+    /// Definition order depends on the order in which imports were merged,
+    /// so sorting keeps committed artifacts free of spurious diffs.
+    pub sort_elements: bool,
+
     /// Custom options for specific generators
     pub custom: HashMap<String, String>,
 }
@@ -127,6 +134,32 @@ impl GeneratorOptions {
         self
     }
 
+    /// Set whether to sort schema elements by name
+    #[must_use]
+    pub fn with_sorted_elements(mut self, sort_elements: bool) -> Self {
+        self.sort_elements = sort_elements;
+        self
+    }
+
+    /// Schema to generate from: `schema` itself, or a copy with its classes,
+    /// slots, enums, types, subsets and prefixes sorted by name when
+    /// [`sort_elements`](Self::sort_elements) is set
+    #[must_use]
+    pub fn ordered_schema<'a>(&self, schema: &'a SchemaDefinition) -> Cow<'a, SchemaDefinition> {
+        if !self.sort_elements {
+            return Cow::Borrowed(schema);
+        }
+
+        let mut sorted = schema.clone();
+        sorted.classes.sort_keys();
+        sorted.slots.sort_keys();
+        sorted.enums.sort_keys();
+        sorted.types.sort_keys();
+        sorted.subsets.sort_keys();
+        sorted.prefixes.sort_keys();
+        Cow::Owned(sorted)
+    }
+
     /// Set a custom option
     #[must_use]
     pub fn set_custom(mut self, key: &str, value: &str) -> Self {
@@ -329,3 +362,66 @@ pub trait CodeFormatter {
         id.to_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::{OwlRdfGenerator, RustGenerator, TypeQLGenerator};
+
+    /// Class, slot and enum contributed by an imported schema
+    type Import = (&'static str, &'static str, &'static str);
+
+    const COUNTRIES: Import = ("Country", "country_code", "Continent");
+    const CITIES: Import = ("City", "city_name", "CitySize");
+
+    /// Schema whose elements were merged from `imports` in the given order
+    fn merged_schema(imports: [Import; 2]) -> SchemaDefinition {
+        let mut schema = SchemaDefinition::new("places");
+        for (class, slot, enum_name) in imports {
+            schema.classes.insert(
+                class.to_string(),
+                ClassDefinition {
+                    slots: vec![slot.to_string()],
+                    ..ClassDefinition::new(class)
+                },
+            );
+            schema
+                .slots
+                .insert(slot.to_string(), SlotDefinition::new(slot));
+            schema.enums.insert(
+                enum_name.to_string(),
+                EnumDefinition {
+                    name: enum_name.to_string(),
+                    ..EnumDefinition::default()
+                },
+            );
+        }
+        schema
+    }
+
+    #[test]
+    fn test_sorted_output_ignores_merge_order() -> anyhow::Result<()> {
+        let forward = merged_schema([COUNTRIES, CITIES]);
+        let backward = merged_schema([CITIES, COUNTRIES]);
+
+        let sorted = GeneratorOptions::new().with_sorted_elements(true);
+        let generators: Vec<Box<dyn Generator>> = vec![
+            Box::new(RustGenerator::with_options(sorted.clone())),
+            Box::new(TypeQLGenerator::with_options(sorted.clone())),
+            Box::new(OwlRdfGenerator::with_options(sorted)),
+        ];
+        for generator in generators {
+            assert_eq!(
+                generator.generate(&forward)?,
+                generator.generate(&backward)?,
+                "{} output depends on merge order",
+                generator.name()
+            );
+        }
+
+        // Definition order is kept unless sorting is requested
+        let unsorted = RustGenerator::new();
+        assert_ne!(unsorted.generate(&forward)?, unsorted.generate(&backward)?);
+        Ok(())
+    }
+}
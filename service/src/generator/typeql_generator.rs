@@ -516,6 +516,8 @@ impl TypeQLGenerator {
         // Validate schema
         self.validate_schema_sync(schema)?;
 
+        let ordered = options.ordered_schema(schema);
+        let schema = ordered.as_ref();
         let mut output = String::new();
         let indent = &options.indent;
 
@@ -628,8 +630,7 @@ impl Generator for TypeQLGenerator {
 
     fn generate(&self, schema: &SchemaDefinition) -> Result<String> {
         // Call the synchronous generation method directly
-        let options = GeneratorOptions::new();
-        let outputs = self.generate_sync(schema, &options)
+        let outputs = self.generate_sync(schema, &self.options)
             .map_err(|e| LinkMLError::service(e.to_string()))?;
 
         // Concatenate all outputs into a single string
@@ -299,4 +299,439 @@ impl RustGenerator {
 
         Ok(())
     }
+
+    /// Generate an `impl` block with a `validate()` method for a struct
+    /// produced by [`Generator::generate`](super::traits::Generator::generate)
+    ///
+    /// The method collects every violation as a message instead of stopping
+    /// at the first one. Checks follow the field types emitted by
+    /// `generate_field`, and class-valued fields are validated recursively.
+    /// Classes with `recursion_options.max_depth` also get a
+    /// `validate_depth()` method rejecting instances nested deeper than that.
+    pub(super) fn generate_validation_impl(
+        &self,
+        class_name: &str,
+        class_def: &ClassDefinition,
+        schema: &SchemaDefinition,
+    ) -> GeneratorResult<String> {
+        let max_depth = Self::max_depth(class_def);
+        let mut checks = String::new();
+        for (slot_name, slot_def) in &self.collect_class_slots(class_def, schema) {
+            Self::generate_slot_checks(
+                &mut checks,
+                slot_name,
+                slot_def,
+                schema,
+                max_depth.is_some(),
+            )?;
+        }
+
+        let mut output = String::new();
+        writeln!(output, "impl {class_name} {{").map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(
+            output,
+            "    /// Check the constraints declared in the schema, returning every violation"
+        )
+        .map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(
+            output,
+            "    pub fn validate(&self) -> Result<(), Vec<String>> {{"
+        )
+        .map_err(Self::fmt_error_to_generator_error)?;
+        if let Some(max_depth) = max_depth {
+            writeln!(output, "        self.validate_depth(1)")
+                .map_err(Self::fmt_error_to_generator_error)?;
+            writeln!(output, "    }}").map_err(Self::fmt_error_to_generator_error)?;
+            writeln!(output).map_err(Self::fmt_error_to_generator_error)?;
+            writeln!(
+                output,
+                "    /// Validate an instance nested `depth` levels deep, allowing at most {max_depth}"
+            )
+            .map_err(Self::fmt_error_to_generator_error)?;
+            writeln!(
+                output,
+                "    pub fn validate_depth(&self, depth: usize) -> Result<(), Vec<String>> {{"
+            )
+            .map_err(Self::fmt_error_to_generator_error)?;
+            writeln!(output, "        if depth > {max_depth} {{")
+                .map_err(Self::fmt_error_to_generator_error)?;
+            writeln!(
+                output,
+                "            return Err(vec![format!(\"{{}}: nesting exceeds the maximum depth of {max_depth}\", {class_name:?})]);"
+            )
+            .map_err(Self::fmt_error_to_generator_error)?;
+            writeln!(output, "        }}").map_err(Self::fmt_error_to_generator_error)?;
+        }
+        if checks.is_empty() {
+            writeln!(output, "        Ok(())").map_err(Self::fmt_error_to_generator_error)?;
+        } else {
+            writeln!(output, "        let mut errors = Vec::new();")
+                .map_err(Self::fmt_error_to_generator_error)?;
+            output.push_str(&checks);
+            writeln!(
+                output,
+                "        if errors.is_empty() {{ Ok(()) }} else {{ Err(errors) }}"
+            )
+            .map_err(Self::fmt_error_to_generator_error)?;
+        }
+        writeln!(output, "    }}").map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(output, "}}").map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(output).map_err(Self::fmt_error_to_generator_error)?;
+        Ok(output)
+    }
+
+    /// Maximum nesting depth validated for instances of a class
+    fn max_depth(class_def: &ClassDefinition) -> Option<usize> {
+        class_def
+            .recursion_options
+            .as_ref()
+            .and_then(|options| options.max_depth)
+    }
+
+    /// Generate the checks of a single slot inside `validate()`, passing the
+    /// nesting depth on to depth-limited classes when `depth_limited` is set
+    fn generate_slot_checks(
+        output: &mut String,
+        slot_name: &str,
+        slot: &SlotDefinition,
+        schema: &SchemaDefinition,
+        depth_limited: bool,
+    ) -> GeneratorResult<()> {
+        let field_name = Self::convert_field_name(slot_name);
+        let required = slot.required.unwrap_or(false);
+        let multivalued = slot.multivalued.unwrap_or(false);
+        let class_range = slot
+            .range
+            .as_deref()
+            .is_some_and(|range| schema.classes.contains_key(range));
+        let rust_type = match slot.range.as_deref() {
+            Some(range) if class_range || schema.enums.contains_key(range) => None,
+            Some(range) => Some(Self::linkml_type_to_rust(range)),
+            None => Some("String"),
+        };
+
+        // Required values must be present: non-empty lists and strings
+        if required && (multivalued || rust_type == Some("String")) {
+            writeln!(output, "        if self.{field_name}.is_empty() {{")
+                .map_err(Self::fmt_error_to_generator_error)?;
+            writeln!(
+                output,
+                "            errors.push(format!(\"{{}}: required value is missing\", {slot_name:?}));"
+            )
+            .map_err(Self::fmt_error_to_generator_error)?;
+            writeln!(output, "        }}").map_err(Self::fmt_error_to_generator_error)?;
+        }
+
+        // Lists must hold between minimum_cardinality and maximum_cardinality
+        // values
+        if multivalued {
+            let bounds = [
+                (slot.minimum_cardinality, "<", "fewer than the minimum"),
+                (slot.maximum_cardinality, ">", "more than the maximum"),
+            ];
+            for (bound, operator, description) in bounds {
+                let Some(bound) = bound.and_then(|n| usize::try_from(n).ok()) else {
+                    continue;
+                };
+                writeln!(
+                    output,
+                    "        if self.{field_name}.len() {operator} {bound} {{"
+                )
+                .map_err(Self::fmt_error_to_generator_error)?;
+                writeln!(
+                    output,
+                    "            errors.push(format!(\"{{}}: {{}} values are {description} cardinality {bound}\", {slot_name:?}, self.{field_name}.len()));"
+                )
+                .map_err(Self::fmt_error_to_generator_error)?;
+                writeln!(output, "        }}").map_err(Self::fmt_error_to_generator_error)?;
+            }
+        }
+
+        // Checks applied to each present value
+        let mut value_checks = String::new();
+        if let Some(pattern) = &slot.pattern
+            && rust_type == Some("String")
+        {
+            Self::generate_value_pattern_check(&mut value_checks, slot_name, pattern)?;
+        }
+
+        let bounds = [
+            (&slot.minimum_value, "<", "below the minimum"),
+            (&slot.maximum_value, ">", "above the maximum"),
+        ];
+        for (bound, operator, description) in bounds {
+            let literal = match (rust_type, bound) {
+                (Some("i64"), Some(value)) => value.as_i64().map(|n| n.to_string()),
+                (Some("f64"), Some(value)) => value.as_f64().map(|n| format!("{n:?}")),
+                _ => None,
+            };
+            if let Some(literal) = literal {
+                writeln!(
+                    value_checks,
+                    "            if *value {operator} {literal} {{"
+                )
+                .map_err(Self::fmt_error_to_generator_error)?;
+                writeln!(
+                    value_checks,
+                    "                errors.push(format!(\"{{}}: {{value}} is {description} {literal}\", {slot_name:?}));"
+                )
+                .map_err(Self::fmt_error_to_generator_error)?;
+                writeln!(value_checks, "            }}")
+                    .map_err(Self::fmt_error_to_generator_error)?;
+            }
+        }
+
+        if class_range {
+            let nested_depth_limited = slot
+                .range
+                .as_deref()
+                .and_then(|range| schema.classes.get(range))
+                .and_then(Self::max_depth)
+                .is_some();
+            let call = if depth_limited && nested_depth_limited {
+                "validate_depth(depth + 1)"
+            } else {
+                "validate()"
+            };
+            writeln!(
+                value_checks,
+                "            if let Err(nested) = value.{call} {{"
+            )
+            .map_err(Self::fmt_error_to_generator_error)?;
+            writeln!(
+                value_checks,
+                "                errors.extend(nested.into_iter().map(|err| format!(\"{{}}.{{err}}\", {slot_name:?})));"
+            )
+            .map_err(Self::fmt_error_to_generator_error)?;
+            writeln!(value_checks, "            }}").map_err(Self::fmt_error_to_generator_error)?;
+        }
+
+        if !value_checks.is_empty() {
+            // Lists are checked item by item and optional values when present
+            if multivalued {
+                writeln!(output, "        for value in &self.{field_name} {{")
+            } else if required {
+                writeln!(
+                    output,
+                    "        {{\n            let value = &self.{field_name};"
+                )
+            } else {
+                writeln!(output, "        if let Some(value) = &self.{field_name} {{")
+            }
+            .map_err(Self::fmt_error_to_generator_error)?;
+            output.push_str(&value_checks);
+            writeln!(output, "        }}").map_err(Self::fmt_error_to_generator_error)?;
+        }
+
+        Ok(())
+    }
+
+    /// Generate a pattern check on `value` using a lazily compiled regex
+    fn generate_value_pattern_check(
+        output: &mut String,
+        slot_name: &str,
+        pattern: &str,
+    ) -> GeneratorResult<()> {
+        writeln!(
+            output,
+            "            static PATTERN: std::sync::LazyLock<Result<regex::Regex, regex::Error>> ="
+        )
+        .map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(
+            output,
+            "                std::sync::LazyLock::new(|| regex::Regex::new({pattern:?}));"
+        )
+        .map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(output, "            match &*PATTERN {{")
+            .map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(
+            output,
+            "                Ok(regex) if !regex.is_match(value) => errors.push(format!(\"{{}}: {{value:?}} does not match pattern {{}}\", {slot_name:?}, {pattern:?})),"
+        )
+        .map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(output, "                Ok(_) => {{}}")
+            .map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(
+            output,
+            "                Err(err) => errors.push(format!(\"{{}}: invalid pattern: {{err}}\", {slot_name:?})),"
+        )
+        .map_err(Self::fmt_error_to_generator_error)?;
+        writeln!(output, "            }}").map_err(Self::fmt_error_to_generator_error)?;
+
+        Ok(())
+    }
+}
+
+/// Generator output for the country test schema with validation, compiled
+/// into the tests so the generated `validate()` methods can be run
+#[cfg(test)]
+#[rustfmt::skip]
+#[allow(
+    dead_code,
+    unused_imports,
+    clippy::collapsible_if,
+    clippy::doc_markdown,
+    clippy::items_after_statements
+)]
+#[path = "../../tests/data/generated/countries_validation.rs"]
+mod countries_validation;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::traits::Generator;
+
+    const COUNTRY_SCHEMA: &str = r"
+id: https://example.org/countries
+name: countries
+slots:
+  code:
+    range: string
+    required: true
+    pattern: '^[A-Z]{2}$'
+  name:
+    range: string
+    required: true
+  population:
+    range: integer
+    minimum_value: 0
+  languages:
+    range: string
+    multivalued: true
+    required: true
+  country:
+    range: Country
+    required: true
+classes:
+  Country:
+    slots: [code, name, population, languages]
+  City:
+    slots: [name, country]
+";
+
+    #[test]
+    fn test_generated_country_validation_is_current() -> anyhow::Result<()> {
+        let schema: SchemaDefinition = serde_yaml::from_str(COUNTRY_SCHEMA)?;
+        let code = RustGenerator::new()
+            .with_validation(true)
+            .generate(&schema)?;
+        assert_eq!(
+            code,
+            include_str!("../../tests/data/generated/countries_validation.rs"),
+            "regenerate tests/data/generated/countries_validation.rs"
+        );
+
+        let plain = RustGenerator::new().generate(&schema)?;
+        assert!(!plain.contains("pub fn validate"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_generated_validate_rejects_three_letter_code() {
+        use super::countries_validation::{City, Country};
+
+        let netherlands = Country {
+            code: "NL".to_string(),
+            name: "Netherlands".to_string(),
+            population: Some(17_900_000),
+            languages: vec!["nld".to_string()],
+        };
+        assert!(netherlands.validate().is_ok());
+
+        let three_letter = Country {
+            code: "NLD".to_string(),
+            ..netherlands
+        };
+        let pattern_error = r#"code: "NLD" does not match pattern ^[A-Z]{2}$"#;
+        assert_eq!(
+            three_letter.validate(),
+            Err(vec![pattern_error.to_string()])
+        );
+
+        // Nested instances are validated too, with the slot as prefix
+        let amsterdam = City {
+            name: "Amsterdam".to_string(),
+            country: three_letter,
+        };
+        assert_eq!(
+            amsterdam.validate(),
+            Err(vec![format!("country.{pattern_error}")])
+        );
+    }
+
+    #[test]
+    fn test_validate_checks_attributes_and_cardinality() -> anyhow::Result<()> {
+        let schema: SchemaDefinition = serde_yaml::from_str(
+            r"
+id: https://example.org/countries
+name: countries
+classes:
+  Country:
+    attributes:
+      code:
+        range: string
+        required: true
+        pattern: '^[A-Z]{2}$'
+      languages:
+        range: string
+        multivalued: true
+        minimum_cardinality: 1
+        maximum_cardinality: 3
+  Capital:
+    is_a: Country
+    attributes:
+      code:
+        range: string
+        pattern: '^[A-Z]{3}$'
+",
+        )?;
+        let code = RustGenerator::new()
+            .with_validation(true)
+            .generate(&schema)?;
+
+        // Attributes become fields and are validated like slots
+        assert!(code.contains("pub code: String,"), "{code}");
+        assert!(code.contains("pub languages: Vec<String>,"), "{code}");
+        assert!(
+            code.contains(r#"errors.push(format!("{}: required value is missing", "code"));"#),
+            "{code}"
+        );
+        assert!(
+            code.contains(r#"regex::Regex::new("^[A-Z]{2}$")"#),
+            "{code}"
+        );
+
+        assert!(
+            code.contains(
+                r#"        if self.languages.len() < 1 {
+            errors.push(format!("{}: {} values are fewer than the minimum cardinality 1", "languages", self.languages.len()));
+        }"#
+            ),
+            "{code}"
+        );
+        assert!(
+            code.contains(
+                r#"        if self.languages.len() > 3 {
+            errors.push(format!("{}: {} values are more than the maximum cardinality 3", "languages", self.languages.len()));
+        }"#
+            ),
+            "{code}"
+        );
+
+        // An attribute redefined by a subclass replaces the inherited one
+        let capital = code
+            .split("impl Capital {")
+            .nth(1)
+            .ok_or_else(|| anyhow::anyhow!("Capital should get a validate method"))?;
+        assert!(
+            capital.contains(r#"regex::Regex::new("^[A-Z]{3}$")"#),
+            "{capital}"
+        );
+        assert!(
+            !capital.contains(r#"regex::Regex::new("^[A-Z]{2}$")"#),
+            "{capital}"
+        );
+        assert!(capital.contains("self.languages.len() < 1"), "{capital}");
+        Ok(())
+    }
 }
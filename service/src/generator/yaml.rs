@@ -10,6 +10,137 @@ use linkml_core::prelude::*;
 use linkml_core::types::PrefixDefinition;
 use serde_yaml;
 
+/// Canonical order of top-level keys in a `LinkML` schema document
+const CANONICAL_SCHEMA_KEYS: &[&str] = &[
+    "id",
+    "name",
+    "title",
+    "description",
+    "version",
+    "license",
+    "status",
+    "metamodel_version",
+    "generation_date",
+    "source_file",
+    "contributors",
+    "categories",
+    "keywords",
+    "see_also",
+    "annotations",
+    "settings",
+    "prefixes",
+    "default_prefix",
+    "default_range",
+    "imports",
+    "subsets",
+    "types",
+    "enums",
+    "slots",
+    "classes",
+];
+
+/// Element collections whose entries are emitted sorted by name
+const SORTED_ELEMENT_KEYS: &[&str] = &["prefixes", "subsets", "types", "enums", "slots", "classes"];
+
+/// Serialize a schema back to canonical `LinkML` `YAML`
+///
+/// Unlike [`YamlGenerator`], which produces a hand-picked subset of each
+/// element, this is lossless: parsing the output yields a `SchemaDefinition`
+/// equal to the input. Top-level keys follow [`CANONICAL_SCHEMA_KEYS`] and
+/// prefixes, subsets, types, enums, slots and classes are sorted by name, so
+/// two equivalent schemas always serialize identically. This makes it
+/// suitable for "normalize then diff" workflows on import-resolved schemas.
+///
+/// # Errors
+///
+/// Returns `LinkMLError::SerializationError` if the schema cannot be
+/// represented as `YAML`.
+pub fn to_linkml_yaml(schema: &SchemaDefinition) -> Result<String> {
+    let value = serde_yaml::to_value(schema)
+        .map_err(|e| LinkMLError::serialization(format!("YAML generation failed: {e}")))?;
+    let serde_yaml::Value::Mapping(mut fields) = value else {
+        return Err(LinkMLError::serialization(
+            "Schema did not serialize to a YAML mapping",
+        ));
+    };
+
+    let mut canonical = serde_yaml::Mapping::new();
+    for key in CANONICAL_SCHEMA_KEYS {
+        if let Some(mut field) = fields.shift_remove(*key) {
+            if SORTED_ELEMENT_KEYS.contains(key)
+                && let serde_yaml::Value::Mapping(elements) = &mut field
+            {
+                let mut entries: Vec<_> = std::mem::take(elements).into_iter().collect();
+                entries.sort_by(|(a, _), (b, _)| a.as_str().cmp(&b.as_str()));
+                elements.extend(entries);
+
+                for (_, element) in elements.iter_mut() {
+                    canonicalize_element(key, element);
+                }
+            }
+            canonical.insert(serde_yaml::Value::String((*key).to_string()), field);
+        }
+    }
+    // Keep any fields not covered by the canonical order, in serde order
+    canonical.extend(fields);
+
+    serde_yaml::to_string(&serde_yaml::Value::Mapping(canonical))
+        .map_err(|e| LinkMLError::serialization(format!("YAML generation failed: {e}")))
+}
+
+/// Normalize a single element of the given top-level collection
+fn canonicalize_element(collection: &str, element: &mut serde_yaml::Value) {
+    strip_empty_name(element);
+    match collection {
+        "enums" => permissible_values_to_map(element),
+        "classes" => {
+            for nested in ["attributes", "slot_usage"] {
+                if let Some(serde_yaml::Value::Mapping(slots)) = element.get_mut(nested) {
+                    slots.values_mut().for_each(strip_empty_name);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Drop an element's `name` when it is empty
+///
+/// Elements are keyed by name in `LinkML` `YAML`, and an empty `name` is what
+/// deserialization defaults to, so omitting it keeps the output lossless.
+fn strip_empty_name(element: &mut serde_yaml::Value) {
+    if let serde_yaml::Value::Mapping(fields) = element
+        && fields.get("name").and_then(serde_yaml::Value::as_str) == Some("")
+    {
+        fields.shift_remove("name");
+    }
+}
+
+/// Rewrite an enum's permissible values from the serde sequence form into the
+/// `LinkML` map form keyed by value text
+fn permissible_values_to_map(enum_def: &mut serde_yaml::Value) {
+    let Some(serde_yaml::Value::Sequence(values)) = enum_def.get_mut("permissible_values") else {
+        return;
+    };
+
+    let mut map = serde_yaml::Mapping::new();
+    for value in std::mem::take(values) {
+        match value {
+            // Simple values carry no metadata
+            serde_yaml::Value::String(text) => {
+                map.insert(serde_yaml::Value::String(text), serde_yaml::Value::Null);
+            }
+            serde_yaml::Value::Mapping(mut metadata) => {
+                if let Some(text) = metadata.shift_remove("text") {
+                    map.insert(text, serde_yaml::Value::Mapping(metadata));
+                }
+            }
+            _ => {}
+        }
+    }
+    enum_def["permissible_values"] = serde_yaml::Value::Mapping(map);
+}
+
 /// `YAML` schema generator
 pub struct YamlGenerator {
     /// Whether to include generated metadata
@@ -769,3 +900,106 @@ impl Generator for YamlGenerator {
         "schema.yaml"
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COUNTRY_SCHEMA: &str = r#"
+id: https://textpast.org/schema/place/polity/country
+name: country
+title: Countries
+prefixes:
+  txp: https://textpast.org/
+  linkml: https://w3id.org/linkml/
+default_prefix: txp
+imports:
+  - linkml:types
+subsets:
+  core:
+    description: Core elements
+types:
+  Iso3166Alpha2:
+    typeof: string
+    pattern: "^[A-Z]{2}$"
+enums:
+  CountryStatus:
+    permissible_values:
+      ACTIVE:
+        description: Currently recognized
+      DISSOLVED:
+slots:
+  alpha2:
+    range: Iso3166Alpha2
+    identifier: true
+  population:
+    range: integer
+    minimum_value: 0
+classes:
+  Country:
+    description: A sovereign state
+    slots:
+      - alpha2
+      - population
+    attributes:
+      status:
+        range: CountryStatus
+"#;
+
+    const HYPERENTITY_SCHEMA: &str = r"
+id: https://textpast.org/schema/meta/entity/hyperentity
+name: hyperentity
+classes:
+  HyperEntity:
+    abstract: true
+    attributes:
+      id:
+        range: string
+        required: true
+  Entity:
+    is_a: HyperEntity
+    mixins:
+      - Named
+    slot_usage:
+      id:
+        pattern: '^txp:'
+  Named:
+    mixin: true
+    attributes:
+      label:
+        range: string
+";
+
+    #[test]
+    fn test_to_linkml_yaml_round_trip() -> anyhow::Result<()> {
+        for source in [COUNTRY_SCHEMA, HYPERENTITY_SCHEMA] {
+            let schema: SchemaDefinition = serde_yaml::from_str(source)?;
+            let yaml = to_linkml_yaml(&schema)?;
+            let reparsed: SchemaDefinition = serde_yaml::from_str(&yaml)?;
+
+            assert_eq!(schema, reparsed);
+            assert_eq!(yaml, to_linkml_yaml(&reparsed)?);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_linkml_yaml_canonical_order() -> anyhow::Result<()> {
+        let schema: SchemaDefinition = serde_yaml::from_str(COUNTRY_SCHEMA)?;
+        let yaml = to_linkml_yaml(&schema)?;
+
+        let position = |needle: &str| {
+            yaml.find(needle)
+                .ok_or_else(|| anyhow::anyhow!("missing {needle}"))
+        };
+        assert!(position("\nprefixes:")? < position("\nsubsets:")?);
+        assert!(position("\ntypes:")? < position("\nenums:")?);
+        assert!(position("\nslots:")? < position("\nclasses:")?);
+        // Elements are sorted by name
+        assert!(position("  linkml:")? < position("  txp:")?);
+        assert!(position("  alpha2:")? < position("  population:")?);
+        // Permissible values use the LinkML map form
+        assert!(yaml.contains("DISSOLVED: null"));
+        Ok(())
+    }
+}
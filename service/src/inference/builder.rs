@@ -2,11 +2,15 @@
 //!
 //! Provides a fluent API for programmatically constructing LinkML schemas.
 //! This builder enables incremental schema construction with type-safe operations
-//! and validates the resulting schema structure.
+//! and validates the resulting schema structure: a class, slot, enum or prefix
+//! defined twice is reported when the schema is built.
 
 use indexmap::IndexMap;
 use linkml_core::LinkMLError;
-use linkml_core::types::{ClassDefinition, PrefixDefinition, SchemaDefinition, SlotDefinition};
+use linkml_core::types::{
+    ClassDefinition, EnumDefinition, PermissibleValue, PrefixDefinition, SchemaDefinition,
+    SlotDefinition,
+};
 use std::sync::Arc;
 use timestamp_core::{TimestampError, TimestampService};
 
@@ -24,13 +28,17 @@ pub type BuilderResult<T> = Result<T, LinkMLError>;
 ///     .with_description("Schema for person data")
 ///     .with_version("1.0.0")
 ///     .add_prefix("schema", "http://schema.org/")
+///     .add_import("linkml:types")
+///     .add_enum("PersonStatus", ["ALIVE", "DEAD"])
 ///     .add_class("Person")
 ///         .with_description("A person entity")
 ///         .add_attribute("name", "string", true, false)
 ///         .add_attribute("age", "integer", false, false)
 ///         .add_attribute("emails", "string", false, true)
+///         .add_attribute("status", "PersonStatus", false, false)
 ///         .finish()
-///     .build();
+///     .build()?;
+/// # Ok::<(), linkml_core::LinkMLError>(())
 /// ```
 pub struct SchemaBuilder {
     schema_id: String,
@@ -42,9 +50,13 @@ pub struct SchemaBuilder {
     default_prefix: Option<String>,
     default_range: Option<String>,
     prefixes: IndexMap<String, PrefixDefinition>,
+    imports: Vec<String>,
     classes: IndexMap<String, ClassDefinition>,
     slots: IndexMap<String, SlotDefinition>,
+    enums: IndexMap<String, EnumDefinition>,
     timestamp_service: Option<Arc<dyn TimestampService<Error = TimestampError>>>,
+    /// First definition that clashed with an earlier one, reported by `build`
+    duplicate: Option<LinkMLError>,
 }
 
 impl SchemaBuilder {
@@ -72,9 +84,12 @@ impl SchemaBuilder {
             default_prefix: None,
             default_range: None,
             prefixes,
+            imports: Vec::new(),
             classes: IndexMap::new(),
             slots: IndexMap::new(),
+            enums: IndexMap::new(),
             timestamp_service: None,
+            duplicate: None,
         }
     }
 
@@ -151,14 +166,15 @@ impl SchemaBuilder {
 
     /// Add a namespace prefix
     ///
+    /// Declaring a prefix again with a different expansion is reported by
+    /// [`build`](Self::build).
+    ///
     /// # Arguments
     ///
     /// * `prefix` - Short prefix name
     /// * `uri` - Full URI expansion
-    pub fn add_prefix(mut self, prefix: impl Into<String>, uri: impl Into<String>) -> Self {
-        self.prefixes
-            .insert(prefix.into(), PrefixDefinition::Simple(uri.into()));
-        self
+    pub fn add_prefix(self, prefix: impl Into<String>, uri: impl Into<String>) -> Self {
+        self.add_prefix_internal(prefix.into(), PrefixDefinition::Simple(uri.into()))
     }
 
     /// Add a complex prefix with reference
@@ -169,18 +185,55 @@ impl SchemaBuilder {
     /// * `prefix_prefix` - Prefix expansion
     /// * `prefix_reference` - Optional reference URL
     pub fn add_complex_prefix(
-        mut self,
+        self,
         prefix: impl Into<String>,
         prefix_prefix: impl Into<String>,
         prefix_reference: Option<String>,
     ) -> Self {
-        self.prefixes.insert(
+        self.add_prefix_internal(
             prefix.into(),
             PrefixDefinition::Complex {
                 prefix_prefix: prefix_prefix.into(),
                 prefix_reference,
             },
-        );
+        )
+    }
+
+    /// Import another schema, such as `linkml:types`
+    pub fn add_import(mut self, import: impl Into<String>) -> Self {
+        let import = import.into();
+        if !self.imports.contains(&import) {
+            self.imports.push(import);
+        }
+        self
+    }
+
+    /// Add an enum with the given permissible values
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the enum
+    /// * `values` - Texts of its permissible values
+    pub fn add_enum<I, V>(mut self, name: impl Into<String>, values: I) -> Self
+    where
+        I: IntoIterator<Item = V>,
+        V: Into<String>,
+    {
+        let name = name.into();
+        // Classes and enums are both ranges, so they share one namespace
+        if self.enums.contains_key(&name) || self.classes.contains_key(&name) {
+            self.record_duplicate("Enum", &name);
+            return self;
+        }
+        let enum_def = EnumDefinition {
+            name: name.clone(),
+            permissible_values: values
+                .into_iter()
+                .map(|value| PermissibleValue::Simple(value.into()))
+                .collect(),
+            ..Default::default()
+        };
+        self.enums.insert(name, enum_def);
         self
     }
 
@@ -215,12 +268,21 @@ impl SchemaBuilder {
     /// # Returns
     ///
     /// A complete `SchemaDefinition` ready for serialization
-    pub fn build(self) -> SchemaDefinition {
+    ///
+    /// # Errors
+    ///
+    /// Returns `LinkMLError::DuplicateDefinition` for the first class, slot,
+    /// enum or prefix that was defined more than once.
+    pub fn build(self) -> BuilderResult<SchemaDefinition> {
+        if let Some(duplicate) = self.duplicate {
+            return Err(duplicate);
+        }
+
         // Note: generation_date is set to None here as timestamp_service.now_utc() is async
         // Callers should set generation_date manually if needed
         let generation_date = None;
 
-        SchemaDefinition {
+        Ok(SchemaDefinition {
             id: self.schema_id,
             name: self.schema_name,
             title: self.title,
@@ -229,11 +291,11 @@ impl SchemaBuilder {
             license: self.license,
             default_prefix: self.default_prefix,
             prefixes: self.prefixes,
-            imports: Vec::new(),
+            imports: self.imports,
             classes: self.classes,
             slots: self.slots,
             types: IndexMap::new(),
-            enums: IndexMap::new(),
+            enums: self.enums,
             subsets: IndexMap::new(),
             default_range: self.default_range,
             generation_date,
@@ -246,20 +308,48 @@ impl SchemaBuilder {
             categories: Vec::new(),
             keywords: Vec::new(),
             see_also: Vec::new(),
+        })
+    }
+
+    /// Internal method to add a prefix, rejecting a conflicting redefinition
+    fn add_prefix_internal(mut self, prefix: String, definition: PrefixDefinition) -> Self {
+        match self.prefixes.get(&prefix) {
+            Some(existing) if *existing != definition => {
+                self.record_duplicate("Prefix", &prefix);
+            }
+            _ => {
+                self.prefixes.insert(prefix, definition);
+            }
         }
+        self
     }
 
     /// Internal method to add a class after building
     fn add_class_internal(mut self, name: String, class_def: ClassDefinition) -> Self {
-        self.classes.insert(name, class_def);
+        if self.classes.contains_key(&name) || self.enums.contains_key(&name) {
+            self.record_duplicate("Class", &name);
+        } else {
+            self.classes.insert(name, class_def);
+        }
         self
     }
 
     /// Internal method to add a slot after building
     fn add_slot_internal(mut self, name: String, slot_def: SlotDefinition) -> Self {
-        self.slots.insert(name, slot_def);
+        if self.slots.contains_key(&name) {
+            self.record_duplicate("Slot", &name);
+        } else {
+            self.slots.insert(name, slot_def);
+        }
         self
     }
+
+    /// Remember a duplicate definition unless an earlier one was found
+    fn record_duplicate(&mut self, kind: &str, name: &str) {
+        if self.duplicate.is_none() {
+            self.duplicate = Some(LinkMLError::duplicate_definition(kind, name));
+        }
+    }
 }
 
 /// Builder for constructing class definitions
@@ -535,40 +625,42 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_basic_schema_builder() {
+    fn test_basic_schema_builder() -> BuilderResult<()> {
         let schema = SchemaBuilder::new("test_schema", "TestSchema")
             .with_description("A test schema")
             .with_version("1.0.0")
-            .build();
+            .build()?;
 
         assert_eq!(schema.id, "test_schema");
         assert_eq!(schema.name, "TestSchema");
         assert_eq!(schema.description, Some("A test schema".to_string()));
         assert_eq!(schema.version, Some("1.0.0".to_string()));
         assert!(schema.prefixes.contains_key("linkml"));
+        Ok(())
     }
 
     #[test]
-    fn test_schema_with_prefix() {
+    fn test_schema_with_prefix() -> BuilderResult<()> {
         let schema = SchemaBuilder::new("test", "Test")
             .add_prefix("schema", "http://schema.org/")
             .add_prefix("dc", "http://purl.org/dc/terms/")
-            .build();
+            .build()?;
 
         assert_eq!(schema.prefixes.len(), 3); // linkml + schema + dc
         assert!(schema.prefixes.contains_key("schema"));
         assert!(schema.prefixes.contains_key("dc"));
+        Ok(())
     }
 
     #[test]
-    fn test_schema_with_class() {
+    fn test_schema_with_class() -> BuilderResult<()> {
         let schema = SchemaBuilder::new("person_schema", "PersonSchema")
             .add_class("Person")
             .with_description("A person entity")
             .add_attribute("name", "string", true, false)
             .add_attribute("age", "integer", false, false)
             .finish()
-            .build();
+            .build()?;
 
         assert!(schema.classes.contains_key("Person"));
         let person_class = schema.classes.get("Person").unwrap();
@@ -582,10 +674,11 @@ mod tests {
         assert_eq!(name_attr.range, Some("string".to_string()));
         assert_eq!(name_attr.required, Some(true));
         assert_eq!(name_attr.multivalued, Some(false));
+        Ok(())
     }
 
     #[test]
-    fn test_schema_with_multiple_classes() {
+    fn test_schema_with_multiple_classes() -> BuilderResult<()> {
         let schema = SchemaBuilder::new("org_schema", "OrganizationSchema")
             .add_class("Person")
             .add_attribute("name", "string", true, false)
@@ -594,7 +687,7 @@ mod tests {
             .add_attribute("org_name", "string", true, false)
             .add_attribute("employees", "Person", false, true)
             .finish()
-            .build();
+            .build()?;
 
         assert_eq!(schema.classes.len(), 2);
         assert!(schema.classes.contains_key("Person"));
@@ -604,10 +697,11 @@ mod tests {
         let employees = org_class.attributes.get("employees").unwrap();
         assert_eq!(employees.range, Some("Person".to_string()));
         assert_eq!(employees.multivalued, Some(true));
+        Ok(())
     }
 
     #[test]
-    fn test_class_inheritance() {
+    fn test_class_inheritance() -> BuilderResult<()> {
         let schema = SchemaBuilder::new("test", "Test")
             .add_class("Entity")
             .abstract_()
@@ -617,17 +711,18 @@ mod tests {
             .is_a("Entity")
             .add_attribute("name", "string", true, false)
             .finish()
-            .build();
+            .build()?;
 
         let entity = schema.classes.get("Entity").unwrap();
         assert_eq!(entity.abstract_, Some(true));
 
         let person = schema.classes.get("Person").unwrap();
         assert_eq!(person.is_a, Some("Entity".to_string()));
+        Ok(())
     }
 
     #[test]
-    fn test_class_mixins() {
+    fn test_class_mixins() -> BuilderResult<()> {
         let schema = SchemaBuilder::new("test", "Test")
             .add_class("Timestamped")
             .mixin()
@@ -637,17 +732,18 @@ mod tests {
             .add_mixin("Timestamped")
             .add_attribute("name", "string", true, false)
             .finish()
-            .build();
+            .build()?;
 
         let timestamped = schema.classes.get("Timestamped").unwrap();
         assert_eq!(timestamped.mixin, Some(true));
 
         let person = schema.classes.get("Person").unwrap();
         assert_eq!(person.mixins, vec!["Timestamped".to_string()]);
+        Ok(())
     }
 
     #[test]
-    fn test_top_level_slot() {
+    fn test_top_level_slot() -> BuilderResult<()> {
         let schema = SchemaBuilder::new("test", "Test")
             .add_slot("email")
             .with_description("Email address")
@@ -658,7 +754,7 @@ mod tests {
             .add_class("Person")
             .use_slot("email")
             .finish()
-            .build();
+            .build()?;
 
         assert!(schema.slots.contains_key("email"));
         let email_slot = schema.slots.get("email").unwrap();
@@ -668,22 +764,24 @@ mod tests {
 
         let person = schema.classes.get("Person").unwrap();
         assert_eq!(person.slots, vec!["email".to_string()]);
+        Ok(())
     }
 
     #[test]
-    fn test_default_range() {
+    fn test_default_range() -> BuilderResult<()> {
         let schema = SchemaBuilder::new("test", "Test")
             .with_default_range("string")
             .add_class("Person")
             .add_attribute("name", "string", true, false)
             .finish()
-            .build();
+            .build()?;
 
         assert_eq!(schema.default_range, Some("string".to_string()));
+        Ok(())
     }
 
     #[test]
-    fn test_schema_serialization_to_yaml() {
+    fn test_schema_serialization_to_yaml() -> BuilderResult<()> {
         let schema = SchemaBuilder::new("simple", "SimpleSchema")
             .with_version("1.0.0")
             .add_prefix("ex", "https://example.org/")
@@ -691,17 +789,18 @@ mod tests {
             .with_description("An example class")
             .add_attribute("field1", "string", true, false)
             .finish()
-            .build();
+            .build()?;
 
         let yaml = serde_yaml::to_string(&schema).expect("Failed to serialize to YAML");
         assert!(yaml.contains("id: simple"));
         assert!(yaml.contains("name: SimpleSchema"));
         assert!(yaml.contains("Example:"));
         assert!(yaml.contains("field1:"));
+        Ok(())
     }
 
     #[test]
-    fn test_tree_root_class() {
+    fn test_tree_root_class() -> BuilderResult<()> {
         let schema = SchemaBuilder::new("test", "Test")
             .add_class("Container")
             .tree_root()
@@ -710,21 +809,22 @@ mod tests {
             .add_class("Item")
             .add_attribute("name", "string", true, false)
             .finish()
-            .build();
+            .build()?;
 
         let container = schema.classes.get("Container").unwrap();
         assert_eq!(container.tree_root, Some(true));
+        Ok(())
     }
 
     #[test]
-    fn test_complex_prefix() {
+    fn test_complex_prefix() -> BuilderResult<()> {
         let schema = SchemaBuilder::new("test", "Test")
             .add_complex_prefix(
                 "obo",
                 "http://purl.obolibrary.org/obo/",
                 Some("https://obofoundry.org".to_string()),
             )
-            .build();
+            .build()?;
 
         assert!(schema.prefixes.contains_key("obo"));
         if let PrefixDefinition::Complex {
@@ -740,5 +840,113 @@ mod tests {
         } else {
             panic!("Expected complex prefix definition");
         }
+        Ok(())
+    }
+
+    #[test]
+    fn test_duplicate_definitions_are_rejected() {
+        let duplicate_class = SchemaBuilder::new("test", "Test")
+            .add_class("Person")
+            .finish()
+            .add_class("Person")
+            .finish()
+            .build();
+        assert!(matches!(
+            duplicate_class,
+            Err(LinkMLError::DuplicateDefinition { ref kind, ref name })
+                if kind == "Class" && name == "Person"
+        ));
+
+        let duplicate_slot = SchemaBuilder::new("test", "Test")
+            .add_slot("name")
+            .finish()
+            .add_slot("name")
+            .with_range("integer")
+            .finish()
+            .build();
+        assert!(matches!(
+            duplicate_slot,
+            Err(LinkMLError::DuplicateDefinition { ref kind, .. }) if kind == "Slot"
+        ));
+
+        // An enum cannot share its name with a class, as both name ranges
+        let enum_clash = SchemaBuilder::new("test", "Test")
+            .add_class("Status")
+            .finish()
+            .add_enum("Status", ["ACTIVE"])
+            .build();
+        assert!(matches!(
+            enum_clash,
+            Err(LinkMLError::DuplicateDefinition { ref kind, .. }) if kind == "Enum"
+        ));
+
+        let prefix_clash = SchemaBuilder::new("test", "Test")
+            .add_prefix("linkml", "https://example.org/not-linkml/")
+            .build();
+        assert!(matches!(
+            prefix_clash,
+            Err(LinkMLError::DuplicateDefinition { ref kind, .. }) if kind == "Prefix"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_person_schema_validates_data() -> anyhow::Result<()> {
+        use crate::validator::ValidationEngine;
+        use serde_json::json;
+
+        let schema = SchemaBuilder::new("https://example.org/person", "person")
+            .add_prefix("ex", "https://example.org/")
+            .with_default_prefix("ex")
+            .add_import("linkml:types")
+            .add_enum("PersonStatus", ["ALIVE", "DEAD", "UNKNOWN"])
+            .add_slot("id")
+            .with_range("string")
+            .identifier()
+            .required()
+            .finish()
+            .add_slot("email")
+            .with_range("string")
+            .with_pattern(r"^[^@\s]+@[^@\s]+$")
+            .finish()
+            .add_class("Person")
+            .use_slot("id")
+            .use_slot("email")
+            .add_attribute("name", "string", true, false)
+            .add_attribute("age", "integer", false, false)
+            .add_attribute("status", "PersonStatus", false, false)
+            .finish()
+            .build()?;
+
+        assert_eq!(schema.imports, vec!["linkml:types".to_string()]);
+        assert_eq!(schema.enums["PersonStatus"].permissible_values.len(), 3);
+
+        let engine = ValidationEngine::new(&schema)?;
+        let valid = json!({
+            "id": "P1",
+            "name": "Ada Lovelace",
+            "email": "ada@example.org",
+            "age": 36,
+            "status": "DEAD"
+        });
+        let report = engine.validate_as_class(&valid, "Person", None).await?;
+        assert!(report.valid, "{report}");
+
+        let invalid = json!({
+            "id": "P2",
+            "email": "not an email",
+            "age": "thirty-six",
+            "status": "RETIRED"
+        });
+        let report = engine.validate_as_class(&invalid, "Person", None).await?;
+        assert!(!report.valid);
+        let mut paths: Vec<_> = report
+            .issues
+            .iter()
+            .map(|issue| issue.path.as_str())
+            .collect();
+        paths.sort_unstable();
+        paths.dedup();
+        assert_eq!(paths, ["$.age", "$.email", "$.name", "$.status"]);
+        Ok(())
     }
 }
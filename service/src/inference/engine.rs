@@ -499,7 +499,9 @@ impl InferenceEngine {
             builder = class_builder.finish();
         }
 
-        let schema = builder.build();
+        let schema = builder
+            .build()
+            .map_err(|e| InferenceError::SchemaGenerationFailed(e.to_string()))?;
 
         self.logger
             .log(
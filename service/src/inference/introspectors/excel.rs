@@ -566,7 +566,9 @@ impl DataIntrospector for ExcelIntrospector {
             builder = class_builder.finish();
         }
 
-        let schema = builder.build();
+        let schema = builder
+            .build()
+            .map_err(|e| InferenceError::SchemaGenerationFailed(e.to_string()))?;
 
         self.logger
             .log_info(&format!(
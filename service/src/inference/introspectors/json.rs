@@ -439,7 +439,9 @@ impl DataIntrospector for JsonIntrospector {
             builder = class_builder.finish();
         }
 
-        let schema = builder.build();
+        let schema = builder
+            .build()
+            .map_err(|e| InferenceError::SchemaGenerationFailed(e.to_string()))?;
 
         self.logger
             .log_info(&format!(
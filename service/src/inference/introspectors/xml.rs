@@ -610,7 +610,9 @@ impl DataIntrospector for XmlIntrospector {
             builder = class_builder.finish();
         }
 
-        let schema = builder.build();
+        let schema = builder
+            .build()
+            .map_err(|e| InferenceError::SchemaGenerationFailed(e.to_string()))?;
 
         self.logger
             .log_info(&format!(
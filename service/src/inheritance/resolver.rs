@@ -3,6 +3,7 @@
 //! This module handles full multiple inheritance including mixins,
 //! slot overrides, and diamond inheritance patterns.
 
+use indexmap::IndexMap;
 use linkml_core::annotations::Annotations;
 use linkml_core::prelude::*;
 use std::collections::{HashMap, HashSet, VecDeque};
@@ -299,6 +300,10 @@ impl<'a> InheritanceResolver<'a> {
 
         // Process each slot
         for slot_name in &resolved_class.slots {
+            if resolved_class.attributes.contains_key(slot_name) {
+                continue;
+            }
+
             // Start with the base slot definition
             let mut slot = self
                 .schema
@@ -312,17 +317,117 @@ impl<'a> InheritanceResolver<'a> {
                 self.apply_slot_override(&mut slot, override_def);
             }
 
-            // Check attributes for inline definitions
-            if let Some(attr_def) = resolved_class.attributes.get(slot_name) {
-                self.apply_slot_override(&mut slot, attr_def);
-            }
-
             resolved_slots.insert(slot_name.clone(), slot);
         }
 
+        // Attributes are complete definitions and need no schema slot
+        for (attr_name, attr_def) in &resolved_class.attributes {
+            let mut slot = attr_def.clone();
+            if let Some(override_def) = resolved_class.slot_usage.get(attr_name) {
+                self.apply_slot_override(&mut slot, override_def);
+            }
+            slot.name.clone_from(attr_name);
+            resolved_slots.insert(attr_name.clone(), slot);
+        }
+
         Ok(resolved_slots)
     }
 
+    /// Compute the effective ("induced") slot definitions of a class
+    ///
+    /// Walks the `is_a` chain and mixins from the most general ancestor to
+    /// the class itself, so later definitions take precedence: a mixin
+    /// overrides the `is_a` parent, a later mixin overrides an earlier one,
+    /// and `slot_usage` overrides whatever was inherited. Slots are returned
+    /// in the order they were first introduced, with `name` set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the class does not exist or its inheritance
+    /// hierarchy is circular.
+    pub fn induced_slots(&self, class_name: &str) -> Result<Vec<SlotDefinition>> {
+        if !self.schema.classes.contains_key(class_name) {
+            return Err(LinkMLError::service(format!(
+                "Class '{class_name}' not found"
+            )));
+        }
+
+        let mut precedence = Vec::new();
+        self.collect_precedence(class_name, &mut precedence, &mut Vec::new())?;
+
+        let mut induced: IndexMap<String, SlotDefinition> = IndexMap::new();
+        for class in precedence
+            .iter()
+            .filter_map(|name| self.schema.classes.get(name))
+        {
+            for slot_name in &class.slots {
+                if !induced.contains_key(slot_name) {
+                    let slot = self
+                        .schema
+                        .slots
+                        .get(slot_name)
+                        .cloned()
+                        .unwrap_or_else(|| SlotDefinition::new(slot_name));
+                    induced.insert(slot_name.clone(), slot);
+                }
+            }
+
+            // Attributes are complete definitions and replace inherited ones
+            for (attr_name, attr_def) in &class.attributes {
+                induced.insert(attr_name.clone(), attr_def.clone());
+            }
+
+            for (slot_name, usage) in &class.slot_usage {
+                if let Some(slot) = induced.get_mut(slot_name) {
+                    self.apply_slot_override(slot, usage);
+                }
+            }
+        }
+
+        Ok(induced
+            .into_iter()
+            .map(|(name, mut slot)| {
+                slot.name = name;
+                slot
+            })
+            .collect())
+    }
+
+    /// Order a class and its ancestors from most general to most specific
+    ///
+    /// Each class appears once, at the position of its first (most general)
+    /// occurrence; unknown ancestors are skipped.
+    fn collect_precedence(
+        &self,
+        class_name: &str,
+        precedence: &mut Vec<String>,
+        stack: &mut Vec<String>,
+    ) -> Result<()> {
+        if stack.iter().any(|name| name == class_name) {
+            return Err(LinkMLError::service(format!(
+                "Circular inheritance detected for class '{class_name}'"
+            )));
+        }
+        if precedence.iter().any(|name| name == class_name) {
+            return Ok(());
+        }
+        let Some(class) = self.schema.classes.get(class_name) else {
+            return Ok(());
+        };
+
+        stack.push(class_name.to_string());
+        if let Some(parent) = &class.is_a {
+            self.collect_precedence(parent, precedence, stack)?;
+        }
+        for mixin in &class.mixins {
+            self.collect_precedence(mixin, precedence, stack)?;
+        }
+        stack.pop();
+
+        precedence.push(class_name.to_string());
+        Ok(())
+    }
+
     /// Apply slot override/usage to a slot definition
     fn apply_slot_override(&self, target: &mut SlotDefinition, override_def: &SlotDefinition) {
         // Handle slot inheritance through is_a
@@ -362,6 +467,11 @@ impl<'a> InheritanceResolver<'a> {
         if override_def.pattern.is_some() {
             target.pattern.clone_from(&override_def.pattern);
         }
+        if override_def.structured_pattern.is_some() {
+            target
+                .structured_pattern
+                .clone_from(&override_def.structured_pattern);
+        }
         if override_def.minimum_value.is_some() {
             target.minimum_value.clone_from(&override_def.minimum_value);
         }
@@ -556,4 +666,85 @@ mod tests {
         assert_eq!(a_count, 1, "Diamond inheritance should not duplicate slots");
         Ok(())
     }
+
+    #[test]
+    fn test_induced_slots_precedence() -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let mut schema = SchemaDefinition::default();
+        schema.slots.insert(
+            "id".to_string(),
+            SlotDefinition {
+                range: Some("string".to_string()),
+                ..SlotDefinition::new("id")
+            },
+        );
+
+        let entity = ClassDefinition {
+            name: "Entity".to_string(),
+            slots: vec!["id".to_string()],
+            ..Default::default()
+        };
+        schema.classes.insert("Entity".to_string(), entity);
+
+        let mut coded = ClassDefinition {
+            name: "Coded".to_string(),
+            mixin: Some(true),
+            ..Default::default()
+        };
+        coded.attributes.insert(
+            "code".to_string(),
+            SlotDefinition {
+                range: Some("string".to_string()),
+                description: Some("Generic code".to_string()),
+                ..Default::default()
+            },
+        );
+        schema.classes.insert("Coded".to_string(), coded);
+
+        let mut alpha_coded = ClassDefinition {
+            name: "AlphaCoded".to_string(),
+            mixin: Some(true),
+            ..Default::default()
+        };
+        alpha_coded.attributes.insert(
+            "code".to_string(),
+            SlotDefinition {
+                pattern: Some("^[A-Z]+$".to_string()),
+                ..Default::default()
+            },
+        );
+        schema.classes.insert("AlphaCoded".to_string(), alpha_coded);
+
+        let mut country = ClassDefinition {
+            name: "Country".to_string(),
+            is_a: Some("Entity".to_string()),
+            mixins: vec!["Coded".to_string(), "AlphaCoded".to_string()],
+            ..Default::default()
+        };
+        country.slot_usage.insert(
+            "id".to_string(),
+            SlotDefinition {
+                required: Some(true),
+                pattern: Some("^[A-Z]{2}$".to_string()),
+                ..Default::default()
+            },
+        );
+        schema.classes.insert("Country".to_string(), country);
+
+        let resolver = InheritanceResolver::new(&schema);
+        let slots = resolver.induced_slots("Country")?;
+        let names: Vec<&str> = slots.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["id", "code"]);
+
+        // slot_usage refines the inherited global slot
+        assert_eq!(slots[0].range.as_deref(), Some("string"));
+        assert_eq!(slots[0].required, Some(true));
+        assert_eq!(slots[0].pattern.as_deref(), Some("^[A-Z]{2}$"));
+
+        // The later mixin's attribute wins
+        assert_eq!(slots[1].pattern.as_deref(), Some("^[A-Z]+$"));
+        assert_eq!(slots[1].description, None);
+
+        assert!(resolver.induced_slots("Missing").is_err());
+        Ok(())
+    }
 }
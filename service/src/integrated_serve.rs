@@ -4,8 +4,11 @@
 //! not just imports and patterns. The LinkML service MUST NOT create
 //! its own HTTP server but instead register with the REST API service.
 
+use arc_swap::ArcSwap;
 use axum::Router;
-use std::path::PathBuf;
+use chrono::Utc;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use linkml_core::{
@@ -15,6 +18,9 @@ use linkml_core::{
 
 use crate::cli_enhanced::commands::serve::AppState;
 use crate::validator::engine::ValidationEngine;
+use crate::validator::instance_resolver::InstanceResolver;
+use crate::validator::result_cache::ValidationResultCache;
+use hash_core::{HashError, HashService};
 
 // Shutdown integration
 use async_trait::async_trait;
@@ -28,7 +34,6 @@ use timestamp_core::Duration;
 // use dbms_core::DBMSService;
 // use error_handling_core::ObjectSafeErrorHandler;
 // use frontend_framework_service::cors::{CorsConfig, create_cors_layer};
-// use hash_core::HashService;
 // use lakehouse_core::LakehouseService;
 // use logger_core::LoggerService;
 // use monitoring_core::MonitoringService;
@@ -49,9 +54,70 @@ use timestamp_core::Duration;
 /// This is the ONLY way `LinkML` should provide HTTP endpoints - by creating
 /// a router that the REST API service can mount, NOT by running its own server.
 pub struct LinkMLRouterFactory {
-    schema: Arc<SchemaDefinition>,
-    validator: Arc<ValidationEngine>,
+    state: SharedState,
+    reload_route: bool,
+}
+
+/// Handler state whose schema and validator are swapped on reload
+#[derive(Clone)]
+struct SharedState {
+    current: Arc<ArcSwap<AppState>>,
     schema_path: String,
+    /// Reports of earlier validations, cleared on reload
+    result_cache: Option<Arc<ValidationResultCache>>,
+}
+
+impl SharedState {
+    /// Snapshot of the currently loaded schema and validator
+    fn load(&self) -> Arc<AppState> {
+        self.current.load_full()
+    }
+
+    /// Re-read the schema file and swap in a fresh schema and validator
+    fn reload(&self) -> Result<()> {
+        let (schema, validator) =
+            load_schema(Path::new(&self.schema_path), self.result_cache.as_ref())?;
+        if let Some(cache) = &self.result_cache {
+            cache.clear();
+        }
+        let previous = self.load();
+        self.current.store(Arc::new(AppState {
+            schema: Arc::new(schema),
+            validator: Arc::new(validator),
+            schema_path: self.schema_path.clone(),
+            instance_resolver: previous.instance_resolver.clone(),
+            loaded_at: Utc::now(),
+        }));
+        Ok(())
+    }
+}
+
+/// Read and parse a schema file and build its validation engine
+fn load_schema(
+    schema_path: &Path,
+    result_cache: Option<&Arc<ValidationResultCache>>,
+) -> Result<(SchemaDefinition, ValidationEngine)> {
+    let schema_content =
+        std::fs::read_to_string(schema_path).map_err(|e| LinkMLError::DataValidationError {
+            message: format!("Failed to read schema: {e}"),
+            path: Some(schema_path.display().to_string()),
+            expected: Some("readable schema file".to_string()),
+            actual: Some("read error".to_string()),
+        })?;
+
+    let schema: SchemaDefinition =
+        serde_yaml::from_str(&schema_content).map_err(|e| LinkMLError::DataValidationError {
+            message: format!("Failed to parse schema: {e}"),
+            path: Some(schema_path.display().to_string()),
+            expected: Some("valid YAML schema".to_string()),
+            actual: Some("malformed YAML".to_string()),
+        })?;
+
+    let validator = ValidationEngine::new(&schema)?;
+    if let Some(cache) = result_cache {
+        validator.attach_result_cache(Arc::clone(cache))?;
+    }
+    Ok((schema, validator))
 }
 
 impl LinkMLRouterFactory {
@@ -61,62 +127,110 @@ impl LinkMLRouterFactory {
     ///
     /// Returns an error if schema loading or validation fails
     pub fn new(schema_path: PathBuf) -> Result<Self> {
-        // Load and validate schema
-        let schema_content = std::fs::read_to_string(&schema_path).map_err(|e| {
-            LinkMLError::DataValidationError {
-                message: format!("Failed to read schema: {e}"),
-                path: Some(schema_path.display().to_string()),
-                expected: Some("readable schema file".to_string()),
-                actual: Some("read error".to_string()),
-            }
-        })?;
-
-        let schema: SchemaDefinition = serde_yaml::from_str(&schema_content).map_err(|e| {
-            LinkMLError::DataValidationError {
-                message: format!("Failed to parse schema: {e}"),
-                path: Some(schema_path.display().to_string()),
-                expected: Some("valid YAML schema".to_string()),
-                actual: Some("malformed YAML".to_string()),
-            }
-        })?;
-
-        let validator = ValidationEngine::new(&schema)?;
+        let (schema, validator) = load_schema(&schema_path, None)?;
+        let schema_path = schema_path.to_string_lossy().to_string();
 
         Ok(Self {
-            schema: Arc::new(schema),
-            validator: Arc::new(validator),
-            schema_path: schema_path.to_string_lossy().to_string(),
+            state: SharedState {
+                current: Arc::new(ArcSwap::from_pointee(AppState {
+                    schema: Arc::new(schema),
+                    validator: Arc::new(validator),
+                    schema_path: schema_path.clone(),
+                    instance_resolver: None,
+                    loaded_at: Utc::now(),
+                })),
+                schema_path,
+                result_cache: None,
+            },
+            reload_route: false,
         })
     }
 
+    /// Report the instance caches of `resolver` from the health endpoint
+    #[must_use]
+    pub fn with_instance_resolver(self, resolver: Arc<InstanceResolver>) -> Self {
+        let current = self.state.load();
+        self.state.current.store(Arc::new(AppState {
+            instance_resolver: Some(resolver),
+            ..(*current).clone()
+        }));
+        self
+    }
+
+    /// Serve repeated identical validations from a cache of `capacity`
+    /// reports, keyed with digests from `hash_service`
+    ///
+    /// The cache is attached to the loaded validator. Every reload clears
+    /// the cache.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the factory already has a result cache
+    pub fn with_result_cache(
+        mut self,
+        capacity: NonZeroUsize,
+        hash_service: Arc<dyn HashService<Error = HashError>>,
+    ) -> Result<Self> {
+        let cache = Arc::new(ValidationResultCache::new(capacity, hash_service));
+        self.state
+            .load()
+            .validator
+            .attach_result_cache(Arc::clone(&cache))?;
+        self.state.result_cache = Some(cache);
+        Ok(self)
+    }
+
+    /// Expose `POST /reload`, which reloads the schema file on request
+    #[must_use]
+    pub fn with_reload_route(mut self, enabled: bool) -> Self {
+        self.reload_route = enabled;
+        self
+    }
+
+    /// Re-read the schema file and swap in a freshly built validator
+    ///
+    /// Routers created by this factory serve the new schema from their next
+    /// request on; requests already running finish against the old one. If
+    /// the file cannot be loaded, the previous schema stays in place.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the schema file cannot be read, parsed or compiled
+    pub fn reload(&self) -> Result<()> {
+        self.state.reload()
+    }
+
     /// Create the router that will be registered with REST API service
     pub fn create_router(&self) -> Router {
-        let app_state = AppState {
-            schema: self.schema.clone(),
-            validator: self.validator.clone(),
-            schema_path: self.schema_path.clone(),
-        };
-
-        Router::new()
+        let router = Router::new()
             .route("/schema", axum::routing::get(handlers::get_schema))
             .route("/validate", axum::routing::post(handlers::validate_data))
-            .route("/health", axum::routing::get(handlers::health_check))
-            .with_state(app_state)
+            .route(
+                "/validate-batch",
+                axum::routing::post(handlers::validate_batch),
+            )
+            .route("/health", axum::routing::get(handlers::health_check));
+        let router = if self.reload_route {
+            router.route("/reload", axum::routing::post(handlers::reload_schema))
+        } else {
+            router
+        };
+        router.with_state(self.state.clone())
     }
 
     /// Get the schema path for logging/debugging
     pub fn schema_path(&self) -> &str {
-        &self.schema_path
+        &self.state.schema_path
     }
 
-    /// Get the schema for inspection
-    pub fn schema(&self) -> &Arc<SchemaDefinition> {
-        &self.schema
+    /// Get the currently loaded schema
+    pub fn schema(&self) -> Arc<SchemaDefinition> {
+        self.state.load().schema.clone()
     }
 
-    /// Get the validator for inspection
-    pub fn validator(&self) -> &Arc<ValidationEngine> {
-        &self.validator
+    /// Get the currently loaded validator
+    pub fn validator(&self) -> Arc<ValidationEngine> {
+        self.state.load().validator.clone()
     }
 }
 /// Creates a LinkML router that can be nested into the REST API service.
@@ -155,7 +269,10 @@ impl LinkMLRouterFactory {
 /// A configured [`Router`] with LinkML endpoints:
 /// - `GET /schema` - Retrieve the loaded schema definition
 /// - `POST /validate` - Validate data against the schema
-/// - `GET /health` - Health check with schema information
+/// - `POST /validate-batch` - Validate an array of instances of one class
+/// - `GET /health` - Health check with schema and cache statistics
+/// - `POST /reload` - Reload the schema file, if enabled with
+///   [`LinkMLRouterFactory::with_reload_route`]
 ///
 /// # Errors
 ///
@@ -177,18 +294,23 @@ pub fn create_linkml_routes(schema_path: PathBuf) -> Result<Router> {
 
 /// Handler implementations that work with the integrated service
 mod handlers {
-    use super::{AppState, SchemaDefinition};
-    use crate::cli_enhanced::commands::serve::{HealthResponse, ValidateRequest, ValidateResponse};
+    use super::{SchemaDefinition, SharedState};
+    use crate::cli_enhanced::commands::serve::{
+        BatchSummary, HealthResponse, InstanceValidation, ValidateBatchRequest,
+        ValidateBatchResponse, ValidateRequest, ValidateResponse,
+    };
+    use crate::validator::engine::ValidationOptions;
     use axum::{extract::State, http::StatusCode, response::Json};
 
-    pub async fn get_schema(State(state): State<AppState>) -> Json<SchemaDefinition> {
-        Json((*state.schema).clone())
+    pub async fn get_schema(State(shared): State<SharedState>) -> Json<SchemaDefinition> {
+        Json((*shared.load().schema).clone())
     }
 
     pub async fn validate_data(
-        State(state): State<AppState>,
+        State(shared): State<SharedState>,
         Json(request): Json<ValidateRequest>,
     ) -> std::result::Result<Json<ValidateResponse>, StatusCode> {
+        let state = shared.load();
         let options = request.options.map(std::convert::Into::into);
 
         let result = if let Some(class_name) = request.class_name {
@@ -209,13 +331,83 @@ mod handlers {
         }
     }
 
-    pub async fn health_check(State(state): State<AppState>) -> Json<HealthResponse> {
-        Json(HealthResponse {
-            status: "healthy".to_string(),
-            schema_path: state.schema_path.clone(),
-            schema_name: state.schema.name.clone(),
-            version: env!("CARGO_PKG_VERSION").to_string(),
-        })
+    /// Validate every instance of a batch against one class
+    ///
+    /// `fail_fast` stops at the first invalid instance and `max_errors` caps
+    /// the errors reported across the whole batch.
+    pub async fn validate_batch(
+        State(shared): State<SharedState>,
+        Json(request): Json<ValidateBatchRequest>,
+    ) -> std::result::Result<Json<ValidateBatchResponse>, StatusCode> {
+        let state = shared.load();
+        if !state.schema.classes.contains_key(&request.class_name) {
+            return Err(StatusCode::BAD_REQUEST);
+        }
+        let options: ValidationOptions = request.options.map(Into::into).unwrap_or_default();
+
+        let mut summary = BatchSummary {
+            total: request.instances.len(),
+            ..BatchSummary::default()
+        };
+        let mut results = Vec::with_capacity(request.instances.len());
+        let mut error_count = 0;
+        for (index, instance) in request.instances.iter().enumerate() {
+            let mut instance_options = options.clone();
+            instance_options.max_errors = options
+                .max_errors
+                .map(|max| max.saturating_sub(error_count));
+
+            let report = state
+                .validator
+                .validate_as_class(instance, &request.class_name, Some(instance_options))
+                .await
+                .map_err(|_| StatusCode::BAD_REQUEST)?;
+
+            summary.validated += 1;
+            if report.valid {
+                summary.valid += 1;
+            } else {
+                summary.invalid += 1;
+            }
+            error_count += report.stats.error_count;
+            let valid = report.valid;
+            results.push(InstanceValidation {
+                index,
+                valid,
+                report,
+            });
+
+            let limit_reached = options.max_errors.is_some_and(|max| error_count >= max);
+            if !valid && (options.fail_fast() || limit_reached) {
+                summary.stopped_early = summary.validated < summary.total;
+                break;
+            }
+        }
+
+        Ok(Json(ValidateBatchResponse {
+            valid: summary.invalid == 0,
+            summary,
+            results,
+        }))
+    }
+
+    pub async fn health_check(State(shared): State<SharedState>) -> Json<HealthResponse> {
+        Json(HealthResponse::from_state(&shared.load()))
+    }
+
+    /// Reload the schema file and report the newly loaded schema
+    pub async fn reload_schema(
+        State(shared): State<SharedState>,
+    ) -> std::result::Result<Json<HealthResponse>, StatusCode> {
+        let reloading = shared.clone();
+        tokio::task::spawn_blocking(move || reloading.reload())
+            .await
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+            .map_err(|e| {
+                tracing::warn!("Schema reload failed, keeping the previous schema: {e}");
+                StatusCode::UNPROCESSABLE_ENTITY
+            })?;
+        Ok(Json(HealthResponse::from_state(&shared.load())))
     }
 }
 
@@ -357,7 +549,13 @@ impl LinkMLShutdownHook {
         // but we could add explicit flush methods if needed
 
         // 4. Release schema resources
-        // Arc will handle this automatically when the last reference is dropped
+        // Arc will handle this automatically when the last reference is dropped;
+        // interned strings no longer used by any schema are dropped from the pool
+        let released = linkml_core::string_pool::shrink();
+        tracing::debug!(
+            "LinkML shutdown hook '{}' released {released} interned strings",
+            self.name
+        );
 
         tracing::info!("LinkML shutdown hook '{}' completed cleanup", self.name);
 
@@ -435,10 +633,193 @@ where
     let hook = LinkMLShutdownHook::new(
         "default",
         router_factory.schema_path(),
-        router_factory.validator().clone(),
+        router_factory.validator(),
     );
 
     shutdown_service
         .register_shutdown_hook(Box::new(hook))
         .await
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli_enhanced::commands::serve::{
+        BatchSummary, ValidateBatchRequest, ValidateRequest, ValidationOptionsDto,
+    };
+    use crate::validator::instance_loader::InstanceLoader;
+    use axum::{Json, extract::State};
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    const SCHEMA: &str = "\
+id: https://example.org/places
+name: places
+classes:
+  Place:
+    slots: [id, label]
+  Country:
+    is_a: Place
+    slots: [country_code]
+slots:
+  id:
+    identifier: true
+  label:
+    range: string
+  country_code:
+    range: string
+    pattern: \"^[A-Z]{2}$\"
+";
+
+    #[tokio::test]
+    async fn test_health_reports_schema_stats() -> anyhow::Result<()> {
+        let dir = TempDir::new()?;
+        let schema_path = dir.path().join("schema.yaml");
+        std::fs::write(&schema_path, SCHEMA)?;
+        let loader = Arc::new(InstanceLoader::default());
+        let resolver = Arc::new(InstanceResolver::new(dir.path().to_path_buf(), loader));
+        let factory = LinkMLRouterFactory::new(schema_path)?.with_instance_resolver(resolver);
+
+        let health = handlers::health_check(State(factory.state.clone())).await.0;
+
+        assert_eq!(health.status, "healthy");
+        assert_eq!(health.schema_name, "places");
+        assert_eq!(health.class_count, factory.schema().classes.len());
+        assert_eq!(health.class_count, 2);
+        assert_eq!(health.slot_count, 3);
+        assert_eq!(health.metrics, factory.schema().metrics());
+        assert!(!health.instance_caches_warm);
+        assert_eq!(health.validator_cache_hit_rate, None);
+        assert!(health.schema_loaded_at <= Utc::now());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_validate_batch_reports_each_instance() -> anyhow::Result<()> {
+        let dir = TempDir::new()?;
+        let schema_path = dir.path().join("schema.yaml");
+        std::fs::write(&schema_path, SCHEMA)?;
+        let factory = LinkMLRouterFactory::new(schema_path)?;
+        let request = |options: Option<ValidationOptionsDto>| ValidateBatchRequest {
+            class_name: "Country".to_string(),
+            instances: vec![
+                json!({"id": "nl", "country_code": "NL"}),
+                json!({"id": "de", "country_code": "deu"}),
+                json!({"id": "fr", "country_code": "FR"}),
+                json!({"id": "be", "country_code": "Belgium"}),
+            ],
+            options,
+        };
+
+        let response = handlers::validate_batch(State(factory.state.clone()), Json(request(None)))
+            .await
+            .map_err(|status| anyhow::anyhow!("batch validation failed: {status}"))?
+            .0;
+        assert!(!response.valid);
+        assert_eq!(
+            response.summary,
+            BatchSummary {
+                total: 4,
+                validated: 4,
+                valid: 2,
+                invalid: 2,
+                stopped_early: false,
+            }
+        );
+        let invalid: Vec<_> = response
+            .results
+            .iter()
+            .filter(|result| !result.valid)
+            .map(|result| result.index)
+            .collect();
+        assert_eq!(invalid, [1, 3]);
+
+        let fail_fast = ValidationOptionsDto {
+            fail_fast: Some(true),
+            ..ValidationOptionsDto::default()
+        };
+        let response =
+            handlers::validate_batch(State(factory.state.clone()), Json(request(Some(fail_fast))))
+                .await
+                .map_err(|status| anyhow::anyhow!("batch validation failed: {status}"))?
+                .0;
+        assert_eq!(response.summary.validated, 2);
+        assert_eq!(response.summary.invalid, 1);
+        assert!(response.summary.stopped_early);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_reload_picks_up_edited_schema() -> anyhow::Result<()> {
+        let dir = TempDir::new()?;
+        let schema_path = dir.path().join("schema.yaml");
+        std::fs::write(&schema_path, SCHEMA)?;
+        let factory = LinkMLRouterFactory::new(schema_path.clone())?.with_reload_route(true);
+        let state = factory.state.clone();
+        assert_eq!(factory.schema().classes.len(), 2);
+
+        let edited = SCHEMA.replace("\nslots:", "\n  City:\n    is_a: Place\nslots:");
+        std::fs::write(&schema_path, edited)?;
+        // Nothing changes until the schema is reloaded
+        assert_eq!(factory.schema().classes.len(), 2);
+        factory.reload()?;
+        assert_eq!(factory.schema().classes.len(), 3);
+        assert!(factory.schema().classes.contains_key("City"));
+        let health = handlers::health_check(State(state.clone())).await.0;
+        assert_eq!(health.class_count, 3);
+
+        // A broken schema leaves the last good one in place
+        std::fs::write(&schema_path, "classes: [")?;
+        let status = handlers::reload_schema(State(state))
+            .await
+            .err()
+            .ok_or_else(|| anyhow::anyhow!("reloading a broken schema should fail"))?;
+        assert_eq!(status, axum::http::StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(factory.schema().classes.len(), 3);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_reload_clears_result_cache() -> anyhow::Result<()> {
+        let dir = TempDir::new()?;
+        let schema_path = dir.path().join("schema.yaml");
+        std::fs::write(&schema_path, SCHEMA)?;
+        let capacity = NonZeroUsize::new(8).ok_or_else(|| anyhow::anyhow!("zero capacity"))?;
+        let factory = LinkMLRouterFactory::new(schema_path.clone())?;
+        let loaded = factory.state.load();
+        let factory =
+            factory.with_result_cache(capacity, hash_service::wiring::wire_hash().into_arc())?;
+        // The cache goes on the validator already loaded, without a reload
+        assert!(Arc::ptr_eq(&loaded, &factory.state.load()));
+        assert!(loaded.validator.result_cache().is_some());
+        let cache = factory
+            .state
+            .result_cache
+            .clone()
+            .ok_or_else(|| anyhow::anyhow!("factory should have a result cache"))?;
+
+        let request = || ValidateRequest {
+            data: json!({"id": "nl", "country_code": "nl"}),
+            class_name: Some("Country".to_string()),
+            options: None,
+        };
+        for _ in 0..2 {
+            let response = handlers::validate_data(State(factory.state.clone()), Json(request()))
+                .await
+                .map_err(|status| anyhow::anyhow!("validation failed: {status}"))?;
+            assert!(!response.0.valid);
+        }
+        assert_eq!((cache.hits(), cache.misses()), (1, 1));
+
+        // The reloaded schema accepts lowercase codes; no stale report is served
+        std::fs::write(&schema_path, SCHEMA.replace("^[A-Z]{2}$", "^[a-zA-Z]{2}$"))?;
+        factory.reload()?;
+        assert!(cache.is_empty());
+        let response = handlers::validate_data(State(factory.state.clone()), Json(request()))
+            .await
+            .map_err(|status| anyhow::anyhow!("validation failed: {status}"))?;
+        assert!(response.0.valid);
+        assert_eq!((cache.hits(), cache.misses()), (1, 2));
+        Ok(())
+    }
+}
@@ -10,6 +10,8 @@ use serde_json::Value as JsonValue;
 use std::collections::HashMap;
 use std::path::Path;
 
+use crate::inheritance::InheritanceResolver;
+
 use super::traits::{
     DataDumper, DataInstance, DataLoader, DumpOptions, DumperError, DumperResult, LoadOptions,
     LoaderError, LoaderResult,
@@ -41,6 +43,12 @@ pub struct CsvOptions {
 
     /// Encoding (currently only UTF-8 supported)
     pub encoding: String,
+
+    /// Column holding the instance identifier
+    ///
+    /// When unset, the column named after the target class's identifier
+    /// slot is used.
+    pub id_column: Option<String>,
 }
 
 impl Default for CsvOptions {
@@ -54,6 +62,7 @@ impl Default for CsvOptions {
             trim: true,
             flexible: false,
             encoding: "utf-8".to_string(),
+            id_column: None,
         }
     }
 }
@@ -97,23 +106,50 @@ impl CsvLoader {
         }
     }
 
+    /// Use the given column as the instance identifier
+    #[must_use]
+    pub fn with_id_column(mut self, column: impl Into<String>) -> Self {
+        self.options.id_column = Some(column.into());
+        self
+    }
+
+    /// Resolve the slots of the target class, keyed by slot name
+    ///
+    /// Inherited slots, attributes and `slot_usage` refinements are taken
+    /// into account so that values are coerced using the ranges that apply
+    /// to this class.
+    fn class_slots(
+        class_name: &str,
+        schema: &SchemaDefinition,
+    ) -> LoaderResult<HashMap<String, SlotDefinition>> {
+        if !schema.classes.contains_key(class_name) {
+            return Err(LoaderError::SchemaValidation(format!(
+                "Class '{class_name}' not found in schema"
+            )));
+        }
+
+        let slots = InheritanceResolver::new(schema)
+            .induced_slots(class_name)
+            .map_err(|e| LoaderError::SchemaValidation(e.to_string()))?;
+        Ok(slots
+            .into_iter()
+            .map(|slot| (slot.name.clone(), slot))
+            .collect())
+    }
+
     /// Parse a CSV record into a data instance
     fn parse_record(
         &self,
         record: &StringRecord,
         headers: &[String],
         class_name: &str,
+        class_slots: &HashMap<String, SlotDefinition>,
         schema: &SchemaDefinition,
         field_mappings: &HashMap<String, String>,
     ) -> LoaderResult<DataInstance> {
         let mut data = HashMap::new();
         let mut id = None;
 
-        // Get class definition
-        let _class_def = schema.classes.get(class_name).ok_or_else(|| {
-            LoaderError::SchemaValidation(format!("Class '{class_name}' not found in schema"))
-        })?;
-
         // Process each field
         for (i, value) in record.iter().enumerate() {
             if i >= headers.len() {
@@ -135,15 +171,21 @@ impl CsvLoader {
                 continue;
             }
 
-            // Check if this is an identifier field
-            if let Some(slot_def) = schema.slots.get(field_name)
-                && slot_def.identifier == Some(true)
-            {
-                id = Some(value.to_string());
+            let slot_def = class_slots
+                .get(field_name)
+                .or_else(|| schema.slots.get(field_name));
+
+            // Check if this is the identifier column
+            let is_id = match &self.options.id_column {
+                Some(id_column) => header == id_column || field_name == id_column,
+                None => slot_def.is_some_and(|slot| slot.identifier == Some(true)),
+            };
+            if is_id {
+                id = Some(value.trim().to_string());
             }
 
             // Convert value based on slot type
-            let json_value = self.convert_value(value, field_name, schema)?;
+            let json_value = self.convert_value(value, slot_def, schema)?;
             data.insert(field_name.clone(), json_value);
         }
 
@@ -159,14 +201,15 @@ impl CsvLoader {
     fn convert_value(
         &self,
         value: &str,
-        field_name: &str,
+        slot_def: Option<&SlotDefinition>,
         schema: &SchemaDefinition,
     ) -> LoaderResult<JsonValue> {
-        // Get slot definition to determine type
-        if let Some(slot_def) = schema.slots.get(field_name)
+        // Use the slot range to determine type
+        if let Some(slot_def) = slot_def
             && let Some(range) = &slot_def.range
         {
-            return self.convert_typed_value(value, range, slot_def);
+            let type_name = schema.base_type(range);
+            return self.convert_typed_value(value, type_name, slot_def);
         }
 
         // Default to string
@@ -391,6 +434,17 @@ impl DataLoader for CsvLoader {
                 "No target class specified and type inference disabled".to_string(),
             ));
         };
+        let class_slots = Self::class_slots(&target_class, schema)?;
+
+        if let Some(id_column) = &self.options.id_column
+            && !headers.iter().any(|header| {
+                header == id_column || options.field_mappings.get(header) == Some(id_column)
+            })
+        {
+            return Err(LoaderError::MissingField(format!(
+                "ID column '{id_column}' not found in CSV headers"
+            )));
+        }
 
         // Load records
         let mut instances = Vec::new();
@@ -410,6 +464,7 @@ impl DataLoader for CsvLoader {
                         &record,
                         &headers,
                         &target_class,
+                        &class_slots,
                         schema,
                         &options.field_mappings,
                     ) {
@@ -981,7 +1036,7 @@ impl DataDumper for CsvDumper {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use linkml_core::types::{ClassDefinition, SchemaDefinition, SlotDefinition};
+    use linkml_core::types::{ClassDefinition, SchemaDefinition, SlotDefinition, TypeDefinition};
 
     fn create_test_schema() -> SchemaDefinition {
         let mut schema = SchemaDefinition {
@@ -1159,4 +1214,103 @@ p2,Bob,not_a_number,bob@example.com,
         assert_eq!(instances.len(), 1); // Only valid record
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_country_csv_to_rdf() -> std::result::Result<(), Box<dyn std::error::Error>> {
+        use crate::loader::rdf::{RdfDumper, RdfSerializationFormat};
+
+        let mut schema = SchemaDefinition::new("countries");
+        schema.types.insert(
+            "Population".to_string(),
+            TypeDefinition {
+                name: "Population".to_string(),
+                base_type: Some("integer".to_string()),
+                ..Default::default()
+            },
+        );
+        for (name, range) in [
+            ("code", "string"),
+            ("name", "string"),
+            ("population", "Population"),
+            ("area_km2", "float"),
+            ("landlocked", "boolean"),
+        ] {
+            schema.slots.insert(
+                name.to_string(),
+                SlotDefinition {
+                    range: Some(range.to_string()),
+                    ..SlotDefinition::new(name)
+                },
+            );
+        }
+        let mut country = ClassDefinition {
+            slots: vec![
+                "code".to_string(),
+                "name".to_string(),
+                "population".to_string(),
+            ],
+            ..ClassDefinition::new("Country")
+        };
+        country.attributes.insert(
+            "area_km2".to_string(),
+            SlotDefinition {
+                range: Some("float".to_string()),
+                ..SlotDefinition::new("area_km2")
+            },
+        );
+        country.attributes.insert(
+            "landlocked".to_string(),
+            SlotDefinition {
+                range: Some("boolean".to_string()),
+                ..SlotDefinition::new("landlocked")
+            },
+        );
+        schema.classes.insert("Country".to_string(), country);
+
+        let tsv_content = "code\tname\tpopulation\tarea_km2\tlandlocked
+CH\tSwitzerland\t8776000\t41285.0\ttrue
+PT\tPortugal\t10467000\t92212.0\tfalse
+";
+        let loader = CsvLoader::tsv().with_id_column("code");
+        let options = LoadOptions {
+            target_class: Some("Country".to_string()),
+            ..Default::default()
+        };
+        let instances = loader.load_string(tsv_content, &schema, &options).await?;
+
+        assert_eq!(instances.len(), 2);
+        let switzerland = &instances[0];
+        assert_eq!(switzerland.class_name, "Country");
+        assert_eq!(switzerland.id.as_deref(), Some("CH"));
+        assert_eq!(
+            switzerland.data.get("population"),
+            Some(&serde_json::json!(8_776_000))
+        );
+        assert_eq!(
+            switzerland.data.get("area_km2"),
+            Some(&serde_json::json!(41285.0))
+        );
+        assert_eq!(
+            switzerland.data.get("landlocked"),
+            Some(&JsonValue::Bool(true))
+        );
+
+        let missing_id = CsvLoader::tsv().with_id_column("iso_code");
+        assert!(
+            missing_id
+                .load_string(tsv_content, &schema, &options)
+                .await
+                .is_err()
+        );
+
+        let dumper = RdfDumper::with_format(RdfSerializationFormat::NTriples);
+        let output = dumper
+            .dump_string(&instances, &schema, &DumpOptions::default())
+            .await?;
+        assert!(output.contains("/CH>"));
+        assert!(output.contains("\"Portugal\""));
+        assert!(output.contains("\"8776000\"^^<http://www.w3.org/2001/XMLSchema#integer>"));
+        assert!(output.contains("\"false\"^^<http://www.w3.org/2001/XMLSchema#boolean>"));
+        Ok(())
+    }
 }
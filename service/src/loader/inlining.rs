@@ -0,0 +1,102 @@
+//! Inlined and referenced object-valued slots
+//!
+//! A slot whose range is a class holds either nested objects or references
+//! to other instances by identifier. Following `LinkML`, objects of classes
+//! without an identifier are always inlined; otherwise `inlined` or
+//! `inlined_as_list` opt into nesting and the default is a reference.
+
+use linkml_core::types::{SchemaDefinition, SlotDefinition};
+
+/// How the values of a slot with a class range are serialized
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ObjectSlot<'a> {
+    /// Values are nested objects of the range class
+    Inlined {
+        /// Range class of the nested objects
+        range: &'a str,
+        /// Identifier slot of the range class, if any
+        identifier: Option<&'a str>,
+        /// Multivalued slots nest a list rather than a map keyed by identifier
+        as_list: bool,
+    },
+    /// Values are identifiers of instances of the range class
+    Referenced {
+        /// Range class of the referenced instances
+        range: &'a str,
+        /// Identifier slot of the range class
+        identifier: &'a str,
+    },
+}
+
+impl<'a> ObjectSlot<'a> {
+    /// Serialization of `slot`, or `None` if its range is not a class
+    pub(crate) fn of(schema: &'a SchemaDefinition, slot: &'a SlotDefinition) -> Option<Self> {
+        let range = slot.range.as_deref()?;
+        if !schema.classes.contains_key(range) {
+            return None;
+        }
+
+        let identifier = identifier_slot(schema, range);
+        let inlined = slot.inlined == Some(true) || slot.inlined_as_list == Some(true);
+        match identifier {
+            Some(identifier) if !inlined => Some(Self::Referenced { range, identifier }),
+            _ => Some(Self::Inlined {
+                range,
+                identifier,
+                as_list: identifier.is_none() || slot.inlined_as_list == Some(true),
+            }),
+        }
+    }
+
+    /// Range class of the slot
+    pub(crate) fn range(self) -> &'a str {
+        match self {
+            Self::Inlined { range, .. } | Self::Referenced { range, .. } => range,
+        }
+    }
+}
+
+/// Definition of a slot as used by `class_name`, preferring its attributes
+pub(crate) fn find_slot<'a>(
+    schema: &'a SchemaDefinition,
+    class_name: &str,
+    slot_name: &str,
+) -> Option<&'a SlotDefinition> {
+    schema
+        .classes
+        .get(class_name)
+        .and_then(|class_def| class_def.attributes.get(slot_name))
+        .or_else(|| schema.slots.get(slot_name))
+}
+
+/// Identifier slot of a class, including one inherited through `is_a`
+pub(crate) fn identifier_slot<'a>(
+    schema: &'a SchemaDefinition,
+    class_name: &str,
+) -> Option<&'a str> {
+    let mut current = schema.classes.get(class_name);
+    // Bounded by the class count so that inheritance cycles terminate
+    for _ in 0..schema.classes.len() {
+        let class_def = current?;
+        let attribute = class_def
+            .attributes
+            .iter()
+            .find(|(_, slot)| slot.identifier == Some(true))
+            .map(|(name, _)| name.as_str());
+        let slot = class_def
+            .slots
+            .iter()
+            .find(|name| {
+                schema
+                    .slots
+                    .get(name.as_str())
+                    .is_some_and(|slot| slot.identifier == Some(true))
+            })
+            .map(String::as_str);
+        if let Some(identifier) = attribute.or(slot) {
+            return Some(identifier);
+        }
+        current = schema.classes.get(class_def.is_a.as_deref()?);
+    }
+    None
+}
@@ -0,0 +1,163 @@
+//! SPARQL queries over data instances
+//!
+//! [`InstanceGraph`] holds instances as RDF in an in-memory store, built the
+//! same way [`RdfDumper`] writes them, so they can be sanity-checked with
+//! SPARQL before being exported. Available with the `in_memory_rdf` feature.
+
+use linkml_core::error::{LinkMLError, Result};
+use linkml_core::types::SchemaDefinition;
+use oxigraph::sparql::{QueryResults, SparqlEvaluator};
+use oxigraph::store::Store;
+use serde_json::Value as JsonValue;
+use std::collections::HashMap;
+
+use super::rdf::{RdfDumper, RdfLoader, RdfOptions};
+use super::traits::DataInstance;
+
+/// Values bound by one SPARQL solution, keyed by variable name
+///
+/// Literals convert to `JSON` as the RDF loader reads them; IRIs and blank
+/// nodes become strings.
+pub type Bindings = HashMap<String, JsonValue>;
+
+/// In-memory RDF graph of data instances
+pub struct InstanceGraph {
+    store: Store,
+}
+
+impl InstanceGraph {
+    /// Build the graph of `instances` using the default RDF options
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an instance cannot be converted to RDF
+    pub fn from_instances(instances: &[DataInstance], schema: &SchemaDefinition) -> Result<Self> {
+        Self::with_options(instances, schema, RdfOptions::default())
+    }
+
+    /// Build the graph of `instances`, naming nodes as `options` direct
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an instance cannot be converted to RDF
+    pub fn with_options(
+        instances: &[DataInstance],
+        schema: &SchemaDefinition,
+        options: RdfOptions,
+    ) -> Result<Self> {
+        let store = RdfDumper::with_options(options).create_store(instances, schema)?;
+        Ok(Self { store })
+    }
+
+    /// Run a SPARQL `SELECT` query and collect its solutions
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query is invalid, fails to evaluate, or is not
+    /// a `SELECT` query
+    pub fn query(&self, sparql: &str) -> Result<Vec<Bindings>> {
+        let results = SparqlEvaluator::new()
+            .parse_query(sparql)
+            .map_err(|e| LinkMLError::parse(format!("Invalid SPARQL query: {e}")))?
+            .on_store(&self.store)
+            .execute()
+            .map_err(|e| LinkMLError::service(format!("SPARQL evaluation failed: {e}")))?;
+        let QueryResults::Solutions(solutions) = results else {
+            return Err(LinkMLError::service("Only SELECT queries return bindings"));
+        };
+
+        solutions
+            .map(|solution| {
+                let solution = solution
+                    .map_err(|e| LinkMLError::service(format!("SPARQL evaluation failed: {e}")))?;
+                solution
+                    .iter()
+                    .map(|(variable, term)| {
+                        Ok((
+                            variable.as_str().to_string(),
+                            RdfLoader::term_to_json(term)?,
+                        ))
+                    })
+                    .collect::<Result<Bindings>>()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use linkml_core::types::{ClassDefinition, SlotDefinition};
+    use serde_json::json;
+
+    #[test]
+    fn test_count_countries_by_tld() -> anyhow::Result<()> {
+        let mut schema = SchemaDefinition::new("countries");
+        schema.classes.insert(
+            "Country".to_string(),
+            ClassDefinition {
+                slots: vec!["id".to_string(), "tld".to_string()],
+                ..ClassDefinition::new("Country")
+            },
+        );
+        schema.slots.insert(
+            "id".to_string(),
+            SlotDefinition {
+                identifier: Some(true),
+                ..SlotDefinition::new("id")
+            },
+        );
+        schema
+            .slots
+            .insert("tld".to_string(), SlotDefinition::new("tld"));
+
+        // Caribbean Netherlands shares the Dutch top-level domain in practice
+        let instances: Vec<DataInstance> = [("NL", ".nl"), ("BQ", ".nl"), ("BE", ".be")]
+            .into_iter()
+            .map(|(id, tld)| DataInstance {
+                class_name: "Country".to_string(),
+                data: HashMap::from([
+                    ("id".to_string(), json!(id)),
+                    ("tld".to_string(), json!(tld)),
+                ]),
+                id: Some(id.to_string()),
+                metadata: HashMap::new(),
+            })
+            .collect();
+        let graph = InstanceGraph::from_instances(&instances, &schema)?;
+
+        let total = graph.query(
+            "SELECT (COUNT(?country) AS ?count) \
+             WHERE { ?country a <http://example.org/Country> }",
+        )?;
+        assert_eq!(total, [Bindings::from([("count".to_string(), json!(3))])]);
+
+        let by_tld = graph.query(
+            "SELECT ?tld (COUNT(?country) AS ?count) \
+             WHERE { ?country <http://example.org/tld> ?tld } \
+             GROUP BY ?tld ORDER BY ?tld",
+        )?;
+        assert_eq!(
+            by_tld,
+            [
+                Bindings::from([
+                    ("tld".to_string(), json!(".be")),
+                    ("count".to_string(), json!(1)),
+                ]),
+                Bindings::from([
+                    ("tld".to_string(), json!(".nl")),
+                    ("count".to_string(), json!(2)),
+                ]),
+            ]
+        );
+
+        let error = graph
+            .query("SELECT WHERE")
+            .expect_err("malformed queries should be rejected");
+        assert!(
+            error.to_string().contains("Invalid SPARQL query"),
+            "{error}"
+        );
+        Ok(())
+    }
+}
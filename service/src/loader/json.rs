@@ -2,6 +2,7 @@
 //!
 //! This module provides functionality to load and dump `LinkML` data in JSON format.
 
+use super::inlining::{ObjectSlot, find_slot, identifier_slot};
 use super::traits::{
     DataDumper, DataInstance, DataLoader, DumpOptions, DumperError, DumperResult, LoadOptions,
     LoaderError, LoaderResult,
@@ -394,6 +395,88 @@ impl JsonDumper {
     }
 }
 
+impl JsonDumper {
+    /// Nest or reference the objects held by an object-valued slot
+    ///
+    /// Inlined slots embed the instance an identifier refers to; multivalued
+    /// ones become a map keyed by identifier unless `inlined_as_list` is set.
+    /// Referenced slots replace nested objects by their identifier.
+    fn dump_object_slot(
+        value: &Value,
+        object_slot: ObjectSlot<'_>,
+        multivalued: bool,
+        instances_by_id: &HashMap<&str, &DataInstance>,
+    ) -> Value {
+        match object_slot {
+            ObjectSlot::Referenced { identifier, .. } => {
+                let reference = |item: &Value| match item {
+                    Value::Object(target) => target
+                        .get(identifier)
+                        .cloned()
+                        .unwrap_or_else(|| item.clone()),
+                    other => other.clone(),
+                };
+                match value {
+                    Value::Array(items) => Value::Array(items.iter().map(reference).collect()),
+                    Value::Object(keyed) if multivalued => {
+                        Value::Array(keyed.keys().cloned().map(Value::String).collect())
+                    }
+                    other => reference(other),
+                }
+            }
+            ObjectSlot::Inlined {
+                identifier,
+                as_list,
+                ..
+            } => {
+                let embed = |item: &Value| match item {
+                    Value::String(id) => instances_by_id.get(id.as_str()).map_or_else(
+                        || item.clone(),
+                        |target| Value::Object(target.data.clone().into_iter().collect()),
+                    ),
+                    other => other.clone(),
+                };
+                if !multivalued {
+                    return embed(value);
+                }
+
+                let items: Vec<Value> = match value {
+                    Value::Array(items) => items.iter().map(embed).collect(),
+                    Value::Object(keyed) => keyed
+                        .iter()
+                        .map(|(key, item)| {
+                            let mut item = embed(item);
+                            if let (Some(identifier), Value::Object(fields)) =
+                                (identifier, &mut item)
+                            {
+                                fields
+                                    .entry(identifier)
+                                    .or_insert_with(|| Value::String(key.clone()));
+                            }
+                            item
+                        })
+                        .collect(),
+                    other => vec![embed(other)],
+                };
+                match identifier {
+                    Some(identifier) if !as_list => {
+                        // Objects without an identifier value can only be listed
+                        let keyed: Option<Map<String, Value>> = items
+                            .iter()
+                            .map(|item| {
+                                let key = item.get(identifier)?.as_str()?;
+                                Some((key.to_string(), item.clone()))
+                            })
+                            .collect();
+                        keyed.map_or(Value::Array(items), Value::Object)
+                    }
+                    _ => Value::Array(items),
+                }
+            }
+        }
+    }
+}
+
 impl Default for JsonDumper {
     fn default() -> Self {
         Self::new(true)
@@ -429,16 +512,41 @@ impl DataDumper for JsonDumper {
     async fn dump_string(
         &self,
         instances: &[DataInstance],
-        _schema: &SchemaDefinition,
+        schema: &SchemaDefinition,
         options: &DumpOptions,
     ) -> DumperResult<String> {
+        // Instances reachable by their id or identifier slot value
+        let instances_by_id: HashMap<&str, &DataInstance> = instances
+            .iter()
+            .flat_map(|instance| {
+                let identifier = identifier_slot(schema, &instance.class_name)
+                    .and_then(|slot| instance.data.get(slot)?.as_str());
+                instance
+                    .id
+                    .as_deref()
+                    .into_iter()
+                    .chain(identifier)
+                    .map(move |id| (id, instance))
+            })
+            .collect();
+
         let json_instances: Vec<Value> = instances
             .iter()
             .map(|instance| {
                 let mut obj = Map::new();
                 // Convert HashMap to Map
                 for (k, v) in &instance.data {
-                    obj.insert(k.clone(), v.clone());
+                    let slot = find_slot(schema, &instance.class_name, k);
+                    let value = match slot.and_then(|slot| ObjectSlot::of(schema, slot)) {
+                        Some(object_slot) => Self::dump_object_slot(
+                            v,
+                            object_slot,
+                            slot.is_some_and(|slot| slot.multivalued == Some(true)),
+                            &instances_by_id,
+                        ),
+                        None => v.clone(),
+                    };
+                    obj.insert(k.clone(), value);
                 }
                 obj.insert(
                     "@type".to_string(),
@@ -562,6 +670,7 @@ impl DataDumper for JsonDumper {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use serde_json::json;
 
     #[tokio::test]
     async fn test_json_loader() -> std::result::Result<(), anyhow::Error> {
@@ -642,4 +751,76 @@ mod tests {
         assert_eq!(parsed[0]["name"], "Alice");
         Ok(())
     }
+
+    /// Countries whose `capital` slot has the class range `City`
+    fn capital_schema(inlined: bool) -> SchemaDefinition {
+        let mut schema = SchemaDefinition::new("geography");
+        schema.classes.insert(
+            "Country".to_string(),
+            ClassDefinition {
+                slots: vec!["id".to_string(), "capital".to_string()],
+                ..ClassDefinition::new("Country")
+            },
+        );
+        schema.classes.insert(
+            "City".to_string(),
+            ClassDefinition {
+                slots: vec!["id".to_string(), "name".to_string()],
+                ..ClassDefinition::new("City")
+            },
+        );
+        for slot in [
+            SlotDefinition {
+                identifier: Some(true),
+                ..SlotDefinition::new("id")
+            },
+            SlotDefinition::new("name"),
+            SlotDefinition {
+                range: Some("City".to_string()),
+                inlined: Some(inlined),
+                ..SlotDefinition::new("capital")
+            },
+        ] {
+            schema.slots.insert(slot.name.clone(), slot);
+        }
+        schema
+    }
+
+    fn instance(class_name: &str, data: Value) -> DataInstance {
+        DataInstance {
+            class_name: class_name.to_string(),
+            data: serde_json::from_value(data).expect("instance data should be an object"),
+            id: None,
+            metadata: HashMap::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_json_dumper_inlines_or_references_objects() -> anyhow::Result<()> {
+        let amsterdam = json!({"id": "AMS", "name": "Amsterdam"});
+        let instances = vec![
+            instance("City", amsterdam.clone()),
+            instance("Country", json!({"id": "NL", "capital": "AMS"})),
+            instance("Country", json!({"id": "BQ", "capital": amsterdam.clone()})),
+        ];
+        let dumper = JsonDumper::new(false);
+        let options = DumpOptions::default();
+
+        // Inlined slots embed the referenced city
+        let json_str = dumper
+            .dump_string(&instances, &capital_schema(true), &options)
+            .await?;
+        let parsed: Vec<Value> = serde_json::from_str(&json_str)?;
+        assert_eq!(parsed[1]["capital"], amsterdam);
+        assert_eq!(parsed[2]["capital"], amsterdam);
+
+        // Referenced slots hold the city's identifier
+        let json_str = dumper
+            .dump_string(&instances, &capital_schema(false), &options)
+            .await?;
+        let parsed: Vec<Value> = serde_json::from_str(&json_str)?;
+        assert_eq!(parsed[1]["capital"], "AMS");
+        assert_eq!(parsed[2]["capital"], "AMS");
+        Ok(())
+    }
 }
@@ -10,8 +10,13 @@ pub mod csv;
 pub mod database;
 pub mod dbms_executor;
 pub mod excel;
+mod inlining;
+#[cfg(feature = "in_memory_rdf")]
+pub mod instance_graph;
 pub mod json;
 pub mod json_v2;
+#[cfg(feature = "parquet")]
+pub mod parquet;
 pub mod rdf;
 pub mod traits;
 pub mod traits_v2;
@@ -31,8 +36,12 @@ pub use csv::{CsvDumper, CsvLoader, CsvOptions};
 pub use database::{DatabaseDumper, DatabaseLoader, DatabaseOptions, ForeignKeyRelation};
 pub use dbms_executor::DBMSServiceExecutor;
 pub use excel::{ExcelLoader, ExcelOptions};
+#[cfg(feature = "in_memory_rdf")]
+pub use instance_graph::{Bindings, InstanceGraph};
 pub use json::{JsonDumper, JsonLoader};
-pub use rdf::{RdfDumper, RdfLoader, RdfOptions, RdfSerializationFormat};
+#[cfg(feature = "parquet")]
+pub use parquet::{LINKML_CLASS_METADATA_KEY, ParquetDumper, ParquetOptions};
+pub use rdf::{IriStrategy, RdfDumper, RdfLoader, RdfOptions, RdfSerializationFormat};
 pub use traits::{
     DataDumper, DataInstance, DataLoader, DumpOptions, DumperError, DumperResult, LoadOptions,
     LoaderError, LoaderResult,
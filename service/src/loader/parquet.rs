@@ -0,0 +1,648 @@
+//! Parquet data dumper for `LinkML`
+//!
+//! This module writes `LinkML` data instances to Apache Parquet files. The
+//! Arrow schema of the file is inferred from the induced slots of the dumped
+//! class: slot ranges map to Arrow primitive types and multivalued slots
+//! become list columns.
+
+use arrow::array::{
+    Array, ArrayRef, BooleanArray, Float64Array, Int64Array, ListArray, RecordBatch, StringArray,
+};
+use arrow::buffer::{NullBuffer, OffsetBuffer};
+use arrow::datatypes::{DataType, Field, Schema};
+use async_trait::async_trait;
+use linkml_core::prelude::*;
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression;
+use parquet::file::properties::WriterProperties;
+use serde_json::Value as JsonValue;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::inheritance::InheritanceResolver;
+
+use super::traits::{DataDumper, DataInstance, DumpOptions, DumperError, DumperResult};
+
+/// Arrow schema metadata key holding the name of the dumped `LinkML` class
+pub const LINKML_CLASS_METADATA_KEY: &str = "linkml:class";
+
+/// Options specific to Parquet dumping
+#[derive(Debug, Clone)]
+pub struct ParquetOptions {
+    /// Class whose instances are written (default: the class of the first
+    /// instance)
+    pub target_class: Option<String>,
+
+    /// Maximum number of rows per row group
+    pub row_group_size: usize,
+
+    /// Compress column chunks with Snappy
+    pub compress: bool,
+}
+
+impl Default for ParquetOptions {
+    fn default() -> Self {
+        Self {
+            target_class: None,
+            row_group_size: 64 * 1024,
+            compress: true,
+        }
+    }
+}
+
+/// Physical representation of a slot's values in Arrow
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ColumnKind {
+    Integer,
+    Float,
+    Boolean,
+    Utf8,
+}
+
+impl ColumnKind {
+    fn data_type(self) -> DataType {
+        match self {
+            Self::Integer => DataType::Int64,
+            Self::Float => DataType::Float64,
+            Self::Boolean => DataType::Boolean,
+            Self::Utf8 => DataType::Utf8,
+        }
+    }
+}
+
+/// A Parquet column derived from an induced slot
+#[derive(Debug, Clone)]
+struct ColumnSpec {
+    name: String,
+    kind: ColumnKind,
+    multivalued: bool,
+    required: bool,
+    identifier: bool,
+}
+
+impl ColumnSpec {
+    fn field(&self) -> Field {
+        let item_type = self.kind.data_type();
+        let data_type = if self.multivalued {
+            DataType::List(Arc::new(Field::new("item", item_type, true)))
+        } else {
+            item_type
+        };
+        Field::new(&self.name, data_type, !self.required)
+    }
+}
+
+/// Parquet data dumper
+pub struct ParquetDumper {
+    options: ParquetOptions,
+}
+
+impl ParquetDumper {
+    /// Create a new Parquet dumper
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            options: ParquetOptions::default(),
+        }
+    }
+
+    /// Create a new Parquet dumper with custom options
+    #[must_use]
+    pub fn with_options(options: ParquetOptions) -> Self {
+        Self { options }
+    }
+
+    /// Infer the Arrow schema used to dump instances of a class
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the class does not exist or its slots cannot be
+    /// resolved.
+    pub fn arrow_schema(
+        &self,
+        class_name: &str,
+        schema: &SchemaDefinition,
+    ) -> DumperResult<Schema> {
+        let columns = Self::columns(class_name, schema)?;
+        Ok(Self::schema_for(class_name, &columns))
+    }
+
+    fn schema_for(class_name: &str, columns: &[ColumnSpec]) -> Schema {
+        let metadata = HashMap::from([(
+            LINKML_CLASS_METADATA_KEY.to_string(),
+            class_name.to_string(),
+        )]);
+        Schema::new_with_metadata(
+            columns.iter().map(ColumnSpec::field).collect::<Vec<_>>(),
+            metadata,
+        )
+    }
+
+    /// Derive one column per induced slot of the class
+    fn columns(class_name: &str, schema: &SchemaDefinition) -> DumperResult<Vec<ColumnSpec>> {
+        if !schema.classes.contains_key(class_name) {
+            return Err(DumperError::SchemaValidation(format!(
+                "Class '{class_name}' not found in schema"
+            )));
+        }
+
+        let slots = InheritanceResolver::new(schema)
+            .induced_slots(class_name)
+            .map_err(|e| DumperError::SchemaValidation(e.to_string()))?;
+
+        Ok(slots
+            .into_iter()
+            .map(|slot| ColumnSpec {
+                kind: slot
+                    .range
+                    .as_deref()
+                    .map_or(ColumnKind::Utf8, |range| column_kind(range, schema)),
+                multivalued: slot.multivalued == Some(true),
+                required: slot.required == Some(true) || slot.identifier == Some(true),
+                identifier: slot.identifier == Some(true),
+                name: slot.name,
+            })
+            .collect())
+    }
+
+    /// Pick the class to dump and the instances belonging to it
+    fn select_instances<'a>(
+        &self,
+        instances: &'a [DataInstance],
+        options: &DumpOptions,
+    ) -> DumperResult<(String, Vec<&'a DataInstance>)> {
+        let mut selected: Vec<&DataInstance> = instances
+            .iter()
+            .filter(|instance| {
+                options
+                    .include_classes
+                    .as_ref()
+                    .is_none_or(|classes| classes.contains(&instance.class_name))
+            })
+            .collect();
+        if let Some(limit) = options.limit {
+            selected.truncate(limit);
+        }
+
+        let class_name = match (&self.options.target_class, selected.first()) {
+            (Some(class_name), _) => class_name.clone(),
+            (None, Some(first)) => first.class_name.clone(),
+            (None, None) => {
+                return Err(DumperError::Configuration(
+                    "No instances to dump and no target class specified".to_string(),
+                ));
+            }
+        };
+
+        // A Parquet file has a single schema, so every row must share a class
+        if let Some(other) = selected
+            .iter()
+            .find(|instance| instance.class_name != class_name)
+        {
+            return Err(DumperError::Configuration(format!(
+                "Parquet output holds instances of one class, found '{}' alongside '{class_name}'",
+                other.class_name
+            )));
+        }
+
+        Ok((class_name, selected))
+    }
+
+    /// Build a record batch holding the given instances
+    fn record_batch(
+        class_name: &str,
+        columns: &[ColumnSpec],
+        instances: &[&DataInstance],
+    ) -> DumperResult<RecordBatch> {
+        let arrays = columns
+            .iter()
+            .map(|column| {
+                let values: Vec<Option<JsonValue>> = instances
+                    .iter()
+                    .map(|instance| {
+                        instance
+                            .data
+                            .get(&column.name)
+                            .cloned()
+                            .or_else(|| {
+                                // Fall back to the instance id for the identifier column
+                                column
+                                    .identifier
+                                    .then(|| instance.id.clone().map(JsonValue::String))
+                                    .flatten()
+                            })
+                            .filter(|value| !value.is_null())
+                    })
+                    .collect();
+
+                if column.multivalued {
+                    list_array(column, &values)
+                } else {
+                    scalar_array(column, &values)
+                }
+            })
+            .collect::<DumperResult<Vec<ArrayRef>>>()?;
+
+        RecordBatch::try_new(Arc::new(Self::schema_for(class_name, columns)), arrays)
+            .map_err(|e| DumperError::Serialization(format!("Failed to build record batch: {e}")))
+    }
+}
+
+impl Default for ParquetDumper {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl DataDumper for ParquetDumper {
+    fn name(&self) -> &'static str {
+        "parquet"
+    }
+
+    fn description(&self) -> &'static str {
+        "Dumps data to Apache Parquet files"
+    }
+
+    fn supported_extensions(&self) -> Vec<&str> {
+        vec![".parquet"]
+    }
+
+    async fn dump_file(
+        &self,
+        instances: &[DataInstance],
+        path: &Path,
+        schema: &SchemaDefinition,
+        options: &DumpOptions,
+    ) -> DumperResult<()> {
+        let data = self.dump_bytes(instances, schema, options).await?;
+        tokio::fs::write(path, data).await?;
+        Ok(())
+    }
+
+    async fn dump_string(
+        &self,
+        _instances: &[DataInstance],
+        _schema: &SchemaDefinition,
+        _options: &DumpOptions,
+    ) -> DumperResult<String> {
+        Err(DumperError::Configuration(
+            "Parquet is a binary format; use dump_bytes or dump_file".to_string(),
+        ))
+    }
+
+    async fn dump_bytes(
+        &self,
+        instances: &[DataInstance],
+        schema: &SchemaDefinition,
+        options: &DumpOptions,
+    ) -> DumperResult<Vec<u8>> {
+        if self.options.row_group_size == 0 {
+            return Err(DumperError::Configuration(
+                "Parquet row group size must be greater than zero".to_string(),
+            ));
+        }
+
+        let (class_name, selected) = self.select_instances(instances, options)?;
+        let columns = Self::columns(&class_name, schema)?;
+        let arrow_schema = Arc::new(Self::schema_for(&class_name, &columns));
+
+        let properties = WriterProperties::builder()
+            .set_max_row_group_size(self.options.row_group_size)
+            .set_compression(if self.options.compress {
+                Compression::SNAPPY
+            } else {
+                Compression::UNCOMPRESSED
+            })
+            .build();
+
+        let mut buffer = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut buffer, arrow_schema, Some(properties))
+            .map_err(|e| DumperError::Serialization(format!("Failed to create writer: {e}")))?;
+
+        for chunk in selected.chunks(self.options.row_group_size) {
+            let batch = Self::record_batch(&class_name, &columns, chunk)?;
+            writer.write(&batch).map_err(|e| {
+                DumperError::Serialization(format!("Failed to write row group: {e}"))
+            })?;
+        }
+
+        writer.close().map_err(|e| {
+            DumperError::Serialization(format!("Failed to finish Parquet file: {e}"))
+        })?;
+
+        Ok(buffer)
+    }
+
+    fn validate_schema(&self, schema: &SchemaDefinition) -> DumperResult<()> {
+        if let Some(class_name) = &self.options.target_class {
+            Self::columns(class_name, schema)?;
+        }
+        Ok(())
+    }
+}
+
+/// Map a slot range to the Arrow representation of its values
+///
+/// Schema-defined types are followed through `typeof` to their built-in base.
+/// Classes, enums and any other ranges are written as strings.
+fn column_kind(range: &str, schema: &SchemaDefinition) -> ColumnKind {
+    match schema.base_type(range) {
+        "integer" | "int" | "long" | "short" | "byte" => ColumnKind::Integer,
+        "float" | "double" | "decimal" => ColumnKind::Float,
+        "boolean" => ColumnKind::Boolean,
+        _ => ColumnKind::Utf8,
+    }
+}
+
+/// Build a non-list column from one optional value per row
+fn scalar_array(column: &ColumnSpec, values: &[Option<JsonValue>]) -> DumperResult<ArrayRef> {
+    let array: ArrayRef = match column.kind {
+        ColumnKind::Integer => Arc::new(
+            values
+                .iter()
+                .map(|value| value.as_ref().map(|v| to_i64(v, &column.name)).transpose())
+                .collect::<DumperResult<Int64Array>>()?,
+        ),
+        ColumnKind::Float => Arc::new(
+            values
+                .iter()
+                .map(|value| value.as_ref().map(|v| to_f64(v, &column.name)).transpose())
+                .collect::<DumperResult<Float64Array>>()?,
+        ),
+        ColumnKind::Boolean => Arc::new(
+            values
+                .iter()
+                .map(|value| value.as_ref().map(|v| to_bool(v, &column.name)).transpose())
+                .collect::<DumperResult<BooleanArray>>()?,
+        ),
+        ColumnKind::Utf8 => Arc::new(
+            values
+                .iter()
+                .map(|value| value.as_ref().map(to_text))
+                .collect::<StringArray>(),
+        ),
+    };
+    Ok(array)
+}
+
+/// Build a list column; a scalar value becomes a single-element list
+fn list_array(column: &ColumnSpec, values: &[Option<JsonValue>]) -> DumperResult<ArrayRef> {
+    let mut items = Vec::new();
+    let mut lengths = Vec::with_capacity(values.len());
+    let mut validity = Vec::with_capacity(values.len());
+
+    for value in values {
+        match value {
+            Some(JsonValue::Array(elements)) => {
+                lengths.push(elements.len());
+                items.extend(elements.iter().map(|item| Some(item.clone())));
+                validity.push(true);
+            }
+            Some(scalar) => {
+                lengths.push(1);
+                items.push(Some(scalar.clone()));
+                validity.push(true);
+            }
+            None => {
+                lengths.push(0);
+                validity.push(false);
+            }
+        }
+    }
+
+    // Null list elements are stored as null items
+    for item in &mut items {
+        if item.as_ref().is_some_and(JsonValue::is_null) {
+            *item = None;
+        }
+    }
+
+    let item_values = scalar_array(column, &items)?;
+    let item_field = Arc::new(Field::new("item", column.kind.data_type(), true));
+    let array = ListArray::try_new(
+        item_field,
+        OffsetBuffer::from_lengths(lengths),
+        item_values,
+        Some(NullBuffer::from(validity)),
+    )
+    .map_err(|e| {
+        DumperError::Serialization(format!(
+            "Failed to build list column '{}': {e}",
+            column.name
+        ))
+    })?;
+    Ok(Arc::new(array) as Arc<dyn Array>)
+}
+
+fn to_i64(value: &JsonValue, column: &str) -> DumperResult<i64> {
+    match value {
+        JsonValue::Number(n) => n.as_i64(),
+        JsonValue::String(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+    .ok_or_else(|| {
+        DumperError::TypeConversion(format!(
+            "Cannot write '{value}' to integer column '{column}'"
+        ))
+    })
+}
+
+fn to_f64(value: &JsonValue, column: &str) -> DumperResult<f64> {
+    match value {
+        JsonValue::Number(n) => n.as_f64(),
+        JsonValue::String(s) => s.trim().parse().ok(),
+        _ => None,
+    }
+    .ok_or_else(|| {
+        DumperError::TypeConversion(format!("Cannot write '{value}' to float column '{column}'"))
+    })
+}
+
+fn to_bool(value: &JsonValue, column: &str) -> DumperResult<bool> {
+    match value {
+        JsonValue::Bool(b) => Some(*b),
+        JsonValue::String(s) => match s.trim().to_lowercase().as_str() {
+            "true" | "yes" | "1" => Some(true),
+            "false" | "no" | "0" => Some(false),
+            _ => None,
+        },
+        _ => None,
+    }
+    .ok_or_else(|| {
+        DumperError::TypeConversion(format!(
+            "Cannot write '{value}' to boolean column '{column}'"
+        ))
+    })
+}
+
+/// Render a value for a string column; nested objects are written as `JSON`
+fn to_text(value: &JsonValue) -> String {
+    match value {
+        JsonValue::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::AsArray;
+    use arrow::datatypes::Int64Type;
+    use linkml_core::types::{ClassDefinition, SchemaDefinition, SlotDefinition};
+    use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+    use serde_json::json;
+
+    fn country_schema() -> SchemaDefinition {
+        let mut schema = SchemaDefinition::new("countries");
+        for (name, range) in [
+            ("id", "string"),
+            ("label", "string"),
+            ("population", "integer"),
+            ("languages", "string"),
+        ] {
+            schema.slots.insert(
+                name.to_string(),
+                SlotDefinition {
+                    range: Some(range.to_string()),
+                    identifier: (name == "id").then_some(true),
+                    multivalued: (name == "languages").then_some(true),
+                    ..SlotDefinition::new(name)
+                },
+            );
+        }
+        schema.classes.insert(
+            "Country".to_string(),
+            ClassDefinition {
+                slots: vec![
+                    "id".to_string(),
+                    "label".to_string(),
+                    "population".to_string(),
+                    "languages".to_string(),
+                ],
+                ..ClassDefinition::new("Country")
+            },
+        );
+        schema
+    }
+
+    fn country(id: &str, label: &str, population: i64, languages: &[&str]) -> DataInstance {
+        DataInstance {
+            class_name: "Country".to_string(),
+            data: HashMap::from([
+                ("label".to_string(), json!(label)),
+                ("population".to_string(), json!(population)),
+                ("languages".to_string(), json!(languages)),
+            ]),
+            id: Some(id.to_string()),
+            metadata: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_arrow_schema_from_induced_slots() -> anyhow::Result<()> {
+        let arrow_schema = ParquetDumper::new().arrow_schema("Country", &country_schema())?;
+
+        let id = arrow_schema.field_with_name("id")?;
+        assert_eq!(id.data_type(), &DataType::Utf8);
+        assert!(!id.is_nullable());
+        assert_eq!(
+            arrow_schema.field_with_name("population")?.data_type(),
+            &DataType::Int64
+        );
+        assert!(matches!(
+            arrow_schema.field_with_name("languages")?.data_type(),
+            DataType::List(item) if item.data_type() == &DataType::Utf8
+        ));
+        assert_eq!(
+            arrow_schema.metadata().get(LINKML_CLASS_METADATA_KEY),
+            Some(&"Country".to_string())
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_country_round_trip() -> anyhow::Result<()> {
+        let schema = country_schema();
+        let instances = vec![
+            country("CH", "Switzerland", 8_776_000, &["de", "fr", "it", "rm"]),
+            country("PT", "Portugal", 10_467_000, &["pt"]),
+            country("NL", "Netherlands", 17_877_000, &["nl"]),
+        ];
+
+        let dumper = ParquetDumper::with_options(ParquetOptions {
+            row_group_size: 2,
+            ..Default::default()
+        });
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("countries.parquet");
+        dumper
+            .dump_file(&instances, &path, &schema, &DumpOptions::default())
+            .await?;
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(std::fs::File::open(&path)?)?;
+        assert_eq!(builder.metadata().num_row_groups(), 2);
+        let batches = builder.build()?.collect::<Result<Vec<_>, _>>()?;
+
+        let mut ids = Vec::new();
+        let mut labels = Vec::new();
+        let mut populations = Vec::new();
+        for batch in &batches {
+            let id_column = batch
+                .column_by_name("id")
+                .ok_or_else(|| anyhow::anyhow!("missing id column"))?;
+            let label_column = batch
+                .column_by_name("label")
+                .ok_or_else(|| anyhow::anyhow!("missing label column"))?;
+            let population_column = batch
+                .column_by_name("population")
+                .ok_or_else(|| anyhow::anyhow!("missing population column"))?;
+            ids.extend(
+                id_column
+                    .as_string::<i32>()
+                    .iter()
+                    .flatten()
+                    .map(String::from),
+            );
+            labels.extend(
+                label_column
+                    .as_string::<i32>()
+                    .iter()
+                    .flatten()
+                    .map(String::from),
+            );
+            populations.extend(
+                population_column
+                    .as_primitive::<Int64Type>()
+                    .iter()
+                    .flatten(),
+            );
+        }
+        assert_eq!(ids, ["CH", "PT", "NL"]);
+        assert_eq!(labels, ["Switzerland", "Portugal", "Netherlands"]);
+        assert_eq!(populations, [8_776_000, 10_467_000, 17_877_000]);
+
+        let languages = batches[0]
+            .column_by_name("languages")
+            .ok_or_else(|| anyhow::anyhow!("missing languages column"))?
+            .as_list::<i32>()
+            .value(0);
+        let languages: Vec<&str> = languages.as_string::<i32>().iter().flatten().collect();
+        assert_eq!(languages, ["de", "fr", "it", "rm"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_mixed_classes_are_rejected() {
+        let schema = country_schema();
+        let mut other = country("XX", "Nowhere", 0, &[]);
+        other.class_name = "Region".to_string();
+        let instances = vec![country("CH", "Switzerland", 8_776_000, &["de"]), other];
+
+        let result = ParquetDumper::new()
+            .dump_bytes(&instances, &schema, &DumpOptions::default())
+            .await;
+        assert!(matches!(result, Err(DumperError::Configuration(_))));
+    }
+}
@@ -2,6 +2,10 @@
 //!
 //! This module provides functionality to load RDF data (Turtle, N-Triples, RDF/XML)
 //! into `LinkML` data instances and dump instances back to RDF format.
+//!
+//! Slots map to predicates through their `slot_uri`, expanded with the schema
+//! prefixes, and classes to `rdf:type` objects through their `class_uri`.
+//! Elements without one live in the default namespace under their own name.
 
 use async_trait::async_trait;
 use linkml_core::prelude::*;
@@ -13,6 +17,7 @@ use std::collections::HashMap;
 use std::io::Cursor;
 use std::path::Path;
 
+use super::inlining::{ObjectSlot, find_slot, identifier_slot};
 use super::traits::{
     DataDumper, DataInstance, DataLoader, DumpOptions, DumperError, DumperResult, LoadOptions,
     LoaderError, LoaderResult,
@@ -78,6 +83,29 @@ impl Default for SkolemnizationOptions {
     }
 }
 
+/// How dumped instances are named
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IriStrategy {
+    /// Every instance is a blank node
+    BlankNode,
+    /// IRIs built from the instance id
+    FromId {
+        /// Namespace relative ids resolve against; the default namespace when unset
+        base: Option<String>,
+    },
+    /// IRIs built from the value of the class's identifier slot
+    ///
+    /// CURIE values expand with the schema prefixes; plain values resolve
+    /// against the class URI, giving IRIs like `{class_uri}/{value}`.
+    FromIdentifierSlot,
+}
+
+impl Default for IriStrategy {
+    fn default() -> Self {
+        Self::FromId { base: None }
+    }
+}
+
 /// Options specific to RDF loading/dumping
 #[derive(Debug, Clone)]
 pub struct RdfOptions {
@@ -96,6 +124,9 @@ pub struct RdfOptions {
     /// Whether to generate blank node identifiers
     pub generate_blank_nodes: bool,
 
+    /// How dumped instances are named
+    pub iri_strategy: IriStrategy,
+
     /// Skolemnization settings for blank nodes
     pub skolemnization: SkolemnizationOptions,
 
@@ -132,6 +163,7 @@ impl Default for RdfOptions {
             default_namespace: "http://example.org/".to_string(),
             prefixes,
             generate_blank_nodes: false,
+            iri_strategy: IriStrategy::default(),
             skolemnization: SkolemnizationOptions::None,
             type_predicate: "http://www.w3.org/1999/02/22-rdf-syntax-ns#type".to_string(),
             infer_from_rdf_type: true,
@@ -213,6 +245,7 @@ impl RdfLoader {
     ) -> LoaderResult<Vec<DataInstance>> {
         let mut instances = Vec::new();
         let mut instance_map: HashMap<String, DataInstance> = HashMap::new();
+        let slot_predicates = self.slot_predicates(schema);
 
         // Find all subjects that have a type
         let type_predicate = NamedNode::new(&self.options.type_predicate)
@@ -258,43 +291,15 @@ impl RdfLoader {
                 continue;
             };
 
-            // Create instance
-            let mut data = HashMap::new();
-
-            // Get all properties for this subject
-            for quad_result in store.quads_for_pattern(Some((&subject).into()), None, None, None) {
-                let quad = quad_result
-                    .map_err(|e| LoaderError::Parse(format!("Failed to read quad: {e}")))?;
-
-                // Skip type predicates
-                if quad.predicate == type_predicate {
-                    continue;
-                }
-
-                let property = self.predicate_to_property(&quad.predicate);
-                let value = Self::term_to_json(&quad.object)?;
-
-                // Handle multivalued properties
-                if let Some(existing) = data.get_mut(&property) {
-                    match existing {
-                        JsonValue::Array(arr) => arr.push(value),
-                        other => {
-                            let old_value = other.clone();
-                            *other = JsonValue::Array(vec![old_value, value]);
-                        }
-                    }
-                } else {
-                    data.insert(property, value);
-                }
-            }
-
-            let instance = DataInstance {
+            let instance = self.subject_to_instance(
+                store,
+                &subject,
+                &subject_str,
                 class_name,
-                data,
-                id: Some(subject_str.clone()),
-                metadata: HashMap::new(),
-            };
-
+                &type_predicate,
+                &slot_predicates,
+                schema,
+            )?;
             instance_map.insert(subject_str, instance);
         }
 
@@ -324,30 +329,17 @@ impl RdfLoader {
                     NamedOrBlankNode::BlankNode(node) => NamedOrBlankNode::BlankNode(node.clone()),
                 };
                 if let Some(class_name) =
-                    self.infer_class_from_properties(&subject_node, store, schema)
+                    self.infer_class_from_properties(&subject_node, store, schema, &slot_predicates)
                 {
-                    let mut data = HashMap::new();
-
-                    // Get all properties
-                    for prop_quad_result in
-                        store.quads_for_pattern(Some((&quad.subject).into()), None, None, None)
-                    {
-                        let prop_quad = prop_quad_result
-                            .map_err(|e| LoaderError::Parse(format!("Failed to read quad: {e}")))?;
-
-                        let property = self.predicate_to_property(&prop_quad.predicate);
-                        let value = Self::term_to_json(&prop_quad.object)?;
-
-                        data.insert(property, value);
-                    }
-
-                    let instance = DataInstance {
+                    let instance = self.subject_to_instance(
+                        store,
+                        &subject_node,
+                        &subject_str,
                         class_name,
-                        data,
-                        id: Some(subject_str.clone()),
-                        metadata: HashMap::new(),
-                    };
-
+                        &type_predicate,
+                        &slot_predicates,
+                        schema,
+                    )?;
                     instance_map.insert(subject_str, instance);
                 }
             }
@@ -363,6 +355,88 @@ impl RdfLoader {
         Ok(instances)
     }
 
+    /// Map the predicate IRI of every slot in the schema to the slot name
+    fn slot_predicates(&self, schema: &SchemaDefinition) -> HashMap<String, String> {
+        schema_slots(schema)
+            .map(|(name, slot)| {
+                let iri = element_iri(
+                    name,
+                    slot.slot_uri.as_deref(),
+                    schema,
+                    &self.options.default_namespace,
+                );
+                (iri, name.clone())
+            })
+            .collect()
+    }
+
+    /// Build the instance described by the triples of `subject`
+    ///
+    /// Repeated predicates become arrays, as does the single value of a
+    /// multivalued slot. The identifier slot's value, when present, is used as
+    /// the instance id in place of the subject IRI.
+    #[allow(clippy::too_many_arguments)]
+    fn subject_to_instance(
+        &self,
+        store: &Store,
+        subject: &NamedOrBlankNode,
+        subject_str: &str,
+        class_name: String,
+        type_predicate: &NamedNode,
+        slot_predicates: &HashMap<String, String>,
+        schema: &SchemaDefinition,
+    ) -> LoaderResult<DataInstance> {
+        let mut data: HashMap<String, JsonValue> = HashMap::new();
+
+        for quad_result in store.quads_for_pattern(Some(subject.into()), None, None, None) {
+            let quad =
+                quad_result.map_err(|e| LoaderError::Parse(format!("Failed to read quad: {e}")))?;
+
+            // Skip type predicates
+            if quad.predicate == *type_predicate {
+                continue;
+            }
+
+            let property = self.predicate_to_property(&quad.predicate, slot_predicates);
+            let value = Self::term_to_json(&quad.object)?;
+
+            // Handle multivalued properties
+            if let Some(existing) = data.get_mut(&property) {
+                match existing {
+                    JsonValue::Array(arr) => arr.push(value),
+                    other => {
+                        let old_value = other.clone();
+                        *other = JsonValue::Array(vec![old_value, value]);
+                    }
+                }
+            } else {
+                data.insert(property, value);
+            }
+        }
+
+        let mut id = Some(subject_str.to_string());
+        for (property, value) in &mut data {
+            let Some(slot) = find_slot(schema, &class_name, property) else {
+                continue;
+            };
+            if slot.multivalued == Some(true) && !value.is_array() {
+                *value = JsonValue::Array(vec![value.take()]);
+            }
+            if slot.identifier == Some(true)
+                && let JsonValue::String(identifier) = value
+            {
+                id = Some(identifier.clone());
+            }
+        }
+
+        Ok(DataInstance {
+            class_name,
+            data,
+            id,
+            metadata: HashMap::new(),
+        })
+    }
+
     /// Convert subject to string, applying skolemnization if configured
     fn subject_to_string(&self, subject: &NamedOrBlankNode) -> String {
         match subject {
@@ -429,9 +503,18 @@ impl RdfLoader {
     }
 
     /// Convert predicate to property name
-    fn predicate_to_property(&self, predicate: &NamedNode) -> String {
+    fn predicate_to_property(
+        &self,
+        predicate: &NamedNode,
+        slot_predicates: &HashMap<String, String>,
+    ) -> String {
         let uri = predicate.as_str();
 
+        // Predicates of schema slots map to the slot name
+        if let Some(slot_name) = slot_predicates.get(uri) {
+            return slot_name.clone();
+        }
+
         // Try to use prefixed name
         for (prefix, namespace) in &self.options.prefixes {
             if uri.starts_with(namespace) {
@@ -449,7 +532,7 @@ impl RdfLoader {
     }
 
     /// Convert RDF term to `JSON` value
-    fn term_to_json(term: &Term) -> LoaderResult<JsonValue> {
+    pub(super) fn term_to_json(term: &Term) -> LoaderResult<JsonValue> {
         match term {
             Term::NamedNode(n) => Ok(JsonValue::String(n.as_str().to_string())),
             Term::BlankNode(b) => Ok(JsonValue::String(format!("_:{}", b.as_str()))),
@@ -519,6 +602,18 @@ impl RdfLoader {
     ) -> LoaderResult<String> {
         // Try to find a matching class
         for rdf_type in types {
+            // Prefer a class whose class_uri names this type
+            let by_uri = schema.classes.iter().find(|(_, class_def)| {
+                class_def
+                    .class_uri
+                    .as_deref()
+                    .and_then(|uri| schema.expand_curie(uri))
+                    .is_some_and(|iri| iri == *rdf_type)
+            });
+            if let Some((class_name, _)) = by_uri {
+                return Ok(class_name.clone());
+            }
+
             // Extract local name
             let local_name = if let Some(pos) = rdf_type.rfind(['#', '/']) {
                 &rdf_type[pos + 1..]
@@ -543,12 +638,13 @@ impl RdfLoader {
         subject: &NamedOrBlankNode,
         store: &Store,
         schema: &SchemaDefinition,
+        slot_predicates: &HashMap<String, String>,
     ) -> Option<String> {
         // Get all properties
         let property_list: Vec<String> = store
             .quads_for_pattern(Some(subject.into()), None, None, None)
             .filter_map(std::result::Result::ok)
-            .map(|quad| self.predicate_to_property(&quad.predicate))
+            .map(|quad| self.predicate_to_property(&quad.predicate, slot_predicates))
             .collect();
 
         // Find best matching class
@@ -633,6 +729,83 @@ impl RdfLoader {
     }
 }
 
+/// IRI of a schema element: its URI expanded with the schema prefixes, else
+/// its name in the default namespace
+fn element_iri(
+    name: &str,
+    uri: Option<&str>,
+    schema: &SchemaDefinition,
+    default_namespace: &str,
+) -> String {
+    uri.and_then(|uri| schema.expand_curie(uri))
+        .unwrap_or_else(|| format!("{default_namespace}{name}"))
+}
+
+/// Top-level slots of the schema followed by the attributes of its classes
+fn schema_slots(schema: &SchemaDefinition) -> impl Iterator<Item = (&String, &SlotDefinition)> {
+    schema.slots.iter().chain(
+        schema
+            .classes
+            .values()
+            .flat_map(|class_def| class_def.attributes.iter()),
+    )
+}
+
+/// XSD namespace
+const XSD: &str = "http://www.w3.org/2001/XMLSchema#";
+const XSD_BOOLEAN: &str = "http://www.w3.org/2001/XMLSchema#boolean";
+const XSD_INTEGER: &str = "http://www.w3.org/2001/XMLSchema#integer";
+const XSD_DECIMAL: &str = "http://www.w3.org/2001/XMLSchema#decimal";
+
+/// XSD datatype IRI of a slot range, or `None` for strings and non-literal ranges
+///
+/// Schema types are followed through `typeof` until one declares a `uri` or
+/// a built-in type is reached.
+fn range_datatype(range: &str, schema: &SchemaDefinition) -> Option<String> {
+    for name in schema.type_chain(range) {
+        let Some(type_def) = schema.types.get(name) else {
+            let local = match name {
+                "integer" | "int" | "long" | "short" | "byte" => "integer",
+                "decimal" => "decimal",
+                "float" => "float",
+                "double" => "double",
+                "boolean" | "bool" => "boolean",
+                "date" => "date",
+                "datetime" => "dateTime",
+                "time" => "time",
+                _ => return None,
+            };
+            return Some(format!("{XSD}{local}"));
+        };
+        if let Some(uri) = &type_def.uri {
+            let iri = match uri.strip_prefix("xsd:") {
+                Some(local) => format!("{XSD}{local}"),
+                None => schema.expand_curie(uri)?,
+            };
+            return (iri != format!("{XSD}string")).then_some(iri);
+        }
+    }
+    None
+}
+
+/// Insert a triple into the default graph
+fn insert_triple(
+    store: &Store,
+    subject: &NamedOrBlankNode,
+    predicate: &NamedNode,
+    object: Term,
+) -> DumperResult<()> {
+    store
+        .insert(&Quad::new(
+            subject.clone(),
+            predicate.clone(),
+            object,
+            GraphName::DefaultGraph,
+        ))
+        .map(|_| ())
+        .map_err(|e| DumperError::Io(std::io::Error::other(format!("Failed to insert quad: {e}"))))
+}
+
 /// Check if a type name represents a valid RDF datatype
 fn is_valid_rdf_datatype(type_name: &str) -> bool {
     matches!(
@@ -922,7 +1095,7 @@ impl RdfDumper {
     }
 
     /// Create RDF store from instances
-    fn create_store(
+    pub(super) fn create_store(
         &self,
         instances: &[DataInstance],
         schema: &SchemaDefinition,
@@ -937,28 +1110,20 @@ impl RdfDumper {
             .map_err(|e| DumperError::Configuration(format!("Invalid type predicate: {e}")))?;
 
         for instance in instances {
+            let id = match &self.options.iri_strategy {
+                IriStrategy::BlankNode => None,
+                IriStrategy::FromId { .. } => instance.id.as_deref(),
+                IriStrategy::FromIdentifierSlot => identifier_slot(schema, &instance.class_name)
+                    .and_then(|slot| instance.data.get(slot)?.as_str())
+                    .or(instance.id.as_deref()),
+            };
+
             // Create subject
-            let subject = if let Some(id) = &instance.id {
-                if let Some(stripped) = id.strip_prefix("_:") {
-                    // Blank node
-                    NamedOrBlankNode::BlankNode(BlankNode::new(stripped).map_err(|e| {
-                        DumperError::Serialization(format!("Invalid blank node ID: {e}"))
-                    })?)
-                } else if id.starts_with("http://") || id.starts_with("https://") {
-                    // Already a full URI
-                    NamedOrBlankNode::NamedNode(
-                        NamedNode::new(id)
-                            .map_err(|e| DumperError::Serialization(format!("Invalid URI: {e}")))?,
-                    )
-                } else {
-                    // Create URI with default namespace
-                    let uri = format!("{}{}", self.options.default_namespace, id);
-                    NamedOrBlankNode::NamedNode(
-                        NamedNode::new(&uri)
-                            .map_err(|e| DumperError::Serialization(format!("Invalid URI: {e}")))?,
-                    )
-                }
-            } else if self.options.generate_blank_nodes {
+            let subject = if let Some(id) = id {
+                self.subject_node(id, &instance.class_name, schema)?
+            } else if self.options.generate_blank_nodes
+                || self.options.iri_strategy == IriStrategy::BlankNode
+            {
                 NamedOrBlankNode::BlankNode(BlankNode::default())
             } else {
                 return Err(DumperError::Serialization(
@@ -966,73 +1131,167 @@ impl RdfDumper {
                 ));
             };
 
-            // Add type triple
-            let class_uri = format!("{}{}", self.options.default_namespace, instance.class_name);
-            let class_node = NamedNode::new(&class_uri)
-                .map_err(|e| DumperError::Serialization(format!("Invalid class URI: {e}")))?;
+            self.insert_object(
+                &store,
+                &subject,
+                &instance.class_name,
+                &instance.data,
+                &type_predicate,
+                schema,
+            )?;
+        }
 
-            let type_quad = Quad {
-                subject: subject.clone(),
-                predicate: type_predicate.clone(),
-                object: Term::NamedNode(class_node),
-                graph_name: GraphName::DefaultGraph,
-            };
+        Ok(store)
+    }
 
-            store.insert(&type_quad).map_err(|e| {
-                DumperError::Io(std::io::Error::other(format!(
-                    "Failed to insert type quad: {e}"
-                )))
-            })?;
+    /// Node named by an instance identifier, following the IRI strategy
+    fn subject_node(
+        &self,
+        id: &str,
+        class_name: &str,
+        schema: &SchemaDefinition,
+    ) -> DumperResult<NamedOrBlankNode> {
+        if let Some(stripped) = id.strip_prefix("_:") {
+            // Blank node
+            return Ok(NamedOrBlankNode::BlankNode(
+                BlankNode::new(stripped).map_err(|e| {
+                    DumperError::Serialization(format!("Invalid blank node ID: {e}"))
+                })?,
+            ));
+        }
 
-            // Add property triples
-            for (property, value) in &instance.data {
-                if value.is_null() {
-                    continue;
+        let uri = if id.starts_with("http://") || id.starts_with("https://") {
+            // Already a full URI
+            id.to_string()
+        } else {
+            match &self.options.iri_strategy {
+                IriStrategy::FromId { base: Some(base) } => format!("{base}{id}"),
+                IriStrategy::FromIdentifierSlot => id
+                    .contains(':')
+                    .then(|| schema.expand_curie(id))
+                    .flatten()
+                    .unwrap_or_else(|| {
+                        let class_iri = self.class_iri(class_name, schema);
+                        format!("{}/{id}", class_iri.trim_end_matches(['/', '#']))
+                    }),
+                // Create URI with default namespace
+                IriStrategy::FromId { base: None } | IriStrategy::BlankNode => {
+                    format!("{}{}", self.options.default_namespace, id)
                 }
+            }
+        };
+        NamedNode::new(&uri)
+            .map(NamedOrBlankNode::NamedNode)
+            .map_err(|e| DumperError::Serialization(format!("Invalid URI: {e}")))
+    }
 
-                let predicate = self.property_to_predicate(property, schema)?;
-
-                if let JsonValue::Array(arr) = value {
-                    for item in arr {
-                        let object = self.json_to_term(item, property, schema)?;
-                        let quad = Quad {
-                            subject: subject.clone(),
-                            predicate: predicate.clone(),
-                            object,
-                            graph_name: GraphName::DefaultGraph,
-                        };
-                        store.insert(&quad).map_err(|e| {
-                            DumperError::Io(std::io::Error::other(format!(
-                                "Failed to insert quad: {e}"
-                            )))
-                        })?;
-                    }
-                } else {
-                    let object = self.json_to_term(value, property, schema)?;
-                    let quad = Quad {
-                        subject: subject.clone(),
-                        predicate: predicate.clone(),
-                        object,
-                        graph_name: GraphName::DefaultGraph,
-                    };
-                    store.insert(&quad).map_err(|e| {
-                        DumperError::Io(std::io::Error::other(format!(
-                            "Failed to insert quad: {e}"
-                        )))
-                    })?;
+    /// IRI of a class: its `class_uri`, else its name in the default namespace
+    fn class_iri(&self, class_name: &str, schema: &SchemaDefinition) -> String {
+        element_iri(
+            class_name,
+            schema
+                .classes
+                .get(class_name)
+                .and_then(|class_def| class_def.class_uri.as_deref()),
+            schema,
+            &self.options.default_namespace,
+        )
+    }
+
+    /// Add the type and property triples describing an object
+    ///
+    /// Values of inlined object-valued slots become blank nodes described in
+    /// place; those of referenced ones become the IRI of the target instance.
+    fn insert_object<'a>(
+        &self,
+        store: &Store,
+        subject: &NamedOrBlankNode,
+        class_name: &str,
+        data: impl IntoIterator<Item = (&'a String, &'a JsonValue)>,
+        type_predicate: &NamedNode,
+        schema: &SchemaDefinition,
+    ) -> DumperResult<()> {
+        // Add type triple
+        let class_uri = self.class_iri(class_name, schema);
+        let class_node = NamedNode::new(&class_uri)
+            .map_err(|e| DumperError::Serialization(format!("Invalid class URI: {e}")))?;
+        insert_triple(store, subject, type_predicate, Term::NamedNode(class_node))?;
+
+        // Add property triples
+        for (property, value) in data {
+            if value.is_null() {
+                continue;
+            }
+
+            let predicate = self.property_to_predicate(property, class_name, schema)?;
+            let slot = find_slot(schema, class_name, property);
+            let object_slot = slot.and_then(|slot| ObjectSlot::of(schema, slot));
+
+            let items: Vec<&JsonValue> = match value {
+                JsonValue::Array(arr) => arr.iter().collect(),
+                // Multivalued objects may be keyed by their identifier
+                JsonValue::Object(keyed)
+                    if object_slot.is_some()
+                        && slot.is_some_and(|slot| slot.multivalued == Some(true)) =>
+                {
+                    keyed.values().collect()
                 }
+                other => vec![other],
+            };
+
+            for item in items {
+                let object = match (object_slot, item) {
+                    (Some(ObjectSlot::Inlined { range, .. }), JsonValue::Object(nested)) => {
+                        let node = NamedOrBlankNode::BlankNode(BlankNode::default());
+                        self.insert_object(store, &node, range, nested, type_predicate, schema)?;
+                        Term::from(node)
+                    }
+                    (
+                        Some(ObjectSlot::Referenced { range, identifier }),
+                        JsonValue::Object(target),
+                    ) => {
+                        let id = target
+                            .get(identifier)
+                            .and_then(JsonValue::as_str)
+                            .ok_or_else(|| {
+                                DumperError::Serialization(format!(
+                                    "Object referenced by '{property}' has no '{identifier}'"
+                                ))
+                            })?;
+                        Term::from(self.subject_node(id, range, schema)?)
+                    }
+                    // Identifiers name the target instance whether or not the slot is inlined
+                    (Some(object_slot), JsonValue::String(id)) => {
+                        Term::from(self.subject_node(id, object_slot.range(), schema)?)
+                    }
+                    _ => Self::json_to_term(item, slot, schema)?,
+                };
+                insert_triple(store, subject, &predicate, object)?;
             }
         }
 
-        Ok(store)
+        Ok(())
     }
 
     /// Convert property name to predicate
     fn property_to_predicate(
         &self,
         property: &str,
-        _schema: &SchemaDefinition,
+        class_name: &str,
+        schema: &SchemaDefinition,
     ) -> DumperResult<NamedNode> {
+        // Schema slots use their slot_uri
+        if let Some(slot) = find_slot(schema, class_name, property) {
+            let uri = element_iri(
+                property,
+                slot.slot_uri.as_deref(),
+                schema,
+                &self.options.default_namespace,
+            );
+            return NamedNode::new(&uri)
+                .map_err(|e| DumperError::Serialization(format!("Invalid predicate URI: {e}")));
+        }
+
         // Handle prefixed names
         if let Some(colon_pos) = property.find(':') {
             let prefix = &property[..colon_pos];
@@ -1053,52 +1312,45 @@ impl RdfDumper {
     }
 
     /// Convert `JSON` value to RDF term
+    ///
+    /// Literals are typed by the XSD datatype of the slot's range, so the
+    /// string "42" of an integer slot becomes `"42"^^xsd:integer`.
     fn json_to_term(
-        &self,
         value: &JsonValue,
-        property: &str,
+        slot: Option<&SlotDefinition>,
         schema: &SchemaDefinition,
     ) -> DumperResult<Term> {
+        let range = slot.and_then(|slot| slot.range.as_deref());
+        let datatype = range.and_then(|range| range_datatype(range, schema));
+        let typed_literal = |lexical: String, datatype: &str| {
+            NamedNode::new(datatype)
+                .map(|datatype| Term::Literal(Literal::new_typed_literal(lexical, datatype)))
+                .map_err(|e| DumperError::Serialization(format!("Invalid datatype IRI: {e}")))
+        };
+
         match value {
             JsonValue::Null => Err(DumperError::TypeConversion(
                 "Cannot convert null to RDF".to_string(),
             )),
 
             JsonValue::Bool(b) => {
-                let literal = Literal::new_typed_literal(
-                    b.to_string(),
-                    NamedNode::new("http://www.w3.org/2001/XMLSchema#boolean")
-                        .expect("hardcoded XSD boolean datatype URI is valid: {}"),
-                );
-                Ok(Term::Literal(literal))
+                typed_literal(b.to_string(), datatype.as_deref().unwrap_or(XSD_BOOLEAN))
             }
 
             JsonValue::Number(n) => {
-                if n.is_i64() || n.is_u64() {
-                    let literal = Literal::new_typed_literal(
-                        n.to_string(),
-                        NamedNode::new("http://www.w3.org/2001/XMLSchema#integer")
-                            .expect("hardcoded XSD integer datatype URI is valid: {}"),
-                    );
-                    Ok(Term::Literal(literal))
+                let default = if n.is_i64() || n.is_u64() {
+                    XSD_INTEGER
                 } else {
-                    let literal = Literal::new_typed_literal(
-                        n.to_string(),
-                        NamedNode::new("http://www.w3.org/2001/XMLSchema#decimal")
-                            .expect("hardcoded XSD decimal datatype URI is valid: {}"),
-                    );
-                    Ok(Term::Literal(literal))
-                }
+                    XSD_DECIMAL
+                };
+                typed_literal(n.to_string(), datatype.as_deref().unwrap_or(default))
             }
 
             JsonValue::String(s) => {
                 // Check if it's a URI reference
                 if s.starts_with("http://") || s.starts_with("https://") {
                     // Check if this property expects a URI
-                    if let Some(slot) = schema.slots.get(property)
-                        && (slot.range.as_deref() == Some("uri")
-                            || slot.range.as_deref() == Some("uriorcurie"))
-                    {
+                    if matches!(range, Some("uri" | "uriorcurie")) {
                         let node = NamedNode::new(s)
                             .map_err(|e| DumperError::Serialization(format!("Invalid URI: {e}")))?;
                         return Ok(Term::NamedNode(node));
@@ -1113,8 +1365,11 @@ impl RdfDumper {
                     return Ok(Term::BlankNode(blank));
                 }
 
-                // Otherwise create a string literal
-                Ok(Term::Literal(Literal::new_simple_literal(s)))
+                // Otherwise create a literal, typed if the range has a datatype
+                match datatype {
+                    Some(datatype) => typed_literal(s.clone(), datatype.as_str()),
+                    None => Ok(Term::Literal(Literal::new_simple_literal(s))),
+                }
             }
 
             JsonValue::Array(_) => Err(DumperError::TypeConversion(
@@ -1600,7 +1855,7 @@ ex:bob rdf:type ex:Person ;
         assert_eq!(alice.data.get("name"), Some(&json!("Alice")));
         assert_eq!(
             alice.data.get("knows"),
-            Some(&json!("http://example.org/bob"))
+            Some(&json!(["http://example.org/bob"]))
         );
 
         // Dump back to Turtle
@@ -1617,6 +1872,287 @@ ex:bob rdf:type ex:Person ;
         Ok(())
     }
 
+    fn country_schema() -> SchemaDefinition {
+        let mut schema = SchemaDefinition::new("countries");
+        schema.prefixes.insert(
+            "geo".to_string(),
+            PrefixDefinition::Simple("https://example.org/geo/".to_string()),
+        );
+        schema.prefixes.insert(
+            "rdfs".to_string(),
+            PrefixDefinition::Simple("http://www.w3.org/2000/01/rdf-schema#".to_string()),
+        );
+        schema.classes.insert(
+            "Country".to_string(),
+            ClassDefinition {
+                class_uri: Some("geo:Country".to_string()),
+                slots: vec![
+                    "id".to_string(),
+                    "label".to_string(),
+                    "languages".to_string(),
+                    "population".to_string(),
+                ],
+                ..ClassDefinition::new("Country")
+            },
+        );
+        for slot in [
+            SlotDefinition {
+                identifier: Some(true),
+                ..SlotDefinition::new("id")
+            },
+            SlotDefinition {
+                slot_uri: Some("rdfs:label".to_string()),
+                ..SlotDefinition::new("label")
+            },
+            SlotDefinition {
+                slot_uri: Some("geo:language".to_string()),
+                multivalued: Some(true),
+                ..SlotDefinition::new("languages")
+            },
+            SlotDefinition {
+                range: Some("integer".to_string()),
+                ..SlotDefinition::new("population")
+            },
+        ] {
+            schema.slots.insert(slot.name.clone(), slot);
+        }
+        schema
+    }
+
+    fn country(id: &str, label: &str, languages: &[&str], population: i64) -> DataInstance {
+        DataInstance {
+            class_name: "Country".to_string(),
+            data: HashMap::from([
+                ("id".to_string(), json!(id)),
+                ("label".to_string(), json!(label)),
+                ("languages".to_string(), json!(languages)),
+                ("population".to_string(), json!(population)),
+            ]),
+            id: Some(id.to_string()),
+            metadata: HashMap::new(),
+        }
+    }
+
+    /// Sort instances by id and multivalued slots by value, neither of which
+    /// RDF preserves
+    fn normalized(mut instances: Vec<DataInstance>) -> Vec<DataInstance> {
+        instances.sort_by(|a, b| a.id.cmp(&b.id));
+        for instance in &mut instances {
+            if let Some(JsonValue::Array(values)) = instance.data.get_mut("languages") {
+                values.sort_by_key(ToString::to_string);
+            }
+        }
+        instances
+    }
+
+    #[tokio::test]
+    async fn test_country_round_trip() -> anyhow::Result<()> {
+        let schema = country_schema();
+        let countries = vec![
+            country("NL", "Netherlands", &["nld", "fry"], 17_900_000),
+            country("BE", "Belgium", &["nld", "fra", "deu"], 11_800_000),
+            country("PT", "Portugal", &["por"], 10_400_000),
+        ];
+
+        let turtle = RdfDumper::new()
+            .dump_string(&countries, &schema, &DumpOptions::default())
+            .await?;
+        assert!(
+            turtle.contains("<https://example.org/geo/Country>"),
+            "{turtle}"
+        );
+        assert!(
+            turtle.contains("<http://www.w3.org/2000/01/rdf-schema#label>"),
+            "{turtle}"
+        );
+        assert!(
+            turtle.contains("<https://example.org/geo/language>"),
+            "{turtle}"
+        );
+
+        let loaded = RdfLoader::new()
+            .load_string(&turtle, &schema, &LoadOptions::default())
+            .await?;
+        assert_eq!(normalized(loaded), normalized(countries));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_iri_strategy_names_countries() -> anyhow::Result<()> {
+        let schema = country_schema();
+        let countries = vec![
+            country("US", "United States", &["eng"], 335_000_000),
+            country("NL", "Netherlands", &["nld", "fry"], 17_900_000),
+        ];
+        let dump = |iri_strategy| {
+            let dumper = RdfDumper::with_options(RdfOptions {
+                format: RdfSerializationFormat::NTriples,
+                iri_strategy,
+                ..Default::default()
+            });
+            let countries = &countries;
+            let schema = &schema;
+            async move {
+                dumper
+                    .dump_string(countries, schema, &DumpOptions::default())
+                    .await
+            }
+        };
+
+        let base = "https://textpast.org/instance/place/polity/country/iso_3166_entity/";
+        let from_id = IriStrategy::FromId {
+            base: Some(base.to_string()),
+        };
+        let ntriples = dump(from_id.clone()).await?;
+        assert!(ntriples.contains(&format!("<{base}US> ")), "{ntriples}");
+        assert!(ntriples.contains(&format!("<{base}NL> ")), "{ntriples}");
+        assert!(!ntriples.contains("_:"), "{ntriples}");
+
+        // The same instances are always given the same IRIs
+        let mut first: Vec<_> = ntriples.lines().collect();
+        let again = dump(from_id).await?;
+        let mut second: Vec<_> = again.lines().collect();
+        first.sort_unstable();
+        second.sort_unstable();
+        assert_eq!(first, second);
+
+        let ntriples = dump(IriStrategy::FromIdentifierSlot).await?;
+        assert!(
+            ntriples.contains("<https://example.org/geo/Country/US> "),
+            "{ntriples}"
+        );
+
+        let ntriples = dump(IriStrategy::BlankNode).await?;
+        assert!(ntriples.starts_with("_:"), "{ntriples}");
+        assert!(!ntriples.contains("example.org/US"), "{ntriples}");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_literals_typed_by_slot_range() -> anyhow::Result<()> {
+        let mut schema = create_test_schema();
+        schema.types.insert(
+            "year".to_string(),
+            TypeDefinition {
+                base_type: Some("integer".to_string()),
+                ..Default::default()
+            },
+        );
+        for (name, range) in [("age", "integer"), ("founded", "year"), ("born", "date")] {
+            schema.slots.insert(
+                name.to_string(),
+                SlotDefinition {
+                    range: Some(range.to_string()),
+                    ..SlotDefinition::new(name)
+                },
+            );
+        }
+
+        let alice = DataInstance {
+            class_name: "Person".to_string(),
+            data: HashMap::from([
+                ("name".to_string(), json!("Alice")),
+                ("age".to_string(), json!("42")),
+                ("founded".to_string(), json!(1999)),
+                ("born".to_string(), json!("1983-04-01")),
+            ]),
+            id: Some("alice".to_string()),
+            metadata: HashMap::new(),
+        };
+        let ntriples = RdfDumper::with_format(RdfSerializationFormat::NTriples)
+            .dump_string(&[alice], &schema, &DumpOptions::default())
+            .await?;
+
+        for literal in [
+            r#""42"^^<http://www.w3.org/2001/XMLSchema#integer>"#,
+            r#""1999"^^<http://www.w3.org/2001/XMLSchema#integer>"#,
+            r#""1983-04-01"^^<http://www.w3.org/2001/XMLSchema#date>"#,
+            r#"<http://example.org/name> "Alice" ."#,
+        ] {
+            assert!(
+                ntriples.contains(literal),
+                "{literal} missing from {ntriples}"
+            );
+        }
+        Ok(())
+    }
+
+    /// Countries whose `capital` slot has the class range `City`
+    fn capital_schema(inlined: bool) -> SchemaDefinition {
+        let mut schema = create_test_schema();
+        schema.classes.insert(
+            "Country".to_string(),
+            ClassDefinition {
+                slots: vec!["id".to_string(), "capital".to_string()],
+                ..ClassDefinition::new("Country")
+            },
+        );
+        schema.classes.insert(
+            "City".to_string(),
+            ClassDefinition {
+                slots: vec!["id".to_string(), "name".to_string()],
+                ..ClassDefinition::new("City")
+            },
+        );
+        schema.slots.insert(
+            "capital".to_string(),
+            SlotDefinition {
+                range: Some("City".to_string()),
+                inlined: Some(inlined),
+                ..SlotDefinition::new("capital")
+            },
+        );
+        schema
+    }
+
+    #[tokio::test]
+    async fn test_dumper_inlines_or_references_objects() -> anyhow::Result<()> {
+        let countries = vec![DataInstance {
+            class_name: "Country".to_string(),
+            data: HashMap::from([
+                ("id".to_string(), json!("NL")),
+                (
+                    "capital".to_string(),
+                    json!({"id": "AMS", "name": "Amsterdam"}),
+                ),
+            ]),
+            id: Some("NL".to_string()),
+            metadata: HashMap::new(),
+        }];
+        let dumper = RdfDumper::with_format(RdfSerializationFormat::NTriples);
+        let capital_of = |ntriples: &str| {
+            ntriples
+                .lines()
+                .find(|line| line.contains("<http://example.org/capital>"))
+                .and_then(|line| line.split(' ').nth(2))
+                .map(str::to_string)
+        };
+
+        // Inlined capitals are blank nodes described in place
+        let ntriples = dumper
+            .dump_string(&countries, &capital_schema(true), &DumpOptions::default())
+            .await?;
+        let capital = capital_of(&ntriples).unwrap_or_default();
+        assert!(capital.starts_with("_:"), "{ntriples}");
+        assert!(
+            ntriples.contains(&format!(
+                "{capital} <http://example.org/name> \"Amsterdam\" ."
+            )),
+            "{ntriples}"
+        );
+
+        // Referenced capitals are the IRI of the city
+        let ntriples = dumper
+            .dump_string(&countries, &capital_schema(false), &DumpOptions::default())
+            .await?;
+        assert_eq!(
+            capital_of(&ntriples).as_deref(),
+            Some("<http://example.org/AMS>")
+        );
+        assert!(!ntriples.contains("Amsterdam"), "{ntriples}");
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_ntriples_format() -> anyhow::Result<()> {
         let schema = create_test_schema();
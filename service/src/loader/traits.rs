@@ -136,7 +136,7 @@ impl From<DumperError> for linkml_core::LinkMLError {
 }
 
 /// Represents a loaded data instance
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct DataInstance {
     /// Name of the LinkML class this instance represents
     pub class_name: String,
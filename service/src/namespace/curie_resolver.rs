@@ -163,7 +163,7 @@ impl CurieResolver {
             if let Some(uri_base) = self.prefixes.get(prefix) {
                 return Ok(format!("{uri_base}{local}"));
             } else if self.strict {
-                return Err(LinkMLError::service(format!("Unknown prefix: {prefix}")));
+                return Err(LinkMLError::invalid_prefix(prefix));
             }
             // In non-strict mode, return as-is
             return Ok(curie.to_string());
@@ -424,6 +424,13 @@ mod tests {
                 .expect("Should expand with default prefix"),
             "http://example.org/Person"
         );
+
+        // Test unknown prefix in strict mode
+        resolver.set_strict(true);
+        assert!(matches!(
+            resolver.expand_curie("foaf:Person"),
+            Err(LinkMLError::InvalidPrefix { ref prefix }) if prefix == "foaf"
+        ));
     }
 
     #[test]
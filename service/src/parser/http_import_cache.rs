@@ -0,0 +1,263 @@
+//! Cache for schemas imported over HTTP
+//!
+//! Responses are kept in memory and, when a cache directory is configured,
+//! on disk so they survive across resolvers and processes. Entries younger
+//! than the maximum age are served without touching the network; older ones
+//! are revalidated with a conditional GET using the stored `ETag` and
+//! `Last-Modified` headers.
+
+use dashmap::DashMap;
+use linkml_core::error::{LinkMLError, Result};
+use reqwest::StatusCode;
+use reqwest::header::{ETAG, HeaderName, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::fs;
+
+/// Maximum age of a cached import used when none is configured
+pub const DEFAULT_MAX_AGE: Duration = Duration::from_secs(60 * 60);
+
+/// Cached response for an imported URL
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedImport {
+    body: String,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    /// Seconds since the Unix epoch at which the response was last validated
+    validated_at: u64,
+}
+
+/// Cache of HTTP-fetched imports keyed on their URL
+#[derive(Debug)]
+pub struct HttpImportCache {
+    /// Directory persisting entries across processes; memory only when unset
+    cache_dir: Option<PathBuf>,
+    /// Age after which an entry is revalidated with the server
+    max_age: Duration,
+    /// In-memory entries
+    entries: DashMap<String, CachedImport>,
+}
+
+impl HttpImportCache {
+    /// Create an in-memory cache with the default maximum age of one hour
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            cache_dir: None,
+            max_age: DEFAULT_MAX_AGE,
+            entries: DashMap::new(),
+        }
+    }
+
+    /// Persist cached imports in `cache_dir`
+    #[must_use]
+    pub fn with_cache_dir(mut self, cache_dir: impl Into<PathBuf>) -> Self {
+        self.cache_dir = Some(cache_dir.into());
+        self
+    }
+
+    /// Set the age after which cached imports are revalidated
+    #[must_use]
+    pub fn with_max_age(mut self, max_age: Duration) -> Self {
+        self.max_age = max_age;
+        self
+    }
+
+    /// Fetch the body of `url`, using the cache where possible
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the server responds with
+    /// anything other than success or `304 Not Modified`.
+    pub async fn fetch(&self, client: &reqwest::Client, url: &str) -> Result<String> {
+        let in_memory = self.entries.get(url).map(|entry| entry.clone());
+        let cached = match in_memory {
+            Some(entry) => Some(entry),
+            None => self.read_from_disk(url).await,
+        };
+
+        let now = unix_now();
+        if let Some(cached) = &cached
+            && now.saturating_sub(cached.validated_at) < self.max_age.as_secs()
+        {
+            return Ok(cached.body.clone());
+        }
+
+        let mut request = client.get(url);
+        if let Some(cached) = &cached {
+            if let Some(etag) = &cached.etag {
+                request = request.header(IF_NONE_MATCH, etag);
+            }
+            if let Some(last_modified) = &cached.last_modified {
+                request = request.header(IF_MODIFIED_SINCE, last_modified);
+            }
+        }
+        let response = request
+            .send()
+            .await
+            .map_err(|e| LinkMLError::import(url, format!("Failed to fetch URL: {e}")))?;
+
+        let entry = match (response.status(), cached) {
+            (StatusCode::NOT_MODIFIED, Some(cached)) => CachedImport {
+                validated_at: now,
+                ..cached
+            },
+            (status, _) if status.is_success() => {
+                let header = |name: HeaderName| {
+                    response
+                        .headers()
+                        .get(name)
+                        .and_then(|value| value.to_str().ok())
+                        .map(str::to_string)
+                };
+                let etag = header(ETAG);
+                let last_modified = header(LAST_MODIFIED);
+                let body = response.text().await.map_err(|e| {
+                    LinkMLError::import(url, format!("Failed to read response: {e}"))
+                })?;
+                CachedImport {
+                    body,
+                    etag,
+                    last_modified,
+                    validated_at: now,
+                }
+            }
+            (status, _) => {
+                return Err(LinkMLError::import(url, format!("HTTP error: {status}")));
+            }
+        };
+
+        self.write_to_disk(url, &entry).await;
+        let body = entry.body.clone();
+        self.entries.insert(url.to_string(), entry);
+        Ok(body)
+    }
+
+    /// Drop all cached imports, including those on disk
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the cache directory cannot be removed
+    pub async fn clear(&self) -> Result<()> {
+        self.entries.clear();
+        if let Some(dir) = &self.cache_dir
+            && dir.exists()
+        {
+            fs::remove_dir_all(dir)
+                .await
+                .map_err(|e| LinkMLError::service(format!("Failed to clear import cache: {e}")))?;
+        }
+        Ok(())
+    }
+
+    /// File holding the cached response for `url`
+    fn entry_path(&self, url: &str) -> Option<PathBuf> {
+        let key = blake3::hash(url.as_bytes()).to_hex();
+        self.cache_dir
+            .as_ref()
+            .map(|dir| dir.join(format!("{key}.json")))
+    }
+
+    async fn read_from_disk(&self, url: &str) -> Option<CachedImport> {
+        let content = fs::read_to_string(self.entry_path(url)?).await.ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    /// Persist an entry; failures only cost a later re-download
+    async fn write_to_disk(&self, url: &str, entry: &CachedImport) {
+        let Some(path) = self.entry_path(url) else {
+            return;
+        };
+        let Ok(content) = serde_json::to_string(entry) else {
+            return;
+        };
+        if let Some(dir) = path.parent()
+            && fs::create_dir_all(dir).await.is_ok()
+            && let Err(e) = fs::write(&path, content).await
+        {
+            tracing::debug!("Failed to persist cached import {url}: {e}");
+        }
+    }
+}
+
+impl Default for HttpImportCache {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tempfile::TempDir;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    const SCHEMA: &str = "id: https://example.org/remote\nname: remote\n";
+
+    /// Serve `SCHEMA` with an `ETag`, answering matching conditional GETs
+    /// with `304 Not Modified`, and count requests by status
+    async fn serve_schema() -> anyhow::Result<(String, Arc<AtomicUsize>, Arc<AtomicUsize>)> {
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let url = format!("http://{}/remote.yaml", listener.local_addr()?);
+        let full = Arc::new(AtomicUsize::new(0));
+        let not_modified = Arc::new(AtomicUsize::new(0));
+        let (full_count, not_modified_count) = (Arc::clone(&full), Arc::clone(&not_modified));
+
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let mut buffer = [0; 4096];
+                let Ok(read) = stream.read(&mut buffer).await else {
+                    continue;
+                };
+                let request = String::from_utf8_lossy(&buffer[..read]).to_lowercase();
+                let response = if request.contains("if-none-match: \"v1\"") {
+                    not_modified_count.fetch_add(1, Ordering::SeqCst);
+                    "HTTP/1.1 304 Not Modified\r\netag: \"v1\"\r\ncontent-length: 0\r\n\r\n"
+                        .to_string()
+                } else {
+                    full_count.fetch_add(1, Ordering::SeqCst);
+                    format!(
+                        "HTTP/1.1 200 OK\r\netag: \"v1\"\r\ncontent-length: {}\r\n\r\n{SCHEMA}",
+                        SCHEMA.len()
+                    )
+                };
+                let _ = stream.write_all(response.as_bytes()).await;
+            }
+        });
+
+        Ok((url, full, not_modified))
+    }
+
+    #[tokio::test]
+    async fn test_revalidates_with_conditional_get() -> anyhow::Result<()> {
+        let (url, full, not_modified) = serve_schema().await?;
+        let cache_dir = TempDir::new()?;
+        let client = reqwest::Client::new();
+
+        let cache = HttpImportCache::new().with_cache_dir(cache_dir.path());
+        assert_eq!(cache.fetch(&client, &url).await?, SCHEMA);
+        // Fresh entries are served without a request
+        assert_eq!(cache.fetch(&client, &url).await?, SCHEMA);
+        assert_eq!(full.load(Ordering::SeqCst), 1);
+        assert_eq!(not_modified.load(Ordering::SeqCst), 0);
+
+        // A new cache over the same directory revalidates the stale entry
+        let stale = HttpImportCache::new()
+            .with_cache_dir(cache_dir.path())
+            .with_max_age(Duration::ZERO);
+        assert_eq!(stale.fetch(&client, &url).await?, SCHEMA);
+        assert_eq!(full.load(Ordering::SeqCst), 1);
+        assert_eq!(not_modified.load(Ordering::SeqCst), 1);
+        Ok(())
+    }
+}
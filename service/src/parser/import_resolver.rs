@@ -1,5 +1,6 @@
 //! Import resolution for `LinkML` schemas
 
+use indexmap::IndexMap;
 use linkml_core::{
     error::{LinkMLError, Result},
     types::SchemaDefinition,
@@ -8,9 +9,12 @@ use parking_lot::RwLock;
 use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Instant;
 
+use super::import_resolver_v2::DEFAULT_MAX_IMPORT_DEPTH;
 use super::{AsyncSchemaParser, JsonParserV2, YamlParserV2};
 use crate::file_system_adapter::FileSystemOperations;
+use crate::utils::safe_cast::u128_to_u64_saturating;
 use parse_core::ParseService;
 
 /// Import resolver for handling schema imports
@@ -51,7 +55,7 @@ impl<P: ParseService, F: FileSystemOperations> ImportResolver<P, F> {
             search_paths: Arc::new(RwLock::new(vec![PathBuf::from(".")])),
             base_path: Arc::new(RwLock::new(None)),
             base_url: Arc::new(RwLock::new(None)),
-            max_depth: 10,
+            max_depth: DEFAULT_MAX_IMPORT_DEPTH,
             yaml_parser,
             json_parser,
         }
@@ -78,12 +82,19 @@ impl<P: ParseService, F: FileSystemOperations> ImportResolver<P, F> {
             search_paths: Arc::new(RwLock::new(search_paths)),
             base_path: Arc::new(RwLock::new(None)),
             base_url: Arc::new(RwLock::new(None)),
-            max_depth: 10,
+            max_depth: DEFAULT_MAX_IMPORT_DEPTH,
             yaml_parser,
             json_parser,
         }
     }
 
+    /// Set the maximum import depth
+    #[must_use]
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
     /// Set the base path for relative imports
     pub fn set_base_path(&self, path: &Path) {
         *self.base_path.write() = Some(path.to_path_buf());
@@ -111,19 +122,39 @@ impl<P: ParseService, F: FileSystemOperations> ImportResolver<P, F> {
 
     /// Resolve imports asynchronously
     ///
+    /// Runs in a `linkml.resolve_imports` span that records the number of
+    /// imports resolved and the elapsed time.
+    ///
     /// # Errors
     ///
     /// Returns an error if import resolution fails.
+    #[tracing::instrument(
+        name = "linkml.resolve_imports",
+        skip_all,
+        fields(
+            schema = %schema.name,
+            imports = schema.imports.len(),
+            resolved = tracing::field::Empty,
+            elapsed_ms = tracing::field::Empty,
+        )
+    )]
     pub async fn resolve_imports_async(
         &self,
         schema: &SchemaDefinition,
     ) -> Result<SchemaDefinition> {
+        let start = Instant::now();
         let mut merged = schema.clone();
         let mut visited = HashSet::new();
 
         self.resolve_imports_recursive(&mut merged, &mut visited, 0)
             .await?;
 
+        let span = tracing::Span::current();
+        span.record("resolved", visited.len());
+        span.record(
+            "elapsed_ms",
+            u128_to_u64_saturating(start.elapsed().as_millis()),
+        );
         Ok(merged)
     }
 
@@ -161,11 +192,24 @@ impl<P: ParseService, F: FileSystemOperations> ImportResolver<P, F> {
     }
 
     /// Load an imported schema
+    #[tracing::instrument(
+        name = "linkml.load_import",
+        skip(self),
+        fields(cached = false, elapsed_ms = tracing::field::Empty)
+    )]
     async fn load_import(&self, import: &str) -> Result<SchemaDefinition> {
+        let start = Instant::now();
+
         // Check cache first
         {
             let cache = self.cache.read();
             if let Some(schema) = cache.get(import) {
+                let span = tracing::Span::current();
+                span.record("cached", true);
+                span.record(
+                    "elapsed_ms",
+                    u128_to_u64_saturating(start.elapsed().as_millis()),
+                );
                 return Ok(schema.clone());
             }
         }
@@ -182,6 +226,10 @@ impl<P: ParseService, F: FileSystemOperations> ImportResolver<P, F> {
             cache.insert(import.to_string(), schema.clone());
         }
 
+        tracing::Span::current().record(
+            "elapsed_ms",
+            u128_to_u64_saturating(start.elapsed().as_millis()),
+        );
         Ok(schema)
     }
 
@@ -246,50 +294,47 @@ impl<P: ParseService, F: FileSystemOperations> ImportResolver<P, F> {
             }
         }
 
-        // Merge classes
-        for (name, class) in &source.classes {
-            if target.classes.contains_key(name) {
-                return Err(LinkMLError::import(
-                    &target.name,
-                    format!("Class '{name}' already defined"),
-                ));
-            }
-            target.classes.insert(name.clone(), class.clone());
-        }
+        Self::merge_definitions("Class", &mut target.classes, &source.classes)?;
+        Self::merge_definitions("Slot", &mut target.slots, &source.slots)?;
+        Self::merge_definitions("Type", &mut target.types, &source.types)?;
+        Self::merge_definitions("Enum", &mut target.enums, &source.enums)?;
 
-        // Merge slots
-        for (name, slot) in &source.slots {
-            if target.slots.contains_key(name) {
-                return Err(LinkMLError::import(
-                    &target.name,
-                    format!("Slot '{name}' already defined"),
-                ));
+        // Custom settings (e.g. named patterns) are inherited unless overridden
+        if let Some(source_settings) = &source.settings
+            && !source_settings.custom.is_empty()
+        {
+            let target_custom = &mut target.settings.get_or_insert_with(Default::default).custom;
+            for (key, value) in &source_settings.custom {
+                target_custom
+                    .entry(key.clone())
+                    .or_insert_with(|| value.clone());
             }
-            target.slots.insert(name.clone(), slot.clone());
         }
 
-        // Merge types
-        for (name, type_def) in &source.types {
-            if target.types.contains_key(name) {
-                return Err(LinkMLError::import(
-                    &target.name,
-                    format!("Type '{name}' already defined"),
-                ));
-            }
-            target.types.insert(name.clone(), type_def.clone());
-        }
+        Ok(())
+    }
 
-        // Merge enums
-        for (name, enum_def) in &source.enums {
-            if target.enums.contains_key(name) {
-                return Err(LinkMLError::import(
-                    &target.name,
-                    format!("Enum '{name}' already defined"),
-                ));
+    /// Merge one kind of named definition into the target schema
+    ///
+    /// Redefinitions that are structurally equal to the existing definition
+    /// are ignored, so a shared base schema can be imported through several
+    /// paths. Only conflicting redefinitions are reported as errors.
+    fn merge_definitions<T: Clone + PartialEq>(
+        kind: &str,
+        target: &mut IndexMap<String, T>,
+        source: &IndexMap<String, T>,
+    ) -> Result<()> {
+        for (name, definition) in source {
+            match target.get(name) {
+                Some(existing) if existing == definition => {}
+                Some(_) => {
+                    return Err(LinkMLError::duplicate_definition(kind, name));
+                }
+                None => {
+                    target.insert(name.clone(), definition.clone());
+                }
             }
-            target.enums.insert(name.clone(), enum_def.clone());
         }
-
         Ok(())
     }
 }
@@ -298,6 +343,7 @@ impl<P: ParseService, F: FileSystemOperations> ImportResolver<P, F> {
 mod tests {
     use super::*;
     use crate::file_system_adapter::TokioFileSystemAdapter;
+    use linkml_core::types::{ClassDefinition, EnumDefinition, SlotDefinition, TypeDefinition};
     use std::fs;
     use tempfile::TempDir;
 
@@ -355,4 +401,113 @@ classes:
         assert!(merged.classes.contains_key("MainClass"));
         Ok(())
     }
+
+    type TestResolver =
+        ImportResolver<parse_core::service::ParseServiceImpl, TokioFileSystemAdapter>;
+
+    fn schema_with_definitions(name: &str, description: &str) -> SchemaDefinition {
+        let mut schema = SchemaDefinition::new(name);
+        schema.classes.insert(
+            "Shared".to_string(),
+            ClassDefinition {
+                name: "Shared".to_string(),
+                description: Some(description.to_string()),
+                ..Default::default()
+            },
+        );
+        schema.slots.insert(
+            "shared_slot".to_string(),
+            SlotDefinition {
+                name: "shared_slot".to_string(),
+                description: Some(description.to_string()),
+                ..Default::default()
+            },
+        );
+        schema.types.insert(
+            "SharedType".to_string(),
+            TypeDefinition {
+                name: "SharedType".to_string(),
+                description: Some(description.to_string()),
+                ..Default::default()
+            },
+        );
+        schema.enums.insert(
+            "SharedEnum".to_string(),
+            EnumDefinition {
+                name: "SharedEnum".to_string(),
+                description: Some(description.to_string()),
+                ..Default::default()
+            },
+        );
+        schema
+    }
+
+    #[test]
+    fn test_merge_identical_definitions_is_noop() -> std::result::Result<(), anyhow::Error> {
+        let mut target = schema_with_definitions("main", "shared base");
+        let source = schema_with_definitions("base", "shared base");
+
+        TestResolver::merge_schema(&mut target, &source)?;
+
+        assert_eq!(target.classes.len(), 1);
+        assert_eq!(target.slots.len(), 1);
+        assert_eq!(target.types.len(), 1);
+        assert_eq!(target.enums.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_conflicting_definitions_errors() {
+        let conflicting = schema_with_definitions("base", "conflicting");
+
+        // Each kind of definition must be checked independently
+        let cases = [
+            (
+                "Class",
+                "Shared",
+                SchemaDefinition {
+                    classes: conflicting.classes.clone(),
+                    ..SchemaDefinition::new("base")
+                },
+            ),
+            (
+                "Slot",
+                "shared_slot",
+                SchemaDefinition {
+                    slots: conflicting.slots.clone(),
+                    ..SchemaDefinition::new("base")
+                },
+            ),
+            (
+                "Type",
+                "SharedType",
+                SchemaDefinition {
+                    types: conflicting.types.clone(),
+                    ..SchemaDefinition::new("base")
+                },
+            ),
+            (
+                "Enum",
+                "SharedEnum",
+                SchemaDefinition {
+                    enums: conflicting.enums.clone(),
+                    ..SchemaDefinition::new("base")
+                },
+            ),
+        ];
+
+        for (expected_kind, expected_name, source) in cases {
+            let mut target = schema_with_definitions("main", "original");
+            let err = TestResolver::merge_schema(&mut target, &source)
+                .expect_err("conflicting redefinition should fail");
+            assert!(
+                matches!(
+                    err,
+                    LinkMLError::DuplicateDefinition { ref kind, ref name }
+                        if kind == expected_kind && name == expected_name
+                ),
+                "unexpected error: {err}"
+            );
+        }
+    }
 }
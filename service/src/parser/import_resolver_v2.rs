@@ -19,9 +19,18 @@ use std::future::Future;
 use std::path::PathBuf;
 use std::pin::Pin;
 use std::sync::Arc;
+use std::time::Instant;
 use tokio::fs;
 
 use crate::parser::SchemaParser;
+use crate::parser::http_import_cache::HttpImportCache;
+use crate::parser::import_uri_resolver::{
+    ImportKind, ImportLocation, ImportUriResolver, TxpUriResolver,
+};
+use crate::utils::safe_cast::u128_to_u64_saturating;
+
+/// Import depth limit used when neither the caller nor the schema sets one
+pub const DEFAULT_MAX_IMPORT_DEPTH: usize = 10;
 
 /// Import specification with advanced options
 #[derive(Debug, Clone)]
@@ -62,6 +71,12 @@ pub struct ImportResolverV2 {
     fallback_client: reqwest::Client,
     /// Visited imports for circular dependency detection
     visited_stack: Arc<RwLock<Vec<String>>>,
+    /// Caller-supplied import depth limit, takes precedence over schema settings
+    max_import_depth: Arc<RwLock<Option<usize>>>,
+    /// Resolvers for prefixed imports, keyed by prefix
+    uri_resolvers: Arc<RwLock<HashMap<String, Arc<dyn ImportUriResolver>>>>,
+    /// Cache for URL imports fetched with the fallback client
+    http_cache: Arc<RwLock<Option<Arc<HttpImportCache>>>>,
 }
 
 impl ImportResolverV2 {
@@ -74,6 +89,9 @@ impl ImportResolverV2 {
             http_client: None,
             fallback_client: reqwest::Client::new(),
             visited_stack: Arc::new(RwLock::new(Vec::new())),
+            max_import_depth: Arc::new(RwLock::new(None)),
+            uri_resolvers: Self::default_uri_resolvers(),
+            http_cache: Arc::new(RwLock::new(None)),
         }
     }
 
@@ -86,6 +104,9 @@ impl ImportResolverV2 {
             http_client: None,
             fallback_client: reqwest::Client::new(),
             visited_stack: Arc::new(RwLock::new(Vec::new())),
+            max_import_depth: Arc::new(RwLock::new(None)),
+            uri_resolvers: Self::default_uri_resolvers(),
+            http_cache: Arc::new(RwLock::new(None)),
         }
     }
 
@@ -98,6 +119,9 @@ impl ImportResolverV2 {
             http_client: Some(http_client),
             fallback_client: reqwest::Client::new(),
             visited_stack: Arc::new(RwLock::new(Vec::new())),
+            max_import_depth: Arc::new(RwLock::new(None)),
+            uri_resolvers: Self::default_uri_resolvers(),
+            http_cache: Arc::new(RwLock::new(None)),
         }
     }
 
@@ -113,21 +137,104 @@ impl ImportResolverV2 {
             http_client: Some(http_client),
             fallback_client: reqwest::Client::new(),
             visited_stack: Arc::new(RwLock::new(Vec::new())),
+            max_import_depth: Arc::new(RwLock::new(None)),
+            uri_resolvers: Self::default_uri_resolvers(),
+            http_cache: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    /// Resolver for a single resolution, sharing this one's schema cache,
+    /// HTTP clients and URI resolvers
+    ///
+    /// Settings, depth limit and URL cache start as copies of this
+    /// resolver's, so configuring the returned resolver for one call does
+    /// not change what other users of this resolver see.
+    #[must_use]
+    pub fn scoped(&self) -> Self {
+        Self {
+            cache: Arc::clone(&self.cache),
+            settings: Arc::new(RwLock::new(self.settings.read().clone())),
+            http_client: self.http_client.clone(),
+            fallback_client: self.fallback_client.clone(),
+            visited_stack: Arc::new(RwLock::new(Vec::new())),
+            max_import_depth: Arc::new(RwLock::new(*self.max_import_depth.read())),
+            uri_resolvers: Arc::clone(&self.uri_resolvers),
+            http_cache: Arc::new(RwLock::new(self.http_cache.read().clone())),
         }
     }
 
+    /// Resolvers registered by default: `txp:` maps to the local schema tree
+    fn default_uri_resolvers() -> Arc<RwLock<HashMap<String, Arc<dyn ImportUriResolver>>>> {
+        let mut resolvers: HashMap<String, Arc<dyn ImportUriResolver>> = HashMap::new();
+        resolvers.insert("txp".to_string(), Arc::new(TxpUriResolver::default()));
+        Arc::new(RwLock::new(resolvers))
+    }
+
+    /// Register the resolver for imports with the given prefix
+    ///
+    /// Replaces any resolver registered for the prefix, including the default
+    /// `txp:` resolver.
+    pub fn register_uri_resolver(
+        &self,
+        prefix: impl Into<String>,
+        resolver: Arc<dyn ImportUriResolver>,
+    ) {
+        self.uri_resolvers.write().insert(prefix.into(), resolver);
+    }
+
+    /// Cache URL imports fetched without an external HTTP client
+    ///
+    /// The cache may be shared between resolvers so repeated resolutions do
+    /// not download the same imports again.
+    pub fn set_http_cache(&self, cache: Arc<HttpImportCache>) {
+        *self.http_cache.write() = Some(cache);
+    }
+
     /// Update import settings
     pub fn set_settings(&self, settings: ImportSettings) {
         *self.settings.write() = settings;
     }
 
+    /// Set the maximum import depth
+    ///
+    /// Overrides both the default limit of 10 and any `max_import_depth`
+    /// declared in the settings of the schemas being resolved.
+    pub fn set_max_import_depth(&self, max_depth: usize) {
+        *self.max_import_depth.write() = Some(max_depth);
+    }
+
+    /// Get the effective maximum import depth
+    #[must_use]
+    pub fn max_import_depth(&self) -> usize {
+        self.max_import_depth
+            .read()
+            .or(self.settings.read().max_import_depth)
+            .unwrap_or(DEFAULT_MAX_IMPORT_DEPTH)
+    }
+
     /// Resolve all imports in a schema.
     ///
+    /// Slot owners are derived from the merged classes afterwards, see
+    /// [`SchemaDefinition::populate_owners`]. Runs in a
+    /// `linkml.resolve_imports` span that records the number of schemas
+    /// merged and the elapsed time.
+    ///
     /// # Errors
     ///
     /// Returns an error when an import cannot be downloaded, parsed, or merged
     /// into the target schema using the configured settings.
+    #[tracing::instrument(
+        name = "linkml.resolve_imports",
+        skip_all,
+        fields(
+            schema = %schema.name,
+            imports = schema.imports.len(),
+            resolved = tracing::field::Empty,
+            elapsed_ms = tracing::field::Empty,
+        )
+    )]
     pub async fn resolve_imports(&self, schema: &SchemaDefinition) -> Result<SchemaDefinition> {
+        let start = Instant::now();
         let mut resolved = schema.clone();
 
         // Apply settings from schema if available, merging with existing settings
@@ -171,32 +278,36 @@ impl ImportResolverV2 {
         }
 
         // Check if imports should be followed
-        let (should_follow, max_depth) = {
-            let settings = self.settings.read();
-            (
-                settings.should_follow_imports(),
-                settings.max_import_depth.unwrap_or(10),
-            )
-        };
-
-        if !should_follow {
-            return Ok(resolved);
-        }
+        let should_follow = self.settings.read().should_follow_imports();
+        let max_depth = self.max_import_depth();
 
         // Resolve imports recursively
-        self.resolve_imports_recursive(&mut resolved, 0, max_depth)
-            .await?;
+        let merged = if should_follow {
+            self.resolve_imports_recursive(&mut resolved, 0, max_depth)
+                .await?
+        } else {
+            0
+        };
 
+        // Slot owners depend on the classes of the merged schema
+        resolved.populate_owners();
+
+        let span = tracing::Span::current();
+        span.record("resolved", merged);
+        span.record(
+            "elapsed_ms",
+            u128_to_u64_saturating(start.elapsed().as_millis()),
+        );
         Ok(resolved)
     }
 
-    /// Resolve imports recursively
+    /// Resolve imports recursively, returning the number of schemas merged
     fn resolve_imports_recursive<'a>(
         &'a self,
         schema: &'a mut SchemaDefinition,
         depth: usize,
         max_depth: usize,
-    ) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
+    ) -> Pin<Box<dyn Future<Output = Result<usize>> + Send + 'a>> {
         Box::pin(async move {
             if depth >= max_depth {
                 return Err(LinkMLError::import(
@@ -205,15 +316,27 @@ impl ImportResolverV2 {
                 ));
             }
 
-            // Convert imports to ImportSpecs
-            let import_specs: Vec<ImportSpec> = schema
-                .imports
+            // Convert imports to ImportSpecs; slot-scoped imports are loaded too
+            let mut imports = schema.imports.clone();
+            for import in Self::scoped_imports(schema) {
+                if !imports.contains(&import) {
+                    imports.push(import);
+                }
+            }
+            let import_specs: Vec<ImportSpec> = imports
                 .iter()
                 .map(|import| Self::parse_import_spec(import))
                 .collect();
+            let mut merged_names = HashMap::new();
+            let mut merged = 0;
 
             // Process each import
             for spec in import_specs {
+                // Instance files are loaded by the instance resolver, not merged
+                if self.import_kind(&spec)? == ImportKind::Instance {
+                    continue;
+                }
+
                 // Check for circular dependencies
                 {
                     let stack = self.visited_stack.read();
@@ -236,17 +359,21 @@ impl ImportResolverV2 {
                 let mut imported = self.load_import(&spec).await?;
 
                 // Recursively resolve imports in the imported schema
-                self.resolve_imports_recursive(&mut imported, depth + 1, max_depth)
-                    .await?;
+                merged += self
+                    .resolve_imports_recursive(&mut imported, depth + 1, max_depth)
+                    .await?
+                    + 1;
 
                 // Merge into current schema
-                Self::merge_schema(schema, imported, &spec);
+                let names = Self::merge_schema(schema, imported, &spec);
+                merged_names.insert(spec.path.clone(), names);
 
                 // Remove from visited stack
                 self.visited_stack.write().pop();
             }
 
-            Ok(())
+            Self::apply_scoped_imports(schema, &merged_names);
+            Ok(merged)
         })
     }
 
@@ -257,30 +384,69 @@ impl ImportResolverV2 {
         ImportSpec::from(import.to_string())
     }
 
+    /// Path an import resolves to after applying aliases
+    fn aliased_path(&self, spec: &ImportSpec) -> String {
+        self.settings
+            .read()
+            .aliases
+            .get(&spec.path)
+            .cloned()
+            .unwrap_or_else(|| spec.path.clone())
+    }
+
+    /// Resolver registered for the prefix of an import, if any
+    fn uri_resolver_for(&self, import_path: &str) -> Option<Arc<dyn ImportUriResolver>> {
+        import_path
+            .split_once(':')
+            .and_then(|(prefix, _)| self.uri_resolvers.read().get(prefix).cloned())
+    }
+
+    /// Classify an import through the resolver registered for its prefix
+    ///
+    /// Imports without a registered prefix are always schemas.
+    fn import_kind(&self, spec: &ImportSpec) -> Result<ImportKind> {
+        let import_path = self.aliased_path(spec);
+        self.uri_resolver_for(&import_path)
+            .map_or(Ok(ImportKind::Schema), |resolver| {
+                resolver.kind(&import_path)
+            })
+    }
+
     /// Load an imported schema
+    #[tracing::instrument(
+        name = "linkml.load_import",
+        skip_all,
+        fields(
+            import = %spec.path,
+            cached = false,
+            elapsed_ms = tracing::field::Empty,
+        )
+    )]
     async fn load_import(&self, spec: &ImportSpec) -> Result<SchemaDefinition> {
+        let start = Instant::now();
+
         // Check aliases
-        let import_path = {
-            let settings = self.settings.read();
-            settings
-                .aliases
-                .get(&spec.path)
-                .cloned()
-                .unwrap_or_else(|| spec.path.clone())
-        };
+        let import_path = self.aliased_path(spec);
 
         // Check cache
         {
             let cache = self.cache.read();
             if let Some(schema) = cache.get(&import_path) {
+                let span = tracing::Span::current();
+                span.record("cached", true);
+                span.record(
+                    "elapsed_ms",
+                    u128_to_u64_saturating(start.elapsed().as_millis()),
+                );
                 return Ok(schema.clone());
             }
         }
 
-        // Load schema based on type (txp:, linkml:, URL, or file)
-        let schema = if import_path.starts_with("txp:") {
-            // TextPast/RootReal convention: local-first with remote fallback
-            self.load_txp_import(&import_path).await?
+        // Load schema based on type (registered prefix, linkml:, URL, or file)
+        let schema = if let Some(uri_resolver) = self.uri_resolver_for(&import_path) {
+            // e.g. the TextPast txp: namespace, local-first with remote fallback
+            self.load_resolved_import(uri_resolver.as_ref(), &import_path)
+                .await?
         } else if import_path.starts_with("linkml:") {
             // LinkML standard library imports
             self.load_linkml_import(&import_path).await?
@@ -298,59 +464,29 @@ impl ImportResolverV2 {
             cache.insert(import_path, schema.clone());
         }
 
+        tracing::Span::current().record(
+            "elapsed_ms",
+            u128_to_u64_saturating(start.elapsed().as_millis()),
+        );
         Ok(schema)
     }
 
-    /// Load schema using TextPast/RootReal txp: prefix convention
-    ///
-    /// Resolution strategy:
-    /// 1. Try local file in crates/model/symbolic/schemata/
-    /// 2. Fall back to https://textpast.org/ if not found
-    ///
-    /// Path mapping:
-    /// - Schema: txp:meta/entity/hyperentity/schema
-    ///   → Local: crates/model/symbolic/schemata/meta/entity/hyperentity/schema.yaml
-    ///   → Remote: https://textpast.org/schema/meta/entity/hyperentity
-    /// - Instance: txp:place/polity/country/iso_3166_entity
-    ///   → Local: crates/model/symbolic/schemata/place/polity/country/iso_3166_entity.yaml
-    ///   → Remote: https://textpast.org/instance/place/polity/country/iso_3166_entity
-    async fn load_txp_import(&self, txp_path: &str) -> Result<SchemaDefinition> {
-        // Remove txp: prefix
-        let path_without_prefix = txp_path.strip_prefix("txp:").unwrap_or(txp_path);
-
-        // Determine if this is a schema or instance import
-        let is_schema = path_without_prefix.ends_with("/schema");
-        let is_instance = path_without_prefix.ends_with("/instance");
-
-        // Try local file first
-        // Use absolute path or search in current directory and parent directories
-        let local_path = self.find_local_schema_file(path_without_prefix)?;
-
-        if let Some(local_path) = local_path {
-            // Load from local file
-            let content = fs::read_to_string(&local_path)
-                .await
-                .map_err(|e| LinkMLError::import(txp_path, format!("Failed to read local file: {e}")))?;
-
-            return self.parse_schema_content(&content, txp_path).await;
+    /// Load a prefixed import from the location its URI resolver maps it to
+    async fn load_resolved_import(
+        &self,
+        uri_resolver: &dyn ImportUriResolver,
+        import: &str,
+    ) -> Result<SchemaDefinition> {
+        match uri_resolver.resolve(import)? {
+            ImportLocation::Local(path) => {
+                let content = fs::read_to_string(&path).await.map_err(|e| {
+                    LinkMLError::import(import, format!("Failed to read local file: {e}"))
+                })?;
+                self.parse_schema_content(&content, &path.to_string_lossy())
+                    .await
+            }
+            ImportLocation::Remote(url) => self.load_url_import(&url).await,
         }
-
-        // Fall back to remote URL
-        let remote_url = if is_schema {
-            // Schema: remove /schema suffix from path for URL
-            let schema_path = path_without_prefix.strip_suffix("/schema").unwrap_or(path_without_prefix);
-            format!("https://textpast.org/schema/{}", schema_path)
-        } else if is_instance {
-            // Instance: remove /instance suffix from path for URL
-            let instance_path = path_without_prefix.strip_suffix("/instance").unwrap_or(path_without_prefix);
-            format!("https://textpast.org/instance/{}", instance_path)
-        } else {
-            // Unknown type - try as-is
-            format!("https://textpast.org/instance/{}", path_without_prefix)
-        };
-
-        // Fetch from remote
-        self.load_url_import(&remote_url).await
     }
 
     /// Load schema using LinkML standard library prefix convention
@@ -394,6 +530,7 @@ impl ImportResolverV2 {
         };
 
         // Use external API client if available, otherwise fall back to reqwest
+        let http_cache = self.http_cache.read().clone();
         let content = if let Some(ref http_client) = self.http_client {
             // Use production-ready HTTP client with rate limiting, caching, retries
             let response = http_client
@@ -409,6 +546,8 @@ impl ImportResolverV2 {
             }
 
             response.body
+        } else if let Some(http_cache) = http_cache {
+            http_cache.fetch(&self.fallback_client, &final_url).await?
         } else {
             // Fallback to direct reqwest client
             let response = self
@@ -481,67 +620,6 @@ impl ImportResolverV2 {
         }
     }
 
-    /// Find local schema file in the repository
-    ///
-    /// Walks up from the current directory to find the repository root (identified by
-    /// Cargo.toml with [workspace]), then looks for the schema file in
-    /// crates/model/symbolic/schemata/
-    ///
-    /// Handles both schema and instance imports:
-    /// - txp:path/to/module/schema → path/to/module/schema.yaml
-    /// - txp:path/to/module/instance → path/to/module.yaml (strips /instance suffix)
-    fn find_local_schema_file(&self, path_without_prefix: &str) -> Result<Option<PathBuf>> {
-        // Handle instance imports: strip /instance suffix for file path
-        let file_path = if path_without_prefix.ends_with("/instance") {
-            path_without_prefix.strip_suffix("/instance").unwrap_or(path_without_prefix)
-        } else {
-            path_without_prefix
-        };
-
-        let schema_file = format!("{}.yaml", file_path);
-
-        // Get current directory
-        let mut current_dir = std::env::current_dir()
-            .map_err(|e| LinkMLError::service(format!("Failed to get current directory: {e}")))?;
-
-        // Walk up to find repository root (directory with Cargo.toml containing [workspace])
-        loop {
-            let cargo_toml = current_dir.join("Cargo.toml");
-            if cargo_toml.exists() {
-                // Check if this is the workspace root
-                if let Ok(content) = std::fs::read_to_string(&cargo_toml) {
-                    if content.contains("[workspace]") {
-                        // Found workspace root
-                        break;
-                    }
-                }
-            }
-
-            // Try parent directory
-            match current_dir.parent() {
-                Some(parent) => current_dir = parent.to_path_buf(),
-                None => {
-                    // Reached filesystem root without finding workspace
-                    // Fall back to original current_dir
-                    current_dir = std::env::current_dir()
-                        .map_err(|e| LinkMLError::service(format!("Failed to get current directory: {e}")))?;
-                    break;
-                }
-            }
-        }
-
-        // Construct path: crates/model/symbolic/schemata/{path}.yaml
-        let schema_path = current_dir
-            .join("crates/model/symbolic/schemata")
-            .join(&schema_file);
-
-        if schema_path.exists() {
-            Ok(Some(schema_path))
-        } else {
-            Ok(None)
-        }
-    }
-
     /// Find file in given paths
     fn find_in_paths(import: &str, paths: &[PathBuf], extensions: &[&str]) -> Result<PathBuf> {
         for base_path in paths {
@@ -591,11 +669,16 @@ impl ImportResolverV2 {
     }
 
     /// Merge imported schema into target schema
+    ///
+    /// Returns the name each imported class, type and enum was merged under,
+    /// keyed by its name in the imported schema.
     fn merge_schema(
         target: &mut SchemaDefinition,
         mut source: SchemaDefinition,
         spec: &ImportSpec,
-    ) {
+    ) -> HashMap<String, String> {
+        let mut merged_names = HashMap::new();
+
         // Apply prefix if specified
         if let Some(prefix) = &spec.prefix {
             Self::apply_prefix(&mut source, prefix);
@@ -627,8 +710,10 @@ impl ImportResolverV2 {
             let qualified_name = Self::get_qualified_name(&name, spec, &source.name);
             if target.classes.contains_key(&name) {
                 // Conflict - use qualified name
+                merged_names.insert(name, qualified_name.clone());
                 target.classes.insert(qualified_name, class);
             } else {
+                merged_names.insert(name.clone(), name.clone());
                 target.classes.insert(name, class);
             }
         }
@@ -647,8 +732,10 @@ impl ImportResolverV2 {
         for (name, type_def) in source.types {
             let qualified_name = Self::get_qualified_name(&name, spec, &source.name);
             if target.types.contains_key(&name) {
+                merged_names.insert(name, qualified_name.clone());
                 target.types.insert(qualified_name, type_def);
             } else {
+                merged_names.insert(name.clone(), name.clone());
                 target.types.insert(name, type_def);
             }
         }
@@ -657,11 +744,76 @@ impl ImportResolverV2 {
         for (name, enum_def) in source.enums {
             let qualified_name = Self::get_qualified_name(&name, spec, &source.name);
             if target.enums.contains_key(&name) {
+                merged_names.insert(name, qualified_name.clone());
                 target.enums.insert(qualified_name, enum_def);
             } else {
+                merged_names.insert(name.clone(), name.clone());
                 target.enums.insert(name, enum_def);
             }
         }
+
+        // Custom settings (e.g. named patterns) are inherited unless overridden
+        if let Some(source_settings) = source.settings
+            && !source_settings.custom.is_empty()
+        {
+            let target_custom = &mut target.settings.get_or_insert_with(Default::default).custom;
+            for (key, value) in source_settings.custom {
+                target_custom.entry(key).or_insert(value);
+            }
+        }
+
+        merged_names
+    }
+
+    /// Imports scoped to individual slots, in declaration order
+    fn scoped_imports(schema: &SchemaDefinition) -> Vec<String> {
+        let class_slots = schema
+            .classes
+            .values()
+            .flat_map(|class| class.attributes.values().chain(class.slot_usage.values()));
+        let mut imports: Vec<String> = Vec::new();
+        for import in schema
+            .slots
+            .values()
+            .chain(class_slots)
+            .flat_map(|slot| &slot.imports)
+        {
+            if !imports.contains(import) {
+                imports.push(import.clone());
+            }
+        }
+        imports
+    }
+
+    /// Point slot ranges at the definitions from the slots' scoped imports
+    ///
+    /// A range defined by one of the slot's own imports wins over a
+    /// same-named element from any other import, which may have taken the
+    /// unqualified name. Ranges not found in the scoped imports are left to
+    /// the schema-level imports.
+    fn apply_scoped_imports(
+        schema: &mut SchemaDefinition,
+        merged_names: &HashMap<String, HashMap<String, String>>,
+    ) {
+        let class_slots = schema.classes.values_mut().flat_map(|class| {
+            class
+                .attributes
+                .values_mut()
+                .chain(class.slot_usage.values_mut())
+        });
+        for slot in schema.slots.values_mut().chain(class_slots) {
+            let Some(range) = &slot.range else {
+                continue;
+            };
+            let scoped_name = slot
+                .imports
+                .iter()
+                .filter_map(|import| merged_names.get(import))
+                .find_map(|names| names.get(range));
+            if let Some(name) = scoped_name {
+                slot.range = Some(name.clone());
+            }
+        }
     }
 
     /// Apply prefix to all elements in schema
@@ -911,4 +1063,211 @@ imports:
         assert!(err.to_string().contains("Circular import"));
         Ok(())
     }
+
+    /// Maps `fake:<name>` to `<name>.yaml` in a fixed directory
+    struct FakeUriResolver {
+        dir: PathBuf,
+    }
+
+    impl ImportUriResolver for FakeUriResolver {
+        fn resolve(&self, import: &str) -> Result<ImportLocation> {
+            let name = import.strip_prefix("fake:").unwrap_or(import);
+            Ok(ImportLocation::Local(self.dir.join(format!("{name}.yaml"))))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_registered_uri_resolver() -> std::result::Result<(), anyhow::Error> {
+        let temp_dir = TempDir::new()?;
+        tokio::fs::write(
+            temp_dir.path().join("core.yaml"),
+            r"
+id: https://example.org/core
+name: core
+classes:
+  CoreClass:
+    description: Provided by the fake resolver
+",
+        )
+        .await?;
+
+        let mut schema = SchemaDefinition::new("main");
+        schema.imports.push("fake:core".to_string());
+
+        let resolver = ImportResolverV2::new();
+        resolver.register_uri_resolver(
+            "fake",
+            Arc::new(FakeUriResolver {
+                dir: temp_dir.path().to_path_buf(),
+            }),
+        );
+        let resolved = resolver.resolve_imports(&schema).await?;
+
+        assert!(resolved.classes.contains_key("CoreClass"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_txp_import_suffixes_route_imports() -> std::result::Result<(), anyhow::Error> {
+        let temp_dir = TempDir::new()?;
+        let country_dir = temp_dir.path().join("place/polity/country");
+        tokio::fs::create_dir_all(&country_dir).await?;
+        tokio::fs::write(
+            country_dir.join("schema.yaml"),
+            r"
+id: https://textpast.org/schema/place/polity/country
+name: country
+classes:
+  ISO3166Entity:
+    description: Country from ISO 3166-1
+",
+        )
+        .await?;
+        tokio::fs::write(
+            country_dir.join("iso_3166_entity.yaml"),
+            r"
+id: https://textpast.org/instance/place/polity/country/iso_3166_entity
+schema: https://textpast.org/schema/place/polity/country
+instances:
+  - id: NL
+",
+        )
+        .await?;
+
+        let resolver = ImportResolverV2::new();
+        resolver.register_uri_resolver("txp", Arc::new(TxpUriResolver::new(temp_dir.path())));
+
+        // The instance import is left to the instance resolver
+        let mut schema = SchemaDefinition::new("main");
+        schema.imports = vec![
+            "txp:place/polity/country/schema".to_string(),
+            "txp:place/polity/country/iso_3166_entity/instance".to_string(),
+        ];
+        let resolved = resolver.resolve_imports(&schema).await?;
+        assert!(resolved.classes.contains_key("ISO3166Entity"));
+
+        // Without its suffix the instance file would be parsed as a schema
+        schema.imports = vec!["txp:place/polity/country/iso_3166_entity".to_string()];
+        let error = resolver
+            .resolve_imports(&schema)
+            .await
+            .expect_err("import without a suffix should be rejected");
+        assert!(
+            error.to_string().contains("must end with /schema"),
+            "{error}"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_slot_scoped_import_wins() -> std::result::Result<(), anyhow::Error> {
+        let temp_dir = TempDir::new()?;
+        tokio::fs::write(
+            temp_dir.path().join("unrelated.yaml"),
+            r"
+id: https://example.org/unrelated
+name: unrelated
+types:
+  CountryCodeAlpha2Identifier:
+    typeof: integer
+",
+        )
+        .await?;
+        tokio::fs::write(
+            temp_dir.path().join("identifier.yaml"),
+            r"
+id: https://example.org/identifier
+name: identifier
+types:
+  CountryCodeAlpha2Identifier:
+    typeof: string
+    pattern: '^[A-Z]{2}$'
+",
+        )
+        .await?;
+
+        let mut schema = SchemaDefinition::new("country");
+        schema.imports.push("unrelated".to_string());
+        let mut country = ClassDefinition::new("Country");
+        country.slot_usage.insert(
+            "identifier".to_string(),
+            SlotDefinition {
+                range: Some("CountryCodeAlpha2Identifier".to_string()),
+                imports: vec!["identifier".to_string()],
+                ..SlotDefinition::new("identifier")
+            },
+        );
+        schema.classes.insert("Country".to_string(), country);
+        schema.slots.insert(
+            "code".to_string(),
+            SlotDefinition {
+                range: Some("CountryCodeAlpha2Identifier".to_string()),
+                ..SlotDefinition::new("code")
+            },
+        );
+
+        let resolver = ImportResolverV2::with_settings(ImportSettings {
+            search_paths: vec![temp_dir.path().to_string_lossy().into_owned()],
+            ..Default::default()
+        });
+        let resolved = resolver.resolve_imports(&schema).await?;
+
+        let range = resolved.classes["Country"].slot_usage["identifier"]
+            .range
+            .as_deref()
+            .expect("identifier should keep a range");
+        assert_eq!(range, "identifier_CountryCodeAlpha2Identifier");
+        assert_eq!(resolved.types[range].base_type.as_deref(), Some("string"));
+        // Slots without scoped imports keep resolving through schema imports
+        assert_eq!(
+            resolved.slots["code"].range.as_deref(),
+            Some("CountryCodeAlpha2Identifier")
+        );
+        assert_eq!(
+            resolved.types["CountryCodeAlpha2Identifier"]
+                .base_type
+                .as_deref(),
+            Some("integer")
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_owners_cover_imported_classes() -> std::result::Result<(), anyhow::Error> {
+        let temp_dir = TempDir::new()?;
+        tokio::fs::write(
+            temp_dir.path().join("things.yaml"),
+            r"
+id: https://example.org/things
+name: things
+slots:
+  id:
+    identifier: true
+    range: string
+classes:
+  Thing:
+    slots: [id]
+",
+        )
+        .await?;
+
+        let mut schema = SchemaDefinition::new("people");
+        schema.imports.push("things".to_string());
+        let mut person = ClassDefinition::new("Person");
+        person.is_a = Some("Thing".to_string());
+        person.slots.push("id".to_string());
+        schema.classes.insert("Person".to_string(), person);
+
+        let resolver = ImportResolverV2::with_settings(ImportSettings {
+            search_paths: vec![temp_dir.path().to_string_lossy().into_owned()],
+            ..Default::default()
+        });
+        let resolved = resolver.resolve_imports(&schema).await?;
+
+        let id = &resolved.slots["id"];
+        assert_eq!(id.owner.as_deref(), Some("Thing"));
+        assert!(id.domain_of.iter().any(|class| class == "Person"));
+        assert!(id.domain_of.iter().any(|class| class == "Thing"));
+        Ok(())
+    }
 }
@@ -0,0 +1,201 @@
+//! Prefix-based resolution of import URIs
+//!
+//! Imports such as `txp:meta/entity/hyperentity/schema` name a logical
+//! namespace rather than a file. An [`ImportUriResolver`] maps such an import
+//! to a local file or a remote URL; [`ImportResolverV2`](super::ImportResolverV2)
+//! picks the resolver registered for the import's prefix.
+
+use linkml_core::error::{LinkMLError, Result};
+use std::path::PathBuf;
+
+/// Location an import URI resolves to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImportLocation {
+    /// Schema file on the local filesystem
+    Local(PathBuf),
+    /// Schema fetched over HTTP
+    Remote(String),
+}
+
+/// What an import refers to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportKind {
+    /// A schema, merged into the importing schema
+    Schema,
+    /// An instance file, loaded as permissible values for instance ranges
+    Instance,
+}
+
+impl ImportKind {
+    /// Classify an import by its `/schema` or `/instance` suffix
+    ///
+    /// # Errors
+    ///
+    /// Returns an import error if the import ends in neither suffix
+    pub fn from_suffix(import: &str) -> Result<Self> {
+        if import.ends_with("/schema") {
+            Ok(Self::Schema)
+        } else if import.ends_with("/instance") {
+            Ok(Self::Instance)
+        } else {
+            Err(LinkMLError::import(
+                import,
+                "Import must end with /schema for a schema or /instance for an instance file",
+            ))
+        }
+    }
+}
+
+/// Maps prefixed import URIs to the location of the imported schema
+pub trait ImportUriResolver: Send + Sync {
+    /// Resolve an import, including its prefix, to a location
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the import cannot be mapped to any location
+    fn resolve(&self, import: &str) -> Result<ImportLocation>;
+
+    /// Classify an import as a schema or an instance file
+    ///
+    /// Imports are schemas unless the resolver's namespace says otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the import does not follow the namespace's
+    /// naming convention
+    fn kind(&self, _import: &str) -> Result<ImportKind> {
+        Ok(ImportKind::Schema)
+    }
+}
+
+/// Resolver for the TextPast `txp:` namespace
+///
+/// Resolution is local-first: the import is looked up under the local schema
+/// root and only fetched from `https://textpast.org/` when no local file
+/// exists.
+///
+/// - `txp:meta/entity/hyperentity/schema`
+///   → `{root}/meta/entity/hyperentity/schema.yaml`,
+///   else `https://textpast.org/schema/meta/entity/hyperentity`
+/// - `txp:place/polity/country/iso_3166_entity/instance`
+///   → `{root}/place/polity/country/iso_3166_entity.yaml`,
+///   else `https://textpast.org/instance/place/polity/country/iso_3166_entity`
+#[derive(Debug, Clone, Default)]
+pub struct TxpUriResolver {
+    /// Local schema root; discovered from the workspace when unset
+    local_root: Option<PathBuf>,
+}
+
+impl TxpUriResolver {
+    /// Base URL of the remote `txp:` namespace
+    pub const REMOTE_BASE: &'static str = "https://textpast.org";
+
+    /// Create a resolver that looks for local files under `local_root`
+    pub fn new(local_root: impl Into<PathBuf>) -> Self {
+        Self {
+            local_root: Some(local_root.into()),
+        }
+    }
+
+    /// Local schema root, defaulting to `crates/model/symbolic/schemata` in
+    /// the enclosing Cargo workspace
+    fn local_root(&self) -> Result<PathBuf> {
+        if let Some(root) = &self.local_root {
+            return Ok(root.clone());
+        }
+
+        let current_dir = std::env::current_dir()
+            .map_err(|e| LinkMLError::service(format!("Failed to get current directory: {e}")))?;
+        let workspace_root = current_dir
+            .ancestors()
+            .find(|dir| {
+                std::fs::read_to_string(dir.join("Cargo.toml"))
+                    .is_ok_and(|content| content.contains("[workspace]"))
+            })
+            .unwrap_or(current_dir.as_path());
+        Ok(workspace_root.join("crates/model/symbolic/schemata"))
+    }
+}
+
+impl ImportUriResolver for TxpUriResolver {
+    fn resolve(&self, import: &str) -> Result<ImportLocation> {
+        let path = import.strip_prefix("txp:").unwrap_or(import);
+        let kind = self.kind(import)?;
+
+        // Instance imports name the instance file without its /instance suffix
+        let file_path = path.strip_suffix("/instance").unwrap_or(path);
+        let local_path = self.local_root()?.join(format!("{file_path}.yaml"));
+        if local_path.is_file() {
+            return Ok(ImportLocation::Local(local_path));
+        }
+
+        let remote_url = match kind {
+            ImportKind::Schema => {
+                let schema_path = path.strip_suffix("/schema").unwrap_or(path);
+                format!("{}/schema/{schema_path}", Self::REMOTE_BASE)
+            }
+            ImportKind::Instance => format!("{}/instance/{file_path}", Self::REMOTE_BASE),
+        };
+        Ok(ImportLocation::Remote(remote_url))
+    }
+
+    /// `txp:` imports must end with `/schema` or `/instance`
+    fn kind(&self, import: &str) -> Result<ImportKind> {
+        ImportKind::from_suffix(import)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_txp_resolves_local_first() -> anyhow::Result<()> {
+        let root = TempDir::new()?;
+        let resolver = TxpUriResolver::new(root.path());
+
+        assert_eq!(
+            resolver.resolve("txp:meta/entity/hyperentity/schema")?,
+            ImportLocation::Remote("https://textpast.org/schema/meta/entity/hyperentity".into())
+        );
+        assert_eq!(
+            resolver.resolve("txp:place/polity/country/iso_3166_entity/instance")?,
+            ImportLocation::Remote(
+                "https://textpast.org/instance/place/polity/country/iso_3166_entity".into()
+            )
+        );
+
+        let local = root.path().join("meta/entity/hyperentity/schema.yaml");
+        std::fs::create_dir_all(local.parent().expect("path has a parent"))?;
+        std::fs::write(
+            &local,
+            "id: https://textpast.org/schema/meta/entity/hyperentity\n",
+        )?;
+        assert_eq!(
+            resolver.resolve("txp:meta/entity/hyperentity/schema")?,
+            ImportLocation::Local(local)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_txp_rejects_unsuffixed_import() {
+        let root = TempDir::new().expect("should create temporary directory");
+        let resolver = TxpUriResolver::new(root.path());
+
+        assert_eq!(
+            resolver
+                .kind("txp:place/polity/country/iso_3166_entity/instance")
+                .ok(),
+            Some(ImportKind::Instance)
+        );
+        let error = resolver
+            .resolve("txp:place/polity/country/iso_3166_entity")
+            .expect_err("import without a suffix should be rejected");
+        assert!(
+            error.to_string().contains("must end with /schema"),
+            "{error}"
+        );
+    }
+}
@@ -14,7 +14,8 @@
 //! - **YAML parsing**: Uses `parse-linkml` specialized PEG parser
 //!   - Located at `crates/data/parsing/parse/linkml-parser/`
 //!   - Part of centralized infrastructure (not direct `serde_yaml` usage)
-//!   - Required because `ParseFormat` doesn't support LinkML/YAML yet
+//!   - Required because `ParseFormat` doesn't support LinkML/YAML yet; the
+//!     `ParseFormat::LinkMLYaml` variant must be added upstream in `parse_core`
 //!   - Provides LinkML-specific grammar validation and semantic checks
 //!
 //! ## Why Different Approaches for JSON vs YAML?
@@ -39,16 +40,21 @@ use linkml_core::{
 use std::path::Path;
 
 pub mod factory;
+pub mod http_import_cache;
 pub mod import_resolver;
 pub mod import_resolver_v2;
+pub mod import_uri_resolver;
 pub mod json_parser_simple;
 pub mod json_parser_v2;
 pub mod schema_loader;
+pub(crate) mod yaml_events;
 pub mod yaml_parser_simple;
 pub mod yaml_parser_v2;
 
+pub use http_import_cache::HttpImportCache;
 pub use import_resolver::ImportResolver;
 pub use import_resolver_v2::{ImportResolverV2, ImportSpec};
+pub use import_uri_resolver::{ImportKind, ImportLocation, ImportUriResolver, TxpUriResolver};
 pub use json_parser_simple::JsonParserSimple;
 pub use json_parser_v2::JsonParserV2;
 pub use schema_loader::SchemaLoader;
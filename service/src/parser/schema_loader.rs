@@ -10,7 +10,7 @@ use reqwest;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
-use super::{AsyncSchemaParser, ImportResolverV2, YamlParserV2};
+use super::{AsyncSchemaParser, HttpImportCache, ImportResolverV2, YamlParserV2};
 
 /// Loader for `LinkML` schemas from various sources
 pub struct SchemaLoader {
@@ -19,6 +19,10 @@ pub struct SchemaLoader {
     http_client: reqwest::Client,
     /// Optional import resolver with custom HTTP client
     import_resolver: Option<Arc<ImportResolverV2>>,
+    /// Optional override for the maximum import depth
+    max_import_depth: Option<usize>,
+    /// Optional cache for schemas and imports fetched over HTTP
+    http_cache: Option<Arc<HttpImportCache>>,
 }
 
 impl SchemaLoader {
@@ -32,6 +36,8 @@ impl SchemaLoader {
             fs_adapter,
             http_client: reqwest::Client::new(),
             import_resolver: None,
+            max_import_depth: None,
+            http_cache: None,
         }
     }
 
@@ -48,6 +54,8 @@ impl SchemaLoader {
             fs_adapter,
             http_client: reqwest::Client::new(),
             import_resolver: Some(Arc::new(resolver)),
+            max_import_depth: None,
+            http_cache: None,
         }
     }
 
@@ -63,9 +71,74 @@ impl SchemaLoader {
             fs_adapter,
             http_client: reqwest::Client::new(),
             import_resolver: Some(resolver),
+            max_import_depth: None,
+            http_cache: None,
         }
     }
 
+    /// Set the maximum depth of nested imports to follow
+    ///
+    /// Takes precedence over the default limit of 10 and over any
+    /// `max_import_depth` declared in the schema's import settings.
+    #[must_use]
+    pub fn with_max_import_depth(mut self, max_depth: usize) -> Self {
+        self.max_import_depth = Some(max_depth);
+        self
+    }
+
+    /// Cache schemas and imports fetched over HTTP
+    ///
+    /// Cached responses younger than the cache's maximum age are reused;
+    /// older ones are revalidated with a conditional request.
+    #[must_use]
+    pub fn with_http_cache(mut self, cache: Arc<HttpImportCache>) -> Self {
+        self.http_cache = Some(cache);
+        self
+    }
+
+    /// Resolve the imports of an already parsed schema
+    ///
+    /// Uses the schema's own import settings, if any, together with the
+    /// loader's import resolver and depth limit.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an import cannot be loaded, a circular import is
+    /// detected, or the maximum import depth is exceeded.
+    pub async fn resolve_imports(&self, schema: &SchemaDefinition) -> Result<SchemaDefinition> {
+        let settings = schema
+            .settings
+            .as_ref()
+            .and_then(|s| s.imports.clone())
+            .unwrap_or_default();
+
+        self.resolve_with_settings(schema, settings).await
+    }
+
+    /// Resolve imports using a scoped copy of the configured resolver, or a
+    /// fresh one built from `settings`
+    ///
+    /// The loader's depth limit and HTTP cache only apply to this call; a
+    /// resolver shared with other loaders is left as it was.
+    async fn resolve_with_settings(
+        &self,
+        schema: &SchemaDefinition,
+        settings: ImportSettings,
+    ) -> Result<SchemaDefinition> {
+        let import_resolver = match self.import_resolver {
+            // The provided resolver may have a production HTTP client
+            Some(ref resolver) => resolver.scoped(),
+            None => ImportResolverV2::with_settings(settings),
+        };
+        if let Some(max_depth) = self.max_import_depth {
+            import_resolver.set_max_import_depth(max_depth);
+        }
+        if let Some(ref http_cache) = self.http_cache {
+            import_resolver.set_http_cache(Arc::clone(http_cache));
+        }
+        import_resolver.resolve_imports(schema).await
+    }
+
     /// Parse JSON LinkML schema content directly
     /// 
     /// JSON LinkML schemas are rare (most are YAML), so we use direct serde_json
@@ -145,14 +218,7 @@ impl SchemaLoader {
         }
 
         // Resolve imports using custom resolver if available, otherwise create one
-        if let Some(ref resolver) = self.import_resolver {
-            // Use the provided resolver (may have production HTTP client)
-            resolver.resolve_imports(&schema).await
-        } else {
-            // Create a new resolver with settings
-            let import_resolver = ImportResolverV2::with_settings(settings);
-            import_resolver.resolve_imports(&schema).await
-        }
+        self.resolve_with_settings(&schema, settings).await
     }
 
     /// Load a schema from a `URL`
@@ -162,25 +228,29 @@ impl SchemaLoader {
     ///
     pub async fn load_url(&self, url: &str) -> Result<SchemaDefinition> {
         // Fetch content from URL
-        let response = self
-            .http_client
-            .get(url)
-            .send()
-            .await
-            .map_err(|e| LinkMLError::service(format!("Failed to fetch URL: {e}")))?;
-
-        if !response.status().is_success() {
-            return Err(LinkMLError::service(format!(
-                "HTTP error {}: {}",
-                response.status(),
-                response.status().canonical_reason().unwrap_or("Unknown")
-            )));
-        }
+        let content = if let Some(ref http_cache) = self.http_cache {
+            http_cache.fetch(&self.http_client, url).await?
+        } else {
+            let response = self
+                .http_client
+                .get(url)
+                .send()
+                .await
+                .map_err(|e| LinkMLError::service(format!("Failed to fetch URL: {e}")))?;
 
-        let content = response
-            .text()
-            .await
-            .map_err(|e| LinkMLError::service(format!("Failed to read response: {e}")))?;
+            if !response.status().is_success() {
+                return Err(LinkMLError::service(format!(
+                    "HTTP error {}: {}",
+                    response.status(),
+                    response.status().canonical_reason().unwrap_or("Unknown")
+                )));
+            }
+
+            response
+                .text()
+                .await
+                .map_err(|e| LinkMLError::service(format!("Failed to read response: {e}")))?
+        };
 
         // Determine format from URL extension or content type (case-insensitive)
         let url_lower = url.to_lowercase();
@@ -227,12 +297,7 @@ impl SchemaLoader {
         }
 
         // Resolve imports using custom resolver if available, otherwise create one
-        if let Some(ref resolver) = self.import_resolver {
-            resolver.resolve_imports(&schema).await
-        } else {
-            let import_resolver = ImportResolverV2::with_settings(settings);
-            import_resolver.resolve_imports(&schema).await
-        }
+        self.resolve_with_settings(&schema, settings).await
     }
 
     /// Load a schema from a string with specified format
@@ -255,13 +320,81 @@ impl SchemaLoader {
         };
 
         // Resolve imports using custom resolver if available, otherwise create one
-        if let Some(ref resolver) = self.import_resolver {
-            resolver.resolve_imports(&schema).await
-        } else {
-            let import_resolver = ImportResolverV2::with_settings(settings);
-            import_resolver.resolve_imports(&schema).await
-        }
+        self.resolve_with_settings(&schema, settings).await
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
 
+    /// Write a chain of schemas where `schema_N` imports `schema_{N+1}`
+    async fn write_import_chain(dir: &Path, length: usize) -> anyhow::Result<PathBuf> {
+        for i in 0..length {
+            let imports = if i + 1 < length {
+                format!("imports:\n  - schema_{}\n", i + 1)
+            } else {
+                String::new()
+            };
+            let content = format!(
+                "id: https://example.org/schema_{i}\nname: schema_{i}\n{imports}classes:\n  Class{i}:\n    name: Class{i}\n"
+            );
+            tokio::fs::write(dir.join(format!("schema_{i}.yaml")), content).await?;
+        }
+        Ok(dir.join("schema_0.yaml"))
+    }
+
+    #[tokio::test]
+    async fn test_max_import_depth_is_configurable() -> anyhow::Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = write_import_chain(temp_dir.path(), 12).await?;
+
+        let err = SchemaLoader::new()
+            .load_file(&root)
+            .await
+            .expect_err("default depth of 10 should be exceeded");
+        assert!(
+            err.to_string()
+                .contains("Maximum import depth (10) exceeded"),
+            "unexpected error: {err}"
+        );
+
+        let schema = SchemaLoader::new()
+            .with_max_import_depth(15)
+            .load_file(&root)
+            .await?;
+        assert!(schema.classes.contains_key("Class0"));
+        assert!(schema.classes.contains_key("Class11"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_loader_limits_leave_shared_resolver_unchanged() -> anyhow::Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = write_import_chain(temp_dir.path(), 12).await?;
+        let resolver = Arc::new(ImportResolverV2::with_settings(ImportSettings {
+            search_paths: vec![temp_dir.path().to_string_lossy().to_string()],
+            ..ImportSettings::default()
+        }));
+
+        let schema = SchemaLoader::with_shared_resolver(Arc::clone(&resolver))
+            .with_max_import_depth(15)
+            .load_file(&root)
+            .await?;
+        assert!(schema.classes.contains_key("Class11"));
+        assert_eq!(resolver.max_import_depth(), 10);
+
+        // Another loader sharing the resolver still gets the default limit
+        let err = SchemaLoader::with_shared_resolver(resolver)
+            .load_file(&root)
+            .await
+            .expect_err("default depth of 10 should be exceeded");
+        assert!(
+            err.to_string()
+                .contains("Maximum import depth (10) exceeded"),
+            "unexpected error: {err}"
+        );
+        Ok(())
+    }
+}
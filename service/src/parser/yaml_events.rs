@@ -0,0 +1,497 @@
+//! Event-level reading of large YAML documents
+//!
+//! `serde_yaml` parses a whole document before deserializing any of it, so an
+//! instance file with tens of thousands of entries would be held in memory in
+//! full. [`YamlEvents`] pulls parser events from a reader one at a time
+//! instead, so callers can build one node, such as a single instance, hand it
+//! on and drop it before reading the next.
+//!
+//! Only anchored nodes are kept beyond the node being built, so that aliases
+//! can still be resolved. Callers needing a few fields of each instance can
+//! restrict instances to them, and the values of other keys are skipped
+//! without being built.
+
+use linkml_core::error::{LinkMLError, Result};
+use parking_lot::Mutex;
+use serde_json::{Map, Number, Value};
+use std::collections::{HashMap, HashSet};
+use std::io::BufRead;
+use std::sync::Arc;
+use yaml_rust2::Yaml;
+use yaml_rust2::parser::{Event, Parser};
+use yaml_rust2::scanner::TScalarStyle;
+
+/// Characters of a reader, decoded one line at a time
+struct LineChars<R> {
+    reader: R,
+    line: Vec<char>,
+    pos: usize,
+    /// Read failure, reported by [`YamlEvents`] since the parser only sees
+    /// the input end
+    error: Arc<Mutex<Option<std::io::Error>>>,
+}
+
+impl<R: BufRead> Iterator for LineChars<R> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        loop {
+            if let Some(&ch) = self.line.get(self.pos) {
+                self.pos += 1;
+                return Some(ch);
+            }
+            let mut line = String::new();
+            match self.reader.read_line(&mut line) {
+                Ok(0) => return None,
+                Ok(_) => {
+                    self.line.clear();
+                    self.line.extend(line.chars());
+                    self.pos = 0;
+                }
+                Err(e) => {
+                    *self.error.lock() = Some(e);
+                    return None;
+                }
+            }
+        }
+    }
+}
+
+/// Pull parser over the events of a YAML stream
+pub(crate) struct YamlEvents<R: BufRead> {
+    parser: Parser<LineChars<R>>,
+    read_error: Arc<Mutex<Option<std::io::Error>>>,
+    /// Anchored nodes, by anchor id, for resolving aliases
+    anchors: HashMap<usize, Value>,
+    /// Keys kept in instance mappings; all keys when unset
+    instance_fields: Option<HashSet<String>>,
+}
+
+impl<R: BufRead> YamlEvents<R> {
+    /// Read events from `reader`
+    pub(crate) fn new(reader: R) -> Self {
+        let read_error = Arc::default();
+        let chars = LineChars {
+            reader,
+            line: Vec::new(),
+            pos: 0,
+            error: Arc::clone(&read_error),
+        };
+        Self {
+            parser: Parser::new(chars),
+            read_error,
+            anchors: HashMap::new(),
+            instance_fields: None,
+        }
+    }
+
+    /// Keep only `fields` of instances that are mappings
+    ///
+    /// The values of other keys are skipped, so an alias to an anchor inside
+    /// them can't be resolved. Anchored instances are built in full, since a
+    /// later instance may alias them.
+    pub(crate) fn with_instance_fields(
+        mut self,
+        fields: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.instance_fields = Some(fields.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Top-level entries of an instance document, skipping its instances
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the reader fails or the document is not valid YAML.
+    pub(crate) fn header(mut self) -> Result<Map<String, Value>> {
+        let mut header = Map::new();
+        self.walk_instance_document(
+            |key, value| {
+                header.insert(key, value);
+                Ok(())
+            },
+            None,
+        )?;
+        Ok(header)
+    }
+
+    /// Stream an instance document
+    ///
+    /// Instances are the entries of the `instances:` sequence of a top-level
+    /// mapping, or of a top-level sequence. Each is built and handed to
+    /// `on_instance` on its own; the other top-level entries go to
+    /// `on_header`. Returns whether the document had a list of instances.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the reader fails, the document is not valid YAML,
+    /// or a callback fails, in which case reading stops there.
+    pub(crate) fn instances(
+        mut self,
+        on_header: impl FnMut(String, Value) -> Result<()>,
+        mut on_instance: impl FnMut(Value) -> Result<()>,
+    ) -> Result<bool> {
+        self.walk_instance_document(on_header, Some(&mut on_instance))
+    }
+
+    fn walk_instance_document(
+        &mut self,
+        mut on_header: impl FnMut(String, Value) -> Result<()>,
+        mut on_instance: Option<&mut dyn FnMut(Value) -> Result<()>>,
+    ) -> Result<bool> {
+        if !matches!(self.next_event()?, Event::StreamStart) {
+            return Err(LinkMLError::parse("YAML stream does not start a stream"));
+        }
+        match self.next_event()? {
+            Event::DocumentStart => {}
+            Event::StreamEnd => return Ok(false),
+            other => return Err(unexpected(&other)),
+        }
+
+        match self.next_event()? {
+            Event::SequenceStart(..) => {
+                self.sequence_items(on_instance)?;
+                Ok(true)
+            }
+            Event::MappingStart(..) => {
+                let mut found = false;
+                loop {
+                    let event = self.next_event()?;
+                    if matches!(event, Event::MappingEnd) {
+                        return Ok(found);
+                    }
+                    let key = key_string(self.read_node(event)?);
+                    let value = self.next_event()?;
+                    if key == "instances" && matches!(value, Event::SequenceStart(..)) {
+                        let on_instance = on_instance
+                            .as_mut()
+                            .map(|on_instance| &mut **on_instance as &mut dyn FnMut(_) -> _);
+                        self.sequence_items(on_instance)?;
+                        found = true;
+                    } else {
+                        on_header(key, self.read_node(value)?)?;
+                    }
+                }
+            }
+            other => {
+                self.skip_node(&other)?;
+                Ok(false)
+            }
+        }
+    }
+
+    /// Build or skip the items of a sequence whose start was just read
+    fn sequence_items(
+        &mut self,
+        mut on_item: Option<&mut dyn FnMut(Value) -> Result<()>>,
+    ) -> Result<()> {
+        loop {
+            let event = self.next_event()?;
+            if matches!(event, Event::SequenceEnd) {
+                return Ok(());
+            }
+            match on_item.as_deref_mut() {
+                Some(on_item) => on_item(self.read_instance(event)?)?,
+                None => self.skip_node(&event)?,
+            }
+        }
+    }
+
+    /// Build the instance that starts with `event`, keeping only the
+    /// requested fields of an unanchored mapping
+    fn read_instance(&mut self, event: Event) -> Result<Value> {
+        if self.instance_fields.is_none() || !matches!(event, Event::MappingStart(0, _)) {
+            return self.read_node(event);
+        }
+        let mut map = Map::new();
+        loop {
+            let event = self.next_event()?;
+            if matches!(event, Event::MappingEnd) {
+                return Ok(Value::Object(map));
+            }
+            let key = key_string(self.read_node(event)?);
+            let value = self.next_event()?;
+            if self
+                .instance_fields
+                .as_ref()
+                .is_some_and(|fields| fields.contains(&key))
+            {
+                map.insert(key, self.read_node(value)?);
+            } else {
+                self.skip_node(&value)?;
+            }
+        }
+    }
+
+    /// Next event of the stream
+    fn next_event(&mut self) -> Result<Event> {
+        let next = self.parser.next_token();
+        if let Some(e) = self.read_error.lock().take() {
+            return Err(e.into());
+        }
+        next.map(|(event, _)| event)
+            .map_err(|e| LinkMLError::parse(format!("Invalid YAML: {e}")))
+    }
+
+    /// Build the node that starts with `event` as a JSON value
+    fn read_node(&mut self, event: Event) -> Result<Value> {
+        let (anchor, value) = match event {
+            Event::Scalar(text, style, anchor, _) => (anchor, scalar_value(text, style)),
+            Event::SequenceStart(anchor, _) => {
+                let mut items = Vec::new();
+                loop {
+                    let event = self.next_event()?;
+                    if matches!(event, Event::SequenceEnd) {
+                        break;
+                    }
+                    items.push(self.read_node(event)?);
+                }
+                (anchor, Value::Array(items))
+            }
+            Event::MappingStart(anchor, _) => {
+                let mut map = Map::new();
+                loop {
+                    let event = self.next_event()?;
+                    if matches!(event, Event::MappingEnd) {
+                        break;
+                    }
+                    let key = key_string(self.read_node(event)?);
+                    let value = self.next_event()?;
+                    map.insert(key, self.read_node(value)?);
+                }
+                (anchor, Value::Object(map))
+            }
+            Event::Alias(anchor) => {
+                return self.anchors.get(&anchor).cloned().ok_or_else(|| {
+                    LinkMLError::parse("YAML alias refers to an anchor that was skipped")
+                });
+            }
+            other => return Err(unexpected(&other)),
+        };
+        if anchor != 0 {
+            self.anchors.insert(anchor, value.clone());
+        }
+        Ok(value)
+    }
+
+    /// Skip the rest of the node that starts with `event`
+    fn skip_node(&mut self, event: &Event) -> Result<()> {
+        let mut depth = usize::from(matches!(
+            event,
+            Event::SequenceStart(..) | Event::MappingStart(..)
+        ));
+        while depth > 0 {
+            match self.next_event()? {
+                Event::SequenceStart(..) | Event::MappingStart(..) => depth += 1,
+                Event::SequenceEnd | Event::MappingEnd => depth -= 1,
+                Event::StreamEnd => return Err(LinkMLError::parse("YAML stream ends mid-node")),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+fn unexpected(event: &Event) -> LinkMLError {
+    LinkMLError::parse(format!("Unexpected YAML event {event:?}"))
+}
+
+/// Mapping keys are strings in JSON; other scalars keep their YAML text
+fn key_string(key: Value) -> String {
+    match key {
+        Value::String(text) => text,
+        other => other.to_string(),
+    }
+}
+
+/// Resolve a scalar the way the YAML core schema does
+///
+/// Quoted and block scalars are always strings.
+fn scalar_value(text: String, style: TScalarStyle) -> Value {
+    if style != TScalarStyle::Plain {
+        return Value::String(text);
+    }
+    match Yaml::from_str(&text) {
+        Yaml::Null => Value::Null,
+        Yaml::Boolean(flag) => Value::Bool(flag),
+        Yaml::Integer(number) => Value::Number(number.into()),
+        real @ Yaml::Real(_) => real
+            .as_f64()
+            .and_then(Number::from_f64)
+            .map_or(Value::String(text), Value::Number),
+        _ => Value::String(text),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::io::{BufReader, Read};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_instances_are_streamed_one_by_one() -> Result<()> {
+        let document = "\
+id: https://textpast.org/instance/place/polity/country/iso_3166_entity
+instances:
+  - &nl
+    id: NL
+    population: 17.9e6
+    eu: true
+  - {id: 'FR', tags: [a, b]}
+  - id: \"007\"
+    same_as: *nl
+class: Country
+";
+        let mut header = Map::new();
+        let mut instances = Vec::new();
+        let found = YamlEvents::new(document.as_bytes()).instances(
+            |key, value| {
+                header.insert(key, value);
+                Ok(())
+            },
+            |instance| {
+                instances.push(instance);
+                Ok(())
+            },
+        )?;
+
+        assert!(found);
+        assert_eq!(header.get("class"), Some(&json!("Country")));
+        assert_eq!(
+            instances,
+            [
+                json!({"id": "NL", "population": 17.9e6, "eu": true}),
+                json!({"id": "FR", "tags": ["a", "b"]}),
+                json!({"id": "007", "same_as": {"id": "NL", "population": 17.9e6, "eu": true}}),
+            ]
+        );
+
+        let header = YamlEvents::new(document.as_bytes()).header()?;
+        assert_eq!(header.len(), 2);
+        assert!(!header.contains_key("instances"));
+        Ok(())
+    }
+
+    /// Instance document generated while it is read, counting the bytes read
+    struct GeneratedInstances {
+        count: usize,
+        next: usize,
+        chunk: Vec<u8>,
+        pos: usize,
+        read: Arc<AtomicUsize>,
+    }
+
+    fn generated_instance(index: usize) -> String {
+        format!("  - id: C{index:06}\n    label: Country {index:06}\n")
+    }
+
+    impl Read for GeneratedInstances {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            if self.pos == self.chunk.len() {
+                if self.next == self.count {
+                    return Ok(0);
+                }
+                self.chunk = generated_instance(self.next).into_bytes();
+                self.next += 1;
+                self.pos = 0;
+            }
+            let len = buf.len().min(self.chunk.len() - self.pos);
+            buf[..len].copy_from_slice(&self.chunk[self.pos..self.pos + len]);
+            self.pos += len;
+            self.read.fetch_add(len, Ordering::Relaxed);
+            Ok(len)
+        }
+    }
+
+    #[test]
+    fn test_read_ahead_is_bounded() -> Result<()> {
+        const COUNT: usize = 50_000;
+        const HEADER: &[u8] = b"instances:\n";
+        let read = Arc::new(AtomicUsize::new(0));
+        let source = GeneratedInstances {
+            count: COUNT,
+            next: 0,
+            chunk: HEADER.to_vec(),
+            pos: 0,
+            read: Arc::clone(&read),
+        };
+        let instance_len = generated_instance(0).len();
+
+        // Each instance must arrive before much more of the document is read
+        let mut seen = 0;
+        let mut max_read_ahead = 0;
+        YamlEvents::new(BufReader::new(source)).instances(
+            |_, _| Ok(()),
+            |_| {
+                seen += 1;
+                let consumed = HEADER.len() + seen * instance_len;
+                max_read_ahead =
+                    max_read_ahead.max(read.load(Ordering::Relaxed).saturating_sub(consumed));
+                Ok(())
+            },
+        )?;
+
+        assert_eq!(seen, COUNT);
+        assert!(read.load(Ordering::Relaxed) > 1_000_000);
+        assert!(
+            max_read_ahead < 64 * 1024,
+            "read {max_read_ahead} bytes ahead of the current instance"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_instance_fields_skip_other_values() -> Result<()> {
+        let document = "\
+instances:
+  - id: NL
+    label: Netherlands
+    exact_mappings: [wikidata:Q55, {source: &src geonames}]
+    borders: {BE: 450, DE: 577}
+  - &fr
+    id: FR
+    label: France
+    borders: {BE: 620}
+  - {id: DE, label: *src}
+";
+        let mut instances = Vec::new();
+        YamlEvents::new(document.as_bytes())
+            .with_instance_fields(["id", "label"])
+            .instances(
+                |_, _| Ok(()),
+                |instance| {
+                    instances.push(instance);
+                    Ok(())
+                },
+            )
+            .expect_err("the alias refers to a skipped anchor");
+        assert_eq!(
+            instances,
+            [
+                json!({"id": "NL", "label": "Netherlands"}),
+                json!({"id": "FR", "label": "France", "borders": {"BE": 620}}),
+            ]
+        );
+
+        let mut ids = Vec::new();
+        YamlEvents::new(document.as_bytes())
+            .with_instance_fields(["id"])
+            .instances(
+                |_, _| Ok(()),
+                |instance| {
+                    ids.push(instance);
+                    Ok(())
+                },
+            )?;
+        assert_eq!(ids.last(), Some(&json!({"id": "DE"})));
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_yaml_is_a_parse_error() {
+        let result = YamlEvents::new("instances:\n  - id: [NL\n".as_bytes()).header();
+        assert!(matches!(result, Err(LinkMLError::ParseError { .. })));
+    }
+}
@@ -29,6 +29,16 @@
 //! **This YAML parser**: Uses parse-linkml (specialized PEG parser for LinkML).
 //! Both comply with mandatory centralized parsing architecture.
 //!
+//! ## Routing Through ParseService
+//!
+//! **Status: open.** YAML schemas do not go through `ParseService` yet, so
+//! they share neither its cache nor its format detection with `JsonParserV2`.
+//! Doing so requires a `ParseFormat::LinkMLYaml` variant. `ParseFormat` is
+//! owned by `parse_core` (`crates/data/parsing/parse/`), not by this crate, so
+//! the variant has to land there first. Once it exists, only the internals of
+//! this parser need to change; the `SchemaParser` and `AsyncSchemaParser`
+//! surfaces stay as they are.
+//!
 //! File system operations are handled via the `FileSystemOperations` trait
 //! for sandboxed, testable file access.
 
@@ -37,11 +47,16 @@ use linkml_core::{
     types::SchemaDefinition,
 };
 use parse_linkml::LinkMLParser;
+use serde_json::Value;
+use std::collections::HashMap;
 use std::path::Path;
 use std::sync::Arc;
 
 use super::SchemaParser;
-use crate::file_system_adapter::FileSystemOperations;
+use super::yaml_events::YamlEvents;
+use crate::file_system_adapter::{FileSystemOperations, StreamingFileOperations};
+use crate::loader::traits::DataInstance;
+use crate::validator::instance_loader::{InstanceConfig, InstanceData, InstanceLoader};
 
 /// `YAML` parser implementation with LinkML Parser and file system adapter
 #[derive(Clone)]
@@ -56,6 +71,119 @@ impl<F: FileSystemOperations> YamlParserV2<F> {
     }
 }
 
+impl<F: StreamingFileOperations> YamlParserV2<F> {
+    /// Stream the `instances:` sequence of a YAML instance file
+    ///
+    /// The file is read as a stream of YAML events and each entry is built on
+    /// its own and handed to `callback` as a `DataInstance` together with the
+    /// running count of instances seen so far, so neither the file nor the
+    /// instance list is ever held in memory as a whole. Only the key/value
+    /// index described by `config` is retained and returned as
+    /// `InstanceData`, mirroring `InstanceLoader::load_yaml_file`.
+    ///
+    /// The class of each instance is taken from the document's `class`
+    /// header, wherever it appears; the header is read in a first pass that
+    /// skips the instances. Bare top-level sequences are streamed the same
+    /// way.
+    ///
+    /// Use `parse_str`/`parse_file` for schemas; this entry point is only for
+    /// instance data.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be opened or is not valid YAML, if
+    /// an instance lacks the configured key field, or if `callback` returns an
+    /// error (streaming stops at that instance).
+    pub async fn parse_instances_streaming<C>(
+        &self,
+        path: &Path,
+        config: &InstanceConfig,
+        callback: C,
+    ) -> Result<InstanceData>
+    where
+        C: FnMut(usize, DataInstance) -> Result<()>,
+    {
+        let loaded_at = timestamp_service::wiring::wire_timestamp()
+            .into_inner()
+            .now_utc()
+            .await
+            .map_err(|e| LinkMLError::service(format!("Failed to get current timestamp: {e}")))?;
+
+        let in_file = |e: LinkMLError| match e {
+            LinkMLError::ParseError { message, location } => LinkMLError::ParseError {
+                message: format!("{message} in instance file {}", path.display()),
+                location,
+            },
+            other => other,
+        };
+        let header = YamlEvents::new(self.fs.open_reader(path)?)
+            .header()
+            .map_err(in_file)?;
+        let source = format!("file:{}", path.display());
+
+        let mut state = StreamState {
+            config,
+            callback,
+            source: &source,
+            class_name: header
+                .get("class")
+                .and_then(Value::as_str)
+                .map(str::to_string),
+            values: HashMap::new(),
+            count: 0,
+        };
+        YamlEvents::new(self.fs.open_reader(path)?)
+            .instances(|_, _| Ok(()), |item| state.accept(item))
+            .map_err(in_file)?;
+
+        tracing::debug!("Streamed {} instances from {}", state.count, path.display());
+
+        Ok(InstanceData {
+            values: state.values,
+            source,
+            loaded_at,
+        })
+    }
+}
+
+/// State of a streamed instance file
+struct StreamState<'a, C> {
+    config: &'a InstanceConfig,
+    callback: C,
+    source: &'a str,
+    class_name: Option<String>,
+    values: HashMap<String, Vec<String>>,
+    count: usize,
+}
+
+impl<C> StreamState<'_, C>
+where
+    C: FnMut(usize, DataInstance) -> Result<()>,
+{
+    /// Index one streamed entry and hand it to the callback
+    fn accept(&mut self, item: Value) -> Result<()> {
+        InstanceLoader::extract_from_object(&item, self.config, &mut self.values)?;
+
+        let Value::Object(obj) = item else {
+            return Ok(());
+        };
+
+        self.count += 1;
+        let id = obj.get("id").and_then(Value::as_str).map(str::to_string);
+        let mut metadata = HashMap::new();
+        metadata.insert("source".to_string(), self.source.to_string());
+
+        let instance = DataInstance {
+            class_name: self.class_name.clone().unwrap_or_default(),
+            data: obj.into_iter().collect(),
+            id,
+            metadata,
+        };
+
+        (self.callback)(self.count, instance)
+    }
+}
+
 impl<F: FileSystemOperations> SchemaParser for YamlParserV2<F> {
     fn parse_str(&self, content: &str) -> Result<SchemaDefinition> {
         // Use LinkMLParser directly for high-performance PEG parsing
@@ -175,4 +303,82 @@ classes:
         assert!(schema.classes.contains_key("Person"));
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_parse_instances_streaming() -> std::result::Result<(), Box<dyn std::error::Error>>
+    {
+        let temp_dir = TempDir::new()?;
+        let fs = Arc::new(TokioFileSystemAdapter::sandboxed(
+            temp_dir.path().to_path_buf(),
+        ));
+        let parser = YamlParserV2::new(fs.clone());
+
+        let instance_content = r"
+id: https://textpast.org/test/country/instance
+class: Country
+instances:
+  - id: US
+    label: United States
+  - id: DE
+    label: Germany
+  - id: FR
+    label: France
+";
+        let instance_path = Path::new("countries.yaml");
+        fs.write(instance_path, instance_content).await?;
+
+        let config = InstanceConfig {
+            key_field: "id".to_string(),
+            value_field: Some("label".to_string()),
+            filter: None,
+        };
+
+        let mut seen = Vec::new();
+        let data = parser
+            .parse_instances_streaming(instance_path, &config, |count, instance| {
+                assert_eq!(instance.class_name, "Country");
+                seen.push((count, instance.id.unwrap_or_default()));
+                Ok(())
+            })
+            .await?;
+
+        assert_eq!(
+            seen,
+            vec![
+                (1, "US".to_string()),
+                (2, "DE".to_string()),
+                (3, "FR".to_string())
+            ]
+        );
+        assert_eq!(data.values.len(), 3);
+        assert_eq!(data.values.get("DE"), Some(&vec!["Germany".to_string()]));
+        assert_eq!(data.source, "file:countries.yaml");
+
+        // A failing callback stops the stream and surfaces its own error
+        let mut calls = 0;
+        let result = parser
+            .parse_instances_streaming(instance_path, &config, |_, _| {
+                calls += 1;
+                Err(LinkMLError::service("stop"))
+            })
+            .await;
+        assert!(matches!(result, Err(LinkMLError::ServiceError(_))));
+        assert_eq!(calls, 1);
+
+        // The class header may follow the instances
+        fs.write(
+            Path::new("trailing_class.yaml"),
+            "instances:\n  - id: NL\n    label: Netherlands\nclass: Country\n",
+        )
+        .await?;
+        let mut classes = Vec::new();
+        parser
+            .parse_instances_streaming(Path::new("trailing_class.yaml"), &config, |_, instance| {
+                classes.push(instance.class_name);
+                Ok(())
+            })
+            .await?;
+        assert_eq!(classes, ["Country"]);
+        Ok(())
+    }
 }
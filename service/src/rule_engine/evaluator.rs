@@ -3,6 +3,7 @@
 //! This module handles evaluating postconditions and else conditions
 //! after a rule's preconditions have been matched.
 
+use linkml_core::types::PresenceEnum;
 use serde_json::Value;
 use std::collections::HashMap;
 
@@ -155,8 +156,26 @@ impl RuleEvaluator {
         let mut issues = Vec::new();
         let original = &condition.original;
 
+        // Check value presence
+        if original.value_presence == Some(PresenceEnum::Absent) && !value.is_null() {
+            let field_name = path.split('.').next_back().unwrap_or(path);
+            let msg = if let Some(desc) = rule_description {
+                format!("Field '{field_name}' must be absent by rule: {desc}")
+            } else {
+                format!("Field '{field_name}' must be absent by rule")
+            };
+
+            issues.push(
+                ValidationIssue::error(&msg, path, "RuleEvaluator")
+                    .with_code("RULE_VALUE_PRESENCE")
+                    .with_context("actual", value.clone()),
+            );
+            return Ok(issues);
+        }
+
         // Check required
-        if let Some(true) = original.required
+        if (original.required == Some(true)
+            || original.value_presence == Some(PresenceEnum::Present))
             && value.is_null()
         {
             // Extract field name from path (e.g., "person.guardian_name" -> "guardian_name")
@@ -203,7 +222,7 @@ impl RuleEvaluator {
 
         // Check equals_expression
         if let Some(ref expr_ast) = condition.equals_expression_ast {
-            let expr_context = context.get_expression_context();
+            let expr_context = context.get_expression_context()?;
             match self.expression_engine.evaluate_ast(expr_ast, &expr_context) {
                 Ok(computed) => {
                     if value != &computed {
@@ -248,7 +267,7 @@ impl RuleEvaluator {
         rule_description: Option<&str>,
     ) -> linkml_core::error::Result<Vec<ValidationIssue>> {
         let mut issues = Vec::new();
-        let expr_context = context.get_expression_context();
+        let expr_context = context.get_expression_context()?;
 
         for (i, expr) in expressions.iter().enumerate() {
             match self.expression_engine.evaluate_ast(expr, &expr_context) {
@@ -129,7 +129,7 @@ impl RuleExecutor {
             .title
             .clone()
             .or_else(|| rule.original.description.clone())
-            .unwrap_or_else(|| format!("Rule from {}", rule.source_class));
+            .unwrap_or_else(|| format!("{} rules[{}]", rule.source_class, rule.index));
 
         context.current_rule = Some(rule_id.clone());
 
@@ -151,28 +151,31 @@ impl RuleExecutor {
                     .original
                     .description
                     .as_deref()
-                    .or(rule.original.title.as_deref());
+                    .or(rule.original.title.as_deref())
+                    .unwrap_or(&rule_id);
 
                 issues.extend(self.evaluator.evaluate_postconditions(
                     postconditions,
                     context,
-                    rule_desc,
+                    Some(rule_desc),
                 )?);
             }
         } else {
             // Preconditions didn't match, check else conditions
             if let Some(ref else_conditions) = rule.else_condition_ast {
-                let rule_desc = rule
-                    .original
-                    .description
-                    .as_deref()
-                    .or(rule.original.title.as_deref())
-                    .map(|d| format!("{d} (else)"));
+                let rule_desc = format!(
+                    "{} (else)",
+                    rule.original
+                        .description
+                        .as_deref()
+                        .or(rule.original.title.as_deref())
+                        .unwrap_or(&rule_id)
+                );
 
                 issues.extend(self.evaluator.evaluate_postconditions(
                     else_conditions,
                     context,
-                    rule_desc.as_deref(),
+                    Some(&rule_desc),
                 )?);
             }
         }
@@ -180,7 +183,14 @@ impl RuleExecutor {
         // Clear current rule
         context.current_rule = None;
 
-        Ok(issues)
+        Ok(issues
+            .into_iter()
+            .map(|issue| {
+                issue
+                    .with_context("rule_class", rule.source_class.clone().into())
+                    .with_context("rule_index", rule.index.into())
+            })
+            .collect())
     }
 }
 
@@ -370,4 +380,49 @@ mod tests {
         assert_eq!(fail_fast_issues.len(), 1);
         Ok(())
     }
+
+    #[test]
+    fn test_absent_postcondition() -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let executor = RuleExecutor::new(Arc::new(ExpressionEngine::new()));
+        let rule: Rule = serde_yaml::from_str(
+            r"
+description: Active contracts have no end date
+preconditions:
+  slot_conditions:
+    status:
+      equals_string: active
+postconditions:
+  slot_conditions:
+    end_date:
+      value_presence: ABSENT
+",
+        )?;
+        let rule = CompiledRule {
+            index: 2,
+            ..CompiledRule::compile(rule, "Contract".to_string())?
+        };
+
+        let run = |instance: serde_json::Value| {
+            let mut validation_ctx = ValidationContext::new(Arc::default());
+            let mut context =
+                RuleExecutionContext::new(instance, "Contract".to_string(), &mut validation_ctx);
+            executor.execute_single_rule(&rule, &mut context)
+        };
+
+        let issues = run(json!({"status": "active", "end_date": "2024-06-30"}))?;
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues[0].message,
+            "Field 'end_date' must be absent by rule: Active contracts have no end date"
+        );
+        assert_eq!(issues[0].code.as_deref(), Some("RULE_VALUE_PRESENCE"));
+        assert_eq!(issues[0].context["rule_index"], json!(2));
+        assert_eq!(issues[0].context["rule_class"], json!("Contract"));
+
+        assert!(run(json!({"status": "active"}))?.is_empty());
+        assert!(run(json!({"status": "closed", "end_date": "2024-06-30"}))?.is_empty());
+        // A missing status cannot equal "active"
+        assert!(run(json!({"end_date": "2024-06-30"}))?.is_empty());
+        Ok(())
+    }
 }
@@ -24,12 +24,13 @@ impl<'a> RuleInheritanceResolver<'a> {
         }
     }
 
-    /// Get all rules for a class including inherited rules
+    /// Get all rules for a class including inherited rules, each paired with
+    /// the class that declares it and its position in that class's `rules`
     /// Returns an error if the operation fails
     ///
     /// # Errors
     ///
-    pub fn get_all_rules(&mut self, class_name: &str) -> Result<Vec<(Rule, String)>> {
+    pub fn get_all_rules(&mut self, class_name: &str) -> Result<Vec<(Rule, String, usize)>> {
         // Get inheritance chain
         let inheritance_chain = self.get_inheritance_chain(class_name)?;
 
@@ -40,7 +41,7 @@ impl<'a> RuleInheritanceResolver<'a> {
         // Process from most specific to most general
         for ancestor_name in &inheritance_chain {
             if let Some(class_def) = self.schema.classes.get(ancestor_name) {
-                for rule in &class_def.rules {
+                for (index, rule) in class_def.rules.iter().enumerate() {
                     // Create a rule identifier for deduplication
                     let rule_id = self.get_rule_id(rule);
 
@@ -58,7 +59,7 @@ impl<'a> RuleInheritanceResolver<'a> {
                         self.adjust_inherited_rule(rule, ancestor_name, class_name)?
                     };
 
-                    all_rules.push((adjusted_rule, ancestor_name.clone()));
+                    all_rules.push((adjusted_rule, ancestor_name.clone(), index));
                 }
             }
         }
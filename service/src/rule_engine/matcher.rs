@@ -4,7 +4,7 @@
 //! for a given instance.
 
 use linkml_core::error::LinkMLError;
-use linkml_core::types::{SlotCondition, SlotDefinition};
+use linkml_core::types::{PresenceEnum, SlotCondition, SlotDefinition};
 use serde_json::Value;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -108,6 +108,13 @@ impl RuleMatcher {
     ) -> linkml_core::error::Result<bool> {
         let original = &condition.original;
 
+        // Check value presence
+        match original.value_presence {
+            Some(PresenceEnum::Present) if value.is_null() => return Ok(false),
+            Some(PresenceEnum::Absent) => return Ok(value.is_null()),
+            _ => {}
+        }
+
         // Check required
         if let Some(required) = original.required
             && required
@@ -116,9 +123,14 @@ impl RuleMatcher {
             return Ok(false);
         }
 
-        // Skip further checks if value is null
+        // An absent value cannot equal anything or match a pattern or bound
         if value.is_null() {
-            return Ok(true);
+            return Ok(original.pattern.is_none()
+                && original.equals_string.is_none()
+                && original.equals_number.is_none()
+                && condition.equals_expression_ast.is_none()
+                && original.minimum_value.is_none()
+                && original.maximum_value.is_none());
         }
 
         // Check range/type
@@ -175,7 +187,7 @@ impl RuleMatcher {
 
         // Check equals_expression
         if let Some(ref expr_ast) = condition.equals_expression_ast {
-            let expr_context = context.get_expression_context();
+            let expr_context = context.get_expression_context()?;
             let computed = self
                 .expression_engine
                 .evaluate_ast(expr_ast, &expr_context)?;
@@ -205,6 +217,7 @@ impl RuleMatcher {
                 let temp_condition = SlotCondition {
                     range: condition.range.clone(),
                     required: condition.required,
+                    value_presence: None,
                     pattern: condition.pattern.clone(),
                     equals_string: None,
                     equals_number: None,
@@ -236,6 +249,7 @@ impl RuleMatcher {
                 let temp_condition = SlotCondition {
                     range: condition.range.clone(),
                     required: condition.required,
+                    value_presence: None,
                     pattern: condition.pattern.clone(),
                     equals_string: None,
                     equals_number: None,
@@ -264,6 +278,7 @@ impl RuleMatcher {
                 let temp_condition = SlotCondition {
                     range: condition.range.clone(),
                     required: condition.required,
+                    value_presence: None,
                     pattern: condition.pattern.clone(),
                     equals_string: None,
                     equals_number: None,
@@ -297,6 +312,7 @@ impl RuleMatcher {
                 let temp_condition = SlotCondition {
                     range: condition.range.clone(),
                     required: condition.required,
+                    value_presence: None,
                     pattern: condition.pattern.clone(),
                     equals_string: None,
                     equals_number: None,
@@ -326,7 +342,7 @@ impl RuleMatcher {
         expressions: &[crate::expression::ast::Expression],
         context: &RuleExecutionContext,
     ) -> linkml_core::error::Result<bool> {
-        let expr_context = context.get_expression_context();
+        let expr_context = context.get_expression_context()?;
 
         for expr in expressions {
             let result = self.expression_engine.evaluate_ast(expr, &expr_context)?;
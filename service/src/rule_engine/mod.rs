@@ -148,9 +148,9 @@ impl RuleEngine {
         let rules = inheritance_resolver.get_all_rules(class_name)?;
 
         let mut compiled_rules = Vec::new();
-        for (rule, source_class) in rules {
+        for (rule, source_class, index) in rules {
             match CompiledRule::compile(rule, source_class) {
-                Ok(compiled) => compiled_rules.push(compiled),
+                Ok(compiled) => compiled_rules.push(CompiledRule { index, ..compiled }),
                 Err(e) => {
                     // Log warning but continue with other rules
                     eprintln!("Warning: Failed to compile rule: {e}");
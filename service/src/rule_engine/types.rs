@@ -28,6 +28,8 @@ pub struct CompiledRule {
     pub priority: i32,
     /// Source class (for debugging and error messages)
     pub source_class: String,
+    /// Position of the rule in the source class's `rules` list
+    pub index: usize,
     /// Whether this rule is deactivated
     pub deactivated: bool,
 }
@@ -67,6 +69,7 @@ impl CompiledRule {
             else_condition_ast,
             priority,
             source_class,
+            index: 0,
             deactivated,
         })
     }
@@ -335,16 +338,19 @@ impl<'a> RuleExecutionContext<'a> {
     }
 
     /// Get expression evaluation context
-    #[must_use]
-    pub fn get_expression_context(&self) -> HashMap<String, Value> {
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the slots of the class cannot be resolved
+    pub fn get_expression_context(&self) -> linkml_core::error::Result<HashMap<String, Value>> {
         let mut context = HashMap::new();
 
         // First, add all slots for the class with null defaults
         let effective_slots = self
             .validation_context
-            .get_effective_slots(&self.class_name);
-        for (slot_name, _slot_def) in effective_slots {
-            context.insert(slot_name.to_string(), Value::Null);
+            .get_effective_slots(&self.class_name)?;
+        for (slot_name, _slot_def) in effective_slots.iter() {
+            context.insert(slot_name.clone(), Value::Null);
         }
 
         // Then override with actual instance data
@@ -367,7 +373,7 @@ impl<'a> RuleExecutionContext<'a> {
             context.insert("root".to_string(), root.clone());
         }
 
-        context
+        Ok(context)
     }
 }
 
@@ -393,6 +399,7 @@ impl Default for RuleExecutionStrategy {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use linkml_core::types::{ClassDefinition, SchemaDefinition};
 
     #[test]
     fn test_compiled_rule_creation() -> std::result::Result<(), Box<dyn std::error::Error>> {
@@ -410,25 +417,30 @@ mod tests {
     }
 
     #[test]
-    fn test_execution_context() {
+    fn test_execution_context() -> linkml_core::error::Result<()> {
         let instance = serde_json::json!({
             "name": "test",
             "value": 42
         });
 
-        let mut validation_ctx = ValidationContext::new(Arc::default());
+        let mut schema = SchemaDefinition::new("test");
+        schema
+            .classes
+            .insert("TestClass".to_string(), ClassDefinition::new("TestClass"));
+        let mut validation_ctx = ValidationContext::new(Arc::new(schema));
         let ctx = RuleExecutionContext::new(
             instance.clone(),
             "TestClass".to_string(),
             &mut validation_ctx,
         );
 
-        let expr_ctx = ctx.get_expression_context();
+        let expr_ctx = ctx.get_expression_context()?;
         assert_eq!(expr_ctx.get("name"), Some(&serde_json::json!("test")));
         assert_eq!(expr_ctx.get("value"), Some(&serde_json::json!(42)));
         assert_eq!(
             expr_ctx.get("_class"),
             Some(&serde_json::json!("TestClass"))
         );
+        Ok(())
     }
 }
@@ -565,19 +565,25 @@ impl LintRule for SlotConsistencyRule {
         for (class_name, class) in &schema.classes {
             for slot_name in &class.slots {
                 if !schema.slots.contains_key(slot_name) {
+                    let closest = closest_name(slot_name, schema.slots.keys().map(String::as_str));
+                    let mut message =
+                        format!("Class '{class_name}' references undefined slot '{slot_name}'");
+                    let suggestion = if let Some(closest) = closest {
+                        write!(message, "; did you mean '{closest}'?")
+                            .expect("write! to String should never fail");
+                        format!("Replace '{slot_name}' with '{closest}'")
+                    } else {
+                        format!("Define slot '{slot_name}' or remove the reference")
+                    };
                     issues.push(LintIssue {
                         rule: self.name().to_string(),
                         severity: self.severity(),
-                        message: format!(
-                            "Class '{class_name}' references undefined slot '{slot_name}'"
-                        ),
+                        message,
                         element_type: Some("class".to_string()),
                         element_name: Some(class_name.clone()),
                         line: None,
                         column: None,
-                        suggestion: Some(format!(
-                            "Define slot '{slot_name}' or remove the reference"
-                        )),
+                        suggestion: Some(suggestion),
                         fixable: false,
                     });
                 }
@@ -636,17 +642,28 @@ impl LintRule for TypeSafetyRule {
                     && !schema.types.contains_key(range)
                     && !schema.enums.contains_key(range)
                 {
+                    let known = builtin_types
+                        .into_iter()
+                        .chain(schema.classes.keys().map(String::as_str))
+                        .chain(schema.types.keys().map(String::as_str))
+                        .chain(schema.enums.keys().map(String::as_str));
+                    let mut message = format!("Slot '{slot_name}' has invalid range '{range}'");
+                    let suggestion = if let Some(closest) = closest_name(range, known) {
+                        write!(message, "; did you mean '{closest}'?")
+                            .expect("write! to String should never fail");
+                        format!("Replace '{range}' with '{closest}'")
+                    } else {
+                        "Use a valid built-in type or define the type".to_string()
+                    };
                     issues.push(LintIssue {
                         rule: self.name().to_string(),
                         severity: self.severity(),
-                        message: format!("Slot '{slot_name}' has invalid range '{range}'"),
+                        message,
                         element_type: Some("slot".to_string()),
                         element_name: Some(slot_name.clone()),
                         line: None,
                         column: None,
-                        suggestion: Some(
-                            "Use a valid built-in type or define the type".to_string(),
-                        ),
+                        suggestion: Some(suggestion),
                         fixable: false,
                     });
                 }
@@ -738,6 +755,35 @@ impl LintRule for SchemaMetadataRule {
 
 // Helper functions
 
+/// Find the known name closest to a misspelled `name`
+///
+/// Only names within an edit distance of a third of the length of `name`,
+/// rounded up, are considered, so unrelated names are never suggested.
+fn closest_name<'a>(name: &str, known: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+    let max_distance = name.chars().count().div_ceil(3);
+    known
+        .into_iter()
+        .map(|candidate| (edit_distance(name, candidate), candidate))
+        .filter(|&(distance, _)| distance <= max_distance)
+        .min_by_key(|&(distance, _)| distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Levenshtein distance between two strings, counted in characters
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, &b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
 fn to_pascal_case(s: &str) -> String {
     s.split('_')
         .map(|word| {
@@ -813,4 +859,45 @@ mod tests {
         assert!(issues[0].message.contains("never used"));
         assert!(issues[0].fixable);
     }
+
+    #[test]
+    fn test_undefined_references_suggest_closest_name() {
+        let mut schema = SchemaDefinition::default();
+        schema.slots.insert(
+            "email".to_string(),
+            SlotDefinition {
+                range: Some("Adress".to_string()),
+                ..SlotDefinition::default()
+            },
+        );
+        schema
+            .slots
+            .insert("name".to_string(), SlotDefinition::default());
+        schema.classes.insert(
+            "Person".to_string(),
+            ClassDefinition {
+                slots: vec!["emial".to_string(), "zzz".to_string()],
+                ..ClassDefinition::default()
+            },
+        );
+        schema
+            .classes
+            .insert("Address".to_string(), ClassDefinition::default());
+
+        let issues = SlotConsistencyRule.check(&schema);
+        assert_eq!(issues.len(), 2);
+        assert_eq!(
+            issues[0].message,
+            "Class 'Person' references undefined slot 'emial'; did you mean 'email'?"
+        );
+        assert!(!issues[1].message.contains("did you mean"));
+
+        let issues = TypeSafetyRule.check(&schema);
+        assert_eq!(issues.len(), 1);
+        assert!(
+            issues[0].message.ends_with("did you mean 'Address'?"),
+            "{}",
+            issues[0].message
+        );
+    }
 }
@@ -183,7 +183,7 @@ impl SchemaMerge {
 
                     // Resolve conflict
                     let resolved =
-                        self.resolve_class_conflict(existing, class, i, schemas.len())?;
+                        self.resolve_class_conflict(name, existing, class, i, schemas.len())?;
                     merged.classes.insert(name.clone(), resolved);
                 } else {
                     merged.classes.insert(name.clone(), class.clone());
@@ -200,7 +200,8 @@ impl SchemaMerge {
                     conflicts.push(conflict);
 
                     // Resolve conflict
-                    let resolved = self.resolve_slot_conflict(existing, slot, i, schemas.len())?;
+                    let resolved =
+                        self.resolve_slot_conflict(name, existing, slot, i, schemas.len())?;
                     merged.slots.insert(name.clone(), resolved);
                 } else {
                     merged.slots.insert(name.clone(), slot.clone());
@@ -218,7 +219,7 @@ impl SchemaMerge {
 
                     // Resolve conflict
                     let resolved =
-                        self.resolve_type_conflict(existing, type_def, i, schemas.len())?;
+                        self.resolve_type_conflict(name, existing, type_def, i, schemas.len())?;
                     merged.types.insert(name.clone(), resolved);
                 } else {
                     merged.types.insert(name.clone(), type_def.clone());
@@ -412,15 +413,14 @@ impl SchemaMerge {
     /// Resolve class conflict
     fn resolve_class_conflict(
         &self,
+        name: &str,
         existing: &ClassDefinition,
         new: &ClassDefinition,
         schema_index: usize,
         total_schemas: usize,
     ) -> Result<ClassDefinition> {
         match self.options.conflict_resolution {
-            ConflictResolution::Error => {
-                Err(LinkMLError::schema_validation("Class conflict".to_string()))
-            }
+            ConflictResolution::Error => Err(LinkMLError::duplicate_definition("Class", name)),
             ConflictResolution::First => Ok(existing.clone()),
             ConflictResolution::Last => {
                 if schema_index == total_schemas - 1 {
@@ -439,15 +439,14 @@ impl SchemaMerge {
     /// Resolve slot conflict
     fn resolve_slot_conflict(
         &self,
+        name: &str,
         existing: &SlotDefinition,
         new: &SlotDefinition,
         schema_index: usize,
         total_schemas: usize,
     ) -> Result<SlotDefinition> {
         match self.options.conflict_resolution {
-            ConflictResolution::Error => {
-                Err(LinkMLError::schema_validation("Slot conflict".to_string()))
-            }
+            ConflictResolution::Error => Err(LinkMLError::duplicate_definition("Slot", name)),
             ConflictResolution::First => Ok(existing.clone()),
             ConflictResolution::Last => {
                 if schema_index == total_schemas - 1 {
@@ -466,15 +465,14 @@ impl SchemaMerge {
     /// Resolve type conflict
     fn resolve_type_conflict(
         &self,
+        name: &str,
         existing: &TypeDefinition,
         new: &TypeDefinition,
         schema_index: usize,
         total_schemas: usize,
     ) -> Result<TypeDefinition> {
         match self.options.conflict_resolution {
-            ConflictResolution::Error => {
-                Err(LinkMLError::schema_validation("Type conflict".to_string()))
-            }
+            ConflictResolution::Error => Err(LinkMLError::duplicate_definition("Type", name)),
             ConflictResolution::First => Ok(existing.clone()),
             ConflictResolution::Last => {
                 if schema_index == total_schemas - 1 {
@@ -571,4 +569,37 @@ mod tests {
         assert!(!merged.classes.contains_key("Bike"));
         Ok(())
     }
+
+    #[test]
+    fn test_merge_conflict_reports_duplicate_definition() {
+        let mut schema1 = SchemaDefinition::default();
+        schema1.classes.insert(
+            "Person".to_string(),
+            ClassDefinition {
+                slots: vec!["name".to_string()],
+                ..Default::default()
+            },
+        );
+
+        let mut schema2 = SchemaDefinition::default();
+        schema2.classes.insert(
+            "Person".to_string(),
+            ClassDefinition {
+                slots: vec!["age".to_string()],
+                ..Default::default()
+            },
+        );
+
+        let error = SchemaMerge::new(MergeOptions::default())
+            .merge(&[schema1, schema2])
+            .expect_err("conflicting classes should not merge");
+        assert!(
+            matches!(
+                error,
+                LinkMLError::DuplicateDefinition { ref kind, ref name }
+                    if kind == "Class" && name == "Person"
+            ),
+            "{error}"
+        );
+    }
 }
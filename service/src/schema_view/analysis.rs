@@ -97,8 +97,8 @@ impl UsageIndex {
                 index.record_mixin_usage(mixin, &class_name);
             }
 
-            // Track slot usage
-            for slot_name in &class_def.slots {
+            // Track slot usage, attributes included
+            for slot_name in class_def.slots.iter().chain(class_def.attributes.keys()) {
                 index.record_slot_usage_by_class(slot_name, &class_name);
             }
 
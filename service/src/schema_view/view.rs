@@ -3,6 +3,7 @@
 use linkml_core::{
     error::{LinkMLError, Result},
     types::{ClassDefinition, EnumDefinition, SchemaDefinition, SlotDefinition, TypeDefinition},
+    utils::merge_slot_definitions,
 };
 use std::collections::{HashMap, HashSet};
 use std::path::Path;
@@ -12,6 +13,7 @@ use super::analysis::UsageIndex;
 use super::class_view::ClassView;
 use super::navigation::{NavigationCache, SlotResolution};
 use super::slot_view::SlotView;
+use crate::inheritance::InheritanceResolver;
 use crate::parser::{ImportResolver, ImportResolverV2, SchemaLoader};
 
 /// Type of schema element
@@ -253,14 +255,53 @@ impl SchemaView {
         Ok(descendants)
     }
 
-    /// Get all slots applicable to a class (including inherited)
-    /// Returns an error if the operation fails
+    /// Get the names of all slots applicable to a class
+    ///
+    /// Includes slots and attributes inherited through `is_a` and mixins, in
+    /// the order they were first introduced.
     ///
     /// # Errors
     ///
+    /// Returns an error if the class does not exist or its hierarchy is circular.
     pub fn class_slots(&self, class_name: &str) -> Result<Vec<String>> {
-        let induced = self.induced_class(class_name)?;
-        Ok(induced.slots)
+        Ok(self
+            .induced_slots(class_name)?
+            .into_iter()
+            .map(|slot| slot.name)
+            .collect())
+    }
+
+    /// Get the classes that use a slot, sorted by name
+    ///
+    /// A class uses a slot when it lists it in `slots` or declares it as an
+    /// attribute. With `include_induced`, classes that inherit the slot
+    /// through `is_a` or mixins are included as well.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a class hierarchy is circular.
+    pub fn get_classes_by_slot(
+        &self,
+        slot_name: &str,
+        include_induced: bool,
+    ) -> Result<Vec<String>> {
+        let mut classes = Vec::new();
+        for (class_name, class_def) in self.all_classes()? {
+            let uses_slot = if include_induced {
+                self.class_slots(&class_name)?
+                    .iter()
+                    .any(|name| name == slot_name)
+            } else {
+                class_def.slots.iter().any(|name| name == slot_name)
+                    || class_def.attributes.contains_key(slot_name)
+            };
+            if uses_slot {
+                classes.push(class_name);
+            }
+        }
+
+        classes.sort();
+        Ok(classes)
     }
 
     // === Slot Operations ===
@@ -279,16 +320,46 @@ impl SchemaView {
     }
 
     /// Get a specific slot definition
-    /// Returns an error if the operation fails
+    ///
+    /// Falls back to the first class attribute with that name when there is no
+    /// schema-level slot. Properties the slot leaves unset are inherited from
+    /// its `is_a` ancestors, nearest first.
     ///
     /// # Errors
     ///
+    /// Returns an error if the slot's `is_a` chain is circular.
     pub fn get_slot(&self, name: &str) -> Result<Option<SlotDefinition>> {
         let merged = self
             .merged_schema
             .read()
             .map_err(|_| SchemaViewError::CacheError("Failed to acquire read lock".into()))?;
-        Ok(merged.slots.get(name).cloned())
+
+        let Some(mut slot) = merged.slots.get(name).cloned().or_else(|| {
+            merged
+                .classes
+                .values()
+                .find_map(|class| class.attributes.get(name).cloned())
+        }) else {
+            return Ok(None);
+        };
+
+        let mut visited = HashSet::from([name.to_string()]);
+        let mut parent_name = slot.is_a.clone();
+        while let Some(parent_key) = parent_name {
+            if !visited.insert(parent_key.clone()) {
+                return Err(SchemaViewError::CircularDependency(format!(
+                    "Circular inheritance detected at slot '{parent_key}'"
+                ))
+                .into());
+            }
+            let Some(parent) = merged.slots.get(&parent_key) else {
+                break;
+            };
+            slot = merge_slot_definitions(parent, &slot);
+            parent_name = parent.is_a.clone();
+        }
+
+        Ok(Some(slot))
     }
 
     /// Get a fully resolved slot in the context of a specific class
@@ -301,27 +372,35 @@ impl SchemaView {
         resolution.resolve_slot(slot_name, class_name)
     }
 
-    /// Get the identifier slot for a class
-    /// Returns an error if the operation fails
+    /// Get every slot of a class with inheritance and `slot_usage` applied
+    ///
+    /// Ancestors are applied from most general to most specific, so later
+    /// mixins win over earlier ones and `slot_usage` wins over inherited
+    /// definitions.
     ///
     /// # Errors
     ///
-    pub fn get_identifier_slot(&self, class_name: &str) -> Result<Option<String>> {
-        let class_slots = self.class_slots(class_name)?;
+    /// Returns an error if the class does not exist or its hierarchy is circular.
+    pub fn induced_slots(&self, class_name: &str) -> Result<Vec<SlotDefinition>> {
         let merged = self
             .merged_schema
             .read()
             .map_err(|_| SchemaViewError::CacheError("Failed to acquire read lock".into()))?;
+        InheritanceResolver::new(&merged).induced_slots(class_name)
+    }
 
-        for slot_name in &class_slots {
-            if let Some(slot) = merged.slots.get(slot_name)
-                && slot.identifier.unwrap_or(false)
-            {
-                return Ok(Some(slot_name.clone()));
-            }
-        }
-
-        Ok(None)
+    /// Get the identifier slot for a class
+    /// Returns an error if the operation fails
+    ///
+    /// # Errors
+    ///
+    pub fn get_identifier_slot(&self, class_name: &str) -> Result<Option<String>> {
+        // Induced slots cover inline attributes as well as schema slots
+        Ok(self
+            .induced_slots(class_name)?
+            .into_iter()
+            .find(|slot| slot.identifier.unwrap_or(false))
+            .map(|slot| slot.name))
     }
 
     // === Enum Operations ===
@@ -733,33 +812,24 @@ impl SchemaView {
     }
 
     /// Expand a CURIE to its full URI form
-    /// Returns an error if the operation fails
+    ///
+    /// Uses [`SchemaDefinition::expand_curie`] on the merged schema, so a
+    /// bare name is expanded with `default_prefix`.
     ///
     /// # Errors
     ///
+    /// Returns an error if the merged schema lock is poisoned.
     pub fn expand_curie(&self, curie: &str) -> Result<String> {
-        if let Some(colon_pos) = curie.find(':') {
-            let prefix = &curie[..colon_pos];
-            let local = &curie[colon_pos + 1..];
-
-            let merged = self
-                .merged_schema
-                .read()
-                .map_err(|_| SchemaViewError::CacheError("Failed to acquire read lock".into()))?;
-
-            if let Some(prefix_uri) = merged.prefixes.get(prefix) {
-                let uri_str = match prefix_uri {
-                    linkml_core::types::PrefixDefinition::Simple(s) => s,
-                    linkml_core::types::PrefixDefinition::Complex { prefix_prefix, .. } => {
-                        prefix_prefix
-                    }
-                };
-                return Ok(format!("{uri_str}{local}"));
-            }
-        }
+        let merged = self
+            .merged_schema
+            .read()
+            .map_err(|_| SchemaViewError::CacheError("Failed to acquire read lock".into()))?;
 
-        // If not a CURIE or prefix not found, return as-is
-        Ok(curie.to_string())
+        // An undeclared prefix, or a bare name without a default prefix, is
+        // returned as-is
+        Ok(merged
+            .expand_curie(curie)
+            .unwrap_or_else(|| curie.to_string()))
     }
 
     // === Type Hierarchy Methods ===
@@ -1287,3 +1357,117 @@ impl SchemaView {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HYPERENTITY_SCHEMA: &str = r"
+id: https://textpast.org/schema/meta/entity/hyperentity
+name: hyperentity
+slots:
+  id:
+    identifier: true
+    range: string
+  label:
+    description: Human readable label
+    range: string
+  preferred_label:
+    is_a: label
+    required: true
+  created:
+    range: datetime
+  name:
+    range: string
+  birth_date:
+    range: date
+classes:
+  HyperEntity:
+    abstract: true
+    slots: [id, label]
+  Entity:
+    is_a: HyperEntity
+    slots: [created]
+  Named:
+    mixin: true
+    slots: [name]
+  Person:
+    is_a: Entity
+    mixins: [Named]
+    slots: [preferred_label, birth_date]
+  Place:
+    is_a: Entity
+    attributes:
+      coordinates:
+        range: string
+";
+
+    async fn hyperentity_view() -> anyhow::Result<SchemaView> {
+        let schema: SchemaDefinition = serde_yaml::from_str(HYPERENTITY_SCHEMA)?;
+        Ok(SchemaView::new(schema).await?)
+    }
+
+    #[tokio::test]
+    async fn test_class_hierarchy_queries() -> anyhow::Result<()> {
+        let view = hyperentity_view().await?;
+
+        assert!(view.get_class("Person")?.is_some());
+        assert!(view.get_class("Organization")?.is_none());
+        assert_eq!(view.class_ancestors("Person")?, ["Entity", "HyperEntity"]);
+
+        let mut descendants = view.class_descendants("HyperEntity")?;
+        descendants.sort();
+        assert_eq!(descendants, ["Entity", "Person", "Place"]);
+
+        assert_eq!(
+            view.class_slots("Person")?,
+            [
+                "id",
+                "label",
+                "created",
+                "name",
+                "preferred_label",
+                "birth_date"
+            ]
+        );
+        assert_eq!(
+            view.class_slots("Place")?,
+            ["id", "label", "created", "coordinates"]
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_slot_queries() -> anyhow::Result<()> {
+        let view = hyperentity_view().await?;
+
+        let preferred_label = view
+            .get_slot("preferred_label")?
+            .expect("preferred_label should exist");
+        assert_eq!(preferred_label.range.as_deref(), Some("string"));
+        assert_eq!(
+            preferred_label.description.as_deref(),
+            Some("Human readable label")
+        );
+        assert_eq!(preferred_label.required, Some(true));
+        assert_eq!(view.slot_parents("preferred_label")?, ["label"]);
+
+        let coordinates = view
+            .get_slot("coordinates")?
+            .expect("attributes should be found");
+        assert_eq!(coordinates.range.as_deref(), Some("string"));
+        assert!(view.get_slot("missing")?.is_none());
+
+        assert_eq!(view.get_classes_by_slot("label", false)?, ["HyperEntity"]);
+        assert_eq!(
+            view.get_classes_by_slot("label", true)?,
+            ["Entity", "HyperEntity", "Person", "Place"]
+        );
+        assert_eq!(view.get_classes_by_slot("coordinates", false)?, ["Place"]);
+
+        let index = view.usage_index()?;
+        let usage = index.get_usage("label").expect("label should be used");
+        assert_eq!(usage.used_by_classes, ["HyperEntity"]);
+        Ok(())
+    }
+}
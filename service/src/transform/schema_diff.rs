@@ -6,6 +6,7 @@
 use crate::utils::safe_cast::usize_to_f64;
 use linkml_core::prelude::*;
 use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
 use std::collections::{HashMap, HashSet};
 use std::fmt;
 use thiserror::Error;
@@ -521,7 +522,7 @@ impl SchemaDiffer {
             if let (Some(old_class), Some(new_class)) =
                 (old_schema.classes.get(name), new_schema.classes.get(name))
             {
-                Self::diff_class_definition(name, old_class, new_class, changes)?;
+                Self::diff_class_definition(name, old_class, new_class, new_schema, changes)?;
             }
         }
 
@@ -533,6 +534,7 @@ impl SchemaDiffer {
         name: &str,
         old_class: &ClassDefinition,
         new_class: &ClassDefinition,
+        new_schema: &SchemaDefinition,
         changes: &mut Vec<SchemaChange>,
     ) -> DiffResult<()> {
         let base_path = vec!["classes".to_string(), name.to_string()];
@@ -588,13 +590,81 @@ impl SchemaDiffer {
             let mut path = base_path.clone();
             path.push("attributes".to_string());
 
+            // A new required attribute rejects existing instances
+            let severity = if new_class.attributes[added].required == Some(true) {
+                ChangeSeverity::Major
+            } else {
+                ChangeSeverity::Compatible
+            };
+
             changes.push(SchemaChange {
                 change_type: ChangeType::Added,
                 element_type: "attribute".to_string(),
                 element_name: added.clone(),
                 path,
                 description: format!("Attribute '{added}' added to class '{name}'"),
-                severity: ChangeSeverity::Compatible,
+                severity,
+                old_value: None,
+                new_value: Some(added.clone()),
+                details: HashMap::new(),
+            });
+        }
+
+        for common in old_attrs.intersection(&new_attrs) {
+            let mut path = base_path.clone();
+            path.extend(["attributes".to_string(), common.clone()]);
+            Self::diff_slot_definition(
+                common,
+                &path,
+                &old_class.attributes[common],
+                &new_class.attributes[common],
+                changes,
+            )?;
+        }
+
+        // Compare slot references
+        let old_slots: HashSet<_> = old_class.slots.iter().cloned().collect();
+        let new_slots: HashSet<_> = new_class.slots.iter().cloned().collect();
+
+        for removed in old_slots.difference(&new_slots) {
+            let mut path = base_path.clone();
+            path.push("slots".to_string());
+
+            changes.push(SchemaChange {
+                change_type: ChangeType::Removed,
+                element_type: "slot".to_string(),
+                element_name: removed.clone(),
+                path,
+                description: format!("Slot '{removed}' removed from class '{name}'"),
+                severity: ChangeSeverity::Major,
+                old_value: Some(removed.clone()),
+                new_value: None,
+                details: HashMap::new(),
+            });
+        }
+
+        for added in new_slots.difference(&old_slots) {
+            let mut path = base_path.clone();
+            path.push("slots".to_string());
+
+            let required = new_class
+                .slot_usage
+                .get(added)
+                .and_then(|usage| usage.required)
+                .or_else(|| new_schema.slots.get(added).and_then(|slot| slot.required));
+            let severity = if required == Some(true) {
+                ChangeSeverity::Major
+            } else {
+                ChangeSeverity::Compatible
+            };
+
+            changes.push(SchemaChange {
+                change_type: ChangeType::Added,
+                element_type: "slot".to_string(),
+                element_name: added.clone(),
+                path,
+                description: format!("Slot '{added}' added to class '{name}'"),
+                severity,
                 old_value: None,
                 new_value: Some(added.clone()),
                 details: HashMap::new(),
@@ -686,7 +756,8 @@ impl SchemaDiffer {
             if let (Some(old_slot), Some(new_slot)) =
                 (old_schema.slots.get(name), new_schema.slots.get(name))
             {
-                Self::diff_slot_definition(name, old_slot, new_slot, changes)?;
+                let base_path = ["slots".to_string(), name.clone()];
+                Self::diff_slot_definition(name, &base_path, old_slot, new_slot, changes)?;
             }
         }
 
@@ -694,13 +765,17 @@ impl SchemaDiffer {
     }
 
     /// Compare two slot definitions
+    ///
+    /// `base_path` locates the slot, either under `slots` or as an attribute
+    /// of a class.
     fn diff_slot_definition(
         name: &str,
+        base_path: &[String],
         old_slot: &SlotDefinition,
         new_slot: &SlotDefinition,
         changes: &mut Vec<SchemaChange>,
     ) -> DiffResult<()> {
-        let base_path = vec!["slots".to_string(), name.to_string()];
+        let base_path = base_path.to_vec();
 
         // Compare range
         if old_slot.range != new_slot.range {
@@ -725,7 +800,7 @@ impl SchemaDiffer {
             let mut path = base_path.clone();
             path.push("required".to_string());
 
-            let severity = if old_slot.required == Some(false) && new_slot.required == Some(true) {
+            let severity = if old_slot.required != Some(true) && new_slot.required == Some(true) {
                 ChangeSeverity::Major // Making optional field required is breaking
             } else {
                 ChangeSeverity::Compatible // Making required field optional is compatible
@@ -744,7 +819,8 @@ impl SchemaDiffer {
             });
         }
 
-        // Compare multivalued
+        // Compare multivalued; either direction changes the shape of the
+        // values, so existing data no longer fits
         if old_slot.multivalued != new_slot.multivalued {
             let mut path = base_path.clone();
             path.push("multivalued".to_string());
@@ -762,9 +838,118 @@ impl SchemaDiffer {
             });
         }
 
+        // Compare pattern
+        if old_slot.pattern != new_slot.pattern {
+            let severity = match (&old_slot.pattern, &new_slot.pattern) {
+                (Some(_), None) => ChangeSeverity::Compatible, // Dropping a pattern loosens
+                // A new or different pattern may reject existing data; whether
+                // it narrows the old one is not decidable here
+                _ => ChangeSeverity::Major,
+            };
+            let mut path = base_path.clone();
+            path.push("pattern".to_string());
+
+            changes.push(SchemaChange {
+                change_type: ChangeType::Modified,
+                element_type: "slot".to_string(),
+                element_name: name.to_string(),
+                path,
+                description: format!("Pattern changed for slot '{name}'"),
+                severity,
+                old_value: old_slot.pattern.clone(),
+                new_value: new_slot.pattern.clone(),
+                details: HashMap::new(),
+            });
+        }
+
+        // Compare value, length and cardinality bounds
+        let bounds = [
+            (
+                "minimum_value",
+                old_slot.minimum_value.as_ref().and_then(JsonValue::as_f64),
+                new_slot.minimum_value.as_ref().and_then(JsonValue::as_f64),
+                true,
+            ),
+            (
+                "maximum_value",
+                old_slot.maximum_value.as_ref().and_then(JsonValue::as_f64),
+                new_slot.maximum_value.as_ref().and_then(JsonValue::as_f64),
+                false,
+            ),
+            (
+                "min_length",
+                old_slot.min_length.map(usize_to_f64),
+                new_slot.min_length.map(usize_to_f64),
+                true,
+            ),
+            (
+                "max_length",
+                old_slot.max_length.map(usize_to_f64),
+                new_slot.max_length.map(usize_to_f64),
+                false,
+            ),
+            (
+                "minimum_cardinality",
+                old_slot.minimum_cardinality.map(f64::from),
+                new_slot.minimum_cardinality.map(f64::from),
+                true,
+            ),
+            (
+                "maximum_cardinality",
+                old_slot.maximum_cardinality.map(f64::from),
+                new_slot.maximum_cardinality.map(f64::from),
+                false,
+            ),
+        ];
+
+        for (field, old_bound, new_bound, is_lower) in bounds {
+            if old_bound == new_bound {
+                continue;
+            }
+            let mut path = base_path.clone();
+            path.push(field.to_string());
+
+            changes.push(SchemaChange {
+                change_type: ChangeType::Modified,
+                element_type: "slot".to_string(),
+                element_name: name.to_string(),
+                path,
+                description: format!("Constraint '{field}' changed for slot '{name}'"),
+                severity: Self::bound_change_severity(old_bound, new_bound, is_lower),
+                old_value: old_bound.map(|v| v.to_string()),
+                new_value: new_bound.map(|v| v.to_string()),
+                details: HashMap::new(),
+            });
+        }
+
         Ok(())
     }
 
+    /// Classify a change to a numeric bound
+    ///
+    /// Raising a lower bound or lowering an upper bound (including adding a
+    /// bound where there was none) can reject existing data and is breaking;
+    /// the opposite direction only loosens the constraint.
+    fn bound_change_severity(old: Option<f64>, new: Option<f64>, is_lower: bool) -> ChangeSeverity {
+        let tightened = match (old, new) {
+            (_, None) => false,
+            (None, Some(_)) => true,
+            (Some(old), Some(new)) => {
+                if is_lower {
+                    new > old
+                } else {
+                    new < old
+                }
+            }
+        };
+
+        if tightened {
+            ChangeSeverity::Major
+        } else {
+            ChangeSeverity::Compatible
+        }
+    }
+
     /// Compare types
     fn diff_types(
         old_schema: &SchemaDefinition,
@@ -990,6 +1175,46 @@ impl Default for SchemaDiffer {
     }
 }
 
+/// A single classified change, as listed by [`SchemaDiff::items`]
+pub type DiffItem = SchemaChange;
+
+impl SchemaDiff {
+    /// Whether any change is backward-incompatible
+    ///
+    /// Intended for gating CI on schema changes that require a major version
+    /// bump.
+    #[must_use]
+    pub fn is_breaking(&self) -> bool {
+        !self.breaking_changes.is_empty()
+    }
+
+    /// All detected changes with their paths, kinds and severities
+    #[must_use]
+    pub fn items(&self) -> &[DiffItem] {
+        &self.changes
+    }
+}
+
+/// Compare two versions of a schema and classify every change
+///
+/// Uses the default [`SchemaDiffer`] configuration without rename
+/// detection, so a renamed element is reported as a removal plus an addition.
+///
+/// # Errors
+///
+/// Returns `DiffError::InvalidComparison` if either schema has no name, or
+/// `DiffError::AnalysisError` if neither schema defines any classes.
+pub fn schema_diff(
+    old_schema: &SchemaDefinition,
+    new_schema: &SchemaDefinition,
+) -> DiffResult<SchemaDiff> {
+    SchemaDiffer::new(DiffConfig {
+        detect_renames: false,
+        ..DiffConfig::default()
+    })
+    .diff(old_schema, new_schema)
+}
+
 impl fmt::Display for SchemaDiff {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         writeln!(f, "Schema Diff Summary:")?;
@@ -1152,4 +1377,165 @@ mod tests {
         assert!(!diff.breaking_changes.is_empty());
         Ok(())
     }
+
+    #[test]
+    fn test_tightened_constraints_are_breaking()
+    -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let mut old_schema = create_test_schema("test");
+        old_schema.slots.insert(
+            "age".to_string(),
+            SlotDefinition {
+                name: "age".to_string(),
+                range: Some("integer".to_string()),
+                minimum_value: Some(serde_json::json!(0)),
+                ..Default::default()
+            },
+        );
+
+        let mut new_schema = old_schema.clone();
+        if let Some(slot) = new_schema.slots.get_mut("age") {
+            slot.required = Some(true);
+            slot.minimum_value = Some(serde_json::json!(18));
+        }
+        if let Some(slot) = new_schema.slots.get_mut("name") {
+            slot.pattern = Some("^[A-Z]".to_string());
+        }
+
+        let diff = schema_diff(&old_schema, &new_schema)?;
+
+        assert!(diff.is_breaking());
+        assert_eq!(diff.items().len(), 3);
+        assert!(
+            diff.items()
+                .iter()
+                .all(|item| item.severity == ChangeSeverity::Major)
+        );
+        assert!(
+            diff.items()
+                .iter()
+                .any(|item| item.path == ["slots", "age", "required"]
+                    && item.change_type == ChangeType::Modified)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_tightened_patterns_and_cardinality_are_breaking()
+    -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let mut old_schema = create_test_schema("test");
+        old_schema.slots.insert(
+            "aliases".to_string(),
+            SlotDefinition {
+                name: "aliases".to_string(),
+                range: Some("string".to_string()),
+                multivalued: Some(true),
+                maximum_cardinality: Some(5),
+                ..Default::default()
+            },
+        );
+        if let Some(slot) = old_schema.slots.get_mut("name") {
+            slot.pattern = Some("^[A-Za-z]+$".to_string());
+        }
+
+        let mut new_schema = old_schema.clone();
+        if let Some(slot) = new_schema.slots.get_mut("aliases") {
+            slot.minimum_cardinality = Some(1);
+            slot.maximum_cardinality = Some(3);
+        }
+        if let Some(slot) = new_schema.slots.get_mut("name") {
+            slot.pattern = Some("^[A-Z]+$".to_string());
+        }
+
+        let diff = schema_diff(&old_schema, &new_schema)?;
+        let severity = |slot: &str, field: &str| {
+            diff.items()
+                .iter()
+                .find(|item| item.path == ["slots", slot, field])
+                .map(|item| item.severity)
+        };
+        assert_eq!(severity("name", "pattern"), Some(ChangeSeverity::Major));
+        assert_eq!(
+            severity("aliases", "minimum_cardinality"),
+            Some(ChangeSeverity::Major)
+        );
+        assert_eq!(
+            severity("aliases", "maximum_cardinality"),
+            Some(ChangeSeverity::Major)
+        );
+
+        // A list slot turned single-valued no longer accepts existing lists
+        let mut single = old_schema.clone();
+        if let Some(slot) = single.slots.get_mut("aliases") {
+            slot.multivalued = Some(false);
+        }
+        let diff = schema_diff(&old_schema, &single)?;
+        assert!(diff.is_breaking());
+        assert!(diff.items().iter().any(|item| {
+            item.path == ["slots", "aliases", "multivalued"]
+                && item.severity == ChangeSeverity::Major
+        }));
+
+        // Loosening the cardinality is compatible
+        let mut loosened = old_schema.clone();
+        if let Some(slot) = loosened.slots.get_mut("aliases") {
+            slot.maximum_cardinality = None;
+        }
+        assert!(!schema_diff(&old_schema, &loosened)?.is_breaking());
+        Ok(())
+    }
+
+    #[test]
+    fn test_loosened_constraints_are_compatible()
+    -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let mut old_schema = create_test_schema("test");
+        if let Some(slot) = old_schema.slots.get_mut("name") {
+            slot.pattern = Some("^[A-Z]".to_string());
+            slot.max_length = Some(50);
+        }
+
+        let mut new_schema = old_schema.clone();
+        if let Some(slot) = new_schema.slots.get_mut("name") {
+            slot.pattern = None;
+            slot.max_length = Some(100);
+        }
+        if let Some(class) = new_schema.classes.get_mut("Person") {
+            class.attributes.insert(
+                "nickname".to_string(),
+                SlotDefinition {
+                    name: "nickname".to_string(),
+                    range: Some("string".to_string()),
+                    ..Default::default()
+                },
+            );
+        }
+
+        let diff = schema_diff(&old_schema, &new_schema)?;
+
+        assert!(!diff.is_breaking());
+        assert_eq!(diff.stats.compatible_changes, 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_required_attribute_added_is_breaking()
+    -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let old_schema = create_test_schema("test");
+        let mut new_schema = old_schema.clone();
+        if let Some(class) = new_schema.classes.get_mut("Person") {
+            class.attributes.insert(
+                "email".to_string(),
+                SlotDefinition {
+                    name: "email".to_string(),
+                    required: Some(true),
+                    ..Default::default()
+                },
+            );
+        }
+
+        let diff = schema_diff(&old_schema, &new_schema)?;
+
+        assert!(diff.is_breaking());
+        assert_eq!(diff.breaking_changes[0].element_name, "email");
+        Ok(())
+    }
 }
@@ -3,13 +3,127 @@
 //! This module provides simplified TypeDB integration using the official TypeDB driver.
 //! It handles connection management, database operations, and TypeQL generation.
 
+use crate::inheritance::InheritanceResolver;
 use crate::loader::DataInstance;
+use linkml_core::types::{SchemaDefinition, SlotDefinition};
 use std::collections::HashMap;
+use std::fmt;
 use std::path::Path;
+use typedb_driver::answer::ConceptRow;
+use typedb_driver::concept::Concept;
 use typedb_driver::{
     Credentials, DriverOptions, TypeDBDriver, TransactionType,
 };
 
+/// Value bound to a query variable in a TypeDB result row
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConceptValue {
+    /// Integer attribute or value
+    Long(i64),
+    /// Floating point attribute or value
+    Double(f64),
+    /// String attribute or value
+    String(String),
+    /// Boolean attribute or value
+    Boolean(bool),
+    /// Datetime attribute or value
+    Datetime(chrono::NaiveDateTime),
+    /// Entity or relation instance
+    Instance {
+        /// Label of the instance's type
+        type_label: String,
+        /// Internal instance identifier
+        iid: String,
+    },
+    /// Type (entity, relation, attribute or role type)
+    Type(String),
+    /// Any other value kind (decimal, date, duration, ...), rendered as text
+    Other(String),
+}
+
+impl ConceptValue {
+    /// Convert a concept returned by the driver
+    #[must_use]
+    pub fn from_concept(concept: &Concept) -> Self {
+        if concept.is_type() {
+            return Self::Type(concept.get_label().to_string());
+        }
+        if let Some(value) = concept.try_get_integer() {
+            return Self::Long(value);
+        }
+        if let Some(value) = concept.try_get_double() {
+            return Self::Double(value);
+        }
+        if let Some(value) = concept.try_get_boolean() {
+            return Self::Boolean(value);
+        }
+        if let Some(value) = concept.try_get_string() {
+            return Self::String(value.to_string());
+        }
+        if let Some(value) = concept.try_get_datetime() {
+            return Self::Datetime(value);
+        }
+        if let Some(value) = concept.try_get_value() {
+            return Self::Other(value.to_string());
+        }
+        match concept.try_get_iid() {
+            Some(iid) => Self::Instance {
+                type_label: concept.get_label().to_string(),
+                iid: iid.to_string(),
+            },
+            None => Self::Other(concept.get_label().to_string()),
+        }
+    }
+
+    /// The string value, if this is a string attribute
+    #[must_use]
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ConceptValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Long(n) => write!(f, "{n}"),
+            Self::Double(n) => write!(f, "{n}"),
+            Self::String(s) | Self::Type(s) | Self::Other(s) => f.write_str(s),
+            Self::Boolean(b) => write!(f, "{b}"),
+            Self::Datetime(dt) => write!(f, "{}", dt.format("%Y-%m-%dT%H:%M:%S%.f")),
+            Self::Instance { type_label, iid } => write!(f, "{type_label}:{iid}"),
+        }
+    }
+}
+
+/// Decode a result row into a map keyed by variable name (without `$`)
+///
+/// Variables left unbound by optional patterns are omitted.
+fn decode_row(
+    row: &ConceptRow,
+) -> Result<HashMap<String, ConceptValue>, Box<dyn std::error::Error>> {
+    let mut values = HashMap::new();
+    for column in row.get_column_names() {
+        if let Some(concept) = row.get(column)? {
+            values.insert(column.clone(), ConceptValue::from_concept(concept));
+        }
+    }
+    Ok(values)
+}
+
+/// Outcome of a chunked, transactional batch insert
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BatchInsertResult {
+    /// Statements committed to the database
+    pub inserted: usize,
+    /// Statements rolled back because their chunk failed
+    pub failed: usize,
+    /// One message per failed chunk
+    pub errors: Vec<String>,
+}
+
 /// Helper for TypeDB operations
 pub struct TypeDBHelper {
     driver: TypeDBDriver,
@@ -103,27 +217,105 @@ impl TypeDBHelper {
         Ok(inserted)
     }
 
+    /// Insert statements in chunks, one write transaction per chunk
+    ///
+    /// Each chunk of up to `chunk_size` statements runs in a single write
+    /// transaction that is committed once all of its statements succeed. If a
+    /// statement or the commit fails, the whole chunk is rolled back and
+    /// counted as failed; loading then continues with the next chunk when
+    /// `continue_on_error` is set, and stops with an error otherwise.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use linkml_service::typedb_helper::TypeDBHelper;
+    /// # async fn example(statements: Vec<String>) -> Result<(), Box<dyn std::error::Error>> {
+    /// let typedb = TypeDBHelper::connect("localhost:1729").await?;
+    /// let result = typedb
+    ///     .insert_batch_transactional("test_db", &statements, 500, true)
+    ///     .await?;
+    /// println!("{} inserted, {} failed", result.inserted, result.failed);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn insert_batch_transactional(
+        &self,
+        database: &str,
+        typeql_statements: &[String],
+        chunk_size: usize,
+        continue_on_error: bool,
+    ) -> Result<BatchInsertResult, Box<dyn std::error::Error>> {
+        if chunk_size == 0 {
+            return Err("Chunk size must be greater than zero".into());
+        }
+
+        let mut result = BatchInsertResult::default();
+
+        for (chunk_index, chunk) in typeql_statements.chunks(chunk_size).enumerate() {
+            match self.insert_chunk(database, chunk).await {
+                Ok(()) => result.inserted += chunk.len(),
+                Err(e) => {
+                    result.failed += chunk.len();
+                    let message = format!(
+                        "Chunk {} ({} statements) rolled back: {}",
+                        chunk_index + 1,
+                        chunk.len(),
+                        e
+                    );
+                    if !continue_on_error {
+                        return Err(message.into());
+                    }
+                    eprintln!("  ⚠ {}", message);
+                    result.errors.push(message);
+                }
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Run statements in one write transaction, committing only if all succeed
+    ///
+    /// Dropping the transaction without committing discards its changes.
+    async fn insert_chunk(
+        &self,
+        database: &str,
+        typeql_statements: &[String],
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let transaction = self
+            .driver
+            .transaction(database, TransactionType::Write)
+            .await?;
+        for typeql in typeql_statements {
+            transaction.query(typeql.as_str()).await?;
+        }
+        transaction.commit().await?;
+        Ok(())
+    }
+
     /// Query instances from TypeDB
     ///
     /// Executes a TypeQL match query and returns the results as a vector of HashMaps.
-    /// Each HashMap represents one row, with variable names as keys and their values as strings.
+    /// Each HashMap represents one row, with variable names as keys and the bound
+    /// concepts as values.
     ///
     /// # Arguments
     /// * `database` - Name of the database to query
-    /// * `typeql` - TypeQL match query (e.g., "match $x isa person; get $x;")
+    /// * `typeql` - TypeQL match query (e.g., "match $x isa person, has name $name;")
     ///
     /// # Returns
     /// Vector of HashMaps, where each HashMap represents one result row.
-    /// Keys are variable names (without $), values are string representations.
+    /// Keys are variable names (without $), values are [`ConceptValue`]s.
     ///
     /// # Example
     /// ```no_run
     /// # use linkml_service::typedb_helper::TypeDBHelper;
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let typedb = TypeDBHelper::connect("localhost:1729").await?;
-    /// let results = typedb.query_match("test_db", "match $x isa person; get $x;").await?;
+    /// let results = typedb.query_match("test_db", "match $x isa person, has name $name;").await?;
     /// for row in results {
-    ///     println!("Result: {:?}", row);
+    ///     if let Some(name) = row.get("name") {
+    ///         println!("Name: {name}");
+    ///     }
     /// }
     /// # Ok(())
     /// # }
@@ -132,7 +324,7 @@ impl TypeDBHelper {
         &self,
         database: &str,
         typeql: &str,
-    ) -> Result<Vec<HashMap<String, String>>, Box<dyn std::error::Error>> {
+    ) -> Result<Vec<HashMap<String, ConceptValue>>, Box<dyn std::error::Error>> {
         use futures::stream::StreamExt;
 
         let transaction = self.driver.transaction(database, TransactionType::Read).await?;
@@ -147,18 +339,7 @@ impl TypeDBHelper {
             // Iterate through all rows in the stream
             while let Some(row_result) = stream.next().await {
                 match row_result {
-                    Ok(row) => {
-                        let mut row_map = HashMap::new();
-
-                        // Extract all variables from the row
-                        // Note: TypeDB Row API provides access to concepts by variable name
-                        // For now, we convert the row to a debug string representation
-                        // A full implementation would iterate through row.get() for each variable
-                        let row_str = format!("{:?}", row);
-                        row_map.insert("_row".to_string(), row_str);
-
-                        results.push(row_map);
-                    }
+                    Ok(row) => results.push(decode_row(&row)?),
                     Err(e) => {
                         eprintln!("  ⚠ Error reading row from TypeDB: {}", e);
                         // Continue with next row instead of failing entire query
@@ -195,12 +376,26 @@ impl TypeDBHelper {
 
 /// Convert LinkML instance to TypeQL insert statement
 ///
+/// Values are written as literals of the TypeDB value type their slot range
+/// maps to: integer and float ranges as bare numbers, boolean ranges as
+/// `true`/`false` and date/datetime ranges as datetime literals. String-like
+/// ranges, and slots the schema does not describe, are quoted and escaped.
+/// Multivalued slots produce one `has` clause per element and null values are
+/// omitted.
+///
+/// # Errors
+///
+/// Returns an error if the class hierarchy cannot be resolved or a value
+/// cannot be represented in its slot's value type.
+///
 /// # Example
 /// ```no_run
 /// use linkml_service::typedb_helper::instance_to_typeql;
 /// use linkml_service::loader::DataInstance;
+/// use linkml_core::types::SchemaDefinition;
 /// use std::collections::HashMap;
 ///
+/// let schema = SchemaDefinition::new("languages");
 /// let mut data = HashMap::new();
 /// data.insert("label".to_string(), serde_json::json!("English"));
 /// data.insert("part1".to_string(), serde_json::json!("en"));
@@ -212,15 +407,28 @@ impl TypeDBHelper {
 ///     metadata: HashMap::new(),
 /// };
 ///
-/// let typeql = instance_to_typeql(&instance).unwrap();
+/// let typeql = instance_to_typeql(&instance, &schema).unwrap();
 /// // Result: "insert $x isa translation, has id \"eng\", has label \"English\", has part1 \"en\";"
 /// ```
 pub fn instance_to_typeql(
     instance: &DataInstance,
+    schema: &SchemaDefinition,
 ) -> Result<String, Box<dyn std::error::Error>> {
     let type_name = to_snake_case(&instance.class_name);
     let mut typeql = format!("insert $x isa {}", type_name);
 
+    // Ranges of the class's slots, including inherited ones
+    let class_slots: HashMap<String, SlotDefinition> =
+        if schema.classes.contains_key(&instance.class_name) {
+            InheritanceResolver::new(schema)
+                .induced_slots(&instance.class_name)?
+                .into_iter()
+                .map(|slot| (slot.name.clone(), slot))
+                .collect()
+        } else {
+            HashMap::new()
+        };
+
     // Add ID if present
     if let Some(id) = &instance.id {
         typeql.push_str(&format!(", has id \"{}\"", escape_string(id)));
@@ -229,14 +437,112 @@ pub fn instance_to_typeql(
     // Add attributes
     for (key, value) in &instance.data {
         let attr_name = to_snake_case(key);
-        let value_str = value_to_string(value);
-        typeql.push_str(&format!(", has {} \"{}\"", attr_name, escape_string(&value_str)));
+        let kind = class_slots
+            .get(key)
+            .or_else(|| schema.slots.get(key))
+            .and_then(|slot| slot.range.as_deref())
+            .map_or(ValueKind::String, |range| value_kind(range, schema));
+
+        let values = match value {
+            serde_json::Value::Array(items) => items.iter().collect(),
+            single => vec![single],
+        };
+        for item in values.into_iter().filter(|item| !item.is_null()) {
+            let literal = typeql_literal(item, kind)
+                .map_err(|e| format!("Slot '{key}' of {}: {e}", instance.class_name))?;
+            typeql.push_str(&format!(", has {attr_name} {literal}"));
+        }
     }
 
     typeql.push(';');
     Ok(typeql)
 }
 
+/// TypeDB value type a slot range maps to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ValueKind {
+    String,
+    Long,
+    Double,
+    Boolean,
+    Datetime,
+}
+
+/// Map a LinkML range to its TypeDB value type, following custom types to
+/// their base type (same mapping as the TypeQL generator)
+fn value_kind(range: &str, schema: &SchemaDefinition) -> ValueKind {
+    match schema.base_type(range) {
+        "integer" | "int" => ValueKind::Long,
+        "float" | "double" | "decimal" | "number" => ValueKind::Double,
+        "boolean" | "bool" => ValueKind::Boolean,
+        "date" | "datetime" | "time" => ValueKind::Datetime,
+        _ => ValueKind::String,
+    }
+}
+
+/// Render a JSON value as a TypeQL literal of the given value type
+fn typeql_literal(value: &serde_json::Value, kind: ValueKind) -> Result<String, String> {
+    let raw = value_to_string(value);
+    let text = raw.trim();
+    match kind {
+        ValueKind::String => Ok(format!("\"{}\"", escape_string(&raw))),
+        ValueKind::Long => value
+            .as_i64()
+            .or_else(|| text.parse().ok())
+            .map(|n: i64| n.to_string())
+            .ok_or_else(|| format!("'{text}' is not an integer")),
+        ValueKind::Double => value
+            .as_f64()
+            .or_else(|| text.parse().ok())
+            .filter(|n: &f64| n.is_finite())
+            // TypeDB reads a literal without a decimal point as a long
+            .map(|n| {
+                let literal = format!("{n:?}");
+                if literal.contains('.') {
+                    literal
+                } else {
+                    literal.replacen('e', ".0e", 1)
+                }
+            })
+            .ok_or_else(|| format!("'{text}' is not a number")),
+        ValueKind::Boolean => match value {
+            serde_json::Value::Bool(b) => Ok(b.to_string()),
+            _ => match text.to_lowercase().as_str() {
+                "true" => Ok("true".to_string()),
+                "false" => Ok("false".to_string()),
+                _ => Err(format!("'{text}' is not a boolean")),
+            },
+        },
+        ValueKind::Datetime => parse_datetime(text)
+            .map(|dt| dt.format("%Y-%m-%dT%H:%M:%S%.f").to_string())
+            .ok_or_else(|| format!("'{text}' is not a date or datetime")),
+    }
+}
+
+/// Parse a date, time or datetime into the naive datetime TypeDB stores
+///
+/// Datetimes with an offset are converted to UTC, dates are taken at
+/// midnight and bare times are anchored at the Unix epoch date.
+fn parse_datetime(text: &str) -> Option<chrono::NaiveDateTime> {
+    use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime};
+
+    DateTime::parse_from_rfc3339(text)
+        .map(|dt| dt.naive_utc())
+        .ok()
+        .or_else(|| NaiveDateTime::parse_from_str(text, "%Y-%m-%dT%H:%M:%S%.f").ok())
+        .or_else(|| NaiveDateTime::parse_from_str(text, "%Y-%m-%d %H:%M:%S%.f").ok())
+        .or_else(|| {
+            NaiveDate::parse_from_str(text, "%Y-%m-%d")
+                .ok()
+                .map(|date| date.and_time(NaiveTime::MIN))
+        })
+        .or_else(|| {
+            NaiveTime::parse_from_str(text, "%H:%M:%S%.f")
+                .ok()
+                .map(|time| NaiveDate::default().and_time(time))
+        })
+}
+
 /// Convert snake_case to CamelCase
 fn to_snake_case(s: &str) -> String {
     let mut result = String::new();
@@ -300,11 +606,219 @@ mod tests {
             metadata: HashMap::new(),
         };
 
-        let typeql = instance_to_typeql(&instance).unwrap();
+        let schema = SchemaDefinition::new("languages");
+        let typeql = instance_to_typeql(&instance, &schema).unwrap();
         assert!(typeql.contains("insert $x isa translation"));
         assert!(typeql.contains("has id \"eng\""));
         assert!(typeql.contains("has label \"English\""));
         assert!(typeql.contains("has part1 \"en\""));
     }
-}
 
+    #[test]
+    fn test_instance_to_typeql_uses_slot_ranges() {
+        let mut schema = SchemaDefinition::new("languages");
+        for (name, range) in [
+            ("label", "string"),
+            ("rank", "integer"),
+            ("speakers", "float"),
+            ("official", "boolean"),
+            ("recorded", "datetime"),
+            ("aliases", "string"),
+        ] {
+            schema.slots.insert(
+                name.to_string(),
+                SlotDefinition {
+                    range: Some(range.to_string()),
+                    multivalued: (name == "aliases").then_some(true),
+                    ..SlotDefinition::new(name)
+                },
+            );
+        }
+        schema.classes.insert(
+            "Translation".to_string(),
+            linkml_core::types::ClassDefinition {
+                slots: schema.slots.keys().cloned().collect(),
+                ..linkml_core::types::ClassDefinition::new("Translation")
+            },
+        );
+
+        let mut data = HashMap::new();
+        data.insert("label".to_string(), serde_json::json!("Say \"hi\""));
+        data.insert("rank".to_string(), serde_json::json!(3));
+        data.insert("speakers".to_string(), serde_json::json!("1500"));
+        data.insert("official".to_string(), serde_json::json!(true));
+        data.insert(
+            "recorded".to_string(),
+            serde_json::json!("2024-05-01T12:30:00+02:00"),
+        );
+        data.insert("aliases".to_string(), serde_json::json!(["en", "eng"]));
+        let instance = DataInstance {
+            class_name: "Translation".to_string(),
+            id: None,
+            data,
+            metadata: HashMap::new(),
+        };
+
+        let typeql = instance_to_typeql(&instance, &schema).unwrap();
+        assert!(typeql.contains("has label \"Say \\\"hi\\\"\""));
+        assert!(typeql.contains("has rank 3"));
+        assert!(!typeql.contains("has rank \"3\""));
+        assert!(typeql.contains("has speakers 1500.0"));
+        assert!(typeql.contains("has official true"));
+        assert!(typeql.contains("has recorded 2024-05-01T10:30:00"));
+        assert!(typeql.contains("has aliases \"en\""));
+        assert!(typeql.contains("has aliases \"eng\""));
+
+        instance_to_typeql(
+            &DataInstance {
+                data: HashMap::from([("rank".to_string(), serde_json::json!("first"))]),
+                ..instance
+            },
+            &schema,
+        )
+        .unwrap_err();
+    }
+
+    #[test]
+    fn test_concept_value_display() {
+        assert_eq!(ConceptValue::Long(42).to_string(), "42");
+        assert_eq!(ConceptValue::String("eng".to_string()).to_string(), "eng");
+        assert_eq!(ConceptValue::Boolean(true).to_string(), "true");
+        assert_eq!(
+            ConceptValue::String("eng".to_string()).as_str(),
+            Some("eng")
+        );
+        assert_eq!(ConceptValue::Long(1).as_str(), None);
+    }
+
+    #[cfg(feature = "typedb")]
+    #[tokio::test]
+    async fn test_insert_and_query_translation() -> Result<(), Box<dyn std::error::Error>> {
+        let address =
+            std::env::var("TYPEDB_ADDRESS").unwrap_or_else(|_| "localhost:1729".to_string());
+        let database = format!("linkml_helper_test_{}", uuid::Uuid::new_v4().simple());
+
+        let typedb = TypeDBHelper::connect(&address).await?;
+        typedb.ensure_database(&database).await?;
+        typedb
+            .define_schema(
+                &database,
+                "define
+                    attribute id, value string;
+                    attribute label, value string;
+                    attribute part1, value string;
+                    entity translation, owns id @key, owns label, owns part1;",
+            )
+            .await?;
+
+        let schema = SchemaDefinition::new("languages");
+        let mut data = HashMap::new();
+        data.insert("label".to_string(), serde_json::json!("English"));
+        data.insert("part1".to_string(), serde_json::json!("en"));
+        let instance = DataInstance {
+            class_name: "Translation".to_string(),
+            id: Some("eng".to_string()),
+            data,
+            metadata: HashMap::new(),
+        };
+        typedb
+            .insert_instance(&database, &instance_to_typeql(&instance, &schema)?)
+            .await?;
+
+        let rows = typedb
+            .query_match(
+                &database,
+                "match $t isa translation, has id $id, has label $label, has part1 $part1;",
+            )
+            .await?;
+        typedb
+            .driver
+            .databases()
+            .get(&database)
+            .await?
+            .delete()
+            .await?;
+
+        assert_eq!(rows.len(), 1);
+        let row = &rows[0];
+        assert_eq!(
+            row.get("id"),
+            Some(&ConceptValue::String("eng".to_string()))
+        );
+        assert_eq!(
+            row.get("label"),
+            Some(&ConceptValue::String("English".to_string()))
+        );
+        assert_eq!(
+            row.get("part1"),
+            Some(&ConceptValue::String("en".to_string()))
+        );
+        assert!(matches!(
+            row.get("t"),
+            Some(ConceptValue::Instance { type_label, .. }) if type_label == "translation"
+        ));
+        Ok(())
+    }
+
+    #[cfg(feature = "typedb")]
+    #[tokio::test]
+    async fn test_insert_batch_transactional_rolls_back_failed_chunk()
+    -> Result<(), Box<dyn std::error::Error>> {
+        let address =
+            std::env::var("TYPEDB_ADDRESS").unwrap_or_else(|_| "localhost:1729".to_string());
+        let database = format!("linkml_helper_test_{}", uuid::Uuid::new_v4().simple());
+
+        let typedb = TypeDBHelper::connect(&address).await?;
+        typedb.ensure_database(&database).await?;
+        typedb
+            .define_schema(
+                &database,
+                "define
+                    attribute id, value string;
+                    entity translation, owns id @key;",
+            )
+            .await?;
+
+        // The second chunk repeats a key, so both of its statements roll back
+        let statements: Vec<String> = ["aaa", "bbb", "ccc", "ccc", "ddd"]
+            .iter()
+            .map(|id| format!("insert $x isa translation, has id \"{id}\";"))
+            .collect();
+        let result = typedb
+            .insert_batch_transactional(&database, &statements, 2, true)
+            .await?;
+        let rows = typedb
+            .query_match(&database, "match $t isa translation, has id $id;")
+            .await?;
+        let fail_fast = typedb
+            .insert_batch_transactional(&database, &statements[2..4], 2, false)
+            .await;
+        let zero_chunk = typedb
+            .insert_batch_transactional(&database, &statements, 0, true)
+            .await;
+        typedb
+            .driver
+            .databases()
+            .get(&database)
+            .await?
+            .delete()
+            .await?;
+
+        assert_eq!(result.inserted, 3);
+        assert_eq!(result.failed, 2);
+        assert_eq!(result.errors.len(), 1);
+        assert!(result.errors[0].starts_with("Chunk 2 (2 statements) rolled back"));
+
+        let mut ids: Vec<String> = rows
+            .iter()
+            .filter_map(|row| row.get("id").and_then(ConceptValue::as_str))
+            .map(str::to_string)
+            .collect();
+        ids.sort();
+        assert_eq!(ids, ["aaa", "bbb", "ddd"]);
+
+        assert!(fail_fast.is_err());
+        assert!(zero_chunk.is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,193 @@
+//! Constraints written as `validation:` annotations
+//!
+//! Schema authors can attach lightweight constraints to a slot, including
+//! attributes and slot usages, through annotations in the reserved
+//! `validation:` namespace, without writing code or waiting for the
+//! metamodel to grow a facet:
+//!
+//! ```yaml
+//! slots:
+//!   tld:
+//!     range: string
+//!     annotations:
+//!       validation:min_length: 2
+//!       validation:max_length: 63
+//! ```
+//!
+//! | Key                     | Value     | Constraint                                       |
+//! |-------------------------|-----------|--------------------------------------------------|
+//! | `validation:min_length` | integer   | String values have at least this many characters |
+//! | `validation:max_length` | integer   | String values have at most this many characters  |
+//! | `validation:const`      | any value | Values equal this value                          |
+//!
+//! For multivalued slots the constraints apply to each value. Any other key
+//! in the namespace, and a key with an unusable value, is reported as an
+//! error so that misspelled constraints do not pass silently.
+//!
+//! Only slot annotations are interpreted. Each key constrains the values of
+//! a slot, so `validation:` annotations on a class are left as plain
+//! annotations.
+
+use linkml_core::annotations::{AnnotationValue, Annotations};
+use serde_json::Value;
+
+use super::report::ValidationIssue;
+
+/// Prefix of the annotation keys interpreted as constraints
+pub const VALIDATION_NAMESPACE: &str = "validation:";
+
+/// Minimum number of characters of string values
+pub const MIN_LENGTH: &str = "validation:min_length";
+
+/// Maximum number of characters of string values
+pub const MAX_LENGTH: &str = "validation:max_length";
+
+/// Value every value must equal
+pub const CONST: &str = "validation:const";
+
+/// Name reported as the validator of annotation constraint issues
+const VALIDATOR: &str = "annotation_validator";
+
+/// Whether `annotations` hold any key in the `validation:` namespace
+pub(crate) fn has_annotation_constraints(annotations: Option<&Annotations>) -> bool {
+    annotations.is_some_and(|annotations| {
+        annotations
+            .keys()
+            .any(|key| key.starts_with(VALIDATION_NAMESPACE))
+    })
+}
+
+/// Check `value` against the `validation:` annotations of its slot
+pub(crate) fn check_annotation_constraints(
+    value: &Value,
+    annotations: &Annotations,
+    path: &str,
+) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+    for (key, annotation) in annotations {
+        if !key.starts_with(VALIDATION_NAMESPACE) {
+            continue;
+        }
+        let expected = annotation_value(annotation);
+        match key.as_str() {
+            MIN_LENGTH | MAX_LENGTH => {
+                let limit = expected
+                    .as_u64()
+                    .or_else(|| expected.as_str()?.trim().parse().ok())
+                    .and_then(|n| usize::try_from(n).ok());
+                let Some(limit) = limit else {
+                    issues.push(invalid_annotation(
+                        key,
+                        &expected,
+                        "a non-negative integer",
+                        path,
+                    ));
+                    continue;
+                };
+                for_each_value(value, path, |item, item_path| {
+                    check_length(item, key, limit, item_path, &mut issues);
+                });
+            }
+            CONST => {
+                // A list constant is compared with the whole list
+                if expected.is_array() {
+                    check_const(value, &expected, path, &mut issues);
+                } else {
+                    for_each_value(value, path, |item, item_path| {
+                        check_const(item, &expected, item_path, &mut issues);
+                    });
+                }
+            }
+            _ => issues.push(
+                ValidationIssue::error(
+                    format!("Unknown validation annotation '{key}'"),
+                    path,
+                    VALIDATOR,
+                )
+                .with_code("UNKNOWN_VALIDATION_ANNOTATION"),
+            ),
+        }
+    }
+    issues
+}
+
+/// Value of an annotation as `JSON`, unwrapping `{tag, value}` annotations
+fn annotation_value(annotation: &AnnotationValue) -> Value {
+    let value = serde_json::to_value(annotation).unwrap_or(Value::Null);
+    match value {
+        Value::Object(mut object) if object.contains_key("value") => {
+            object.remove("value").unwrap_or(Value::Null)
+        }
+        other => other,
+    }
+}
+
+/// Call `check` with each value of a multivalued slot, or with `value`
+fn for_each_value(value: &Value, path: &str, mut check: impl FnMut(&Value, &str)) {
+    match value {
+        Value::Array(items) => {
+            for (index, item) in items.iter().enumerate() {
+                check(item, &format!("{path}[{index}]"));
+            }
+        }
+        other => check(other, path),
+    }
+}
+
+fn check_length(
+    value: &Value,
+    key: &str,
+    limit: usize,
+    path: &str,
+    issues: &mut Vec<ValidationIssue>,
+) {
+    let Some(text) = value.as_str() else {
+        return;
+    };
+    let length = text.chars().count();
+    let (violated, message, code) = if key == MIN_LENGTH {
+        (
+            length < limit,
+            format!("Value '{text}' is shorter than the minimum length {limit}"),
+            "ANNOTATION_MIN_LENGTH",
+        )
+    } else {
+        (
+            length > limit,
+            format!("Value '{text}' is longer than the maximum length {limit}"),
+            "ANNOTATION_MAX_LENGTH",
+        )
+    };
+    if violated {
+        issues.push(
+            ValidationIssue::error(message, path, VALIDATOR)
+                .with_code(code)
+                .with_context("annotation", Value::String(key.to_string()))
+                .with_context("length", Value::from(length)),
+        );
+    }
+}
+
+fn check_const(value: &Value, expected: &Value, path: &str, issues: &mut Vec<ValidationIssue>) {
+    if value != expected {
+        issues.push(
+            ValidationIssue::error(
+                format!("Value {value} does not equal the constant {expected}"),
+                path,
+                VALIDATOR,
+            )
+            .with_code("ANNOTATION_CONST")
+            .with_context("annotation", Value::String(CONST.to_string()))
+            .with_context("expected", expected.clone()),
+        );
+    }
+}
+
+fn invalid_annotation(key: &str, value: &Value, wanted: &str, path: &str) -> ValidationIssue {
+    ValidationIssue::error(
+        format!("Validation annotation '{key}' must be {wanted}, found {value}"),
+        path,
+        VALIDATOR,
+    )
+    .with_code("INVALID_VALIDATION_ANNOTATION")
+}
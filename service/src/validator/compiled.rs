@@ -1,10 +1,13 @@
 //! Compiled validator for optimized validation performance
 
+use super::EnumResolver;
 use super::context::ValidationContext;
+use super::json_path::json_pointer;
 use super::report::{Severity, ValidationIssue};
-use super::validators::Validator;
+use super::validators::{LengthFacets, StringLengthValidator, Validator};
 use linkml_core::error::{LinkMLError, Result as LinkMLResult};
 use linkml_core::prelude::*;
+use linkml_core::types::WhitespaceFacet;
 use serde_json::Value as JsonValue;
 use std::collections::HashMap;
 
@@ -82,6 +85,8 @@ pub enum ValidationInstruction {
         min: Option<usize>,
         /// Maximum length
         max: Option<usize>,
+        /// Whitespace handling before measuring
+        whitespace: Option<WhitespaceFacet>,
     },
 
     /// Validate against permissible values
@@ -254,10 +259,12 @@ impl CompiledValidator {
         ValidationIssue {
             severity: Severity::Error,
             path: path.to_string(),
+            pointer: json_pointer(path),
             message: format!("Value {num} is out of range"),
             validator: self.name.clone(),
             code: Some("range_violation".to_string()),
             context,
+            location: None,
         }
     }
 
@@ -316,9 +323,20 @@ impl CompiledValidator {
                 context,
             ),
 
-            ValidationInstruction::ValidateLength { path, min, max } => {
-                self.execute_length_validation(value, path, min.as_ref(), max.as_ref())
-            }
+            ValidationInstruction::ValidateLength {
+                path,
+                min,
+                max,
+                whitespace,
+            } => Self::execute_length_validation(
+                value,
+                path,
+                LengthFacets {
+                    min_length: *min,
+                    max_length: *max,
+                    whitespace: *whitespace,
+                },
+            ),
         }
     }
 
@@ -344,10 +362,12 @@ impl CompiledValidator {
             issues.push(ValidationIssue {
                 severity: Severity::Error,
                 path: path.to_string(),
+                pointer: json_pointer(path),
                 message: format!("Value '{s}' is not a permissible value"),
                 validator: self.name.clone(),
                 code: Some("enum_violation".to_string()),
                 context,
+                location: None,
             });
         }
 
@@ -384,10 +404,12 @@ impl CompiledValidator {
                 issues.push(ValidationIssue {
                     severity: Severity::Error,
                     path: path.to_string(),
+                    pointer: json_pointer(path),
                     message: format!("Expected type {expected_type:?}, got {actual_type:?}"),
                     validator: self.name.clone(),
                     code: Some("type_mismatch".to_string()),
                     context,
+                    location: None,
                 });
             }
         }
@@ -475,50 +497,30 @@ impl CompiledValidator {
     }
 
     /// Execute length validation instruction
+    ///
+    /// Each value of a list is checked on its own.
     fn execute_length_validation(
-        &self,
         value: &JsonValue,
         path: &str,
-        min: Option<&usize>,
-        max: Option<&usize>,
+        facets: LengthFacets,
     ) -> Vec<ValidationIssue> {
         let mut issues = Vec::new();
 
-        if let Some(field_value) = Self::extract_value_at_path(value, path)
-            && let Some(s) = field_value.as_str()
-        {
-            let len = s.chars().count();
-            let valid = match (min, max) {
-                (Some(min_len), Some(max_len)) => len >= *min_len && len <= *max_len,
-                (Some(min_len), None) => len >= *min_len,
-                (None, Some(max_len)) => len <= *max_len,
-                (None, None) => true,
-            };
-
-            if !valid {
-                let mut context_map = HashMap::new();
-                context_map.insert("length".to_string(), serde_json::Value::Number(len.into()));
-                if let Some(min_len) = min {
-                    context_map.insert(
-                        "min".to_string(),
-                        serde_json::Value::Number((*min_len).into()),
+        match Self::extract_value_at_path(value, path) {
+            Some(JsonValue::Array(items)) => {
+                for (i, item) in items.iter().enumerate() {
+                    StringLengthValidator::check_value(
+                        item,
+                        facets,
+                        &format!("{path}[{i}]"),
+                        &mut issues,
                     );
                 }
-                if let Some(max_len) = max {
-                    context_map.insert(
-                        "max".to_string(),
-                        serde_json::Value::Number((*max_len).into()),
-                    );
-                }
-                issues.push(ValidationIssue {
-                    severity: Severity::Error,
-                    path: path.to_string(),
-                    message: format!("String length {len} is out of range"),
-                    validator: self.name.clone(),
-                    code: Some("length_violation".to_string()),
-                    context: context_map,
-                });
             }
+            Some(field_value) => {
+                StringLengthValidator::check_value(field_value, facets, path, &mut issues);
+            }
+            None => {}
         }
 
         issues
@@ -538,10 +540,12 @@ impl CompiledValidator {
             issues.push(ValidationIssue {
                 severity: Severity::Error,
                 path: path.to_string(),
+                pointer: json_pointer(path),
                 message: format!("Required field '{field}' is missing"),
                 validator: self.name.clone(),
                 code: Some("required_field_missing".to_string()),
                 context: HashMap::new(),
+                location: None,
             });
         }
         issues
@@ -572,10 +576,12 @@ impl CompiledValidator {
             issues.push(ValidationIssue {
                 severity: Severity::Error,
                 path: path.to_string(),
+                pointer: json_pointer(path),
                 message: format!("Value does not match pattern: {}", pattern.as_str()),
                 validator: self.name.clone(),
                 code: Some("pattern_mismatch".to_string()),
                 context,
+                location: None,
             });
         }
         issues
@@ -754,12 +760,7 @@ impl<'a> ValidatorCompiler<'a> {
         let mut instructions = Vec::new();
 
         // Compile slot validations
-        for slot_name in &class.slots {
-            if let Some(slot) = self.schema.slots.get(slot_name) {
-                let slot_instructions = self.compile_slot(slot_name, slot)?;
-                instructions.extend(slot_instructions);
-            }
-        }
+        instructions.extend(self.compile_declared_slots(class)?);
 
         // Handle inheritance if precompute_inheritance is enabled
         if self
@@ -839,9 +840,11 @@ impl<'a> ValidatorCompiler<'a> {
             });
         }
 
-        // Enum validation
+        // Enum validation; dynamic enums have no values until their code
+        // set is resolved
         if let Some(range) = &slot.range
             && let Some(enum_def) = self.schema.enums.get(range)
+            && !EnumResolver::is_dynamic(enum_def)
             && self
                 .options
                 .contains(CompilationOptions::CACHE_PERMISSIBLE_VALUES)
@@ -865,26 +868,55 @@ impl<'a> ValidatorCompiler<'a> {
             };
 
             instructions = vec![ValidationInstruction::ValidateArray {
-                path,
+                path: path.clone(),
                 element_instructions,
             }];
         }
 
+        // Length validation, with the facets of the range type
+        let facets = StringLengthValidator::facets(slot, self.schema);
+        if facets.has_bounds() {
+            instructions.push(ValidationInstruction::ValidateLength {
+                path,
+                min: facets.min_length,
+                max: facets.max_length,
+                whitespace: facets.whitespace,
+            });
+        }
+
         Ok(instructions)
     }
 
-    fn compile_inherited_slots(
+    /// Compile the slots a class lists and its inline attributes
+    ///
+    /// An attribute is a complete slot definition, so it replaces a schema
+    /// slot of the same name.
+    fn compile_declared_slots(
         &mut self,
-        parent_class: &ClassDefinition,
+        class: &ClassDefinition,
     ) -> LinkMLResult<Vec<ValidationInstruction>> {
         let mut instructions = Vec::new();
 
-        for slot_name in &parent_class.slots {
+        for slot_name in &class.slots {
+            if class.attributes.contains_key(slot_name) {
+                continue;
+            }
             if let Some(slot) = self.schema.slots.get(slot_name) {
-                let slot_instructions = self.compile_slot(slot_name, slot)?;
-                instructions.extend(slot_instructions);
+                instructions.extend(self.compile_slot(slot_name, slot)?);
             }
         }
+        for (attr_name, attr) in &class.attributes {
+            instructions.extend(self.compile_slot(attr_name, attr)?);
+        }
+
+        Ok(instructions)
+    }
+
+    fn compile_inherited_slots(
+        &mut self,
+        parent_class: &ClassDefinition,
+    ) -> LinkMLResult<Vec<ValidationInstruction>> {
+        let mut instructions = self.compile_declared_slots(parent_class)?;
 
         // Recursively compile parent's parent
         if let Some(grandparent_name) = &parent_class.is_a
@@ -928,8 +960,15 @@ impl<'a> ValidatorCompiler<'a> {
                 PermissibleValue::Simple(s) => {
                     enum_set.insert(s.clone());
                 }
-                PermissibleValue::Complex { text, .. } => {
+                PermissibleValue::Complex { text, meaning, .. } => {
                     enum_set.insert(text.clone());
+                    // Instance data may carry the meaning instead of the text
+                    if let Some(meaning) = meaning {
+                        if let Some(uri) = self.schema.expand_curie(meaning) {
+                            enum_set.insert(uri);
+                        }
+                        enum_set.insert(meaning.clone());
+                    }
                 }
             }
         }
@@ -3,11 +3,21 @@
 use super::buffer_pool::ValidationBufferPools;
 use super::compiled::CompiledValidator;
 use super::json_path::{JsonNavigator, JsonPath};
+use super::pattern_cache::PatternCache;
+use crate::inheritance::InheritanceResolver;
 use linkml_core::types::{ClassDefinition, SchemaDefinition, SlotDefinition};
 use parking_lot::RwLock;
-use std::collections::HashMap;
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
+/// Effective slots of a class, by slot name, in the order they were
+/// introduced
+pub type EffectiveSlots = Arc<Vec<(String, SlotDefinition)>>;
+
+/// Effective slots already computed, by class name
+pub type EffectiveSlotCache = RwLock<HashMap<String, EffectiveSlots>>;
+
 /// Validation context that tracks state during validation
 pub struct ValidationContext {
     /// The LinkML schema being used for validation
@@ -24,10 +34,17 @@ pub struct ValidationContext {
     pub validator_cache: Arc<RwLock<HashMap<String, CompiledValidator>>>,
     /// Instance data for permissible values
     pub instance_data: Option<Arc<HashMap<String, Vec<String>>>>,
+    /// Permissible values of dynamic enums, by enum name
+    pub enum_values: Option<Arc<HashMap<String, Arc<HashSet<String>>>>>,
     /// Additional context data
     pub data: HashMap<String, serde_json::Value>,
     /// Buffer pools for efficient memory reuse
     pub buffer_pools: Arc<ValidationBufferPools>,
+    /// Compiled patterns shared with the owning engine, if any
+    pub pattern_cache: Option<Arc<PatternCache>>,
+    /// Effective slots of the classes validated so far, shared with the
+    /// owning engine, if any
+    effective_slots: Arc<EffectiveSlotCache>,
     /// Parent value in the validation tree
     parent_value: Option<serde_json::Value>,
     /// Root value being validated
@@ -50,8 +67,11 @@ impl ValidationContext {
             class_stack: Vec::new(),
             validator_cache: Arc::new(RwLock::new(HashMap::new())),
             instance_data: None,
+            enum_values: None,
             data: HashMap::new(),
             buffer_pools: Arc::new(ValidationBufferPools::new()),
+            pattern_cache: None,
+            effective_slots: Arc::default(),
             parent_value: None,
             root_value: None,
             all_instances: None,
@@ -73,8 +93,11 @@ impl ValidationContext {
             class_stack: Vec::new(),
             validator_cache: Arc::new(RwLock::new(HashMap::new())),
             instance_data: None,
+            enum_values: None,
             data: HashMap::new(),
             buffer_pools,
+            pattern_cache: None,
+            effective_slots: Arc::default(),
             parent_value: None,
             root_value: None,
             all_instances: None,
@@ -82,6 +105,33 @@ impl ValidationContext {
         }
     }
 
+    /// Share a pattern cache so compiled regexes outlive this context
+    #[must_use]
+    pub fn with_pattern_cache(mut self, pattern_cache: Arc<PatternCache>) -> Self {
+        self.pattern_cache = Some(pattern_cache);
+        self
+    }
+
+    /// Share the effective slots computed for each class, so they are
+    /// resolved once rather than once per validated instance
+    #[must_use]
+    pub fn with_effective_slots(mut self, effective_slots: Arc<EffectiveSlotCache>) -> Self {
+        self.effective_slots = effective_slots;
+        self
+    }
+
+    /// Compile a regex pattern, reusing the shared pattern cache when present
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pattern is not a valid regular expression.
+    pub fn compile_pattern(&self, pattern: &str) -> Result<Arc<Regex>, regex::Error> {
+        match &self.pattern_cache {
+            Some(cache) => cache.get_or_compile(pattern),
+            None => Regex::new(pattern).map(Arc::new),
+        }
+    }
+
     /// Get the current `JSON` path as a string
     #[must_use]
     pub fn path(&self) -> String {
@@ -176,56 +226,35 @@ impl ValidationContext {
         self.schema.slots.get(name)
     }
 
-    /// Get effective slots for a class (including inherited)
-    #[must_use]
-    pub fn get_effective_slots<'a>(
-        &'a self,
-        class_name: &str,
-    ) -> Vec<(&'a str, &'a SlotDefinition)> {
-        let mut slots = Vec::new();
-        let mut visited = std::collections::HashSet::new();
-        self.collect_slots_recursive(class_name, &mut slots, &mut visited);
-        slots
-    }
-
-    /// Recursively collect slots including from parent classes
-    fn collect_slots_recursive<'a>(
-        &'a self,
+    /// Get effective slots for a class, including inherited slots,
+    /// attributes and `slot_usage` refinements
+    ///
+    /// The slots of each class are resolved on first use and shared by all
+    /// contexts of the same engine afterwards.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the class is unknown or its inheritance
+    /// hierarchy is circular.
+    pub fn get_effective_slots(
+        &self,
         class_name: &str,
-        slots: &mut Vec<(&'a str, &'a SlotDefinition)>,
-        visited: &mut std::collections::HashSet<String>,
-    ) {
-        if !visited.insert(class_name.to_string()) {
-            return; // Already visited
+    ) -> linkml_core::error::Result<EffectiveSlots> {
+        if let Some(slots) = self.effective_slots.read().get(class_name) {
+            return Ok(Arc::clone(slots));
         }
 
-        if let Some(class) = self.get_class(class_name) {
-            // First collect from parent
-            if let Some(parent) = &class.is_a {
-                self.collect_slots_recursive(parent, slots, visited);
-            }
-
-            // Then add this class's slots
-            for slot_name in &class.slots {
-                if let Some(slot_def) = self.get_slot(slot_name) {
-                    // Check if we should override an inherited slot
-                    if let Some(pos) = slots.iter().position(|(name, _)| name == slot_name) {
-                        slots[pos] = (slot_name, slot_def);
-                    } else {
-                        slots.push((slot_name, slot_def));
-                    }
-                }
-            }
-
-            // Apply slot usage overrides
-            for (slot_name, _usage) in &class.slot_usage {
-                if let Some(_pos) = slots.iter().position(|(name, _)| name == slot_name) {
-                    // Merge usage with base slot definition
-                    // For now, just note that we'd merge here in a real implementation
-                    // This would involve creating a new SlotDefinition with overrides applied
-                }
-            }
-        }
+        let slots: EffectiveSlots = Arc::new(
+            InheritanceResolver::new(&self.schema)
+                .induced_slots(class_name)?
+                .into_iter()
+                .map(|slot| (slot.name.clone(), slot))
+                .collect(),
+        );
+        self.effective_slots
+            .write()
+            .insert(class_name.to_string(), Arc::clone(&slots));
+        Ok(slots)
     }
 
     /// Check if a value is in the permissible values from instance data
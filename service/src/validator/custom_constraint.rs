@@ -0,0 +1,62 @@
+//! Custom constraints registered on the validation engine
+//!
+//! Some domain rules cannot be written in a schema, such as "a country's
+//! top-level domain starts with a dot". A [`CustomValidator`] implements
+//! such a rule in code and is registered on a slot or class with
+//! [`ValidationEngine::register_validator`](super::ValidationEngine::register_validator),
+//! which runs it alongside the built-in checks.
+
+use linkml_core::types::{self, SchemaDefinition, SlotDefinition};
+use serde_json::Value;
+
+use super::report::{Severity, ValidationIssue};
+
+/// A user-defined constraint on slot values or class instances
+///
+/// The trait is object safe, so validators are registered as
+/// `Box<dyn CustomValidator>`. Registered on a slot, the validator is called
+/// with each value of the slot. Registered on a class, it is called with
+/// each instance of the class and a slot whose range is the class, so rules
+/// spanning several fields can be checked.
+pub trait CustomValidator: Send + Sync {
+    /// Check `value` of `slot`, returning one error per violation
+    ///
+    /// An error's `path`, if set, is taken relative to the checked value.
+    fn validate(
+        &self,
+        value: &Value,
+        slot: &SlotDefinition,
+        schema: &SchemaDefinition,
+    ) -> Vec<types::ValidationError>;
+
+    /// Name reported as the validator of the issues this validator finds
+    fn name(&self) -> &str {
+        "custom_validator"
+    }
+}
+
+/// Report an error of a custom validator as an issue at `path`
+pub(crate) fn custom_issue(
+    error: types::ValidationError,
+    path: &str,
+    validator: &str,
+) -> ValidationIssue {
+    let severity = match error.severity {
+        types::Severity::Error => Severity::Error,
+        types::Severity::Warning => Severity::Warning,
+        types::Severity::Info => Severity::Info,
+    };
+    let path = match error.path.as_deref() {
+        Some(relative) if relative.starts_with('[') => format!("{path}{relative}"),
+        Some(relative) if !relative.is_empty() => format!("{path}.{relative}"),
+        _ => path.to_string(),
+    };
+    let mut issue = ValidationIssue::new(severity, error.message, path, validator);
+    if let Some(expected) = error.expected {
+        issue = issue.with_context("expected", Value::String(expected));
+    }
+    if let Some(actual) = error.actual {
+        issue = issue.with_context("actual", Value::String(actual));
+    }
+    issue
+}
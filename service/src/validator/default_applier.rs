@@ -2,6 +2,18 @@
 //!
 //! This module handles the ifabsent logic for applying default values
 //! to slots when values are missing.
+//!
+//! Supported `ifabsent` forms:
+//!
+//! - `string(text)`, `int(n)`, `float(x)` and `boolean(true|false)`
+//! - `date(today)` and `datetime(now)`
+//! - bare literals such as `unknown`, `10` or `true`, read against the slot's
+//!   range (`boolean`, `integer`, `float`/`double`/`decimal`, otherwise string)
+//! - the keywords `slot_name`, `class_name`, `class_curie`, `class_slot_curie`,
+//!   `bnode`, `default_value`, `date` and `datetime`
+//!
+//! Any other `name(...)` form, or a literal that does not fit the slot's
+//! range, is rejected with an error naming the slot.
 
 use crate::expression::ExpressionEngine;
 use linkml_core::types::{IfAbsentAction, SchemaDefinition};
@@ -101,8 +113,8 @@ impl<'a> DefaultApplier<'a> {
 
         // Process all slots for this class
         for slot_name in &class.slots {
-            // Skip if value already exists
-            if data.contains_key(slot_name) {
+            // Skip if value already exists or an attribute redefines the slot
+            if data.contains_key(slot_name) || class.attributes.contains_key(slot_name) {
                 continue;
             }
 
@@ -110,8 +122,13 @@ impl<'a> DefaultApplier<'a> {
             if let Some(slot) = self.schema.slots.get(slot_name) {
                 // Check if slot has ifabsent
                 if let Some(ifabsent) = &slot.ifabsent {
+                    let range = class
+                        .slot_usage
+                        .get(slot_name)
+                        .and_then(|usage| usage.range.as_deref())
+                        .or(slot.range.as_deref());
                     let default_value =
-                        self.compute_default_value(ifabsent, slot_name, class_name, data);
+                        self.compute_default_value(ifabsent, slot_name, range, class_name, data)?;
 
                     if let Some(value) = default_value {
                         data.insert(slot_name.clone(), value);
@@ -120,6 +137,27 @@ impl<'a> DefaultApplier<'a> {
             }
         }
 
+        // Inline attributes carry their own defaults
+        for (attr_name, attr) in &class.attributes {
+            if data.contains_key(attr_name) {
+                continue;
+            }
+
+            if let Some(ifabsent) = &attr.ifabsent {
+                let default_value = self.compute_default_value(
+                    ifabsent,
+                    attr_name,
+                    attr.range.as_deref(),
+                    class_name,
+                    data,
+                )?;
+
+                if let Some(value) = default_value {
+                    data.insert(attr_name.clone(), value);
+                }
+            }
+        }
+
         // Also check slot_usage for class-specific defaults
         for (slot_name, slot_override) in &class.slot_usage {
             if data.contains_key(slot_name) {
@@ -127,8 +165,14 @@ impl<'a> DefaultApplier<'a> {
             }
 
             if let Some(ifabsent) = &slot_override.ifabsent {
+                let range = slot_override.range.as_deref().or_else(|| {
+                    self.schema
+                        .slots
+                        .get(slot_name)
+                        .and_then(|slot| slot.range.as_deref())
+                });
                 let default_value =
-                    self.compute_default_value(ifabsent, slot_name, class_name, data);
+                    self.compute_default_value(ifabsent, slot_name, range, class_name, data)?;
 
                 if let Some(value) = default_value {
                     data.insert(slot_name.clone(), value);
@@ -140,14 +184,18 @@ impl<'a> DefaultApplier<'a> {
     }
 
     /// Compute the default value based on `IfAbsentAction`
+    ///
+    /// Returns `Ok(None)` when the action yields no value and an error for
+    /// unsupported `ifabsent` forms.
     fn compute_default_value(
         &self,
         action: &IfAbsentAction,
         slot_name: &str,
+        range: Option<&str>,
         class_name: &str,
         data: &serde_json::Map<String, Value>,
-    ) -> Option<Value> {
-        match action {
+    ) -> Result<Option<Value>, String> {
+        let value = match action {
             IfAbsentAction::SlotName => {
                 // Use the slot name as the value
                 Some(Value::String(slot_name.to_string()))
@@ -164,6 +212,16 @@ impl<'a> DefaultApplier<'a> {
                 Some(Value::String(curie))
             }
 
+            IfAbsentAction::ClassCurie => {
+                // Create a CURIE from the schema's default prefix and the class name
+                let prefix = self
+                    .schema
+                    .default_prefix
+                    .as_deref()
+                    .unwrap_or(&self.schema.name);
+                Some(Value::String(format!("{prefix}:{class_name}")))
+            }
+
             IfAbsentAction::Bnode => {
                 // Generate a blank node identifier
                 let bnode = format!("_:b{}", uuid::Uuid::new_v4().simple());
@@ -171,50 +229,24 @@ impl<'a> DefaultApplier<'a> {
             }
 
             IfAbsentAction::DefaultValue => {
-                // Look up the default value field in the slot definition
-                if let Some(slot_def) = self.schema.slots.get(slot_name) {
-                    slot_def.default.clone()
-                } else {
-                    None
-                }
+                // Look up the default value field in the attribute or slot definition
+                let slot_def = self
+                    .schema
+                    .classes
+                    .get(class_name)
+                    .and_then(|class| class.attributes.get(slot_name))
+                    .or_else(|| self.schema.slots.get(slot_name));
+                slot_def.and_then(|slot_def| slot_def.default.clone())
             }
 
             IfAbsentAction::String(s) => {
-                // Use the provided string
-                Some(Value::String(s.clone()))
+                // Interpret the literal or function form
+                Some(self.parse_literal(s, slot_name, range)?)
             }
 
-            IfAbsentAction::Date => {
-                // Use current date
-                if let Some(ts_service) = &self.timestamp_service {
-                    // Use TimestampService for getting current date
-                    let rt = tokio::runtime::Runtime::new().ok()?;
-                    let local_time = rt.block_on(async { ts_service.now_local().await }).ok()?;
-                    let current_date = local_time.format("%Y-%m-%d").to_string();
-                    Some(Value::String(current_date))
-                } else {
-                    // Fallback to direct chrono call
-                    let current_date = Local::now().format("%Y-%m-%d").to_string();
-                    Some(Value::String(current_date))
-                }
-            }
+            IfAbsentAction::Date => self.current_date(),
 
-            IfAbsentAction::Datetime => {
-                // Use current datetime
-                if let Some(ts_service) = &self.timestamp_service {
-                    // Use TimestampService for getting current datetime
-                    let rt = tokio::runtime::Runtime::new().ok()?;
-                    let utc_time = rt.block_on(async { ts_service.now_utc().await }).ok()?;
-                    let datetime = rt
-                        .block_on(async { ts_service.format_iso8601(&utc_time).await })
-                        .ok()?;
-                    Some(Value::String(datetime))
-                } else {
-                    // Fallback to direct chrono call
-                    let datetime = Utc::now().to_rfc3339();
-                    Some(Value::String(datetime))
-                }
-            }
+            IfAbsentAction::Datetime => self.current_datetime(),
 
             IfAbsentAction::Int(n) => {
                 // Use the provided integer
@@ -227,6 +259,95 @@ impl<'a> DefaultApplier<'a> {
                     data.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
                 Some(self.evaluate_expression(expr, &data_hashmap))
             }
+        };
+        Ok(value)
+    }
+
+    /// Parse an `ifabsent` literal such as `string(unknown)`, `int(5)` or a
+    /// bare value read against the slot's range
+    fn parse_literal(
+        &self,
+        text: &str,
+        slot_name: &str,
+        range: Option<&str>,
+    ) -> Result<Value, String> {
+        let invalid = |expected: &str| {
+            format!("ifabsent value '{text}' for slot '{slot_name}' is not a valid {expected}")
+        };
+
+        if let Some((function, argument)) = split_function_call(text) {
+            return match (function, argument) {
+                ("string", arg) => Ok(Value::String(arg.to_string())),
+                ("int", arg) => arg
+                    .parse::<i64>()
+                    .map(Value::from)
+                    .map_err(|_| invalid("integer")),
+                ("float", arg) => arg
+                    .parse::<f64>()
+                    .ok()
+                    .and_then(serde_json::Number::from_f64)
+                    .map(Value::Number)
+                    .ok_or_else(|| invalid("float")),
+                ("boolean", arg) => parse_boolean(arg)
+                    .map(Value::Bool)
+                    .ok_or_else(|| invalid("boolean")),
+                ("date", "today") => self.current_date().ok_or_else(|| {
+                    format!("could not determine the current date for slot '{slot_name}'")
+                }),
+                ("datetime", "now") => self.current_datetime().ok_or_else(|| {
+                    format!("could not determine the current datetime for slot '{slot_name}'")
+                }),
+                _ => Err(format!(
+                    "unsupported ifabsent form '{text}' for slot '{slot_name}'; expected string(...), \
+                     int(...), float(...), boolean(...), date(today), datetime(now) or a literal"
+                )),
+            };
+        }
+
+        match range {
+            Some("boolean") => parse_boolean(text)
+                .map(Value::Bool)
+                .ok_or_else(|| invalid("boolean")),
+            Some("integer") => text
+                .parse::<i64>()
+                .map(Value::from)
+                .map_err(|_| invalid("integer")),
+            Some("float" | "double" | "decimal") => text
+                .parse::<f64>()
+                .ok()
+                .and_then(serde_json::Number::from_f64)
+                .map(Value::Number)
+                .ok_or_else(|| invalid("number")),
+            _ => Ok(Value::String(text.to_string())),
+        }
+    }
+
+    /// Current local date as `YYYY-MM-DD`
+    fn current_date(&self) -> Option<Value> {
+        if let Some(ts_service) = &self.timestamp_service {
+            // Use TimestampService for getting current date
+            let rt = tokio::runtime::Runtime::new().ok()?;
+            let local_time = rt.block_on(async { ts_service.now_local().await }).ok()?;
+            Some(Value::String(local_time.format("%Y-%m-%d").to_string()))
+        } else {
+            // Fallback to direct chrono call
+            Some(Value::String(Local::now().format("%Y-%m-%d").to_string()))
+        }
+    }
+
+    /// Current UTC datetime in ISO 8601 form
+    fn current_datetime(&self) -> Option<Value> {
+        if let Some(ts_service) = &self.timestamp_service {
+            // Use TimestampService for getting current datetime
+            let rt = tokio::runtime::Runtime::new().ok()?;
+            let utc_time = rt.block_on(async { ts_service.now_utc().await }).ok()?;
+            let datetime = rt
+                .block_on(async { ts_service.format_iso8601(&utc_time).await })
+                .ok()?;
+            Some(Value::String(datetime))
+        } else {
+            // Fallback to direct chrono call
+            Some(Value::String(Utc::now().to_rfc3339()))
         }
     }
 
@@ -265,6 +386,24 @@ impl<'a> DefaultApplier<'a> {
     }
 }
 
+/// Split `name(argument)` into its parts when `name` is a plain identifier
+fn split_function_call(text: &str) -> Option<(&str, &str)> {
+    let (name, rest) = text.trim().split_once('(')?;
+    let argument = rest.strip_suffix(')')?;
+    let is_identifier =
+        !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    is_identifier.then_some((name, argument.trim()))
+}
+
+/// Parse the boolean spellings used in `LinkML` schemas
+fn parse_boolean(text: &str) -> Option<bool> {
+    match text {
+        "true" | "True" | "TRUE" => Some(true),
+        "false" | "False" | "FALSE" => Some(false),
+        _ => None,
+    }
+}
+
 /// Integration with the validation context
 /// Returns an error if the operation fails
 ///
@@ -433,4 +572,74 @@ mod tests {
             Some(&Value::String("ITEM_123".to_string()))
         );
     }
+
+    fn schema_with_slots(slots: &str) -> SchemaDefinition {
+        let yaml = format!(
+            "id: https://example.org/people\nname: people\ndefault_prefix: ex\n\
+             classes:\n  Person:\n    slots: [name, status, age, active]\n\
+             slots:\n{slots}"
+        );
+        serde_yaml::from_str(&yaml).expect("schema should parse")
+    }
+
+    #[test]
+    fn test_string_function_default() {
+        let schema = schema_with_slots(
+            "  name:\n    range: string\n  status:\n    range: string\n    ifabsent: string(unknown)\n",
+        );
+        let mut data = serde_json::json!({"name": "Ada"});
+        apply_defaults_to_instance(&schema, &mut data, "Person").expect("Should apply defaults");
+
+        assert_eq!(data["status"], "unknown");
+        assert_eq!(data["name"], "Ada");
+    }
+
+    #[test]
+    fn test_literal_defaults_follow_range() {
+        let schema = schema_with_slots(
+            "  age:\n    range: integer\n    ifabsent: '10'\n  \
+             active:\n    range: boolean\n    ifabsent: 'true'\n  \
+             status:\n    range: string\n    ifabsent: class_curie\n",
+        );
+        let mut data = serde_json::json!({});
+        apply_defaults_to_instance(&schema, &mut data, "Person").expect("Should apply defaults");
+
+        assert_eq!(data["age"], 10);
+        assert_eq!(data["active"], true);
+        assert_eq!(data["status"], "ex:Person");
+    }
+
+    #[test]
+    fn test_unsupported_form_is_rejected() {
+        let schema = schema_with_slots("  status:\n    range: string\n    ifabsent: uri(ex:x)\n");
+        let mut data = serde_json::json!({});
+        let error = apply_defaults_to_instance(&schema, &mut data, "Person")
+            .expect_err("uri(...) is not supported");
+
+        assert!(
+            error.contains("unsupported ifabsent form 'uri(ex:x)'"),
+            "{error}"
+        );
+        assert!(error.contains("status"), "{error}");
+        assert!(data.get("status").is_none());
+    }
+
+    #[test]
+    fn test_engine_apply_defaults() {
+        let schema = schema_with_slots(
+            "  status:\n    range: string\n    ifabsent: string(unknown)\n  \
+             age:\n    range: integer\n    ifabsent: int(abc)\n",
+        );
+        let engine = crate::validator::ValidationEngine::new(&schema).expect("engine");
+
+        let mut data = serde_json::json!({"age": 3});
+        engine
+            .apply_defaults(&mut data, "Person")
+            .expect("Should apply defaults");
+        assert_eq!(data, serde_json::json!({"age": 3, "status": "unknown"}));
+
+        let mut data = serde_json::json!({});
+        assert!(engine.apply_defaults(&mut data, "Person").is_err());
+        assert!(engine.apply_defaults(&mut data, "Missing").is_err());
+    }
 }
@@ -2,29 +2,43 @@
 
 use crate::performance::profiling::Profiler;
 use crate::utils::safe_cast::u128_to_u64_saturating;
+use indexmap::IndexMap;
 use linkml_core::{
     error::{LinkMLError, Result},
-    settings::SchemaSettings,
+    settings::{SchemaSettings, ValidationSettings},
     types::{ClassDefinition, SchemaDefinition, SlotDefinition},
 };
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::sync::Arc;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::sync::{Arc, OnceLock};
 use timestamp_core::SyncTimestampService;
+use tokio::runtime::RuntimeFlavor;
+use tokio::sync::OnceCell;
 
 use super::{
+    annotation_constraints::{check_annotation_constraints, has_annotation_constraints},
     buffer_pool::ValidationBufferPools,
-    cache::{CompiledValidatorCache, ValidatorCacheKey},
+    cache::{CacheStats, CompiledValidatorCache, ValidatorCacheKey},
     compiled::{CompilationOptions, CompiledValidator},
     conditional_validator::ConditionalValidator,
-    context::ValidationContext,
+    context::{EffectiveSlotCache, ValidationContext},
+    custom_constraint::{CustomValidator, custom_issue},
     default_applier::DefaultApplier,
+    instance_resolver::InstanceResolver,
+    json_path::{PathSegment, path_segments},
+    pattern_cache::PatternCache,
     recursion_checker::{RecursionTracker, check_recursion},
+    references::validate_schema_references,
     report::{ValidationIssue, ValidationReport},
-    validators::{Validator, ValidatorRegistry},
+    result_cache::{ResultCacheKey, ValidationResultCache},
+    source_map::SourceMap,
+    type_coercion::CoercibleRange,
+    validators::{UniqueKeyValidator, Validator, ValidatorRegistry},
 };
-use crate::inheritance::InheritanceResolver;
 use crate::namespace::CurieResolver;
-use crate::schema_view::SchemaView;
+use crate::parser::ImportResolverV2;
 
 /// Options for validation
 #[derive(Default)]
@@ -33,6 +47,8 @@ pub struct ValidationOptions {
     pub max_depth: Option<usize>,
     /// Whether to fail fast on first error
     pub fail_fast: Option<bool>,
+    /// Stop once this many errors have been reported
+    pub max_errors: Option<usize>,
     /// Whether to validate permissible values
     pub check_permissibles: Option<bool>,
     /// Whether to use cached validators
@@ -41,10 +57,34 @@ pub struct ValidationOptions {
     pub parallel: Option<bool>,
     /// Whether to allow additional properties not defined in schema
     pub allow_additional_properties: Option<bool>,
+    /// How to handle instance fields the class does not declare; overrides
+    /// `allow_additional_properties`
+    pub on_unknown_field: Option<UnknownFieldPolicy>,
     /// Whether to fail on warnings (treat warnings as errors)
     pub fail_on_warning: Option<bool>,
+    /// Whether to coerce strings to integer, float and boolean slot ranges
+    /// before validating them
+    pub coerce_types: Option<bool>,
     /// Custom validators to use
     pub custom_validators: Vec<Box<dyn Validator>>,
+    /// Spans of the validated document, used to give issues a line and column
+    pub source_map: Option<Arc<SourceMap>>,
+}
+
+/// Handling of instance fields that the validated class does not declare
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UnknownFieldPolicy {
+    /// Accept them without comment
+    Ignore,
+    /// Report each one as a warning
+    #[default]
+    Warn,
+    /// Report each one as an error, catching typos such as `lable`
+    Error,
+    /// Accept them and keep their values in the report's
+    /// `additional_properties`
+    Collect,
 }
 
 impl Clone for ValidationOptions {
@@ -52,13 +92,17 @@ impl Clone for ValidationOptions {
         Self {
             max_depth: self.max_depth,
             fail_fast: self.fail_fast,
+            max_errors: self.max_errors,
             check_permissibles: self.check_permissibles,
             use_cache: self.use_cache,
             parallel: self.parallel,
             allow_additional_properties: self.allow_additional_properties,
+            on_unknown_field: self.on_unknown_field,
             fail_on_warning: self.fail_on_warning,
+            coerce_types: self.coerce_types,
             // We can't clone custom validators, so we just create an empty vec
             custom_validators: Vec::new(),
+            source_map: self.source_map.clone(),
         }
     }
 }
@@ -111,6 +155,19 @@ impl ValidationOptions {
         self.fail_fast.unwrap_or(false)
     }
 
+    /// Whether validation should stop given the errors reported so far
+    ///
+    /// In fail-fast mode this is the first error; otherwise it is the
+    /// `max_errors`th error, if a limit is set.
+    #[must_use]
+    pub fn should_stop(&self, report: &ValidationReport) -> bool {
+        !report.valid
+            && (self.fail_fast()
+                || self
+                    .max_errors
+                    .is_some_and(|max| report.stats.error_count >= max))
+    }
+
     /// Get the effective `check_permissibles` setting
     #[must_use]
     pub fn check_permissibles(&self) -> bool {
@@ -128,6 +185,21 @@ impl ValidationOptions {
     pub fn parallel(&self) -> bool {
         self.parallel.unwrap_or(false)
     }
+
+    /// Get the effective `coerce_types` setting
+    #[must_use]
+    pub fn coerce_types(&self) -> bool {
+        self.coerce_types.unwrap_or(false)
+    }
+}
+
+/// Class the instances of a validation are checked against
+#[derive(Clone, Copy)]
+struct TargetClass<'a> {
+    name: &'a str,
+    def: &'a ClassDefinition,
+    /// Compiled validator of the class, if the engine uses one
+    compiled: Option<&'a CompiledValidator>,
 }
 
 /// Main validation engine
@@ -136,8 +208,44 @@ pub struct ValidationEngine {
     registry: ValidatorRegistry,
     compiled_cache: Option<Arc<CompiledValidatorCache>>,
     buffer_pools: Arc<ValidationBufferPools>,
+    pattern_cache: Arc<PatternCache>,
+    /// Effective slots of each validated class, resolved once
+    effective_slots: Arc<EffectiveSlotCache>,
     timestamp_service: Arc<dyn SyncTimestampService<Error = timestamp_core::TimestampError>>,
     profiler: Arc<Profiler>,
+    /// Valid identifiers of `range_type: instance` slots, by slot name
+    instance_values: Option<Arc<HashMap<String, Vec<String>>>>,
+    /// Permissible values of dynamic enums, by enum name
+    enum_values: Option<Arc<HashMap<String, Arc<HashSet<String>>>>>,
+    /// Validators registered with [`Self::register_validator`], by slot or
+    /// class name
+    custom_constraints: HashMap<String, Vec<Box<dyn CustomValidator>>>,
+    /// Reports of earlier validations, set with [`Self::attach_result_cache`]
+    result_cache: OnceLock<Arc<ValidationResultCache>>,
+    /// Fingerprint of the schema, computed on the first cached validation
+    schema_fingerprint: OnceCell<String>,
+}
+
+/// Reject schemas with undefined references when `strict_references` is set
+fn check_schema_references(schema: &SchemaDefinition) -> Result<()> {
+    let strict = schema
+        .settings
+        .as_ref()
+        .and_then(|settings| settings.validation.as_ref())
+        .is_some_and(ValidationSettings::checks_references);
+    if !strict {
+        return Ok(());
+    }
+
+    let errors = validate_schema_references(schema);
+    if errors.is_empty() {
+        return Ok(());
+    }
+    let messages: Vec<String> = errors.iter().map(ToString::to_string).collect();
+    Err(LinkMLError::schema_validation(format!(
+        "Schema contains undefined references: {}",
+        messages.join("; ")
+    )))
 }
 
 impl ValidationEngine {
@@ -145,8 +253,10 @@ impl ValidationEngine {
     ///
     /// # Errors
     ///
-    /// Returns an error if validator registry creation fails
+    /// Returns an error if validator registry creation fails, or if the
+    /// schema enables `strict_references` and contains undefined references
     pub fn new(schema: &SchemaDefinition) -> Result<Self> {
+        check_schema_references(schema)?;
         let schema = Arc::new(schema.clone());
         let registry = ValidatorRegistry::new(&schema)?;
         // Use wiring function for sync timestamp service
@@ -160,8 +270,15 @@ impl ValidationEngine {
             registry,
             compiled_cache: None,
             buffer_pools: Arc::new(ValidationBufferPools::new()),
+            pattern_cache: Arc::new(PatternCache::new()),
+            effective_slots: Arc::default(),
             timestamp_service,
             profiler,
+            instance_values: None,
+            enum_values: None,
+            custom_constraints: HashMap::new(),
+            result_cache: OnceLock::new(),
+            schema_fingerprint: OnceCell::new(),
         })
     }
 
@@ -177,6 +294,7 @@ impl ValidationEngine {
     where
         T: SyncTimestampService<Error = timestamp_core::TimestampError> + Send + Sync + 'static,
     {
+        check_schema_references(schema)?;
         let schema = Arc::new(schema.clone());
         let registry = ValidatorRegistry::new(&schema)?;
 
@@ -189,8 +307,15 @@ impl ValidationEngine {
             registry,
             compiled_cache: None,
             buffer_pools: Arc::new(ValidationBufferPools::new()),
+            pattern_cache: Arc::new(PatternCache::new()),
+            effective_slots: Arc::default(),
             timestamp_service,
             profiler,
+            instance_values: None,
+            enum_values: None,
+            custom_constraints: HashMap::new(),
+            result_cache: OnceLock::new(),
+            schema_fingerprint: OnceCell::new(),
         })
     }
 
@@ -203,6 +328,7 @@ impl ValidationEngine {
         schema: &SchemaDefinition,
         cache: Arc<CompiledValidatorCache>,
     ) -> Result<Self> {
+        check_schema_references(schema)?;
         let schema = Arc::new(schema.clone());
         let registry = ValidatorRegistry::new(&schema)?;
         // Use wiring function for sync timestamp service
@@ -213,10 +339,17 @@ impl ValidationEngine {
             registry,
             compiled_cache: Some(cache),
             buffer_pools: Arc::new(ValidationBufferPools::new()),
+            pattern_cache: Arc::new(PatternCache::new()),
+            effective_slots: Arc::default(),
             timestamp_service: timestamp_service.clone(),
             profiler: Arc::new(Profiler::new(
                 timestamp_service::wiring::wire_timestamp().into_inner(),
             )),
+            instance_values: None,
+            enum_values: None,
+            custom_constraints: HashMap::new(),
+            result_cache: OnceLock::new(),
+            schema_fingerprint: OnceCell::new(),
         })
     }
 
@@ -230,6 +363,7 @@ impl ValidationEngine {
         cache: Arc<CompiledValidatorCache>,
         timestamp_service: Arc<dyn SyncTimestampService<Error = timestamp_core::TimestampError>>,
     ) -> Result<Self> {
+        check_schema_references(schema)?;
         let schema = Arc::new(schema.clone());
         let registry = ValidatorRegistry::new(&schema)?;
 
@@ -238,16 +372,241 @@ impl ValidationEngine {
             registry,
             compiled_cache: Some(cache),
             buffer_pools: Arc::new(ValidationBufferPools::new()),
+            pattern_cache: Arc::new(PatternCache::new()),
+            effective_slots: Arc::default(),
             timestamp_service,
             profiler: Arc::new(Profiler::new(
                 timestamp_service::wiring::wire_timestamp().into_inner(),
             )),
+            instance_values: None,
+            enum_values: None,
+            custom_constraints: HashMap::new(),
+            result_cache: OnceLock::new(),
+            schema_fingerprint: OnceCell::new(),
         })
     }
 
+    /// Create a validation engine with its imports resolved and its
+    /// instance-based ranges pre-loaded
+    ///
+    /// The imports of `schema` are resolved once and the engine validates
+    /// against the merged schema. The valid identifiers of every slot with
+    /// `range_type: instance`, and the permissible values of every dynamic
+    /// enum, are loaded through `resolver` up front, so validation never
+    /// has to wait on schema or instance files; values of those slots are
+    /// then checked against them. Schemas without imports, instance ranges
+    /// or dynamic enums can use [`Self::new`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an import cannot be resolved, the engine cannot
+    /// be created, an instance file cannot be loaded or a dynamic enum's
+    /// code set cannot be resolved
+    pub async fn new_async(schema: &SchemaDefinition, resolver: &InstanceResolver) -> Result<Self> {
+        let merged = ImportResolverV2::new().resolve_imports(schema).await?;
+        let mut engine = Self::new(&merged)?;
+
+        let instance_slots = engine
+            .schema
+            .slots
+            .values()
+            .chain(
+                engine
+                    .schema
+                    .classes
+                    .values()
+                    .flat_map(|class| class.attributes.values()),
+            )
+            .filter(|slot| slot.range_type.as_deref() == Some("instance"));
+        let mut instance_values = HashMap::new();
+        for slot in instance_slots {
+            if let Some(ids) = resolver
+                .get_valid_ids_for_slot(slot, &engine.schema)
+                .await?
+            {
+                instance_values.insert(slot.name.clone(), ids);
+            }
+        }
+
+        let enum_resolver = resolver.enum_resolver();
+        let mut enum_values = HashMap::new();
+        for enum_name in engine.schema.enums.keys() {
+            if let Some(values) = enum_resolver
+                .resolve_enum(enum_name, &engine.schema)
+                .await?
+            {
+                enum_values.insert(enum_name.clone(), values);
+            }
+        }
+
+        engine.instance_values = Some(Arc::new(instance_values));
+        engine.enum_values = Some(Arc::new(enum_values));
+        Ok(engine)
+    }
+
+    /// Create a context sharing the engine's pools, patterns and instance data
+    fn new_context(&self) -> ValidationContext {
+        let mut context =
+            ValidationContext::with_buffer_pools(self.schema.clone(), self.buffer_pools.clone())
+                .with_pattern_cache(self.pattern_cache.clone())
+                .with_effective_slots(self.effective_slots.clone());
+        context.instance_data = self.instance_values.clone();
+        context.enum_values = self.enum_values.clone();
+        context
+    }
+
+    /// Get the cache of compiled slot patterns shared by all validations
+    #[must_use]
+    pub fn pattern_cache(&self) -> &PatternCache {
+        &self.pattern_cache
+    }
+
+    /// Get statistics of the compiled validator cache, if the engine has one
+    #[must_use]
+    pub fn compiled_cache_stats(&self) -> Option<CacheStats> {
+        self.compiled_cache.as_ref().map(|cache| cache.stats())
+    }
+
+    /// Serve repeated validations from `cache`
+    ///
+    /// The cache can be attached to an engine that is already shared, such
+    /// as the validator of a running service.
+    ///
+    /// [`Self::validate_as_class`] then returns the cached report when the
+    /// same data is validated as the same class with the same options.
+    /// Validations with custom validators or a source map in their options,
+    /// or with `use_cache` set to false, bypass the cache. The cache may be
+    /// shared with engines for other schemas; engines for the same schema
+    /// that share it must have the same instance data and registered
+    /// validators.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the engine already has a result cache
+    pub fn attach_result_cache(&self, cache: Arc<ValidationResultCache>) -> Result<()> {
+        self.result_cache
+            .set(cache)
+            .map_err(|_| LinkMLError::service("Validation engine already has a result cache"))
+    }
+
+    /// Get the validation result cache, if the engine has one
+    #[must_use]
+    pub fn result_cache(&self) -> Option<&ValidationResultCache> {
+        self.result_cache.get().map(Arc::as_ref)
+    }
+
+    /// Fill missing slots of a class instance from their `ifabsent` defaults
+    ///
+    /// See [`super::default_applier`] for the supported `ifabsent` forms.
+    /// Values that are already present are left untouched.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the class is not in the schema or a slot uses an
+    /// unsupported `ifabsent` form.
+    pub fn apply_defaults(&self, data: &mut Value, class_name: &str) -> Result<()> {
+        let Value::Object(obj) = data else {
+            return Ok(());
+        };
+        DefaultApplier::from_schema(&self.schema)
+            .apply_defaults_to_object(obj, class_name)
+            .map_err(LinkMLError::schema_validation)
+    }
+
+    /// Register a custom validator on a slot or class
+    ///
+    /// `target` names a slot, whose values the validator then checks, or a
+    /// class, whose instances it then checks. Validators run after the
+    /// built-in checks of their target, in registration order.
+    pub fn register_validator(
+        &mut self,
+        target: impl Into<String>,
+        validator: Box<dyn CustomValidator>,
+    ) {
+        self.custom_constraints
+            .entry(target.into())
+            .or_default()
+            .push(validator);
+        self.clear_result_cache();
+    }
+
+    /// Run the validators registered on `target` on `value`
+    ///
+    /// Returns true if validation should stop.
+    fn run_custom_constraints(
+        &self,
+        target: &str,
+        value: &Value,
+        slot_def: &SlotDefinition,
+        context: &ValidationContext,
+        report: &mut ValidationReport,
+        options: &ValidationOptions,
+    ) -> bool {
+        let Some(validators) = self.custom_constraints.get(target) else {
+            return false;
+        };
+        let path = context.path();
+        for validator in validators {
+            for error in validator.validate(value, slot_def, &self.schema) {
+                report.add_issue(custom_issue(error, &path, validator.name()));
+                if options.should_stop(report) {
+                    return true;
+                }
+            }
+            report.stats.validators_executed += 1;
+        }
+        false
+    }
+
     /// Add a custom validator to the engine
     pub fn add_custom_validator(&mut self, validator: Box<dyn Validator>) {
         self.registry.add_validator(validator);
+        self.clear_result_cache();
+    }
+
+    /// Drop cached reports, which a new validator may invalidate
+    fn clear_result_cache(&self) {
+        if let Some(cache) = self.result_cache.get() {
+            cache.clear();
+        }
+    }
+
+    /// Result cache key for validating `data` as `class_name`, if the
+    /// validation may use the cache
+    async fn result_cache_key(
+        &self,
+        data: &Value,
+        class_name: &str,
+        options: &ValidationOptions,
+    ) -> Result<Option<ResultCacheKey>> {
+        let Some(cache) = self.result_cache.get() else {
+            return Ok(None);
+        };
+        // Neither custom validators nor source maps can be part of the key
+        if !options.use_cache()
+            || !options.custom_validators.is_empty()
+            || options.source_map.is_some()
+        {
+            return Ok(None);
+        }
+        let settings = (
+            options.max_depth,
+            options.fail_fast,
+            options.max_errors,
+            options.check_permissibles,
+            options.allow_additional_properties,
+            options.on_unknown_field,
+            options.fail_on_warning,
+            options.coerce_types,
+        );
+        let fingerprint = self
+            .schema_fingerprint
+            .get_or_try_init(|| cache.schema_fingerprint(&self.schema))
+            .await?;
+        cache
+            .key(fingerprint, class_name, &settings, data)
+            .await
+            .map(Some)
     }
 
     /// Validate data against the schema
@@ -282,9 +641,23 @@ impl ValidationEngine {
 
     /// Validate data as a specific class
     ///
+    /// Runs in a `linkml.validate_as_class` span that records the number of
+    /// issues found, the validation time and whether the report came from
+    /// the result cache.
+    ///
     /// # Errors
     ///
     /// Returns an error if validation fails
+    #[tracing::instrument(
+        name = "linkml.validate_as_class",
+        skip(self, data, options),
+        fields(
+            schema = %self.schema.id,
+            cached = false,
+            issues = tracing::field::Empty,
+            duration_ms = tracing::field::Empty,
+        )
+    )]
     pub async fn validate_as_class(
         &self,
         data: &Value,
@@ -314,11 +687,21 @@ impl ValidationEngine {
             })
         })?;
 
+        let cache_key = self.result_cache_key(data, class_name, &options).await?;
+        if let Some(report) = cache_key
+            .as_ref()
+            .and_then(|key| self.result_cache.get()?.get(key))
+        {
+            let span = tracing::Span::current();
+            span.record("cached", true);
+            span.record("issues", report.issues.len());
+            return Ok(report);
+        }
+
         let mut report = ValidationReport::new(&self.schema.id);
         report.target_class = Some(class_name.to_string());
 
-        let mut context =
-            ValidationContext::with_buffer_pools(self.schema.clone(), self.buffer_pools.clone());
+        let mut context = self.new_context();
 
         // Validate the data
         self.validate_class_instance(
@@ -342,9 +725,23 @@ impl ValidationEngine {
         report.stats.duration_ms = duration.as_millis().try_into().unwrap_or(u64::MAX);
         report.stats.total_validated = 1; // For now, we validate one root object
 
+        if options.coerce_types() {
+            report.coerced_instance = Some(report.with_coerced_values(data));
+        }
+        if let Some(source_map) = &options.source_map {
+            report.locate_issues(source_map);
+        }
+
         // Sort issues by severity and path
         report.sort_issues();
 
+        if let (Some(cache), Some(key)) = (self.result_cache.get(), cache_key) {
+            cache.insert(key, report.clone());
+        }
+
+        let span = tracing::Span::current();
+        span.record("issues", report.issues.len());
+        span.record("duration_ms", report.stats.duration_ms);
         Ok(report)
     }
 
@@ -358,27 +755,83 @@ impl ValidationEngine {
         report: &mut ValidationReport,
         options: &ValidationOptions,
     ) -> Result<()> {
-        let data = self.apply_defaults_and_prepare(data, context, report);
+        let compiled = self
+            .compiled_validator(class_name, class_def, options)
+            .await?;
+        let target = TargetClass {
+            name: class_name,
+            def: class_def,
+            compiled: compiled.as_deref(),
+        };
+        self.validate_target_instance(data, &target, context, report, options)
+    }
+
+    /// Validate a single instance of a class whose compiled validator, the
+    /// only part that needs the async cache, has already been looked up
+    fn validate_target_instance(
+        &self,
+        data: &Value,
+        target: &TargetClass<'_>,
+        context: &mut ValidationContext,
+        report: &mut ValidationReport,
+        options: &ValidationOptions,
+    ) -> Result<()> {
+        let TargetClass {
+            name: class_name,
+            def: class_def,
+            compiled,
+        } = *target;
+        let effective_slots = context.get_effective_slots(class_name)?;
+        let data = self.apply_defaults_and_prepare(data, class_name, context, report);
+        let data = if options.coerce_types() {
+            self.coerce_slot_values(data, &effective_slots, context, report)
+        } else {
+            data
+        };
 
-        self.setup_schema_analysis(class_name).await?;
         self.check_recursion_constraints(&data, class_name, class_def, context, report);
 
         if self.handle_recursion_guard(&data, class_name, class_def, context, report) {
             return Ok(());
         }
 
+        Self::warn_recommended_and_deprecated(
+            &data,
+            class_name,
+            class_def,
+            &effective_slots,
+            context,
+            report,
+        );
+
         let _curie_resolver = CurieResolver::from_schema(&self.schema);
 
         if self.run_conditional_rules(&data, class_name, context, report, options)? {
             return Ok(());
         }
 
-        if self
-            .try_validate_with_compiled_validator(
-                &data, class_name, class_def, context, report, options,
-            )
-            .await?
-        {
+        if let Some(compiled) = compiled {
+            self.run_compiled_validator(compiled, &data, context, report, options);
+            // Compiled validators only cover slot constraints
+            if !options.should_stop(report) {
+                if let Some(obj) = data.as_object() {
+                    let slot_names: Vec<String> = effective_slots
+                        .iter()
+                        .map(|(name, _)| name.clone())
+                        .collect();
+                    self.audit_unknown_slots(
+                        obj,
+                        class_name,
+                        context,
+                        &slot_names,
+                        report,
+                        options,
+                    );
+                }
+                self.run_class_level_validators(
+                    &data, class_name, class_def, context, report, options,
+                );
+            }
             return Ok(());
         }
 
@@ -390,9 +843,13 @@ impl ValidationEngine {
         };
 
         let valid_slot_names =
-            self.validate_declared_slots(&data, obj, class_name, context, report, options);
+            self.validate_declared_slots(&data, obj, &effective_slots, context, report, options);
+        if options.should_stop(report) {
+            context.pop_class();
+            return Ok(());
+        }
 
-        self.audit_unknown_slots(obj, class_name, context, &valid_slot_names, report);
+        self.audit_unknown_slots(obj, class_name, context, &valid_slot_names, report, options);
 
         if self.run_class_level_validators(&data, class_name, class_def, context, report, options) {
             context.pop_class();
@@ -403,6 +860,56 @@ impl ValidationEngine {
         Ok(())
     }
 
+    /// Report missing `recommended` slots and uses of `deprecated` classes
+    /// and slots as warnings, which never make the report invalid
+    fn warn_recommended_and_deprecated(
+        data: &Value,
+        class_name: &str,
+        class_def: &ClassDefinition,
+        effective_slots: &[(String, SlotDefinition)],
+        context: &ValidationContext,
+        report: &mut ValidationReport,
+    ) {
+        if let Some(reason) = &class_def.deprecated {
+            report.add_issue(
+                ValidationIssue::warning(
+                    format!("Class '{class_name}' is deprecated: {reason}"),
+                    context.path(),
+                    "deprecation_checker",
+                )
+                .with_code("DEPRECATED_CLASS"),
+            );
+        }
+
+        let Some(obj) = data.as_object() else {
+            return;
+        };
+        for (name, slot_def) in effective_slots {
+            let present = obj.get(name).is_some_and(|value| !value.is_null());
+            if present {
+                if let Some(reason) = &slot_def.deprecated {
+                    report.add_issue(
+                        ValidationIssue::warning(
+                            format!("Slot '{name}' is deprecated: {reason}"),
+                            format!("{}.{name}", context.path()),
+                            "deprecation_checker",
+                        )
+                        .with_code("DEPRECATED_SLOT"),
+                    );
+                }
+            } else if slot_def.recommended.unwrap_or(false) && !slot_def.required.unwrap_or(false) {
+                report.add_issue(
+                    ValidationIssue::warning(
+                        format!("Recommended slot '{name}' is missing"),
+                        format!("{}.{name}", context.path()),
+                        "recommended_validator",
+                    )
+                    .with_code("RECOMMENDED_SLOT_MISSING"),
+                );
+            }
+        }
+    }
+
     fn handle_recursion_guard(
         &self,
         data: &Value,
@@ -449,7 +956,7 @@ impl ValidationEngine {
                 context.path(),
                 "conditional_validator",
             ));
-            if options.fail_fast() {
+            if options.should_stop(report) {
                 return Ok(true);
             }
         }
@@ -457,28 +964,41 @@ impl ValidationEngine {
         Ok(false)
     }
 
-    async fn try_validate_with_compiled_validator(
+    /// Get the compiled validator of a class, compiling it on first use
+    ///
+    /// Returns `None` if the engine has no validator cache, `options` do
+    /// not allow compiled validators or a slot of the class carries
+    /// `validation:` annotations.
+    async fn compiled_validator(
         &self,
-        data: &Value,
         class_name: &str,
         class_def: &ClassDefinition,
-        context: &mut ValidationContext,
-        report: &mut ValidationReport,
         options: &ValidationOptions,
-    ) -> Result<bool> {
-        if !options.use_cache() {
-            return Ok(false);
+    ) -> Result<Option<Arc<CompiledValidator>>> {
+        // Compiled validators do not visit slot values for registered
+        // validators to check
+        if !options.use_cache() || !self.custom_constraints.is_empty() {
+            return Ok(None);
         }
 
         let Some(cache) = self.compiled_cache.as_ref() else {
-            return Ok(false);
+            return Ok(None);
         };
 
+        // Nor do they check `validation:` annotations
+        if self
+            .new_context()
+            .get_effective_slots(class_name)?
+            .iter()
+            .any(|(_, slot)| has_annotation_constraints(slot.annotations.as_ref()))
+        {
+            return Ok(None);
+        }
+
         let compilation_options = CompilationOptions::default();
         let cache_key = ValidatorCacheKey::new(&self.schema, class_name, &compilation_options);
 
         let compiled_validator = if let Some(validator) = cache.get(&cache_key).await {
-            report.stats.cache_hit_rate = cache.stats().hit_rate();
             validator
         } else {
             let compile_start = self
@@ -512,18 +1032,32 @@ impl ValidationEngine {
                 .await
                 .ok_or_else(|| LinkMLError::service("Failed to retrieve cached validator"))?
         };
+        Ok(Some(compiled_validator))
+    }
+
+    /// Check an instance with the compiled validator of its class
+    fn run_compiled_validator(
+        &self,
+        compiled: &CompiledValidator,
+        data: &Value,
+        context: &mut ValidationContext,
+        report: &mut ValidationReport,
+        options: &ValidationOptions,
+    ) {
+        if let Some(cache) = &self.compiled_cache {
+            report.stats.cache_hit_rate = cache.stats().hit_rate();
+        }
 
-        let issues = compiled_validator.execute(data, context);
+        let issues = compiled.execute(data, context);
         for issue in issues {
             report.add_issue(issue);
-            if options.fail_fast() && !report.valid {
-                return Ok(true);
+            if options.should_stop(report) {
+                return;
             }
         }
 
         report.stats.validators_executed += 1;
         context.pop_class();
-        Ok(true)
     }
 
     fn ensure_object_for_class<'a>(
@@ -556,29 +1090,24 @@ impl ValidationEngine {
         &self,
         data: &Value,
         obj: &serde_json::Map<String, Value>,
-        class_name: &str,
+        effective_slots: &[(String, SlotDefinition)],
         context: &mut ValidationContext,
         report: &mut ValidationReport,
         options: &ValidationOptions,
     ) -> Vec<String> {
         context.set_parent(data.clone());
-        let effective_slots: Vec<(String, SlotDefinition)> = context
-            .get_effective_slots(class_name)
-            .into_iter()
-            .map(|(name, slot_def)| (name.to_string(), slot_def.clone()))
-            .collect();
         let valid_slot_names: Vec<String> = effective_slots
             .iter()
             .map(|(name, _)| name.clone())
             .collect();
 
-        for (name, slot_def) in &effective_slots {
+        for (name, slot_def) in effective_slots {
             if let Some(value) = obj.get(name.as_str()) {
                 context.push_path(name.clone());
                 self.validate_slot_value(value, slot_def, context, report, options);
                 context.pop_path();
 
-                if options.fail_fast() && !report.valid {
+                if options.should_stop(report) {
                     break;
                 }
             } else if slot_def.required.unwrap_or(false) {
@@ -588,7 +1117,7 @@ impl ValidationEngine {
                     "required_validator",
                 ));
 
-                if options.fail_fast() {
+                if options.should_stop(report) {
                     break;
                 }
             }
@@ -604,35 +1133,42 @@ impl ValidationEngine {
         context: &ValidationContext,
         valid_slot_names: &[String],
         report: &mut ValidationReport,
+        options: &ValidationOptions,
     ) {
-        let allow_additional = self
-            .schema
-            .settings
-            .as_ref()
-            .and_then(|s| s.validation.as_ref())
-            .and_then(|v| v.allow_additional_properties)
-            .unwrap_or(true);
+        let policy = options.on_unknown_field.unwrap_or_else(|| {
+            let allow_additional = options.allow_additional_properties.or_else(|| {
+                self.schema
+                    .settings
+                    .as_ref()
+                    .and_then(|s| s.validation.as_ref())
+                    .and_then(|v| v.allow_additional_properties)
+            });
+            if allow_additional == Some(false) {
+                UnknownFieldPolicy::Error
+            } else {
+                UnknownFieldPolicy::Warn
+            }
+        });
 
-        for key in obj.keys() {
+        for (key, value) in obj {
             if valid_slot_names.iter().any(|name| name == key) {
                 continue;
             }
 
-            let issue = if allow_additional {
-                ValidationIssue::warning(
-                    format!("Unknown slot '{key}' in class '{class_name}'"),
-                    format!("{}.{key}", context.path()),
-                    "schema_validator",
-                )
-            } else {
-                ValidationIssue::error(
-                    format!("Unknown slot '{key}' in class '{class_name}'"),
-                    format!("{}.{key}", context.path()),
-                    "schema_validator",
-                )
-            };
-
-            report.add_issue(issue);
+            let message = format!("Unknown slot '{key}' in class '{class_name}'");
+            let path = format!("{}.{key}", context.path());
+            match policy {
+                UnknownFieldPolicy::Ignore => {}
+                UnknownFieldPolicy::Warn => {
+                    report.add_issue(ValidationIssue::warning(message, path, "schema_validator"));
+                }
+                UnknownFieldPolicy::Error => {
+                    report.add_issue(ValidationIssue::error(message, path, "schema_validator"));
+                }
+                UnknownFieldPolicy::Collect => {
+                    report.additional_properties.insert(path, value.clone());
+                }
+            }
         }
     }
 
@@ -649,7 +1185,7 @@ impl ValidationEngine {
             let rule_issues = rule_validator.validate_instance(data, class_name, context);
             for issue in rule_issues {
                 report.add_issue(issue);
-                if options.fail_fast() && !report.valid {
+                if options.should_stop(report) {
                     return true;
                 }
             }
@@ -660,13 +1196,28 @@ impl ValidationEngine {
             let conditional_issues = conditional_validator.validate_class(data, class_def, context);
             for issue in conditional_issues {
                 report.add_issue(issue);
-                if options.fail_fast() && !report.valid {
+                if options.should_stop(report) {
                     return true;
                 }
             }
             report.stats.validators_executed += 1;
         }
 
+        if self.custom_constraints.contains_key(class_name) {
+            let instance_slot = SlotDefinition {
+                range: Some(class_name.to_string()),
+                ..SlotDefinition::new(class_name)
+            };
+            return self.run_custom_constraints(
+                class_name,
+                data,
+                &instance_slot,
+                context,
+                report,
+                options,
+            );
+        }
+
         false
     }
 
@@ -700,7 +1251,29 @@ impl ValidationEngine {
 
             for issue in issues {
                 report.add_issue(issue);
-                if options.fail_fast() && !report.valid {
+                if options.should_stop(report) {
+                    return;
+                }
+            }
+            report.stats.validators_executed += 1;
+        }
+
+        if slot_def.range_type.as_deref() == Some("instance") {
+            for issue in Self::check_instance_values(value, slot_def, context) {
+                report.add_issue(issue);
+                if options.should_stop(report) {
+                    return;
+                }
+            }
+            report.stats.validators_executed += 1;
+        }
+
+        if let Some(annotations) = &slot_def.annotations
+            && has_annotation_constraints(Some(annotations))
+        {
+            for issue in check_annotation_constraints(value, annotations, &context.path()) {
+                report.add_issue(issue);
+                if options.should_stop(report) {
                     return;
                 }
             }
@@ -712,16 +1285,63 @@ impl ValidationEngine {
             let issues = validator.validate(value, slot_def, context);
             for issue in issues {
                 report.add_issue(issue);
-                if options.fail_fast() && !report.valid {
+                if options.should_stop(report) {
                     return;
                 }
             }
             report.stats.validators_executed += 1;
         }
+
+        self.run_custom_constraints(&slot_def.name, value, slot_def, context, report, options);
+    }
+
+    /// Check the values of a `range_type: instance` slot against the
+    /// pre-loaded identifiers, if any were loaded for it
+    fn check_instance_values(
+        value: &Value,
+        slot_def: &linkml_core::types::SlotDefinition,
+        context: &ValidationContext,
+    ) -> Vec<ValidationIssue> {
+        if !context
+            .instance_data
+            .as_ref()
+            .is_some_and(|data| data.contains_key(&slot_def.name))
+        {
+            return Vec::new();
+        }
+
+        let range = slot_def.range.as_deref().unwrap_or("instance");
+        let values: Vec<(String, &Value)> = match value {
+            Value::Array(items) => items
+                .iter()
+                .enumerate()
+                .map(|(index, item)| (format!("{}[{index}]", context.path()), item))
+                .collect(),
+            other => vec![(context.path(), other)],
+        };
+
+        values
+            .into_iter()
+            .filter_map(|(path, item)| {
+                let id = item.as_str()?;
+                (!context.check_instance_permissible(&slot_def.name, id)).then(|| {
+                    ValidationIssue::error(
+                        format!("'{id}' is not a known {range} instance"),
+                        path,
+                        "instance_validator",
+                    )
+                })
+            })
+            .collect()
     }
 
     /// Try to infer the target class from the data
     fn infer_target_class(&self, data: &Value) -> Result<String> {
+        // A designates_type slot names the concrete class of the instance
+        if let Some(class_name) = self.resolve_designated_type(data)? {
+            return Ok(class_name);
+        }
+
         // Simple heuristic: look for a @type field
         if let Some(obj) = data.as_object()
             && let Some(type_value) = obj.get("@type")
@@ -730,17 +1350,9 @@ impl ValidationEngine {
             return Ok(type_str.to_string());
         }
 
-        // If we can't infer, look for tree_root classes
-        let tree_roots: Vec<_> = self
-            .schema
-            .classes
-            .iter()
-            .filter(|(_, class)| class.tree_root.unwrap_or(false))
-            .map(|(name, _)| name.clone())
-            .collect();
-
-        if tree_roots.len() == 1 {
-            return Ok(tree_roots[0].clone());
+        // If we can't infer, fall back to the tree_root class
+        if let Some(tree_root) = self.schema.tree_root_class()? {
+            return Ok(tree_root.to_string());
         }
 
         Err(LinkMLError::schema_validation(
@@ -748,19 +1360,71 @@ impl ValidationEngine {
         ))
     }
 
-    /// Validate a collection of instances with unique key constraints
-    ///
-    /// This method validates multiple instances and checks for unique key violations
-    /// across the entire collection.
+    /// Resolve the class named by a `designates_type` slot in the data
     ///
-    /// # Errors
-    ///
-    /// Returns an error if validation fails
-    pub async fn validate_collection(
-        &mut self,
-        instances: &[Value],
-        class_name: &str,
-        options: Option<ValidationOptions>,
+    /// The value may be a class name, a class URI, or a CURIE or URI whose
+    /// local part is the class name.
+    fn resolve_designated_type(&self, data: &Value) -> Result<Option<String>> {
+        let Some(obj) = data.as_object() else {
+            return Ok(None);
+        };
+
+        let discriminator = self
+            .schema
+            .slots
+            .iter()
+            .chain(
+                self.schema
+                    .classes
+                    .values()
+                    .flat_map(|class| &class.attributes),
+            )
+            .filter(|(_, slot)| slot.designates_type.unwrap_or(false))
+            .find_map(|(name, _)| obj.get(name).map(|value| (name, value)));
+        let Some((slot_name, value)) = discriminator else {
+            return Ok(None);
+        };
+
+        let designated = value.as_str().ok_or_else(|| {
+            LinkMLError::schema_validation(format!(
+                "Type designator slot '{slot_name}' must be a string, got {}",
+                data_type_name(value)
+            ))
+        })?;
+
+        let local_name = designated
+            .rsplit([':', '/', '#'])
+            .next()
+            .unwrap_or(designated);
+        self.schema
+            .classes
+            .iter()
+            .find(|(name, class)| {
+                name.as_str() == designated || class.class_uri.as_deref() == Some(designated)
+            })
+            .or_else(|| self.schema.classes.get_key_value(local_name))
+            .map(|(name, _)| Some(name.clone()))
+            .ok_or_else(|| {
+                LinkMLError::schema_validation(format!(
+                    "Type designator slot '{slot_name}' names '{designated}', \
+                     which does not match any class in the schema"
+                ))
+            })
+    }
+
+    /// Validate a collection of instances with unique key constraints
+    ///
+    /// This method validates multiple instances and checks for unique key violations
+    /// across the entire collection.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if validation fails
+    pub async fn validate_collection(
+        &mut self,
+        instances: &[Value],
+        class_name: &str,
+        options: Option<ValidationOptions>,
     ) -> Result<ValidationReport> {
         let start = self
             .timestamp_service
@@ -782,14 +1446,11 @@ impl ValidationEngine {
         }
 
         // Validate each instance
-        for (index, instance) in instances.iter().enumerate() {
-            let mut context = ValidationContext::with_buffer_pools(
-                self.schema.clone(),
-                self.buffer_pools.clone(),
-            );
+        'instances: for (index, instance) in instances.iter().enumerate() {
+            let mut context = self.new_context();
 
             // Add collection context
-            context.push_path(format!("[{index}]"));
+            context.push_index(index);
 
             // Validate the instance
             let class_def = self.schema.classes.get(class_name).ok_or_else(|| {
@@ -804,6 +1465,9 @@ impl ValidationEngine {
                 &options,
             )
             .await?;
+            if options.should_stop(&report) {
+                break;
+            }
 
             // Run unique key validation after each instance
             if let Some(unique_validator) = self.registry.unique_key_validator()
@@ -818,34 +1482,33 @@ impl ValidationEngine {
 
                 for issue in unique_issues {
                     report.add_issue(issue);
-                    if options.fail_fast() && !report.valid {
-                        return Ok(report);
+                    if options.should_stop(&report) {
+                        break 'instances;
                     }
                 }
             }
 
             context.pop_path();
 
-            if options.fail_fast() && !report.valid {
+            if options.should_stop(&report) {
                 break;
             }
         }
 
-        let end = self
-            .timestamp_service
-            .system_time()
-            .map_err(|e| LinkMLError::service(format!("Failed to get system time: {e}")))?;
-        let duration = end
-            .duration_since(start)
-            .map_err(|e| LinkMLError::service(format!("Time calculation error: {e}")))?;
-        report.stats.duration_ms = u128_to_u64_saturating(duration.as_millis());
+        self.finish_collection_report(&mut report, instances, start, &options)?;
         Ok(report)
     }
 
     /// Validate a collection in parallel
     ///
-    /// This method validates instances in parallel but still maintains
-    /// proper unique key tracking across the collection.
+    /// Instances are validated concurrently on rayon's thread pool, which is
+    /// bounded by the number of CPUs; the calling thread blocks until all are
+    /// done, handing its other tasks to another worker when it runs on a
+    /// multi-threaded tokio runtime. Each instance gets its own context
+    /// sharing the engine's instance data, so instance files are loaded once,
+    /// by [`Self::new_async`], rather than per instance. The issues are then
+    /// merged in collection order and unique keys are checked sequentially,
+    /// giving the same report as [`Self::validate_collection`].
     ///
     /// # Errors
     ///
@@ -856,42 +1519,391 @@ impl ValidationEngine {
         class_name: &str,
         options: Option<ValidationOptions>,
     ) -> Result<ValidationReport> {
-        // For unique key validation, we need sequential processing
-        // to properly track duplicates, so delegate to sequential version
-        self.validate_collection(instances, class_name, options)
-            .await
+        let start = self
+            .timestamp_service
+            .system_time()
+            .map_err(|e| LinkMLError::service(format!("Failed to get system time: {e}")))?;
+        let options = options.unwrap_or_default();
+
+        // Reset unique key validator if present
+        if let Some(validator) = self.registry.unique_key_validator_mut() {
+            let _ = validator.reset();
+        }
+
+        let class_def = self.schema.classes.get(class_name).ok_or_else(|| {
+            LinkMLError::schema_validation(format!("Class '{class_name}' not found in schema"))
+        })?;
+
+        // The compiled validator is the only async step, so look it up once
+        // and validate the instances synchronously
+        let compiled = self
+            .compiled_validator(class_name, class_def, &options)
+            .await?;
+        let target = TargetClass {
+            name: class_name,
+            def: class_def,
+            compiled: compiled.as_deref(),
+        };
+        let validate_instances = || {
+            instances
+                .par_iter()
+                .enumerate()
+                .map(|(index, instance)| {
+                    let mut context = self.new_context();
+                    context.push_index(index);
+                    let mut instance_report = ValidationReport::new(&self.schema.id);
+                    self.validate_target_instance(
+                        instance,
+                        &target,
+                        &mut context,
+                        &mut instance_report,
+                        &options,
+                    )?;
+                    Ok(instance_report)
+                })
+                .collect::<Result<Vec<_>>>()
+        };
+        // Let a multi-threaded runtime move its other tasks off this worker
+        // while it waits for rayon
+        let on_multi_thread_runtime = tokio::runtime::Handle::try_current()
+            .is_ok_and(|handle| handle.runtime_flavor() == RuntimeFlavor::MultiThread);
+        let instance_reports = if on_multi_thread_runtime {
+            tokio::task::block_in_place(validate_instances)?
+        } else {
+            validate_instances()?
+        };
+
+        let mut report = ValidationReport::new(&self.schema.id);
+        report.target_class = Some(class_name.to_string());
+        'instances: for (index, (instance, instance_report)) in
+            instances.iter().zip(instance_reports).enumerate()
+        {
+            report.stats.validators_executed += instance_report.stats.validators_executed;
+            report.stats.cache_hit_rate = instance_report.stats.cache_hit_rate;
+            report
+                .additional_properties
+                .extend(instance_report.additional_properties);
+            report.coerced_values.extend(instance_report.coerced_values);
+            for issue in instance_report.issues {
+                report.add_issue(issue);
+                if options.should_stop(&report) {
+                    break 'instances;
+                }
+            }
+
+            if let Some(unique_validator) = self.registry.unique_key_validator() {
+                let mut context = self.new_context();
+                context.push_index(index);
+                let unique_issues = unique_validator.validate_instance(
+                    instance,
+                    class_def,
+                    &self.schema,
+                    &mut context,
+                );
+                for issue in unique_issues {
+                    report.add_issue(issue);
+                    if options.should_stop(&report) {
+                        break 'instances;
+                    }
+                }
+            }
+        }
+
+        self.finish_collection_report(&mut report, instances, start, &options)?;
+        Ok(report)
+    }
+
+    /// Revalidate a collection after some of its instances changed
+    ///
+    /// `previous` is the report of the collection before the change, from
+    /// [`Self::validate_collection`] or an earlier revalidation, and
+    /// `changed_indices` are the positions of the instances that changed
+    /// since. Only those instances are validated again. Unique keys are
+    /// rechecked for the instances that share an identifier or unique key
+    /// value with a changed instance, before or after the change; all other
+    /// issues are carried over from `previous`. The result is the report a
+    /// full validation of `instances` would give, provided no instances
+    /// were added or removed.
+    ///
+    /// The carried over issues are part of the report from the start, so
+    /// revalidation does not stop early.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the class does not exist, a changed index lies
+    /// outside the collection or validation fails
+    pub async fn revalidate_delta(
+        &mut self,
+        previous: &ValidationReport,
+        changed_indices: &[usize],
+        instances: &[Value],
+        class_name: &str,
+        options: Option<ValidationOptions>,
+    ) -> Result<ValidationReport> {
+        let start = self
+            .timestamp_service
+            .system_time()
+            .map_err(|e| LinkMLError::service(format!("Failed to get system time: {e}")))?;
+        let options = options.unwrap_or_default();
+
+        if let Some(index) = changed_indices
+            .iter()
+            .find(|&&index| index >= instances.len())
+        {
+            return Err(LinkMLError::service(format!(
+                "Changed instance {index} is outside the collection of {} instances",
+                instances.len()
+            )));
+        }
+        let changed: BTreeSet<usize> = changed_indices.iter().copied().collect();
+
+        // Reset unique key validator if present
+        if let Some(validator) = self.registry.unique_key_validator_mut() {
+            let _ = validator.reset();
+        }
+
+        let class_def = self.schema.classes.get(class_name).ok_or_else(|| {
+            LinkMLError::schema_validation(format!("Class '{class_name}' not found in schema"))
+        })?;
+        let rechecked = self.unique_key_dependents(previous, &changed, instances, class_def);
+
+        // Issues by instance, so fresh ones take the place of stale ones
+        let mut issues: BTreeMap<Option<usize>, Vec<ValidationIssue>> = BTreeMap::new();
+        for issue in &previous.issues {
+            let index = instance_index(&issue.path);
+            let stale = index.is_some_and(|index| {
+                changed.contains(&index)
+                    || (rechecked.contains(&index) && issue.validator == "UniqueKeyValidator")
+            });
+            if !stale {
+                issues.entry(index).or_default().push(issue.clone());
+            }
+        }
+
+        let mut report = ValidationReport::new(&self.schema.id);
+        report.target_class = Some(class_name.to_string());
+        report.additional_properties = carried_over(&previous.additional_properties, &changed);
+        report.coerced_values = carried_over(&previous.coerced_values, &changed);
+
+        for &index in &changed {
+            let mut context = self.new_context();
+            context.push_index(index);
+            let mut instance_report = ValidationReport::new(&self.schema.id);
+            self.validate_class_instance(
+                &instances[index],
+                class_name,
+                class_def,
+                &mut context,
+                &mut instance_report,
+                &options,
+            )
+            .await?;
+            report.stats.validators_executed += instance_report.stats.validators_executed;
+            report.stats.cache_hit_rate = instance_report.stats.cache_hit_rate;
+            report
+                .additional_properties
+                .extend(instance_report.additional_properties);
+            report.coerced_values.extend(instance_report.coerced_values);
+            issues
+                .entry(Some(index))
+                .or_default()
+                .extend(instance_report.issues);
+        }
+
+        if let Some(unique_validator) = self.registry.unique_key_validator() {
+            for &index in &rechecked {
+                let mut context = self.new_context();
+                context.push_index(index);
+                let unique_issues = unique_validator.validate_instance(
+                    &instances[index],
+                    class_def,
+                    &self.schema,
+                    &mut context,
+                );
+                issues.entry(Some(index)).or_default().extend(unique_issues);
+            }
+        }
+
+        for issue in issues.into_values().flatten() {
+            report.add_issue(issue);
+        }
+        self.finish_collection_report(&mut report, instances, start, &options)?;
+        Ok(report)
+    }
+
+    /// Instances whose unique keys need checking again after the `changed`
+    /// instances changed
+    ///
+    /// These are the changed instances, the instances a unique key issue in
+    /// `previous` ties to a changed one, and, transitively, every instance
+    /// sharing an identifier or unique key value with one of them. Checking
+    /// them in collection order then finds each duplicate the full
+    /// collection would.
+    fn unique_key_dependents(
+        &self,
+        previous: &ValidationReport,
+        changed: &BTreeSet<usize>,
+        instances: &[Value],
+        class_def: &ClassDefinition,
+    ) -> BTreeSet<usize> {
+        if self.registry.unique_key_validator().is_none() {
+            return BTreeSet::new();
+        }
+
+        let mut pending: Vec<usize> = changed.iter().copied().collect();
+        for issue in previous
+            .issues
+            .iter()
+            .filter(|issue| issue.validator == "UniqueKeyValidator")
+        {
+            let index = instance_index(&issue.path);
+            let first = issue
+                .context
+                .get("first_path")
+                .and_then(Value::as_str)
+                .and_then(instance_index);
+            if [index, first]
+                .into_iter()
+                .flatten()
+                .any(|index| changed.contains(&index))
+            {
+                pending.extend(index.into_iter().chain(first));
+            }
+        }
+
+        let keys: Vec<Vec<(String, String)>> = instances
+            .iter()
+            .map(|instance| UniqueKeyValidator::key_values(instance, class_def, &self.schema))
+            .collect();
+        let mut holders: HashMap<&(String, String), Vec<usize>> = HashMap::new();
+        for (index, instance_keys) in keys.iter().enumerate() {
+            for key in instance_keys {
+                holders.entry(key).or_default().push(index);
+            }
+        }
+
+        let mut dependents = BTreeSet::new();
+        while let Some(index) = pending.pop() {
+            if index >= instances.len() || !dependents.insert(index) {
+                continue;
+            }
+            for key in &keys[index] {
+                pending.extend(holders.get(key).into_iter().flatten());
+            }
+        }
+        dependents
+    }
+
+    /// Record the duration of a collection validation and locate its issues
+    fn finish_collection_report(
+        &self,
+        report: &mut ValidationReport,
+        instances: &[Value],
+        start: std::time::SystemTime,
+        options: &ValidationOptions,
+    ) -> Result<()> {
+        let end = self
+            .timestamp_service
+            .system_time()
+            .map_err(|e| LinkMLError::service(format!("Failed to get system time: {e}")))?;
+        let duration = end
+            .duration_since(start)
+            .map_err(|e| LinkMLError::service(format!("Time calculation error: {e}")))?;
+        report.stats.duration_ms = u128_to_u64_saturating(duration.as_millis());
+
+        if options.coerce_types() {
+            report.coerced_instance =
+                Some(report.with_coerced_values(&Value::Array(instances.to_vec())));
+        }
+        if let Some(source_map) = &options.source_map {
+            report.locate_issues(source_map);
+        }
+        Ok(())
     }
 
     /// Apply defaults and prepare data for validation
     fn apply_defaults_and_prepare(
         &self,
         data: &Value,
+        class_name: &str,
         context: &ValidationContext,
         report: &mut ValidationReport,
     ) -> Value {
         let mut data = data.clone();
-        let default_applier = DefaultApplier::from_schema(&self.schema);
-        if let Err(e) = default_applier.apply_defaults(&mut data, &self.schema) {
-            report.add_issue(ValidationIssue::warning(
-                format!("Failed to apply defaults: {e}"),
-                context.path(),
-                "default_applier",
-            ));
+        if let Err(e) = self.apply_defaults(&mut data, class_name) {
+            report.add_issue(
+                ValidationIssue::error(
+                    format!("Failed to apply defaults: {e}"),
+                    context.path(),
+                    "default_applier",
+                )
+                .with_code("IFABSENT_DEFAULT_FAILED"),
+            );
         }
         data
     }
 
-    /// Setup schema analysis components
-    async fn setup_schema_analysis(&self, class_name: &str) -> Result<()> {
-        // Use SchemaView for comprehensive class analysis
-        let schema_view = SchemaView::new(self.schema.as_ref().clone()).await?;
-        let _class_view = schema_view.class_view(class_name)?;
-
-        // Use InheritanceResolver for complete slot resolution
-        let mut inheritance_resolver = InheritanceResolver::new(&self.schema);
-        let _resolved_class = inheritance_resolver.resolve_class(class_name)?;
+    /// Coerce string values of the class's slots to their primitive range
+    ///
+    /// Each coerced value is recorded in the report's `coerced_values`;
+    /// strings that do not spell a value of the range are reported as errors.
+    fn coerce_slot_values(
+        &self,
+        mut data: Value,
+        effective_slots: &[(String, SlotDefinition)],
+        context: &ValidationContext,
+        report: &mut ValidationReport,
+    ) -> Value {
+        if let Some(obj) = data.as_object_mut() {
+            for (name, slot_def) in effective_slots {
+                let Some(value) = obj.get_mut(name) else {
+                    continue;
+                };
+                let Some(range) = slot_def
+                    .range
+                    .as_deref()
+                    .and_then(|range| CoercibleRange::of(range, &self.schema))
+                else {
+                    continue;
+                };
+
+                let path = format!("{}.{name}", context.path());
+                match value {
+                    Value::Array(items) if slot_def.multivalued.unwrap_or(false) => {
+                        for (index, item) in items.iter_mut().enumerate() {
+                            Self::coerce_value(item, range, format!("{path}[{index}]"), report);
+                        }
+                    }
+                    _ => Self::coerce_value(value, range, path, report),
+                }
+            }
+        }
+        data
+    }
 
-        Ok(())
+    /// Coerce a single string value in place
+    fn coerce_value(
+        value: &mut Value,
+        range: CoercibleRange,
+        path: String,
+        report: &mut ValidationReport,
+    ) {
+        let Value::String(text) = value else {
+            return;
+        };
+        if let Some(coerced) = range.coerce(text) {
+            report.coerced_values.insert(path, coerced.clone());
+            *value = coerced;
+        } else {
+            report.add_issue(
+                ValidationIssue::error(
+                    format!("Cannot coerce '{text}' to {range}"),
+                    path,
+                    "type_coercion",
+                )
+                .with_code("TYPE_COERCION_FAILED"),
+            );
+        }
     }
 
     /// Check recursion constraints
@@ -920,6 +1932,26 @@ impl ValidationEngine {
     }
 }
 
+/// Position in the collection of the instance an issue path points into
+fn instance_index(path: &str) -> Option<usize> {
+    match path_segments(path).first() {
+        Some(PathSegment::Index(index)) => Some(*index),
+        _ => None,
+    }
+}
+
+/// Entries of a by-path report map that belong to unchanged instances
+fn carried_over(
+    values: &IndexMap<String, Value>,
+    changed: &BTreeSet<usize>,
+) -> IndexMap<String, Value> {
+    values
+        .iter()
+        .filter(|(path, _)| !instance_index(path).is_some_and(|index| changed.contains(&index)))
+        .map(|(path, value)| (path.clone(), value.clone()))
+        .collect()
+}
+
 /// Get a human-readable name for a `JSON` value type
 fn data_type_name(value: &Value) -> &'static str {
     match value {
@@ -931,3 +1963,1475 @@ fn data_type_name(value: &Value) -> &'static str {
         Value::Object(_) => "object",
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validator::instance_loader::InstanceLoader;
+    use crate::validator::report::Severity;
+
+    fn person_schema() -> SchemaDefinition {
+        let mut schema = SchemaDefinition::new("people");
+        for name in ["name", "email", "age"] {
+            schema.slots.insert(
+                name.to_string(),
+                SlotDefinition {
+                    required: Some(true),
+                    ..SlotDefinition::new(name)
+                },
+            );
+        }
+        schema.classes.insert(
+            "Person".to_string(),
+            ClassDefinition {
+                slots: ["name", "email", "age"].map(str::to_string).to_vec(),
+                ..ClassDefinition::new("Person")
+            },
+        );
+        schema
+    }
+
+    fn options(fail_fast: bool, max_errors: Option<usize>) -> Option<ValidationOptions> {
+        Some(ValidationOptions {
+            fail_fast: Some(fail_fast),
+            max_errors,
+            ..ValidationOptions::default()
+        })
+    }
+
+    #[tokio::test]
+    async fn test_fail_fast_and_max_errors() -> anyhow::Result<()> {
+        let engine = ValidationEngine::new(&person_schema())?;
+        let data = serde_json::json!({});
+
+        let all = engine
+            .validate_as_class(&data, "Person", options(false, None))
+            .await?;
+        let first = engine
+            .validate_as_class(&data, "Person", options(true, None))
+            .await?;
+        let capped = engine
+            .validate_as_class(&data, "Person", options(false, Some(2)))
+            .await?;
+
+        assert_eq!(all.errors().count(), 3, "{all}");
+        assert_eq!(first.errors().count(), 1, "{first}");
+        assert_eq!(capped.errors().count(), 2, "{capped}");
+        assert!(!all.valid && !first.valid && !capped.valid);
+
+        let valid = engine
+            .validate_as_class(
+                &serde_json::json!({"name": "Ada", "email": "ada@example.org", "age": 36}),
+                "Person",
+                options(true, None),
+            )
+            .await?;
+        assert!(valid.valid, "{valid}");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_unknown_field_policies() -> anyhow::Result<()> {
+        check_unknown_field_policies(&ValidationEngine::new(&person_schema())?).await?;
+        // Compiled validators leave unknown fields to the same policy
+        check_unknown_field_policies(&ValidationEngine::with_cache(
+            &person_schema(),
+            Arc::new(CompiledValidatorCache::new()),
+        )?)
+        .await
+    }
+
+    async fn check_unknown_field_policies(engine: &ValidationEngine) -> anyhow::Result<()> {
+        let data = serde_json::json!({
+            "name": "Ada",
+            "email": "ada@example.org",
+            "age": 36,
+            "lable": "Ada Lovelace"
+        });
+        let with_policy = |policy: Option<UnknownFieldPolicy>| {
+            Some(ValidationOptions {
+                on_unknown_field: policy,
+                ..ValidationOptions::default()
+            })
+        };
+
+        let default = engine
+            .validate_as_class(&data, "Person", with_policy(None))
+            .await?;
+        assert!(default.valid, "{default}");
+        assert_eq!(default.stats.warning_count, 1, "{default}");
+
+        let ignored = engine
+            .validate_as_class(
+                &data,
+                "Person",
+                with_policy(Some(UnknownFieldPolicy::Ignore)),
+            )
+            .await?;
+        assert!(ignored.valid && ignored.issues.is_empty(), "{ignored}");
+
+        let warned = engine
+            .validate_as_class(&data, "Person", with_policy(Some(UnknownFieldPolicy::Warn)))
+            .await?;
+        assert!(warned.valid, "{warned}");
+        assert!(
+            warned
+                .warnings()
+                .any(|issue| issue.message.contains("'lable'"))
+        );
+
+        let rejected = engine
+            .validate_as_class(
+                &data,
+                "Person",
+                with_policy(Some(UnknownFieldPolicy::Error)),
+            )
+            .await?;
+        assert!(!rejected.valid, "{rejected}");
+        assert!(
+            rejected
+                .errors()
+                .any(|issue| issue.message.contains("'lable'"))
+        );
+
+        let collected = engine
+            .validate_as_class(
+                &data,
+                "Person",
+                with_policy(Some(UnknownFieldPolicy::Collect)),
+            )
+            .await?;
+        assert!(
+            collected.valid && collected.issues.is_empty(),
+            "{collected}"
+        );
+        assert_eq!(collected.additional_properties.len(), 1);
+        let (path, value) = collected
+            .additional_properties
+            .first()
+            .ok_or_else(|| anyhow::anyhow!("no additional properties collected"))?;
+        assert!(path.ends_with("lable"), "{path}");
+        assert_eq!(value, "Ada Lovelace");
+        Ok(())
+    }
+
+    fn measurement_schema() -> SchemaDefinition {
+        let mut schema = SchemaDefinition::new("measurements");
+        let slots = [
+            SlotDefinition {
+                range: Some("integer".to_string()),
+                maximum_value: Some(serde_json::json!(150)),
+                ..SlotDefinition::new("age")
+            },
+            SlotDefinition {
+                range: Some("float".to_string()),
+                ..SlotDefinition::new("height")
+            },
+            SlotDefinition {
+                range: Some("boolean".to_string()),
+                ..SlotDefinition::new("living")
+            },
+            SlotDefinition {
+                range: Some("integer".to_string()),
+                multivalued: Some(true),
+                ..SlotDefinition::new("scores")
+            },
+        ];
+        for slot in slots {
+            schema.slots.insert(slot.name.clone(), slot);
+        }
+        schema.classes.insert(
+            "Measurement".to_string(),
+            ClassDefinition {
+                slots: ["age", "height", "living", "scores"]
+                    .map(str::to_string)
+                    .to_vec(),
+                ..ClassDefinition::new("Measurement")
+            },
+        );
+        schema
+    }
+
+    #[tokio::test]
+    async fn test_coerce_types_converts_strings_to_range() -> anyhow::Result<()> {
+        let mut engine = ValidationEngine::new(&measurement_schema())?;
+        let coerce = Some(ValidationOptions {
+            coerce_types: Some(true),
+            ..ValidationOptions::default()
+        });
+        let data = serde_json::json!({
+            "age": "42",
+            "height": " 1.85 ",
+            "living": "TRUE",
+            "scores": ["7", "9"]
+        });
+
+        let strict = engine.validate_as_class(&data, "Measurement", None).await?;
+        assert!(!strict.valid, "{strict}");
+        assert!(strict.coerced_values.is_empty());
+        assert!(strict.coerced_instance.is_none());
+
+        let coerced = engine
+            .validate_as_class(&data, "Measurement", coerce.clone())
+            .await?;
+        assert!(coerced.valid, "{coerced}");
+        let mut values: Vec<(&str, &Value)> = coerced
+            .coerced_values
+            .iter()
+            .map(|(path, value)| (path.rsplit('.').next().unwrap_or(path), value))
+            .collect();
+        values.sort_unstable_by_key(|(slot, _)| *slot);
+        assert_eq!(
+            values,
+            [
+                ("age", &serde_json::json!(42)),
+                ("height", &serde_json::json!(1.85)),
+                ("living", &serde_json::json!(true)),
+                ("scores[0]", &serde_json::json!(7)),
+                ("scores[1]", &serde_json::json!(9)),
+            ]
+        );
+        let expected = serde_json::json!({
+            "age": 42,
+            "height": 1.85,
+            "living": true,
+            "scores": [7, 9]
+        });
+        assert_eq!(coerced.coerced_instance.as_ref(), Some(&expected));
+
+        // A collection comes back with each instance coerced
+        let collection = engine
+            .validate_collection(&[data.clone(), data], "Measurement", coerce.clone())
+            .await?;
+        assert!(collection.valid, "{collection}");
+        assert_eq!(
+            collection.coerced_instance,
+            Some(serde_json::json!([expected.clone(), expected]))
+        );
+
+        // Coerced values are validated against the slot's constraints
+        let too_old = engine
+            .validate_as_class(&serde_json::json!({"age": "200"}), "Measurement", coerce)
+            .await?;
+        assert!(!too_old.valid, "{too_old}");
+        assert!(
+            too_old
+                .errors()
+                .any(|issue| issue.message.contains("maximum"))
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_type_range_and_pattern_validators_check_slots() -> anyhow::Result<()> {
+        let schema: SchemaDefinition = serde_yaml::from_str(
+            r"
+id: https://example.org/countries
+name: countries
+slots:
+  code:
+    range: string
+    pattern: '^[A-Z]{2}$'
+  population:
+    range: integer
+    minimum_value: 0
+classes:
+  Country:
+    slots: [code, population]
+",
+        )?;
+        let engine = ValidationEngine::new(&schema)?;
+
+        let netherlands = serde_json::json!({"code": "NL", "population": 17_900_000});
+        let report = engine
+            .validate_as_class(&netherlands, "Country", None)
+            .await?;
+        assert!(report.valid, "{report}");
+
+        // The registry names these validators in snake_case
+        let cases = [
+            (
+                serde_json::json!({"population": "many"}),
+                "$.population",
+                "type_validator",
+            ),
+            (
+                serde_json::json!({"population": -1}),
+                "$.population",
+                "range_validator",
+            ),
+            (
+                serde_json::json!({"code": "nl"}),
+                "$.code",
+                "enhanced_pattern_validator",
+            ),
+        ];
+        for (instance, path, validator) in cases {
+            let report = engine.validate_as_class(&instance, "Country", None).await?;
+            assert!(
+                report
+                    .errors()
+                    .any(|issue| issue.path == path && issue.validator == validator),
+                "{report}"
+            );
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_coerce_types_reports_impossible_coercions() -> anyhow::Result<()> {
+        let engine = ValidationEngine::new(&measurement_schema())?;
+        let coerce = Some(ValidationOptions {
+            coerce_types: Some(true),
+            ..ValidationOptions::default()
+        });
+        let data = serde_json::json!({
+            "age": "abc",
+            "height": "tall",
+            "living": "yes",
+            "scores": ["7", "4.5"]
+        });
+
+        let report = engine
+            .validate_as_class(&data, "Measurement", coerce)
+            .await?;
+        let mut failures: Vec<&str> = report
+            .errors()
+            .filter(|issue| issue.code.as_deref() == Some("TYPE_COERCION_FAILED"))
+            .map(|issue| issue.message.as_str())
+            .collect();
+        failures.sort_unstable();
+        assert_eq!(
+            failures,
+            [
+                "Cannot coerce '4.5' to integer",
+                "Cannot coerce 'abc' to integer",
+                "Cannot coerce 'tall' to float",
+                "Cannot coerce 'yes' to boolean",
+            ]
+        );
+        assert_eq!(report.coerced_values.len(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_temporal_ranges_check_format_and_bounds() -> anyhow::Result<()> {
+        let mut schema = SchemaDefinition::new("instance_metadata");
+        let slots = [
+            SlotDefinition {
+                range: Some("date".to_string()),
+                minimum_value: Some(serde_json::json!("1900-01-01")),
+                maximum_value: Some(serde_json::json!("2100-12-31")),
+                ..SlotDefinition::new("founded_on")
+            },
+            SlotDefinition {
+                range: Some("datetime".to_string()),
+                minimum_value: Some(serde_json::json!("2000-01-01T00:00:00Z")),
+                ..SlotDefinition::new("created_on")
+            },
+            SlotDefinition {
+                range: Some("time".to_string()),
+                maximum_value: Some(serde_json::json!("18:00:00")),
+                ..SlotDefinition::new("opens_at")
+            },
+        ];
+        for slot in slots {
+            schema.slots.insert(slot.name.clone(), slot);
+        }
+        schema.classes.insert(
+            "InstanceFile".to_string(),
+            ClassDefinition {
+                slots: ["founded_on", "created_on", "opens_at"]
+                    .map(str::to_string)
+                    .to_vec(),
+                ..ClassDefinition::new("InstanceFile")
+            },
+        );
+        let engine = ValidationEngine::new(&schema)?;
+
+        let valid = serde_json::json!({
+            "founded_on": "1945-10-24",
+            "created_on": "2025-03-30T10:41:26+01:00",
+            "opens_at": "09:30:00"
+        });
+        let report = engine
+            .validate_as_class(&valid, "InstanceFile", None)
+            .await?;
+        assert!(report.valid, "{report}");
+
+        let out_of_range = serde_json::json!({
+            "founded_on": "1815-06-18",
+            "created_on": "1999-12-31T23:59:59",
+            "opens_at": "21:00:00"
+        });
+        let report = engine
+            .validate_as_class(&out_of_range, "InstanceFile", None)
+            .await?;
+        let mut messages: Vec<&str> = report
+            .errors()
+            .map(|issue| issue.message.as_str())
+            .collect();
+        messages.sort_unstable();
+        assert_eq!(
+            messages,
+            [
+                "date '1815-06-18' is before minimum 1900-01-01",
+                "datetime '1999-12-31T23:59:59' is before minimum 2000-01-01T00:00:00Z",
+                "time '21:00:00' is after maximum 18:00:00",
+            ]
+        );
+
+        let malformed = serde_json::json!({"created_on": "30/03/2025 10:41"});
+        let report = engine
+            .validate_as_class(&malformed, "InstanceFile", None)
+            .await?;
+        assert!(!report.valid, "{report}");
+        assert!(
+            report
+                .errors()
+                .any(|issue| issue.message.contains("Invalid datetime format"))
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_parallel_collection_matches_sequential() -> anyhow::Result<()> {
+        let mut engine = ValidationEngine::new(&person_schema())?;
+        let instances = [
+            serde_json::json!({"name": "Ada", "email": "ada@example.org", "age": 36}),
+            serde_json::json!({"name": "Alan", "age": 41}),
+            serde_json::json!({}),
+            serde_json::json!({"name": "Grace", "email": "grace@example.org", "age": 85}),
+            serde_json::json!({"email": "edsger@example.org"}),
+        ];
+
+        let sequential = engine
+            .validate_collection(&instances, "Person", None)
+            .await?;
+        let parallel = engine
+            .validate_collection_parallel(&instances, "Person", None)
+            .await?;
+
+        let issues = |report: &ValidationReport| -> Vec<(String, String)> {
+            report
+                .issues
+                .iter()
+                .map(|issue| (issue.path.clone(), issue.message.clone()))
+                .collect()
+        };
+        assert!(!parallel.valid, "{parallel}");
+        assert_eq!(parallel.stats.error_count, sequential.stats.error_count);
+        assert_eq!(issues(&parallel), issues(&sequential));
+        // Issues keep the index of the instance they were found in
+        assert!(
+            parallel.issues[0].path.contains("[1]"),
+            "{}",
+            parallel.issues[0].path
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_revalidate_delta_rechecks_affected_unique_keys() -> anyhow::Result<()> {
+        let mut schema = SchemaDefinition::new("people");
+        schema.slots.insert(
+            "id".to_string(),
+            SlotDefinition {
+                identifier: Some(true),
+                ..SlotDefinition::new("id")
+            },
+        );
+        schema.classes.insert(
+            "Person".to_string(),
+            ClassDefinition {
+                slots: vec!["id".to_string()],
+                ..ClassDefinition::new("Person")
+            },
+        );
+        let mut engine = ValidationEngine::new(&schema)?;
+        let mut instances = ["ada", "ada", "alan", "grace"]
+            .map(|id| serde_json::json!({ "id": id }))
+            .to_vec();
+
+        let mut previous = engine
+            .validate_collection(&instances, "Person", None)
+            .await?;
+        assert_eq!(previous.issues.len(), 1, "{previous}");
+        // Mark the duplicate of `ada` to tell carried over issues from fresh ones
+        previous.issues[0].message = "carried over".to_string();
+
+        instances[3] = serde_json::json!({"id": "alan"});
+        let updated = engine
+            .revalidate_delta(&previous, &[3], &instances, "Person", None)
+            .await?;
+        let issues = |report: &ValidationReport| -> Vec<(String, Option<String>)> {
+            report
+                .issues
+                .iter()
+                .map(|issue| (issue.path.clone(), issue.code.clone()))
+                .collect()
+        };
+        let full = engine
+            .validate_collection(&instances, "Person", None)
+            .await?;
+        assert_eq!(issues(&updated), issues(&full));
+        assert_eq!(updated.stats.error_count, 2);
+        // Only the uniqueness of `alan` was checked again
+        assert_eq!(updated.issues[0].message, "carried over");
+        assert_eq!(
+            updated.issues[1].context.get("first_path"),
+            Some(&serde_json::json!("$[2]"))
+        );
+
+        // Renaming the first `ada` resolves the duplicate it was tied to
+        instances[0] = serde_json::json!({"id": "edsger"});
+        let updated = engine
+            .revalidate_delta(&updated, &[0], &instances, "Person", None)
+            .await?;
+        let full = engine
+            .validate_collection(&instances, "Person", None)
+            .await?;
+        assert_eq!(issues(&updated), issues(&full));
+        assert_eq!(
+            issues(&updated),
+            [("$[3]".to_string(), full.issues[0].code.clone())]
+        );
+
+        assert!(
+            engine
+                .revalidate_delta(&updated, &[4], &instances, "Person", None)
+                .await
+                .is_err()
+        );
+        Ok(())
+    }
+
+    struct LeadingDot;
+
+    impl CustomValidator for LeadingDot {
+        fn validate(
+            &self,
+            value: &Value,
+            slot: &SlotDefinition,
+            _schema: &SchemaDefinition,
+        ) -> Vec<linkml_core::types::ValidationError> {
+            match value.as_str() {
+                Some(text) if !text.starts_with('.') => {
+                    vec![linkml_core::types::ValidationError {
+                        message: format!("{} '{text}' must start with a dot", slot.name),
+                        path: None,
+                        expected: Some(format!(".{text}")),
+                        actual: Some(text.to_string()),
+                        severity: linkml_core::types::Severity::Error,
+                    }]
+                }
+                _ => Vec::new(),
+            }
+        }
+
+        fn name(&self) -> &str {
+            "leading_dot"
+        }
+    }
+
+    struct TldMatchesId;
+
+    impl CustomValidator for TldMatchesId {
+        fn validate(
+            &self,
+            value: &Value,
+            _slot: &SlotDefinition,
+            _schema: &SchemaDefinition,
+        ) -> Vec<linkml_core::types::ValidationError> {
+            let id = value["id"].as_str().unwrap_or_default().to_lowercase();
+            match value["tld"].as_str() {
+                Some(tld) if tld.trim_start_matches('.') != id => {
+                    vec![linkml_core::types::ValidationError {
+                        message: format!("tld '{tld}' does not match id"),
+                        path: Some("tld".to_string()),
+                        expected: None,
+                        actual: None,
+                        severity: linkml_core::types::Severity::Warning,
+                    }]
+                }
+                _ => Vec::new(),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_registered_validators_run_with_built_in_checks() -> anyhow::Result<()> {
+        let mut schema = SchemaDefinition::new("countries");
+        for name in ["id", "tld"] {
+            schema
+                .slots
+                .insert(name.to_string(), SlotDefinition::new(name));
+        }
+        schema.classes.insert(
+            "Country".to_string(),
+            ClassDefinition {
+                slots: vec!["id".to_string(), "tld".to_string()],
+                ..ClassDefinition::new("Country")
+            },
+        );
+        let mut engine = ValidationEngine::new(&schema)?;
+        engine.register_validator("tld", Box::new(LeadingDot));
+        engine.register_validator("Country", Box::new(TldMatchesId));
+
+        let report = engine
+            .validate_as_class(
+                &serde_json::json!({"id": "NL", "tld": ".nl"}),
+                "Country",
+                None,
+            )
+            .await?;
+        assert!(report.issues.is_empty(), "{report}");
+
+        let report = engine
+            .validate_as_class(
+                &serde_json::json!({"id": "FR", "tld": "de"}),
+                "Country",
+                None,
+            )
+            .await?;
+        assert!(!report.valid);
+        let issues: Vec<_> = report
+            .issues
+            .iter()
+            .map(|issue| {
+                (
+                    issue.path.as_str(),
+                    issue.validator.as_str(),
+                    issue.severity,
+                )
+            })
+            .collect();
+        assert_eq!(
+            issues,
+            [
+                ("$.tld", "leading_dot", Severity::Error),
+                ("$.tld", "custom_validator", Severity::Warning),
+            ]
+        );
+        assert_eq!(report.issues[0].message, "tld 'de' must start with a dot");
+        assert_eq!(
+            report.issues[0].context.get("expected"),
+            Some(&serde_json::json!(".de"))
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_validation_annotations_bound_string_length() -> anyhow::Result<()> {
+        let schema: SchemaDefinition = serde_yaml::from_str(
+            r"
+id: https://example.org/countries
+name: countries
+slots:
+  code:
+    range: string
+    annotations:
+      validation:min_length: 2
+      validation:max_length: 3
+classes:
+  Country:
+    slots: [code]
+",
+        )?;
+        // The cached engine validates like the service does
+        let engines = [
+            ValidationEngine::new(&schema)?,
+            ValidationEngine::with_cache(&schema, Arc::new(CompiledValidatorCache::new()))?,
+        ];
+
+        for engine in &engines {
+            for code in ["NL", "NLD"] {
+                let report = engine
+                    .validate_as_class(&serde_json::json!({ "code": code }), "Country", None)
+                    .await?;
+                assert!(report.valid, "{report}");
+            }
+
+            let mut codes = Vec::new();
+            for code in ["N", "NLD1"] {
+                let report = engine
+                    .validate_as_class(&serde_json::json!({ "code": code }), "Country", None)
+                    .await?;
+                assert!(!report.valid);
+                assert_eq!(report.issues.len(), 1, "{report}");
+                assert_eq!(report.issues[0].path, "$.code");
+                codes.extend(report.issues[0].code.clone());
+            }
+            assert_eq!(codes, ["ANNOTATION_MIN_LENGTH", "ANNOTATION_MAX_LENGTH"]);
+        }
+
+        // Misspelled constraints are reported rather than ignored
+        let mut schema = schema;
+        if let Some(annotations) = schema
+            .slots
+            .get_mut("code")
+            .and_then(|slot| slot.annotations.as_mut())
+        {
+            annotations.insert("validation:max_lenght".to_string(), 3.into());
+        }
+        let engine = ValidationEngine::new(&schema)?;
+        let report = engine
+            .validate_as_class(&serde_json::json!({"code": "NL"}), "Country", None)
+            .await?;
+        assert_eq!(
+            report.issues[0].code.as_deref(),
+            Some("UNKNOWN_VALIDATION_ANNOTATION")
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_class_defined_by_attributes_only() -> anyhow::Result<()> {
+        let schema: SchemaDefinition = serde_yaml::from_str(
+            r"
+id: https://example.org/people
+name: people
+classes:
+  Person:
+    attributes:
+      id:
+        identifier: true
+        range: string
+      name:
+        range: string
+        required: true
+        pattern: '^[A-Z][a-z]+( [A-Z][a-z]+)*$'
+      age:
+        range: integer
+        minimum_value: 0
+        maximum_value: 150
+      status:
+        range: string
+        ifabsent: string(ALIVE)
+",
+        )?;
+
+        let valid = serde_json::json!({"id": "P1", "name": "Ada Lovelace", "age": 36});
+        let invalid = serde_json::json!({"id": "P2", "age": -1});
+        let misnamed = serde_json::json!({"id": "P3", "name": "ada", "age": "old"});
+
+        let mut engine = ValidationEngine::new(&schema)?;
+        let report = engine.validate_as_class(&valid, "Person", None).await?;
+        assert!(report.valid, "{report}");
+
+        let report = engine.validate_as_class(&invalid, "Person", None).await?;
+        let paths: BTreeSet<_> = report.issues.iter().map(|i| i.path.as_str()).collect();
+        assert_eq!(paths, BTreeSet::from(["$.age", "$.name"]), "{report}");
+
+        let report = engine.validate_as_class(&misnamed, "Person", None).await?;
+        let paths: BTreeSet<_> = report.issues.iter().map(|i| i.path.as_str()).collect();
+        assert_eq!(paths, BTreeSet::from(["$.age", "$.name"]), "{report}");
+
+        // An identifier attribute is unique across a collection
+        let report = engine
+            .validate_collection(&[valid.clone(), valid.clone()], "Person", None)
+            .await?;
+        assert_eq!(report.issues.len(), 1, "{report}");
+        assert_eq!(
+            report.issues[0].code.as_deref(),
+            Some("DUPLICATE_IDENTIFIER")
+        );
+
+        // Defaults of attributes are filled in
+        let mut defaulted = valid.clone();
+        engine.apply_defaults(&mut defaulted, "Person")?;
+        assert_eq!(defaulted["status"], "ALIVE");
+
+        // Compiled validators check attributes too
+        let cached =
+            ValidationEngine::with_cache(&schema, Arc::new(CompiledValidatorCache::new()))?;
+        let report = cached.validate_as_class(&valid, "Person", None).await?;
+        assert!(report.valid, "{report}");
+        let report = cached.validate_as_class(&invalid, "Person", None).await?;
+        assert!(!report.valid);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_min_and_max_length_bound_strings() -> anyhow::Result<()> {
+        let schema: SchemaDefinition = serde_yaml::from_str(
+            r"
+id: https://example.org/countries
+name: countries
+types:
+  tld_string:
+    typeof: string
+    min_length: 2
+    max_length: 63
+  country_tld:
+    typeof: tld_string
+slots:
+  code:
+    range: string
+    min_length: 2
+    max_length: 3
+  tld:
+    range: country_tld
+  aliases:
+    range: string
+    multivalued: true
+    max_length: 5
+classes:
+  Country:
+    slots: [code, tld, aliases]
+",
+        )?;
+        // The cached engine checks lengths through compiled instructions
+        let engines = [
+            ValidationEngine::new(&schema)?,
+            ValidationEngine::with_cache(&schema, Arc::new(CompiledValidatorCache::new()))?,
+        ];
+        let in_range = serde_json::json!({"code": "NLD", "tld": "nl", "aliases": ["NL"]});
+
+        let cases = [
+            (
+                serde_json::json!({"code": "N"}),
+                "$.code",
+                "MIN_LENGTH_VIOLATION",
+            ),
+            (
+                serde_json::json!({"code": "NLD1"}),
+                "$.code",
+                "MAX_LENGTH_VIOLATION",
+            ),
+            // Bounds of the range type apply through typeof
+            (
+                serde_json::json!({"tld": "n"}),
+                "$.tld",
+                "MIN_LENGTH_VIOLATION",
+            ),
+            (
+                serde_json::json!({"tld": "n".repeat(64)}),
+                "$.tld",
+                "MAX_LENGTH_VIOLATION",
+            ),
+            // Each value of a multivalued slot is checked
+            (
+                serde_json::json!({"aliases": ["NL", "Holland"]}),
+                "$.aliases[1]",
+                "MAX_LENGTH_VIOLATION",
+            ),
+        ];
+        for engine in &engines {
+            let report = engine.validate_as_class(&in_range, "Country", None).await?;
+            assert!(report.valid, "{report}");
+
+            for (instance, path, code) in &cases {
+                let report = engine.validate_as_class(instance, "Country", None).await?;
+                assert!(!report.valid);
+                assert_eq!(report.issues.len(), 1, "{report}");
+                assert_eq!(report.issues[0].path, *path);
+                assert_eq!(report.issues[0].code.as_deref(), Some(*code));
+            }
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_whitespace_facet_applies_before_length_check() -> anyhow::Result<()> {
+        let schema: SchemaDefinition = serde_yaml::from_str(
+            r"
+id: https://example.org/countries
+name: countries
+types:
+  label_string:
+    typeof: string
+    max_length: 10
+    whitespace: collapse
+slots:
+  label:
+    range: label_string
+  code:
+    range: string
+    max_length: 3
+    whitespace: preserve
+classes:
+  Country:
+    slots: [label, code]
+",
+        )?;
+        let engines = [
+            ValidationEngine::new(&schema)?,
+            ValidationEngine::with_cache(&schema, Arc::new(CompiledValidatorCache::new()))?,
+        ];
+
+        for engine in &engines {
+            // Collapsed, the label is "Hol land" and fits
+            let collapsed = serde_json::json!({"label": "  Hol\tland  "});
+            let report = engine
+                .validate_as_class(&collapsed, "Country", None)
+                .await?;
+            assert!(report.valid, "{report}");
+
+            let too_long = serde_json::json!({"label": "The  Netherlands"});
+            let report = engine.validate_as_class(&too_long, "Country", None).await?;
+            assert_eq!(report.issues.len(), 1, "{report}");
+            assert_eq!(report.issues[0].path, "$.label");
+
+            // Preserved whitespace counts towards the length
+            let padded = serde_json::json!({"code": " NL "});
+            let report = engine.validate_as_class(&padded, "Country", None).await?;
+            assert_eq!(report.issues.len(), 1, "{report}");
+            assert_eq!(
+                report.issues[0].code.as_deref(),
+                Some("MAX_LENGTH_VIOLATION")
+            );
+        }
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_recommended_and_deprecated_are_warnings() -> anyhow::Result<()> {
+        let mut schema = person_schema();
+        for (name, slot) in &mut schema.slots {
+            slot.required = Some(name == "name");
+        }
+        if let Some(slot) = schema.slots.get_mut("email") {
+            slot.recommended = Some(true);
+        }
+        if let Some(slot) = schema.slots.get_mut("age") {
+            slot.deprecated = Some("use birth_date".to_string());
+        }
+        let engine = ValidationEngine::new(&schema)?;
+
+        let report = engine
+            .validate_as_class(
+                &serde_json::json!({"name": "Ada", "age": 36}),
+                "Person",
+                None,
+            )
+            .await?;
+
+        assert!(report.valid, "{report}");
+        assert_eq!(report.errors().count(), 0);
+        let mut codes: Vec<_> = report
+            .warnings()
+            .map(|issue| (issue.code.as_deref(), issue.path.as_str()))
+            .collect();
+        codes.sort_unstable();
+        assert_eq!(
+            codes,
+            [
+                (Some("DEPRECATED_SLOT"), "$.age"),
+                (Some("RECOMMENDED_SLOT_MISSING"), "$.email"),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_effective_slots_are_resolved_once_and_errors_surface() -> anyhow::Result<()> {
+        let engine = ValidationEngine::new(&person_schema())?;
+        let first = engine.new_context().get_effective_slots("Person")?;
+        let second = engine.new_context().get_effective_slots("Person")?;
+        assert!(Arc::ptr_eq(&first, &second));
+        assert!(engine.new_context().get_effective_slots("Nobody").is_err());
+
+        let mut schema = person_schema();
+        for (name, parent) in [("Person", "Agent"), ("Agent", "Person")] {
+            schema
+                .classes
+                .entry(name.to_string())
+                .or_insert_with(|| ClassDefinition::new(name))
+                .is_a = Some(parent.to_string());
+        }
+        let context = ValidationContext::new(Arc::new(schema));
+        assert!(context.get_effective_slots("Person").is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_validate_resolves_designated_type() -> anyhow::Result<()> {
+        let mut schema = SchemaDefinition::new("shapes");
+        schema.default_prefix = Some("shapes".to_string());
+        schema.slots.insert(
+            "category".to_string(),
+            SlotDefinition {
+                designates_type: Some(true),
+                range: Some("string".to_string()),
+                ..SlotDefinition::new("category")
+            },
+        );
+        for (slot, class) in [("radius", "Circle"), ("side", "Square")] {
+            schema.slots.insert(
+                slot.to_string(),
+                SlotDefinition {
+                    required: Some(true),
+                    range: Some("float".to_string()),
+                    ..SlotDefinition::new(slot)
+                },
+            );
+            schema.classes.insert(
+                class.to_string(),
+                ClassDefinition {
+                    slots: vec!["category".to_string(), slot.to_string()],
+                    ..ClassDefinition::new(class)
+                },
+            );
+        }
+        let engine = ValidationEngine::new(&schema)?;
+
+        let circle = engine
+            .validate(
+                &serde_json::json!({"category": "Circle", "radius": 1.5}),
+                None,
+            )
+            .await?;
+        assert!(circle.valid, "{circle}");
+        assert_eq!(circle.target_class.as_deref(), Some("Circle"));
+
+        // A CURIE resolves by its local name, and the square is checked as a square
+        let square = engine
+            .validate(
+                &serde_json::json!({"category": "shapes:Square", "radius": 2.0}),
+                None,
+            )
+            .await?;
+        assert_eq!(square.target_class.as_deref(), Some("Square"));
+        assert!(!square.valid, "{square}");
+        assert!(
+            square.errors().any(|issue| issue.path == "$.side"),
+            "{square}"
+        );
+
+        let error = engine
+            .validate(&serde_json::json!({"category": "Triangle"}), None)
+            .await
+            .expect_err("unknown class");
+        assert!(error.to_string().contains("'Triangle'"), "{error}");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_collection_stops_early() -> anyhow::Result<()> {
+        let mut engine = ValidationEngine::new(&person_schema())?;
+        let instances = vec![serde_json::json!({}); 3];
+
+        let all = engine
+            .validate_collection(&instances, "Person", options(false, None))
+            .await?;
+        let first = engine
+            .validate_collection(&instances, "Person", options(true, None))
+            .await?;
+        let capped = engine
+            .validate_collection(&instances, "Person", options(false, Some(4)))
+            .await?;
+
+        assert_eq!(all.errors().count(), 9, "{all}");
+        assert_eq!(first.errors().count(), 1, "{first}");
+        assert!(first.issues[0].path.starts_with("$[0]."), "{first}");
+        assert_eq!(capped.errors().count(), 4, "{capped}");
+        assert!(!first.valid && !capped.valid);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_new_async_preloads_instance_ranges() -> anyhow::Result<()> {
+        let dir = tempfile::TempDir::new()?;
+        let instance_file = dir.path().join("place/polity/country/iso_3166_entity.yaml");
+        std::fs::create_dir_all(dir.path().join("place/polity/country"))?;
+        std::fs::write(
+            &instance_file,
+            "instances:\n  - id: NL\n  - id: DE\n  - id: FR\n",
+        )?;
+
+        // The range class comes from a schema-level import
+        let countries_file = dir.path().join("countries.yaml");
+        std::fs::write(
+            &countries_file,
+            "id: https://example.org/countries\nname: countries\nclasses:\n  Country:\n    slots: [code]\nslots:\n  code:\n    range: string\n",
+        )?;
+
+        let mut schema = SchemaDefinition::new("addresses");
+        schema
+            .imports
+            .push(dir.path().join("countries").display().to_string());
+        schema
+            .imports
+            .push("txp:place/polity/country/iso_3166_entity/instance".to_string());
+        schema.slots.insert(
+            "country".to_string(),
+            SlotDefinition {
+                range: Some("Country".to_string()),
+                range_type: Some("instance".to_string()),
+                ..SlotDefinition::new("country")
+            },
+        );
+        schema.classes.insert(
+            "Address".to_string(),
+            ClassDefinition {
+                slots: vec!["country".to_string()],
+                ..ClassDefinition::new("Address")
+            },
+        );
+
+        let resolver = InstanceResolver::new(
+            dir.path().to_path_buf(),
+            Arc::new(InstanceLoader::default()),
+        );
+        let engine = ValidationEngine::new_async(&schema, &resolver).await?;
+        assert_eq!(resolver.cached_entries(), 1);
+
+        // Imports and the code set were loaded up front, so validation needs
+        // no file access
+        std::fs::remove_file(&countries_file)?;
+        std::fs::remove_file(&instance_file)?;
+        let country = engine
+            .validate_as_class(&serde_json::json!({"code": "NL"}), "Country", None)
+            .await?;
+        assert!(country.valid, "{country}");
+        let valid = engine
+            .validate_as_class(&serde_json::json!({"country": "NL"}), "Address", None)
+            .await?;
+        assert!(valid.valid, "{valid}");
+        let invalid = engine
+            .validate_as_class(&serde_json::json!({"country": "XX"}), "Address", None)
+            .await?;
+        assert!(!invalid.valid);
+        assert!(
+            invalid.errors().any(|issue| issue
+                .message
+                .contains("'XX' is not a known Country instance")),
+            "{invalid}"
+        );
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_parallel_collection_with_imported_schema() -> anyhow::Result<()> {
+        let dir = tempfile::TempDir::new()?;
+        std::fs::write(
+            dir.path().join("countries.yaml"),
+            "id: https://example.org/countries\nname: countries\nclasses:\n  Country:\n    slots: [code]\nslots:\n  code:\n    range: string\n    pattern: \"^[A-Z]{2}$\"\n",
+        )?;
+        let mut schema = SchemaDefinition::new("atlas");
+        schema
+            .imports
+            .push(dir.path().join("countries").display().to_string());
+
+        let resolver = InstanceResolver::new(
+            dir.path().to_path_buf(),
+            Arc::new(InstanceLoader::default()),
+        );
+        let mut engine = ValidationEngine::new_async(&schema, &resolver).await?;
+        let instances: Vec<_> = ["NL", "nl", "DE", "FRA"]
+            .map(|code| serde_json::json!({ "code": code }))
+            .to_vec();
+
+        let parallel = engine
+            .validate_collection_parallel(&instances, "Country", None)
+            .await?;
+        let sequential = engine
+            .validate_collection(&instances, "Country", None)
+            .await?;
+
+        assert_eq!(parallel.stats.error_count, 2, "{parallel}");
+        let paths = |report: &ValidationReport| -> Vec<String> {
+            report.errors().map(|issue| issue.path.clone()).collect()
+        };
+        assert_eq!(paths(&parallel), paths(&sequential));
+        Ok(())
+    }
+
+    /// Fields of a span, as recorded so far
+    #[derive(Default)]
+    struct SpanFields(HashMap<String, String>);
+
+    impl tracing::field::Visit for SpanFields {
+        fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+            self.0.insert(field.name().to_string(), value.to_string());
+        }
+
+        fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+            self.0
+                .insert(field.name().to_string(), format!("{value:?}"));
+        }
+    }
+
+    /// Name and final fields of a closed span
+    type ClosedSpan = (String, HashMap<String, String>);
+
+    /// Layer collecting every closed span
+    #[derive(Clone, Default)]
+    struct SpanCollector {
+        closed: Arc<parking_lot::Mutex<Vec<ClosedSpan>>>,
+    }
+
+    impl SpanCollector {
+        fn fields(&self, name: &str) -> Vec<HashMap<String, String>> {
+            self.closed
+                .lock()
+                .iter()
+                .filter(|(span_name, _)| span_name == name)
+                .map(|(_, fields)| fields.clone())
+                .collect()
+        }
+    }
+
+    impl<S> tracing_subscriber::Layer<S> for SpanCollector
+    where
+        S: tracing::Subscriber + for<'a> tracing_subscriber::registry::LookupSpan<'a>,
+    {
+        fn on_new_span(
+            &self,
+            attrs: &tracing::span::Attributes<'_>,
+            id: &tracing::span::Id,
+            ctx: tracing_subscriber::layer::Context<'_, S>,
+        ) {
+            let mut fields = SpanFields::default();
+            attrs.record(&mut fields);
+            if let Some(span) = ctx.span(id) {
+                span.extensions_mut().insert(fields);
+            }
+        }
+
+        fn on_record(
+            &self,
+            id: &tracing::span::Id,
+            values: &tracing::span::Record<'_>,
+            ctx: tracing_subscriber::layer::Context<'_, S>,
+        ) {
+            if let Some(span) = ctx.span(id) {
+                let mut extensions = span.extensions_mut();
+                if let Some(fields) = extensions.get_mut::<SpanFields>() {
+                    values.record(fields);
+                }
+            }
+        }
+
+        fn on_close(&self, id: tracing::span::Id, ctx: tracing_subscriber::layer::Context<'_, S>) {
+            if let Some(span) = ctx.span(&id) {
+                let fields = span
+                    .extensions_mut()
+                    .remove::<SpanFields>()
+                    .unwrap_or_default();
+                self.closed.lock().push((span.name().to_string(), fields.0));
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_spans_record_counts_and_durations() -> anyhow::Result<()> {
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let collector = SpanCollector::default();
+        let _guard = tracing::subscriber::set_default(
+            tracing_subscriber::registry().with(collector.clone()),
+        );
+
+        let dir = tempfile::TempDir::new()?;
+        std::fs::create_dir_all(dir.path().join("place/polity/country"))?;
+        std::fs::write(
+            dir.path().join("place/polity/country/iso_3166_entity.yaml"),
+            "instances:\n  - id: NL\n  - id: DE\n",
+        )?;
+        std::fs::write(
+            dir.path().join("countries.yaml"),
+            "id: https://example.org/countries\nname: countries\nclasses:\n  Country: {}\n",
+        )?;
+
+        let mut schema = SchemaDefinition::new("addresses");
+        schema
+            .imports
+            .push("txp:place/polity/country/iso_3166_entity/instance".to_string());
+        schema
+            .imports
+            .push(dir.path().join("countries").display().to_string());
+        schema.slots.insert(
+            "country".to_string(),
+            SlotDefinition {
+                range: Some("Country".to_string()),
+                range_type: Some("instance".to_string()),
+                ..SlotDefinition::new("country")
+            },
+        );
+        schema.classes.insert(
+            "Address".to_string(),
+            ClassDefinition {
+                slots: vec!["country".to_string()],
+                ..ClassDefinition::new("Address")
+            },
+        );
+
+        let resolver = InstanceResolver::new(
+            dir.path().to_path_buf(),
+            Arc::new(InstanceLoader::default()),
+        );
+        let engine = ValidationEngine::new_async(&schema, &resolver).await?;
+        engine
+            .validate_as_class(&serde_json::json!({"country": "XX"}), "Address", None)
+            .await?;
+
+        let lookups = collector.fields("linkml.instance_ids");
+        assert_eq!(lookups.len(), 1);
+        assert_eq!(lookups[0]["slot"], "country");
+        assert_eq!(lookups[0]["ids"], "2");
+        assert!(lookups[0].contains_key("elapsed_ms"));
+
+        let validations = collector.fields("linkml.validate_as_class");
+        assert_eq!(validations.len(), 1);
+        assert_eq!(validations[0]["class_name"], "Address");
+        assert_eq!(validations[0]["schema"], schema.id);
+        assert_eq!(validations[0]["issues"], "1");
+        assert!(validations[0].contains_key("duration_ms"));
+
+        // The instance import is left to the instance resolver
+        let resolutions = collector.fields("linkml.resolve_imports");
+        assert_eq!(resolutions.len(), 1);
+        assert_eq!(resolutions[0]["schema"], "addresses");
+        assert_eq!(resolutions[0]["imports"], "2");
+        assert_eq!(resolutions[0]["resolved"], "1");
+        assert!(resolutions[0].contains_key("elapsed_ms"));
+
+        // A second resolution with the same resolver hits the import cache
+        let imports = ImportResolverV2::new();
+        imports.resolve_imports(&schema).await?;
+        imports.resolve_imports(&schema).await?;
+        let loads = collector.fields("linkml.load_import");
+        assert_eq!(loads.len(), 3);
+        assert_eq!(
+            loads
+                .iter()
+                .map(|load| load["cached"].as_str())
+                .collect::<Vec<_>>(),
+            ["false", "false", "true"]
+        );
+        assert!(loads.iter().all(|load| load.contains_key("elapsed_ms")));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_new_async_checks_dynamic_enum_values() -> anyhow::Result<()> {
+        let dir = tempfile::TempDir::new()?;
+        std::fs::create_dir_all(dir.path().join("language"))?;
+        std::fs::write(
+            dir.path().join("language/iso_639_3_entity.yaml"),
+            "instances:\n  - id: eng\n  - id: nld\n",
+        )?;
+        let schema: SchemaDefinition = serde_yaml::from_str(
+            r"
+id: https://example.org/documents
+name: documents
+enums:
+  LanguageCode:
+    code_set: txp:language/iso_639_3_entity/instance
+slots:
+  language:
+    range: LanguageCode
+classes:
+  Document:
+    slots: [language]
+",
+        )?;
+
+        let resolver = InstanceResolver::new(
+            dir.path().to_path_buf(),
+            Arc::new(InstanceLoader::default()),
+        );
+        let engine = ValidationEngine::new_async(&schema, &resolver).await?;
+        let report = engine
+            .validate_as_class(&serde_json::json!({"language": "nld"}), "Document", None)
+            .await?;
+        assert!(report.valid, "{report}");
+        let report = engine
+            .validate_as_class(&serde_json::json!({"language": "xxx"}), "Document", None)
+            .await?;
+        assert_eq!(report.stats.error_count, 1, "{report}");
+        assert!(
+            report
+                .errors()
+                .any(|issue| issue.message.contains("'xxx' is not in permissible values")),
+            "{report}"
+        );
+
+        // Without the resolved code set, values are not checked
+        let report = ValidationEngine::new(&schema)?
+            .validate_as_class(&serde_json::json!({"language": "xxx"}), "Document", None)
+            .await?;
+        assert!(report.valid, "{report}");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_parallel_all_of_issues_point_at_the_slot() -> anyhow::Result<()> {
+        // More than three all_of constraints are evaluated in parallel
+        let schema: SchemaDefinition = serde_yaml::from_str(
+            r"
+id: https://example.org/countries
+name: countries
+slots:
+  code:
+    range: string
+    all_of:
+      - pattern: '^[A-Z]'
+      - pattern: '[A-Z]$'
+      - pattern: '^[A-Z]{2,3}$'
+      - pattern: '^[^0-9]*$'
+classes:
+  Country:
+    slots: [code]
+",
+        )?;
+        let engine = ValidationEngine::new(&schema)?;
+        let report = engine
+            .validate_as_class(&serde_json::json!({"code": "nl"}), "Country", None)
+            .await?;
+
+        assert!(!report.valid, "{report}");
+        let paths: BTreeSet<&str> = report.errors().map(|issue| issue.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            BTreeSet::from([
+                "$.code",
+                "$.code.all_of[0]",
+                "$.code.all_of[1]",
+                "$.code.all_of[2]"
+            ]),
+            "{report}"
+        );
+        assert!(
+            report.errors().all(|issue| issue.pointer == "/code"),
+            "{report}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_new_rejects_undefined_range_with_strict_references() -> anyhow::Result<()> {
+        let mut schema: SchemaDefinition = serde_yaml::from_str(
+            r"
+id: https://example.org/addresses
+name: addresses
+settings:
+  validation:
+    strict_references: true
+slots:
+  country:
+    range: Country
+classes:
+  Address:
+    slots: [country]
+",
+        )?;
+
+        let Err(error) = ValidationEngine::new(&schema) else {
+            panic!("an undefined range should be rejected");
+        };
+        assert!(
+            error
+                .to_string()
+                .contains("slot 'country' range references undefined element 'Country'"),
+            "{error}"
+        );
+
+        // The same schema is accepted unless strict references are requested
+        schema.settings = None;
+        ValidationEngine::new(&schema)?;
+        Ok(())
+    }
+}
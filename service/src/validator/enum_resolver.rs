@@ -0,0 +1,298 @@
+//! Dynamic enum resolver
+//!
+//! Materializes the permissible values of enums whose values come from an
+//! external code set rather than being listed in the schema
+
+use linkml_core::error::{LinkMLError, Result};
+use linkml_core::types::{EnumDefinition, PermissibleValue, SchemaDefinition};
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use super::instance_loader::{InstanceConfig, InstanceLoader};
+use super::instance_resolver::resolve_instance_import;
+
+/// Resolved permissible values together with the instance data source they came from
+struct CachedEnumValues {
+    values: Arc<HashSet<String>>,
+    source: String,
+}
+
+/// Resolves dynamic enums to their concrete set of permissible values
+///
+/// An enum is dynamic when it declares a `code_set`. The code set may be an
+/// instance import (`txp:path/to/module/instance`, resolved the same way as
+/// [`InstanceResolver`](super::InstanceResolver) resolves them) or a path to a
+/// YAML, JSON or CSV file relative to the schema directory. `pv_formula`
+/// selects the field read from each instance:
+///
+/// - `CODE`, `CURIE` and `URI` (the default) read `id`
+/// - `LABEL` reads `label`
+pub struct EnumResolver {
+    /// Base directory for schema files
+    schema_base_dir: PathBuf,
+    /// Instance loader
+    loader: Arc<InstanceLoader>,
+    /// Cache of resolved permissible values by enum name
+    cache: dashmap::DashMap<String, CachedEnumValues>,
+}
+
+impl EnumResolver {
+    /// Create a new enum resolver
+    pub fn new(schema_base_dir: PathBuf, loader: Arc<InstanceLoader>) -> Self {
+        Self {
+            schema_base_dir,
+            loader,
+            cache: dashmap::DashMap::new(),
+        }
+    }
+
+    /// Check whether an enum takes its permissible values from a code set
+    #[must_use]
+    pub fn is_dynamic(enum_def: &EnumDefinition) -> bool {
+        enum_def.code_set.is_some() || enum_def.pv_formula.is_some()
+    }
+
+    /// Drop the cached permissible values of an enum
+    ///
+    /// The loader's cache entry for the enum's code set is dropped too, so
+    /// the next lookup rereads the file.
+    pub fn invalidate(&self, enum_name: &str) {
+        if let Some((_, cached)) = self.cache.remove(enum_name) {
+            self.loader.invalidate(&cached.source);
+        }
+    }
+
+    /// Drop all cached permissible values, including the loader's cache
+    pub fn clear_cache(&self) {
+        self.cache.clear();
+        self.loader.clear_cache();
+    }
+
+    /// Resolve the permissible values of a dynamic enum
+    ///
+    /// The result holds the values loaded from the code set plus any values
+    /// listed in the schema. Returns `None` if the enum is unknown or static.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the code set cannot be located or loaded, or the
+    /// `pv_formula` is not supported
+    pub async fn resolve_enum(
+        &self,
+        enum_name: &str,
+        schema: &SchemaDefinition,
+    ) -> Result<Option<Arc<HashSet<String>>>> {
+        let Some(enum_def) = schema.enums.get(enum_name) else {
+            return Ok(None);
+        };
+        if !Self::is_dynamic(enum_def) {
+            return Ok(None);
+        }
+
+        if let Some(cached) = self.cache.get(enum_name) {
+            return Ok(Some(Arc::clone(&cached.values)));
+        }
+
+        let code_set = enum_def.code_set.as_deref().ok_or_else(|| {
+            LinkMLError::schema_validation(format!(
+                "Enum '{enum_name}' has a pv_formula but no code_set to apply it to"
+            ))
+        })?;
+        let key_field = match enum_def.pv_formula.as_deref() {
+            None | Some("CODE" | "CURIE" | "URI") => "id",
+            Some("LABEL") => "label",
+            Some(other) => {
+                return Err(LinkMLError::schema_validation(format!(
+                    "Enum '{enum_name}' uses unsupported pv_formula '{other}'; \
+                     expected CODE, CURIE, URI or LABEL"
+                )));
+            }
+        };
+        let path = self
+            .resolve_code_set_path(code_set, schema)
+            .ok_or_else(|| {
+                LinkMLError::schema_validation(format!(
+                    "Code set '{code_set}' of enum '{enum_name}' is not an instance import \
+                 or a file under {}",
+                    self.schema_base_dir.display()
+                ))
+            })?;
+
+        let config = InstanceConfig {
+            key_field: key_field.to_string(),
+            ..InstanceConfig::default()
+        };
+        let instance_data = self.loader.load_file(&path, &config).await?;
+
+        let mut values: HashSet<String> = instance_data.values.keys().cloned().collect();
+        values.extend(enum_def.permissible_values.iter().map(|pv| match pv {
+            PermissibleValue::Simple(text) | PermissibleValue::Complex { text, .. } => text.clone(),
+        }));
+
+        let values = Arc::new(values);
+        self.cache.insert(
+            enum_name.to_string(),
+            CachedEnumValues {
+                values: Arc::clone(&values),
+                source: instance_data.source.clone(),
+            },
+        );
+        Ok(Some(values))
+    }
+
+    /// Validate a value against a dynamic enum
+    ///
+    /// Values of static or unknown enums are accepted; those are checked by
+    /// the permissible value validator.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the enum's code set cannot be resolved
+    pub async fn validate_enum_value(
+        &self,
+        value: &str,
+        enum_name: &str,
+        schema: &SchemaDefinition,
+    ) -> Result<bool> {
+        Ok(self
+            .resolve_enum(enum_name, schema)
+            .await?
+            .is_none_or(|values| values.contains(value)))
+    }
+
+    /// Locate the file behind a code set reference
+    fn resolve_code_set_path(&self, code_set: &str, schema: &SchemaDefinition) -> Option<PathBuf> {
+        if let Some(path) = resolve_instance_import(&self.schema_base_dir, code_set, schema) {
+            return Some(path);
+        }
+
+        let relative = code_set.strip_prefix("file:").unwrap_or(code_set);
+        let path = self.schema_base_dir.join(relative);
+        path.is_file().then_some(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+    use timestamp_service::wiring::wire_timestamp;
+    use tokio::fs;
+
+    async fn write_languages(dir: &TempDir, ids: &[(&str, &str)]) {
+        let entries: String = ids
+            .iter()
+            .map(|(id, label)| format!("  - id: {id}\n    label: {label}\n"))
+            .collect();
+        fs::create_dir_all(dir.path().join("language"))
+            .await
+            .expect("should create instance directory");
+        fs::write(
+            dir.path().join("language/iso_639_3_entity.yaml"),
+            format!("instances:\n{entries}"),
+        )
+        .await
+        .expect("should write instance file");
+    }
+
+    fn language_schema(pv_formula: Option<&str>) -> SchemaDefinition {
+        let mut schema = SchemaDefinition::new("languages");
+        schema.enums.insert(
+            "LanguageCode".to_string(),
+            EnumDefinition {
+                name: "LanguageCode".to_string(),
+                code_set: Some("txp:language/iso_639_3_entity/instance".to_string()),
+                pv_formula: pv_formula.map(str::to_string),
+                permissible_values: vec![PermissibleValue::Simple("und".to_string())],
+                ..EnumDefinition::default()
+            },
+        );
+        schema.enums.insert(
+            "Status".to_string(),
+            EnumDefinition {
+                name: "Status".to_string(),
+                permissible_values: vec![PermissibleValue::Simple("ACTIVE".to_string())],
+                ..EnumDefinition::default()
+            },
+        );
+        schema
+    }
+
+    fn resolver_for(dir: &TempDir) -> EnumResolver {
+        let loader = Arc::new(InstanceLoader::new(wire_timestamp().into_arc()));
+        EnumResolver::new(dir.path().to_path_buf(), loader)
+    }
+
+    #[tokio::test]
+    async fn test_resolves_code_set_from_instance_file() -> anyhow::Result<()> {
+        let dir = TempDir::new()?;
+        write_languages(&dir, &[("eng", "English"), ("nld", "Dutch")]).await;
+        let resolver = resolver_for(&dir);
+        let schema = language_schema(Some("CODE"));
+
+        let values = resolver
+            .resolve_enum("LanguageCode", &schema)
+            .await?
+            .expect("dynamic enum should resolve");
+        let mut values: Vec<_> = values.iter().map(String::as_str).collect();
+        values.sort_unstable();
+        assert_eq!(values, ["eng", "nld", "und"]);
+
+        assert!(
+            resolver
+                .validate_enum_value("nld", "LanguageCode", &schema)
+                .await?
+        );
+        assert!(
+            !resolver
+                .validate_enum_value("xxx", "LanguageCode", &schema)
+                .await?
+        );
+        // Static enums are left to the permissible value validator
+        assert!(resolver.resolve_enum("Status", &schema).await?.is_none());
+
+        // Cached until invalidated
+        write_languages(&dir, &[("eng", "English"), ("fra", "French")]).await;
+        assert!(
+            !resolver
+                .validate_enum_value("fra", "LanguageCode", &schema)
+                .await?
+        );
+        resolver.invalidate("LanguageCode");
+        assert!(
+            resolver
+                .validate_enum_value("fra", "LanguageCode", &schema)
+                .await?
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_pv_formula_selects_field() -> anyhow::Result<()> {
+        let dir = TempDir::new()?;
+        write_languages(&dir, &[("eng", "English")]).await;
+        let resolver = resolver_for(&dir);
+
+        let schema = language_schema(Some("LABEL"));
+        assert!(
+            resolver
+                .validate_enum_value("English", "LanguageCode", &schema)
+                .await?
+        );
+        assert!(
+            !resolver
+                .validate_enum_value("eng", "LanguageCode", &schema)
+                .await?
+        );
+
+        resolver.clear_cache();
+        let schema = language_schema(Some("FHIR_CODING"));
+        let error = resolver
+            .resolve_enum("LanguageCode", &schema)
+            .await
+            .expect_err("FHIR_CODING is not supported");
+        assert!(error.to_string().contains("FHIR_CODING"), "{error}");
+        Ok(())
+    }
+}
@@ -2,10 +2,14 @@
 //!
 //! Loads permissible values from external data sources
 
+use crate::inheritance::InheritanceResolver;
+use crate::parser::yaml_events::YamlEvents;
 use linkml_core::error::{LinkMLError, Result};
+use linkml_core::types::SchemaDefinition;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::collections::HashMap;
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 use std::sync::Arc;
 use timestamp_core::TimestampService;
@@ -28,9 +32,55 @@ pub struct InstanceConfig {
     /// Key field in the data (e.g., "code", "id")
     pub key_field: String,
     /// Value field in the data (e.g., "name", "label")
+    ///
+    /// An instance without a string in this field is still loaded, with no
+    /// value under its key.
     pub value_field: Option<String>,
-    /// Filter expression (future enhancement)
-    pub filter: Option<String>,
+    /// Only load instances matching this predicate
+    pub filter: Option<InstanceFilter>,
+}
+
+/// Field/value predicate selecting which instances are loaded
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InstanceFilter {
+    /// Field to test on each instance
+    pub field: String,
+    /// Value the field must be equal to for the instance to be kept
+    pub equals: Value,
+}
+
+impl InstanceFilter {
+    /// Create a filter keeping instances whose `field` equals `equals`
+    #[must_use]
+    pub fn new(field: impl Into<String>, equals: impl Into<Value>) -> Self {
+        Self {
+            field: field.into(),
+            equals: equals.into(),
+        }
+    }
+
+    /// Check whether a `JSON` object passes the filter
+    #[must_use]
+    pub fn matches(&self, obj: &serde_json::Map<String, Value>) -> bool {
+        obj.get(&self.field) == Some(&self.equals)
+    }
+
+    /// Check whether a YAML mapping passes the filter
+    #[must_use]
+    pub fn matches_yaml(&self, obj: &serde_yaml::Mapping) -> bool {
+        obj.get(self.field.as_str())
+            .and_then(|value| serde_json::to_value(value).ok())
+            .is_some_and(|value| value == self.equals)
+    }
+
+    /// Check whether a raw text cell (e.g. from CSV) passes the filter
+    #[must_use]
+    pub fn matches_text(&self, cell: &str) -> bool {
+        match &self.equals {
+            Value::String(expected) => expected == cell,
+            other => other.to_string() == cell,
+        }
+    }
 }
 
 impl Default for InstanceConfig {
@@ -49,6 +99,81 @@ impl InstanceConfig {
     pub fn is_valid(&self) -> bool {
         !self.key_field.is_empty()
     }
+
+    /// Cache key for data loaded from `path` with this configuration
+    ///
+    /// Filtered loads and loads with a value field get their own entry so
+    /// they never shadow the plain data.
+    fn cache_key(&self, path: &Path) -> String {
+        let mut query = Vec::new();
+        if let Some(value_field) = &self.value_field {
+            query.push(format!("value_field={value_field}"));
+        }
+        if let Some(filter) = &self.filter {
+            query.push(format!("{}={}", filter.field, filter.equals));
+        }
+
+        if query.is_empty() {
+            format!("file:{}", path.display())
+        } else {
+            format!("file:{}?{}", path.display(), query.join("&"))
+        }
+    }
+}
+
+/// Metadata header of a `TextPast` instance file
+///
+/// Instance files carry this header next to their `instances` list; see
+/// [`InstanceLoader::validate_instance_header`] for the rules it follows.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstanceHeader {
+    /// Identifier of the instance file, under the `/instance` namespace
+    pub id: String,
+    /// Identifier of the schema the instances conform to, under `/schema`
+    pub schema: String,
+    /// Name of the instance file
+    pub name: Option<String>,
+    /// Version of the instance data
+    pub version: String,
+    /// When the instance file was created
+    pub created_on: String,
+    /// When the instance file was last updated
+    pub last_updated_on: String,
+}
+
+/// Instance whose ID does not satisfy the expected type
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstanceLintIssue {
+    /// Position of the instance in the file's list of instances
+    pub index: usize,
+    /// ID of the instance; `None` if it has no string `id`
+    pub id: Option<String>,
+    /// Why the instance was reported
+    pub message: String,
+}
+
+/// Result of checking the IDs of an instance file against a type
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstanceLintReport {
+    /// Instance file that was checked
+    pub source: String,
+    /// Type or class the IDs are expected to satisfy
+    pub expected_type: String,
+    /// Pattern of the expected type, or of the identifier slot of the
+    /// expected class
+    pub pattern: String,
+    /// Number of instances checked
+    pub checked: usize,
+    /// Instances whose ID is missing or does not match the pattern
+    pub issues: Vec<InstanceLintIssue>,
+}
+
+impl InstanceLintReport {
+    /// Whether every instance ID satisfies the expected type
+    #[must_use]
+    pub fn is_clean(&self) -> bool {
+        self.issues.is_empty()
+    }
 }
 
 /// Loads instance data from various sources
@@ -57,6 +182,9 @@ pub struct InstanceLoader {
     cache: dashmap::DashMap<String, Arc<InstanceData>>,
     /// Timestamp service for `loaded_at` timestamps
     timestamp_service: Arc<dyn TimestampService<Error = timestamp_core::TimestampError>>,
+    /// Whether YAML files are read one instance at a time instead of parsed
+    /// whole
+    stream_yaml: bool,
 }
 
 impl InstanceLoader {
@@ -68,7 +196,140 @@ impl InstanceLoader {
         Self {
             cache: dashmap::DashMap::new(),
             timestamp_service,
+            stream_yaml: false,
+        }
+    }
+
+    /// Read YAML instance files one instance at a time
+    ///
+    /// Each instance is built from the parser events, its configured fields
+    /// are extracted and it is dropped before the next one is read, so very
+    /// large instance files are never held in memory whole. Files without an
+    /// `instances:` list or a top-level list are still parsed in full.
+    #[must_use]
+    pub fn with_yaml_streaming(mut self, enabled: bool) -> Self {
+        self.stream_yaml = enabled;
+        self
+    }
+
+    /// Validate the metadata header of an instance file
+    ///
+    /// The header must give an `id` under the `/instance` namespace, the
+    /// `/schema` URL of the schema it conforms to, a `version`, and the
+    /// `created_on` and `last_updated_on` timestamps.
+    ///
+    /// # Errors
+    ///
+    /// Returns a data validation error naming the first field that is
+    /// missing or breaks the convention.
+    pub fn validate_instance_header(document: &Value) -> Result<InstanceHeader> {
+        let required = |field: &str| {
+            document
+                .get(field)
+                .and_then(Value::as_str)
+                .map(str::to_string)
+                .ok_or_else(|| {
+                    LinkMLError::data_validation(format!(
+                        "Instance file header is missing required field '{field}'"
+                    ))
+                })
+        };
+
+        let id = required("id")?;
+        if !has_path_segment(&id, "instance") {
+            return Err(LinkMLError::data_validation(format!(
+                "Instance file id '{id}' is not under the /instance namespace"
+            )));
         }
+        let schema = required("schema")?;
+        if !has_path_segment(&schema, "schema") {
+            return Err(LinkMLError::data_validation(format!(
+                "Instance file schema '{schema}' is not a /schema URL"
+            )));
+        }
+
+        Ok(InstanceHeader {
+            id,
+            schema,
+            name: document
+                .get("name")
+                .and_then(Value::as_str)
+                .map(str::to_string),
+            version: required("version")?,
+            created_on: required("created_on")?,
+            last_updated_on: required("last_updated_on")?,
+        })
+    }
+
+    /// Check the ID of every instance in a file against a type's pattern
+    ///
+    /// `expected_type` names a type of `schema`, such as
+    /// `CountryCodeAlpha2Identifier`; its `pattern`, or else the pattern of
+    /// the nearest type it derives from via `typeof`, must match every `id`.
+    /// It may also name a class, such as `ISO3166Entity`, whose identifier
+    /// slot, as refined by `slot_usage`, supplies the pattern directly or
+    /// through its range type. The file may be `YAML` or `JSON`, holding
+    /// either an `instances` list or a plain list of instances.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or parsed, or if the type
+    /// or class is not defined or yields no valid pattern.
+    pub async fn lint_instances(
+        path: impl AsRef<Path>,
+        schema: &SchemaDefinition,
+        expected_type: &str,
+    ) -> Result<InstanceLintReport> {
+        let path = path.as_ref();
+        let pattern = id_pattern(schema, expected_type)?;
+        let regex = regex::Regex::new(&pattern).map_err(|e| {
+            LinkMLError::pattern(format!("Invalid pattern of type '{expected_type}': {e}"))
+        })?;
+
+        let content = tokio::fs::read_to_string(path)
+            .await
+            .map_err(LinkMLError::from)?;
+        // JSON is valid YAML, so one parser covers both formats
+        let document: Value = serde_yaml::from_str(&content)
+            .map_err(|e| LinkMLError::parse(format!("Invalid instance file: {e}")))?;
+        let instances = document
+            .get("instances")
+            .unwrap_or(&document)
+            .as_array()
+            .ok_or_else(|| {
+                LinkMLError::parse(format!(
+                    "Instance file {} holds no list of instances",
+                    path.display()
+                ))
+            })?;
+
+        let issues = instances
+            .iter()
+            .enumerate()
+            .filter_map(|(index, instance)| {
+                let id = instance.get("id").and_then(Value::as_str);
+                let message = match id {
+                    Some(id) if regex.is_match(id) => return None,
+                    Some(id) => {
+                        format!("Id '{id}' does not match the {expected_type} pattern {pattern}")
+                    }
+                    None => "Instance has no string 'id'".to_string(),
+                };
+                Some(InstanceLintIssue {
+                    index,
+                    id: id.map(str::to_string),
+                    message,
+                })
+            })
+            .collect();
+
+        Ok(InstanceLintReport {
+            source: path.display().to_string(),
+            expected_type: expected_type.to_string(),
+            pattern: pattern.to_string(),
+            checked: instances.len(),
+            issues,
+        })
     }
 
     /// Load instance data from a `JSON` file
@@ -82,7 +343,7 @@ impl InstanceLoader {
         config: &InstanceConfig,
     ) -> linkml_core::error::Result<Arc<InstanceData>> {
         let path = path.as_ref();
-        let cache_key = format!("file:{}", path.display());
+        let cache_key = config.cache_key(path);
 
         // Check cache first
         if let Some(cached) = self.cache.get(&cache_key) {
@@ -96,6 +357,7 @@ impl InstanceLoader {
 
         let json: Value = serde_json::from_str(&content)
             .map_err(|e| LinkMLError::parse(format!("Invalid JSON in instance file: {e}")))?;
+        Self::check_instance_header(&json)?;
 
         // Extract values based on config
         let values = Self::extract_values_from_json(&json, config)?;
@@ -154,23 +416,33 @@ impl InstanceLoader {
         config: &InstanceConfig,
     ) -> linkml_core::error::Result<Arc<InstanceData>> {
         let path = path.as_ref();
-        let cache_key = format!("file:{}", path.display());
+        let cache_key = config.cache_key(path);
 
         // Check cache first
         if let Some(cached) = self.cache.get(&cache_key) {
             return Ok(Arc::clone(&cached));
         }
 
-        // Read and parse file
-        let content = tokio::fs::read_to_string(path)
-            .await
-            .map_err(LinkMLError::from)?;
+        let scanned = if self.stream_yaml {
+            Self::scan_yaml_file(path, config).await?
+        } else {
+            None
+        };
+        let values = if let Some(values) = scanned {
+            values
+        } else {
+            // Read and parse file
+            let content = tokio::fs::read_to_string(path)
+                .await
+                .map_err(LinkMLError::from)?;
 
-        let yaml: serde_yaml::Value = serde_yaml::from_str(&content)
-            .map_err(|e| LinkMLError::parse(format!("Invalid YAML in instance file: {e}")))?;
+            let yaml: serde_yaml::Value = serde_yaml::from_str(&content)
+                .map_err(|e| LinkMLError::parse(format!("Invalid YAML in instance file: {e}")))?;
+            Self::check_yaml_header(&yaml)?;
 
-        // Extract values based on config
-        let values = Self::extract_values_from_yaml(&yaml, config)?;
+            // Extract values based on config
+            Self::extract_values_from_yaml(&yaml, config)?
+        };
 
         let loaded_at =
             self.timestamp_service.now_utc().await.map_err(|e| {
@@ -188,6 +460,71 @@ impl InstanceLoader {
         Ok(instance_data)
     }
 
+    /// Extract the configured fields of a YAML instance file one instance
+    /// at a time, skipping the values of all other fields unparsed
+    ///
+    /// Returns `None` when the file has no list of instances, leaving its
+    /// layout to the full parse.
+    async fn scan_yaml_file(
+        path: &Path,
+        config: &InstanceConfig,
+    ) -> Result<Option<HashMap<String, Vec<String>>>> {
+        let path = path.to_path_buf();
+        let config = config.clone();
+        tokio::task::spawn_blocking(move || {
+            let file = std::fs::File::open(&path).map_err(LinkMLError::from)?;
+            let mut header = serde_json::Map::new();
+            let mut values = HashMap::new();
+            // Only the fields read by `extract_from_object` are built
+            let fields = std::iter::once(&config.key_field)
+                .chain(&config.value_field)
+                .chain(config.filter.as_ref().map(|filter| &filter.field));
+            let events =
+                YamlEvents::new(std::io::BufReader::new(file)).with_instance_fields(fields);
+            let found = events.instances(
+                |key, value| {
+                    header.insert(key, value);
+                    Ok(())
+                },
+                |instance| Self::extract_from_object(&instance, &config, &mut values),
+            )?;
+            if !found {
+                return Ok(None);
+            }
+            // Any header entries sat next to the streamed `instances` list
+            Self::check_header_fields(&Value::Object(header))?;
+            Ok(Some(values))
+        })
+        .await
+        .map_err(|e| LinkMLError::service(format!("Instance file scan failed: {e}")))?
+    }
+
+    /// Validate the header of a YAML instance document, if it has one
+    fn check_yaml_header(yaml: &serde_yaml::Value) -> Result<()> {
+        Self::check_instance_header(&serde_json::to_value(yaml)?)
+    }
+
+    /// Validate the header of an instance document, if it has one
+    ///
+    /// Only a mapping with an `instances` list is a `TextPast` instance
+    /// document; other data that happens to have an `id` or `schema` key is
+    /// not held to the header convention.
+    fn check_instance_header(document: &Value) -> Result<()> {
+        if document.get("instances").is_some() {
+            Self::check_header_fields(document)?;
+        }
+        Ok(())
+    }
+
+    /// Validate the header entries next to an `instances` list, if any
+    fn check_header_fields(document: &Value) -> Result<()> {
+        // Bare lists of instances carry no header to check
+        if document.get("id").is_some() || document.get("schema").is_some() {
+            Self::validate_instance_header(document)?;
+        }
+        Ok(())
+    }
+
     /// Load instance data from a CSV file
     ///
     /// # Errors
@@ -199,7 +536,7 @@ impl InstanceLoader {
         config: &InstanceConfig,
     ) -> linkml_core::error::Result<Arc<InstanceData>> {
         let path = path.as_ref();
-        let cache_key = format!("file:{}", path.display());
+        let cache_key = config.cache_key(path);
 
         // Check cache first
         if let Some(cached) = self.cache.get(&cache_key) {
@@ -243,6 +580,22 @@ impl InstanceLoader {
             None
         };
 
+        let filter_idx = if let Some(filter) = &config.filter {
+            Some(
+                headers
+                    .iter()
+                    .position(|h| h == filter.field)
+                    .ok_or_else(|| {
+                        LinkMLError::data_validation(format!(
+                            "Filter field '{}' not found in CSV",
+                            filter.field
+                        ))
+                    })?,
+            )
+        } else {
+            None
+        };
+
         // Extract values
         let mut values: HashMap<String, Vec<String>> = HashMap::new();
 
@@ -250,6 +603,14 @@ impl InstanceLoader {
             let record = result
                 .map_err(|e| LinkMLError::parse(format!("Failed to read CSV record: {e}")))?;
 
+            if let (Some(filter), Some(idx)) = (&config.filter, filter_idx)
+                && !record
+                    .get(idx)
+                    .is_some_and(|cell| filter.matches_text(cell))
+            {
+                continue;
+            }
+
             let key = record
                 .get(key_idx)
                 .ok_or_else(|| LinkMLError::parse("Missing key field in CSV record"))?
@@ -321,12 +682,20 @@ impl InstanceLoader {
     }
 
     /// Extract key-value pair from a `JSON` object
-    fn extract_from_object(
+    pub(crate) fn extract_from_object(
         obj: &Value,
         config: &InstanceConfig,
         values: &mut HashMap<String, Vec<String>>,
     ) -> linkml_core::error::Result<()> {
         if let Some(obj_map) = obj.as_object() {
+            if config
+                .filter
+                .as_ref()
+                .is_some_and(|filter| !filter.matches(obj_map))
+            {
+                return Ok(());
+            }
+
             // Get key
             let key = obj_map
                 .get(&config.key_field)
@@ -339,22 +708,16 @@ impl InstanceLoader {
                 })?
                 .to_string();
 
-            // Get value
-            let value = if let Some(value_field) = &config.value_field {
-                obj_map
+            // Get value; an instance without one keeps its key but no value
+            let value = match &config.value_field {
+                Some(value_field) => obj_map
                     .get(value_field)
                     .and_then(|v| v.as_str())
-                    .ok_or_else(|| {
-                        LinkMLError::data_validation(format!(
-                            "Value field '{value_field}' not found or not a string"
-                        ))
-                    })?
-                    .to_string()
-            } else {
-                key.clone()
+                    .map(str::to_string),
+                None => Some(key.clone()),
             };
 
-            values.entry(key).or_default().push(value);
+            values.entry(key).or_default().extend(value);
         }
 
         Ok(())
@@ -411,6 +774,14 @@ impl InstanceLoader {
         values: &mut HashMap<String, Vec<String>>,
     ) -> Result<()> {
         if let Some(obj_map) = obj.as_mapping() {
+            if config
+                .filter
+                .as_ref()
+                .is_some_and(|filter| !filter.matches_yaml(obj_map))
+            {
+                return Ok(());
+            }
+
             // Get key
             let key_value = serde_yaml::Value::String(config.key_field.clone());
             let key = obj_map
@@ -424,23 +795,20 @@ impl InstanceLoader {
                 })?
                 .to_string();
 
-            // Get value (if value_field specified, otherwise use key)
-            let value = if let Some(value_field) = &config.value_field {
-                let value_key = serde_yaml::Value::String(value_field.clone());
-                obj_map
-                    .get(&value_key)
-                    .and_then(|v| v.as_str())
-                    .ok_or_else(|| {
-                        LinkMLError::data_validation(format!(
-                            "Value field '{value_field}' not found or not a string"
-                        ))
-                    })?
-                    .to_string()
-            } else {
-                key.clone()
+            // Get value (if value_field specified, otherwise use key); an
+            // instance without one keeps its key but no value
+            let value = match &config.value_field {
+                Some(value_field) => {
+                    let value_key = serde_yaml::Value::String(value_field.clone());
+                    obj_map
+                        .get(&value_key)
+                        .and_then(|v| v.as_str())
+                        .map(str::to_string)
+                }
+                None => Some(key.clone()),
             };
 
-            values.entry(key).or_default().push(value);
+            values.entry(key).or_default().extend(value);
         }
 
         Ok(())
@@ -493,6 +861,25 @@ impl InstanceLoader {
         self.cache.clear();
     }
 
+    /// Drop cached data loaded from `source`
+    ///
+    /// `source` is either an [`InstanceData::source`] value or a file path.
+    /// Filtered loads of the same file are dropped as well. Returns the
+    /// number of entries removed.
+    pub fn invalidate(&self, source: &str) -> usize {
+        let source = if source.starts_with("file:") {
+            source.to_string()
+        } else {
+            format!("file:{source}")
+        };
+        let filtered_prefix = format!("{source}?");
+
+        let before = self.cache.len();
+        self.cache
+            .retain(|key, _| key != &source && !key.starts_with(&filtered_prefix));
+        before.saturating_sub(self.cache.len())
+    }
+
     /// Get cache statistics
     #[must_use]
     pub fn cache_stats(&self) -> CacheStats {
@@ -507,6 +894,63 @@ impl InstanceLoader {
     }
 }
 
+/// Pattern instance ids must match: that of a type, or that of the
+/// identifier slot of a class as induced through inheritance and `slot_usage`
+fn id_pattern<'a>(schema: &'a SchemaDefinition, expected: &str) -> Result<Cow<'a, str>> {
+    if !schema.classes.contains_key(expected) {
+        return type_pattern(schema, expected).map(Cow::Borrowed);
+    }
+
+    let identifier = InheritanceResolver::new(schema)
+        .induced_slots(expected)?
+        .into_iter()
+        .find(|slot| slot.identifier == Some(true))
+        .ok_or_else(|| {
+            LinkMLError::schema_validation(format!("Class '{expected}' has no identifier slot"))
+        })?;
+    if let Some(pattern) = identifier.pattern {
+        return Ok(Cow::Owned(pattern));
+    }
+    let range = identifier.range.ok_or_else(|| {
+        LinkMLError::schema_validation(format!(
+            "Identifier slot '{}' of class '{expected}' has neither a pattern nor a range",
+            identifier.name
+        ))
+    })?;
+    type_pattern(schema, &range).map(Cow::Borrowed)
+}
+
+/// Pattern a type's values must match, inherited through `typeof`
+fn type_pattern<'a>(schema: &'a SchemaDefinition, type_name: &str) -> Result<&'a str> {
+    if !schema.types.contains_key(type_name) {
+        return Err(LinkMLError::schema_validation(format!(
+            "Type '{type_name}' is not defined in schema '{}'",
+            schema.name
+        )));
+    }
+
+    let mut seen = HashSet::new();
+    let mut current = schema.types.get(type_name);
+    while let Some(type_def) = current {
+        if let Some(pattern) = &type_def.pattern {
+            return Ok(pattern);
+        }
+        current = type_def
+            .base_type
+            .as_deref()
+            .filter(|base| seen.insert(*base))
+            .and_then(|base| schema.types.get(base));
+    }
+    Err(LinkMLError::schema_validation(format!(
+        "Type '{type_name}' has no pattern to check instance ids against"
+    )))
+}
+
+/// Whether a URL has `segment` as one of its path segments
+fn has_path_segment(url: &str, segment: &str) -> bool {
+    url.split('/').any(|part| part == segment)
+}
+
 /// Cache statistics
 #[derive(Debug, Clone)]
 pub struct CacheStats {
@@ -654,4 +1098,358 @@ CA,Canada
         assert_eq!(stats.entries, 1);
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_load_yaml_file_with_filter() -> anyhow::Result<(), LinkMLError> {
+        let temp_dir = TempDir::new().expect("should create temporary directory: {}");
+        let file_path = temp_dir.path().join("iso_639_3_entity.yaml");
+
+        // Shaped like the ISO 639-3 language code table
+        let yaml_data = "instances:
+  - id: eng
+    name: English
+    scope: individual
+    type: living
+  - id: ang
+    name: Old English (ca. 450-1100)
+    scope: individual
+    type: historical
+  - id: lat
+    name: Latin
+    scope: individual
+    type: ancient
+  - id: nld
+    name: Dutch
+    scope: individual
+    type: living
+";
+        fs::write(&file_path, yaml_data)
+            .await
+            .expect("should write test YAML file: {}");
+
+        let timestamp_service = wire_timestamp().into_arc();
+        let loader = InstanceLoader::new(timestamp_service);
+        let living = InstanceConfig {
+            filter: Some(InstanceFilter::new("type", "living")),
+            ..InstanceConfig::default()
+        };
+
+        let filtered = loader
+            .load_yaml_file(&file_path, &living)
+            .await
+            .expect("should load filtered YAML instance data: {}");
+        let mut ids: Vec<&str> = filtered.values.keys().map(String::as_str).collect();
+        ids.sort_unstable();
+        assert_eq!(ids, ["eng", "nld"]);
+
+        // The unfiltered load must not be served from the filtered cache entry
+        let all = loader
+            .load_yaml_file(&file_path, &InstanceConfig::default())
+            .await
+            .expect("should load full YAML instance data: {}");
+        assert_eq!(all.values.len(), 4);
+        assert_eq!(loader.cache_stats().entries, 2);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_streamed_yaml_matches_full_parse() -> anyhow::Result<(), LinkMLError> {
+        let temp_dir = TempDir::new().expect("should create temporary directory: {}");
+        let file_path = temp_dir.path().join("iso_3166_entity.yaml");
+
+        // A country file blown up to the size of the language list
+        let header = "\
+id: https://textpast.org/instance/place/polity/country/iso_3166_entity
+schema: https://textpast.org/schema/place/polity/country
+version: 1.0.0
+created_on: '2025-03-30T10:41:26+01:00'
+last_updated_on: '2025-03-30T10:41:26+01:00'
+instances:
+";
+        let instances: String = (0..10_000)
+            .map(|index| {
+                format!(
+                    "  - id: C{index:04}
+    label: \"Country {index}\" # official name
+    tld: .c{index}
+    exact_mappings:
+      - wikidata:Q{index}
+"
+                )
+            })
+            .collect();
+        fs::write(&file_path, format!("{header}{instances}"))
+            .await
+            .expect("should write test YAML file: {}");
+
+        let labels = InstanceConfig {
+            value_field: Some("label".to_string()),
+            ..InstanceConfig::default()
+        };
+        let timestamp_service = wire_timestamp().into_arc();
+        let parsed = InstanceLoader::new(Arc::clone(&timestamp_service))
+            .load_yaml_file(&file_path, &labels)
+            .await?;
+        let streamed = InstanceLoader::new(timestamp_service)
+            .with_yaml_streaming(true)
+            .load_yaml_file(&file_path, &labels)
+            .await?;
+
+        assert_eq!(streamed.values.len(), 10_000);
+        assert_eq!(streamed.values, parsed.values);
+        assert_eq!(
+            streamed.values.get("C0042"),
+            Some(&vec!["Country 42".to_string()])
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_streaming_reads_any_yaml_layout() -> anyhow::Result<(), LinkMLError> {
+        let temp_dir = TempDir::new().expect("should create temporary directory: {}");
+        let loader = InstanceLoader::new(wire_timestamp().into_arc()).with_yaml_streaming(true);
+
+        // Flow-style and block scalar instances are streamed like any other
+        let flow_path = temp_dir.path().join("flow.yaml");
+        fs::write(
+            &flow_path,
+            "instances:\n  - {id: NL}\n  - id: |-\n      FR\n",
+        )
+        .await
+        .expect("should write test YAML file: {}");
+        let data = loader
+            .load_yaml_file(&flow_path, &InstanceConfig::default())
+            .await?;
+        assert_eq!(data.values.len(), 2);
+        assert!(data.values.contains_key("FR"));
+
+        // Without a list of instances the file is parsed in full
+        let nested_path = temp_dir.path().join("nested.yaml");
+        fs::write(&nested_path, "countries:\n  - id: NL\n")
+            .await
+            .expect("should write test YAML file: {}");
+        let data = loader
+            .load_yaml_file(&nested_path, &InstanceConfig::default())
+            .await?;
+        assert_eq!(data.values.len(), 1);
+        Ok(())
+    }
+
+    fn iso_3166_header() -> Value {
+        serde_json::json!({
+            "id": "https://textpast.org/instance/place/polity/country/iso_3166_entity",
+            "schema": "https://textpast.org/schema/place/polity/country",
+            "name": "iso_3166_entity",
+            "version": "1.0.0",
+            "created_on": "2025-03-30T10:41:26+01:00",
+            "last_updated_on": "2025-03-30T10:41:26+01:00",
+            "instances": []
+        })
+    }
+
+    #[test]
+    fn test_validate_compliant_instance_header() -> anyhow::Result<(), LinkMLError> {
+        let header = InstanceLoader::validate_instance_header(&iso_3166_header())?;
+        assert_eq!(
+            header.id,
+            "https://textpast.org/instance/place/polity/country/iso_3166_entity"
+        );
+        assert_eq!(
+            header.schema,
+            "https://textpast.org/schema/place/polity/country"
+        );
+        assert_eq!(header.name.as_deref(), Some("iso_3166_entity"));
+        assert_eq!(header.version, "1.0.0");
+        assert_eq!(header.created_on, "2025-03-30T10:41:26+01:00");
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_non_compliant_instance_header() {
+        let mut outside_instance = iso_3166_header();
+        outside_instance["id"] =
+            "https://textpast.org/schema/place/polity/country/iso_3166_entity".into();
+        let error = InstanceLoader::validate_instance_header(&outside_instance)
+            .expect_err("id outside /instance should be rejected");
+        assert!(error.to_string().contains("/instance namespace"), "{error}");
+
+        let mut undated = iso_3166_header();
+        undated
+            .as_object_mut()
+            .expect("header should be an object")
+            .remove("last_updated_on");
+        let error = InstanceLoader::validate_instance_header(&undated)
+            .expect_err("missing timestamp should be rejected");
+        assert!(error.to_string().contains("'last_updated_on'"), "{error}");
+    }
+
+    #[tokio::test]
+    async fn test_lint_instances_reports_bad_ids() -> anyhow::Result<(), LinkMLError> {
+        let temp_dir = TempDir::new().expect("should create temporary directory: {}");
+        let file_path = temp_dir.path().join("iso_3166_entity.yaml");
+        fs::write(
+            &file_path,
+            "instances:\n  - id: NL\n  - id: USA\n  - id: FR\n  - label: Nowhere\n",
+        )
+        .await
+        .expect("should write test YAML file: {}");
+
+        let mut schema = SchemaDefinition::new("identifier");
+        schema.types.insert(
+            "CountryCodeAlpha2Identifier".to_string(),
+            linkml_core::types::TypeDefinition {
+                name: "CountryCodeAlpha2Identifier".to_string(),
+                base_type: Some("string".to_string()),
+                pattern: Some("^[A-Z]{2}$".to_string()),
+                ..Default::default()
+            },
+        );
+
+        let report =
+            InstanceLoader::lint_instances(&file_path, &schema, "CountryCodeAlpha2Identifier")
+                .await?;
+        assert_eq!(report.checked, 4);
+        let reported: Vec<_> = report
+            .issues
+            .iter()
+            .map(|issue| (issue.index, issue.id.as_deref()))
+            .collect();
+        assert_eq!(reported, [(1, Some("USA")), (3, None)]);
+        assert!(!report.is_clean());
+
+        let unknown = InstanceLoader::lint_instances(&file_path, &schema, "Alpha3").await;
+        assert!(unknown.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_lint_instances_uses_class_identifier_pattern() -> anyhow::Result<(), LinkMLError>
+    {
+        let temp_dir = TempDir::new().expect("should create temporary directory: {}");
+        let file_path = temp_dir.path().join("iso_3166_entity.yaml");
+        fs::write(&file_path, "instances:\n  - id: NL\n  - id: USA\n")
+            .await
+            .expect("should write test YAML file: {}");
+
+        let schema: SchemaDefinition = serde_yaml::from_str(
+            r"
+id: https://textpast.org/schema/place/polity/country
+name: country
+types:
+  CountryCodeAlpha2Identifier:
+    typeof: string
+    pattern: '^[A-Z]{2}$'
+slots:
+  identifier:
+    identifier: true
+    range: string
+classes:
+  Entity:
+    slots: [identifier]
+  ISO3166Entity:
+    is_a: Entity
+    slot_usage:
+      identifier:
+        range: CountryCodeAlpha2Identifier
+  Region:
+    is_a: Entity
+    slot_usage:
+      identifier:
+        pattern: '^[A-Z]{2,3}$'
+  Tag:
+    attributes:
+      label:
+        range: string
+",
+        )
+        .expect("should parse schema: {}");
+
+        // The pattern comes from the range type set by slot_usage
+        let report = InstanceLoader::lint_instances(&file_path, &schema, "ISO3166Entity").await?;
+        assert_eq!(report.pattern, "^[A-Z]{2}$");
+        let reported: Vec<_> = report.issues.iter().map(|issue| issue.index).collect();
+        assert_eq!(reported, [1]);
+
+        // ...or directly from a pattern set by slot_usage
+        let report = InstanceLoader::lint_instances(&file_path, &schema, "Region").await?;
+        assert!(report.is_clean());
+
+        // The base slot has no pattern and a plain string range
+        let entity = InstanceLoader::lint_instances(&file_path, &schema, "Entity").await;
+        assert!(entity.is_err());
+        let tag = InstanceLoader::lint_instances(&file_path, &schema, "Tag").await;
+        assert!(tag.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_load_yaml_file_checks_header() {
+        let temp_dir = TempDir::new().expect("should create temporary directory: {}");
+        let file_path = temp_dir.path().join("iso_3166_entity.yaml");
+
+        let yaml_data = "id: https://textpast.org/instance/place/polity/country/iso_3166_entity
+schema: https://textpast.org/schema/place/polity/country
+version: 1.0.0
+instances:
+  - id: NL
+    label: Netherlands
+";
+        fs::write(&file_path, yaml_data)
+            .await
+            .expect("should write test YAML file: {}");
+
+        let loader = InstanceLoader::new(wire_timestamp().into_arc());
+        let error = loader
+            .load_yaml_file(&file_path, &InstanceConfig::default())
+            .await
+            .expect_err("header without timestamps should be rejected");
+        assert!(error.to_string().contains("'created_on'"), "{error}");
+    }
+
+    #[tokio::test]
+    async fn test_header_is_only_checked_next_to_instances() -> anyhow::Result<(), LinkMLError> {
+        let temp_dir = TempDir::new().expect("should create temporary directory: {}");
+        let loader = InstanceLoader::new(wire_timestamp().into_arc());
+
+        // Data with an id of its own but no instances list is not a TextPast
+        // instance document
+        let json_path = temp_dir.path().join("languages.json");
+        fs::write(
+            &json_path,
+            r#"{"id": "languages", "languages": [{"id": "nld"}, {"id": "fry"}]}"#,
+        )
+        .await
+        .expect("should write test JSON file: {}");
+        let data = loader
+            .load_json_file(&json_path, &InstanceConfig::default())
+            .await?;
+        assert_eq!(data.values.len(), 2);
+
+        let yaml_path = temp_dir.path().join("languages.yaml");
+        fs::write(
+            &yaml_path,
+            "id: languages\nlanguages:\n  - id: nld\n  - id: fry\n",
+        )
+        .await
+        .expect("should write test YAML file: {}");
+        let data = loader
+            .load_yaml_file(&yaml_path, &InstanceConfig::default())
+            .await?;
+        assert_eq!(data.values.len(), 2);
+
+        // Next to an instances list the same id is held to the convention
+        let json_path = temp_dir.path().join("instances.json");
+        fs::write(
+            &json_path,
+            r#"{"id": "languages", "instances": [{"id": "nld"}]}"#,
+        )
+        .await
+        .expect("should write test JSON file: {}");
+        let error = loader
+            .load_json_file(&json_path, &InstanceConfig::default())
+            .await
+            .expect_err("a non-compliant header should be rejected");
+        assert!(error.to_string().contains("/instance"), "{error}");
+        Ok(())
+    }
 }
@@ -2,12 +2,70 @@
 //!
 //! Automatically detects and configures instance-based validation from schema definitions
 
-use linkml_core::error::Result;
+use linkml_core::annotations::{Annotatable, AnnotationValue};
+use linkml_core::error::{LinkMLError, Result};
 use linkml_core::types::{SchemaDefinition, SlotDefinition};
-use std::path::PathBuf;
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::time::Instant;
+use unicode_normalization::UnicodeNormalization;
 
-use super::instance_loader::{InstanceConfig, InstanceData, InstanceLoader};
+use super::enum_resolver::EnumResolver;
+use super::instance_loader::{InstanceConfig, InstanceData, InstanceFilter, InstanceLoader};
+use crate::parser::ImportKind;
+use crate::utils::safe_cast::u128_to_u64_saturating;
+
+/// Slot annotation accepting fixed UTC offsets besides the instance IDs
+///
+/// With `accept_utc_offsets: true`, a slot with `range_type: instance` also
+/// accepts values such as `UTC+5` or `UTC+05:30`, so timezone slots can take
+/// fixed offsets without listing every offset in the instance file.
+pub const ACCEPT_UTC_OFFSETS: &str = "accept_utc_offsets";
+
+/// How values are compared with instance IDs
+///
+/// The default compares values exactly. Each option applies to both the
+/// value and the IDs it is compared with.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct InstanceMatchOptions {
+    /// Compare without regard to case, so `us` matches `US`
+    pub case_insensitive: bool,
+    /// Ignore leading and trailing whitespace
+    pub trim: bool,
+    /// Compare the Unicode NFC forms, so composed and decomposed accents match
+    pub nfc_normalize: bool,
+}
+
+impl InstanceMatchOptions {
+    /// Bring a value or ID into the form it is compared in
+    #[must_use]
+    pub fn normalize<'a>(&self, value: &'a str) -> Cow<'a, str> {
+        let mut value = Cow::Borrowed(if self.trim { value.trim() } else { value });
+        if self.case_insensitive {
+            value = Cow::Owned(value.to_lowercase());
+        }
+        if self.nfc_normalize {
+            value = Cow::Owned(value.nfc().collect());
+        }
+        value
+    }
+}
+
+/// Resolved instance data together with the file it was loaded from
+#[derive(Clone)]
+struct CachedInstanceData {
+    data: Arc<InstanceData>,
+    path: PathBuf,
+}
+
+/// Valid IDs normalized for one set of match options
+struct NormalizedIds {
+    /// Instance data the IDs were taken from; stale once the cache reloads it
+    data: Arc<InstanceData>,
+    ids: Arc<HashSet<String>>,
+}
 
 /// Resolves instance files and configures validation
 pub struct InstanceResolver {
@@ -16,7 +74,15 @@ pub struct InstanceResolver {
     /// Instance loader
     loader: Arc<InstanceLoader>,
     /// Cache of resolved instance data by range class name
-    instance_cache: dashmap::DashMap<String, Arc<InstanceData>>,
+    instance_cache: dashmap::DashMap<String, CachedInstanceData>,
+    /// Filters restricting which instances are loaded, by range class name
+    filters: dashmap::DashMap<String, InstanceFilter>,
+    /// Whether to reload cached data whose file changed after it was loaded
+    reload_on_change: bool,
+    /// How values are compared with instance IDs
+    match_options: InstanceMatchOptions,
+    /// Normalized valid IDs by range class, key field and match options
+    normalized_ids: dashmap::DashMap<(String, InstanceMatchOptions), NormalizedIds>,
 }
 
 impl InstanceResolver {
@@ -26,45 +92,115 @@ impl InstanceResolver {
             schema_base_dir,
             loader,
             instance_cache: dashmap::DashMap::new(),
+            filters: dashmap::DashMap::new(),
+            reload_on_change: false,
+            match_options: InstanceMatchOptions::default(),
+            normalized_ids: dashmap::DashMap::new(),
         }
     }
 
-    /// Resolve instance file path from import statement
+    /// Enum resolver reading code sets from the same directory and loader
+    #[must_use]
+    pub fn enum_resolver(&self) -> EnumResolver {
+        EnumResolver::new(self.schema_base_dir.clone(), Arc::clone(&self.loader))
+    }
+
+    /// Compare values with instance IDs using `options`
     ///
-    /// RootReal/Textpast convention:
-    /// - Schema imports: `txp:path/to/module/schema` → `path/to/module/schema.yaml`
-    /// - Instance imports: `txp:path/to/module/instance` → `path/to/module.yaml`
+    /// Matching is exact by default.
+    #[must_use]
+    pub fn with_match_options(mut self, options: InstanceMatchOptions) -> Self {
+        self.match_options = options;
+        self
+    }
+
+    /// Reload instance files modified on disk after they were cached
     ///
-    /// Example: `txp:place/polity/country/iso_3166_entity/instance`
-    ///       → `place/polity/country/iso_3166_entity.yaml`
-    fn resolve_instance_path(&self, import: &str) -> Option<PathBuf> {
-        // Remove prefix (e.g., "txp:")
-        let path_part = if let Some(idx) = import.find(':') {
-            &import[idx + 1..]
+    /// Each cache hit then compares the file's modification time with
+    /// [`InstanceData::loaded_at`], which costs one `stat` per lookup.
+    #[must_use]
+    pub fn with_reload_on_change(mut self, enabled: bool) -> Self {
+        self.reload_on_change = enabled;
+        self
+    }
+
+    /// Drop cached instance data
+    ///
+    /// `source` may be a range class name, an instance file path, or an
+    /// [`InstanceData::source`] value. The underlying loader cache entries for
+    /// the affected files are dropped too, so the next lookup rereads them.
+    pub fn invalidate(&self, source: &str) {
+        let class_prefix = format!("{source}::");
+        let file_source = if source.starts_with("file:") {
+            source.to_string()
         } else {
-            import
+            format!("file:{source}")
         };
+        let filtered_prefix = format!("{file_source}?");
 
-        // Check if this is an instance import (ends with /instance)
-        if !path_part.ends_with("/instance") {
-            return None;
+        let mut removed_sources = Vec::new();
+        self.instance_cache.retain(|key, cached| {
+            let matches = key.starts_with(&class_prefix)
+                || cached.data.source == file_source
+                || cached.data.source.starts_with(&filtered_prefix);
+            if matches {
+                removed_sources.push(cached.data.source.clone());
+            }
+            !matches
+        });
+
+        self.loader.invalidate(source);
+        for removed in &removed_sources {
+            self.loader.invalidate(removed);
         }
+    }
 
-        // Remove the /instance suffix to get the base path
-        let base_path = &path_part[..path_part.len() - "/instance".len()];
+    /// Drop all cached instance data, including the loader's cache
+    pub fn clear_cache(&self) {
+        self.instance_cache.clear();
+        self.normalized_ids.clear();
+        self.loader.clear_cache();
+    }
+
+    /// Number of cached instance data sets, one per range class and key field
+    #[must_use]
+    pub fn cached_entries(&self) -> usize {
+        self.instance_cache.len()
+    }
 
-        // Try .yaml extension first, then .yml
-        let yaml_path = self.schema_base_dir.join(format!("{base_path}.yaml"));
-        if yaml_path.exists() {
-            return Some(yaml_path);
+    /// Check whether a cached entry's file was modified after it was loaded
+    async fn is_modified_since_load(cached: &CachedInstanceData) -> bool {
+        match tokio::fs::metadata(&cached.path)
+            .await
+            .and_then(|metadata| metadata.modified())
+        {
+            Ok(modified) => chrono::DateTime::<chrono::Utc>::from(modified) > cached.data.loaded_at,
+            // A file we cannot stat is left to the next explicit invalidation
+            Err(_) => false,
         }
+    }
 
-        let yml_path = self.schema_base_dir.join(format!("{base_path}.yml"));
-        if yml_path.exists() {
-            return Some(yml_path);
+    /// Restrict the instances loaded for a range class to those matching `filter`
+    ///
+    /// Passing `None` removes the filter. Cached data for the range class is
+    /// discarded so the next lookup reflects the change.
+    pub fn set_instance_filter(&self, range_class: &str, filter: Option<InstanceFilter>) {
+        match filter {
+            Some(filter) => {
+                self.filters.insert(range_class.to_string(), filter);
+            }
+            None => {
+                self.filters.remove(range_class);
+            }
         }
 
-        None
+        let prefix = format!("{range_class}::");
+        self.instance_cache.retain(|key, _| !key.starts_with(&prefix));
+    }
+
+    /// Resolve instance file path from import statement
+    fn resolve_instance_path(&self, import: &str, schema: &SchemaDefinition) -> Option<PathBuf> {
+        resolve_instance_import(&self.schema_base_dir, import, schema)
     }
 
     /// Load instance data for a range class with specified key field
@@ -78,39 +214,73 @@ impl InstanceResolver {
         key_field: &str,
         schema: &SchemaDefinition,
     ) -> Result<Option<Arc<InstanceData>>> {
-        // Create cache key that includes the field name
-        let cache_key = format!("{range_class}::{key_field}");
+        self.load_instance_for_range_with_fields(range_class, key_field, None, schema)
+            .await
+    }
+
+    /// Load instance data keyed by `key_field`, holding `value_field` values
+    ///
+    /// Without a value field each key maps to itself.
+    async fn load_instance_for_range_with_fields(
+        &self,
+        range_class: &str,
+        key_field: &str,
+        value_field: Option<&str>,
+        schema: &SchemaDefinition,
+    ) -> Result<Option<Arc<InstanceData>>> {
+        // Create cache key that includes the field names
+        let cache_key = match value_field {
+            Some(value_field) => format!("{range_class}::{key_field}::{value_field}"),
+            None => format!("{range_class}::{key_field}"),
+        };
 
         // Check cache first
-        if let Some(cached) = self.instance_cache.get(&cache_key) {
-            return Ok(Some(Arc::clone(&cached)));
+        let cached = self
+            .instance_cache
+            .get(&cache_key)
+            .map(|entry| entry.value().clone());
+        if let Some(cached) = cached {
+            if !self.reload_on_change || !Self::is_modified_since_load(&cached).await {
+                return Ok(Some(cached.data));
+            }
+            self.invalidate(&cached.data.source);
         }
 
         // Find the import that provides this range class
         for import in &schema.imports {
-            if let Some(instance_path) = self.resolve_instance_path(import) {
+            // `txp:` imports must say whether they name a schema or an instance file
+            if import.starts_with("txp:") && ImportKind::from_suffix(import)? == ImportKind::Schema
+            {
+                continue;
+            }
+            if let Some(instance_path) = self.resolve_instance_path(import, schema) {
                 // Load the instance file with specified key field
                 let config = InstanceConfig {
                     key_field: key_field.to_string(),
-                    value_field: None,
-                    filter: None,
+                    value_field: value_field.map(str::to_string),
+                    filter: self.filters.get(range_class).map(|filter| filter.clone()),
                 };
 
-                match self.loader.load_file(&instance_path, &config).await {
-                    Ok(instance_data) => {
-                        // Cache it
-                        self.instance_cache.insert(
-                            cache_key,
-                            Arc::clone(&instance_data),
-                        );
-                        return Ok(Some(instance_data));
-                    }
-                    Err(e) => {
-                        // Log but continue - might not be the right file
-                        eprintln!("Warning: Failed to load instance file {}: {}",
-                            instance_path.display(), e);
-                    }
-                }
+                let instance_data = self
+                    .loader
+                    .load_file(&instance_path, &config)
+                    .await
+                    .map_err(|e| {
+                        LinkMLError::data_validation(format!(
+                            "Failed to load instance file {} for {range_class}: {e}",
+                            instance_path.display()
+                        ))
+                    })?;
+
+                // Cache it
+                self.instance_cache.insert(
+                    cache_key,
+                    CachedInstanceData {
+                        data: Arc::clone(&instance_data),
+                        path: instance_path,
+                    },
+                );
+                return Ok(Some(instance_data));
             }
         }
 
@@ -132,64 +302,130 @@ impl InstanceResolver {
 
     /// Get valid instance IDs for a slot with range_type: instance
     ///
+    /// Runs in a `linkml.instance_ids` span that records the number of valid
+    /// IDs and the time spent loading them.
+    ///
     /// # Errors
     ///
     /// Returns an error if the instance data cannot be loaded
+    #[tracing::instrument(
+        name = "linkml.instance_ids",
+        skip_all,
+        fields(
+            slot = %slot.name,
+            ids = tracing::field::Empty,
+            elapsed_ms = tracing::field::Empty,
+        )
+    )]
     pub async fn get_valid_ids_for_slot(
         &self,
         slot: &SlotDefinition,
         schema: &SchemaDefinition,
     ) -> Result<Option<Vec<String>>> {
-        // Check if this slot has range_type: instance
-        if slot.range_type.as_deref() != Some("instance") {
+        let Some((range_class, property)) = instance_range(slot) else {
             return Ok(None);
-        }
+        };
+        let start = Instant::now();
+
+        // Load instance data for this range with the correct field
+        let ids = self
+            .load_instance_for_range_with_field(range_class, property, schema)
+            .await?
+            .and_then(|data| instance_ids(&data, property));
+
+        let span = tracing::Span::current();
+        span.record("ids", ids.as_ref().map_or(0, Vec::len));
+        span.record(
+            "elapsed_ms",
+            u128_to_u64_saturating(start.elapsed().as_millis()),
+        );
+        Ok(ids)
+    }
 
-        // Get the range class
-        let range_class = match &slot.range {
-            Some(range) => range,
-            None => return Ok(None),
+    /// Valid instance IDs for a slot, normalized with `options`
+    ///
+    /// The normalized set is cached per range class, key field and option
+    /// set, and rebuilt once the underlying instance data is reloaded.
+    async fn normalized_ids_for_slot(
+        &self,
+        slot: &SlotDefinition,
+        schema: &SchemaDefinition,
+        options: InstanceMatchOptions,
+    ) -> Result<Option<Arc<HashSet<String>>>> {
+        let Some((range_class, property)) = instance_range(slot) else {
+            return Ok(None);
+        };
+        let Some(data) = self
+            .load_instance_for_range_with_field(range_class, property, schema)
+            .await?
+        else {
+            return Ok(None);
         };
 
-        // Get the property to use (default to 'id')
-        let property = slot.range_properties
-            .first()
-            .map(|s| s.as_str())
-            .unwrap_or("id");
+        let key = (format!("{range_class}::{property}"), options);
+        if let Some(cached) = self.normalized_ids.get(&key)
+            && Arc::ptr_eq(&cached.data, &data)
+        {
+            return Ok(Some(Arc::clone(&cached.ids)));
+        }
 
-        // Load instance data for this range with the correct field
-        let instance_data = match self.load_instance_for_range_with_field(
-            range_class,
-            property,
-            schema
-        ).await? {
-            Some(data) => data,
-            None => return Ok(None),
+        let Some(ids) = instance_ids(&data, property) else {
+            return Ok(None);
         };
+        let ids: Arc<HashSet<String>> = Arc::new(
+            ids.iter()
+                .map(|id| options.normalize(id).into_owned())
+                .collect(),
+        );
+        self.normalized_ids.insert(
+            key,
+            NormalizedIds {
+                data,
+                ids: Arc::clone(&ids),
+            },
+        );
+        Ok(Some(ids))
+    }
 
-        // Extract IDs from instance data
-        // The keys in the values map are the instance IDs
-        let ids = instance_data.values
-            .get(property)
-            .cloned()
-            .or_else(|| {
-                // If property not found, try to get all unique keys
-                let all_ids: Vec<String> = instance_data.values
-                    .keys()
-                    .cloned()
-                    .collect();
-                if all_ids.is_empty() {
-                    None
-                } else {
-                    Some(all_ids)
-                }
-            });
+    /// Look up the value field of the instance identified by `id`
+    ///
+    /// The value field is the slot's second `range_properties` entry, e.g.
+    /// `label` for `range_properties: [id, label]`, and `label` if the slot
+    /// names only its key field. The values are loaded and cached next to the
+    /// slot's valid IDs. Returns `None` if the slot is not instance-based or
+    /// no instance has the ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the instance data cannot be loaded
+    pub async fn lookup_value(
+        &self,
+        id: &str,
+        slot: &SlotDefinition,
+        schema: &SchemaDefinition,
+    ) -> Result<Option<String>> {
+        let Some((range_class, key_field)) = instance_range(slot) else {
+            return Ok(None);
+        };
+        let value_field = slot.range_properties.get(1).map_or("label", String::as_str);
+        let Some(data) = self
+            .load_instance_for_range_with_fields(range_class, key_field, Some(value_field), schema)
+            .await?
+        else {
+            return Ok(None);
+        };
 
-        Ok(ids)
+        Ok(data
+            .values
+            .get(id)
+            .and_then(|values| values.first())
+            .cloned())
     }
 
     /// Validate a value against instance data
     ///
+    /// Values are compared with the resolver's [`InstanceMatchOptions`].
+    ///
     /// # Errors
     ///
     /// Returns an error if validation fails
@@ -199,12 +435,542 @@ impl InstanceResolver {
         slot: &SlotDefinition,
         schema: &SchemaDefinition,
     ) -> Result<bool> {
-        let valid_ids = match self.get_valid_ids_for_slot(slot, schema).await? {
+        let valid_ids = match self
+            .normalized_ids_for_slot(slot, schema, self.match_options)
+            .await?
+        {
             Some(ids) => ids,
             None => return Ok(true), // No instance validation needed
         };
 
-        Ok(valid_ids.contains(&value.to_string()))
+        let value_matches = valid_ids.contains(&*self.match_options.normalize(value));
+        Ok(value_matches || is_accepted_utc_offset(slot, value))
+    }
+
+    /// Validate many values for the same slot against instance data
+    ///
+    /// Loads the valid IDs once (through the instance cache) and checks every
+    /// value against them. Results are returned in the same order as `values`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the instance data cannot be loaded
+    pub async fn validate_instance_values(
+        &self,
+        values: &[&str],
+        slot: &SlotDefinition,
+        schema: &SchemaDefinition,
+    ) -> Result<Vec<bool>> {
+        self.validate_instance_values_with(values, slot, schema, self.match_options)
+            .await
+    }
+
+    /// Validate many values for the same slot, compared using `options`
+    ///
+    /// Like [`Self::validate_instance_values`], but with match options for
+    /// this call only instead of the resolver's.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the instance data cannot be loaded
+    pub async fn validate_instance_values_with(
+        &self,
+        values: &[&str],
+        slot: &SlotDefinition,
+        schema: &SchemaDefinition,
+        options: InstanceMatchOptions,
+    ) -> Result<Vec<bool>> {
+        let valid_ids = match self.normalized_ids_for_slot(slot, schema, options).await? {
+            Some(ids) => ids,
+            None => return Ok(vec![true; values.len()]), // No instance validation needed
+        };
+
+        Ok(values
+            .iter()
+            .map(|value| {
+                valid_ids.contains(&*options.normalize(value))
+                    || is_accepted_utc_offset(slot, value)
+            })
+            .collect())
     }
 }
 
+/// Range class and key field of a slot with `range_type: instance`
+///
+/// The key field is the first of the slot's `range_properties`, `id` if it
+/// has none.
+fn instance_range(slot: &SlotDefinition) -> Option<(&str, &str)> {
+    if slot.range_type.as_deref() != Some("instance") {
+        return None;
+    }
+    let range_class = slot.range.as_deref()?;
+    let property = slot.range_properties.first().map_or("id", String::as_str);
+    Some((range_class, property))
+}
+
+/// IDs of loaded instance data for a key field
+///
+/// Falls back to all keys of the data if no values were collected for the
+/// field itself; `None` if the data holds no IDs at all.
+fn instance_ids(data: &InstanceData, property: &str) -> Option<Vec<String>> {
+    data.values.get(property).cloned().or_else(|| {
+        let all_ids: Vec<String> = data.values.keys().cloned().collect();
+        if all_ids.is_empty() {
+            None
+        } else {
+            Some(all_ids)
+        }
+    })
+}
+
+/// Whether `value` is a UTC offset and `slot` accepts those
+///
+/// See [`ACCEPT_UTC_OFFSETS`]. Offsets take the form `UTC±H`, `UTC±HH` or
+/// `UTC±HH:MM`, with hours up to 14 and minutes below 60.
+pub(crate) fn is_accepted_utc_offset(slot: &SlotDefinition, value: &str) -> bool {
+    if !matches!(
+        slot.get_annotation(ACCEPT_UTC_OFFSETS),
+        Some(AnnotationValue::Bool(true))
+    ) {
+        return false;
+    }
+    let Some(offset) = value
+        .strip_prefix("UTC")
+        .and_then(|rest| rest.strip_prefix(['+', '-']))
+    else {
+        return false;
+    };
+    let (hours, minutes) = match offset.split_once(':') {
+        Some((hours, minutes)) if minutes.len() == 2 => (hours, minutes),
+        Some(_) => return false,
+        None => (offset, "00"),
+    };
+    let at_most = |digits: &str, max: u8| {
+        digits.bytes().all(|byte| byte.is_ascii_digit())
+            && digits.parse::<u8>().is_ok_and(|number| number <= max)
+    };
+
+    (1..=2).contains(&hours.len()) && at_most(hours, 14) && at_most(minutes, 59)
+}
+
+/// Resolve an instance import to the instance file under `schema_base_dir`
+///
+/// RootReal/Textpast convention:
+/// - Schema imports: `txp:path/to/module/schema` → `path/to/module/schema.yaml`
+/// - Instance imports: `txp:path/to/module/instance` → `path/to/module.yaml`
+///
+/// Example: `txp:place/polity/country/iso_3166_entity/instance`
+///       → `place/polity/country/iso_3166_entity.yaml`
+///
+/// An import written as a full URI under one of the schema's prefixes is
+/// contracted to its CURIE first.
+pub(crate) fn resolve_instance_import(
+    schema_base_dir: &Path,
+    import: &str,
+    schema: &SchemaDefinition,
+) -> Option<PathBuf> {
+    let curie = schema.contract_uri(import);
+    let import = curie.as_deref().unwrap_or(import);
+
+    // Remove prefix (e.g., "txp:")
+    let path_part = import.split_once(':').map_or(import, |(_, local)| local);
+
+    // Check if this is an instance import (ends with /instance)
+    if !path_part.ends_with("/instance") {
+        return None;
+    }
+
+    // Remove the /instance suffix to get the base path
+    let base_path = &path_part[..path_part.len() - "/instance".len()];
+
+    // Try .yaml extension first, then .yml
+    let yaml_path = schema_base_dir.join(format!("{base_path}.yaml"));
+    if yaml_path.exists() {
+        return Some(yaml_path);
+    }
+
+    let yml_path = schema_base_dir.join(format!("{base_path}.yml"));
+    if yml_path.exists() {
+        return Some(yml_path);
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+    use timestamp_service::wiring::wire_timestamp;
+    use tokio::fs;
+
+    const IMPORT: &str = "txp:language/iso_639_3_entity/instance";
+
+    async fn write_languages(dir: &TempDir, ids: &[&str]) {
+        let entries: String = ids.iter().map(|id| format!("  - id: {id}\n")).collect();
+        let yaml = format!("instances:\n{entries}");
+        fs::create_dir_all(dir.path().join("language"))
+            .await
+            .expect("should create instance directory");
+        fs::write(dir.path().join("language/iso_639_3_entity.yaml"), yaml)
+            .await
+            .expect("should write instance file");
+    }
+
+    fn resolver_for(dir: &TempDir) -> InstanceResolver {
+        let loader = Arc::new(InstanceLoader::new(wire_timestamp().into_arc()));
+        InstanceResolver::new(dir.path().to_path_buf(), loader)
+    }
+
+    fn schema() -> SchemaDefinition {
+        let mut schema = SchemaDefinition::new("languages");
+        schema.imports.push(IMPORT.to_string());
+        schema
+    }
+
+    async fn language_count(resolver: &InstanceResolver, schema: &SchemaDefinition) -> usize {
+        resolver
+            .load_instance_for_range("Iso6393Entity", schema)
+            .await
+            .expect("should load instance data")
+            .map_or(0, |data| data.values.len())
+    }
+
+    #[tokio::test]
+    async fn test_invalidate_rereads_instance_file() {
+        let dir = TempDir::new().expect("should create temporary directory");
+        write_languages(&dir, &["eng", "nld"]).await;
+        let resolver = resolver_for(&dir);
+        let schema = schema();
+
+        assert_eq!(language_count(&resolver, &schema).await, 2);
+
+        write_languages(&dir, &["eng", "nld", "fra"]).await;
+        assert_eq!(language_count(&resolver, &schema).await, 2);
+
+        resolver.invalidate("Iso6393Entity");
+        assert_eq!(language_count(&resolver, &schema).await, 3);
+
+        write_languages(&dir, &["eng"]).await;
+        resolver.clear_cache();
+        assert_eq!(language_count(&resolver, &schema).await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_reload_on_change_detects_modified_file() {
+        let dir = TempDir::new().expect("should create temporary directory");
+        write_languages(&dir, &["eng", "nld"]).await;
+        let resolver = resolver_for(&dir).with_reload_on_change(true);
+        let schema = schema();
+
+        assert_eq!(language_count(&resolver, &schema).await, 2);
+
+        // Date the rewrite past `loaded_at` rather than waiting for the clock
+        write_languages(&dir, &["eng", "nld", "fra"]).await;
+        std::fs::File::options()
+            .write(true)
+            .open(dir.path().join("language/iso_639_3_entity.yaml"))
+            .and_then(|file| {
+                file.set_modified(std::time::SystemTime::now() + std::time::Duration::from_secs(60))
+            })
+            .expect("should set the modification time");
+        assert_eq!(language_count(&resolver, &schema).await, 3);
+    }
+
+    #[tokio::test]
+    async fn test_full_uri_import_is_contracted_with_schema_prefixes() {
+        let dir = TempDir::new().expect("should create temporary directory");
+        write_languages(&dir, &["eng", "nld"]).await;
+        let resolver = resolver_for(&dir);
+
+        let mut schema = SchemaDefinition::new("languages");
+        schema.prefixes.insert(
+            "txp".to_string(),
+            linkml_core::types::PrefixDefinition::Simple("https://textpast.org/".to_string()),
+        );
+        schema.imports =
+            vec!["https://textpast.org/language/iso_639_3_entity/instance".to_string()];
+        assert_eq!(language_count(&resolver, &schema).await, 2);
+    }
+
+    #[tokio::test]
+    async fn test_misrouted_instance_import_is_rejected() {
+        let dir = TempDir::new().expect("should create temporary directory");
+        write_languages(&dir, &["eng"]).await;
+        let resolver = resolver_for(&dir);
+
+        // Schema imports are left to the import resolver
+        let mut schema = SchemaDefinition::new("languages");
+        schema.imports = vec!["txp:language/iso_639_3_entity/schema".to_string()];
+        assert_eq!(language_count(&resolver, &schema).await, 0);
+
+        schema.imports = vec!["txp:language/iso_639_3_entity".to_string()];
+        let error = resolver
+            .load_instance_for_range("Iso6393Entity", &schema)
+            .await
+            .expect_err("import without a suffix should be rejected");
+        assert!(error.to_string().contains("/instance"), "{error}");
+    }
+
+    #[tokio::test]
+    async fn test_utc_offsets_complement_instance_ids() {
+        let dir = TempDir::new().expect("should create temporary directory");
+        fs::create_dir_all(dir.path().join("time/timezone"))
+            .await
+            .expect("should create instance directory");
+        fs::write(
+            dir.path().join("time/timezone/timezone.yaml"),
+            "instances:\n  - id: Europe/London\n  - id: Asia/Kolkata\n",
+        )
+        .await
+        .expect("should write instance file");
+        let resolver = resolver_for(&dir);
+        let mut schema = SchemaDefinition::new("timezone");
+        schema.imports = vec!["txp:time/timezone/timezone/instance".to_string()];
+
+        let mut slot = SlotDefinition::new("timezone");
+        slot.range = Some("Timezone".to_string());
+        slot.range_type = Some("instance".to_string());
+        let values = ["Europe/London", "UTC+5", "UTC+05:30", "UTC+25", "UTC+5:3"];
+
+        let without_offsets = resolver
+            .validate_instance_values(&values, &slot, &schema)
+            .await
+            .expect("should validate timezones");
+        assert_eq!(without_offsets, [true, false, false, false, false]);
+
+        slot.annotations = Some(
+            [(ACCEPT_UTC_OFFSETS.to_string(), AnnotationValue::Bool(true))]
+                .into_iter()
+                .collect(),
+        );
+        let with_offsets = resolver
+            .validate_instance_values(&values, &slot, &schema)
+            .await
+            .expect("should validate timezones");
+        assert_eq!(with_offsets, [true, true, true, false, false]);
+    }
+
+    #[tokio::test]
+    async fn test_match_options_relax_comparison() {
+        let dir = TempDir::new().expect("should create temporary directory");
+        fs::create_dir_all(dir.path().join("place/polity/country"))
+            .await
+            .expect("should create instance directory");
+        fs::write(
+            dir.path().join("place/polity/country/iso_3166_entity.yaml"),
+            "instances:\n  - id: US\n  - id: Cura\u{e7}ao\n",
+        )
+        .await
+        .expect("should write instance file");
+        let mut schema = SchemaDefinition::new("country");
+        schema.imports = vec!["txp:place/polity/country/iso_3166_entity/instance".to_string()];
+        let mut slot = SlotDefinition::new("country");
+        slot.range = Some("ISO3166Entity".to_string());
+        slot.range_type = Some("instance".to_string());
+
+        let strict = resolver_for(&dir)
+            .validate_instance_value("us", &slot, &schema)
+            .await
+            .expect("should validate country");
+        assert!(!strict);
+
+        let lenient = resolver_for(&dir).with_match_options(InstanceMatchOptions {
+            case_insensitive: true,
+            ..InstanceMatchOptions::default()
+        });
+        let case_insensitive = lenient
+            .validate_instance_value("us", &slot, &schema)
+            .await
+            .expect("should validate country");
+        assert!(case_insensitive);
+
+        // Decomposed c + cedilla, padded with whitespace
+        let values = [" US ", "Curac\u{327}ao"];
+        let exact = lenient
+            .validate_instance_values_with(&values, &slot, &schema, InstanceMatchOptions::default())
+            .await
+            .expect("should validate countries");
+        assert_eq!(exact, [false, false]);
+        let normalized = lenient
+            .validate_instance_values_with(
+                &values,
+                &slot,
+                &schema,
+                InstanceMatchOptions {
+                    trim: true,
+                    nfc_normalize: true,
+                    ..InstanceMatchOptions::default()
+                },
+            )
+            .await
+            .expect("should validate countries");
+        assert_eq!(normalized, [true, true]);
+    }
+
+    #[tokio::test]
+    async fn test_lookup_value_maps_id_to_label() {
+        let dir = TempDir::new().expect("should create temporary directory");
+        fs::create_dir_all(dir.path().join("language"))
+            .await
+            .expect("should create instance directory");
+        fs::write(
+            dir.path().join("language/iso_639_3_entity.yaml"),
+            "instances:\n  - id: eng\n    label: English\n  - id: nld\n    label: Dutch\n",
+        )
+        .await
+        .expect("should write instance file");
+        let resolver = resolver_for(&dir);
+        let schema = schema();
+        let mut slot = SlotDefinition::new("language");
+        slot.range = Some("Iso6393Entity".to_string());
+        slot.range_type = Some("instance".to_string());
+
+        let valid = resolver
+            .validate_instance_value("eng", &slot, &schema)
+            .await
+            .expect("should validate language");
+        assert!(valid);
+        let label = resolver
+            .lookup_value("eng", &slot, &schema)
+            .await
+            .expect("should look up language");
+        assert_eq!(label.as_deref(), Some("English"));
+        let unknown = resolver
+            .lookup_value("xxx", &slot, &schema)
+            .await
+            .expect("should look up language");
+        assert_eq!(unknown, None);
+        // The labels are cached separately from the plain ID set
+        assert_eq!(resolver.cached_entries(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_lookup_value_skips_instances_without_the_field() -> anyhow::Result<()> {
+        let dir = TempDir::new()?;
+        fs::create_dir_all(dir.path().join("language")).await?;
+        fs::write(
+            dir.path().join("language/iso_639_3_entity.yaml"),
+            "instances:\n  - id: eng\n    label: English\n  - id: und\n",
+        )
+        .await?;
+        let resolver = resolver_for(&dir);
+        let schema = schema();
+        let mut slot = SlotDefinition::new("language");
+        slot.range = Some("Iso6393Entity".to_string());
+        slot.range_type = Some("instance".to_string());
+
+        assert_eq!(
+            resolver
+                .lookup_value("eng", &slot, &schema)
+                .await?
+                .as_deref(),
+            Some("English")
+        );
+        assert_eq!(resolver.lookup_value("und", &slot, &schema).await?, None);
+        assert!(
+            resolver
+                .validate_instance_value("und", &slot, &schema)
+                .await?
+        );
+
+        // A file that cannot be loaded is reported rather than skipped
+        fs::write(
+            dir.path().join("language/iso_639_3_entity.yaml"),
+            "instances:\n  - label: English\n",
+        )
+        .await?;
+        resolver.clear_cache();
+        let error = resolver
+            .lookup_value("eng", &slot, &schema)
+            .await
+            .expect_err("an instance without an id should fail the load");
+        assert!(
+            error.to_string().contains("iso_639_3_entity.yaml"),
+            "{error}"
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_instance_filter_restricts_valid_ids() -> anyhow::Result<()> {
+        let dir = TempDir::new()?;
+        fs::create_dir_all(dir.path().join("language")).await?;
+        fs::write(
+            dir.path().join("language/iso_639_3_entity.yaml"),
+            "instances:\n  - id: eng\n    status: active\n  - id: nld\n    status: active\n  \
+             - id: ang\n    status: historical\n",
+        )
+        .await?;
+        let resolver = resolver_for(&dir);
+        let schema = schema();
+        let mut slot = SlotDefinition::new("language");
+        slot.range = Some("Iso6393Entity".to_string());
+        slot.range_type = Some("instance".to_string());
+        let sorted_ids = |ids: Option<Vec<String>>| {
+            let mut ids = ids.unwrap_or_default();
+            ids.sort_unstable();
+            ids
+        };
+
+        let ids = resolver.get_valid_ids_for_slot(&slot, &schema).await?;
+        assert_eq!(sorted_ids(ids), ["ang", "eng", "nld"]);
+
+        // Setting a filter discards the cached, unfiltered IDs
+        resolver.set_instance_filter(
+            "Iso6393Entity",
+            Some(InstanceFilter::new("status", "active")),
+        );
+        let ids = resolver.get_valid_ids_for_slot(&slot, &schema).await?;
+        assert_eq!(sorted_ids(ids), ["eng", "nld"]);
+        assert!(
+            !resolver
+                .validate_instance_value("ang", &slot, &schema)
+                .await?
+        );
+
+        resolver.set_instance_filter("Iso6393Entity", None);
+        let ids = resolver.get_valid_ids_for_slot(&slot, &schema).await?;
+        assert_eq!(sorted_ids(ids), ["ang", "eng", "nld"]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_validate_instance_values_checks_positionally() -> anyhow::Result<()> {
+        let dir = TempDir::new()?;
+        write_languages(&dir, &["eng", "nld"]).await;
+        let resolver = resolver_for(&dir);
+        let schema = schema();
+        let mut slot = SlotDefinition::new("language");
+        slot.range = Some("Iso6393Entity".to_string());
+        slot.range_type = Some("instance".to_string());
+
+        let values = ["nld", "xxx", "eng", "ENG", "nld"];
+        let results = resolver
+            .validate_instance_values(&values, &slot, &schema)
+            .await?;
+        assert_eq!(results, [true, false, true, false, true]);
+        for (value, batched) in values.iter().zip(&results) {
+            let single = resolver
+                .validate_instance_value(value, &slot, &schema)
+                .await?;
+            assert_eq!(single, *batched, "{value}");
+        }
+
+        // The IDs were loaded once and are reused from the cache
+        assert_eq!(resolver.cached_entries(), 1);
+        write_languages(&dir, &["fra"]).await;
+        let results = resolver
+            .validate_instance_values(&["eng", "fra"], &slot, &schema)
+            .await?;
+        assert_eq!(results, [true, false]);
+
+        // Slots without an instance range accept every value
+        let plain = SlotDefinition::new("note");
+        let results = resolver
+            .validate_instance_values(&["anything", ""], &plain, &schema)
+            .await?;
+        assert_eq!(results, [true, true]);
+        Ok(())
+    }
+}
@@ -272,6 +272,83 @@ impl fmt::Display for JsonPath {
     }
 }
 
+/// Path segments that name a part of a slot's definition rather than a value
+/// in the data, e.g. the `any_of[1]` in `$.age.any_of[1]`
+const SCHEMA_SCOPES: &[&str] = &[
+    "any_of",
+    "all_of",
+    "exactly_one_of",
+    "none_of",
+    "if_required",
+    "then_required",
+];
+
+/// Split a validation path into segments
+///
+/// Unlike [`JsonPath::parse`] this never fails: it accepts the forms validators
+/// emit in issue paths, such as `$[0].id`, schema scopes like `any_of[1]`, and
+/// non-numeric brackets (read as property names).
+pub(crate) fn path_segments(path: &str) -> Vec<PathSegment> {
+    let mut segments = Vec::new();
+    let mut property = String::new();
+    let mut chars = path.strip_prefix('$').unwrap_or(path).chars();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '.' | '[' => {
+                if !property.is_empty() {
+                    segments.push(PathSegment::Property(std::mem::take(&mut property)));
+                }
+                if ch == '[' {
+                    let inner: String = chars.by_ref().take_while(|&c| c != ']').collect();
+                    segments.push(match inner.parse() {
+                        Ok(index) => PathSegment::Index(index),
+                        Err(_) if inner == "*" => PathSegment::Wildcard,
+                        Err(_) => PathSegment::Property(inner),
+                    });
+                }
+            }
+            _ => property.push(ch),
+        }
+    }
+    if !property.is_empty() {
+        segments.push(PathSegment::Property(property));
+    }
+    segments
+}
+
+/// Convert a validation path into an RFC 6901 `JSON` Pointer
+///
+/// `$.instances[5].identifier` becomes `/instances/5/identifier` and the root
+/// path becomes the empty pointer. Schema scopes are dropped so the pointer
+/// names the offending value: `$.age.any_of[1]` points at `/age`, while
+/// `$.if_required[a].then_required[b]` points at the missing slot `/b`.
+/// Wildcards have no pointer form and are dropped too.
+#[must_use]
+pub fn json_pointer(path: &str) -> String {
+    let mut pointer = String::new();
+    let mut segments = path_segments(path).into_iter();
+
+    while let Some(segment) = segments.next() {
+        let token = match segment {
+            PathSegment::Property(name) if SCHEMA_SCOPES.contains(&name.as_str()) => {
+                // `then_required[b]` names the required slot; the other
+                // scopes are followed by a schema index or condition
+                if name != "then_required" {
+                    segments.next();
+                }
+                continue;
+            }
+            PathSegment::Property(name) => name.replace('~', "~0").replace('/', "~1"),
+            PathSegment::Index(index) => index.to_string(),
+            PathSegment::Root | PathSegment::Wildcard => continue,
+        };
+        pointer.push('/');
+        pointer.push_str(&token);
+    }
+    pointer
+}
+
 /// Optimized `JSON` path navigator with caching
 pub struct JsonNavigator {
     /// Cache of compiled paths
@@ -404,4 +481,23 @@ mod tests {
         assert_eq!(navigator.path_cache.len(), 1);
         Ok(())
     }
+
+    #[test]
+    fn test_json_pointer() {
+        assert_eq!(json_pointer("$"), "");
+        assert_eq!(
+            json_pointer("$.instances[5].identifier"),
+            "/instances/5/identifier"
+        );
+        assert_eq!(json_pointer("$[3].code"), "/3/code");
+        assert_eq!(json_pointer("$.[3].code"), "/3/code");
+        assert_eq!(json_pointer("$.a~b.c/d"), "/a~0b/c~1d");
+        assert_eq!(json_pointer("$.age.any_of[1]"), "/age");
+        assert_eq!(json_pointer("$.exactly_one_of[0].none_of[2]"), "");
+        assert_eq!(
+            json_pointer("$.if_required[kind].then_required[code]"),
+            "/code"
+        );
+        assert_eq!(json_pointer("$.items[*].id"), "/items/id");
+    }
 }
@@ -5,6 +5,7 @@
 
 use super::compiled::{CompiledType, ValidationInstruction};
 use super::report::{Severity, ValidationIssue};
+use linkml_core::ast::Span;
 use std::mem;
 
 /// Optimized validation issue with better field ordering
@@ -15,6 +16,8 @@ use std::mem;
 pub struct OptimizedValidationIssue {
     /// Path string (typically 24 bytes on 64-bit)
     pub path: String,
+    /// `JSON` Pointer string (24 bytes)
+    pub pointer: String,
     /// Message string (24 bytes)
     pub message: String,
     /// Validator name (24 bytes)
@@ -25,6 +28,8 @@ pub struct OptimizedValidationIssue {
     pub severity: Severity,
     /// Context stored separately to avoid inline `HashMap` overhead
     pub context_id: Option<u32>,
+    /// Source location if known (40 bytes for `Option<Span>`)
+    pub location: Option<Span>,
 }
 
 impl OptimizedValidationIssue {
@@ -33,11 +38,13 @@ impl OptimizedValidationIssue {
     pub fn from_standard(issue: ValidationIssue, context_id: Option<u32>) -> Self {
         Self {
             path: issue.path,
+            pointer: issue.pointer,
             message: issue.message,
             validator: issue.validator,
             code: issue.code,
             severity: issue.severity,
             context_id,
+            location: issue.location,
         }
     }
 
@@ -51,9 +58,11 @@ impl OptimizedValidationIssue {
             severity: self.severity,
             message: self.message,
             path: self.path,
+            pointer: self.pointer,
             validator: self.validator,
             code: self.code,
             context,
+            location: self.location,
         }
     }
 }
@@ -150,7 +159,7 @@ impl CompactInstruction {
                     inclusive,
                 },
             },
-            ValidationInstruction::ValidateLength { path, min, max } => Self {
+            ValidationInstruction::ValidateLength { path, min, max, .. } => Self {
                 path,
                 variant: InstructionVariant::ValidateLength {
                     min: min.map(|v| u32::try_from(v).unwrap_or(u32::MAX)),
@@ -11,6 +11,7 @@
 //! - Parallel validation support
 
 use linkml_core::types::SchemaDefinition;
+pub mod annotation_constraints;
 pub mod buffer_pool;
 pub mod cache;
 pub mod cache_key_optimizer;
@@ -19,8 +20,10 @@ pub mod compiled;
 pub mod composition;
 pub mod conditional_validator;
 pub mod context;
+pub mod custom_constraint;
 pub mod default_applier;
 pub mod engine;
+pub mod enum_resolver;
 pub mod error_recovery;
 pub mod instance_loader;
 pub mod instance_resolver;
@@ -31,14 +34,19 @@ pub mod memory_safety;
 pub mod multi_layer_cache;
 pub mod panic_prevention;
 pub mod parallel;
+pub mod pattern_cache;
 pub mod pattern_validator;
 pub mod recursion_checker;
+pub mod references;
 pub mod report;
 pub mod resource_limiter;
+pub mod result_cache;
 pub mod security;
+pub mod source_map;
 pub mod stress_test;
 pub mod string_interner;
 pub mod ttl_manager;
+mod type_coercion;
 pub mod unique_key_validator;
 pub mod validators;
 
@@ -48,15 +56,24 @@ pub use conditional_validator::{
     Condition, ConditionalRule, ConditionalValidator, ConditionalViolation, Requirement,
 };
 pub use context::ValidationContext;
+pub use custom_constraint::CustomValidator;
 pub use default_applier::{DefaultApplier, apply_defaults_to_instance};
-pub use engine::{ValidationEngine, ValidationOptions};
-pub use instance_loader::{InstanceConfig, InstanceData, InstanceLoader};
-pub use instance_resolver::InstanceResolver;
+pub use engine::{UnknownFieldPolicy, ValidationEngine, ValidationOptions};
+pub use enum_resolver::EnumResolver;
+pub use instance_loader::{
+    InstanceConfig, InstanceData, InstanceFilter, InstanceHeader, InstanceLintIssue,
+    InstanceLintReport, InstanceLoader,
+};
+pub use instance_resolver::{ACCEPT_UTC_OFFSETS, InstanceMatchOptions, InstanceResolver};
+pub use pattern_cache::PatternCache;
 pub use pattern_validator::{PatternTransformer, PatternValidator, validate_patterns};
 pub use recursion_checker::{RecursionTracker, check_recursion};
+pub use references::{ReferenceError, ReferenceIssue, validate_schema_references};
 pub use report::{Severity, ValidationIssue, ValidationReport};
+pub use result_cache::{ResultCacheKey, ValidationResultCache};
+pub use source_map::SourceMap;
 pub use unique_key_validator::{UniqueKeyIndex, UniqueKeyValidator, UniqueKeyViolation};
-pub use validators::Validator;
+pub use validators::{Validator, resolve_structured_pattern};
 
 use serde_json::Value;
 
@@ -0,0 +1,136 @@
+//! Shared cache of compiled regular expressions
+//!
+//! A [`ValidationEngine`](super::ValidationEngine) owns one `PatternCache` and
+//! hands it to every [`ValidationContext`](super::ValidationContext) it creates,
+//! so a slot pattern is compiled once per engine rather than once per
+//! validated instance.
+
+use parking_lot::RwLock;
+use regex::Regex;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Lazily populated cache of compiled patterns keyed on the pattern string
+#[derive(Debug, Default)]
+pub struct PatternCache {
+    patterns: RwLock<HashMap<String, Arc<Regex>>>,
+    compilations: AtomicUsize,
+}
+
+impl PatternCache {
+    /// Create an empty pattern cache
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the compiled regex for a pattern, compiling it on first use
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the pattern is not a valid regular expression.
+    /// Invalid patterns are not cached.
+    pub fn get_or_compile(&self, pattern: &str) -> Result<Arc<Regex>, regex::Error> {
+        if let Some(regex) = self.patterns.read().get(pattern) {
+            return Ok(Arc::clone(regex));
+        }
+
+        let mut patterns = self.patterns.write();
+        // Another thread may have compiled it while we waited for the lock
+        if let Some(regex) = patterns.get(pattern) {
+            return Ok(Arc::clone(regex));
+        }
+
+        let regex = Arc::new(Regex::new(pattern)?);
+        self.compilations.fetch_add(1, Ordering::Relaxed);
+        patterns.insert(pattern.to_string(), Arc::clone(&regex));
+        Ok(regex)
+    }
+
+    /// Number of distinct patterns currently cached
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.patterns.read().len()
+    }
+
+    /// Whether the cache holds no patterns
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.patterns.read().is_empty()
+    }
+
+    /// Total number of regex compilations performed by this cache
+    #[must_use]
+    pub fn compilations(&self) -> usize {
+        self.compilations.load(Ordering::Relaxed)
+    }
+
+    /// Remove all cached patterns
+    pub fn clear(&self) {
+        self.patterns.write().clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validator::ValidationEngine;
+    use linkml_core::types::{ClassDefinition, SchemaDefinition, SlotDefinition};
+    use serde_json::json;
+
+    #[test]
+    fn test_invalid_pattern_is_not_cached() {
+        let cache = PatternCache::new();
+        assert!(cache.get_or_compile("[unclosed").is_err());
+        assert!(cache.is_empty());
+        assert_eq!(cache.compilations(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_engine_reuses_compiled_patterns() -> anyhow::Result<()> {
+        let mut schema = SchemaDefinition::new("countries");
+        schema.slots.insert(
+            "code".to_string(),
+            SlotDefinition {
+                range: Some("string".to_string()),
+                pattern: Some("^[A-Z]{2}$".to_string()),
+                ..SlotDefinition::new("code")
+            },
+        );
+        schema.classes.insert(
+            "Country".to_string(),
+            ClassDefinition {
+                slots: vec!["code".to_string()],
+                ..ClassDefinition::new("Country")
+            },
+        );
+
+        let codes: Vec<String> = (0..10_000u32)
+            .map(|i| {
+                let first = char::from(b'A' + u8::try_from(i % 26).unwrap_or(0));
+                let second = char::from(b'A' + u8::try_from((i / 26) % 26).unwrap_or(0));
+                format!("{first}{second}")
+            })
+            .collect();
+
+        let engine = ValidationEngine::new(&schema)?;
+        for code in &codes {
+            let report = engine
+                .validate_as_class(&json!({ "code": code }), "Country", None)
+                .await?;
+            assert!(report.valid, "{code} should be valid");
+        }
+        assert_eq!(engine.pattern_cache().len(), 1);
+        let compilations = engine.pattern_cache().compilations();
+        assert_eq!(compilations, 1);
+
+        for code in &codes {
+            engine
+                .validate_as_class(&json!({ "code": code }), "Country", None)
+                .await?;
+        }
+        assert_eq!(engine.pattern_cache().compilations(), compilations);
+        Ok(())
+    }
+}
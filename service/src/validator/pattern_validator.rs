@@ -6,6 +6,7 @@
 //! - Named capture groups with extraction
 //! - Pattern inheritance and overrides
 
+use super::json_path::json_pointer;
 use super::report::ValidationIssue;
 use linkml_core::prelude::*;
 use regex::Regex;
@@ -314,7 +315,7 @@ impl PatternValidator {
                             if let Ok(index) = index_str.parse::<usize>() {
                                 issues.push(ValidationIssue::error(
                                     error_msg,
-                                    format!("/{slot_name}/[{index}]"),
+                                    format!("$.{slot_name}[{index}]"),
                                     format!("pattern:{slot_name}[{index}]"),
                                 ));
                                 continue;
@@ -324,7 +325,7 @@ impl PatternValidator {
                     // Default error formatting
                     issues.push(ValidationIssue::error(
                         error_msg,
-                        format!("/{slot_name}"),
+                        format!("$.{slot_name}"),
                         format!("pattern:{slot_name}"),
                     ));
                 }
@@ -354,7 +355,8 @@ pub fn validate_patterns(
                 let mut issues = validator.validate_instance(item, class_name, schema)?;
                 // Add index to path
                 for issue in &mut issues {
-                    issue.path = format!("[{}]{}", i, issue.path);
+                    issue.path = format!("$[{i}]{}", issue.path.trim_start_matches('$'));
+                    issue.pointer = json_pointer(&issue.path);
                 }
                 all_issues.extend(issues);
             }
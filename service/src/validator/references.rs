@@ -0,0 +1,385 @@
+//! Schema reference checking
+//!
+//! Verifies that every name a schema element points at (slot ranges,
+//! `is_a`, mixins, domains, inverses and class slot lists) resolves to an
+//! element defined in the schema, and that inverse slot pairs agree with each
+//! other. Run it on a schema whose imports have already been merged,
+//! otherwise references into imported schemas are reported as undefined.
+
+use linkml_core::types::{SchemaDefinition, SlotDefinition};
+use linkml_core::utils_v2::is_builtin_type;
+use std::fmt;
+
+/// What is wrong with a reference
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReferenceIssue {
+    /// The referenced name is not defined
+    Undefined,
+    /// The inverse slot does not declare the referencing slot as its inverse
+    InverseNotReciprocal {
+        /// The inverse the target slot declares instead, if any
+        declared: Option<String>,
+    },
+    /// A domain or range does not match the range or domain of the inverse
+    InverseMismatch {
+        /// `domain` or `range` of the referencing slot
+        field: String,
+        /// Value of that field on the referencing slot
+        value: String,
+        /// Value of the opposite field on the inverse slot
+        inverse_value: String,
+    },
+}
+
+/// A problem with a reference from a schema element to another element
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReferenceError {
+    /// Kind of the element holding the reference (`class`, `slot`,
+    /// `attribute` or `slot_usage`)
+    pub element_type: String,
+    /// Name of the element holding the reference
+    pub element_name: String,
+    /// Field holding the reference, e.g. `range` or `is_a`
+    pub field: String,
+    /// The referenced name
+    pub target: String,
+    /// What is wrong with the reference
+    pub issue: ReferenceIssue,
+}
+
+impl ReferenceError {
+    fn new(element_type: &str, element_name: &str, field: &str, target: &str) -> Self {
+        Self {
+            element_type: element_type.to_string(),
+            element_name: element_name.to_string(),
+            field: field.to_string(),
+            target: target.to_string(),
+            issue: ReferenceIssue::Undefined,
+        }
+    }
+
+    fn inverse(slot_name: &str, inverse_name: &str, issue: ReferenceIssue) -> Self {
+        Self {
+            issue,
+            ..Self::new("slot", slot_name, "inverse", inverse_name)
+        }
+    }
+}
+
+impl fmt::Display for ReferenceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            element_type,
+            element_name,
+            field,
+            target,
+            issue,
+        } = self;
+        match issue {
+            ReferenceIssue::Undefined => write!(
+                f,
+                "{element_type} '{element_name}' {field} references undefined element '{target}'"
+            ),
+            ReferenceIssue::InverseNotReciprocal { declared: None } => write!(
+                f,
+                "{element_type} '{element_name}' has inverse '{target}', \
+                 which declares no inverse"
+            ),
+            ReferenceIssue::InverseNotReciprocal {
+                declared: Some(declared),
+            } => write!(
+                f,
+                "{element_type} '{element_name}' has inverse '{target}', \
+                 which declares inverse '{declared}'"
+            ),
+            ReferenceIssue::InverseMismatch {
+                field,
+                value,
+                inverse_value,
+            } => {
+                let opposite = if field == "domain" { "range" } else { "domain" };
+                write!(
+                    f,
+                    "{element_type} '{element_name}' {field} '{value}' does not match \
+                     {opposite} '{inverse_value}' of its inverse '{target}'"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ReferenceError {}
+
+/// Check that all element references in a schema resolve
+///
+/// Slot ranges must name a built-in type or a class, type or enum; slot
+/// `is_a`/mixins must name slots; slot domains and class `is_a`/mixins must
+/// name classes; and class `slots` entries must name slots. Ranges of class
+/// attributes and `slot_usage` refinements are checked as well.
+///
+/// When slot `A` declares `inverse: B`, slot `B` must exist and declare
+/// `inverse: A`, and the domain of each slot must match the range of the
+/// other wherever both are set.
+#[must_use]
+pub fn validate_schema_references(schema: &SchemaDefinition) -> Vec<ReferenceError> {
+    let mut errors = Vec::new();
+
+    for (slot_name, slot) in &schema.slots {
+        check_slot(schema, "slot", slot_name, slot, &mut errors);
+        check_inverse(schema, slot_name, slot, &mut errors);
+    }
+
+    for (class_name, class) in &schema.classes {
+        if let Some(parent) = &class.is_a
+            && !schema.classes.contains_key(parent)
+        {
+            errors.push(ReferenceError::new("class", class_name, "is_a", parent));
+        }
+        for mixin in &class.mixins {
+            if !schema.classes.contains_key(mixin) {
+                errors.push(ReferenceError::new("class", class_name, "mixins", mixin));
+            }
+        }
+        for slot_name in &class.slots {
+            if !schema.slots.contains_key(slot_name) {
+                errors.push(ReferenceError::new("class", class_name, "slots", slot_name));
+            }
+        }
+        for (attr_name, attr) in &class.attributes {
+            let name = format!("{class_name}.{attr_name}");
+            check_slot(schema, "attribute", &name, attr, &mut errors);
+        }
+        for (slot_name, usage) in &class.slot_usage {
+            let name = format!("{class_name}.{slot_name}");
+            check_slot(schema, "slot_usage", &name, usage, &mut errors);
+        }
+    }
+
+    errors
+}
+
+fn check_slot(
+    schema: &SchemaDefinition,
+    element_type: &str,
+    name: &str,
+    slot: &SlotDefinition,
+    errors: &mut Vec<ReferenceError>,
+) {
+    if let Some(range) = &slot.range
+        && !is_builtin_type(range)
+        && !schema.classes.contains_key(range)
+        && !schema.types.contains_key(range)
+        && !schema.enums.contains_key(range)
+    {
+        errors.push(ReferenceError::new(element_type, name, "range", range));
+    }
+    if let Some(parent) = &slot.is_a
+        && !schema.slots.contains_key(parent)
+    {
+        errors.push(ReferenceError::new(element_type, name, "is_a", parent));
+    }
+    for mixin in &slot.mixins {
+        if !schema.slots.contains_key(mixin) {
+            errors.push(ReferenceError::new(element_type, name, "mixins", mixin));
+        }
+    }
+    if let Some(domain) = &slot.domain
+        && !schema.classes.contains_key(domain)
+    {
+        errors.push(ReferenceError::new(element_type, name, "domain", domain));
+    }
+}
+
+fn check_inverse(
+    schema: &SchemaDefinition,
+    slot_name: &str,
+    slot: &SlotDefinition,
+    errors: &mut Vec<ReferenceError>,
+) {
+    let Some(inverse_name) = &slot.inverse else {
+        return;
+    };
+    let Some(inverse) = schema.slots.get(inverse_name) else {
+        errors.push(ReferenceError::new(
+            "slot",
+            slot_name,
+            "inverse",
+            inverse_name,
+        ));
+        return;
+    };
+    if inverse.inverse.as_deref() != Some(slot_name) {
+        errors.push(ReferenceError::inverse(
+            slot_name,
+            inverse_name,
+            ReferenceIssue::InverseNotReciprocal {
+                declared: inverse.inverse.clone(),
+            },
+        ));
+        return;
+    }
+
+    // A reciprocal pair is compared once, from the slot whose name sorts first
+    if slot_name > inverse_name.as_str() {
+        return;
+    }
+    let pairs = [
+        ("domain", &slot.domain, &inverse.range),
+        ("range", &slot.range, &inverse.domain),
+    ];
+    for (field, value, inverse_value) in pairs {
+        if let (Some(value), Some(inverse_value)) = (value, inverse_value)
+            && value != inverse_value
+        {
+            errors.push(ReferenceError::inverse(
+                slot_name,
+                inverse_name,
+                ReferenceIssue::InverseMismatch {
+                    field: field.to_string(),
+                    value: value.clone(),
+                    inverse_value: inverse_value.clone(),
+                },
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use linkml_core::types::{ClassDefinition, EnumDefinition};
+
+    #[test]
+    fn test_valid_schema_has_no_reference_errors() {
+        let mut schema = SchemaDefinition::new("test");
+        schema.enums.insert(
+            "Status".to_string(),
+            EnumDefinition {
+                name: "Status".to_string(),
+                ..Default::default()
+            },
+        );
+        schema.slots.insert(
+            "status".to_string(),
+            SlotDefinition {
+                range: Some("Status".to_string()),
+                domain: Some("Entity".to_string()),
+                ..SlotDefinition::new("status")
+            },
+        );
+        schema.slots.insert(
+            "id".to_string(),
+            SlotDefinition {
+                range: Some("uriorcurie".to_string()),
+                ..SlotDefinition::new("id")
+            },
+        );
+        schema.classes.insert(
+            "Entity".to_string(),
+            ClassDefinition {
+                slots: vec!["id".to_string(), "status".to_string()],
+                ..ClassDefinition::new("Entity")
+            },
+        );
+
+        assert!(validate_schema_references(&schema).is_empty());
+    }
+
+    #[test]
+    fn test_undefined_references_are_reported() {
+        let mut schema = SchemaDefinition::new("test");
+        schema.slots.insert(
+            "country".to_string(),
+            SlotDefinition {
+                range: Some("Country".to_string()),
+                ..SlotDefinition::new("country")
+            },
+        );
+        let mut entity = ClassDefinition {
+            is_a: Some("Thing".to_string()),
+            mixins: vec!["Named".to_string()],
+            slots: vec!["country".to_string(), "label".to_string()],
+            ..ClassDefinition::new("Entity")
+        };
+        entity.attributes.insert(
+            "code".to_string(),
+            SlotDefinition {
+                range: Some("CodeType".to_string()),
+                ..Default::default()
+            },
+        );
+        schema.classes.insert("Entity".to_string(), entity);
+
+        let errors = validate_schema_references(&schema);
+        let rendered: Vec<String> = errors.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            rendered,
+            [
+                "slot 'country' range references undefined element 'Country'",
+                "class 'Entity' is_a references undefined element 'Thing'",
+                "class 'Entity' mixins references undefined element 'Named'",
+                "class 'Entity' slots references undefined element 'label'",
+                "attribute 'Entity.code' range references undefined element 'CodeType'",
+            ]
+        );
+    }
+
+    fn inverse_slot(name: &str, inverse: &str, domain: &str, range: &str) -> SlotDefinition {
+        SlotDefinition {
+            inverse: Some(inverse.to_string()),
+            domain: Some(domain.to_string()),
+            range: Some(range.to_string()),
+            ..SlotDefinition::new(name)
+        }
+    }
+
+    fn inverse_schema(slots: [SlotDefinition; 2]) -> SchemaDefinition {
+        let mut schema = SchemaDefinition::new("test");
+        for class in ["Person", "Organization"] {
+            schema
+                .classes
+                .insert(class.to_string(), ClassDefinition::new(class));
+        }
+        for slot in slots {
+            schema.slots.insert(slot.name.clone(), slot);
+        }
+        schema
+    }
+
+    #[test]
+    fn test_inverse_pairs_are_checked() {
+        let schema = inverse_schema([
+            inverse_slot("employs", "employed_by", "Organization", "Person"),
+            inverse_slot("employed_by", "employs", "Person", "Organization"),
+        ]);
+        assert!(validate_schema_references(&schema).is_empty());
+
+        let schema = inverse_schema([
+            inverse_slot("employs", "employed_by", "Organization", "Person"),
+            inverse_slot("employed_by", "works_for", "Organization", "Organization"),
+        ]);
+        let rendered: Vec<String> = validate_schema_references(&schema)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            rendered,
+            [
+                "slot 'employs' has inverse 'employed_by', which declares inverse 'works_for'",
+                "slot 'employed_by' inverse references undefined element 'works_for'",
+            ]
+        );
+
+        let schema = inverse_schema([
+            inverse_slot("employs", "employed_by", "Organization", "Person"),
+            inverse_slot("employed_by", "employs", "Organization", "Organization"),
+        ]);
+        let errors = validate_schema_references(&schema);
+        assert_eq!(errors.len(), 1, "{errors:?}");
+        assert_eq!(
+            errors[0].to_string(),
+            "slot 'employed_by' domain 'Organization' does not match \
+             range 'Person' of its inverse 'employs'"
+        );
+    }
+}
@@ -1,5 +1,9 @@
 //! Validation report structures
 
+use super::json_path::json_pointer;
+use super::source_map::SourceMap;
+use indexmap::IndexMap;
+use linkml_core::ast::Span;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt;
@@ -34,12 +38,18 @@ pub struct ValidationIssue {
     pub message: String,
     /// `JSON` path to the problematic value
     pub path: String,
+    /// RFC 6901 `JSON` Pointer to the problematic value, e.g. `/instances/5/identifier`
+    #[serde(default)]
+    pub pointer: String,
     /// Name of the validator that detected this issue
     pub validator: String,
     /// Optional error code for programmatic handling
     pub code: Option<String>,
     /// Additional context information
     pub context: HashMap<String, serde_json::Value>,
+    /// Location of the value in the source document, when known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<Span>,
 }
 
 impl ValidationIssue {
@@ -50,13 +60,16 @@ impl ValidationIssue {
         path: impl Into<String>,
         validator: impl Into<String>,
     ) -> Self {
+        let path = path.into();
         Self {
             severity,
             message: message.into(),
-            path: path.into(),
+            pointer: json_pointer(&path),
+            path,
             validator: validator.into(),
             code: None,
             context: HashMap::new(),
+            location: None,
         }
     }
 
@@ -100,11 +113,22 @@ impl ValidationIssue {
         self.context.insert(key.into(), value);
         self
     }
+
+    /// Set the source location
+    #[must_use]
+    pub fn with_location(mut self, location: Span) -> Self {
+        self.location = Some(location);
+        self
+    }
 }
 
 impl fmt::Display for ValidationIssue {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "[{}] {}: {}", self.severity, self.path, self.message)
+        write!(f, "[{}] {}: {}", self.severity, self.path, self.message)?;
+        if let Some(location) = &self.location {
+            write!(f, " ({location})")?;
+        }
+        Ok(())
     }
 }
 
@@ -140,6 +164,17 @@ pub struct ValidationReport {
     pub schema_id: String,
     /// Optional target class if specified
     pub target_class: Option<String>,
+    /// Values of undeclared instance fields by path, kept when unknown
+    /// fields are collected
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub additional_properties: IndexMap<String, serde_json::Value>,
+    /// Values coerced to their slot's range by path, when types are coerced
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub coerced_values: IndexMap<String, serde_json::Value>,
+    /// The validated data with the coerced values in place, when types are
+    /// coerced
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub coerced_instance: Option<serde_json::Value>,
 }
 
 impl ValidationReport {
@@ -151,9 +186,24 @@ impl ValidationReport {
             stats: ValidationStats::default(),
             schema_id: schema_id.into(),
             target_class: None,
+            additional_properties: IndexMap::new(),
+            coerced_values: IndexMap::new(),
+            coerced_instance: None,
         }
     }
 
+    /// Copy of `data` with the values of `coerced_values` put in place
+    #[must_use]
+    pub fn with_coerced_values(&self, data: &serde_json::Value) -> serde_json::Value {
+        let mut data = data.clone();
+        for (path, value) in &self.coerced_values {
+            if let Some(target) = data.pointer_mut(&json_pointer(path)) {
+                target.clone_from(value);
+            }
+        }
+        data
+    }
+
     /// Add an issue to the report
     pub fn add_issue(&mut self, issue: ValidationIssue) {
         match issue.severity {
@@ -195,6 +245,15 @@ impl ValidationReport {
         }
     }
 
+    /// Fill in the source location of issues that lack one
+    pub fn locate_issues(&mut self, source_map: &SourceMap) {
+        for issue in &mut self.issues {
+            if issue.location.is_none() {
+                issue.location = source_map.span(&issue.path);
+            }
+        }
+    }
+
     /// Sort issues by severity and path
     pub fn sort_issues(&mut self) {
         self.issues.sort_by(|a, b| {
@@ -0,0 +1,289 @@
+//! Cache of validation reports for repeated identical requests
+//!
+//! A service that validates the same payloads again and again, such as the
+//! country codes of the examples, can attach a `ValidationResultCache` to its
+//! [`ValidationEngine`](super::ValidationEngine) with
+//! [`attach_result_cache`](super::ValidationEngine::attach_result_cache).
+//! Reports are keyed on a digest, computed by the RootReal hash service, of
+//! the schema fingerprint, the target class, the options that affect the
+//! report and the canonicalized data, so the order of keys in JSON objects
+//! does not matter and a reloaded schema never sees the reports of the
+//! previous one.
+
+use super::report::ValidationReport;
+use hash_core::{HashError, HashService};
+use linkml_core::error::{LinkMLError, Result};
+use linkml_core::types::SchemaDefinition;
+use lru::LruCache;
+use parking_lot::Mutex;
+use serde::{Serialize, Serializer};
+use serde_json::Value;
+use std::fmt;
+use std::num::NonZeroUsize;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Key of a cached validation report
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ResultCacheKey(String);
+
+/// JSON value serialized with the entries of every object in key order
+struct Canonical<'a>(&'a Value);
+
+impl Serialize for Canonical<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        match self.0 {
+            Value::Array(items) => serializer.collect_seq(items.iter().map(Canonical)),
+            Value::Object(map) => {
+                let mut entries: Vec<_> = map.iter().collect();
+                entries.sort_unstable_by_key(|(key, _)| *key);
+                serializer.collect_map(
+                    entries
+                        .into_iter()
+                        .map(|(key, item)| (key, Canonical(item))),
+                )
+            }
+            scalar => scalar.serialize(serializer),
+        }
+    }
+}
+
+/// Least recently used cache of validation reports
+///
+/// The cache can be shared by several engines; the schema fingerprint in
+/// each key keeps their reports apart.
+pub struct ValidationResultCache {
+    reports: Mutex<LruCache<ResultCacheKey, ValidationReport>>,
+    hash_service: Arc<dyn HashService<Error = HashError>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl fmt::Debug for ValidationResultCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ValidationResultCache")
+            .field("len", &self.len())
+            .field("hits", &self.hits())
+            .field("misses", &self.misses())
+            .finish_non_exhaustive()
+    }
+}
+
+impl ValidationResultCache {
+    /// Create an empty cache holding at most `capacity` reports, whose keys
+    /// are digests computed by `hash_service`
+    #[must_use]
+    pub fn new(
+        capacity: NonZeroUsize,
+        hash_service: Arc<dyn HashService<Error = HashError>>,
+    ) -> Self {
+        Self {
+            reports: Mutex::new(LruCache::new(capacity)),
+            hash_service,
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// Fingerprint of a schema, changing whenever any part of it changes
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the schema cannot be serialized or hashed
+    pub async fn schema_fingerprint(&self, schema: &SchemaDefinition) -> Result<String> {
+        let bytes = serde_json::to_vec(schema)
+            .map_err(|e| LinkMLError::service(format!("Failed to fingerprint schema: {e}")))?;
+        self.digest(&bytes).await
+    }
+
+    /// Key for validating `data` as `class_name` with `options`
+    ///
+    /// Objects are serialized with their keys in sorted order, so data that
+    /// differs only in key order gets the same key.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the options cannot be serialized or the request
+    /// cannot be hashed
+    pub async fn key(
+        &self,
+        schema_fingerprint: &str,
+        class_name: &str,
+        options: &impl Serialize,
+        data: &Value,
+    ) -> Result<ResultCacheKey> {
+        let bytes = serde_json::to_vec(&(schema_fingerprint, class_name, options, Canonical(data)))
+            .map_err(|e| {
+                LinkMLError::service(format!("Failed to serialize validation request: {e}"))
+            })?;
+        self.digest(&bytes).await.map(ResultCacheKey)
+    }
+
+    /// Digest of `bytes` from the hash service
+    async fn digest(&self, bytes: &[u8]) -> Result<String> {
+        self.hash_service
+            .hash(bytes)
+            .await
+            .map_err(|e| LinkMLError::service(format!("Failed to hash cache key: {e}")))
+    }
+
+    /// Get the cached report for `key`, counting a hit or a miss
+    #[must_use]
+    pub fn get(&self, key: &ResultCacheKey) -> Option<ValidationReport> {
+        let report = self.reports.lock().get(key).cloned();
+        let counter = if report.is_some() {
+            &self.hits
+        } else {
+            &self.misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        report
+    }
+
+    /// Cache `report` under `key`, evicting the least recently used report
+    /// if the cache is full
+    pub fn insert(&self, key: ResultCacheKey, report: ValidationReport) {
+        self.reports.lock().put(key, report);
+    }
+
+    /// Number of lookups served from the cache
+    #[must_use]
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    /// Number of lookups that found no cached report
+    #[must_use]
+    pub fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+
+    /// Number of cached reports
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.reports.lock().len()
+    }
+
+    /// Whether the cache holds no reports
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.reports.lock().is_empty()
+    }
+
+    /// Remove all cached reports
+    pub fn clear(&self) {
+        self.reports.lock().clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validator::{ValidationEngine, ValidationOptions};
+    use hash_service::wiring::wire_hash;
+    use linkml_core::types::{ClassDefinition, SlotDefinition};
+    use serde_json::json;
+
+    fn country_schema() -> SchemaDefinition {
+        let mut schema = SchemaDefinition::new("countries");
+        schema.slots.insert(
+            "code".to_string(),
+            SlotDefinition {
+                range: Some("string".to_string()),
+                pattern: Some("^[A-Z]{2}$".to_string()),
+                ..SlotDefinition::new("code")
+            },
+        );
+        schema.classes.insert(
+            "Country".to_string(),
+            ClassDefinition {
+                slots: vec!["code".to_string()],
+                ..ClassDefinition::new("Country")
+            },
+        );
+        schema
+    }
+
+    fn new_cache(capacity: usize) -> anyhow::Result<ValidationResultCache> {
+        let capacity =
+            NonZeroUsize::new(capacity).ok_or_else(|| anyhow::anyhow!("zero capacity"))?;
+        Ok(ValidationResultCache::new(capacity, wire_hash().into_arc()))
+    }
+
+    #[tokio::test]
+    async fn test_key_ignores_object_key_order() -> anyhow::Result<()> {
+        let cache = new_cache(4)?;
+        let options = (Some(true), None::<usize>);
+        let netherlands = json!({"code": "NL", "name": "Netherlands"});
+
+        let first = cache.key("7", "Country", &options, &netherlands).await?;
+        let second = cache
+            .key(
+                "7",
+                "Country",
+                &options,
+                &json!({"name": "Netherlands", "code": "NL"}),
+            )
+            .await?;
+        assert_eq!(first, second);
+        let other_data = cache
+            .key(
+                "7",
+                "Country",
+                &options,
+                &json!({"code": "DE", "name": "Netherlands"}),
+            )
+            .await?;
+        assert_ne!(first, other_data);
+        let other_schema = cache.key("8", "Country", &options, &netherlands).await?;
+        assert_ne!(first, other_schema);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_repeated_validation_is_served_from_cache() -> anyhow::Result<()> {
+        let cache = Arc::new(new_cache(16)?);
+        let engine = ValidationEngine::new(&country_schema())?;
+        engine.attach_result_cache(cache.clone())?;
+
+        let first = engine
+            .validate_as_class(&json!({"code": "nl"}), "Country", None)
+            .await?;
+        assert_eq!((cache.hits(), cache.misses()), (0, 1));
+        let second = engine
+            .validate_as_class(&json!({"code": "nl"}), "Country", None)
+            .await?;
+        assert_eq!((cache.hits(), cache.misses()), (1, 1));
+        assert!(!second.valid);
+        assert_eq!(second.issues.len(), first.issues.len());
+
+        // Other data, and validations opting out, are not served from cache
+        engine
+            .validate_as_class(&json!({"code": "NL"}), "Country", None)
+            .await?;
+        let uncached = ValidationOptions {
+            use_cache: Some(false),
+            ..ValidationOptions::default()
+        };
+        engine
+            .validate_as_class(&json!({"code": "nl"}), "Country", Some(uncached))
+            .await?;
+        assert_eq!((cache.hits(), cache.misses()), (1, 2));
+        assert_eq!(cache.len(), 2);
+
+        // A different schema sharing the cache does not see these reports
+        let mut relaxed = country_schema();
+        relaxed.slots["code"].pattern = None;
+        let other = ValidationEngine::new(&relaxed)?;
+        other.attach_result_cache(cache.clone())?;
+        let report = other
+            .validate_as_class(&json!({"code": "nl"}), "Country", None)
+            .await?;
+        assert!(report.valid, "{report}");
+        assert_eq!(cache.hits(), 1);
+
+        // An engine takes one cache only
+        assert!(engine.attach_result_cache(cache.clone()).is_err());
+        Ok(())
+    }
+}
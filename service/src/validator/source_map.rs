@@ -0,0 +1,557 @@
+//! Source locations for validated instance data
+//!
+//! Instances are deserialized into a `serde_json::Value` before validation,
+//! which forgets where each value came from. A [`SourceMap`] is built from the
+//! same YAML or JSON text and maps the `JSON` path of every value to its
+//! [`Span`], so that issues can point at a line and column. Hand one to the
+//! engine through [`ValidationOptions::source_map`](super::ValidationOptions).
+
+use super::json_path::{PathSegment, path_segments};
+use linkml_core::ast::Span;
+use std::collections::HashMap;
+
+/// Spans of the values in a YAML or JSON document, keyed by `JSON` path
+#[derive(Debug, Clone, Default)]
+pub struct SourceMap {
+    spans: HashMap<Vec<PathSegment>, Span>,
+}
+
+impl SourceMap {
+    /// Index a YAML document
+    ///
+    /// Block mappings and sequences are indexed down to their scalars. Flow
+    /// collections (`[a, b]`, `{a: b}`) and multi-line scalars get a single
+    /// span covering their first line. Text that is not valid YAML yields a
+    /// partial map rather than an error; parse the document with serde to
+    /// find out whether it is well formed.
+    #[must_use]
+    pub fn from_yaml(text: &str) -> Self {
+        let mut scanner = YamlScanner {
+            lines: LineIndex::new(text),
+            map: Self::default(),
+            frames: Vec::new(),
+            pending: None,
+            continuation: None,
+        };
+
+        let mut offset = 0;
+        for line in text.split_inclusive('\n') {
+            scanner.line(line, offset);
+            offset += line.len();
+        }
+        scanner.map
+    }
+
+    /// Index a JSON document
+    ///
+    /// Scanning stops at the first syntax error, keeping the spans found up
+    /// to that point.
+    #[must_use]
+    pub fn from_json(text: &str) -> Self {
+        let mut scanner = JsonScanner {
+            text,
+            pos: 0,
+            lines: LineIndex::new(text),
+            map: Self::default(),
+        };
+        let _ = scanner.value(&mut Vec::new());
+        scanner.map
+    }
+
+    /// Span of the value at a `JSON` path such as `$.countries[3].code`
+    ///
+    /// Paths that do not resolve, e.g. a required slot that is missing, fall
+    /// back to the closest enclosing value that does.
+    #[must_use]
+    pub fn span(&self, path: &str) -> Option<Span> {
+        let mut segments = path_segments(path);
+        loop {
+            if let Some(span) = self.spans.get(&segments) {
+                return Some(*span);
+            }
+            segments.pop()?;
+        }
+    }
+
+    /// Number of indexed values
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.spans.len()
+    }
+
+    /// Whether no values were indexed
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    fn insert(&mut self, path: Vec<PathSegment>, span: Span) {
+        self.spans.entry(path).or_insert(span);
+    }
+}
+
+/// Byte offsets of line starts, for turning offsets into lines and columns
+struct LineIndex<'a> {
+    text: &'a str,
+    starts: Vec<usize>,
+}
+
+impl<'a> LineIndex<'a> {
+    fn new(text: &'a str) -> Self {
+        let starts = std::iter::once(0)
+            .chain(text.match_indices('\n').map(|(i, _)| i + 1))
+            .collect();
+        Self { text, starts }
+    }
+
+    fn span(&self, start: usize, end: usize) -> Span {
+        let line = self.starts.partition_point(|&s| s <= start);
+        let line_start = self.starts[line - 1];
+        let column = self.text[line_start..start].chars().count() + 1;
+        Span::new(start, end, line, column)
+    }
+}
+
+/// An open block mapping or sequence
+struct YamlFrame {
+    indent: usize,
+    path: Vec<PathSegment>,
+    sequence: bool,
+    next_index: usize,
+}
+
+/// A key or item with no inline value, whose block opens on a later line
+struct PendingBlock {
+    indent: usize,
+    path: Vec<PathSegment>,
+    /// Mapping values may be sequences indented as far as their key
+    sequence_at_indent: bool,
+}
+
+struct YamlScanner<'a> {
+    lines: LineIndex<'a>,
+    map: SourceMap,
+    frames: Vec<YamlFrame>,
+    pending: Option<PendingBlock>,
+    /// Lines indented past this column continue the previous scalar
+    continuation: Option<usize>,
+}
+
+impl YamlScanner<'_> {
+    fn line(&mut self, line: &str, offset: usize) {
+        let line = line.trim_end_matches(['\n', '\r']);
+        let indent = line.len() - line.trim_start_matches(' ').len();
+        let content = strip_comment(&line[indent..]).trim_end();
+        if content.is_empty() {
+            return;
+        }
+        if let Some(limit) = self.continuation {
+            if indent > limit {
+                return;
+            }
+            self.continuation = None;
+        }
+        if content == "---" || content == "..." || content.starts_with("--- ") {
+            self.frames.clear();
+            self.pending = None;
+            return;
+        }
+
+        let item = is_sequence_item(content);
+        if let Some(pending) = self.pending.take()
+            && (indent > pending.indent
+                || (indent == pending.indent && item && pending.sequence_at_indent))
+        {
+            self.frames.push(YamlFrame {
+                indent,
+                path: pending.path,
+                sequence: item,
+                next_index: 0,
+            });
+        }
+        while self.frames.last().is_some_and(|top| {
+            top.indent > indent || (top.indent == indent && top.sequence && !item)
+        }) {
+            self.frames.pop();
+        }
+        if self.frames.is_empty() {
+            let start = offset + indent;
+            self.map
+                .insert(Vec::new(), self.lines.span(start, start + content.len()));
+            self.frames.push(YamlFrame {
+                indent,
+                path: Vec::new(),
+                sequence: item,
+                next_index: 0,
+            });
+        }
+        if self.frames.last().is_some_and(|top| top.indent == indent) {
+            self.entry(content, offset + indent, indent);
+        }
+    }
+
+    /// Index one mapping entry or sequence item starting at byte `start`,
+    /// which sits at column `indent` of its line
+    fn entry(&mut self, content: &str, start: usize, indent: usize) {
+        let Some(top) = self.frames.last_mut() else {
+            return;
+        };
+
+        if top.sequence {
+            if !is_sequence_item(content) {
+                return;
+            }
+            let mut path = top.path.clone();
+            path.push(PathSegment::Index(top.next_index));
+            top.next_index += 1;
+
+            let rest = content[1..].trim_start();
+            let rest_offset = content.len() - rest.len();
+            if rest.is_empty() {
+                self.map
+                    .insert(path.clone(), self.lines.span(start, start + 1));
+                self.pending = Some(PendingBlock {
+                    indent,
+                    path,
+                    sequence_at_indent: false,
+                });
+            } else if split_key(rest).is_some() || is_sequence_item(rest) {
+                // `- key: value` and `- - value` open a block on the item's line
+                self.map
+                    .insert(path.clone(), self.lines.span(start, start + content.len()));
+                self.frames.push(YamlFrame {
+                    indent: indent + rest_offset,
+                    path,
+                    sequence: is_sequence_item(rest),
+                    next_index: 0,
+                });
+                self.entry(rest, start + rest_offset, indent + rest_offset);
+            } else {
+                let value_start = start + rest_offset;
+                self.map
+                    .insert(path, self.lines.span(value_start, value_start + rest.len()));
+                self.continuation = Some(indent);
+            }
+            return;
+        }
+
+        let Some((key, value_offset)) = split_key(content) else {
+            return;
+        };
+        let mut path = top.path.clone();
+        path.push(PathSegment::Property(key));
+
+        let value = skip_node_properties(content[value_offset..].trim_start());
+        if value.is_empty() {
+            self.map
+                .insert(path.clone(), self.lines.span(start, start + value_offset));
+            self.pending = Some(PendingBlock {
+                indent,
+                path,
+                sequence_at_indent: true,
+            });
+        } else {
+            let value_start = start + content.len() - value.len();
+            let value_end = if value.starts_with(['|', '>']) {
+                value_start + 1
+            } else {
+                value_start + value.len()
+            };
+            self.map
+                .insert(path, self.lines.span(value_start, value_end));
+            self.continuation = Some(indent);
+        }
+    }
+}
+
+fn is_sequence_item(content: &str) -> bool {
+    content == "-" || content.starts_with("- ")
+}
+
+/// Drop a trailing `# comment`, ignoring `#` inside quotes or words
+fn strip_comment(content: &str) -> &str {
+    let mut quote = None;
+    let mut previous = ' ';
+    for (i, ch) in content.char_indices() {
+        match (quote, ch) {
+            (None, '#') if previous.is_whitespace() => return &content[..i],
+            (None, '"' | '\'') if previous.is_whitespace() || previous == ':' => {
+                quote = Some(ch);
+            }
+            (Some(open), _) if ch == open => quote = None,
+            _ => {}
+        }
+        previous = ch;
+    }
+    content
+}
+
+/// Skip anchors (`&name`) and tags (`!tag`) in front of a value
+fn skip_node_properties(mut value: &str) -> &str {
+    while value.starts_with(['&', '!']) {
+        let end = value.find(' ').unwrap_or(value.len());
+        value = value[end..].trim_start();
+    }
+    value
+}
+
+/// Split `key: value` into the key and the byte offset just past the colon
+fn split_key(content: &str) -> Option<(String, usize)> {
+    let (key, rest_offset) = match content.chars().next()? {
+        quote @ ('"' | '\'') => {
+            let close = content[1..].find(quote)? + 1;
+            let raw = &content[..=close];
+            let key = if quote == '"' {
+                serde_json::from_str(raw).unwrap_or_else(|_| raw[1..close].to_string())
+            } else {
+                raw[1..close].to_string()
+            };
+            let after = &content[close + 1..];
+            let colon = close + 1 + after.len() - after.trim_start().len();
+            (key, colon)
+        }
+        '[' | '{' | '-' | '?' | '#' => return None,
+        _ => {
+            let colon = content
+                .match_indices(':')
+                .map(|(i, _)| i)
+                .find(|&i| content[i + 1..].is_empty() || content[i + 1..].starts_with(' '))?;
+            (content[..colon].trim_end().to_string(), colon)
+        }
+    };
+
+    let rest = &content[rest_offset..];
+    if !rest.starts_with(':') || !(rest.len() == 1 || rest[1..].starts_with(' ')) {
+        return None;
+    }
+    Some((key, rest_offset + 1))
+}
+
+struct JsonScanner<'a> {
+    text: &'a str,
+    pos: usize,
+    lines: LineIndex<'a>,
+    map: SourceMap,
+}
+
+impl JsonScanner<'_> {
+    fn value(&mut self, path: &mut Vec<PathSegment>) -> Option<()> {
+        self.skip_whitespace();
+        let start = self.pos;
+        match self.peek()? {
+            b'{' => {
+                self.pos += 1;
+                self.skip_whitespace();
+                if self.peek()? == b'}' {
+                    self.pos += 1;
+                } else {
+                    loop {
+                        self.skip_whitespace();
+                        let key = self.string()?;
+                        self.skip_whitespace();
+                        self.expect(b':')?;
+                        path.push(PathSegment::Property(key));
+                        self.value(path)?;
+                        path.pop();
+                        if self.separator(b'}')? {
+                            break;
+                        }
+                    }
+                }
+            }
+            b'[' => {
+                self.pos += 1;
+                self.skip_whitespace();
+                if self.peek()? == b']' {
+                    self.pos += 1;
+                } else {
+                    for index in 0.. {
+                        path.push(PathSegment::Index(index));
+                        self.value(path)?;
+                        path.pop();
+                        if self.separator(b']')? {
+                            break;
+                        }
+                    }
+                }
+            }
+            b'"' => {
+                self.string()?;
+            }
+            _ => {
+                let len = self.text[self.pos..]
+                    .find([',', ']', '}', ' ', '\t', '\r', '\n'])
+                    .unwrap_or(self.text.len() - self.pos);
+                if len == 0 {
+                    return None;
+                }
+                self.pos += len;
+            }
+        }
+        self.map
+            .insert(path.clone(), self.lines.span(start, self.pos));
+        Some(())
+    }
+
+    /// Consume a `,` (returning `false`) or the closing bracket (`true`)
+    fn separator(&mut self, close: u8) -> Option<bool> {
+        self.skip_whitespace();
+        let byte = self.peek()?;
+        self.pos += 1;
+        match byte {
+            b',' => Some(false),
+            _ if byte == close => Some(true),
+            _ => None,
+        }
+    }
+
+    fn string(&mut self) -> Option<String> {
+        let start = self.pos;
+        self.expect(b'"')?;
+        let bytes = self.text.as_bytes();
+        while let Some(&byte) = bytes.get(self.pos) {
+            self.pos += if byte == b'\\' { 2 } else { 1 };
+            if byte == b'"' {
+                return serde_json::from_str(self.text.get(start..self.pos)?).ok();
+            }
+        }
+        None
+    }
+
+    fn expect(&mut self, byte: u8) -> Option<()> {
+        (self.peek()? == byte).then(|| self.pos += 1)
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.text.as_bytes().get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.text[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validator::{ValidationEngine, ValidationOptions};
+    use linkml_core::types::{ClassDefinition, SchemaDefinition, SlotDefinition};
+    use std::sync::Arc;
+
+    const COUNTRIES_YAML: &str = "\
+# ISO 3166-1 entries
+- id: NL
+  label: Netherlands
+  exact_mappings:
+    - wikidata:Q55
+- id: FRA   # should be FR
+  label: France
+  notes: |
+    Metropolitan France
+    and overseas regions
+- id: BE
+  label: Belgium
+";
+
+    fn line_column(span: Option<Span>) -> Option<(usize, usize)> {
+        span.map(|span| (span.line, span.column))
+    }
+
+    #[test]
+    fn test_yaml_spans() {
+        let map = SourceMap::from_yaml(COUNTRIES_YAML);
+        assert_eq!(line_column(map.span("$[0].id")), Some((2, 7)));
+        assert_eq!(
+            line_column(map.span("$[0].exact_mappings[0]")),
+            Some((5, 7))
+        );
+        assert_eq!(line_column(map.span("$.[1].id")), Some((6, 7)));
+        assert_eq!(line_column(map.span("$[1].notes")), Some((8, 10)));
+        assert_eq!(line_column(map.span("$[2].label")), Some((12, 10)));
+
+        let span = map.span("$[1].id").expect("FRA is indexed");
+        assert_eq!(&COUNTRIES_YAML[span.start..span.end], "FRA");
+    }
+
+    #[test]
+    fn test_yaml_nested_blocks() {
+        let text = "countries:\n- code: NL\n  names:\n    en: Netherlands\nupdated: 2024-01-01\n";
+        let map = SourceMap::from_yaml(text);
+        assert_eq!(line_column(map.span("$.countries[0].code")), Some((2, 9)));
+        assert_eq!(
+            line_column(map.span("$.countries[0].names.en")),
+            Some((4, 9))
+        );
+        assert_eq!(line_column(map.span("$.updated")), Some((5, 10)));
+    }
+
+    #[test]
+    fn test_json_spans() {
+        let text = "[\n  {\"id\": \"NL\", \"tags\": [1, 2]},\n  {\"id\": \"FRA\"}\n]";
+        let map = SourceMap::from_json(text);
+        assert_eq!(line_column(map.span("$[0].tags[1]")), Some((2, 28)));
+        assert_eq!(line_column(map.span("$[1].id")), Some((3, 10)));
+        assert_eq!(line_column(map.span("$[1]")), Some((3, 3)));
+    }
+
+    #[test]
+    fn test_missing_paths_fall_back_to_enclosing_value() {
+        let map = SourceMap::from_yaml(COUNTRIES_YAML);
+        assert_eq!(map.span("$[2].tld"), map.span("$[2]"));
+        assert_eq!(line_column(map.span("$[2]")), Some((11, 1)));
+        assert!(SourceMap::from_yaml("").span("$.id").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_engine_reports_issue_locations() -> anyhow::Result<()> {
+        let mut schema = SchemaDefinition::new("iso3166");
+        for (name, pattern) in [("id", Some("^[A-Z]{2}$")), ("label", None)] {
+            schema.slots.insert(
+                name.to_string(),
+                SlotDefinition {
+                    range: Some("string".to_string()),
+                    required: Some(true),
+                    pattern: pattern.map(str::to_string),
+                    ..SlotDefinition::new(name)
+                },
+            );
+        }
+        schema.slots.insert(
+            "exact_mappings".to_string(),
+            SlotDefinition {
+                range: Some("string".to_string()),
+                multivalued: Some(true),
+                ..SlotDefinition::new("exact_mappings")
+            },
+        );
+        schema
+            .slots
+            .insert("notes".to_string(), SlotDefinition::new("notes"));
+        schema.classes.insert(
+            "Country".to_string(),
+            ClassDefinition {
+                slots: ["id", "label", "exact_mappings", "notes"]
+                    .map(str::to_string)
+                    .to_vec(),
+                ..ClassDefinition::new("Country")
+            },
+        );
+
+        let instances: Vec<serde_json::Value> = serde_yaml::from_str(COUNTRIES_YAML)?;
+        let options = ValidationOptions {
+            source_map: Some(Arc::new(SourceMap::from_yaml(COUNTRIES_YAML))),
+            ..ValidationOptions::default()
+        };
+        let mut engine = ValidationEngine::new(&schema)?;
+        let report = engine
+            .validate_collection(&instances, "Country", Some(options))
+            .await?;
+
+        let errors: Vec<_> = report.errors().collect();
+        assert_eq!(errors.len(), 1, "{report}");
+        assert_eq!(line_column(errors[0].location), Some((6, 7)));
+        assert!(errors[0].to_string().ends_with("(line 6, column 7)"));
+        Ok(())
+    }
+}
@@ -0,0 +1,70 @@
+//! Coercion of string values to primitive slot ranges
+//!
+//! Data loaded from YAML or CSV often carries numbers and booleans as
+//! strings. When `coerce_types` is set, the validation engine converts such
+//! strings to their slot's range before validating them, so `"42"` is
+//! checked as the integer 42.
+
+use linkml_core::types::SchemaDefinition;
+use serde_json::Value;
+use std::fmt;
+
+/// Primitive range that string values can be coerced to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CoercibleRange {
+    /// `integer`
+    Integer,
+    /// `float`, `double` or `decimal`
+    Float,
+    /// `boolean`
+    Boolean,
+}
+
+impl CoercibleRange {
+    /// Primitive range behind `range`, following `typeof` through the
+    /// schema's types
+    ///
+    /// Returns `None` for ranges that are not numeric or boolean.
+    pub(crate) fn of(range: &str, schema: &SchemaDefinition) -> Option<Self> {
+        schema.type_chain(range).find_map(|name| match name {
+            "integer" | "int" => Some(Self::Integer),
+            "float" | "double" | "decimal" => Some(Self::Float),
+            "boolean" | "bool" => Some(Self::Boolean),
+            _ => None,
+        })
+    }
+
+    /// Convert `text` to a value of this range
+    ///
+    /// Returns `None` if `text` does not spell such a value.
+    pub(crate) fn coerce(self, text: &str) -> Option<Value> {
+        let text = text.trim();
+        match self {
+            Self::Integer => text.parse::<i64>().ok().map(Value::from),
+            Self::Float => text
+                .parse::<f64>()
+                .ok()
+                .filter(|number| number.is_finite())
+                .map(Value::from),
+            Self::Boolean => {
+                if text.eq_ignore_ascii_case("true") {
+                    Some(Value::Bool(true))
+                } else if text.eq_ignore_ascii_case("false") {
+                    Some(Value::Bool(false))
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for CoercibleRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Integer => "integer",
+            Self::Float => "float",
+            Self::Boolean => "boolean",
+        })
+    }
+}
@@ -71,11 +71,14 @@ impl UniqueKeyValidator {
                 });
             }
 
-            // Check for identifier slots (implicit unique key)
-            for slot_name in &class_def.slots {
-                if let Some(slot_def) = schema.slots.get(slot_name)
-                    && slot_def.identifier.unwrap_or(false)
-                {
+            // Identifier slots and attributes are implicit unique keys
+            let declared = class_def
+                .slots
+                .iter()
+                .filter(|slot_name| !class_def.attributes.contains_key(*slot_name))
+                .filter_map(|slot_name| Some((slot_name, schema.slots.get(slot_name)?)));
+            for (slot_name, slot_def) in declared.chain(&class_def.attributes) {
+                if slot_def.identifier.unwrap_or(false) {
                     keys.push(UniqueKeyDefinition {
                         name: format!("{class_name}_id"),
                         unique_key_slots: vec![slot_name.clone()],
@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use crate::validator::{
     context::ValidationContext,
+    json_path::{PathSegment, path_segments},
     report::{Severity, ValidationIssue},
 };
 
@@ -53,8 +54,8 @@ impl AnyOfValidator {
             minimum_value: expr.minimum_value.clone(),
             maximum_value: expr.maximum_value.clone(),
             required: expr.required,
-            // Note: minimum_cardinality and maximum_cardinality are not in SlotDefinition
-            // They would need to be handled separately if needed
+            minimum_cardinality: expr.minimum_cardinality,
+            maximum_cardinality: expr.maximum_cardinality,
             ..Default::default()
         };
 
@@ -198,8 +199,8 @@ impl AllOfValidator {
             minimum_value: expr.minimum_value.clone(),
             maximum_value: expr.maximum_value.clone(),
             required: expr.required,
-            // Note: minimum_cardinality and maximum_cardinality are not in SlotDefinition
-            // They would need to be handled separately if needed
+            minimum_cardinality: expr.minimum_cardinality,
+            maximum_cardinality: expr.maximum_cardinality,
             ..Default::default()
         };
 
@@ -245,14 +246,17 @@ impl AllOfValidator {
         path: &str,
         schema: &Arc<linkml_core::types::SchemaDefinition>,
     ) -> Vec<ValidationIssue> {
-        // Create a new context for this thread
+        // Create a new context for this thread, positioned at `path`
         let mut context = ValidationContext::new(Arc::clone(schema));
-        context.push_path(path);
-
-        let issues = Self::validate_expression(value, expr, &mut context);
+        for segment in path_segments(path) {
+            match segment {
+                PathSegment::Property(name) => context.push_path(name),
+                PathSegment::Index(index) => context.push_index(index),
+                PathSegment::Root | PathSegment::Wildcard => {}
+            }
+        }
 
-        context.pop_path();
-        issues
+        Self::validate_expression(value, expr, &mut context)
     }
 }
 
@@ -284,7 +288,7 @@ impl Validator for AllOfValidator {
                     .par_iter()
                     .enumerate()
                     .map(|(i, constraint)| {
-                        let path = format!("{base_path}/all_of[{i}]");
+                        let path = format!("{base_path}.all_of[{i}]");
                         let issues = Self::validate_expression_parallel(
                             &value_arc,
                             constraint,
@@ -408,8 +412,8 @@ impl ExactlyOneOfValidator {
             minimum_value: expr.minimum_value.clone(),
             maximum_value: expr.maximum_value.clone(),
             required: expr.required,
-            // Note: minimum_cardinality and maximum_cardinality are not in SlotDefinition
-            // They would need to be handled separately if needed
+            minimum_cardinality: expr.minimum_cardinality,
+            maximum_cardinality: expr.maximum_cardinality,
             ..Default::default()
         };
 
@@ -612,8 +616,8 @@ impl NoneOfValidator {
             minimum_value: expr.minimum_value.clone(),
             maximum_value: expr.maximum_value.clone(),
             required: expr.required,
-            // Note: minimum_cardinality and maximum_cardinality are not in SlotDefinition
-            // They would need to be handled separately if needed
+            minimum_cardinality: expr.minimum_cardinality,
+            maximum_cardinality: expr.maximum_cardinality,
             ..Default::default()
         };
 
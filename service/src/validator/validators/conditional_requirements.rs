@@ -42,9 +42,9 @@ impl ConditionalRequirementValidator {
             _ => None,
         };
 
-        // If there's no condition specified, it's always satisfied
+        // Without a condition, the requirement is triggered by the slot being present
         let Some(slot_condition) = &condition.condition else {
-            return Ok(true);
+            return Ok(!matches!(slot_value, None | Some(Value::Null)));
         };
 
         // Check various condition types
@@ -164,9 +164,14 @@ impl ConditionalRequirementValidator {
 
                                 // Check if the required slot is missing or null
                                 if slot_value.is_none() || matches!(slot_value, Some(Value::Null)) {
+                                    let trigger = if requirement.condition.is_some() {
+                                        "satisfies condition"
+                                    } else {
+                                        "is present"
+                                    };
                                     let mut issue = ValidationIssue::error(
                                         format!(
-                                            "Field '{required_slot}' is required when '{condition_slot}' satisfies condition"
+                                            "Field '{required_slot}' is required when '{condition_slot}' {trigger}"
                                         ),
                                         context.path(),
                                         "ConditionalRequirementValidator",
@@ -505,4 +510,106 @@ mod tests {
         assert!(error_fields.contains(&"state".to_string()));
         assert!(error_fields.contains(&"student_id".to_string()));
     }
+
+    #[test]
+    fn test_presence_triggers_requirement() {
+        let validator = ConditionalRequirementValidator::new();
+        let mut context = ValidationContext::new(Arc::new(SchemaDefinition::default()));
+
+        let mut if_required = IndexMap::new();
+        if_required.insert(
+            "country".to_string(),
+            ConditionalRequirement {
+                condition: None,
+                then_required: Some(vec!["postal_code".to_string()]),
+            },
+        );
+        let class_def = ClassDefinition {
+            name: "Address".to_string(),
+            if_required: Some(if_required),
+            ..Default::default()
+        };
+
+        let issues = validator.validate_class(
+            &serde_json::json!({"street": "Main St", "country": "NL"}),
+            &class_def,
+            &mut context,
+        );
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues[0].message,
+            "Field 'postal_code' is required when 'country' is present"
+        );
+        assert_eq!(issues[0].pointer, "/postal_code");
+
+        for instance in [
+            serde_json::json!({"street": "Main St"}),
+            serde_json::json!({"street": "Main St", "country": null}),
+            serde_json::json!({"country": "NL", "postal_code": "1012 AB"}),
+        ] {
+            let issues = validator.validate_class(&instance, &class_def, &mut context);
+            assert!(issues.is_empty(), "{instance}: {issues:?}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_engine_enforces_if_required() -> anyhow::Result<()> {
+        let mut schema = SchemaDefinition::new("addresses");
+        for name in ["street", "country", "postal_code"] {
+            schema.slots.insert(
+                name.to_string(),
+                SlotDefinition {
+                    range: Some("string".to_string()),
+                    ..SlotDefinition::new(name)
+                },
+            );
+        }
+        let mut if_required = IndexMap::new();
+        if_required.insert(
+            "country".to_string(),
+            ConditionalRequirement {
+                condition: None,
+                then_required: Some(vec!["postal_code".to_string()]),
+            },
+        );
+        schema.classes.insert(
+            "Address".to_string(),
+            ClassDefinition {
+                slots: ["street", "country", "postal_code"]
+                    .map(str::to_string)
+                    .to_vec(),
+                if_required: Some(if_required),
+                ..ClassDefinition::new("Address")
+            },
+        );
+
+        let engine = crate::validator::ValidationEngine::new(&schema)?;
+        let report = engine
+            .validate_as_class(
+                &serde_json::json!({"street": "Main St", "country": "NL"}),
+                "Address",
+                None,
+            )
+            .await?;
+        let errors: Vec<_> = report.errors().collect();
+        assert_eq!(errors.len(), 1, "{report}");
+        assert_eq!(
+            errors[0].code.as_deref(),
+            Some("CONDITIONAL_REQUIREMENT_NOT_MET")
+        );
+        assert_eq!(
+            errors[0].context["condition_slot"],
+            serde_json::json!("country")
+        );
+        assert_eq!(
+            errors[0].context["required_slot"],
+            serde_json::json!("postal_code")
+        );
+
+        let report = engine
+            .validate_as_class(&serde_json::json!({"street": "Main St"}), "Address", None)
+            .await?;
+        assert!(report.valid, "{report}");
+        Ok(())
+    }
 }
@@ -3,6 +3,7 @@
 use super::utils::value_type;
 use super::{ValidationContext, ValidationIssue, Validator};
 use crate::utils::safe_cast::u64_to_f64_lossy;
+use crate::validator::EnumResolver;
 use linkml_core::annotations::AnnotationValue;
 use linkml_core::types::{ClassDefinition, SchemaDefinition, SlotDefinition};
 use serde_json::Value;
@@ -119,6 +120,209 @@ impl Validator for MultivaluedValidator {
     }
 }
 
+/// Validator for `minimum_cardinality` and `maximum_cardinality` of multivalued slots
+///
+/// Also rejects an empty list for a required multivalued slot, which the
+/// engine's presence check cannot see.
+pub struct CardinalityValidator;
+
+impl Default for CardinalityValidator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CardinalityValidator {
+    /// Create a new cardinality validator
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Validator for CardinalityValidator {
+    fn validate(
+        &self,
+        value: &Value,
+        slot: &SlotDefinition,
+        context: &mut ValidationContext,
+    ) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+
+        // Non-array values are reported by the multivalued validator
+        let Some(items) = value.as_array() else {
+            return issues;
+        };
+        if !slot.multivalued.unwrap_or(false) {
+            return issues;
+        }
+
+        let count = items.len();
+        if count == 0 && slot.required.unwrap_or(false) {
+            issues.push(
+                ValidationIssue::error(
+                    format!("Required multivalued slot '{}' is empty", slot.name),
+                    context.path(),
+                    self.name(),
+                )
+                .with_code("REQUIRED_MULTIVALUED_EMPTY"),
+            );
+            return issues;
+        }
+
+        // Negative bounds are meaningless; treat them as no lower bound and an
+        // empty upper bound respectively
+        let minimum = slot
+            .minimum_cardinality
+            .map(|min| usize::try_from(min).unwrap_or(0));
+        let maximum = slot
+            .maximum_cardinality
+            .map(|max| usize::try_from(max).unwrap_or(0));
+
+        if let Some(minimum) = minimum
+            && count < minimum
+        {
+            issues.push(
+                ValidationIssue::error(
+                    format!(
+                        "Slot '{}' has {count} values, fewer than the minimum cardinality of {minimum}",
+                        slot.name
+                    ),
+                    context.path(),
+                    self.name(),
+                )
+                .with_code("CARDINALITY_TOO_FEW")
+                .with_context("count", Value::from(count))
+                .with_context("minimum_cardinality", Value::from(minimum)),
+            );
+        }
+
+        if let Some(maximum) = maximum
+            && count > maximum
+        {
+            issues.push(
+                ValidationIssue::error(
+                    format!(
+                        "Slot '{}' has {count} values, more than the maximum cardinality of {maximum}",
+                        slot.name
+                    ),
+                    context.path(),
+                    self.name(),
+                )
+                .with_code("CARDINALITY_TOO_MANY")
+                .with_context("count", Value::from(count))
+                .with_context("maximum_cardinality", Value::from(maximum)),
+            );
+        }
+
+        issues
+    }
+
+    fn name(&self) -> &'static str {
+        "CardinalityValidator"
+    }
+}
+
+/// Validator for the `unique` and `ordered` list constraints of multivalued slots
+///
+/// Uniqueness compares elements by `JSON` value equality. Ordering requires
+/// non-decreasing values, comparing strings lexicographically and numbers
+/// numerically; adjacent elements of other or mixed types are not compared.
+pub struct ListElementsValidator;
+
+impl Default for ListElementsValidator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ListElementsValidator {
+    /// Create a new list elements validator
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn compare(left: &Value, right: &Value) -> Option<std::cmp::Ordering> {
+        match (left, right) {
+            (Value::String(left), Value::String(right)) => Some(left.cmp(right)),
+            (Value::Number(left), Value::Number(right)) => {
+                left.as_f64()?.partial_cmp(&right.as_f64()?)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Validator for ListElementsValidator {
+    fn validate(
+        &self,
+        value: &Value,
+        slot: &SlotDefinition,
+        context: &mut ValidationContext,
+    ) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+
+        let Some(items) = value.as_array() else {
+            return issues;
+        };
+        if !slot.multivalued.unwrap_or(false) {
+            return issues;
+        }
+
+        if slot.unique.unwrap_or(false) {
+            let mut reported: Vec<&Value> = Vec::new();
+            for (index, item) in items.iter().enumerate() {
+                let Some(first) = items[..index].iter().position(|seen| seen == item) else {
+                    continue;
+                };
+                if reported.contains(&item) {
+                    continue;
+                }
+                reported.push(item);
+                issues.push(
+                    ValidationIssue::error(
+                        format!(
+                            "Duplicate value {item} in slot '{}' (first at index {first})",
+                            slot.name
+                        ),
+                        format!("{}[{index}]", context.path()),
+                        self.name(),
+                    )
+                    .with_code("DUPLICATE_LIST_ELEMENT")
+                    .with_context("duplicate", item.clone()),
+                );
+            }
+        }
+
+        if slot.ordered.unwrap_or(false) {
+            let out_of_order = items.windows(2).position(|pair| {
+                Self::compare(&pair[0], &pair[1]) == Some(std::cmp::Ordering::Greater)
+            });
+            if let Some(position) = out_of_order {
+                let index = position + 1;
+                issues.push(
+                    ValidationIssue::error(
+                        format!(
+                            "Value {} at index {index} of slot '{}' sorts before the preceding value {}",
+                            items[index], slot.name, items[position]
+                        ),
+                        format!("{}[{index}]", context.path()),
+                        self.name(),
+                    )
+                    .with_code("LIST_ELEMENTS_NOT_ORDERED"),
+                );
+            }
+        }
+
+        issues
+    }
+
+    fn name(&self) -> &'static str {
+        "ListElementsValidator"
+    }
+}
+
 /// Validator for permissible values (enums)
 pub struct PermissibleValueValidator {
     name: String,
@@ -138,8 +342,25 @@ impl PermissibleValueValidator {
         })
     }
 
-    fn get_enum_values(&self, enum_name: &str) -> Option<HashSet<String>> {
-        self.schema.enums.get(enum_name).map(|enum_def| {
+    /// Permissible values of an enum
+    ///
+    /// A dynamic enum takes its values from the context, where
+    /// [`ValidationEngine::new_async`](crate::validator::ValidationEngine::new_async)
+    /// puts them; without them its values are not checked.
+    fn get_enum_values(
+        &self,
+        enum_name: &str,
+        context: &ValidationContext,
+    ) -> Option<HashSet<String>> {
+        let enum_def = self.schema.enums.get(enum_name)?;
+        if EnumResolver::is_dynamic(enum_def) {
+            return context
+                .enum_values
+                .as_ref()?
+                .get(enum_name)
+                .map(|values| values.as_ref().clone());
+        }
+        Some(
             enum_def
                 .permissible_values
                 .iter()
@@ -147,8 +368,8 @@ impl PermissibleValueValidator {
                     linkml_core::types::PermissibleValue::Simple(s) => s.clone(),
                     linkml_core::types::PermissibleValue::Complex { text, .. } => text.clone(),
                 })
-                .collect()
-        })
+                .collect(),
+        )
     }
 }
 
@@ -163,11 +384,15 @@ impl Validator for PermissibleValueValidator {
 
         // Check if the slot range is an enum
         if let Some(range) = &slot.range
-            && let Some(enum_values) = self.get_enum_values(range)
+            && let Some(enum_def) = self.schema.enums.get(range)
+            && let Some(enum_values) = self.get_enum_values(range, context)
         {
             let check_value = |v: &Value, path: &str| -> Option<ValidationIssue> {
                 if let Some(s) = v.as_str() {
-                    if enum_values.contains(s) {
+                    // Values may also be given as a permissible value's meaning
+                    if enum_values.contains(s)
+                        || enum_def.resolve_meaning(s, &self.schema).is_some()
+                    {
                         None
                     } else {
                         Some(ValidationIssue::error(
@@ -1006,3 +1231,174 @@ impl Validator for CrossReferenceValidator {
         &self.name
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn tags_slot() -> SlotDefinition {
+        let mut slot = SlotDefinition::new("tags");
+        slot.multivalued = Some(true);
+        slot.minimum_cardinality = Some(1);
+        slot.maximum_cardinality = Some(3);
+        slot
+    }
+
+    fn codes(issues: &[ValidationIssue]) -> Vec<&str> {
+        issues
+            .iter()
+            .filter_map(|issue| issue.code.as_deref())
+            .collect()
+    }
+
+    #[test]
+    fn test_cardinality_bounds() {
+        let validator = CardinalityValidator::new();
+        let mut context = ValidationContext::new(Arc::new(SchemaDefinition::default()));
+        let slot = tags_slot();
+
+        let issues = validator.validate(&json!([]), &slot, &mut context);
+        assert_eq!(codes(&issues), ["CARDINALITY_TOO_FEW"]);
+
+        let issues = validator.validate(&json!(["a", "b"]), &slot, &mut context);
+        assert!(issues.is_empty());
+
+        let issues = validator.validate(&json!(["a", "b", "c", "d"]), &slot, &mut context);
+        assert_eq!(codes(&issues), ["CARDINALITY_TOO_MANY"]);
+        assert_eq!(issues[0].context["count"], json!(4));
+    }
+
+    #[test]
+    fn test_required_multivalued_slot_cannot_be_empty() {
+        let validator = CardinalityValidator::new();
+        let mut context = ValidationContext::new(Arc::new(SchemaDefinition::default()));
+        let mut slot = SlotDefinition::new("tags");
+        slot.multivalued = Some(true);
+        slot.required = Some(true);
+
+        let issues = validator.validate(&json!([]), &slot, &mut context);
+        assert_eq!(codes(&issues), ["REQUIRED_MULTIVALUED_EMPTY"]);
+
+        let issues = validator.validate(&json!(["a"]), &slot, &mut context);
+        assert!(issues.is_empty());
+
+        // Bounds only apply to multivalued slots
+        slot.multivalued = Some(false);
+        let issues = validator.validate(&json!([]), &slot, &mut context);
+        assert!(issues.is_empty());
+    }
+
+    #[test]
+    fn test_list_elements_unique() {
+        let validator = ListElementsValidator::new();
+        let mut context = ValidationContext::new(Arc::new(SchemaDefinition::default()));
+        let mut slot = SlotDefinition::new("tags");
+        slot.multivalued = Some(true);
+        slot.unique = Some(true);
+
+        let issues = validator.validate(&json!(["a", "b", "c"]), &slot, &mut context);
+        assert!(issues.is_empty());
+
+        let issues = validator.validate(
+            &json!(["a", "b", "a", {"x": 1}, "a", {"x": 1}]),
+            &slot,
+            &mut context,
+        );
+        assert_eq!(
+            codes(&issues),
+            ["DUPLICATE_LIST_ELEMENT", "DUPLICATE_LIST_ELEMENT"]
+        );
+        assert_eq!(issues[0].path, "$[2]");
+        assert!(issues[0].message.contains("\"a\""));
+        assert_eq!(issues[1].context["duplicate"], json!({"x": 1}));
+    }
+
+    #[test]
+    fn test_list_elements_ordered() {
+        let validator = ListElementsValidator::new();
+        let mut context = ValidationContext::new(Arc::new(SchemaDefinition::default()));
+        let mut slot = SlotDefinition::new("scores");
+        slot.multivalued = Some(true);
+        slot.ordered = Some(true);
+
+        let issues = validator.validate(&json!([1, 2.5, 2.5, 10]), &slot, &mut context);
+        assert!(issues.is_empty());
+        let issues = validator.validate(&json!(["apple", "banana", "cherry"]), &slot, &mut context);
+        assert!(issues.is_empty());
+
+        let issues = validator.validate(&json!([1, 10, 2]), &slot, &mut context);
+        assert_eq!(codes(&issues), ["LIST_ELEMENTS_NOT_ORDERED"]);
+        assert_eq!(issues[0].path, "$[2]");
+
+        let issues = validator.validate(&json!(["b", "a"]), &slot, &mut context);
+        assert_eq!(codes(&issues), ["LIST_ELEMENTS_NOT_ORDERED"]);
+
+        // Constraints only apply to multivalued slots
+        slot.multivalued = Some(false);
+        let issues = validator.validate(&json!(["b", "a"]), &slot, &mut context);
+        assert!(issues.is_empty());
+    }
+
+    const STATUS_SCHEMA: &str = r"
+id: https://example.org/accounts
+name: accounts
+prefixes:
+  schema: http://schema.org/
+slots:
+  status:
+    range: AccountStatus
+classes:
+  Account:
+    slots: [status]
+enums:
+  AccountStatus:
+    permissible_values:
+      ACTIVE:
+        meaning: schema:Active
+      CLOSED:
+";
+
+    #[test]
+    fn test_enum_accepts_meaning_uri() -> anyhow::Result<()> {
+        let schema: SchemaDefinition = serde_yaml::from_str(STATUS_SCHEMA)?;
+        let validator = PermissibleValueValidator::new(&schema)?;
+        let mut context = ValidationContext::new(Arc::new(schema.clone()));
+        let slot = &schema.slots["status"];
+
+        for value in [
+            "ACTIVE",
+            "CLOSED",
+            "schema:Active",
+            "http://schema.org/Active",
+        ] {
+            let issues = validator.validate(&json!(value), slot, &mut context);
+            assert!(issues.is_empty(), "{value} should be accepted: {issues:?}");
+        }
+
+        let issues = validator.validate(&json!("http://schema.org/Closed"), slot, &mut context);
+        assert_eq!(issues.len(), 1);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_engine_validates_enum_meaning() -> anyhow::Result<()> {
+        let schema: SchemaDefinition = serde_yaml::from_str(STATUS_SCHEMA)?;
+        let engine = crate::validator::ValidationEngine::new(&schema)?;
+
+        let report = engine
+            .validate_as_class(
+                &json!({"status": "http://schema.org/Active"}),
+                "Account",
+                None,
+            )
+            .await?;
+        assert!(report.valid, "{report}");
+
+        let report = engine
+            .validate_as_class(&json!({"status": "PENDING"}), "Account", None)
+            .await?;
+        assert_eq!(report.errors().count(), 1, "{report}");
+        Ok(())
+    }
+}
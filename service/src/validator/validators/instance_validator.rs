@@ -2,6 +2,7 @@
 
 use super::{ValidationContext, ValidationIssue, Validator};
 use crate::validator::instance_loader::{InstanceConfig, InstanceLoader};
+use crate::validator::instance_resolver::is_accepted_utc_offset;
 use linkml_core::types::SlotDefinition;
 use serde_json::Value;
 use std::collections::HashMap;
@@ -196,7 +197,7 @@ impl InstanceValidator {
     ) -> Vec<ValidationIssue> {
         let mut issues = Vec::new();
 
-        if !self.check_value(value, &slot.name, context) {
+        if !self.check_value(value, &slot.name, context) && !is_accepted_utc_offset(slot, value) {
             // Get available values for better error message
             let available = if let Some(instance_data) = context.instance_data.as_ref() {
                 instance_data.get(&slot.name).map_or_else(
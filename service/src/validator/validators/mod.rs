@@ -25,7 +25,8 @@ pub use boolean_constraints::{
 };
 pub use conditional_requirements::ConditionalRequirementValidator;
 pub use constraint_validators::{
-    MultivaluedValidator, PermissibleValueValidator, RequiredValidator,
+    CardinalityValidator, ListElementsValidator, MultivaluedValidator, PermissibleValueValidator,
+    RequiredValidator,
 };
 pub use custom_validator::{
     AppliesTo, CustomValidator, CustomValidatorBuilder, ValidationFunction, helpers,
@@ -36,7 +37,11 @@ pub use pattern_validator::PatternValidator;
 pub use pattern_validator_enhanced::{EnhancedPatternValidator, PatternMatchResult};
 pub use range_validator::RangeValidator;
 pub use rule_validator::{RuleValidation, RuleValidator};
-pub use string_constraints::{EqualsStringInValidator, StructuredPatternValidator};
+pub(crate) use string_constraints::LengthFacets;
+pub use string_constraints::{
+    EqualsStringInValidator, EqualsValueValidator, StringLengthValidator,
+    StructuredPatternValidator, resolve_structured_pattern,
+};
 pub use type_validators::*;
 pub use unique_key_validator::{UniqueKeyValidator, UniqueValueTracker};
 
@@ -75,6 +80,8 @@ impl ValidatorRegistry {
             // Constraint validators
             Box::new(RequiredValidator::new()),
             Box::new(MultivaluedValidator::new()),
+            Box::new(CardinalityValidator::new()),
+            Box::new(ListElementsValidator::new()),
             Box::new(EnhancedPatternValidator::new()),
             Box::new(RangeValidator::new()),
             Box::new(PermissibleValueValidator::new(schema)?),
@@ -87,6 +94,8 @@ impl ValidatorRegistry {
             Box::new(ExpressionValidator::new()),
             // String constraint validators
             Box::new(EqualsStringInValidator::new()),
+            Box::new(EqualsValueValidator::new()),
+            Box::new(StringLengthValidator::new()),
             Box::new(StructuredPatternValidator::new()),
         ];
 
@@ -106,9 +115,12 @@ impl ValidatorRegistry {
             None
         };
 
-        // Create unique key validator if schema has classes with unique keys or identifier slots
-        let has_unique_constraints = schema.classes.values().any(|c| !c.unique_keys.is_empty())
-            || schema.slots.values().any(|s| s.identifier.unwrap_or(false));
+        // Create unique key validator if schema has classes with unique keys or identifiers
+        let has_unique_constraints =
+            schema.classes.values().any(|c| {
+                !c.unique_keys.is_empty()
+                    || c.attributes.values().any(|a| a.identifier.unwrap_or(false))
+            }) || schema.slots.values().any(|s| s.identifier.unwrap_or(false));
         let unique_key_validator = if has_unique_constraints {
             Some(UniqueKeyValidator::new())
         } else {
@@ -135,15 +147,37 @@ impl ValidatorRegistry {
                     "MultivaluedValidator" if slot.multivalued.is_some() => {
                         Some(validator.as_ref())
                     }
-                    "TypeValidator" if slot.range.is_some() => Some(validator.as_ref()),
-                    "EnhancedPatternValidator" | "PatternValidator"
+                    "CardinalityValidator"
+                        if slot.multivalued.unwrap_or(false)
+                            && (slot.minimum_cardinality.is_some()
+                                || slot.maximum_cardinality.is_some()
+                                || slot.required.unwrap_or(false)) =>
+                    {
+                        Some(validator.as_ref())
+                    }
+                    "ListElementsValidator"
+                        if slot.multivalued.unwrap_or(false)
+                            && (slot.unique.unwrap_or(false) || slot.ordered.unwrap_or(false)) =>
+                    {
+                        Some(validator.as_ref())
+                    }
+                    "TypeValidator" | "type_validator" if slot.range.is_some() => {
+                        Some(validator.as_ref())
+                    }
+                    "permissible_value_validator" if slot.range.is_some() => {
+                        Some(validator.as_ref())
+                    }
+                    "EnhancedPatternValidator"
+                    | "enhanced_pattern_validator"
+                    | "PatternValidator"
+                    | "pattern_validator"
                         if slot.pattern.is_some()
                             || slot.structured_pattern.is_some()
                             || slot.range.as_ref().is_some_and(|r| !r.is_empty()) =>
                     {
                         Some(validator.as_ref())
                     }
-                    "RangeValidator"
+                    "RangeValidator" | "range_validator"
                         if slot.minimum_value.is_some() || slot.maximum_value.is_some() =>
                     {
                         Some(validator.as_ref())
@@ -160,6 +194,19 @@ impl ValidatorRegistry {
                     "EqualsStringInValidator" if slot.equals_string_in.is_some() => {
                         Some(validator.as_ref())
                     }
+                    "EqualsValueValidator"
+                        if slot.equals_string.is_some() || slot.equals_number.is_some() =>
+                    {
+                        Some(validator.as_ref())
+                    }
+                    // Length bounds may also come from the range type
+                    "StringLengthValidator"
+                        if slot.min_length.is_some()
+                            || slot.max_length.is_some()
+                            || slot.range.is_some() =>
+                    {
+                        Some(validator.as_ref())
+                    }
                     "StructuredPatternValidator" if slot.structured_pattern.is_some() => {
                         Some(validator.as_ref())
                     }
@@ -1,6 +1,7 @@
 //! Enhanced pattern validation with named capture groups and LRU caching
 
 use super::{ValidationContext, ValidationIssue, Validator};
+use crate::validator::pattern_cache::PatternCache;
 use linkml_core::types::SlotDefinition;
 use lru::LruCache;
 use regex::Regex;
@@ -77,16 +78,24 @@ impl EnhancedPatternValidator {
     }
 
     /// Validate a string against a pattern and extract named captures
+    ///
+    /// Uses the engine's shared pattern cache when one is given, falling back
+    /// to this validator's own LRU cache otherwise.
     fn validate_pattern_with_captures(
         &self,
         value: &str,
         pattern: &str,
         path: &str,
+        shared_cache: Option<&PatternCache>,
     ) -> (Vec<ValidationIssue>, Option<Map<String, Value>>) {
         let mut issues = Vec::new();
         let mut captures = None;
 
-        match self.get_regex(pattern) {
+        let regex = match shared_cache {
+            Some(cache) => cache.get_or_compile(pattern),
+            None => self.get_regex(pattern),
+        };
+        match regex {
             Ok(regex) => {
                 if let Some(caps) = regex.captures(value) {
                     // Pattern matched, extract named captures
@@ -145,7 +154,12 @@ impl EnhancedPatternValidator {
         path: &str,
         context: &mut ValidationContext,
     ) -> Vec<ValidationIssue> {
-        let (issues, captures) = self.validate_pattern_with_captures(value, pattern, path);
+        let (issues, captures) = self.validate_pattern_with_captures(
+            value,
+            pattern,
+            path,
+            context.pattern_cache.as_deref(),
+        );
 
         // If we have captures and the slot name, store them
         if let Some(capture_map) = captures
@@ -278,7 +292,7 @@ mod tests {
         let pattern = r"^(?P<year>\d{4})-(?P<month>\d{2})-(?P<day>\d{2})$";
 
         let (issues, captures) =
-            validator.validate_pattern_with_captures("2025-01-31", pattern, "test_date");
+            validator.validate_pattern_with_captures("2025-01-31", pattern, "test_date", None);
 
         assert!(issues.is_empty());
         assert!(captures.is_some());
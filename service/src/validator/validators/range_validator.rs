@@ -1,5 +1,6 @@
 //! Range validation for numeric values
 
+use super::utils::parse_temporal;
 use super::{ValidationContext, ValidationIssue, Validator};
 use crate::utils::safe_cast::i64_to_f64_lossy;
 use linkml_core::types::SlotDefinition;
@@ -82,6 +83,51 @@ impl RangeValidator {
 
         issues
     }
+
+    /// Validate a `date`, `datetime` or `time` value against range bounds
+    ///
+    /// Values and bounds that do not parse are left to the type validator.
+    fn validate_temporal_range(
+        &self,
+        value: &Value,
+        range: &str,
+        slot: &SlotDefinition,
+        path: &str,
+    ) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+        let Some((text, actual)) = value
+            .as_str()
+            .and_then(|text| parse_temporal(range, text).map(|parsed| (text, parsed)))
+        else {
+            return issues;
+        };
+        let bound = |limit: &Option<Value>| {
+            limit
+                .as_ref()
+                .and_then(Value::as_str)
+                .and_then(|limit| parse_temporal(range, limit).map(|parsed| (limit, parsed)))
+        };
+
+        if let Some((min_text, min)) = bound(&slot.minimum_value)
+            && actual < min
+        {
+            issues.push(ValidationIssue::error(
+                format!("{range} '{text}' is before minimum {min_text}"),
+                path,
+                &self.name,
+            ));
+        }
+        if let Some((max_text, max)) = bound(&slot.maximum_value)
+            && actual > max
+        {
+            issues.push(ValidationIssue::error(
+                format!("{range} '{text}' is after maximum {max_text}"),
+                path,
+                &self.name,
+            ));
+        }
+        issues
+    }
 }
 
 impl Validator for RangeValidator {
@@ -100,6 +146,30 @@ impl Validator for RangeValidator {
 
         // Check if we should validate based on range type or actual value type
         let range_type = slot.range.as_deref().unwrap_or("string");
+
+        // Dates, datetimes and times are bounded by values of their own range
+        if matches!(range_type, "date" | "datetime" | "time") {
+            if slot.multivalued.unwrap_or(false) {
+                if let Some(array) = value.as_array() {
+                    for (i, element) in array.iter().enumerate() {
+                        issues.extend(self.validate_temporal_range(
+                            element,
+                            range_type,
+                            slot,
+                            &format!("{}[{}]", context.path(), i),
+                        ));
+                    }
+                }
+            } else {
+                issues.extend(self.validate_temporal_range(
+                    value,
+                    range_type,
+                    slot,
+                    &context.path(),
+                ));
+            }
+            return issues;
+        }
         let is_numeric_type = matches!(
             range_type,
             "integer" | "int" | "float" | "double" | "decimal" | "number"
@@ -1,15 +1,17 @@
 //! String constraint validators for `LinkML`
 //!
 //! This module implements validators for string-specific constraints like
-//! `equals_string_in` and `structured_pattern`.
+//! `equals_string_in` and `structured_pattern`, along with the constant-value
+//! constraints `equals_string` and `equals_number`.
 
 use linkml_core::{
     Value,
     error::{LinkMLError, Result},
-    types::SlotDefinition,
+    types::{SchemaDefinition, SlotDefinition, StructuredPattern, WhitespaceFacet},
 };
 use regex::Regex;
 use std::collections::HashSet;
+use std::sync::LazyLock;
 
 use crate::validator::{context::ValidationContext, report::ValidationIssue};
 
@@ -136,6 +138,229 @@ impl Validator for EqualsStringInValidator {
     }
 }
 
+/// Validator for the `equals_string` and `equals_number` constraints
+///
+/// Pins a slot to a single constant value. Each element of a multivalued
+/// slot must equal the constant.
+pub struct EqualsValueValidator;
+
+impl EqualsValueValidator {
+    /// Create a new constant-value validator
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Check one value against the slot's constants
+    fn check_value(
+        value: &Value,
+        slot: &SlotDefinition,
+        context: &ValidationContext,
+        issues: &mut Vec<ValidationIssue>,
+    ) {
+        if let Some(expected) = &slot.equals_string
+            && value.as_str() != Some(expected.as_str())
+        {
+            issues.push(
+                ValidationIssue::error(
+                    format!("Value {value} does not equal the required string '{expected}'"),
+                    context.path(),
+                    "EqualsValueValidator",
+                )
+                .with_code("EQUALS_STRING_VIOLATION")
+                .with_context("expected", Value::String(expected.clone()))
+                .with_context("value", value.clone()),
+            );
+        }
+
+        if let Some(expected) = slot.equals_number {
+            // Tolerate rounding relative to the magnitude of the numbers
+            let matches = value.as_f64().is_some_and(|actual| {
+                (actual - expected).abs() <= f64::EPSILON * actual.abs().max(expected.abs())
+            });
+            if !matches {
+                let mut issue = ValidationIssue::error(
+                    format!("Value {value} does not equal the required number {expected}"),
+                    context.path(),
+                    "EqualsValueValidator",
+                )
+                .with_code("EQUALS_NUMBER_VIOLATION")
+                .with_context("value", value.clone());
+                if let Some(number) = serde_json::Number::from_f64(expected) {
+                    issue = issue.with_context("expected", Value::Number(number));
+                }
+                issues.push(issue);
+            }
+        }
+    }
+}
+
+impl Validator for EqualsValueValidator {
+    fn validate(
+        &self,
+        value: &Value,
+        slot: &SlotDefinition,
+        context: &mut ValidationContext,
+    ) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+
+        if slot.equals_string.is_none() && slot.equals_number.is_none() {
+            return issues;
+        }
+
+        match value {
+            // Missing values are the concern of the required check
+            Value::Null => {}
+            Value::Array(items) if slot.multivalued.unwrap_or(false) => {
+                for (i, item) in items.iter().enumerate() {
+                    context.push_index(i);
+                    Self::check_value(item, slot, context, &mut issues);
+                    context.pop_path();
+                }
+            }
+            _ => Self::check_value(value, slot, context, &mut issues),
+        }
+
+        issues
+    }
+
+    fn name(&self) -> &'static str {
+        "EqualsValueValidator"
+    }
+}
+
+/// Validator for the `min_length` and `max_length` constraints
+///
+/// Bounds set on the slot take precedence over those of its range type,
+/// which are looked up through `typeof`. Lengths count characters, not
+/// bytes, and only string values are checked. A `whitespace` facet
+/// (`preserve`, `replace` or `collapse`, as in XSD) normalizes the value
+/// before it is measured.
+pub struct StringLengthValidator;
+
+/// Length facets of a slot, resolved through its range type
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct LengthFacets {
+    /// Minimum length
+    pub(crate) min_length: Option<usize>,
+    /// Maximum length
+    pub(crate) max_length: Option<usize>,
+    /// Whitespace handling before measuring
+    pub(crate) whitespace: Option<WhitespaceFacet>,
+}
+
+impl LengthFacets {
+    /// Whether there is a bound to check
+    pub(crate) fn has_bounds(&self) -> bool {
+        self.min_length.is_some() || self.max_length.is_some()
+    }
+}
+
+impl StringLengthValidator {
+    /// Create a new string length validator
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Length facets of `slot`, falling back to those of its range type
+    pub(crate) fn facets(slot: &SlotDefinition, schema: &SchemaDefinition) -> LengthFacets {
+        let mut facets = LengthFacets {
+            min_length: slot.min_length,
+            max_length: slot.max_length,
+            whitespace: slot.whitespace,
+        };
+        let range_types = slot
+            .range
+            .iter()
+            .flat_map(|range| schema.type_chain(range))
+            .filter_map(|name| schema.types.get(name));
+        for type_def in range_types {
+            facets.min_length = facets.min_length.or(type_def.min_length);
+            facets.max_length = facets.max_length.or(type_def.max_length);
+            facets.whitespace = facets.whitespace.or(type_def.whitespace);
+        }
+        facets
+    }
+
+    /// Check one value at `path` against the length facets
+    pub(crate) fn check_value(
+        value: &Value,
+        facets: LengthFacets,
+        path: &str,
+        issues: &mut Vec<ValidationIssue>,
+    ) {
+        let Some(text) = value.as_str() else {
+            return;
+        };
+        let length = match facets.whitespace {
+            Some(whitespace) => whitespace.normalize(text).chars().count(),
+            None => text.chars().count(),
+        };
+        if let Some(min) = facets.min_length
+            && length < min
+        {
+            issues.push(
+                ValidationIssue::error(
+                    format!("Value '{text}' is shorter than the minimum length {min}"),
+                    path,
+                    "StringLengthValidator",
+                )
+                .with_code("MIN_LENGTH_VIOLATION")
+                .with_context("min_length", Value::from(min))
+                .with_context("length", Value::from(length)),
+            );
+        }
+        if let Some(max) = facets.max_length
+            && length > max
+        {
+            issues.push(
+                ValidationIssue::error(
+                    format!("Value '{text}' is longer than the maximum length {max}"),
+                    path,
+                    "StringLengthValidator",
+                )
+                .with_code("MAX_LENGTH_VIOLATION")
+                .with_context("max_length", Value::from(max))
+                .with_context("length", Value::from(length)),
+            );
+        }
+    }
+}
+
+impl Validator for StringLengthValidator {
+    fn validate(
+        &self,
+        value: &Value,
+        slot: &SlotDefinition,
+        context: &mut ValidationContext,
+    ) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+
+        let facets = Self::facets(slot, &context.schema);
+        if !facets.has_bounds() {
+            return issues;
+        }
+
+        match value {
+            Value::Array(items) if slot.multivalued.unwrap_or(false) => {
+                for (i, item) in items.iter().enumerate() {
+                    context.push_index(i);
+                    Self::check_value(item, facets, &context.path(), &mut issues);
+                    context.pop_path();
+                }
+            }
+            _ => Self::check_value(value, facets, &context.path(), &mut issues),
+        }
+
+        issues
+    }
+
+    fn name(&self) -> &'static str {
+        "StringLengthValidator"
+    }
+}
+
 /// Validator for `structured_pattern` constraint
 ///
 /// This validator supports advanced pattern matching with different syntaxes
@@ -212,8 +437,14 @@ impl StructuredPatternValidator {
     }
 
     /// Validate using regex syntax
-    fn validate_regex(value: &str, pattern: &str, partial: bool) -> Result<bool> {
-        let regex = Regex::new(pattern)
+    fn validate_regex(
+        value: &str,
+        pattern: &str,
+        partial: bool,
+        context: &ValidationContext,
+    ) -> Result<bool> {
+        let regex = context
+            .compile_pattern(pattern)
             .map_err(|e| LinkMLError::data_validation(format!("Invalid regex pattern: {e}")))?;
 
         if partial {
@@ -225,13 +456,13 @@ impl StructuredPatternValidator {
     }
 
     /// Validate using glob syntax
-    fn validate_glob(value: &str, pattern: &str, partial: bool) -> Result<bool> {
-        // Simple glob implementation
-        // In production, use a proper glob library
-        let regex_pattern = pattern
-            .replace('.', r"\.")
-            .replace('*', ".*")
-            .replace('?', ".");
+    fn validate_glob(
+        value: &str,
+        pattern: &str,
+        partial: bool,
+        context: &ValidationContext,
+    ) -> Result<bool> {
+        let regex_pattern = glob_to_regex(pattern);
 
         // Use anchors for full match, or no anchors for partial match
         let final_pattern = if partial {
@@ -240,7 +471,8 @@ impl StructuredPatternValidator {
             format!("^{regex_pattern}$")
         };
 
-        let regex = Regex::new(&final_pattern)
+        let regex = context
+            .compile_pattern(&final_pattern)
             .map_err(|e| LinkMLError::data_validation(format!("Invalid glob pattern: {e}")))?;
 
         Ok(regex.is_match(value))
@@ -261,13 +493,16 @@ impl Validator for StructuredPatternValidator {
             return issues;
         };
 
-        let Some(pattern) = &structured_pattern.pattern else {
+        let Some(pattern) = structured_pattern_template(structured_pattern) else {
             return issues;
         };
 
-        // Apply interpolation if enabled
+        // Apply interpolation if enabled: named patterns from the schema
+        // settings first, then values from the instance being validated
         let final_pattern = if structured_pattern.interpolated.unwrap_or(false) {
-            match Self::interpolate_pattern(pattern, context) {
+            let interpolated = expand_pattern_settings(pattern, &context.schema, &mut Vec::new())
+                .and_then(|expanded| Self::interpolate_pattern(&expanded, context));
+            match interpolated {
                 Ok(p) => p,
                 Err(e) => {
                     let mut issue = ValidationIssue::error(
@@ -281,20 +516,17 @@ impl Validator for StructuredPatternValidator {
                 }
             }
         } else {
-            pattern.clone()
+            pattern.to_string()
         };
 
-        let syntax = structured_pattern
-            .syntax
-            .as_deref()
-            .unwrap_or("regular_expression");
+        let syntax = structured_pattern_dialect(structured_pattern);
         let partial = structured_pattern.partial_match.unwrap_or(false);
 
         match value {
             Value::String(s) => {
                 let matches = match syntax {
                     "regular_expression" | "regex" => {
-                        match Self::validate_regex(s, &final_pattern, partial) {
+                        match Self::validate_regex(s, &final_pattern, partial, context) {
                             Ok(m) => m,
                             Err(e) => {
                                 let mut issue = ValidationIssue::error(
@@ -308,7 +540,7 @@ impl Validator for StructuredPatternValidator {
                             }
                         }
                     }
-                    "glob" => match Self::validate_glob(s, &final_pattern, partial) {
+                    "glob" => match Self::validate_glob(s, &final_pattern, partial, context) {
                         Ok(m) => m,
                         Err(e) => {
                             let mut issue = ValidationIssue::error(
@@ -357,7 +589,7 @@ impl Validator for StructuredPatternValidator {
                     if let Value::String(s) = item {
                         let matches = match syntax {
                             "regular_expression" | "regex" => {
-                                match Self::validate_regex(s, &final_pattern, partial) {
+                                match Self::validate_regex(s, &final_pattern, partial, context) {
                                     Ok(m) => m,
                                     Err(e) => {
                                         let mut issue = ValidationIssue::error(
@@ -372,20 +604,22 @@ impl Validator for StructuredPatternValidator {
                                     }
                                 }
                             }
-                            "glob" => match Self::validate_glob(s, &final_pattern, partial) {
-                                Ok(m) => m,
-                                Err(e) => {
-                                    let mut issue = ValidationIssue::error(
-                                        format!("Pattern validation error: {e}"),
-                                        context.path(),
-                                        "StructuredPatternValidator",
-                                    );
-                                    issue.code = Some("PATTERN_ERROR".to_string());
-                                    issues.push(issue);
-                                    context.pop_path();
-                                    continue;
+                            "glob" => {
+                                match Self::validate_glob(s, &final_pattern, partial, context) {
+                                    Ok(m) => m,
+                                    Err(e) => {
+                                        let mut issue = ValidationIssue::error(
+                                            format!("Pattern validation error: {e}"),
+                                            context.path(),
+                                            "StructuredPatternValidator",
+                                        );
+                                        issue.code = Some("PATTERN_ERROR".to_string());
+                                        issues.push(issue);
+                                        context.pop_path();
+                                        continue;
+                                    }
                                 }
-                            },
+                            }
                             _ => {
                                 let mut issue = ValidationIssue::error(
                                     format!("Unsupported pattern syntax: {syntax}"),
@@ -432,12 +666,168 @@ impl Validator for StructuredPatternValidator {
     }
 }
 
+/// Matches `{name}` placeholders in structured patterns
+///
+/// Names must start with a letter or underscore so that regex quantifiers
+/// such as `{2}` or `{2,3}` are never mistaken for placeholders.
+static PLACEHOLDER_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\{([A-Za-z_][A-Za-z0-9_]*)\}").expect("Valid placeholder regex pattern")
+});
+
+/// Get the expression of a structured pattern
+///
+/// `LinkML` puts the expression in `syntax`; the older form used here keeps
+/// it in `pattern` and uses `syntax` to name the dialect.
+fn structured_pattern_template(structured_pattern: &StructuredPattern) -> Option<&str> {
+    structured_pattern
+        .pattern
+        .as_deref()
+        .or(structured_pattern.syntax.as_deref())
+}
+
+/// Get the matching dialect of a structured pattern
+fn structured_pattern_dialect(structured_pattern: &StructuredPattern) -> &str {
+    if structured_pattern.pattern.is_some() {
+        structured_pattern
+            .syntax
+            .as_deref()
+            .unwrap_or("regular_expression")
+    } else {
+        "regular_expression"
+    }
+}
+
+/// Translate a glob expression into an unanchored regular expression
+fn glob_to_regex(pattern: &str) -> String {
+    // Simple glob implementation
+    // In production, use a proper glob library
+    pattern
+        .replace('.', r"\.")
+        .replace('*', ".*")
+        .replace('?', ".")
+}
+
+/// Expand `{name}` placeholders from the schema's `settings.custom`
+///
+/// Setting values may themselves contain placeholders and are expanded
+/// recursively; `stack` holds the names currently being expanded so cycles
+/// are reported instead of recursing forever. Placeholders without a
+/// matching string setting are left in place.
+fn expand_pattern_settings(
+    template: &str,
+    schema: &SchemaDefinition,
+    stack: &mut Vec<String>,
+) -> Result<String> {
+    let Some(settings) = &schema.settings else {
+        return Ok(template.to_string());
+    };
+
+    let mut result = String::with_capacity(template.len());
+    let mut last = 0;
+    for caps in PLACEHOLDER_REGEX.captures_iter(template) {
+        let (Some(placeholder), Some(name)) = (caps.get(0), caps.get(1)) else {
+            continue;
+        };
+        result.push_str(&template[last..placeholder.start()]);
+        last = placeholder.end();
+
+        let name = name.as_str();
+        let Some(value) = settings.custom.get(name).and_then(Value::as_str) else {
+            result.push_str(placeholder.as_str());
+            continue;
+        };
+        if stack.iter().any(|entry| entry == name) {
+            stack.push(name.to_string());
+            return Err(LinkMLError::schema_validation(format!(
+                "Circular pattern reference: {}",
+                stack.join(" -> ")
+            )));
+        }
+
+        stack.push(name.to_string());
+        let expanded = expand_pattern_settings(value, schema, stack)?;
+        stack.pop();
+        result.push_str(&expanded);
+    }
+    result.push_str(&template[last..]);
+
+    Ok(result)
+}
+
+/// Resolve a slot's `structured_pattern` into a compiled regex
+///
+/// When the pattern is interpolated, `{pattern_name}` tokens are replaced by
+/// the named patterns in `settings.custom` (imported schemas contribute their
+/// settings when merged) until a concrete expression remains. The result is
+/// anchored unless `partial_match` is set.
+///
+/// # Errors
+///
+/// Returns an error if the slot has no structured pattern, a placeholder is
+/// undefined or part of a cycle, or the expanded pattern is not a valid regex.
+pub fn resolve_structured_pattern(
+    slot: &SlotDefinition,
+    schema: &SchemaDefinition,
+) -> Result<Regex> {
+    let structured_pattern = slot.structured_pattern.as_ref();
+    let (Some(structured_pattern), Some(template)) = (
+        structured_pattern,
+        structured_pattern.and_then(structured_pattern_template),
+    ) else {
+        return Err(LinkMLError::schema_validation(format!(
+            "Slot '{}' has no structured_pattern",
+            slot.name
+        )));
+    };
+
+    let expanded = if structured_pattern.interpolated.unwrap_or(false) {
+        expand_pattern_settings(template, schema, &mut Vec::new())?
+    } else {
+        template.to_string()
+    };
+    if let Some(caps) = PLACEHOLDER_REGEX.captures(&expanded) {
+        return Err(LinkMLError::schema_validation(format!(
+            "Undefined pattern '{}' in structured_pattern of slot '{}'",
+            &caps[1], slot.name
+        )));
+    }
+
+    let expression = match structured_pattern_dialect(structured_pattern) {
+        "glob" => glob_to_regex(&expanded),
+        _ => expanded,
+    };
+    let anchored = if structured_pattern.partial_match.unwrap_or(false) {
+        expression
+    } else {
+        format!("^(?:{expression})$")
+    };
+
+    Regex::new(&anchored).map_err(|e| {
+        LinkMLError::pattern(format!(
+            "Invalid structured_pattern for slot '{}': {e}",
+            slot.name
+        ))
+    })
+}
+
 impl Default for EqualsStringInValidator {
     fn default() -> Self {
         Self::new()
     }
 }
 
+impl Default for EqualsValueValidator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Default for StringLengthValidator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Default for StructuredPatternValidator {
     fn default() -> Self {
         Self::new()
@@ -447,7 +837,7 @@ impl Default for StructuredPatternValidator {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use linkml_core::types::{SchemaDefinition, StructuredPattern};
+    use linkml_core::settings::SchemaSettings;
     use std::sync::Arc;
 
     #[test]
@@ -506,6 +896,100 @@ mod tests {
         assert_eq!(issues.len(), 1);
     }
 
+    #[test]
+    fn test_equals_string_pins_value() {
+        let validator = EqualsValueValidator::new();
+        let schema = Arc::new(SchemaDefinition::default());
+        let mut context = ValidationContext::new(schema);
+
+        let mut slot = SlotDefinition::new("version");
+        slot.equals_string = Some("v1".to_string());
+
+        let issues = validator.validate(&Value::String("v1".to_string()), &slot, &mut context);
+        assert!(issues.is_empty());
+
+        let issues = validator.validate(&Value::String("v2".to_string()), &slot, &mut context);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].code.as_deref(), Some("EQUALS_STRING_VIOLATION"));
+        assert!(issues[0].message.contains("\"v2\""));
+        assert!(issues[0].message.contains("'v1'"));
+
+        // A number is never equal to a string constant
+        let issues = validator.validate(&serde_json::json!(1), &slot, &mut context);
+        assert_eq!(issues.len(), 1);
+    }
+
+    #[test]
+    fn test_equals_number() {
+        let validator = EqualsValueValidator::new();
+        let schema = Arc::new(SchemaDefinition::default());
+        let mut context = ValidationContext::new(schema);
+
+        let mut slot = SlotDefinition::new("schema_version");
+        slot.equals_number = Some(2.0);
+
+        assert!(
+            validator
+                .validate(&serde_json::json!(2), &slot, &mut context)
+                .is_empty()
+        );
+        assert!(
+            validator
+                .validate(&serde_json::json!(2.0), &slot, &mut context)
+                .is_empty()
+        );
+
+        let issues = validator.validate(&serde_json::json!(3), &slot, &mut context);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].code.as_deref(), Some("EQUALS_NUMBER_VIOLATION"));
+        assert_eq!(issues[0].context["expected"], serde_json::json!(2.0));
+
+        let issues = validator.validate(&serde_json::json!("2"), &slot, &mut context);
+        assert_eq!(issues.len(), 1);
+
+        // The tolerance scales with the numbers compared
+        slot.equals_number = Some(0.3);
+        assert!(
+            validator
+                .validate(&serde_json::json!(0.1 + 0.2), &slot, &mut context)
+                .is_empty()
+        );
+        slot.equals_number = Some(1e-20);
+        let issues = validator.validate(&serde_json::json!(2e-20), &slot, &mut context);
+        assert_eq!(issues.len(), 1);
+        slot.equals_number = Some(1e20);
+        assert!(
+            validator
+                .validate(&serde_json::json!(1e20 + 1.0), &slot, &mut context)
+                .is_empty()
+        );
+        let issues = validator.validate(&serde_json::json!(1.001e20), &slot, &mut context);
+        assert_eq!(issues.len(), 1);
+    }
+
+    #[test]
+    fn test_equals_string_in_reports_allowed_and_actual() {
+        let validator = EqualsStringInValidator::new();
+        let schema = Arc::new(SchemaDefinition::default());
+        let mut context = ValidationContext::new(schema);
+
+        let mut slot = SlotDefinition::new("grade");
+        slot.equals_string_in = Some(vec!["a".to_string(), "b".to_string(), "c".to_string()]);
+
+        for allowed in ["a", "b", "c"] {
+            let issues =
+                validator.validate(&Value::String(allowed.to_string()), &slot, &mut context);
+            assert!(issues.is_empty(), "{allowed} should be accepted");
+        }
+
+        let issues = validator.validate(&Value::String("d".to_string()), &slot, &mut context);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(
+            issues[0].message,
+            "Value 'd' is not in the allowed set: [a, b, c]"
+        );
+    }
+
     #[test]
     fn test_structured_pattern_regex() {
         let validator = StructuredPatternValidator::new();
@@ -647,4 +1131,72 @@ mod tests {
         let issues = validator.validate(&value, &slot, &mut context);
         assert_eq!(issues.len(), 1);
     }
+
+    fn schema_with_patterns(patterns: &[(&str, &str)]) -> SchemaDefinition {
+        let mut schema = SchemaDefinition::new("patterns");
+        schema.settings = Some(SchemaSettings {
+            custom: patterns
+                .iter()
+                .map(|(name, pattern)| ((*name).to_string(), Value::String((*pattern).to_string())))
+                .collect(),
+            ..Default::default()
+        });
+        schema
+    }
+
+    fn interpolated_slot(name: &str, template: &str) -> SlotDefinition {
+        let mut slot = SlotDefinition::new(name);
+        slot.structured_pattern = Some(StructuredPattern {
+            syntax: Some(template.to_string()),
+            interpolated: Some(true),
+            ..Default::default()
+        });
+        slot
+    }
+
+    #[test]
+    fn test_resolve_structured_pattern_from_settings() -> Result<()> {
+        let schema = schema_with_patterns(&[
+            ("country_code_identifier_pattern", "[A-Z]{2}"),
+            ("region_code_identifier_pattern", "[A-Z]{2,3}"),
+            (
+                "fqn_pattern",
+                "{country_code_identifier_pattern}-{region_code_identifier_pattern}",
+            ),
+        ]);
+        let slot = interpolated_slot("fqn", "{fqn_pattern}");
+
+        let regex = resolve_structured_pattern(&slot, &schema)?;
+        assert_eq!(regex.as_str(), "^(?:[A-Z]{2}-[A-Z]{2,3})$");
+        assert!(regex.is_match("US-NYC"));
+        assert!(!regex.is_match("us-NYC"));
+
+        // The validator expands the same settings during validation
+        let validator = StructuredPatternValidator::new();
+        let mut context = ValidationContext::new(Arc::new(schema));
+        let valid = Value::String("GB-LND".to_string());
+        assert!(validator.validate(&valid, &slot, &mut context).is_empty());
+        let invalid = Value::String("GB-london".to_string());
+        assert_eq!(validator.validate(&invalid, &slot, &mut context).len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_resolve_structured_pattern_errors() {
+        let schema =
+            schema_with_patterns(&[("a_pattern", "{b_pattern}"), ("b_pattern", "x{a_pattern}")]);
+
+        let cyclic = interpolated_slot("cyclic", "{a_pattern}");
+        let err = resolve_structured_pattern(&cyclic, &schema)
+            .expect_err("cyclic patterns should be rejected");
+        assert!(
+            err.to_string()
+                .contains("a_pattern -> b_pattern -> a_pattern")
+        );
+
+        let undefined = interpolated_slot("undefined", "{missing_pattern}");
+        let err = resolve_structured_pattern(&undefined, &schema)
+            .expect_err("undefined patterns should be rejected");
+        assert!(err.to_string().contains("missing_pattern"));
+    }
 }
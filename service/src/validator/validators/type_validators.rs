@@ -1,9 +1,10 @@
 //! Type validators for `LinkML` primitive types
 
+use super::utils::parse_temporal;
 use super::{ValidationContext, ValidationIssue, Validator};
 use crate::validator::interned_report::{InternedValidationIssue, IssueBuilder};
 use crate::validator::string_interner::global_interner;
-use chrono::{DateTime, NaiveDate};
+use chrono::NaiveDate;
 use linkml_core::types::SlotDefinition;
 use serde_json::Value;
 use url::Url;
@@ -107,16 +108,16 @@ impl TypeValidator {
             }
             "datetime" => {
                 if let Some(s) = value.as_str() {
-                    if DateTime::parse_from_rfc3339(s).is_err() {
+                    if parse_temporal("datetime", s).is_none() {
                         issues.push(ValidationIssue::error(
-                            format!("Invalid datetime format: '{s}'. Expected RFC3339"),
+                            format!("Invalid datetime format: '{s}'. Expected ISO 8601"),
                             path,
                             &self.name,
                         ));
                     }
                 } else {
                     issues.push(ValidationIssue::error(
-                        "Datetime must be a string in RFC3339 format",
+                        "Datetime must be a string in ISO 8601 format",
                         path,
                         &self.name,
                     ));
@@ -5,7 +5,8 @@
 
 use linkml_core::types::{ClassDefinition, SchemaDefinition, SlotDefinition};
 use serde_json::Value;
-use std::collections::{HashMap, HashSet};
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
 use std::sync::Mutex;
 
 use crate::validator::{context::ValidationContext, report::ValidationIssue};
@@ -15,8 +16,9 @@ use super::Validator;
 /// Tracks unique values seen for validation
 #[derive(Default)]
 pub struct UniqueValueTracker {
-    /// Maps from class name to unique key name to set of seen value combinations
-    seen_values: HashMap<String, HashMap<String, HashSet<String>>>,
+    /// Maps from class name to unique key name to seen value combinations and
+    /// the path of the instance that first used each
+    seen_values: HashMap<String, HashMap<String, HashMap<String, String>>>,
 }
 
 impl UniqueValueTracker {
@@ -34,12 +36,30 @@ impl UniqueValueTracker {
         unique_key_name: &str,
         value_key: String,
     ) -> bool {
+        self.record(class_name, unique_key_name, value_key, "")
+            .is_some()
+    }
+
+    /// Record a value combination for the instance at `instance_path`
+    /// Returns the path of the instance that already used it, if any
+    pub fn record(
+        &mut self,
+        class_name: &str,
+        unique_key_name: &str,
+        value_key: String,
+        instance_path: &str,
+    ) -> Option<String> {
         let class_values = self.seen_values.entry(class_name.to_string()).or_default();
 
         let key_values = class_values.entry(unique_key_name.to_string()).or_default();
 
-        // Returns false if inserted (new value), true if already existed (duplicate)
-        !key_values.insert(value_key)
+        match key_values.entry(value_key) {
+            Entry::Occupied(first) => Some(first.get().clone()),
+            Entry::Vacant(slot) => {
+                slot.insert(instance_path.to_string());
+                None
+            }
+        }
     }
 
     /// Clear all tracked values
@@ -117,6 +137,62 @@ impl UniqueKeyValidator {
         Some(key_parts.join("\u{001F}")) // Unit separator character
     }
 
+    /// The class's identifier slot or attribute, if it has one
+    fn identifier_slot<'a>(
+        class_def: &'a ClassDefinition,
+        schema: &SchemaDefinition,
+    ) -> Option<&'a String> {
+        class_def
+            .attributes
+            .iter()
+            .find(|(_, attr)| attr.identifier.unwrap_or(false))
+            .map(|(attr_name, _)| attr_name)
+            .or_else(|| {
+                class_def.slots.iter().find(|slot_name| {
+                    !class_def.attributes.contains_key(*slot_name)
+                        && schema
+                            .slots
+                            .get(*slot_name)
+                            .and_then(|s| s.identifier)
+                            .unwrap_or(false)
+                })
+            })
+    }
+
+    /// Identifier and unique key values of a class instance
+    ///
+    /// Each value is paired with the name of its key, `__identifier__` for
+    /// the identifier slot. Two instances violate a constraint exactly when
+    /// they share a pair.
+    #[must_use]
+    pub fn key_values(
+        instance: &Value,
+        class_def: &ClassDefinition,
+        schema: &SchemaDefinition,
+    ) -> Vec<(String, String)> {
+        let mut values = Vec::new();
+        if let Some(identifier_slot) = Self::identifier_slot(class_def, schema)
+            && let Some(value) = Self::get_slot_value(instance, identifier_slot)
+            && !matches!(value, Value::Null)
+        {
+            let key = serde_json::to_string(value).unwrap_or_else(|_| value.to_string());
+            values.push(("__identifier__".to_string(), key));
+        }
+        for (key_name, unique_key_def) in &class_def.unique_keys {
+            if unique_key_def.unique_key_slots.is_empty() {
+                continue;
+            }
+            if let Some(composite_key) = Self::create_composite_key(
+                instance,
+                &unique_key_def.unique_key_slots,
+                unique_key_def.consider_nulls_inequal.unwrap_or(true),
+            ) {
+                values.push((key_name.clone(), composite_key));
+            }
+        }
+        values
+    }
+
     /// Validate unique keys for a class instance
     /// Returns an error if the operation fails
     ///
@@ -141,27 +217,31 @@ impl UniqueKeyValidator {
             .expect("tracker mutex should not be poisoned: {}");
 
         // Check identifier slot (if present)
-        if let Some(identifier_slot) = class_def.slots.iter().find(|slot_name| {
-            schema
-                .slots
-                .get(*slot_name)
-                .and_then(|s| s.identifier)
-                .unwrap_or(false)
-        }) && let Some(value) = Self::get_slot_value(instance, identifier_slot)
+        if let Some(identifier_slot) = Self::identifier_slot(class_def, schema)
+            && let Some(value) = Self::get_slot_value(instance, identifier_slot)
             && !matches!(value, Value::Null)
         {
             let key = serde_json::to_string(value).unwrap_or_else(|_| value.to_string());
 
-            if tracker.check_and_record(&class_def.name, "__identifier__", key.clone()) {
+            if let Some(first_path) = tracker.record(
+                &class_def.name,
+                "__identifier__",
+                key.clone(),
+                instance_path,
+            ) {
                 issues.push(
                     ValidationIssue::error(
-                        format!("Duplicate identifier value '{key}' for slot '{identifier_slot}'"),
+                        format!(
+                            "Duplicate identifier value '{key}' for slot '{identifier_slot}' \
+                             (first used at {first_path})"
+                        ),
                         instance_path,
                         "UniqueKeyValidator",
                     )
                     .with_code("DUPLICATE_IDENTIFIER")
                     .with_context("slot", serde_json::json!(identifier_slot))
-                    .with_context("value", value.clone()),
+                    .with_context("value", value.clone())
+                    .with_context("first_path", serde_json::json!(first_path)),
                 );
             }
         }
@@ -178,7 +258,8 @@ impl UniqueKeyValidator {
                 instance,
                 &unique_key_def.unique_key_slots,
                 consider_nulls_inequal,
-            ) && tracker.check_and_record(&class_def.name, key_name, composite_key.clone())
+            ) && let Some(first_path) =
+                tracker.record(&class_def.name, key_name, composite_key, instance_path)
             {
                 let slot_values: HashMap<String, Value> = unique_key_def
                     .unique_key_slots
@@ -191,9 +272,10 @@ impl UniqueKeyValidator {
                 issues.push(
                     ValidationIssue::error(
                         format!(
-                            "Duplicate values for unique key '{}' on slots: {}",
+                            "Duplicate values for unique key '{}' on slots: {} (first used at {})",
                             key_name,
-                            unique_key_def.unique_key_slots.join(", ")
+                            unique_key_def.unique_key_slots.join(", "),
+                            first_path
                         ),
                         instance_path,
                         "UniqueKeyValidator",
@@ -204,7 +286,8 @@ impl UniqueKeyValidator {
                         "unique_key_slots",
                         serde_json::json!(unique_key_def.unique_key_slots),
                     )
-                    .with_context("duplicate_values", serde_json::json!(slot_values)),
+                    .with_context("duplicate_values", serde_json::json!(slot_values))
+                    .with_context("first_path", serde_json::json!(first_path)),
                 );
             }
         }
@@ -260,8 +343,8 @@ impl UniqueKeyValidator {
         match self.validate_class(instance, class_def, schema, &context.path()) {
             Ok(issues) => issues,
             Err(e) => vec![ValidationIssue::error(
-                context.path(),
                 format!("Unique key validation error: {e}"),
+                context.path(),
                 "UniqueKeyValidator",
             )],
         }
@@ -428,6 +511,11 @@ mod tests {
                 .contains("Duplicate values for unique key 'name_email'")
         );
         assert_eq!(issues3[0].code.as_deref(), Some("DUPLICATE_UNIQUE_KEY"));
+        assert_eq!(issues3[0].path, "$.persons[2]");
+        assert_eq!(
+            issues3[0].context["first_path"],
+            serde_json::json!("$.persons[0]")
+        );
         Ok(())
     }
 
@@ -648,4 +736,56 @@ mod tests {
         assert!(issues3.is_empty());
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_engine_collection_reports_duplicate_composite_key() -> anyhow::Result<()> {
+        let mut schema = SchemaDefinition::new("assignments");
+        for name in ["employee", "project", "role"] {
+            schema.slots.insert(
+                name.to_string(),
+                SlotDefinition {
+                    range: Some("string".to_string()),
+                    ..SlotDefinition::new(name)
+                },
+            );
+        }
+        let mut unique_keys = IndexMap::new();
+        unique_keys.insert(
+            "employee_project".to_string(),
+            UniqueKeyDefinition {
+                description: None,
+                unique_key_slots: vec!["employee".to_string(), "project".to_string()],
+                consider_nulls_inequal: None,
+            },
+        );
+        schema.classes.insert(
+            "Assignment".to_string(),
+            ClassDefinition {
+                slots: ["employee", "project", "role"].map(str::to_string).to_vec(),
+                unique_keys,
+                ..ClassDefinition::new("Assignment")
+            },
+        );
+
+        let instances = vec![
+            serde_json::json!({"employee": "e1", "project": "p1", "role": "lead"}),
+            serde_json::json!({"employee": "e1", "project": "p2", "role": "lead"}),
+            serde_json::json!({"employee": "e1", "project": "p1", "role": "reviewer"}),
+        ];
+        let mut engine = crate::validator::ValidationEngine::new(&schema)?;
+        let report = engine
+            .validate_collection(&instances, "Assignment", None)
+            .await?;
+
+        let errors: Vec<_> = report.errors().collect();
+        assert_eq!(errors.len(), 1, "{report}");
+        assert_eq!(errors[0].code.as_deref(), Some("DUPLICATE_UNIQUE_KEY"));
+        assert_eq!(errors[0].path, "$[2]");
+        assert_eq!(errors[0].context["first_path"], serde_json::json!("$[0]"));
+        assert_eq!(
+            errors[0].context["unique_key_name"],
+            serde_json::json!("employee_project")
+        );
+        Ok(())
+    }
 }
@@ -1,5 +1,6 @@
 //! Utility functions for validators
 
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime};
 use serde_json::Value;
 
 /// Get the type name of a `JSON` value
@@ -14,3 +15,25 @@ pub fn value_type(value: &Value) -> &'static str {
         Value::Object(_) => "object",
     }
 }
+
+/// Parse an ISO 8601 value of a `date`, `datetime` or `time` range
+///
+/// Values are returned as comparable datetimes: datetimes with an offset
+/// in UTC, dates at the start of the day and times on 1970-01-01. Returns
+/// `None` for other ranges and for text not in the range's format.
+#[must_use]
+pub fn parse_temporal(range: &str, text: &str) -> Option<NaiveDateTime> {
+    match range {
+        "date" => NaiveDate::parse_from_str(text, "%Y-%m-%d")
+            .ok()
+            .map(|date| date.and_time(NaiveTime::MIN)),
+        "datetime" => DateTime::parse_from_rfc3339(text)
+            .map(|datetime| datetime.naive_utc())
+            .or_else(|_| NaiveDateTime::parse_from_str(text, "%Y-%m-%dT%H:%M:%S%.f"))
+            .ok(),
+        "time" => NaiveTime::parse_from_str(text, "%H:%M:%S%.f")
+            .ok()
+            .map(|time| DateTime::UNIX_EPOCH.date_naive().and_time(time)),
+        _ => None,
+    }
+}
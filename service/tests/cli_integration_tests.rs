@@ -1,9 +1,12 @@
-//! End-to-end CLI integration tests for LinkML SchemaSheets commands
+//! End-to-end CLI integration tests for LinkML SchemaSheets, validate and
+//! generate commands
 //!
 //! These tests execute the actual CLI commands via `std::process::Command`
 //! to validate the complete workflow from command-line to output files.
 
 use linkml_core::prelude::*;
+use linkml_service::cli_enhanced::commands::{JsonValidationReport, JsonValidationSummary};
+use linkml_service::validator::Severity;
 use std::fs;
 use std::path::PathBuf;
 use std::process::Command;
@@ -249,6 +252,145 @@ fn test_schema2sheets_all_options() {
     assert!(excel_path.exists(), "Excel file was not created");
 }
 
+/// Test validate reports a malformed country code and exits non-zero
+#[test]
+fn test_validate_rejects_invalid_country_code() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+
+    let schema_path = temp_dir.path().join("countries.yaml");
+    fs::write(
+        &schema_path,
+        r"id: https://example.org/countries
+name: countries
+slots:
+  code:
+    range: string
+    required: true
+    pattern: '^[A-Z]{2}$'
+  name:
+    range: string
+classes:
+  Country:
+    slots: [code, name]
+",
+    )
+    .expect("Failed to write schema");
+
+    let valid_path = temp_dir.path().join("netherlands.yaml");
+    fs::write(&valid_path, "code: NL\nname: Netherlands\n").expect("Failed to write data");
+    let invalid_path = temp_dir.path().join("belgium.json");
+    fs::write(&invalid_path, r#"{"code": "bel", "name": "Belgium"}"#)
+        .expect("Failed to write data");
+
+    let validate = |data: &PathBuf| {
+        Command::new(env!("CARGO_BIN_EXE_linkml"))
+            .args([
+                "validate",
+                "--schema",
+                schema_path.to_str().unwrap(),
+                "--class",
+                "Country",
+                data.to_str().unwrap(),
+            ])
+            .output()
+            .expect("Failed to execute validate")
+    };
+
+    let output = validate(&valid_path);
+    assert!(
+        output.status.success(),
+        "validate failed on valid data: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let output = validate(&invalid_path);
+    assert!(
+        !output.status.success(),
+        "validate should fail on an invalid country code"
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        stdout.contains("$.code") && stdout.contains("bel"),
+        "Each error should be printed with its path: {stdout}"
+    );
+
+    // Structured output for pipelines, with the exit code unchanged
+    let output = Command::new(env!("CARGO_BIN_EXE_linkml"))
+        .args([
+            "validate",
+            "--format",
+            "json",
+            "--schema",
+            schema_path.to_str().unwrap(),
+            "--class",
+            "Country",
+            valid_path.to_str().unwrap(),
+            invalid_path.to_str().unwrap(),
+        ])
+        .output()
+        .expect("Failed to execute validate");
+    assert!(!output.status.success());
+    let report: JsonValidationReport =
+        serde_json::from_slice(&output.stdout).expect("validate should print a JSON report");
+    assert!(!report.valid);
+    assert_eq!(
+        report.summary,
+        JsonValidationSummary {
+            passed: 1,
+            failed: 1
+        }
+    );
+    let errors: Vec<_> = report
+        .errors
+        .iter()
+        .filter(|issue| issue.severity == Severity::Error)
+        .collect();
+    assert_eq!(errors.len(), 1, "{:?}", report.errors);
+    assert_eq!(errors[0].file, invalid_path);
+    assert_eq!(errors[0].path, "$.code");
+}
+
+/// Test generate writes TypeQL for the Person schema to a file or stdout
+#[test]
+fn test_generate_typeql_from_person_schema() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let schema_path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../core/tests/fixtures/person_schema.yaml"
+    );
+    let tql_path = temp_dir.path().join("typeql").join("person.tql");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_linkml"))
+        .args([
+            "generate",
+            "--schema",
+            schema_path,
+            "--format",
+            "typeql",
+            "--output",
+            tql_path.to_str().unwrap(),
+        ])
+        .output()
+        .expect("Failed to execute generate");
+    assert!(
+        output.status.success(),
+        "generate failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let typeql = fs::read_to_string(&tql_path).expect("TypeQL file was not created");
+    assert!(typeql.contains("define"), "{typeql}");
+    assert!(typeql.contains("person sub entity"), "{typeql}");
+    assert!(typeql.contains("owns email"), "{typeql}");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_linkml"))
+        .args(["generate", "--schema", schema_path, "--format", "typeql"])
+        .output()
+        .expect("Failed to execute generate");
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("person sub entity"));
+}
+
 /// Helper function to create a test schema
 fn create_test_schema() -> SchemaDefinition {
     let mut schema = SchemaDefinition::new("test_schema");
@@ -0,0 +1,84 @@
+//! Generated from LinkML schema: countries
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Validation errors for generated types
+#[derive(Debug, Error)]
+pub enum ValidationError {
+    #[error("Required field missing: {field}")]
+    RequiredField { field: &'static str },
+    #[error("Invalid value for field {field}: {message}")]
+    InvalidValue { field: &'static str, message: String },
+    #[error("Pattern validation failed for field {field}")]
+    PatternValidation { field: &'static str },
+}
+
+
+/// Country
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Country {
+    pub code: String,
+    pub name: String,
+    pub population: Option<i64>,
+    pub languages: Vec<String>,
+}
+
+impl Country {
+    /// Check the constraints declared in the schema, returning every violation
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+        if self.code.is_empty() {
+            errors.push(format!("{}: required value is missing", "code"));
+        }
+        {
+            let value = &self.code;
+            static PATTERN: std::sync::LazyLock<Result<regex::Regex, regex::Error>> =
+                std::sync::LazyLock::new(|| regex::Regex::new("^[A-Z]{2}$"));
+            match &*PATTERN {
+                Ok(regex) if !regex.is_match(value) => errors.push(format!("{}: {value:?} does not match pattern {}", "code", "^[A-Z]{2}$")),
+                Ok(_) => {}
+                Err(err) => errors.push(format!("{}: invalid pattern: {err}", "code")),
+            }
+        }
+        if self.name.is_empty() {
+            errors.push(format!("{}: required value is missing", "name"));
+        }
+        if let Some(value) = &self.population {
+            if *value < 0 {
+                errors.push(format!("{}: {value} is below the minimum 0", "population"));
+            }
+        }
+        if self.languages.is_empty() {
+            errors.push(format!("{}: required value is missing", "languages"));
+        }
+        if errors.is_empty() { Ok(()) } else { Err(errors) }
+    }
+}
+
+
+/// City
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct City {
+    pub name: String,
+    pub country: Country,
+}
+
+impl City {
+    /// Check the constraints declared in the schema, returning every violation
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+        if self.name.is_empty() {
+            errors.push(format!("{}: required value is missing", "name"));
+        }
+        {
+            let value = &self.country;
+            if let Err(nested) = value.validate() {
+                errors.extend(nested.into_iter().map(|err| format!("{}.{err}", "country")));
+            }
+        }
+        if errors.is_empty() { Ok(()) } else { Err(errors) }
+    }
+}
+
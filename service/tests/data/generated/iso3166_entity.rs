@@ -0,0 +1,37 @@
+//! Generated from LinkML schema: iso3166
+//! ISO 3166-1 country codes
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use thiserror::Error;
+
+/// Validation errors for generated types
+#[derive(Debug, Error)]
+pub enum ValidationError {
+    #[error("Required field missing: {field}")]
+    RequiredField { field: &'static str },
+    #[error("Invalid value for field {field}: {message}")]
+    InvalidValue { field: &'static str, message: String },
+    #[error("Pattern validation failed for field {field}")]
+    PatternValidation { field: &'static str },
+}
+
+
+/// An ISO 3166-1 country
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ISO3166Entity {
+    pub id: std::sync::Arc<str>,
+    #[serde(alias = "name")]
+    pub label: std::sync::Arc<str>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tld: Option<std::sync::Arc<str>>,
+    #[serde(alias = "exactMappings")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub exact_mappings: Vec<std::sync::Arc<str>>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<std::sync::Arc<str>>,
+}
+
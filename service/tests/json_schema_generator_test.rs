@@ -56,13 +56,16 @@ async fn test_basic_json_schema_generation() {
     let parsed: Value = serde_json::from_str(&json_schema.content).expect("Test operation failed");
 
     // Check basic properties
-    assert_eq!(parsed["$schema"], "http://json-schema.org/draft-07/schema#");
+    assert_eq!(
+        parsed["$schema"],
+        "https://json-schema.org/draft/2020-12/schema"
+    );
     assert_eq!(parsed["$id"], "https://example.org/person");
     assert_eq!(parsed["title"], "person_schema");
     assert_eq!(parsed["description"], "Schema for person data");
 
     // Check Person definition
-    let person_def = &parsed["definitions"]["Person"];
+    let person_def = &parsed["$defs"]["Person"];
     assert_eq!(person_def["type"], "object");
     assert_eq!(person_def["description"], "A human being");
 
@@ -132,7 +135,7 @@ async fn test_enum_json_schema() {
     let parsed: Value = serde_json::from_str(&results[0].content).expect("Test operation failed");
 
     // Check enum definition
-    let order_status = &parsed["definitions"]["OrderStatus"];
+    let order_status = &parsed["$defs"]["OrderStatus"];
     assert_eq!(order_status["type"], "string");
     assert_eq!(order_status["description"], "Status of an order");
 
@@ -145,10 +148,10 @@ async fn test_enum_json_schema() {
     assert!(enum_values.contains(&json!("delivered")));
 
     // Check that class references the enum
-    let order_def = &parsed["definitions"]["Order"];
+    let order_def = &parsed["$defs"]["Order"];
     assert_eq!(
         order_def["properties"]["status"]["$ref"],
-        "#/definitions/OrderStatus"
+        "#/$defs/OrderStatus"
     );
 }
 
@@ -191,7 +194,7 @@ async fn test_inheritance_json_schema() {
     let parsed: Value = serde_json::from_str(&results[0].content).expect("Test operation failed");
 
     // Check Person uses allOf for inheritance
-    let person_def = &parsed["definitions"]["Person"];
+    let person_def = &parsed["$defs"]["Person"];
     assert!(person_def["allOf"].is_array());
     let all_of = person_def["allOf"]
         .as_array()
@@ -199,7 +202,7 @@ async fn test_inheritance_json_schema() {
     assert_eq!(all_of.len(), 2);
 
     // First element should be reference to Entity
-    assert_eq!(all_of[0]["$ref"], "#/definitions/Entity");
+    assert_eq!(all_of[0]["$ref"], "#/$defs/Entity");
 
     // Second element should have Person's own properties
     assert!(all_of[1]["properties"]["name"].is_object());
@@ -237,7 +240,7 @@ async fn test_multivalued_json_schema() {
     let parsed: Value = serde_json::from_str(&results[0].content).expect("Test operation failed");
 
     // Check array handling
-    let team_def = &parsed["definitions"]["Team"];
+    let team_def = &parsed["$defs"]["Team"];
     assert_eq!(team_def["properties"]["members"]["type"], "array");
     assert_eq!(team_def["properties"]["members"]["items"]["type"], "string");
 }
@@ -287,24 +290,18 @@ async fn test_custom_types_json_schema() {
     let parsed: Value = serde_json::from_str(&results[0].content).expect("Test operation failed");
 
     // Check custom type definitions
-    let url_def = &parsed["definitions"]["URL"];
+    let url_def = &parsed["$defs"]["URL"];
     assert_eq!(url_def["type"], "string");
     assert_eq!(url_def["pattern"], r"^https?://");
 
-    let price_def = &parsed["definitions"]["Price"];
+    let price_def = &parsed["$defs"]["Price"];
     assert_eq!(price_def["type"], "number");
     assert_eq!(price_def["minimum"], 0.0);
 
     // Check that properties reference custom types
-    let product_def = &parsed["definitions"]["Product"];
-    assert_eq!(
-        product_def["properties"]["website"]["$ref"],
-        "#/definitions/URL"
-    );
-    assert_eq!(
-        product_def["properties"]["price"]["$ref"],
-        "#/definitions/Price"
-    );
+    let product_def = &parsed["$defs"]["Product"];
+    assert_eq!(product_def["properties"]["website"]["$ref"], "#/$defs/URL");
+    assert_eq!(product_def["properties"]["price"]["$ref"], "#/$defs/Price");
 }
 
 #[tokio::test]
@@ -331,7 +328,7 @@ async fn test_additional_properties_handling() {
     let parsed: Value = serde_json::from_str(&results[0].content).expect("Test operation failed");
 
     // Check that additionalProperties is false
-    let class_def = &parsed["definitions"]["StrictClass"];
+    let class_def = &parsed["$defs"]["StrictClass"];
     // The generator might not set additionalProperties by default, so let's check if it exists
     if class_def.get("additionalProperties").is_some() {
         assert_eq!(class_def["additionalProperties"], false);
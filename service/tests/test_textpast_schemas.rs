@@ -10,6 +10,7 @@
 #![allow(missing_docs)]
 
 use linkml_service::parser::{YamlParserSimple, SchemaLoader, SchemaParser};
+use linkml_service::validator::InstanceLoader;
 use std::path::PathBuf;
 
 /// Helper function to get the repository root path
@@ -48,6 +49,43 @@ async fn test_parse_hyperentity_schema_with_service() {
     println!("✓ Hyperentity schema parsed successfully with {} classes (including imports)", schema.classes.len());
 }
 
+/// Test that the hyperentity schema metrics match its class hierarchy
+#[tokio::test]
+async fn test_hyperentity_schema_metrics() {
+    let loader = SchemaLoader::new();
+    let schema_path = get_repo_root().join("crates/model/symbolic/schemata/meta/entity/hyperentity/schema.yaml");
+
+    let schema = loader.load_file(&schema_path)
+        .await
+        .expect("Failed to load hyperentity schema");
+    let metrics = schema.metrics();
+
+    assert!(metrics.class_count >= 3, "Should report at least 3 classes");
+    assert_eq!(metrics.class_count, schema.classes.len());
+
+    // Longest is_a chain among the loaded classes, counted link by link
+    let expected_depth = schema
+        .classes
+        .keys()
+        .map(|name| {
+            let mut depth = 0;
+            let mut current = name.as_str();
+            while let Some(parent) = schema.classes.get(current).and_then(|c| c.is_a.as_deref()) {
+                if depth >= schema.classes.len() {
+                    break;
+                }
+                depth += 1;
+                current = parent;
+            }
+            depth
+        })
+        .max()
+        .unwrap_or(0);
+    assert_eq!(metrics.max_inheritance_depth, expected_depth);
+
+    println!("✓ Hyperentity schema reports {} classes with inheritance depth {}", metrics.class_count, metrics.max_inheritance_depth);
+}
+
 /// Test that the country schema parses with txp: imports resolved
 #[tokio::test]
 async fn test_parse_country_schema_with_txp_imports() {
@@ -96,18 +134,16 @@ fn test_parse_iso3166_instance_file() {
         .expect("Failed to parse instance YAML");
 
     // Check required metadata (NEW CONVENTION: real metadata, not commented out)
+    let header = InstanceLoader::validate_instance_header(
+        &serde_json::to_value(&instance_data).expect("Failed to convert instance YAML"),
+    )
+    .expect("Instance file header should follow the convention");
     assert_eq!(
-        instance_data["id"].as_str().unwrap(),
+        header.id,
         "https://textpast.org/instance/place/polity/country/iso_3166_entity"
     );
-    assert_eq!(
-        instance_data["schema"].as_str().unwrap(),
-        "https://textpast.org/schema/place/polity/country"
-    );
-    assert_eq!(instance_data["name"].as_str().unwrap(), "iso_3166_entity");
-    assert!(instance_data["version"].as_str().is_some(), "Version should be present");
-    assert!(instance_data["created_on"].as_str().is_some(), "created_on should be present");
-    assert!(instance_data["last_updated_on"].as_str().is_some(), "last_updated_on should be present");
+    assert_eq!(header.schema, "https://textpast.org/schema/place/polity/country");
+    assert_eq!(header.name.as_deref(), Some("iso_3166_entity"));
 
     // Check instances array exists and is not empty (NEW CONVENTION: 'instances' key)
     let instances = instance_data["instances"].as_sequence().unwrap();
@@ -122,38 +158,31 @@ fn test_parse_iso3166_instance_file() {
     assert!(first["exact_mappings"].as_sequence().is_some());
 }
 
-#[test]
-fn test_iso3166_identifier_validation() {
-    let path = get_repo_root().join("crates/model/symbolic/schemata/place/polity/country/iso_3166_entity.yaml");
-    let content = std::fs::read_to_string(&path)
-        .expect("Failed to read instance file");
-
-    let instance_data: serde_yaml::Value = serde_yaml::from_str(&content)
-        .expect("Failed to parse instance YAML");
-
-    let instances = instance_data["instances"].as_sequence().unwrap();
-
-    // All IDs should be exactly 2 uppercase letters (ISO 3166-1 alpha-2)
-    // This validates the CountryCodeAlpha2Identifier type constraint
-    let pattern = regex::Regex::new(r"^[A-Z]{2}$").unwrap();
-
-    let mut valid_count = 0;
-    let mut invalid_ids = Vec::new();
+#[tokio::test]
+async fn test_iso3166_identifier_validation() {
+    let country_dir = get_repo_root().join("crates/model/symbolic/schemata/place/polity/country");
+    let schema = SchemaLoader::new()
+        .load_file(&country_dir.join("schema.yaml"))
+        .await
+        .expect("Failed to load country schema");
+    let instance_path = country_dir.join("iso_3166_entity.yaml");
 
-    for instance in instances {
-        let id = instance["id"].as_str().unwrap();
-        if pattern.is_match(id) {
-            valid_count += 1;
-        } else {
-            invalid_ids.push(id.to_string());
-        }
-    }
+    // ISO3166Entity's identifier slot_usage ranges over
+    // CountryCodeAlpha2Identifier, so linting against the class checks the
+    // same ISO 3166-1 alpha-2 pattern as linting against the type
+    let report = InstanceLoader::lint_instances(&instance_path, &schema, "ISO3166Entity")
+        .await
+        .expect("Failed to lint instance file against ISO3166Entity");
+    let type_report = InstanceLoader::lint_instances(&instance_path, &schema, "CountryCodeAlpha2Identifier")
+        .await
+        .expect("Failed to lint instance file against CountryCodeAlpha2Identifier");
+    assert_eq!(report.pattern, type_report.pattern);
 
-    assert_eq!(
-        valid_count,
-        instances.len(),
+    assert_eq!(report.checked, 248);
+    assert!(
+        report.is_clean(),
         "All IDs should match CountryCodeAlpha2Identifier pattern. Invalid IDs: {:?}",
-        invalid_ids
+        report.issues
     );
 }
 
@@ -191,9 +220,13 @@ fn test_slot_usage_scoped_imports() {
         //
         // This means the LinkML service should only look for CountryCodeAlpha2Identifier
         // in txp:meta/identifier/identifier/schema, not in other imports.
-        //
-        // Note: The SlotDefinition type may need an 'imports' field to fully support this.
-        // For now, we verify the range and required fields are correct.
+        // Resolution through the scoped import is covered by the import
+        // resolver's `test_slot_scoped_import_wins`; here we check it is parsed.
+        assert_eq!(
+            identifier_usage.imports,
+            ["txp:meta/identifier/identifier/schema"],
+            "Identifier slot usage should keep its scoped import"
+        );
     }
 }
 